@@ -20,6 +20,7 @@
 use crate::ai_proposal_types::AIProposal;
 use crate::deterministic_inference::InferenceRecord;
 use bleep_crypto::pq_crypto::{DigitalSignature, PublicKey};
+use bleep_crypto::tx_signer::verify_tx_signature;
 use serde::{Deserialize, Serialize};
 use sha3::{Digest, Sha3_256};
 use std::collections::BTreeMap;
@@ -58,6 +59,8 @@ impl fmt::Display for AttestationError {
 
 impl std::error::Error for AttestationError {}
 
+pub type AttestationResult<T> = Result<T, AttestationError>;
+
 // ==================== PROOF OF INFERENCE ====================
 
 /// Complete proof-of-inference record for auditing
@@ -247,14 +250,7 @@ impl AIOutputCommitment {
         let timestamp = Self::current_timestamp();
 
         // Determine constraint approval from proof
-        let constraint_approved = if let Some(ref proof) = proof_of_inference {
-            matches!(
-                proof.constraint_outcome,
-                ConstraintOutcome::Approved | ConstraintOutcome::ApprovedWithWarnings { .. }
-            )
-        } else {
-            false
-        };
+        let constraint_approved = Self::approved_by(proof_of_inference.as_ref());
 
         Ok(Self {
             proposal,
@@ -285,6 +281,48 @@ impl AIOutputCommitment {
         format!("{:x}", hasher.finalize())
     }
 
+    /// Digest the AI system signs, binding the model ID, input hash, output
+    /// (the proposal, re-hashed here rather than trusting `proposal_hash`),
+    /// epoch, commitment nonce, and constraint outcome.
+    ///
+    /// Returns `None` without a proof of inference, since the model, input,
+    /// and outcome are only known from it.
+    pub fn output_commitment(&self) -> Option<[u8; 32]> {
+        let proof = self.proof_of_inference.as_ref()?;
+        let inference = &proof.inference;
+        let outcome = bincode::serialize(&proof.constraint_outcome).ok()?;
+        let mut hasher = Sha3_256::new();
+
+        hasher.update(b"BLEEP-AI-OUTPUT-v2");
+        for field in [
+            inference.model_id.as_bytes(),
+            inference.input_hash.as_bytes(),
+            Self::compute_proposal_hash(&self.proposal).as_bytes(),
+            &self.epoch.to_le_bytes(),
+            &self.commitment_nonce,
+            &outcome,
+        ] {
+            hasher.update((field.len() as u64).to_le_bytes());
+            hasher.update(field);
+        }
+
+        Some(hasher.finalize().into())
+    }
+
+    /// Whether `constraint_approved` agrees with the signed constraint outcome
+    pub fn approval_consistent(&self) -> bool {
+        self.constraint_approved == Self::approved_by(self.proof_of_inference.as_ref())
+    }
+
+    fn approved_by(proof: Option<&ProofOfInference>) -> bool {
+        proof.is_some_and(|proof| {
+            matches!(
+                proof.constraint_outcome,
+                ConstraintOutcome::Approved | ConstraintOutcome::ApprovedWithWarnings { .. }
+            )
+        })
+    }
+
     fn compute_proposal_hash(proposal: &AIProposal) -> String {
         let serialized = bincode::serialize(proposal).unwrap_or_default();
         let mut hasher = Sha3_256::new();
@@ -414,6 +452,25 @@ impl AIAttestationManager {
         Ok(())
     }
 
+    /// Verify the record's SPHINCS+ signature over its recomputed output
+    /// commitment.
+    ///
+    /// False if the record has no proof of inference, the key or signature
+    /// is malformed, the model, input, output, epoch, nonce, or constraint
+    /// outcome differ from what was signed, or `constraint_approved`
+    /// contradicts the signed outcome.
+    pub fn verify_attestation(&self, record: &AIAttestationRecord, pubkey: &[u8]) -> bool {
+        if !record.commitment.approval_consistent() {
+            return false;
+        }
+        match record.commitment.output_commitment() {
+            Some(commitment) => {
+                verify_tx_signature(&commitment, &record.commitment.ai_signature, pubkey)
+            }
+            None => false,
+        }
+    }
+
     /// Get attestation by ID
     pub fn get_attestation(&self, attestation_id: &str) -> Option<AIAttestationRecord> {
         self.records.get(attestation_id).cloned()
@@ -483,7 +540,8 @@ pub struct AttestationStats {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::ai_proposal_types::ConsensusModeProposal;
+    use crate::ai_proposal_types::{ConsensusModeProposal, EvidenceType};
+    use bleep_crypto::tx_signer::{generate_tx_keypair, sign_tx_payload};
 
     #[test]
     fn test_proof_of_inference_consistency() {
@@ -505,6 +563,7 @@ mod tests {
             processing_ms: 10,
             success: true,
             error: None,
+            fixed_point_scale: None,
         };
 
         let proof = ProofOfInference {
@@ -572,4 +631,118 @@ mod tests {
         let record2 = AIAttestationRecord::new(commitment1);
         assert!(manager.record_attestation(record2).is_err());
     }
+
+    fn mode_switch(proposed_mode: &str) -> AIProposal {
+        AIProposal::ConsensusModeSwitch(ConsensusModeProposal {
+            current_mode: "PoS".to_string(),
+            proposed_mode: proposed_mode.to_string(),
+            activation_epoch: 100,
+            reason: "Test".to_string(),
+            confidence: 0.8,
+            evidence: vec![EvidenceType::Metric {
+                name: "finality_latency".to_string(),
+                value: 15.0,
+                threshold: 20.0,
+                direction: "below".to_string(),
+            }],
+            risk_score: 20,
+            cooldown_epochs: 2,
+        })
+    }
+
+    fn signed_record(secret_key: &[u8]) -> AIAttestationRecord {
+        let inference = InferenceRecord {
+            inference_id: "inf-1".to_string(),
+            model_id: "consensus-advisor".to_string(),
+            model_version: "1.0".to_string(),
+            model_hash: "hash123".to_string(),
+            input_hash: "input-digest".to_string(),
+            inputs: vec![1.0, 2.0],
+            normalized_inputs: vec![1.0, 2.0],
+            outputs: vec![0.5],
+            final_outputs: vec![0.5],
+            output_hash: "output".to_string(),
+            timestamp: 1000,
+            epoch_id: 10,
+            nonce: vec![1, 2, 3],
+            confidence: 0.95,
+            processing_ms: 10,
+            success: true,
+            error: None,
+            fixed_point_scale: None,
+        };
+        let proof = ProofOfInference {
+            inference,
+            inference_hash: "hash".to_string(),
+            ai_signature: vec![1, 2, 3],
+            attestation_timestamp: 1001,
+            attestation_nonce: vec![4, 5, 6],
+            constraints_checked: vec!["safety".to_string()],
+            constraints_passed: vec!["safety".to_string()],
+            constraints_failed: vec![],
+            constraint_outcome: ConstraintOutcome::Approved,
+        };
+
+        let mut commitment =
+            AIOutputCommitment::new(mode_switch("PBFT"), 10, Vec::new(), Some(proof)).unwrap();
+        let message = commitment.output_commitment().unwrap();
+        commitment.ai_signature = sign_tx_payload(&message, secret_key).unwrap();
+        AIAttestationRecord::new(commitment)
+    }
+
+    #[test]
+    fn test_verify_attestation_valid_signature() {
+        let (public_key, secret_key) = generate_tx_keypair();
+        let manager = AIAttestationManager::new(10);
+        let record = signed_record(&secret_key);
+
+        assert!(manager.verify_attestation(&record, &public_key));
+    }
+
+    #[test]
+    fn test_verify_attestation_rejects_tampered_output() {
+        let (public_key, secret_key) = generate_tx_keypair();
+        let manager = AIAttestationManager::new(10);
+        let mut record = signed_record(&secret_key);
+
+        record.commitment.proposal = mode_switch("PoW");
+        assert!(!manager.verify_attestation(&record, &public_key));
+    }
+
+    #[test]
+    fn test_verify_attestation_rejects_wrong_key() {
+        let (_, secret_key) = generate_tx_keypair();
+        let (other_key, _) = generate_tx_keypair();
+        let manager = AIAttestationManager::new(10);
+        let record = signed_record(&secret_key);
+
+        assert!(!manager.verify_attestation(&record, &other_key));
+        assert!(!manager.verify_attestation(&record, &[0u8; 7]));
+    }
+
+    #[test]
+    fn test_verify_attestation_rejects_replayed_context_and_outcome() {
+        let (public_key, secret_key) = generate_tx_keypair();
+        let manager = AIAttestationManager::new(10);
+        let record = signed_record(&secret_key);
+
+        // Replayed into another epoch
+        let mut replayed = record.clone();
+        replayed.commitment.epoch = 11;
+        assert!(!manager.verify_attestation(&replayed, &public_key));
+
+        // Replayed under a fresh nonce to dodge the used-nonce set
+        let mut replayed = record.clone();
+        replayed.commitment.commitment_nonce = vec![9; 16];
+        assert!(!manager.verify_attestation(&replayed, &public_key));
+
+        // Rejected outcome relabelled as approved, or approval flag flipped
+        let mut relabelled = record.clone();
+        let proof = relabelled.commitment.proof_of_inference.as_mut().unwrap();
+        proof.constraint_outcome = ConstraintOutcome::ApprovedWithWarnings { warnings: vec![] };
+        assert!(!manager.verify_attestation(&relabelled, &public_key));
+        let mut flipped = record;
+        flipped.commitment.constraint_approved = false;
+        assert!(!manager.verify_attestation(&flipped, &public_key));
+    }
 }
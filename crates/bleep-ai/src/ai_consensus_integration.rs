@@ -46,6 +46,8 @@ impl fmt::Display for IntegrationError {
 
 impl std::error::Error for IntegrationError {}
 
+pub type IntegrationResult<T> = Result<T, IntegrationError>;
+
 // ==================== PROPOSAL LIFECYCLE ====================
 
 /// Track proposal through consensus and execution
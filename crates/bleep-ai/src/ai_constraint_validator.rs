@@ -29,7 +29,7 @@ use crate::ai_proposal_types::{
     ValidatorSecurityProposal,
 };
 use serde::{Deserialize, Serialize};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt;
 
 // ==================== ERROR TYPES ====================
@@ -65,6 +65,8 @@ impl fmt::Display for ConstraintError {
 
 impl std::error::Error for ConstraintError {}
 
+pub type ConstraintResult<T> = Result<T, ConstraintError>;
+
 // ==================== PROTOCOL INVARIANTS ====================
 
 /// Protocol safety invariants
@@ -96,6 +98,9 @@ pub struct ProtocolInvariants {
 
     /// Maximum rollback distance (blocks)
     pub max_rollback_distance: u64,
+
+    /// Maximum fraction of active validators removed in one epoch (0.0-1.0)
+    pub max_removal_fraction_per_epoch: f32,
 }
 
 impl ProtocolInvariants {
@@ -111,6 +116,7 @@ impl ProtocolInvariants {
             max_finality_latency_blocks: 100,
             min_mode_switch_cooldown: 10,
             max_rollback_distance: 10000,
+            max_removal_fraction_per_epoch: 0.10,
         }
     }
 
@@ -126,6 +132,7 @@ impl ProtocolInvariants {
             max_finality_latency_blocks: 500,
             min_mode_switch_cooldown: 2,
             max_rollback_distance: 100000,
+            max_removal_fraction_per_epoch: 0.20,
         }
     }
 }
@@ -138,6 +145,9 @@ pub struct ConstraintContext {
     /// Current epoch
     pub current_epoch: u64,
 
+    /// Current chain head height (blocks)
+    pub current_block_height: u64,
+
     /// Last consensus mode switch epoch
     pub last_mode_switch_epoch: u64,
 
@@ -187,6 +197,9 @@ pub struct ConstraintValidator {
 
     /// Tracked constraint evaluations (for audit)
     constraint_log: Vec<ConstraintEvaluation>,
+
+    /// Validators removed by accepted proposals, per epoch
+    removed_per_epoch: BTreeMap<u64, BTreeSet<String>>,
 }
 
 /// Record of a constraint evaluation
@@ -205,6 +218,36 @@ pub struct ConstraintEvaluation {
     pub evaluated_at: u64,
 }
 
+/// Outcome of a single constraint in a `ValidationReport`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConstraintCheck {
+    /// Constraint name
+    pub name: String,
+
+    /// Whether it passed
+    pub passed: bool,
+
+    /// "OK" or the failure reason
+    pub reason: String,
+}
+
+/// Per-constraint results for one proposal
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ValidationReport {
+    /// Every evaluated constraint, in evaluation order
+    pub checks: Vec<ConstraintCheck>,
+
+    /// True only if every constraint passed
+    pub accepted: bool,
+}
+
+impl ValidationReport {
+    /// Names of the constraints that failed
+    pub fn failed(&self) -> Vec<&str> {
+        self.checks.iter().filter(|c| !c.passed).map(|c| c.name.as_str()).collect()
+    }
+}
+
 impl ConstraintValidator {
     /// Create new validator
     pub fn new(invariants: ProtocolInvariants, context: ConstraintContext) -> Self {
@@ -212,6 +255,7 @@ impl ConstraintValidator {
             invariants,
             context,
             constraint_log: Vec::new(),
+            removed_per_epoch: BTreeMap::new(),
         }
     }
 
@@ -220,105 +264,11 @@ impl ConstraintValidator {
         let mut passed_constraints = Vec::new();
         let mut failed_constraints = Vec::new();
 
-        match proposal {
-            AIProposal::ConsensusModeSwitch(p) => {
-                // Consensus switching constraints
-                if self.validate_mode_switch_not_duplicate(p).is_ok() {
-                    passed_constraints.push("mode_switch_not_duplicate".to_string());
-                } else {
-                    failed_constraints.push("mode_switch_not_duplicate".to_string());
-                }
-
-                if self.validate_mode_switch_cooldown(p).is_ok() {
-                    passed_constraints.push("mode_switch_cooldown".to_string());
-                } else {
-                    failed_constraints.push("mode_switch_cooldown".to_string());
-                }
-
-                if self.validate_mode_switch_min_validators(p).is_ok() {
-                    passed_constraints.push("mode_switch_min_validators".to_string());
-                } else {
-                    failed_constraints.push("mode_switch_min_validators".to_string());
-                }
-
-                if self.validate_mode_switch_min_participation(p).is_ok() {
-                    passed_constraints.push("mode_switch_min_participation".to_string());
-                } else {
-                    failed_constraints.push("mode_switch_min_participation".to_string());
-                }
-
-                if self.validate_mode_switch_risk(p).is_ok() {
-                    passed_constraints.push("mode_switch_risk_acceptable".to_string());
-                } else {
-                    failed_constraints.push("mode_switch_risk_acceptable".to_string());
-                }
-            }
-
-            AIProposal::ShardRollback(p) => {
-                if self.validate_rollback_distance(p).is_ok() {
-                    passed_constraints.push("rollback_distance_valid".to_string());
-                } else {
-                    failed_constraints.push("rollback_distance_valid".to_string());
-                }
-
-                if self.validate_rollback_not_privilege_escalation(p).is_ok() {
-                    passed_constraints.push("rollback_no_privilege_escalation".to_string());
-                } else {
-                    failed_constraints.push("rollback_no_privilege_escalation".to_string());
-                }
-
-                if self.validate_rollback_byzantine_tolerance(p).is_ok() {
-                    passed_constraints.push("rollback_byzantine_tolerance".to_string());
-                } else {
-                    failed_constraints.push("rollback_byzantine_tolerance".to_string());
-                }
-            }
-
-            AIProposal::ShardRebalance(p) => {
-                if self.validate_rebalance_validator_count(p).is_ok() {
-                    passed_constraints.push("rebalance_validator_count_valid".to_string());
-                } else {
-                    failed_constraints.push("rebalance_validator_count_valid".to_string());
-                }
-
-                if self.validate_rebalance_not_removal(p).is_ok() {
-                    passed_constraints.push("rebalance_is_rebalance".to_string());
-                } else {
-                    failed_constraints.push("rebalance_is_rebalance".to_string());
-                }
-            }
-
-            AIProposal::ValidatorSecurity(p) => {
-                if self.validate_security_not_mass_removal(p).is_ok() {
-                    passed_constraints.push("security_not_mass_removal".to_string());
-                } else {
-                    failed_constraints.push("security_not_mass_removal".to_string());
-                }
-
-                if self.validate_security_privilege_level(p).is_ok() {
-                    passed_constraints.push("security_privilege_level".to_string());
-                } else {
-                    failed_constraints.push("security_privilege_level".to_string());
-                }
-            }
-
-            AIProposal::TokenomicsAdjustment(p) => {
-                if self.validate_tokenomics_fee_change(p).is_ok() {
-                    passed_constraints.push("tokenomics_fee_change_bounded".to_string());
-                } else {
-                    failed_constraints.push("tokenomics_fee_change_bounded".to_string());
-                }
-
-                if self.validate_tokenomics_reward_change(p).is_ok() {
-                    passed_constraints.push("tokenomics_reward_change_bounded".to_string());
-                } else {
-                    failed_constraints.push("tokenomics_reward_change_bounded".to_string());
-                }
-            }
-
-            AIProposal::GovernancePriority(_p) => {
-                // Governance proposals have minimal constraints
-                passed_constraints.push("governance_proposal_valid".to_string());
+        for (name, result) in self.evaluate_constraints(proposal) {
+            if result.is_ok() {
+                passed_constraints.push(name.to_string());
+            } else {
+                failed_constraints.push(name.to_string());
             }
         }
 
@@ -333,6 +283,83 @@ impl ConstraintValidator {
         Ok(passed_constraints)
     }
 
+    /// Evaluate every applicable constraint and report each outcome,
+    /// keeping the passing constraints alongside the failures
+    pub fn validate_proposal_report(&mut self, proposal: &AIProposal) -> ValidationReport {
+        let checks: Vec<ConstraintCheck> = self
+            .evaluate_constraints(proposal)
+            .into_iter()
+            .map(|(name, result)| ConstraintCheck {
+                name: name.to_string(),
+                passed: result.is_ok(),
+                reason: match result {
+                    Ok(()) => "OK".to_string(),
+                    Err(e) => e.to_string(),
+                },
+            })
+            .collect();
+        let accepted = checks.iter().all(|c| c.passed);
+
+        ValidationReport { checks, accepted }
+    }
+
+    /// Count an executed removal against the current epoch's removal budget
+    /// 
+    /// Validation never consumes the budget; call this once the proposal has
+    /// passed consensus and actually been executed.
+    pub fn record_executed(&mut self, proposal: &AIProposal) {
+        if let AIProposal::ValidatorSecurity(p) = proposal {
+            if p.action == "remove" {
+                let epoch = self.context.current_epoch;
+                self.removed_per_epoch.retain(|e, _| *e >= epoch);
+                self.removed_per_epoch
+                    .entry(epoch)
+                    .or_default()
+                    .extend(p.targets().into_iter().map(str::to_string));
+            }
+        }
+    }
+
+    /// Run all constraints applicable to the proposal type, in a fixed order
+    fn evaluate_constraints(
+        &mut self,
+        proposal: &AIProposal,
+    ) -> Vec<(&'static str, ConstraintResult<()>)> {
+        match proposal {
+            AIProposal::ConsensusModeSwitch(p) => vec![
+                ("mode_switch_not_duplicate", self.validate_mode_switch_not_duplicate(p)),
+                ("mode_switch_cooldown", self.validate_mode_switch_cooldown(p)),
+                ("mode_switch_min_validators", self.validate_mode_switch_min_validators(p)),
+                ("mode_switch_min_participation", self.validate_mode_switch_min_participation(p)),
+                ("mode_switch_risk_acceptable", self.validate_mode_switch_risk(p)),
+            ],
+
+            AIProposal::ShardRollback(p) => vec![
+                ("rollback_distance_valid", self.validate_rollback_distance(p)),
+                ("rollback_no_privilege_escalation", self.validate_rollback_not_privilege_escalation(p)),
+                ("rollback_byzantine_tolerance", self.validate_rollback_byzantine_tolerance(p)),
+            ],
+
+            AIProposal::ShardRebalance(p) => vec![
+                ("rebalance_validator_count_valid", self.validate_rebalance_validator_count(p)),
+                ("rebalance_is_rebalance", self.validate_rebalance_not_removal(p)),
+            ],
+
+            AIProposal::ValidatorSecurity(p) => vec![
+                ("security_not_mass_removal", self.validate_security_not_mass_removal(p)),
+                ("security_privilege_level", self.validate_security_privilege_level(p)),
+            ],
+
+            AIProposal::TokenomicsAdjustment(p) => vec![
+                ("tokenomics_fee_change_bounded", self.validate_tokenomics_fee_change(p)),
+                ("tokenomics_reward_change_bounded", self.validate_tokenomics_reward_change(p)),
+            ],
+
+            // Governance proposals have minimal constraints
+            AIProposal::GovernancePriority(_p) => vec![("governance_proposal_valid", Ok(()))],
+        }
+    }
+
     // ==================== CONSENSUS MODE CONSTRAINTS ====================
 
     fn validate_mode_switch_not_duplicate(
@@ -412,14 +439,29 @@ impl ConstraintValidator {
     // ==================== ROLLBACK CONSTRAINTS ====================
 
     fn validate_rollback_distance(&mut self, proposal: &ShardRollbackProposal) -> ConstraintResult<()> {
-        // Distance check would require block height context
-        // For now, just validate the proposal is reasonable
         if proposal.reason.is_empty() {
             return Err(ConstraintError::ProtocolInvariantViolation(
                 "Rollback must have reason".to_string(),
             ));
         }
 
+        // Cannot roll back to a height the chain has not reached
+        let head = self.context.current_block_height;
+        if proposal.target_checkpoint > head {
+            return Err(ConstraintError::ProtocolInvariantViolation(format!(
+                "Rollback target {} is above current height {}",
+                proposal.target_checkpoint, head
+            )));
+        }
+
+        let distance = head - proposal.target_checkpoint;
+        if distance > self.invariants.max_rollback_distance {
+            return Err(ConstraintError::ProtocolInvariantViolation(format!(
+                "Rollback distance {} exceeds max {}",
+                distance, self.invariants.max_rollback_distance
+            )));
+        }
+
         self.log_constraint("rollback_distance_valid", true, "OK");
         Ok(())
     }
@@ -494,10 +536,35 @@ impl ConstraintValidator {
         &mut self,
         proposal: &ValidatorSecurityProposal,
     ) -> ConstraintResult<()> {
-        // Cannot trigger security action that would remove majority of validators
-        if proposal.action == "remove" && proposal.risk_level == "critical" {
-            // Even critical actions cannot remove more than allowed
-            // This would need context about how many validators would be affected
+        // Even critical removals cannot decimate the validator set, whether
+        // in one proposal or spread over several in the same epoch
+        if proposal.action == "remove" {
+            let already = self.removed_per_epoch.get(&self.context.current_epoch);
+            let removed = proposal
+                .targets()
+                .into_iter()
+                .filter(|id| !already.is_some_and(|set| set.contains(*id)))
+                .count() as u32
+                + already.map_or(0, |set| set.len() as u32);
+            let active = self.context.active_validators;
+            let remaining = active.saturating_sub(removed);
+
+            if remaining < self.invariants.min_validators {
+                return Err(ConstraintError::ByzantineTolerance(format!(
+                    "Removing {} validators this epoch leaves {} < {} required",
+                    removed, remaining, self.invariants.min_validators
+                )));
+            }
+
+            let max_removed = active as f32 * self.invariants.max_removal_fraction_per_epoch;
+            if removed as f32 > max_removed {
+                return Err(ConstraintError::SafetyMarginViolation(format!(
+                    "Removing {} of {} validators this epoch exceeds {:.0}% per epoch",
+                    removed,
+                    active,
+                    self.invariants.max_removal_fraction_per_epoch * 100.0
+                )));
+            }
         }
 
         self.log_constraint("security_not_mass_removal", true, "OK");
@@ -596,13 +663,46 @@ impl ConstraintValidator {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::ai_proposal_types::{ConsensusModeProposal, EvidenceType};
+    use crate::ai_proposal_types::{ConsensusModeProposal, EvidenceType, ValidatorSecurityProposal};
+
+    fn validator_at_height(current_block_height: u64) -> ConstraintValidator {
+        let context = ConstraintContext {
+            current_epoch: 100,
+            current_block_height,
+            last_mode_switch_epoch: 80,
+            current_consensus_mode: "PoS".to_string(),
+            total_validators: 100,
+            active_validators: 85,
+            participation_rate: 0.85,
+            total_stake: 1_000_000,
+            slashing_this_epoch: 0,
+            current_base_fee: 1000,
+            current_validator_reward: 100,
+            avg_finality_latency: 5,
+            recent_proposals: vec![],
+            governance_can_veto: true,
+        };
+        ConstraintValidator::new(ProtocolInvariants::mainnet(), context)
+    }
+
+    fn rollback_to(target_checkpoint: u64) -> ShardRollbackProposal {
+        ShardRollbackProposal {
+            shard_id: 0,
+            target_checkpoint,
+            reason: "State divergence".to_string(),
+            confidence: 0.9,
+            evidence: vec![],
+            slash_validators: false,
+            validators_to_slash: vec![],
+        }
+    }
 
     #[test]
     fn test_constraint_validator_mode_switch() {
         let invariants = ProtocolInvariants::mainnet();
         let context = ConstraintContext {
             current_epoch: 100,
+            current_block_height: 50_000,
             last_mode_switch_epoch: 80,
             current_consensus_mode: "PoS".to_string(),
             total_validators: 100,
@@ -644,6 +744,7 @@ mod tests {
         let invariants = ProtocolInvariants::mainnet();
         let context = ConstraintContext {
             current_epoch: 100,
+            current_block_height: 50_000,
             last_mode_switch_epoch: 95,
             current_consensus_mode: "PoS".to_string(),
             total_validators: 100,
@@ -682,4 +783,134 @@ mod tests {
         assert!(inv.min_validators >= 20);
         assert!(inv.min_participation_rate >= 0.67);
     }
+
+    #[test]
+    fn test_rollback_within_distance_accepted() {
+        let mut validator = validator_at_height(50_000);
+        // Exactly max_rollback_distance (10_000) back is allowed
+        assert!(validator.validate_rollback_distance(&rollback_to(40_000)).is_ok());
+        assert!(validator.validate_proposal(&AIProposal::ShardRollback(rollback_to(49_990))).is_ok());
+    }
+
+    #[test]
+    fn test_rollback_over_distance_rejected() {
+        let mut validator = validator_at_height(50_000);
+        assert!(matches!(
+            validator.validate_rollback_distance(&rollback_to(39_999)),
+            Err(ConstraintError::ProtocolInvariantViolation(_))
+        ));
+        assert!(validator.validate_proposal(&AIProposal::ShardRollback(rollback_to(0))).is_err());
+    }
+
+    #[test]
+    fn test_rollback_to_future_height_rejected() {
+        let mut validator = validator_at_height(50_000);
+        assert!(matches!(
+            validator.validate_rollback_distance(&rollback_to(50_001)),
+            Err(ConstraintError::ProtocolInvariantViolation(_))
+        ));
+    }
+
+    fn removal_of(count: usize) -> ValidatorSecurityProposal {
+        ValidatorSecurityProposal {
+            validator_id: "val-0".to_string(),
+            action: "remove".to_string(),
+            reason: "Repeated equivocation".to_string(),
+            confidence: 0.95,
+            evidence: vec![],
+            risk_level: "critical".to_string(),
+            affected_validators: (1..count).map(|i| format!("val-{}", i)).collect(),
+        }
+    }
+
+    #[test]
+    fn test_small_validator_removal_allowed() {
+        let mut validator = validator_at_height(50_000);
+        // 85 active, 10% cap: 8 removals is fine
+        assert!(validator.validate_security_not_mass_removal(&removal_of(8)).is_ok());
+        assert!(validator.validate_proposal(&AIProposal::ValidatorSecurity(removal_of(1))).is_ok());
+    }
+
+    #[test]
+    fn test_removal_below_min_validators_rejected() {
+        let mut validator = validator_at_height(50_000);
+        let mut context = validator.context.clone();
+        context.active_validators = 22;
+        validator.update_context(context);
+
+        // 22 - 3 = 19 < 20 (mainnet minimum)
+        assert!(matches!(
+            validator.validate_security_not_mass_removal(&removal_of(3)),
+            Err(ConstraintError::ByzantineTolerance(_))
+        ));
+    }
+
+    #[test]
+    fn test_removal_over_epoch_fraction_rejected() {
+        let mut validator = validator_at_height(50_000);
+        assert!(matches!(
+            validator.validate_security_not_mass_removal(&removal_of(9)),
+            Err(ConstraintError::SafetyMarginViolation(_))
+        ));
+    }
+
+    fn removal_from(first: usize, count: usize) -> ValidatorSecurityProposal {
+        ValidatorSecurityProposal {
+            validator_id: format!("val-{}", first),
+            affected_validators: (first + 1..first + count).map(|i| format!("val-{}", i)).collect(),
+            ..removal_of(1)
+        }
+    }
+
+    #[test]
+    fn test_removals_accumulate_within_epoch() {
+        let mut validator = validator_at_height(50_000);
+        // 85 active, 10% cap: 5 + 4 = 9 removals in one epoch is too many
+        let first = AIProposal::ValidatorSecurity(removal_from(0, 5));
+        assert!(validator.validate_proposal(&first).is_ok());
+        validator.record_executed(&first);
+        assert!(matches!(
+            validator.validate_security_not_mass_removal(&removal_from(5, 4)),
+            Err(ConstraintError::SafetyMarginViolation(_))
+        ));
+        let report = validator.validate_proposal_report(&AIProposal::ValidatorSecurity(removal_from(5, 4)));
+        assert_eq!(report.failed(), vec!["security_not_mass_removal"]);
+
+        // Re-proposing already removed validators does not count twice
+        assert!(validator.validate_security_not_mass_removal(&removal_from(2, 6)).is_ok());
+
+        // The budget resets in the next epoch
+        let mut context = validator.context.clone();
+        context.current_epoch += 1;
+        validator.update_context(context);
+        assert!(validator.validate_proposal(&AIProposal::ValidatorSecurity(removal_from(5, 4))).is_ok());
+    }
+
+    #[test]
+    fn test_validation_alone_does_not_consume_removal_budget() {
+        let mut validator = validator_at_height(50_000);
+        let proposal = AIProposal::ValidatorSecurity(removal_from(0, 5));
+        assert!(validator.validate_proposal(&proposal).is_ok());
+        assert!(validator.validate_proposal_report(&proposal).accepted);
+
+        // Neither validation consumed budget, so a further 4 removals still fit
+        assert!(validator.validate_security_not_mass_removal(&removal_from(5, 4)).is_ok());
+    }
+
+    #[test]
+    fn test_validation_report_lists_passing_and_failing() {
+        let mut validator = validator_at_height(50_000);
+        // Fails the distance check only
+        let proposal = AIProposal::ShardRollback(rollback_to(1_000));
+
+        let report = validator.validate_proposal_report(&proposal);
+        assert!(!report.accepted);
+        assert_eq!(report.checks.len(), 3);
+        assert_eq!(report.failed(), vec!["rollback_distance_valid"]);
+        assert!(report.checks[0].reason.contains("exceeds max"));
+        assert!(report.checks[1..].iter().all(|c| c.passed && c.reason == "OK"));
+
+        let report = validator.validate_proposal_report(&AIProposal::ShardRollback(rollback_to(45_000)));
+        assert!(report.accepted);
+    }
 }
@@ -12,9 +12,16 @@
 
 use serde::{Serialize, Deserialize};
 use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, BTreeSet};
 use thiserror::Error;
+use crate::deterministic_inference::ModelMetadata;
 use crate::feature_extractor::ExtractedFeatures;
 
+/// Default feature weights (network_health, validator_downtime,
+/// consensus_latency, finality_lag, proposal_success_rate,
+/// stake_concentration, block_production_rate)
+pub const DEFAULT_FEATURE_WEIGHTS: [f64; 7] = [0.20, 0.25, 0.10, 0.15, 0.10, 0.10, 0.10];
+
 #[derive(Debug, Error)]
 pub enum AIError {
     #[error("Invalid features: {0}")]
@@ -124,6 +131,10 @@ pub struct AnomalyAssessment {
     
     /// Assessment hash (for signing)
     pub assessment_hash: Vec<u8>,
+    
+    /// Ensemble models did not reach a majority (classification is advisory only)
+    #[serde(default)]
+    pub inconclusive: bool,
 }
 
 impl AnomalyAssessment {
@@ -134,6 +145,7 @@ impl AnomalyAssessment {
         confidence: f64,
         input_feature_hash: &[u8],
         epoch: u64,
+        inconclusive: bool,
     ) -> Vec<u8> {
         let mut hasher = Sha256::new();
         hasher.update(anomaly_score.to_le_bytes());
@@ -141,6 +153,7 @@ impl AnomalyAssessment {
         hasher.update(confidence.to_le_bytes());
         hasher.update(input_feature_hash);
         hasher.update(epoch.to_le_bytes());
+        hasher.update([inconclusive as u8]);
         hasher.finalize().to_vec()
     }
 }
@@ -177,6 +190,9 @@ pub struct AIDecisionModule {
     
     /// Minimum confidence for output
     min_confidence: f64,
+    
+    /// Feature weights per model hash (models without an entry use the defaults)
+    model_weights: BTreeMap<String, Vec<f64>>,
 }
 
 impl AIDecisionModule {
@@ -189,7 +205,25 @@ impl AIDecisionModule {
             degraded_threshold: 50.0,
             anomalous_threshold: 75.0,
             min_confidence: 60.0,
+            model_weights: BTreeMap::new(),
+        }
+    }
+    
+    /// Register the feature weights an approved model scores with
+    pub fn register_model_weights(
+        &mut self,
+        model: &ModelMetadata,
+        weights: Vec<f64>,
+    ) -> Result<(), AIError> {
+        if weights.len() != DEFAULT_FEATURE_WEIGHTS.len() {
+            return Err(AIError::InvalidFeatures(format!(
+                "Expected {} weights, got {}",
+                DEFAULT_FEATURE_WEIGHTS.len(),
+                weights.len()
+            )));
         }
+        self.model_weights.insert(model.model_hash.clone(), weights);
+        Ok(())
     }
     
     /// Analyze features and produce assessment
@@ -218,6 +252,7 @@ impl AIDecisionModule {
             confidence,
             &features.feature_hash,
             features.epoch,
+            false,
         );
         
         let assessment = AnomalyAssessment {
@@ -227,6 +262,7 @@ impl AIDecisionModule {
             input_feature_hash: features.feature_hash.clone(),
             epoch: features.epoch,
             assessment_hash: assessment_hash.clone(),
+            inconclusive: false,
         };
         
         // Sign assessment
@@ -247,25 +283,19 @@ impl AIDecisionModule {
     
     /// Compute anomaly score from features (deterministic)
     fn compute_anomaly_score(&self, features: &[f64]) -> Result<f64, AIError> {
+        self.weighted_score(features, &DEFAULT_FEATURE_WEIGHTS)
+    }
+    
+    /// Weighted combination of features, clamped to 0-100 (deterministic)
+    fn weighted_score(&self, features: &[f64], weights: &[f64]) -> Result<f64, AIError> {
         if features.len() != 7 {
             return Err(AIError::InvalidFeatures(
                 format!("Expected 7 features, got {}", features.len()),
             ));
         }
         
-        // Weighted combination (deterministic)
-        let weights = vec![
-            0.20,  // network_health (high weight)
-            0.25,  // validator_downtime (high weight)
-            0.10,  // consensus_latency
-            0.15,  // finality_lag (medium weight)
-            0.10,  // proposal_success_rate
-            0.10,  // stake_concentration
-            0.10,  // block_production_rate
-        ];
-        
         let mut score = 0.0;
-        for (feature, weight) in features.iter().zip(&weights) {
+        for (feature, weight) in features.iter().zip(weights) {
             score += feature * weight;
         }
         
@@ -304,7 +334,7 @@ impl AIDecisionModule {
             self.anomalous_threshold,
         ];
         
-        let mut min_distance = 100.0;
+        let mut min_distance: f64 = 100.0;
         for threshold in thresholds {
             let distance = (anomaly_score - threshold).abs();
             min_distance = min_distance.min(distance);
@@ -319,6 +349,83 @@ impl AIDecisionModule {
         Ok(confidence)
     }
     
+    /// Combine several approved models by majority vote
+    /// 
+    /// Each non-deprecated model with registered weights scores the features
+    /// and votes for a class, once per model hash (in the given order). The most-voted
+    /// class wins (ties go to the more severe class) and confidence is the
+    /// agreeing fraction (0-100). Without a strict majority the assessment
+    /// is marked inconclusive. The anomaly score is the mean of the
+    /// agreeing models' scores.
+    pub fn assess_ensemble(
+        &self,
+        features: &ExtractedFeatures,
+        models: &[ModelMetadata],
+    ) -> AnomalyAssessment {
+        let mut votes: Vec<(AnomalyClass, f64)> = Vec::new();
+        let mut voted: BTreeSet<&str> = BTreeSet::new();
+        for model in models.iter().filter(|m| !m.is_deprecated) {
+            // SAFETY: Unregistered models have no approved weights, and a
+            // model listed twice still gets a single vote
+            let Some(weights) = self.model_weights.get(&model.model_hash) else {
+                continue;
+            };
+            if !voted.insert(model.model_hash.as_str()) {
+                continue;
+            }
+            if let Ok(score) = self.weighted_score(&features.features, weights) {
+                votes.push((self.classify(score), score));
+            }
+        }
+        
+        let mut tally = [0usize; 4];
+        for (class, _) in &votes {
+            tally[class.code() as usize] += 1;
+        }
+        // Highest count wins; scanning from Critical down keeps the more severe class on ties
+        let mut winner = AnomalyClass::Healthy;
+        let mut agreeing = 0;
+        for code in (0..tally.len()).rev() {
+            if tally[code] > agreeing {
+                agreeing = tally[code];
+                winner = AnomalyClass::from_code(code as u8).unwrap_or(AnomalyClass::Healthy);
+            }
+        }
+        
+        let (confidence, anomaly_score) = if votes.is_empty() {
+            (0.0, 0.0)
+        } else {
+            let agreeing_sum: f64 = votes.iter()
+                .filter(|(class, _)| *class == winner)
+                .map(|(_, score)| score)
+                .sum();
+            (
+                agreeing as f64 * 100.0 / votes.len() as f64,
+                agreeing_sum / agreeing as f64,
+            )
+        };
+        let inconclusive = agreeing * 2 <= votes.len();
+        
+        let assessment_hash = AnomalyAssessment::compute_hash(
+            anomaly_score,
+            winner,
+            confidence,
+            &features.feature_hash,
+            features.epoch,
+            inconclusive,
+        );
+        
+        AnomalyAssessment {
+            anomaly_score,
+            classification: winner,
+            confidence,
+            input_feature_hash: features.feature_hash.clone(),
+            epoch: features.epoch,
+            assessment_hash,
+            inconclusive,
+        }
+    }
+    
     /// Generate recovery recommendation (advisory only)
     pub fn recommend_recovery(
         &self,
@@ -483,6 +590,7 @@ mod tests {
             input_feature_hash: b"test".to_vec(),
             epoch: 1,
             assessment_hash: vec![],
+            inconclusive: false,
         };
         
         let rec = module.recommend_recovery(&assessment).unwrap();
@@ -518,4 +626,87 @@ mod tests {
         
         assert_eq!(module.get_assessments().len(), 2);
     }
+
+    fn model(name: &str) -> ModelMetadata {
+        ModelMetadata::new(
+            name.to_string(),
+            "1.0".to_string(),
+            format!("{}-hash", name),
+            format!("{}-file", name),
+            0,
+            7,
+            1,
+        )
+    }
+
+    /// Module with one model per weight set; `scale` multiplies the default weights
+    fn ensemble(scales: &[f64]) -> (AIDecisionModule, Vec<ModelMetadata>) {
+        let mut module = AIDecisionModule::new(b"ai_key".to_vec());
+        let models: Vec<ModelMetadata> = (0..scales.len()).map(|i| model(&format!("m{}", i))).collect();
+        for (m, scale) in models.iter().zip(scales) {
+            let weights = DEFAULT_FEATURE_WEIGHTS.iter().map(|w| w * scale).collect();
+            module.register_model_weights(m, weights).unwrap();
+        }
+        (module, models)
+    }
+
+    #[test]
+    fn test_ensemble_unanimous() {
+        // Default weights score these features at 25.75 (Degraded)
+        let (module, models) = ensemble(&[1.0, 1.0, 1.0]);
+        let assessment = module.assess_ensemble(&create_test_features(10.0), &models);
+
+        assert_eq!(assessment.classification, AnomalyClass::Degraded);
+        assert_eq!(assessment.anomaly_score, 25.75);
+        assert_eq!(assessment.confidence, 100.0);
+        assert!(!assessment.inconclusive);
+    }
+
+    #[test]
+    fn test_ensemble_split_is_inconclusive() {
+        // Default weights score 25.75 (Degraded); half weights score 12.875 (Healthy)
+        let (module, models) = ensemble(&[1.0, 0.5]);
+        let assessment = module.assess_ensemble(&create_test_features(10.0), &models);
+
+        assert_eq!(assessment.confidence, 50.0);
+        assert!(assessment.inconclusive);
+        // Ties resolve to the more severe class
+        assert_eq!(assessment.classification, AnomalyClass::Degraded);
+    }
+
+    #[test]
+    fn test_ensemble_outlier_overruled() {
+        let (module, mut models) = ensemble(&[1.0, 1.0, 0.5]);
+        let features = create_test_features(10.0);
+        let assessment = module.assess_ensemble(&features, &models);
+
+        assert_eq!(assessment.classification, AnomalyClass::Degraded);
+        assert!((assessment.confidence - 200.0 / 3.0).abs() < 1e-9);
+        assert!(!assessment.inconclusive);
+
+        // Deprecated models do not vote
+        models[1].deprecate();
+        assert!(module.assess_ensemble(&features, &models).inconclusive);
+    }
+
+    #[test]
+    fn test_ensemble_ignores_unregistered_and_duplicate_models() {
+        let (module, models) = ensemble(&[1.0, 0.5]);
+        let features = create_test_features(10.0);
+        let split = module.assess_ensemble(&features, &models);
+
+        // Repeating a model or adding one without weights changes nothing
+        let stuffed = vec![models[0].clone(), models[0].clone(), model("unregistered"), models[1].clone()];
+        let assessment = module.assess_ensemble(&features, &stuffed);
+        assert_eq!(assessment.confidence, 50.0);
+        assert!(assessment.inconclusive);
+        assert_eq!(assessment.assessment_hash, split.assessment_hash);
+    }
+
+    #[test]
+    fn test_inconclusive_flag_is_hashed() {
+        let conclusive = AnomalyAssessment::compute_hash(25.0, AnomalyClass::Degraded, 50.0, b"f", 1, false);
+        let inconclusive = AnomalyAssessment::compute_hash(25.0, AnomalyClass::Degraded, 50.0, b"f", 1, true);
+        assert_ne!(conclusive, inconclusive);
+    }
 }
@@ -42,6 +42,8 @@ impl fmt::Display for FeedbackError {
 
 impl std::error::Error for FeedbackError {}
 
+pub type FeedbackResult<T> = Result<T, FeedbackError>;
+
 // ==================== ACCURACY METRICS ====================
 
 /// Track accuracy of AI predictions
@@ -247,6 +249,9 @@ impl ConfidenceCalibration {
 
 // ==================== PERFORMANCE TRACKER ====================
 
+/// Outcomes retained per model for drift detection
+pub const MAX_OUTCOME_HISTORY: usize = 1_000;
+
 /// Track model performance metrics over time
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ModelPerformance {
@@ -283,6 +288,10 @@ pub struct ModelPerformance {
 
     /// Last recalibration epoch
     pub last_recalibration_epoch: u64,
+
+    /// Recent outcomes (true = correct), oldest first
+    #[serde(default)]
+    pub outcome_history: VecDeque<bool>,
 }
 
 impl ModelPerformance {
@@ -301,6 +310,7 @@ impl ModelPerformance {
             drift_detected: false,
             drift_reason: None,
             last_recalibration_epoch: 0,
+            outcome_history: VecDeque::new(),
         }
     }
 
@@ -313,6 +323,10 @@ impl ModelPerformance {
 
         // Record prediction
         self.accuracy.record_prediction(true, correct); // Simplified
+        self.outcome_history.push_back(correct);
+        while self.outcome_history.len() > MAX_OUTCOME_HISTORY {
+            self.outcome_history.pop_front();
+        }
         self.calibration.record_calibration(confidence, correct)?;
 
         // Update performance score
@@ -349,6 +363,26 @@ impl ModelPerformance {
         }
     }
 
+    /// Compare accuracy over the last `window` outcomes with the mean over
+    /// the whole retained history.
+    ///
+    /// Returns `(window_accuracy, baseline_accuracy)`, or `None` until the
+    /// history is longer than the window.
+    pub fn rolling_accuracy(&self, window: usize) -> Option<(f32, f32)> {
+        let total = self.outcome_history.len();
+        if window == 0 || total <= window {
+            return None;
+        }
+
+        let total_correct = self.outcome_history.iter().filter(|&&c| c).count();
+        let window_correct = self.outcome_history.iter().skip(total - window).filter(|&&c| c).count();
+
+        Some((
+            window_correct as f32 / window as f32,
+            total_correct as f32 / total as f32,
+        ))
+    }
+
     /// Record epoch inference count
     pub fn record_epoch_inferences(&mut self, count: u64) {
         self.inferences_per_epoch.push_back(count);
@@ -370,6 +404,25 @@ impl ModelPerformance {
 
 // ==================== FEEDBACK MANAGER ====================
 
+/// Raised when a model's recent accuracy falls well below its own baseline
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DriftAlert {
+    pub model_id: String,
+    pub model_version: String,
+
+    /// Outcomes in the rolling window
+    pub window: usize,
+
+    /// Accuracy over the rolling window (0.0-1.0)
+    pub window_accuracy: f32,
+
+    /// Accuracy over the retained history (0.0-1.0)
+    pub baseline_accuracy: f32,
+
+    /// `baseline_accuracy - window_accuracy`
+    pub accuracy_drop: f32,
+}
+
 /// Manages feedback for all models
 pub struct FeedbackManager {
     /// Performance per model
@@ -380,6 +433,9 @@ pub struct FeedbackManager {
 
     /// Drift detection threshold (default 0.7 = 70% accuracy)
     pub drift_threshold: f32,
+
+    /// Accuracy drop vs. baseline that counts as drift (default 0.1)
+    pub drift_margin: f32,
 }
 
 impl FeedbackManager {
@@ -389,6 +445,7 @@ impl FeedbackManager {
             models: BTreeMap::new(),
             current_epoch,
             drift_threshold: 0.7,
+            drift_margin: 0.1,
         }
     }
 
@@ -438,6 +495,28 @@ impl FeedbackManager {
             .collect()
     }
 
+    /// Find the first model (in key order) whose rolling-window accuracy has
+    /// dropped more than `drift_margin` below its long-run mean.
+    ///
+    /// Deterministic: depends only on the recorded outcome history.
+    pub fn detect_drift(&self, window: usize) -> Option<DriftAlert> {
+        self.models.values().find_map(|perf| {
+            let (window_accuracy, baseline_accuracy) = perf.rolling_accuracy(window)?;
+            let accuracy_drop = baseline_accuracy - window_accuracy;
+            if accuracy_drop <= self.drift_margin {
+                return None;
+            }
+            Some(DriftAlert {
+                model_id: perf.model_id.clone(),
+                model_version: perf.model_version.clone(),
+                window,
+                window_accuracy,
+                baseline_accuracy,
+                accuracy_drop,
+            })
+        })
+    }
+
     /// Calculate aggregate accuracy across all models
     pub fn aggregate_accuracy(&self) -> f32 {
         if self.models.is_empty() {
@@ -554,4 +633,47 @@ mod tests {
         let health = manager.get_health_metrics();
         assert_eq!(health.total_models, 1);
     }
+
+    /// Record `outcomes` in order, returning the index of the first outcome
+    /// after which drift is detected
+    fn first_drift(outcomes: &[bool], window: usize) -> Option<usize> {
+        let mut manager = FeedbackManager::new(0);
+        manager.drift_margin = 0.2;
+        manager.register_model("test".to_string(), "1.0".to_string());
+
+        outcomes.iter().enumerate().find_map(|(i, &correct)| {
+            manager.record_outcome("test", "1.0", 10.0, correct, 0.9).unwrap();
+            manager.detect_drift(window).map(|_| i)
+        })
+    }
+
+    #[test]
+    fn test_stable_accuracy_no_drift() {
+        // 80% accuracy throughout: every 10-outcome window matches the mean
+        let outcomes: Vec<bool> = (0..200).map(|i| i % 5 != 4).collect();
+        assert_eq!(first_drift(&outcomes, 10), None);
+    }
+
+    #[test]
+    fn test_declining_accuracy_raises_drift() {
+        // 20 correct, then only failures. After k failures the window is
+        // (10 - k)/10 and the baseline 20/(20 + k); the drop first exceeds
+        // 0.2 at k = 4 (0.833 - 0.6), i.e. outcome index 23.
+        let outcomes: Vec<bool> = (0..40).map(|i| i < 20).collect();
+        assert_eq!(first_drift(&outcomes, 10), Some(23));
+
+        let mut manager = FeedbackManager::new(0);
+        manager.drift_margin = 0.2;
+        manager.register_model("test".to_string(), "1.0".to_string());
+        for &correct in &outcomes[..24] {
+            manager.record_outcome("test", "1.0", 10.0, correct, 0.9).unwrap();
+        }
+        let alert = manager.detect_drift(10).unwrap();
+        assert_eq!(alert.model_id, "test");
+        assert_eq!(alert.window_accuracy, 0.6);
+        assert_eq!(alert.baseline_accuracy, 20.0 / 24.0);
+
+        // Too short a history for the window never alerts
+        assert!(manager.detect_drift(24).is_none());
+    }
 }
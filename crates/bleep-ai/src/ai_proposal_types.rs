@@ -17,7 +17,7 @@
 /// 5. Governance & Proposal Prioritization
 
 use serde::{Deserialize, Serialize};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use sha3::Sha3_256;
 use bincode;
 use sha3::Digest;
@@ -417,9 +417,20 @@ pub struct ValidatorSecurityProposal {
 
     /// Risk assessment
     pub risk_level: String, // "low", "medium", "high", "critical"
+
+    /// Other validators the action applies to (besides `validator_id`)
+    #[serde(default)]
+    pub affected_validators: Vec<String>,
 }
 
 impl ValidatorSecurityProposal {
+    /// Distinct validators targeted by this proposal
+    pub fn targets(&self) -> BTreeSet<&str> {
+        std::iter::once(self.validator_id.as_str())
+            .chain(self.affected_validators.iter().map(String::as_str))
+            .collect()
+    }
+
     /// Validate proposal
     pub fn validate(&self) -> AIProposalResult<()> {
         if self.validator_id.is_empty() {
@@ -275,6 +275,133 @@ impl OutputRoundingConfig {
     }
 }
 
+// ==================== FIXED-POINT LINEAR MODEL ====================
+
+/// Largest supported fixed-point scale (10^18 still fits in an i64)
+pub const MAX_FIXED_POINT_SCALE: u32 = 18;
+
+/// Linear/threshold model evaluated entirely in integer arithmetic.
+///
+/// Weights, biases, features and outputs are fixed-point values whose real
+/// value is `raw / 10^scale`, so results never depend on platform floats.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FixedPointLinearModel {
+    pub model_id: String,
+    pub version: String,
+
+    /// One weight row per output, each with one weight per feature
+    pub weights: Vec<Vec<i64>>,
+
+    /// One bias per output
+    pub bias: Vec<i64>,
+
+    /// If set, an output becomes 1.0 when it reaches the threshold, else 0
+    pub threshold: Option<i64>,
+
+    /// Decimal places the weights, bias and threshold were quantised at
+    pub scale: u32,
+}
+
+impl FixedPointLinearModel {
+    /// Check that weights and biases describe a well-formed model
+    pub fn validate(&self) -> DeterministicInferenceResult<()> {
+        if self.scale > MAX_FIXED_POINT_SCALE {
+            return Err(DeterministicInferenceError::InvalidInput(format!(
+                "Fixed-point scale {} exceeds maximum {}",
+                self.scale, MAX_FIXED_POINT_SCALE
+            )));
+        }
+        let input_size = self.weights.first().map(|row| row.len()).unwrap_or(0);
+        if input_size == 0 {
+            return Err(DeterministicInferenceError::InvalidInput(
+                "Fixed-point model has no weights".to_string(),
+            ));
+        }
+        if self.weights.iter().any(|row| row.len() != input_size) {
+            return Err(DeterministicInferenceError::InvalidInput(
+                "Fixed-point weight rows differ in length".to_string(),
+            ));
+        }
+        if self.bias.len() != self.weights.len() {
+            return Err(DeterministicInferenceError::InvalidInput(format!(
+                "Expected {} biases, got {}",
+                self.weights.len(),
+                self.bias.len()
+            )));
+        }
+        Ok(())
+    }
+
+    /// SHA3-256 over the model parameters
+    pub fn model_hash(&self) -> String {
+        let mut hasher = Sha3_256::new();
+        hasher.update(self.model_id.as_bytes());
+        hasher.update(self.version.as_bytes());
+        hasher.update(self.scale.to_le_bytes());
+        for row in &self.weights {
+            for w in row {
+                hasher.update(w.to_le_bytes());
+            }
+        }
+        for b in &self.bias {
+            hasher.update(b.to_le_bytes());
+        }
+        if let Some(threshold) = self.threshold {
+            hasher.update(threshold.to_le_bytes());
+        }
+        format!("{:x}", hasher.finalize())
+    }
+
+    /// Evaluate the model on features quantised at `scale`, which must
+    /// match the model's own scale.
+    ///
+    /// Products are accumulated in i128 and rescaled once, rounding half away
+    /// from zero; any overflow is an error rather than a wrapped value. A
+    /// malformed model (see `validate`) is rejected rather than evaluated.
+    pub fn evaluate(&self, features: &[i64], scale: u32) -> DeterministicInferenceResult<Vec<i64>> {
+        self.validate()?;
+        if scale != self.scale {
+            return Err(DeterministicInferenceError::InvalidInput(format!(
+                "Feature scale {} does not match model scale {}",
+                scale, self.scale
+            )));
+        }
+        if features.len() != self.weights[0].len() {
+            return Err(DeterministicInferenceError::InvalidInput(format!(
+                "Expected {} features, got {}",
+                self.weights[0].len(),
+                features.len()
+            )));
+        }
+
+        let one = 10i128.pow(scale);
+        let overflow = || DeterministicInferenceError::InferenceFailed("Fixed-point overflow".to_string());
+
+        let mut outputs = Vec::with_capacity(self.weights.len());
+        for (row, &bias) in self.weights.iter().zip(&self.bias) {
+            let mut acc: i128 = 0;
+            for (&w, &x) in row.iter().zip(features) {
+                acc = acc.checked_add(w as i128 * x as i128).ok_or_else(overflow)?;
+            }
+
+            // Rescale the product sum back to `scale`, rounding half away from zero
+            let mut value = acc / one;
+            if (acc % one).abs() * 2 >= one {
+                value += acc.signum();
+            }
+            let value = value.checked_add(bias as i128).ok_or_else(overflow)?;
+            let value = i64::try_from(value).map_err(|_| overflow())?;
+
+            outputs.push(match self.threshold {
+                Some(threshold) if value >= threshold => one as i64,
+                Some(_) => 0,
+                None => value,
+            });
+        }
+        Ok(outputs)
+    }
+}
+
 // ==================== INFERENCE RECORD ====================
 
 /// Complete record of a single inference execution
@@ -326,6 +453,10 @@ pub struct InferenceRecord {
 
     /// Error message (if failed)
     pub error: Option<String>,
+
+    /// Fixed-point scale (decimal places) if produced by `infer_fixed`
+    #[serde(default)]
+    pub fixed_point_scale: Option<u32>,
 }
 
 impl InferenceRecord {
@@ -347,6 +478,9 @@ impl InferenceRecord {
         hasher.update(self.epoch_id.to_le_bytes());
         hasher.update(&self.nonce);
         hasher.update(self.confidence.to_bits().to_le_bytes());
+        if let Some(scale) = self.fixed_point_scale {
+            hasher.update(scale.to_le_bytes());
+        }
         
         format!("{:x}", hasher.finalize())
     }
@@ -373,6 +507,9 @@ pub struct DeterministicInferenceEngine {
 
     /// Model binary storage (model_hash -> binary)
     model_binaries: BTreeMap<String, Vec<u8>>,
+
+    /// Integer model used by `infer_fixed`
+    fixed_point_model: Option<FixedPointLinearModel>,
 }
 
 impl DeterministicInferenceEngine {
@@ -385,6 +522,7 @@ impl DeterministicInferenceEngine {
             inference_history: Vec::new(),
             current_epoch,
             model_binaries: BTreeMap::new(),
+            fixed_point_model: None,
         }
     }
 
@@ -538,6 +676,7 @@ impl DeterministicInferenceEngine {
             processing_ms: 1, // Mock value
             success: true,
             error: None,
+            fixed_point_scale: None,
         };
 
         // Store in history
@@ -546,6 +685,80 @@ impl DeterministicInferenceEngine {
         Ok(record)
     }
 
+    /// Install the linear/threshold model used for fixed-point inference
+    pub fn set_fixed_point_model(
+        &mut self,
+        model: FixedPointLinearModel,
+    ) -> DeterministicInferenceResult<()> {
+        model.validate()?;
+        self.fixed_point_model = Some(model);
+        Ok(())
+    }
+
+    /// Fixed-point inference: bit-identical on every platform.
+    ///
+    /// `features` and the returned outputs are raw values at `scale`
+    /// decimal places (e.g. 1.5 at scale 2 is 150).
+    pub fn infer_fixed(&self, features: &[i64], scale: u32) -> DeterministicInferenceResult<Vec<i64>> {
+        let model = self.fixed_point_model.as_ref().ok_or_else(|| {
+            DeterministicInferenceError::ModelNotFound("fixed-point model".to_string())
+        })?;
+        model.evaluate(features, scale)
+    }
+
+    /// Run `infer_fixed` and commit the result to the audit trail.
+    ///
+    /// Input and output hashes cover the raw integers; the f32 fields are
+    /// for display only.
+    pub fn infer_fixed_recorded(
+        &mut self,
+        features: &[i64],
+        scale: u32,
+        confidence: f32,
+        nonce: Vec<u8>,
+    ) -> DeterministicInferenceResult<InferenceRecord> {
+        let outputs = self.infer_fixed(features, scale)?;
+        let model = self.fixed_point_model.as_ref().ok_or_else(|| {
+            DeterministicInferenceError::ModelNotFound("fixed-point model".to_string())
+        })?;
+
+        let hash_raw = |values: &[i64]| {
+            let mut hasher = Sha3_256::new();
+            hasher.update(scale.to_le_bytes());
+            for v in values {
+                hasher.update(v.to_le_bytes());
+            }
+            format!("{:x}", hasher.finalize())
+        };
+        let one = 10f64.powi(scale as i32);
+        let to_f32 = |values: &[i64]| values.iter().map(|&v| (v as f64 / one) as f32).collect::<Vec<_>>();
+
+        let record = InferenceRecord {
+            inference_id: uuid::Uuid::new_v4().to_string(),
+            model_id: model.model_id.clone(),
+            model_version: model.version.clone(),
+            model_hash: model.model_hash(),
+            input_hash: hash_raw(features),
+            inputs: to_f32(features),
+            normalized_inputs: to_f32(features),
+            outputs: to_f32(&outputs),
+            final_outputs: to_f32(&outputs),
+            output_hash: hash_raw(&outputs),
+            timestamp: Self::current_timestamp(),
+            epoch_id: self.current_epoch,
+            nonce,
+            confidence: confidence.clamp(0.0, 1.0),
+            processing_ms: 0,
+            success: true,
+            error: None,
+            fixed_point_scale: Some(scale),
+        };
+
+        self.inference_history.push(Arc::new(record.clone()));
+
+        Ok(record)
+    }
+
     /// Compute deterministic seed for inference
     fn compute_inference_seed(&self, inputs: &[f32], model_hash: &str) -> u64 {
         let mut hasher = Sha3_256::new();
@@ -718,6 +931,7 @@ mod tests {
             processing_ms: 10,
             success: true,
             error: None,
+            fixed_point_scale: None,
         };
         
         let hash1 = record.compute_hash();
@@ -726,4 +940,90 @@ mod tests {
         // Hashes must be deterministic
         assert_eq!(hash1, hash2);
     }
+
+    fn fixed_point_engine(threshold: Option<i64>) -> DeterministicInferenceEngine {
+        let mut engine = DeterministicInferenceEngine::new(3);
+        engine.set_fixed_point_model(FixedPointLinearModel {
+            model_id: "linear".to_string(),
+            version: "1.0".to_string(),
+            // Scale 2: [[1.5, -0.5], [0.25, 1.0]], bias [0.1, -0.2]
+            weights: vec![vec![150, -50], vec![25, 100]],
+            bias: vec![10, -20],
+            threshold,
+            scale: 2,
+        }).unwrap();
+        engine
+    }
+
+    #[test]
+    fn test_infer_fixed_is_reproducible() {
+        let features = [123_456, -98_765];
+        let a = fixed_point_engine(None).infer_fixed(&features, 2).unwrap();
+        let b = fixed_point_engine(None).infer_fixed(&features, 2).unwrap();
+        assert_eq!(a, b);
+
+        let mut engine = fixed_point_engine(None);
+        let r1 = engine.infer_fixed_recorded(&features, 2, 1.0, vec![1]).unwrap();
+        let r2 = engine.infer_fixed_recorded(&features, 2, 1.0, vec![1]).unwrap();
+        assert_eq!(r1.output_hash, r2.output_hash);
+        assert_eq!(r1.fixed_point_scale, Some(2));
+    }
+
+    #[test]
+    fn test_infer_fixed_matches_hand_computation() {
+        let engine = fixed_point_engine(None);
+        // x = [2.0, 3.0]: 1.5*2 - 0.5*3 + 0.1 = 1.6; 0.25*2 + 1.0*3 - 0.2 = 3.3
+        assert_eq!(engine.infer_fixed(&[200, 300], 2).unwrap(), vec![160, 330]);
+        // x = [3.33, 0]: 1.5*3.33 = 4.995 rounds to 5.00, + 0.1 = 5.1
+        assert_eq!(engine.infer_fixed(&[333, 0], 2).unwrap()[0], 510);
+        // -4.995 rounds away from zero to -5.00
+        assert_eq!(engine.infer_fixed(&[-333, 0], 2).unwrap()[0], -490);
+
+        // Threshold at 2.0 maps outputs to 0 / 1.0
+        let engine = fixed_point_engine(Some(200));
+        assert_eq!(engine.infer_fixed(&[200, 300], 2).unwrap(), vec![0, 100]);
+
+        assert!(engine.infer_fixed(&[200], 2).is_err());
+        assert!(engine.infer_fixed(&[200, 300], MAX_FIXED_POINT_SCALE + 1).is_err());
+    }
+
+    #[test]
+    fn test_fixed_point_scale_mismatch_rejected() {
+        let engine = fixed_point_engine(None);
+        // Same real inputs at scale 3 would be misread by a scale-2 model
+        assert!(engine.infer_fixed(&[2_000, 3_000], 3).is_err());
+        assert!(engine.infer_fixed(&[20, 30], 1).is_err());
+
+        let mut engine = DeterministicInferenceEngine::new(3);
+        let result = engine.set_fixed_point_model(FixedPointLinearModel {
+            model_id: "linear".to_string(),
+            version: "1.0".to_string(),
+            weights: vec![vec![1]],
+            bias: vec![0],
+            threshold: None,
+            scale: MAX_FIXED_POINT_SCALE + 1,
+        });
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_fixed_point_evaluate_rejects_malformed_model() {
+        let empty = FixedPointLinearModel {
+            model_id: "linear".to_string(),
+            version: "1.0".to_string(),
+            weights: vec![],
+            bias: vec![],
+            threshold: None,
+            scale: 2,
+        };
+        assert!(empty.evaluate(&[], 2).is_err());
+
+        let oversized = FixedPointLinearModel {
+            weights: vec![vec![1]],
+            bias: vec![0],
+            scale: 40,
+            ..empty
+        };
+        assert!(oversized.evaluate(&[1], 40).is_err());
+    }
 }
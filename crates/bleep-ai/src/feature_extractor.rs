@@ -105,6 +105,49 @@ pub struct FinalityMetrics {
     pub blocks_per_finality: u64,
 }
 
+/// Governance-approved range of one raw metric
+/// 
+/// Raw values are min-max scaled onto 0-100; values outside `[min, max]`
+/// are clamped so a spike cannot dominate the feature vector.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct FeatureBounds {
+    pub min: f64,
+    pub max: f64,
+}
+
+impl FeatureBounds {
+    pub fn new(min: f64, max: f64) -> Result<Self, ExtractionError> {
+        if !min.is_finite() || !max.is_finite() || min >= max {
+            return Err(ExtractionError::InvalidTelemetry(format!(
+                "Invalid feature bounds [{}, {}]",
+                min, max
+            )));
+        }
+        Ok(FeatureBounds { min, max })
+    }
+    
+    /// Scale `raw` onto 0-100, clamping out-of-range values (deterministic)
+    pub fn normalize(&self, raw: f64) -> f64 {
+        if raw.is_nan() {
+            return 0.0;
+        }
+        (((raw - self.min) / (self.max - self.min)) * 100.0).clamp(0.0, 100.0)
+    }
+}
+
+/// Default bounds, in feature order, in each metric's raw unit
+pub fn default_feature_bounds() -> Vec<FeatureBounds> {
+    vec![
+        FeatureBounds { min: 0.0, max: 100.0 },  // network_health (% healthy)
+        FeatureBounds { min: 0.0, max: 100.0 },  // validator_downtime (% stalled)
+        FeatureBounds { min: 0.0, max: 1000.0 }, // consensus_latency (ms)
+        FeatureBounds { min: 0.0, max: 10.0 },   // finality_lag (epochs)
+        FeatureBounds { min: 0.0, max: 100.0 },  // proposal_success_rate (%)
+        FeatureBounds { min: 0.0, max: 100.0 },  // stake_concentration (% of stake)
+        FeatureBounds { min: 0.0, max: 100.0 },  // block_production_rate (blocks/epoch)
+    ]
+}
+
 /// Extracted features for AI analysis
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExtractedFeatures {
@@ -128,6 +171,9 @@ pub struct ExtractedFeatures {
 pub struct FeatureExtractor {
     /// Feature names (deterministic order)
     feature_names: Vec<String>,
+    
+    /// Normalization bounds, one per feature name
+    bounds: Vec<FeatureBounds>,
 }
 
 impl FeatureExtractor {
@@ -143,7 +189,29 @@ impl FeatureExtractor {
             "block_production_rate".to_string(),    // blocks_per_epoch (normalized)
         ];
         
-        FeatureExtractor { feature_names }
+        FeatureExtractor { feature_names, bounds: default_feature_bounds() }
+    }
+    
+    /// Extractor using governance-approved bounds (one per feature, in order)
+    pub fn with_bounds(bounds: Vec<FeatureBounds>) -> Result<Self, ExtractionError> {
+        let mut extractor = Self::new();
+        if bounds.len() != extractor.feature_names.len() {
+            return Err(ExtractionError::InvalidTelemetry(format!(
+                "Expected {} feature bounds, got {}",
+                extractor.feature_names.len(),
+                bounds.len()
+            )));
+        }
+        for b in &bounds {
+            FeatureBounds::new(b.min, b.max)?;
+        }
+        extractor.bounds = bounds;
+        Ok(extractor)
+    }
+    
+    /// Normalization bounds in feature order
+    pub fn bounds(&self) -> &[FeatureBounds] {
+        &self.bounds
     }
     
     /// Extract features from telemetry (deterministic)
@@ -178,51 +246,50 @@ impl FeatureExtractor {
     }
     
     /// Extract individual features (deterministic computation)
+    /// 
+    /// Each raw metric is normalized against its bounds; validator metrics
+    /// only enter through order-independent aggregates (sum, max).
     fn extract_features_internal(
         &self,
         telemetry: &OnChainTelemetry,
     ) -> Result<Vec<f64>, ExtractionError> {
-        let mut features = Vec::new();
-        
-        // Feature 1: Network health (0-100)
-        // healthy_count / validator_count
-        let network_health = if telemetry.network.validator_count > 0 {
-            (telemetry.network.healthy_count as f64 / telemetry.network.validator_count as f64) * 100.0
-        } else {
-            0.0
+        let percent = |part: u64, whole: u64, empty: f64| {
+            if whole > 0 {
+                (part as f64 / whole as f64) * 100.0
+            } else {
+                empty
+            }
         };
-        features.push(network_health);
         
-        // Feature 2: Validator downtime (0-100)
-        // stalled_count / validator_count
-        let validator_downtime = if telemetry.network.validator_count > 0 {
-            (telemetry.network.stalled_count as f64 / telemetry.network.validator_count as f64) * 100.0
-        } else {
-            0.0
-        };
-        features.push(validator_downtime);
+        // Feature 1: Network health (% of validators healthy)
+        let network_health = percent(
+            telemetry.network.healthy_count,
+            telemetry.network.validator_count,
+            0.0,
+        );
+        
+        // Feature 2: Validator downtime (% of validators stalled)
+        let validator_downtime = percent(
+            telemetry.network.stalled_count,
+            telemetry.network.validator_count,
+            0.0,
+        );
         
-        // Feature 3: Consensus latency (0-100, normalized)
-        // normalized to 0-1000ms = 0-100
-        let consensus_latency = ((telemetry.consensus.avg_block_time_ms as f64 / 1000.0) * 100.0).min(100.0);
-        features.push(consensus_latency);
+        // Feature 3: Consensus latency (ms)
+        let consensus_latency = telemetry.consensus.avg_block_time_ms as f64;
         
-        // Feature 4: Finality lag (0-100, normalized)
-        // normalized to 0-10 epochs = 0-100
-        let finality_lag = ((telemetry.finality.finality_lag as f64 / 10.0) * 100.0).min(100.0);
-        features.push(finality_lag);
+        // Feature 4: Finality lag (epochs)
+        let finality_lag = telemetry.finality.finality_lag as f64;
         
-        // Feature 5: Proposal success rate (0-100)
+        // Feature 5: Proposal success rate (%)
         let total_proposals = telemetry.consensus.blocks_per_epoch;
-        let proposal_success_rate = if total_proposals > 0 {
-            ((total_proposals - telemetry.consensus.failed_proposals) as f64 / total_proposals as f64) * 100.0
-        } else {
-            100.0
-        };
-        features.push(proposal_success_rate);
+        let proposal_success_rate = percent(
+            total_proposals.saturating_sub(telemetry.consensus.failed_proposals),
+            total_proposals,
+            100.0,
+        );
         
-        // Feature 6: Stake concentration (0-100)
-        // max_stake / total_stake (normalized to percentage)
+        // Feature 6: Stake concentration (max_stake / total_stake, %)
         let total_stake: u128 = telemetry.validators.iter().map(|v| v.stake).sum();
         let max_stake = telemetry.validators.iter().map(|v| v.stake).max().unwrap_or(0);
         let stake_concentration = if total_stake > 0 {
@@ -230,14 +297,25 @@ impl FeatureExtractor {
         } else {
             0.0
         };
-        features.push(stake_concentration);
         
-        // Feature 7: Block production rate (0-100)
-        // normalized to 0-100 blocks/epoch
-        let block_production_rate = ((telemetry.consensus.blocks_per_epoch as f64 / 100.0) * 100.0).min(100.0);
-        features.push(block_production_rate);
+        // Feature 7: Block production rate (blocks/epoch)
+        let block_production_rate = telemetry.consensus.blocks_per_epoch as f64;
+        
+        let raw = [
+            network_health,
+            validator_downtime,
+            consensus_latency,
+            finality_lag,
+            proposal_success_rate,
+            stake_concentration,
+            block_production_rate,
+        ];
         
-        Ok(features)
+        Ok(raw
+            .iter()
+            .zip(&self.bounds)
+            .map(|(value, bounds)| bounds.normalize(*value))
+            .collect())
     }
     
     /// Hash telemetry (commitment to input)
@@ -258,8 +336,16 @@ impl FeatureExtractor {
         hasher.update(telemetry.consensus.avg_block_time_ms.to_le_bytes());
         hasher.update(telemetry.consensus.failed_proposals.to_le_bytes());
         
-        // Hash validator metrics (deterministic order)
-        for validator in &telemetry.validators {
+        // Hash validator metrics (sorted by ID, so arrival order does not matter)
+        let mut validators: Vec<&ValidatorMetrics> = telemetry.validators.iter().collect();
+        validators.sort_by(|a, b| {
+            a.id.cmp(&b.id)
+                .then(a.proposals.cmp(&b.proposals))
+                .then(a.missed.cmp(&b.missed))
+                .then(a.stake.cmp(&b.stake))
+                .then(a.is_active.cmp(&b.is_active))
+        });
+        for validator in validators {
             hasher.update(validator.id.as_bytes());
             hasher.update(validator.proposals.to_le_bytes());
             hasher.update(validator.missed.to_le_bytes());
@@ -412,4 +498,57 @@ mod tests {
         let result = extractor.extract(&telemetry);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_in_range_metrics_normalize_against_bounds() {
+        let extractor = FeatureExtractor::new();
+        let mut telemetry = create_test_telemetry(1, 4);
+        telemetry.consensus.avg_block_time_ms = 250;
+        telemetry.finality.finality_lag = 3;
+        
+        let features = extractor.extract(&telemetry).unwrap();
+        assert_eq!(features.features[2], 25.0); // 250ms of [0, 1000]
+        assert_eq!(features.features[3], 30.0); // 3 epochs of [0, 10]
+        
+        // Governance-approved bounds shift the scale
+        let mut bounds = default_feature_bounds();
+        bounds[2] = FeatureBounds::new(200.0, 1200.0).unwrap();
+        let extractor = FeatureExtractor::with_bounds(bounds).unwrap();
+        telemetry.consensus.avg_block_time_ms = 700;
+        assert_eq!(extractor.extract(&telemetry).unwrap().features[2], 50.0);
+    }
+
+    #[test]
+    fn test_out_of_bounds_spike_is_clamped() {
+        let extractor = FeatureExtractor::new();
+        let mut telemetry = create_test_telemetry(1, 4);
+        telemetry.consensus.avg_block_time_ms = u64::MAX;
+        telemetry.finality.finality_lag = 1_000_000;
+        telemetry.consensus.failed_proposals = 50; // more failures than blocks
+        telemetry.network.healthy_count = 40; // more healthy than validators
+        
+        let features = extractor.extract(&telemetry).unwrap();
+        assert_eq!(features.features[0], 100.0);
+        assert_eq!(features.features[2], 100.0);
+        assert_eq!(features.features[3], 100.0);
+        assert_eq!(features.features[4], 0.0);
+        assert!(features.features.iter().all(|f| (0.0..=100.0).contains(f)));
+        
+        assert!(FeatureBounds::new(10.0, 10.0).is_err());
+    }
+
+    #[test]
+    fn test_extraction_independent_of_validator_order() {
+        let extractor = FeatureExtractor::new();
+        let mut telemetry = create_test_telemetry(1, 4);
+        telemetry.validators[0].stake = 5000;
+        let mut reordered = telemetry.clone();
+        reordered.validators.reverse();
+        
+        let a = extractor.extract(&telemetry).unwrap();
+        let b = extractor.extract(&reordered).unwrap();
+        assert_eq!(a.features, b.features);
+        assert_eq!(a.feature_hash, b.feature_hash);
+        assert_eq!(a.input_hash, b.input_hash);
+    }
 }
@@ -25,6 +25,9 @@ pub enum GovernanceError {
     
     #[error("Governance vote failed: {0}")]
     GovernanceVoteFailed(String),
+    
+    #[error("Proposal vetoed by governance: {0}")]
+    ProposalVetoed(String),
 }
 
 /// AI assessment proposal (wraps AI output for governance)
@@ -65,6 +68,9 @@ pub enum VoteStatus {
     
     /// Executed (governance decided to act)
     Executed = 3,
+    
+    /// Vetoed (terminal: never executed, never re-proposed)
+    Vetoed = 4,
 }
 
 impl AIAssessmentProposal {
@@ -127,6 +133,11 @@ impl AIAssessmentProposal {
     pub fn mark_executed(&mut self) {
         self.vote_status = VoteStatus::Executed;
     }
+    
+    /// Mark as vetoed (governance blocked the recommendation for good)
+    pub fn mark_vetoed(&mut self) {
+        self.vote_status = VoteStatus::Vetoed;
+    }
 }
 
 /// AI feedback (governance decision on AI recommendation)
@@ -182,6 +193,13 @@ impl GovernanceIntegration {
             epoch,
         )?;
         
+        // A vetoed assessment can never come back under the same ID
+        if self.is_vetoed(&proposal.id) {
+            return Err(GovernanceError::ProposalVetoed(
+                "Assessment was vetoed and cannot be re-proposed".to_string(),
+            ));
+        }
+        
         let proposal_id = proposal.id.clone();
         self.proposals.push(proposal);
         
@@ -194,11 +212,7 @@ impl GovernanceIntegration {
         proposal_id: &[u8],
         decided_epoch: u64,
     ) -> Result<(), GovernanceError> {
-        let proposal = self.proposals.iter_mut()
-            .find(|p| p.id == proposal_id)
-            .ok_or_else(|| GovernanceError::GovernanceVoteFailed(
-                "Proposal not found".to_string(),
-            ))?;
+        let proposal = self.open_proposal_mut(proposal_id)?;
         
         proposal.mark_accepted();
         
@@ -219,11 +233,7 @@ impl GovernanceIntegration {
         rationale: String,
         decided_epoch: u64,
     ) -> Result<(), GovernanceError> {
-        let proposal = self.proposals.iter_mut()
-            .find(|p| p.id == proposal_id)
-            .ok_or_else(|| GovernanceError::GovernanceVoteFailed(
-                "Proposal not found".to_string(),
-            ))?;
+        let proposal = self.open_proposal_mut(proposal_id)?;
         
         proposal.mark_rejected();
         
@@ -243,11 +253,7 @@ impl GovernanceIntegration {
         proposal_id: &[u8],
         decided_epoch: u64,
     ) -> Result<(), GovernanceError> {
-        let proposal = self.proposals.iter_mut()
-            .find(|p| p.id == proposal_id)
-            .ok_or_else(|| GovernanceError::GovernanceVoteFailed(
-                "Proposal not found".to_string(),
-            ))?;
+        let proposal = self.open_proposal_mut(proposal_id)?;
         
         // Can only execute if accepted
         if proposal.vote_status != VoteStatus::Accepted {
@@ -269,6 +275,69 @@ impl GovernanceIntegration {
         Ok(())
     }
     
+    /// Governance vetoes AI recommendation
+    ///
+    /// Terminal: the proposal can no longer be accepted, executed, or
+    /// re-registered, and the reason is recorded as feedback. Executed
+    /// recommendations cannot be vetoed.
+    pub fn veto_ai_assessment(
+        &mut self,
+        proposal_id: &[u8],
+        reason: String,
+    ) -> Result<(), GovernanceError> {
+        let proposal = self.open_proposal_mut(proposal_id)?;
+        
+        if proposal.vote_status == VoteStatus::Executed {
+            return Err(GovernanceError::GovernanceVoteFailed(
+                "Cannot veto an executed recommendation".to_string(),
+            ));
+        }
+        
+        proposal.mark_vetoed();
+        
+        // Stamp with the latest epoch governance has acted on this proposal
+        let created_epoch = proposal.created_epoch;
+        let decided_epoch = self.decisions.iter()
+            .filter(|d| d.proposal_id == proposal_id)
+            .map(|d| d.decided_epoch)
+            .fold(created_epoch, u64::max);
+        
+        self.decisions.push(AIFeedback {
+            proposal_id: proposal_id.to_vec(),
+            decision: VoteStatus::Vetoed,
+            rationale: reason,
+            decided_epoch,
+        });
+        
+        Ok(())
+    }
+    
+    /// Whether governance has vetoed this proposal
+    pub fn is_vetoed(&self, proposal_id: &[u8]) -> bool {
+        self.proposals.iter()
+            .any(|p| p.id == proposal_id && p.vote_status == VoteStatus::Vetoed)
+    }
+    
+    /// Find a proposal governance may still decide on
+    fn open_proposal_mut(
+        &mut self,
+        proposal_id: &[u8],
+    ) -> Result<&mut AIAssessmentProposal, GovernanceError> {
+        let proposal = self.proposals.iter_mut()
+            .find(|p| p.id == proposal_id)
+            .ok_or_else(|| GovernanceError::GovernanceVoteFailed(
+                "Proposal not found".to_string(),
+            ))?;
+        
+        if proposal.vote_status == VoteStatus::Vetoed {
+            return Err(GovernanceError::ProposalVetoed(
+                "Vetoed proposals are final".to_string(),
+            ));
+        }
+        
+        Ok(proposal)
+    }
+    
     /// Check if AI passed all validations
     pub fn verify_proposal(&self, proposal_id: &[u8]) -> Result<bool, GovernanceError> {
         let proposal = self.proposals.iter()
@@ -325,18 +394,15 @@ impl GovernanceIntegration {
         rationale: String,
         epoch: u64,
     ) -> Result<(), GovernanceError> {
-        if let Some(id) = proposal_id {
+        if let Some(id) = &proposal_id {
             // Update existing proposal
-            let proposal = self.proposals.iter_mut()
-                .find(|p| p.id == id)
-                .ok_or_else(|| GovernanceError::GovernanceVoteFailed(
-                    "Proposal not found".to_string(),
-                ))?;
+            let proposal = self.open_proposal_mut(id)?;
             
             match decision {
                 VoteStatus::Accepted => proposal.mark_accepted(),
                 VoteStatus::Rejected => proposal.mark_rejected(),
                 VoteStatus::Executed => proposal.mark_executed(),
+                VoteStatus::Vetoed => proposal.mark_vetoed(),
                 _ => {},
             }
         }
@@ -371,6 +437,7 @@ mod tests {
             input_feature_hash: b"test_input".to_vec(),
             epoch: 1,
             assessment_hash: Sha256::digest(b"test_assessment").to_vec(),
+            inconclusive: false,
         };
         
         let signature = AISignature::sign(b"ai_key", &assessment.assessment_hash, 1);
@@ -567,4 +634,60 @@ mod tests {
         // Verify proposal
         assert!(gov.verify_proposal(&proposal_id).unwrap());
     }
+
+    #[test]
+    fn test_veto_blocks_execution() {
+        let mut gov = GovernanceIntegration::new();
+        let (assessment, signature) = create_test_assessment();
+        let recommendation = create_test_recommendation();
+        
+        let proposal_id = gov.register_assessment(
+            assessment.clone(),
+            signature.clone(),
+            recommendation.clone(),
+            1,
+        ).unwrap();
+        gov.accept_recommendation(&proposal_id, 2).unwrap();
+        
+        gov.veto_ai_assessment(&proposal_id, "Unsafe during upgrade".to_string()).unwrap();
+        
+        assert!(gov.is_vetoed(&proposal_id));
+        assert!(matches!(
+            gov.execute_recommendation(&proposal_id, 3),
+            Err(GovernanceError::ProposalVetoed(_))
+        ));
+        assert!(gov.accept_recommendation(&proposal_id, 3).is_err());
+        assert!(gov.get_executed().is_empty());
+        
+        // Same assessment, same epoch -> same ID: cannot be re-proposed
+        assert!(matches!(
+            gov.register_assessment(assessment, signature, recommendation, 1),
+            Err(GovernanceError::ProposalVetoed(_))
+        ));
+    }
+
+    #[test]
+    fn test_veto_reason_recorded_in_feedback() {
+        let mut gov = GovernanceIntegration::new();
+        let (assessment, signature) = create_test_assessment();
+        let recommendation = create_test_recommendation();
+        
+        let proposal_id = gov.register_assessment(
+            assessment,
+            signature,
+            recommendation,
+            1,
+        ).unwrap();
+        gov.veto_ai_assessment(&proposal_id, "Model under review".to_string()).unwrap();
+        
+        let feedback = gov.get_decisions().last().unwrap();
+        assert_eq!(feedback.proposal_id, proposal_id);
+        assert_eq!(feedback.decision, VoteStatus::Vetoed);
+        assert_eq!(feedback.rationale, "Model under review");
+        assert_eq!(feedback.decided_epoch, 1);
+        
+        // A second veto is rejected and records nothing
+        assert!(gov.veto_ai_assessment(&proposal_id, "again".to_string()).is_err());
+        assert_eq!(gov.get_decisions().len(), 1);
+    }
 }
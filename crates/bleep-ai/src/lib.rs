@@ -33,6 +33,7 @@ mod phase3_unit_tests;
 pub use deterministic_inference::{
     DeterministicInferenceEngine, InferenceRecord, ModelMetadata,
     DeterministicInferenceError, DeterministicInferenceResult,
+    FixedPointLinearModel, MAX_FIXED_POINT_SCALE,
 };
 
 pub use ai_proposal_types::{
@@ -49,7 +50,7 @@ pub use ai_attestation::{
 
 pub use ai_constraint_validator::{
     ConstraintValidator, ProtocolInvariants, ConstraintContext,
-    ConstraintError, ConstraintResult,
+    ConstraintError, ConstraintResult, ConstraintCheck, ValidationReport,
 };
 
 pub use ai_consensus_integration::{
@@ -59,13 +60,14 @@ pub use ai_consensus_integration::{
 
 pub use ai_feedback_loop::{
     FeedbackManager, ModelPerformance, AccuracyMetrics,
-    ConfidenceCalibration, SystemHealthMetrics,
+    ConfidenceCalibration, SystemHealthMetrics, DriftAlert,
 };
 
 // PHASE 4: AI ADVISORY SYSTEM
 pub use feature_extractor::{
     FeatureExtractor, ExtractedFeatures, OnChainTelemetry,
     NetworkMetrics, ConsensusMetrics, ValidatorMetrics, FinalityMetrics,
+    FeatureBounds, default_feature_bounds,
 };
 
 pub use ai_decision_module::{
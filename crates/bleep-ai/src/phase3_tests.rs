@@ -199,6 +199,7 @@ mod tests {
         let invariants = ProtocolInvariants::mainnet();
         let context = ConstraintContext {
             current_epoch: 100,
+            current_block_height: 50_000,
             last_mode_switch_epoch: 80,
             current_consensus_mode: "PoS".to_string(),
             total_validators: 100,
@@ -240,6 +241,7 @@ mod tests {
         let invariants = ProtocolInvariants::mainnet();
         let context = ConstraintContext {
             current_epoch: 100,
+            current_block_height: 50_000,
             last_mode_switch_epoch: 95,
             current_consensus_mode: "PoS".to_string(),
             total_validators: 100,
@@ -281,6 +283,7 @@ mod tests {
         let invariants = ProtocolInvariants::mainnet();
         let context = ConstraintContext {
             current_epoch: 100,
+            current_block_height: 50_000,
             last_mode_switch_epoch: 95, // Only 5 epochs ago, need 10
             current_consensus_mode: "PoS".to_string(),
             total_validators: 100,
@@ -440,6 +443,7 @@ mod tests {
         let invariants = ProtocolInvariants::mainnet();
         let context = ConstraintContext {
             current_epoch: 50,
+            current_block_height: 50_000,
             last_mode_switch_epoch: 10,
             current_consensus_mode: "PoS".to_string(),
             total_validators: 100,
@@ -251,6 +251,7 @@ mod phase3_unit_tests {
     fn test_constraint_context_creation() {
         let context = ConstraintContext {
             current_epoch: 100,
+            current_block_height: 50_000,
             last_mode_switch_epoch: 50,
             current_consensus_mode: "PoS".to_string(),
             total_validators: 100,
@@ -84,6 +84,7 @@ mod phase4_ai_integration_tests {
             input_feature_hash: b"test".to_vec(),
             epoch: 1,
             assessment_hash: Sha256::digest(b"test").to_vec(),
+            inconclusive: false,
         };
         
         // Create valid signature
@@ -182,6 +183,7 @@ mod phase4_ai_integration_tests {
             input_feature_hash: b"test".to_vec(),
             epoch: 1,
             assessment_hash: vec![],
+            inconclusive: false,
         };
         
         // Healthy → no action
@@ -347,6 +349,7 @@ mod phase4_ai_integration_tests {
             input_feature_hash: b"test".to_vec(),
             epoch: 5,
             assessment_hash: vec![],
+            inconclusive: false,
         };
         
         let hash1 = AnomalyAssessment::compute_hash(
@@ -355,6 +358,7 @@ mod phase4_ai_integration_tests {
             assessment1.confidence,
             &assessment1.input_feature_hash,
             assessment1.epoch,
+            assessment1.inconclusive,
         );
         
         let hash2 = AnomalyAssessment::compute_hash(
@@ -363,6 +367,7 @@ mod phase4_ai_integration_tests {
             assessment1.confidence,
             &assessment1.input_feature_hash,
             assessment1.epoch,
+            assessment1.inconclusive,
         );
         
         assert_eq!(hash1, hash2);
@@ -432,6 +437,7 @@ mod phase4_ai_integration_tests {
             input_feature_hash: b"input".to_vec(),
             epoch: 2,
             assessment_hash: Sha256::digest(b"correct").to_vec(),
+            inconclusive: false,
         };
         
         let signature = AISignature::sign(b"ai_key", &assessment.assessment_hash, 2);
@@ -27,17 +27,20 @@ use bleep_cli::{
 
 // Real crate imports
 use bleep_wallet_core::wallet::WalletManager;
-use bleep_ai::ai_assistant::{BLEEPAIAssistant, AIRequest};
-use bleep_governance::governance_core::{GovernanceEngine, Proposal, ProposalType, Vote};
+use bleep_governance::governance_core::{GovernanceEngine, Vote};
 use bleep_state::state_manager::StateManager;
 use bleep_zkp::Verifier as ZkVerifier;
-use bleep_core::transaction::ZKTransaction;
-use bleep_crypto::tx_signer::{sign_tx_payload, tx_payload, generate_tx_keypair};
+use bleep_core::transaction::{ZKTransaction, BLEEP_CHAIN_ID};
+use bleep_crypto::tx_signer::{sign_tx_payload, generate_tx_keypair};
 use bleep_crypto::bip39::{mnemonic_to_bleep_seed, validate_mnemonic};
 
 /// Default RPC endpoint (override via BLEEP_RPC env var).
 const DEFAULT_RPC: &str = "http://127.0.0.1:8545";
 
+/// Deposit attached to CLI proposals — the node's minimum for
+/// `POST /rpc/governance/propose`.
+const PROPOSAL_DEPOSIT: u64 = 10_000_000_000_000;
+
 #[tokio::main]
 async fn main() -> Result<()> {
     tracing_subscriber::fmt()
@@ -165,7 +168,25 @@ async fn run(cmd: Commands) -> Result<()> {
                     .unwrap_or_default()
                     .as_secs();
 
+                // Replay protection: sign at the sender's next nonce on this chain.
+                // Guessing a nonce offline would only produce a rejected tx.
+                let nonce = get_account_state(&rpc, &sender)
+                    .await
+                    .map(|(_, nonce, _)| nonce)
+                    .map_err(|e| anyhow!("Could not fetch nonce for {} from {}: {}", sender, rpc, e))?;
+
+                let mut tx = ZKTransaction {
+                    sender:    sender.clone(),
+                    receiver:  to.clone(),
+                    amount:    (amount * 100_000_000.0).round() as u64, // BLEEP → 8-decimal base units
+                    timestamp: ts,
+                    signature: vec![],
+                    nonce,
+                    chain_id:  BLEEP_CHAIN_ID,
+                };
+
                 // Sprint 5: unlock AES-GCM encrypted SK, sign with SPHINCS+
+                // Wire format: pk(TX_PK_LEN) || detached_sig over tx.chain_payload()
                 let sig = {
                     let manager_for_sign = WalletManager::load_or_create()
                         .map_err(|e| anyhow!("Wallet load failed: {}", e))?;
@@ -176,7 +197,7 @@ async fn run(cmd: Commands) -> Result<()> {
 
                     match wallet_opt {
                         Some(w) if w.can_sign() => {
-                            let payload = tx_payload(&sender, &to, amount, ts);
+                            let payload = tx.chain_payload();
                             // Decrypt SK (empty password = default; users who locked
                             // with a custom password set BLEEP_WALLET_PASSWORD env var)
                             let password = std::env::var("BLEEP_WALLET_PASSWORD")
@@ -184,6 +205,7 @@ async fn run(cmd: Commands) -> Result<()> {
                             match w.unlock(&password) {
                                 Ok(sk_plain) => {
                                     sign_tx_payload(&payload, &sk_plain)
+                                        .map(|s| [w.falcon_keys.clone(), s].concat())
                                         .unwrap_or_else(|e| {
                                             warn!("[CLI] SPHINCS+ sign failed: {} — using placeholder", e);
                                             vec![1, 2, 3, 4]
@@ -199,13 +221,7 @@ async fn run(cmd: Commands) -> Result<()> {
                     }
                 };
 
-                let tx = ZKTransaction {
-                    sender:    sender.clone(),
-                    receiver:  to.clone(),
-                    amount,
-                    timestamp: ts,
-                    signature: sig, // Sprint 4: real SPHINCS+ signature
-                };
+                tx.signature = sig; // Sprint 4: real SPHINCS+ signature
 
                 // POST to RPC
                 match post_transaction(&rpc, &tx).await {
@@ -243,15 +259,10 @@ async fn run(cmd: Commands) -> Result<()> {
         // ── AI ────────────────────────────────────────────────────────────
         Commands::Ai { task } => match task {
             AiCommand::Ask { prompt } => {
-                let mut ai = BLEEPAIAssistant::new("cli-node");
-                let req = AIRequest {
-                    user_id: "cli-user".to_string(),
-                    query:   prompt.clone(),
-                };
-                let resp = ai.process_request(req).await;
-                println!("🧠 AI Response:\n{}", resp.response);
-                if let Some(insights) = resp.insights {
-                    println!("💡 Insights: {}", insights);
+                // The assistant runs inside the node; ask its advisory endpoint
+                match reqwest::get(format!("{}/rpc/ai", rpc)).await {
+                    Ok(r) => println!("🧠 AI Response to \"{}\":\n{}", prompt, r.text().await?),
+                    Err(e) => println!("Node not reachable at {}: {}", rpc, e),
                 }
             }
             AiCommand::Status => {
@@ -265,30 +276,20 @@ async fn run(cmd: Commands) -> Result<()> {
             let mut engine = GovernanceEngine::new(1_000_000_000u128);
             match task {
                 GovernanceCommand::Propose { proposal } => {
-                    use bleep_governance::governance_core::{ProposalState, VotingWindow, GovernancePayload};
-                    use std::collections::HashMap as GovMap;
-                    let p = Proposal {
-                        id:                 uuid_now(),
-                        proposal_type:      ProposalType::ProtocolParameter,
-                        title:              proposal.chars().take(60).collect::<String>(),
-                        description:        proposal.clone(),
-                        state:              ProposalState::Draft,
-                        voting_window:      VotingWindow { start_epoch: 0, end_epoch: 10 },
-                        execution_epoch:    11,
-                        approval_threshold: 67,
-                        votes:              GovMap::new(),
-                        tally:              None,
-                        payload:            GovernancePayload::ProtocolParameterChange {
-                            rule_name: "cli_proposal".to_string(),
-                            new_value: 0u128, // value encoded in title/description
-                        },
-                        previous_state:     None,
-                        created_epoch:      0,
-                    };
-                    let id = engine.submit_proposal(p)
-                        .map_err(|e| anyhow!("Proposal failed: {}", e))?;
-                    println!("✅ Proposal {} submitted: \"{}\"", id, proposal);
-                    engine.persist().ok();
+                    // The node escrows the deposit from the proposer's on-chain
+                    // balance; nothing is debited locally.
+                    let proposer = WalletManager::load_or_create()
+                        .map_err(|e| anyhow!("Wallet needed to fund the deposit: {}", e))?
+                        .list_wallets()
+                        .first()
+                        .map(|w| w.address().to_string())
+                        .ok_or_else(|| anyhow!("No wallet found. Run `bleep-cli wallet create` first."))?;
+                    let title = proposal.chars().take(60).collect::<String>();
+                    let resp = post_proposal_tx(&rpc, &proposer, &title, &proposal, PROPOSAL_DEPOSIT)
+                        .await
+                        .map_err(|e| anyhow!("Proposal not submitted: {}", e))?;
+                    println!("✅ Proposal submitted: \"{}\"", proposal);
+                    println!("{}", serde_json::to_string_pretty(&resp).unwrap_or_default());
                 }
                 GovernanceCommand::Vote { proposal_id, yes } => {
                     let vote = Vote {
@@ -298,7 +299,7 @@ async fn run(cmd: Commands) -> Result<()> {
                         vote_epoch:   0,
                         signature:    vec![],
                     };
-                    engine.cast_vote(vote, 0)
+                    engine.cast_vote(&proposal_id.to_string(), vote, 0)
                         .map_err(|e| anyhow!("Vote failed: {}", e))?;
                     println!(
                         "✅ Voted {} on proposal {}",
@@ -313,6 +314,7 @@ async fn run(cmd: Commands) -> Result<()> {
                         ProposalState::Draft,
                         ProposalState::Pending,
                         ProposalState::Voting,
+                        ProposalState::Timelocked,
                     ];
                     let mut found = false;
                     for state in &all_states {
@@ -770,17 +772,6 @@ fn query_balance_local(state_dir: &str, address: &str) -> u128 {
     }
 }
 
-// ── Helpers ────────────────────────────────────────────────────────────────
-
-fn uuid_now() -> String {
-    use std::time::{SystemTime, UNIX_EPOCH};
-    let ns = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .map(|d| d.as_nanos())
-        .unwrap_or(0);
-    format!("prop-{:x}", ns)
-}
-
 // ── Validator / Staking RPC helpers (Sprint 6) ────────────────────────────────
 
 /// POST /rpc/validator/stake — broadcast a stake transaction.
@@ -810,6 +801,41 @@ async fn post_stake_tx(rpc: &str, amount: u64, label: &str) -> Result<String> {
     Ok(resp)
 }
 
+/// POST /rpc/governance/propose — submit a proposal with its deposit.
+///
+/// The node answers a rejected proposal with an `error` field rather than an
+/// HTTP error status, so that is surfaced as an `Err` here.
+async fn post_proposal_tx(
+    rpc: &str,
+    proposer: &str,
+    title: &str,
+    description: &str,
+    deposit: u64,
+) -> Result<serde_json::Value> {
+    #[derive(serde::Serialize)]
+    struct ProposeRequest<'a> {
+        proposer:    &'a str,
+        title:       &'a str,
+        description: &'a str,
+        deposit:     u64,
+    }
+    let url = format!("{}/rpc/governance/propose", rpc);
+    let client = reqwest::Client::new();
+    let body = ProposeRequest { proposer, title, description, deposit };
+    let resp = client
+        .post(&url)
+        .json(&body)
+        .send()
+        .await?
+        .error_for_status()?
+        .json::<serde_json::Value>()
+        .await?;
+    if let Some(err) = resp.get("error") {
+        return Err(anyhow!("node rejected proposal: {}", err));
+    }
+    Ok(resp)
+}
+
 /// POST /rpc/validator/unstake — broadcast an unstake / exit transaction.
 async fn post_unstake_tx(rpc: &str, validator_id: &str) -> Result<String> {
     #[derive(serde::Serialize)]
@@ -11,7 +11,9 @@
 
 use serde::{Serialize, Deserialize};
 use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet};
 use log::{info, warn};
+use bleep_economics::weighted_median_bounds;
 
 /// Bounded numeric score for AI advisory reports.
 /// 
@@ -191,21 +193,7 @@ impl AiReportAggregator {
             );
         }
 
-        // Verify all reports are signed
-        for report in reports {
-            report.verify_signature()?;
-        }
-
-        // Verify all reports are from the same epoch
-        let epoch_id = reports[0].epoch_id;
-        for report in &reports[1..] {
-            if report.epoch_id != epoch_id {
-                return Err(format!(
-                    "Report epoch mismatch: expected {}, got {}",
-                    epoch_id, report.epoch_id
-                ));
-            }
-        }
+        let epoch_id = Self::validate_reports(reports)?;
 
         // Aggregate using median
         let network_health = self.median_score(
@@ -253,23 +241,137 @@ impl AiReportAggregator {
         })
     }
 
-    /// Compute median of a list of scores.
+    /// Aggregate reports weighted by each AI node's stake or reputation,
+    /// discarding outliers before averaging.
     /// 
-    /// SAFETY: Median is robust to outliers.
-    fn median_score(&self, mut scores: Vec<u8>) -> u8 {
-        if scores.is_empty() {
-            return 50; // Default middle value
+    /// SAFETY: A report is rejected if any of its scores lies more than
+    /// `max_deviation` from the weighted median of that score. The median
+    /// cannot be dragged by a weighted minority, so one extreme advisory is
+    /// dropped rather than averaged in. Surviving scores are combined as a
+    /// weighted integer mean, which stays within `BoundedScore` limits and
+    /// is identical on every node. Nodes absent from `weights` or with zero
+    /// weight carry no influence; a node reporting twice is an error.
+    pub fn aggregate_weighted(
+        &self,
+        reports: &[AiAdvisoryReport],
+        weights: &BTreeMap<String, u128>,
+        max_deviation: u8,
+    ) -> Result<WeightedAdvisory, String> {
+        if reports.is_empty() {
+            return Err("No reports to aggregate".to_string());
+        }
+        let epoch_id = Self::validate_reports(reports)?;
+
+        let mut seen = BTreeSet::new();
+        for report in reports {
+            if !seen.insert(report.ai_node_id.as_str()) {
+                return Err(format!("Duplicate report from AI node {}", report.ai_node_id));
+            }
+        }
+
+        let weighted: Vec<(u128, [u8; 4])> = reports
+            .iter()
+            .filter_map(|r| {
+                let weight = weights.get(&r.ai_node_id).copied().unwrap_or(0);
+                (weight > 0).then(|| (weight, Self::score_vector(r)))
+            })
+            .collect();
+        if weighted.is_empty() {
+            return Err("No weighted reports to aggregate".to_string());
+        }
+
+        let mut medians = [0u8; 4];
+        for (dim, median) in medians.iter_mut().enumerate() {
+            let column: Vec<(u128, u8)> = weighted.iter().map(|(w, v)| (*w, v[dim])).collect();
+            *median = Self::midpoint_median(&column);
+        }
+
+        let (honest, outliers): (Vec<_>, Vec<_>) = weighted.iter().partition(|(_, v)| {
+            v.iter().zip(medians.iter()).all(|(s, m)| s.abs_diff(*m) <= max_deviation)
+        });
+
+        // Medians are taken per dimension, so every report can be an outlier
+        // in some dimension; there is then no agreement to report.
+        let total_weight = honest.iter().fold(0u128, |acc, (w, _)| acc.saturating_add(*w));
+        if total_weight == 0 {
+            return Err(format!(
+                "No AI advisory report for epoch {} lies within {} of the per-dimension medians",
+                epoch_id, max_deviation
+            ));
+        }
+        let mut means = [0u8; 4];
+        for (dim, mean) in means.iter_mut().enumerate() {
+            let sum = honest
+                .iter()
+                .fold(0u128, |acc, (w, v)| acc.saturating_add(w.saturating_mul(v[dim] as u128)));
+            *mean = BoundedScore::new((sum / total_weight).min(100) as u8).value();
+        }
+
+        if !outliers.is_empty() {
+            warn!(
+                "Rejected {} outlier AI advisory report(s) for epoch {} (max deviation {})",
+                outliers.len(),
+                epoch_id,
+                max_deviation
+            );
+        }
+
+        Ok(WeightedAdvisory {
+            advisory: AggregatedAdvisory {
+                epoch_id,
+                report_count: honest.len(),
+                network_health: means[0],
+                validator_performance: means[1],
+                byzantine_fault_likelihood: means[2],
+                finality_latency: means[3],
+            },
+            rejected_outliers: outliers.len(),
+        })
+    }
+
+    /// Check every report is signed and targets the same epoch; returns that epoch.
+    fn validate_reports(reports: &[AiAdvisoryReport]) -> Result<u64, String> {
+        // Verify all reports are signed
+        for report in reports {
+            report.verify_signature()?;
+        }
+
+        // Verify all reports are from the same epoch
+        let epoch_id = reports[0].epoch_id;
+        for report in &reports[1..] {
+            if report.epoch_id != epoch_id {
+                return Err(format!(
+                    "Report epoch mismatch: expected {}, got {}",
+                    epoch_id, report.epoch_id
+                ));
+            }
         }
+        Ok(epoch_id)
+    }
 
-        scores.sort_unstable();
+    fn score_vector(report: &AiAdvisoryReport) -> [u8; 4] {
+        [
+            report.network_health_score.value(),
+            report.validator_performance_score.value(),
+            report.byzantine_fault_likelihood.value(),
+            report.finality_latency_score.value(),
+        ]
+    }
+
+    /// Compute median of a list of scores (each report weighted equally).
+    /// 
+    /// SAFETY: Median is robust to outliers.
+    fn median_score(&self, scores: Vec<u8>) -> u8 {
+        let weighted: Vec<(u128, u8)> = scores.into_iter().map(|s| (1, s)).collect();
+        Self::midpoint_median(&weighted)
+    }
 
-        if scores.len() % 2 == 0 {
-            // Even number: average of two middle values
-            let mid = scores.len() / 2;
-            ((scores[mid - 1] as u16 + scores[mid] as u16) / 2) as u8
-        } else {
-            // Odd number: middle value
-            scores[scores.len() / 2]
+    /// Weighted median of scores; an exact half split averages the two
+    /// middle values (50 when there is no weight).
+    fn midpoint_median(weighted: &[(u128, u8)]) -> u8 {
+        match weighted_median_bounds(weighted) {
+            Some((lower, upper)) => ((lower as u16 + upper as u16) / 2) as u8,
+            None => 50, // Default middle value
         }
     }
 }
@@ -298,6 +400,16 @@ pub struct AggregatedAdvisory {
     pub finality_latency: u8,
 }
 
+/// Result of `AiReportAggregator::aggregate_weighted`.
+#[derive(Debug, Clone)]
+pub struct WeightedAdvisory {
+    /// Weighted mean over the reports that survived outlier rejection
+    pub advisory: AggregatedAdvisory,
+    
+    /// Number of reports discarded as outliers
+    pub rejected_outliers: usize,
+}
+
 impl AggregatedAdvisory {
     /// Check if the aggregated report indicates emergency.
     pub fn is_emergency(&self) -> bool {
@@ -461,4 +573,79 @@ mod tests {
         // (80 + 80 + 80 + 80) / 4 = 80
         assert_eq!(adv.overall_health(), 80);
     }
+
+    fn signed_report(node: &str, health: u8, perf: u8, byzantine: u8, finality: u8) -> AiAdvisoryReport {
+        let mut report = AiAdvisoryReport::new(0, node.to_string(), health, perf, byzantine, finality);
+        report.sign(vec![1]);
+        report
+    }
+
+    fn equal_weights(nodes: &[&str]) -> BTreeMap<String, u128> {
+        nodes.iter().map(|n| (n.to_string(), 10)).collect()
+    }
+
+    #[test]
+    fn test_weighted_aggregation_rejects_extreme_advisory() {
+        let aggregator = AiReportAggregator::new(3);
+        let reports = vec![
+            signed_report("ai_1", 80, 75, 20, 85),
+            signed_report("ai_2", 76, 79, 24, 81),
+            signed_report("ai_3", 84, 71, 16, 89),
+            // Malicious node claims total collapse to force emergency mode
+            signed_report("ai_4", 0, 0, 100, 0),
+        ];
+        let weights = equal_weights(&["ai_1", "ai_2", "ai_3", "ai_4"]);
+
+        let result = aggregator.aggregate_weighted(&reports, &weights, 15).unwrap();
+        assert_eq!(result.rejected_outliers, 1);
+        assert_eq!(result.advisory.report_count, 3);
+        // Mean of the honest majority
+        assert_eq!(result.advisory.network_health, 80);
+        assert_eq!(result.advisory.validator_performance, 75);
+        assert_eq!(result.advisory.byzantine_fault_likelihood, 20);
+        assert_eq!(result.advisory.finality_latency, 85);
+        assert!(!result.advisory.is_emergency());
+
+        // Input order does not change the outcome
+        let mut reversed = reports.clone();
+        reversed.reverse();
+        let again = aggregator.aggregate_weighted(&reversed, &weights, 15).unwrap();
+        assert_eq!(again.advisory.network_health, result.advisory.network_health);
+        assert_eq!(again.rejected_outliers, 1);
+    }
+
+    #[test]
+    fn test_weighted_aggregation_applies_weights() {
+        let aggregator = AiReportAggregator::new(2);
+        let reports = vec![signed_report("ai_1", 60, 60, 40, 60), signed_report("ai_2", 70, 70, 30, 70)];
+        let mut weights = equal_weights(&["ai_1", "ai_2"]);
+        weights.insert("ai_2".to_string(), 30);
+
+        let result = aggregator.aggregate_weighted(&reports, &weights, 20).unwrap();
+        assert_eq!(result.rejected_outliers, 0);
+        // (60*10 + 70*30) / 40 = 67
+        assert_eq!(result.advisory.network_health, 67);
+        assert_eq!(result.advisory.byzantine_fault_likelihood, 32);
+
+        // Unweighted nodes carry no influence; duplicates are refused
+        weights.insert("ai_2".to_string(), 0);
+        assert_eq!(aggregator.aggregate_weighted(&reports, &weights, 20).unwrap().advisory.network_health, 60);
+        let duplicated = vec![reports[0].clone(), reports[0].clone()];
+        assert!(aggregator.aggregate_weighted(&duplicated, &weights, 20).is_err());
+    }
+
+    #[test]
+    fn test_weighted_aggregation_errors_when_every_report_is_an_outlier() {
+        let aggregator = AiReportAggregator::new(3);
+        // Each report disagrees with the per-dimension medians (50s) in one dimension
+        let reports = vec![
+            signed_report("ai_1", 90, 50, 50, 50),
+            signed_report("ai_2", 50, 90, 50, 50),
+            signed_report("ai_3", 50, 50, 90, 50),
+        ];
+        let weights = equal_weights(&["ai_1", "ai_2", "ai_3"]);
+
+        assert!(aggregator.aggregate_weighted(&reports, &weights, 10).is_err());
+        assert_eq!(aggregator.aggregate_weighted(&reports, &weights, 40).unwrap().rejected_outliers, 0);
+    }
 }
@@ -20,6 +20,9 @@
 //! Blockchain::add_block(block, pk_32)  ← validate + commit
 //!   │
 //!   ▼
+//! BlockEconomicsReport::assemble        ← per-tx fees split by fee routing
+//!   │
+//!   ▼
 //! P2PNode::broadcast(Block, payload)   ← gossip to peers
 //!   │
 //!   ▼
@@ -31,9 +34,11 @@ use std::time::{Duration, Instant};
 use tracing::{error, info, warn};
 
 use bleep_core::block::{Block, Transaction, ConsensusMode};
+use bleep_core::block_economics::BlockEconomicsReport;
 use bleep_core::blockchain::Blockchain;
 use bleep_core::transaction_pool::TransactionPool;
 use bleep_state::state_manager::StateManager;
+use bleep_economics::runtime::BleepEconomicsRuntime;
 use parking_lot::Mutex as PLMutex;
 
 // P2P node for in-producer gossip broadcast
@@ -61,6 +66,8 @@ pub struct FinalizedBlock {
     pub tx_count:    usize,
     pub state_root:  [u8; 32],
     pub gas_used:    u64,
+    /// Fee revenue and its routing; `None` when no economics runtime is attached.
+    pub economics:   Option<BlockEconomicsReport>,
 }
 
 // ── Constants ─────────────────────────────────────────────────────────────────
@@ -116,6 +123,8 @@ pub struct BlockProducer {
     block_tx:   tokio::sync::broadcast::Sender<FinalizedBlock>,
     /// Live TPS benchmark — records wall-clock throughput from real block production.
     bench:      PLMutex<PerformanceBenchmark>,
+    /// Source of the base fee and fee routing for per-block economics reports.
+    economics:  Option<Arc<PLMutex<BleepEconomicsRuntime>>>,
 }
 
 impl BlockProducer {
@@ -148,11 +157,18 @@ impl BlockProducer {
         let bench = PLMutex::new(PerformanceBenchmark::new(NUM_SHARDS, BENCHMARK_DURATION_SECS, TARGET_TPS));
 
         (
-            Self { blockchain, tx_pool, state, executor, p2p, config, block_tx, bench },
+            Self { blockchain, tx_pool, state, executor, p2p, config, block_tx, bench, economics: None },
             block_rx,
         )
     }
 
+    /// Charge each transaction `gas × base fee` and attach a
+    /// `BlockEconomicsReport` split under the runtime's fee routing.
+    pub fn with_economics(mut self, economics: Arc<PLMutex<BleepEconomicsRuntime>>) -> Self {
+        self.economics = Some(economics);
+        self
+    }
+
     /// Subscribe an additional receiver to the finalized block channel.
     /// Use this to wire a `GossipBridge` before starting the producer.
     pub fn subscribe(&self) -> tokio::sync::broadcast::Receiver<FinalizedBlock> {
@@ -269,6 +285,7 @@ impl BlockProducer {
         //   2. VM StateDiff.balances    — EVM/WASM/ZK engine side-effects
         //      (contract-emitted balance changes beyond the simple transfer)
         let mut block_txs: Vec<Transaction> = Vec::with_capacity(tx_count);
+        let mut block_gas: Vec<u64> = Vec::with_capacity(tx_count);
         let mut total_gas: u64 = 0;
         {
             let mut state = self.state.lock();
@@ -325,12 +342,15 @@ impl BlockProducer {
                 }
 
                 total_gas += gas;
+                block_gas.push(*gas);
                 block_txs.push(Transaction {
                     sender:    zt.sender.clone(),
                     receiver:  zt.receiver.clone(),
                     amount:    zt.amount,
                     timestamp: zt.timestamp,
                     signature: zt.signature.clone(),
+                    nonce:     zt.nonce,
+                    chain_id:  zt.chain_id,
                 });
             }
             state.advance_block();
@@ -362,6 +382,26 @@ impl BlockProducer {
             hex::encode(&state_root),      // shard_state_root = full state root
         );
 
+        // ── 6b: Fee accounting under the governance-set routing ───────────────
+        let economics = match &self.economics {
+            Some(runtime) => {
+                let runtime  = runtime.lock();
+                let base_fee = runtime.current_base_fee();
+                let tx_fees: Vec<u128> = block_gas
+                    .iter()
+                    .map(|gas| (*gas as u128).saturating_mul(base_fee))
+                    .collect();
+                match BlockEconomicsReport::assemble(&block, &tx_fees, &runtime.state.fee_routing) {
+                    Ok(report) => Some(report),
+                    Err(e) => {
+                        warn!("[BlockProducer] economics report skipped: {}", e);
+                        None
+                    }
+                }
+            }
+            None => None,
+        };
+
         // ── 7: Sign block with real SPHINCS+-SHAKE-256f-simple secret key ──────
         if let Err(e) = block.sign_block(&self.config.validator_sk) {
             warn!("[BlockProducer] sign_block failed: {} — stamping validator_id", e);
@@ -420,6 +460,7 @@ impl BlockProducer {
             tx_count:   block_txs.len(),
             state_root,
             gas_used:   total_gas,
+            economics,
         }))
     }
 }
@@ -442,6 +483,7 @@ pub fn start_block_producer(
             let txs: Vec<Transaction> = pending.iter().map(|zt| Transaction {
                 sender: zt.sender.clone(), receiver: zt.receiver.clone(),
                 amount: zt.amount, timestamp: zt.timestamp, signature: zt.signature.clone(),
+                nonce: zt.nonce, chain_id: zt.chain_id,
             }).collect();
             if txs.is_empty() { continue; }
             let (next_height, prev_hash, epoch_id) = {
@@ -166,7 +166,8 @@ impl ChaosEngine {
 
     fn run_scenario(&mut self, scenario: ChaosScenario, height: u64) -> ChaosOutcome {
         let start = Instant::now();
-        match &scenario {
+        let kind = scenario.clone();
+        match &kind {
             ChaosScenario::ValidatorCrash { count } => {
                 let max_safe = (self.validator_count as f64 * self.config.max_crash_fraction) as usize;
                 if *count > max_safe {
@@ -60,6 +60,7 @@ use crate::blockchain_state::BlockchainState;
 use crate::networking::NetworkingModule;
 use bleep_crypto::zkp_verification::BLEEPError;
 use crate::ai_adaptive_logic::AIAdaptiveConsensus;
+use crate::orchestrator::MIN_BFT_VALIDATORS;
 
 // ── SPHINCS+-SHAKE-256-simple constants ───────────────────────────────────────
 
@@ -251,7 +252,7 @@ impl BLEEPAdaptiveConsensus {
             info!("Block {} finalized on retry using {:?}", block.index, self.consensus_mode);
             Ok(())
         } else {
-            Err(BLEEPError::ConsensusFailure(format!(
+            Err(BLEEPError::ConsensusFailed(format!(
                 "Block {} could not be finalized under any mode",
                 block.index
             )))
@@ -379,6 +380,15 @@ impl BLEEPAdaptiveConsensus {
     }
 
     fn has_quorum(&self, votes: &HashSet<String>) -> bool {
+        // Below 3f + 1 (f = 1) no quorum tolerates a Byzantine fault;
+        // an empty registry would otherwise need zero votes
+        if self.validators.len() < MIN_BFT_VALIDATORS as usize {
+            warn!(
+                "{} validators registered; BFT quorum needs at least {}.",
+                self.validators.len(), MIN_BFT_VALIDATORS
+            );
+            return false;
+        }
         let required = (self.validators.len() as f64 * 0.66).ceil() as usize;
         votes.len() >= required
     }
@@ -44,6 +44,9 @@ pub enum ConsensusError {
     /// PBFT consensus did not reach quorum
     PbftQuorumNotReached { validators: usize, threshold: usize },
     
+    /// Validator set is empty or too small for the operation
+    InsufficientValidators { active: usize, required: usize },
+    
     /// Generic consensus error
     Other(String),
 }
@@ -78,6 +81,9 @@ impl fmt::Display for ConsensusError {
             ConsensusError::PbftQuorumNotReached { validators, threshold } => {
                 write!(f, "PBFT quorum not reached: {} validators < {} threshold", validators, threshold)
             }
+            ConsensusError::InsufficientValidators { active, required } => {
+                write!(f, "Insufficient validators: {} active < {} required", active, required)
+            }
             ConsensusError::Other(msg) => write!(f, "Consensus error: {}", msg),
         }
     }
@@ -8,9 +8,13 @@
 // 4. Proofs are deterministic (same input → same proof)
 // 5. Proofs can be stored on-chain or in light client proofs
 
+use crate::engine::ConsensusError;
+use crate::slashing_engine::{SignedBlockProof, SlashingEvidence};
+use crate::validator_identity::ValidatorIdentity;
 use serde::{Serialize, Deserialize};
-use std::collections::HashMap;
-use log::info;
+use std::collections::{HashMap, HashSet};
+use log::{error, info};
+use thiserror::Error;
 
 /// A finality certificate: cryptographic proof that a block is finalized.
 /// 
@@ -118,6 +122,9 @@ impl FinalizyCertificate {
     /// 
     /// SAFETY: For Byzantine fault tolerance, we need >2/3 of total stake.
     pub fn meets_quorum(&self, total_stake: u128) -> bool {
+        if total_stake == 0 {
+            return false;
+        }
         let threshold = (total_stake * 2) / 3;
         self.total_voting_power() > threshold
     }
@@ -222,6 +229,30 @@ impl FinalityProof {
     }
 }
 
+/// Raised when equivocating validators hold enough stake to have produced a
+/// conflicting certificate at the same height.
+///
+/// SAFETY: Two >2/3 quorums must overlap in >1/3 of stake, so >1/3 equivocating
+/// stake among a certificate's signers means a conflicting finalization may
+/// exist. Finalization must halt rather than pick a side.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error(
+    "Safety alarm at height {height}: equivocating signers {equivocators:?} hold {equivocating_stake} of {total_stake} stake (> 1/3)"
+)]
+pub struct SafetyAlarm {
+    /// Height of the certificate being finalized
+    pub height: u64,
+
+    /// Signers of the certificate with equivocation evidence at this height (sorted)
+    pub equivocators: Vec<String>,
+
+    /// Voting power those signers contributed to the certificate
+    pub equivocating_stake: u128,
+
+    /// Total stake of the network
+    pub total_stake: u128,
+}
+
 /// Finality manager: tracks finalized blocks and manages finality proofs.
 /// 
 /// SAFETY: This is the authoritative record of what has been finalized.
@@ -238,6 +269,9 @@ pub struct FinalizityManager {
     
     /// Highest block height that has been finalized
     highest_finalized_height: u64,
+
+    /// Map of block height to validators with equivocation evidence at that height
+    equivocators: HashMap<u64, HashSet<String>>,
 }
 
 impl FinalizityManager {
@@ -248,26 +282,128 @@ impl FinalizityManager {
             finality_proofs: HashMap::new(),
             total_stake,
             highest_finalized_height: 0,
+            equivocators: HashMap::new(),
+        }
+    }
+
+    /// Record double-signing evidence against `validator`.
+    ///
+    /// SAFETY: Both conflicting signatures must verify against the
+    /// validator's registered key, so a forged accusation cannot block
+    /// finalization. Evidence without signed payloads (equivocation votes,
+    /// downtime) is rejected.
+    pub fn record_equivocation(
+        &mut self,
+        evidence: &SlashingEvidence,
+        validator: &ValidatorIdentity,
+    ) -> Result<(), String> {
+        evidence.is_well_formed()?;
+        if evidence.validator_id() != validator.id {
+            return Err(format!(
+                "Evidence names {} but validator {} was supplied",
+                evidence.validator_id(),
+                validator.id
+            ));
+        }
+
+        let height = match evidence {
+            SlashingEvidence::DoubleSigning {
+                height,
+                block_hash_1,
+                block_hash_2,
+                signature_1,
+                signature_2,
+                ..
+            } => {
+                let signed_1 = SignedBlockProof::signing_bytes(*height, block_hash_1);
+                let signed_2 = SignedBlockProof::signing_bytes(*height, block_hash_2);
+                if !validator.verify_signature(&signed_1, signature_1)
+                    || !validator.verify_signature(&signed_2, signature_2)
+                {
+                    return Err(format!(
+                        "Conflicting signatures at height {} do not verify for {}",
+                        height, validator.id
+                    ));
+                }
+                *height
+            }
+            SlashingEvidence::Equivocation { .. } => {
+                return Err("Equivocation votes carry no verifiable signed payload".to_string());
+            }
+            SlashingEvidence::Downtime { .. } => {
+                return Err("Downtime evidence is not equivocation".to_string());
+            }
+        };
+
+        self.equivocators
+            .entry(height)
+            .or_default()
+            .insert(evidence.validator_id().to_string());
+        Ok(())
+    }
+
+    /// Check that equivocating signers of `certificate` hold at most 1/3 of
+    /// total stake.
+    ///
+    /// SAFETY: Called by `finalize_block`; an alarm blocks finalization.
+    pub fn check_safety_before_finalize(
+        &self,
+        certificate: &FinalizyCertificate,
+    ) -> Result<(), SafetyAlarm> {
+        let height = certificate.block_height;
+        let Some(equivocators) = self.equivocators.get(&height) else {
+            return Ok(());
+        };
+
+        let mut offenders: Vec<String> = Vec::new();
+        let mut equivocating_stake: u128 = 0;
+        for sig in &certificate.validator_signatures {
+            if equivocators.contains(&sig.validator_id) {
+                offenders.push(sig.validator_id.clone());
+                equivocating_stake = equivocating_stake.saturating_add(sig.voting_power);
+            }
+        }
+
+        if equivocating_stake.saturating_mul(3) > self.total_stake {
+            offenders.sort();
+            return Err(SafetyAlarm {
+                height,
+                equivocators: offenders,
+                equivocating_stake,
+                total_stake: self.total_stake,
+            });
         }
+        Ok(())
     }
 
     /// Record that a block has been finalized.
     /// 
     /// SAFETY: Once finalized, a block cannot be changed.
-    pub fn finalize_block(&mut self, certificate: FinalizyCertificate) -> Result<(), String> {
+    pub fn finalize_block(&mut self, certificate: FinalizyCertificate) -> Result<(), ConsensusError> {
         let height = certificate.block_height;
 
+        // SAFETY: Without stake there is no quorum to finalize against
+        if self.total_stake == 0 {
+            return Err(ConsensusError::InsufficientValidators { active: 0, required: 1 });
+        }
+
         // SAFETY: Cannot finalize a block that's already finalized
         if self.finalized_blocks.contains_key(&height) {
-            return Err(format!("Block {} is already finalized", height));
+            return Err(ConsensusError::Other(format!("Block {} is already finalized", height)));
         }
 
         // SAFETY: Verify quorum
         if !certificate.meets_quorum(self.total_stake) {
-            return Err(format!(
+            return Err(ConsensusError::Other(format!(
                 "Certificate for block {} does not meet quorum",
                 height
-            ));
+            )));
+        }
+
+        // SAFETY: Refuse to finalize if a conflicting quorum may exist
+        if let Err(alarm) = self.check_safety_before_finalize(&certificate) {
+            error!("{}", alarm);
+            return Err(ConsensusError::Other(alarm.to_string()));
         }
 
         info!(
@@ -500,4 +636,146 @@ mod tests {
         let result = manager.finalize_block(cert);
         assert!(result.is_err());
     }
+
+    /// Validator with a fresh signing key; returns its secret key
+    fn keyed_validator(id: &str) -> (ValidatorIdentity, Vec<u8>) {
+        let (pk, sk) = bleep_crypto::tx_signer::generate_tx_keypair();
+        let validator = ValidatorIdentity::new(
+            id.to_string(),
+            vec![0u8; 1568],
+            format!("{}_signing_key", id),
+            1_000_000,
+            0,
+        )
+        .unwrap()
+        .with_signing_public_key(pk);
+        (validator, sk)
+    }
+
+    /// Two blocks at `height` signed by `sk`
+    fn double_sign(validator_id: &str, height: u64, sk: &[u8]) -> SlashingEvidence {
+        let sign = |hash: &str| {
+            bleep_crypto::tx_signer::sign_tx_payload(&SignedBlockProof::signing_bytes(height, hash), sk)
+                .unwrap()
+        };
+        SlashingEvidence::DoubleSigning {
+            validator_id: validator_id.to_string(),
+            height,
+            block_hash_1: "blockA".to_string(),
+            block_hash_2: "blockB".to_string(),
+            signature_1: sign("blockA"),
+            signature_2: sign("blockB"),
+        }
+    }
+
+    fn signed_cert(height: u64, signers: &[(&str, u128)]) -> FinalizyCertificate {
+        let mut cert = FinalizyCertificate::new(
+            height,
+            format!("hash{}", height),
+            1,
+            "PoS".to_string(),
+            "merkle_root".to_string(),
+            1000,
+            1,
+        )
+        .unwrap();
+        for (id, power) in signers {
+            cert.add_validator_signature(id.to_string(), vec![0u8; 4], *power).unwrap();
+        }
+        cert
+    }
+
+    #[test]
+    fn test_safety_check_clean_certificate() {
+        let mut manager = FinalizityManager::new(1000);
+        let cert = signed_cert(100, &[("v1", 400), ("v2", 300), ("v3", 300)]);
+
+        // Evidence at a different height, and a minority equivocator here
+        let (v1, sk1) = keyed_validator("v1");
+        let (v2, sk2) = keyed_validator("v2");
+        manager.record_equivocation(&double_sign("v1", 99, &sk1), &v1).unwrap();
+        manager.record_equivocation(&double_sign("v2", 100, &sk2), &v2).unwrap();
+
+        assert!(manager.check_safety_before_finalize(&cert).is_ok());
+        manager.finalize_block(cert).unwrap();
+        assert!(manager.is_finalized(100));
+    }
+
+    #[test]
+    fn test_safety_alarm_blocks_finalization() {
+        let mut manager = FinalizityManager::new(1000);
+        let cert = signed_cert(100, &[("v1", 200), ("v2", 200), ("v3", 400)]);
+
+        let (v1, sk1) = keyed_validator("v1");
+        let (v2, sk2) = keyed_validator("v2");
+        manager.record_equivocation(&double_sign("v2", 100, &sk2), &v2).unwrap();
+        manager.record_equivocation(&double_sign("v1", 100, &sk1), &v1).unwrap();
+
+        let alarm = manager.check_safety_before_finalize(&cert).unwrap_err();
+        assert_eq!(alarm.height, 100);
+        assert_eq!(alarm.equivocators, vec!["v1".to_string(), "v2".to_string()]);
+        assert_eq!(alarm.equivocating_stake, 400);
+
+        assert!(manager.finalize_block(cert).is_err());
+        assert!(!manager.is_finalized(100));
+    }
+
+    #[test]
+    fn test_record_equivocation_rejects_downtime() {
+        let mut manager = FinalizityManager::new(1000);
+        let evidence = SlashingEvidence::Downtime {
+            validator_id: "v1".to_string(),
+            missed_blocks: 5,
+            total_blocks_in_epoch: 10,
+        };
+        let (v1, _) = keyed_validator("v1");
+        assert!(manager.record_equivocation(&evidence, &v1).is_err());
+    }
+
+    #[test]
+    fn test_record_equivocation_rejects_forged_signatures() {
+        let mut manager = FinalizityManager::new(1000);
+        let (v1, _) = keyed_validator("v1");
+        let (v2, sk2) = keyed_validator("v2");
+
+        // Signed by someone else's key
+        assert!(manager.record_equivocation(&double_sign("v1", 100, &sk2), &v1).is_err());
+
+        // Evidence against v1 checked against v2's identity
+        assert!(manager.record_equivocation(&double_sign("v1", 100, &sk2), &v2).is_err());
+
+        // One genuine signature and one garbage signature
+        let mut evidence = double_sign("v2", 100, &sk2);
+        if let SlashingEvidence::DoubleSigning { signature_2, .. } = &mut evidence {
+            *signature_2 = vec![0u8; 4];
+        }
+        assert!(manager.record_equivocation(&evidence, &v2).is_err());
+
+        // Unsigned equivocation votes cannot be verified
+        let votes = SlashingEvidence::Equivocation {
+            validator_id: "v2".to_string(),
+            height: 100,
+            vote_1: vec![1],
+            vote_2: vec![2],
+            timestamp_1: 1000,
+            timestamp_2: 1001,
+        };
+        assert!(manager.record_equivocation(&votes, &v2).is_err());
+
+        let cert = signed_cert(100, &[("v1", 400), ("v2", 400)]);
+        assert!(manager.check_safety_before_finalize(&cert).is_ok());
+    }
+
+    #[test]
+    fn test_finalize_rejects_empty_validator_set() {
+        let mut manager = FinalizityManager::new(0);
+        let cert = signed_cert(100, &[("v1", 700)]);
+
+        assert!(!cert.meets_quorum(0));
+        assert!(matches!(
+            manager.finalize_block(cert),
+            Err(ConsensusError::InsufficientValidators { active: 0, required: 1 })
+        ));
+        assert!(!manager.is_finalized(100));
+    }
 }
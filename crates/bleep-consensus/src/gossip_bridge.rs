@@ -41,7 +41,7 @@ pub fn decode_finalized_block(data: &[u8]) -> Option<FinalizedBlock> {
     let sr: [u8; 32] = data[16..48].try_into().ok()?;
     let tx_count = u32::from_le_bytes(data[48..52].try_into().ok()?) as usize;
     let hash     = String::from_utf8(data[52..].to_vec()).ok()?;
-    Some(FinalizedBlock { height, epoch, state_root: sr, tx_count, hash, gas_used: 0 })
+    Some(FinalizedBlock { height, epoch, state_root: sr, tx_count, hash, gas_used: 0, economics: None })
 }
 
 // ── GossipBridge ──────────────────────────────────────────────────────────────
@@ -112,6 +112,7 @@ mod tests {
             tx_count:   7,
             state_root: [1u8; 32],
             gas_used:   0,
+            economics:  None,
         }
     }
 
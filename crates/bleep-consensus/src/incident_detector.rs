@@ -9,9 +9,11 @@
 // 5. False positives are impossible (rules are conservative)
 // 6. No detection is lost (events are persisted)
 
+use crate::epoch::ConsensusMode;
+use crate::validator_identity::ValidatorIdentity;
 use serde::{Serialize, Deserialize};
 use sha2::{Digest, Sha256};
-use std::collections::{HashMap, VecDeque};
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
 use log::{info, warn, error};
 use thiserror::Error;
 
@@ -192,6 +194,48 @@ pub struct IncidentDetector {
     
     /// Detection parameters (thresholds)
     detection_params: DetectionParams,
+    
+    /// Active consensus mode (selects the finality delay threshold)
+    consensus_mode: ConsensusMode,
+    
+    /// Signed state roots per height: height → validator → first report
+    state_root_reports: BTreeMap<u64, BTreeMap<String, StateRootReport>>,
+    
+    /// Second, different roots signed by a validator: height → validator → root
+    root_equivocations: BTreeMap<u64, BTreeMap<String, Vec<u8>>>,
+    
+    /// Root equivocations already raised as incidents
+    raised_root_equivocations: BTreeSet<(u64, String)>,
+    
+    /// Heights whose root conflict was already decided (raised once each)
+    conflicting_root_heights: BTreeSet<u64>,
+    
+    /// Total active stake; state-root quorum is more than 2/3 of it
+    total_stake: u128,
+}
+
+/// Heights of state-root reports retained for conflict detection
+const STATE_ROOT_WINDOW: usize = 100;
+
+/// A validator's signed state root at one height
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct StateRootReport {
+    root: Vec<u8>,
+    signature: Vec<u8>,
+    stake: u128,
+}
+
+/// Bytes a validator signs to report its state root at `height`
+pub fn state_root_signing_bytes(height: u64, root: &[u8]) -> Vec<u8> {
+    let mut bytes = b"BLEEP-STATE-ROOT-V1".to_vec();
+    bytes.extend_from_slice(&height.to_le_bytes());
+    bytes.extend_from_slice(root);
+    bytes
+}
+
+/// Whether `stake` is more than 2/3 of `total`
+fn is_stake_quorum(stake: u128, total: u128) -> bool {
+    total > 0 && stake.saturating_mul(3) > total.saturating_mul(2)
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -220,9 +264,17 @@ struct ValidatorBehavior {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DetectionParams {
-    /// Finality must progress within N epochs
+    /// Finality must progress within N epochs (PoS)
     pub finality_delay_threshold: u64,
     
+    /// Finality threshold while PBFT fast finality is active
+    #[serde(default = "default_pbft_finality_delay_threshold")]
+    pub pbft_finality_delay_threshold: u64,
+    
+    /// Finality threshold while emergency PoW is active
+    #[serde(default = "default_pow_finality_delay_threshold")]
+    pub pow_finality_delay_threshold: u64,
+    
     /// Consensus must produce blocks within N epochs
     pub consensus_stall_threshold: u64,
     
@@ -233,10 +285,34 @@ pub struct DetectionParams {
     pub network_partition_threshold: u64,
 }
 
+fn default_pbft_finality_delay_threshold() -> u64 {
+    2
+}
+
+fn default_pow_finality_delay_threshold() -> u64 {
+    20
+}
+
+impl DetectionParams {
+    /// Finality delay threshold for the given consensus mode
+    ///
+    /// SAFETY: PoW finality is probabilistic and slow, so a gap that is an
+    /// incident under PBFT is normal operation under PoW.
+    pub fn finality_delay_threshold_for(&self, mode: ConsensusMode) -> u64 {
+        match mode {
+            ConsensusMode::PosNormal => self.finality_delay_threshold,
+            ConsensusMode::PbftFastFinality => self.pbft_finality_delay_threshold,
+            ConsensusMode::EmergencyPow => self.pow_finality_delay_threshold,
+        }
+    }
+}
+
 impl Default for DetectionParams {
     fn default() -> Self {
         DetectionParams {
             finality_delay_threshold: 5,      // 5 epochs
+            pbft_finality_delay_threshold: default_pbft_finality_delay_threshold(),
+            pow_finality_delay_threshold: default_pow_finality_delay_threshold(),
             consensus_stall_threshold: 3,     // 3 epochs
             validator_downtime_threshold: 33, // 33%
             network_partition_threshold: 34,  // > 1/3
@@ -253,9 +329,30 @@ impl IncidentDetector {
             finality_history: VecDeque::new(),
             validator_behavior: HashMap::new(),
             detection_params,
+            consensus_mode: ConsensusMode::PosNormal,
+            state_root_reports: BTreeMap::new(),
+            root_equivocations: BTreeMap::new(),
+            raised_root_equivocations: BTreeSet::new(),
+            conflicting_root_heights: BTreeSet::new(),
+            total_stake: 0,
         }
     }
     
+    /// Set the total active stake used for the state-root quorum
+    pub fn set_total_stake(&mut self, total_stake: u128) {
+        self.total_stake = total_stake;
+    }
+    
+    /// Set the active consensus mode (called on mode transitions)
+    pub fn set_consensus_mode(&mut self, mode: ConsensusMode) {
+        self.consensus_mode = mode;
+    }
+    
+    /// Active consensus mode
+    pub fn consensus_mode(&self) -> ConsensusMode {
+        self.consensus_mode
+    }
+    
     /// Add block to history (called at each block)
     pub fn observe_block(
         &mut self,
@@ -297,6 +394,57 @@ impl IncidentDetector {
         }
     }
     
+    /// Record the state root `validator` signed at `height`
+    /// 
+    /// SAFETY: The signature must verify against the validator's registered
+    /// key. A validator's first report at a height counts toward the quorum;
+    /// a second, different signed root is kept as equivocation evidence and
+    /// removes the validator's stake from that height's decision.
+    /// 
+    /// Takes `signature` after the `(height, validator, root)` of the original
+    /// design: an unsigned report would let any peer fabricate a conflict.
+    pub fn observe_state_root(
+        &mut self,
+        height: u64,
+        validator: &ValidatorIdentity,
+        root: Vec<u8>,
+        signature: Vec<u8>,
+    ) -> Result<(), DetectorError> {
+        if !validator.verify_signature(&state_root_signing_bytes(height, &root), &signature) {
+            return Err(DetectorError::InvalidReport);
+        }
+        
+        let reports = self.state_root_reports.entry(height).or_default();
+        match reports.get(&validator.id) {
+            None => {
+                reports.insert(validator.id.clone(), StateRootReport {
+                    root,
+                    signature,
+                    stake: validator.stake,
+                });
+            }
+            Some(first) if first.root == root => {}
+            Some(_) => {
+                warn!("Validator {} signed two state roots at height {}", validator.id, height);
+                self.root_equivocations
+                    .entry(height)
+                    .or_default()
+                    .entry(validator.id.clone())
+                    .or_insert(root);
+            }
+        }
+        
+        // Keep the most recent heights only
+        while self.state_root_reports.len() > STATE_ROOT_WINDOW {
+            if let Some((oldest, _)) = self.state_root_reports.pop_first() {
+                self.root_equivocations.remove(&oldest);
+                self.raised_root_equivocations.retain(|(height, _)| *height != oldest);
+                self.conflicting_root_heights.remove(&oldest);
+            }
+        }
+        Ok(())
+    }
+    
     /// Continuous health check (called at each epoch)
     pub fn check_health(&mut self, current_epoch: u64) -> Result<Vec<IncidentReport>, DetectorError> {
         let mut new_incidents = Vec::new();
@@ -316,8 +464,21 @@ impl IncidentDetector {
             new_incidents.extend(incidents);
         }
         
-        // Check 4: Network partition (simplified)
-        // In production, would compare state roots across nodes
+        // Check 4: Validators signing two state roots at one height
+        let root_equivocations = self.detect_root_equivocations(current_epoch)?;
+        for incident in &root_equivocations {
+            if let IncidentEvidence::Equivocation { validator_id, height, .. } = &incident.evidence {
+                self.raised_root_equivocations.insert((*height, validator_id.clone()));
+            }
+        }
+        new_incidents.extend(root_equivocations);
+        
+        // Check 5: Conflicting state roots (minority against a stake quorum)
+        // and network partition (no root can reach quorum)
+        for (height, incident) in self.detect_conflicting_state_roots(current_epoch)? {
+            self.conflicting_root_heights.insert(height);
+            new_incidents.push(incident);
+        }
         
         // Register incidents
         for incident in &new_incidents {
@@ -334,19 +495,20 @@ impl IncidentDetector {
     fn detect_finality_delay(&self, current_epoch: u64) -> Result<Option<IncidentReport>, DetectorError> {
         if let Some(latest_finality) = self.finality_history.back() {
             let gap = current_epoch - latest_finality.finality_epoch;
+            let threshold = self.detection_params.finality_delay_threshold_for(self.consensus_mode);
             
-            if gap > self.detection_params.finality_delay_threshold {
+            if gap > threshold {
                 let evidence = IncidentEvidence::FinalityGap {
                     last_finalized: latest_finality.finalized_epoch,
                     current_epoch,
                     gap_epochs: gap,
-                    threshold: self.detection_params.finality_delay_threshold,
+                    threshold,
                 };
                 
                 return Ok(Some(self.create_incident_report(
                     IncidentType::FinalityDelay,
-                    format!("Finality delayed by {} epochs (threshold: {})", 
-                            gap, self.detection_params.finality_delay_threshold),
+                    format!("Finality delayed by {} epochs under {} (threshold: {})", 
+                            gap, self.consensus_mode.as_str(), threshold),
                     evidence,
                     current_epoch,
                 )?));
@@ -381,37 +543,222 @@ impl IncidentDetector {
         Ok(None)
     }
     
-    /// Detect validator downtime
-    fn detect_validator_downtime(&self, current_epoch: u64) -> Result<Vec<IncidentReport>, DetectorError> {
-        let mut incidents = Vec::new();
-        
-        // Count total proposals in recent history
+    /// Per-proposer `(validator_id, missed_blocks, downtime_percentage)`
+    /// over the recent block history, assuming uniform proposer rotation.
+    fn proposer_downtime(&self) -> Vec<(String, u64, u64)> {
         let total_blocks = self.block_history.len() as u64;
         if total_blocks == 0 {
-            return Ok(incidents);
+            return Vec::new();
         }
         
         // Count proposals per validator
         let mut validator_proposals = HashMap::new();
         for block in &self.block_history {
-            *validator_proposals.entry(block.proposer.clone()).or_insert(0) += 1;
+            *validator_proposals.entry(block.proposer.clone()).or_insert(0u64) += 1;
         }
         
         // Expected proposals per validator (uniform distribution)
         let expected_proposals = total_blocks / validator_proposals.len() as u64;
         
-        // Detect downtime
-        for (validator_id, actual_proposals) in validator_proposals {
-            let downtime_percentage = if expected_proposals > 0 {
-                ((expected_proposals - actual_proposals) * 100) / expected_proposals
-            } else {
-                0
-            };
+        validator_proposals
+            .into_iter()
+            .map(|(validator_id, actual_proposals)| {
+                let missed = expected_proposals.saturating_sub(actual_proposals);
+                let downtime_percentage = if expected_proposals > 0 {
+                    (missed * 100) / expected_proposals
+                } else {
+                    0
+                };
+                (validator_id, missed, downtime_percentage)
+            })
+            .collect()
+    }
+    
+    /// `(down, total)`: recent proposers above the downtime threshold, out of all recent proposers
+    pub fn validator_downtime_counts(&self) -> (u64, u64) {
+        let downtime = self.proposer_downtime();
+        let down = downtime
+            .iter()
+            .filter(|(_, _, pct)| *pct > self.detection_params.validator_downtime_threshold)
+            .count();
+        (down as u64, downtime.len() as u64)
+    }
+    
+    /// Epochs since the latest finality record (`None` before any finality)
+    pub fn finality_gap(&self, current_epoch: u64) -> Option<u64> {
+        self.finality_history
+            .back()
+            .map(|latest| current_epoch.saturating_sub(latest.finality_epoch))
+    }
+    
+    /// Finality delay threshold for the active consensus mode
+    pub fn finality_delay_threshold(&self) -> u64 {
+        self.detection_params.finality_delay_threshold_for(self.consensus_mode)
+    }
+    
+    /// Detect validators that signed two different state roots at one height
+    /// 
+    /// SAFETY: Iterates ordered maps, so every node raises the same incidents
+    /// in the same order.
+    fn detect_root_equivocations(&self, current_epoch: u64) -> Result<Vec<IncidentReport>, DetectorError> {
+        let mut incidents = Vec::new();
+        
+        for (height, equivocators) in &self.root_equivocations {
+            for (validator, second_root) in equivocators {
+                if self.raised_root_equivocations.contains(&(*height, validator.clone())) {
+                    continue;
+                }
+                let first_root = &self
+                    .state_root_reports
+                    .get(height)
+                    .and_then(|reports| reports.get(validator))
+                    .ok_or(DetectorError::InvalidReport)?
+                    .root;
+                
+                let description = format!(
+                    "Validator {} signed state roots {} and {} at height {}",
+                    validator, hex::encode(first_root), hex::encode(second_root), height
+                );
+                let evidence = IncidentEvidence::Equivocation {
+                    validator_id: validator.clone(),
+                    height: *height,
+                    block_hash1: first_root.clone(),
+                    block_hash2: second_root.clone(),
+                };
+                incidents.push(self.create_incident_report(
+                    IncidentType::ValidatorEquivocation,
+                    description,
+                    evidence,
+                    current_epoch,
+                )?);
+            }
+        }
+        
+        Ok(incidents)
+    }
+    
+    /// Decide state-root disagreements by stake quorum
+    /// 
+    /// SAFETY: A height is only decided once the outcome cannot change:
+    /// - some root is signed by more than 2/3 of total stake and another root
+    ///   was also signed → `ConflictingStateRoots` (the quorum root is
+    ///   canonical, the minority diverged);
+    /// - no root can reach quorum even if every outstanding validator agrees
+    ///   with the heaviest one → `NetworkPartition`.
+    /// Equivocating validators count toward neither side. Evidence is chosen
+    /// from ordered maps, so every node builds the same incident hash
+    /// regardless of the order reports arrived in.
+    fn detect_conflicting_state_roots(
+        &self,
+        current_epoch: u64,
+    ) -> Result<Vec<(u64, IncidentReport)>, DetectorError> {
+        let mut incidents = Vec::new();
+        if self.total_stake == 0 {
+            return Ok(incidents);
+        }
+        
+        for (height, reports) in &self.state_root_reports {
+            if self.conflicting_root_heights.contains(height) {
+                continue;
+            }
+            let equivocators = self.root_equivocations.get(height);
+            
+            // root → (stake, lowest reporting validator)
+            let mut roots: BTreeMap<&Vec<u8>, (u128, &String)> = BTreeMap::new();
+            let mut reported_stake = 0u128;
+            for (validator, report) in reports {
+                reported_stake = reported_stake.saturating_add(report.stake);
+                if equivocators.is_some_and(|e| e.contains_key(validator)) {
+                    continue;
+                }
+                let entry = roots.entry(&report.root).or_insert((0, validator));
+                entry.0 = entry.0.saturating_add(report.stake);
+            }
+            if roots.len() < 2 {
+                continue;
+            }
+            
+            // Heaviest root; ties go to the lowest root
+            let (canonical, &(canonical_stake, canonical_proposer)) = roots
+                .iter()
+                .max_by(|(root_a, (stake_a, _)), (root_b, (stake_b, _))| {
+                    stake_a.cmp(stake_b).then_with(|| root_b.cmp(root_a))
+                })
+                .ok_or(DetectorError::InvalidReport)?;
+            let canonical: &Vec<u8> = canonical;
             
+            if is_stake_quorum(canonical_stake, self.total_stake) {
+                let (minority, (minority_stake, minority_proposer)) = roots
+                    .iter()
+                    .find(|(root, _)| **root != canonical)
+                    .ok_or(DetectorError::InvalidReport)?;
+                
+                let description = format!(
+                    "Conflicting state roots at height {}: quorum root {} ({} stake, {}) vs {} ({} stake, {})",
+                    height,
+                    hex::encode(canonical), canonical_stake, canonical_proposer,
+                    hex::encode(minority), minority_stake, minority_proposer
+                );
+                let evidence = IncidentEvidence::ConflictingRoots {
+                    height: *height,
+                    root1: canonical.clone(),
+                    root1_proposer: canonical_proposer.clone(),
+                    root2: (*minority).clone(),
+                    root2_proposer: (*minority_proposer).clone(),
+                };
+                incidents.push((*height, self.create_incident_report(
+                    IncidentType::ConflictingStateRoots,
+                    description,
+                    evidence,
+                    current_epoch,
+                )?));
+                continue;
+            }
+            
+            let outstanding = self.total_stake.saturating_sub(reported_stake);
+            if is_stake_quorum(canonical_stake.saturating_add(outstanding), self.total_stake) {
+                // The heaviest root can still reach quorum; wait for more reports
+                continue;
+            }
+            
+            let heaviest_reporters = reports
+                .iter()
+                .filter(|(validator, report)| {
+                    report.root == *canonical
+                        && !equivocators.is_some_and(|e| e.contains_key(*validator))
+                })
+                .count() as u64;
+            let description = format!(
+                "State roots at height {} split {} ways; heaviest root {} holds {} of {} stake",
+                height, roots.len(), hex::encode(canonical), canonical_stake, self.total_stake
+            );
+            let evidence = IncidentEvidence::Partition {
+                partition_size: (reports.len() as u64).saturating_sub(heaviest_reporters),
+                network_size: reports.len() as u64,
+                diverging_state_roots: roots.keys().map(|root| (*root).clone()).collect(),
+            };
+            incidents.push((*height, self.create_incident_report(
+                IncidentType::NetworkPartition,
+                description,
+                evidence,
+                current_epoch,
+            )?));
+        }
+        
+        Ok(incidents)
+    }
+    
+    /// Detect validator downtime
+    fn detect_validator_downtime(&self, current_epoch: u64) -> Result<Vec<IncidentReport>, DetectorError> {
+        let mut incidents = Vec::new();
+        let total_blocks = self.block_history.len() as u64;
+        
+        // Detect downtime
+        for (validator_id, missed_blocks, downtime_percentage) in self.proposer_downtime() {
             if downtime_percentage > self.detection_params.validator_downtime_threshold {
                 let evidence = IncidentEvidence::Downtime {
                     validator_id: validator_id.clone(),
-                    missed_blocks: expected_proposals - actual_proposals,
+                    missed_blocks,
                     total_blocks,
                     downtime_percentage,
                     threshold: self.detection_params.validator_downtime_threshold,
@@ -551,6 +898,31 @@ mod tests {
         assert_eq!(incidents[0].incident_type, IncidentType::FinalityDelay);
     }
 
+    #[test]
+    fn test_finality_threshold_follows_consensus_mode() {
+        // Same 4-epoch gap: incident under PBFT (threshold 2), normal under PoW (threshold 20)
+        let mut pbft = IncidentDetector::new(DetectionParams::default());
+        pbft.set_consensus_mode(ConsensusMode::PbftFastFinality);
+        pbft.observe_finality(100, 1, 1);
+        
+        let incidents = pbft.check_health(5).unwrap();
+        assert_eq!(incidents.len(), 1);
+        assert_eq!(incidents[0].incident_type, IncidentType::FinalityDelay);
+        match &incidents[0].evidence {
+            IncidentEvidence::FinalityGap { gap_epochs, threshold, .. } => {
+                assert_eq!(*gap_epochs, 4);
+                assert_eq!(*threshold, 2);
+            }
+            other => panic!("unexpected evidence: {:?}", other),
+        }
+        
+        let mut pow = IncidentDetector::new(DetectionParams::default());
+        pow.set_consensus_mode(ConsensusMode::EmergencyPow);
+        pow.observe_finality(100, 1, 1);
+        
+        assert!(pow.check_health(5).unwrap().is_empty());
+    }
+
     #[test]
     fn test_consensus_stall_detection() {
         let mut detector = IncidentDetector::new(DetectionParams::default());
@@ -593,4 +965,158 @@ mod tests {
         // Same inputs → same hash
         assert_eq!(incident1.incident_hash, incident2.incident_hash);
     }
+
+    /// Validator with a fresh signing key; returns its secret key
+    fn keyed_validator(id: &str, stake: u128) -> (ValidatorIdentity, Vec<u8>) {
+        let (pk, sk) = bleep_crypto::tx_signer::generate_tx_keypair();
+        let validator = ValidatorIdentity::new(
+            id.to_string(),
+            vec![0u8; 1568],
+            format!("{}_signing_key", id),
+            stake,
+            0,
+        )
+        .unwrap()
+        .with_signing_public_key(pk);
+        (validator, sk)
+    }
+    
+    fn report(
+        detector: &mut IncidentDetector,
+        height: u64,
+        root: Vec<u8>,
+        (validator, sk): &(ValidatorIdentity, Vec<u8>),
+    ) -> Result<(), DetectorError> {
+        let signature = bleep_crypto::tx_signer::sign_tx_payload(
+            &state_root_signing_bytes(height, &root),
+            sk,
+        ).unwrap();
+        detector.observe_state_root(height, validator, root, signature)
+    }
+    
+    /// val-1 (100) and val-2, val-3 (150 each): 400 total stake
+    fn three_validators() -> Vec<(ValidatorIdentity, Vec<u8>)> {
+        vec![
+            keyed_validator("val-1", 100),
+            keyed_validator("val-2", 150),
+            keyed_validator("val-3", 150),
+        ]
+    }
+    
+    fn detector_with_stake(total_stake: u128) -> IncidentDetector {
+        let mut detector = IncidentDetector::new(DetectionParams::default());
+        detector.set_total_stake(total_stake);
+        detector
+    }
+
+    #[test]
+    fn test_conflicting_state_roots_detected() {
+        let vals = three_validators();
+        let mut detector = detector_with_stake(400);
+        report(&mut detector, 42, vec![0xbb; 32], &vals[1]).unwrap();
+        report(&mut detector, 42, vec![0xaa; 32], &vals[0]).unwrap();
+        report(&mut detector, 42, vec![0xbb; 32], &vals[2]).unwrap();
+        
+        let incidents = detector.check_health(5).unwrap();
+        assert_eq!(incidents.len(), 1);
+        assert_eq!(incidents[0].incident_type, IncidentType::ConflictingStateRoots);
+        match &incidents[0].evidence {
+            IncidentEvidence::ConflictingRoots { height, root1, root1_proposer, root2, root2_proposer } => {
+                assert_eq!(*height, 42);
+                // The 300-stake quorum root is canonical; val-1 diverged
+                assert_eq!((root1.clone(), root1_proposer.as_str()), (vec![0xbb; 32], "val-2"));
+                assert_eq!((root2.clone(), root2_proposer.as_str()), (vec![0xaa; 32], "val-1"));
+            }
+            other => panic!("unexpected evidence {:?}", other),
+        }
+        
+        // Same reports in a different order yield the same incident hash
+        let mut other = detector_with_stake(400);
+        report(&mut other, 42, vec![0xbb; 32], &vals[2]).unwrap();
+        report(&mut other, 42, vec![0xaa; 32], &vals[0]).unwrap();
+        report(&mut other, 42, vec![0xbb; 32], &vals[1]).unwrap();
+        assert_eq!(other.check_health(5).unwrap()[0].incident_hash, incidents[0].incident_hash);
+        
+        // Raised once per height
+        assert!(detector.check_health(6).unwrap().is_empty());
+    }
+    
+    #[test]
+    fn test_state_root_conflict_waits_for_quorum() {
+        let vals = three_validators();
+        let mut detector = detector_with_stake(400);
+        
+        // 150 vs 100 with 150 outstanding: either root could still win
+        report(&mut detector, 7, vec![0xbb; 32], &vals[1]).unwrap();
+        report(&mut detector, 7, vec![0xaa; 32], &vals[0]).unwrap();
+        assert!(detector.check_health(1).unwrap().is_empty());
+        
+        // val-3 picks a third root: no root can reach 2/3 any more
+        report(&mut detector, 7, vec![0xcc; 32], &vals[2]).unwrap();
+        let incidents = detector.check_health(2).unwrap();
+        assert_eq!(incidents.len(), 1);
+        assert_eq!(incidents[0].incident_type, IncidentType::NetworkPartition);
+        match &incidents[0].evidence {
+            IncidentEvidence::Partition { partition_size, network_size, diverging_state_roots } => {
+                assert_eq!((*partition_size, *network_size), (2, 3));
+                assert_eq!(diverging_state_roots.len(), 3);
+            }
+            other => panic!("unexpected evidence {:?}", other),
+        }
+    }
+    
+    #[test]
+    fn test_unsigned_state_root_rejected() {
+        let vals = three_validators();
+        let mut detector = detector_with_stake(400);
+        
+        // Signature by another validator's key
+        let forged = bleep_crypto::tx_signer::sign_tx_payload(
+            &state_root_signing_bytes(42, &[0xaa; 32]),
+            &vals[1].1,
+        ).unwrap();
+        assert!(matches!(
+            detector.observe_state_root(42, &vals[0].0, vec![0xaa; 32], forged),
+            Err(DetectorError::InvalidReport)
+        ));
+        
+        // Signature for a different height
+        let replayed = bleep_crypto::tx_signer::sign_tx_payload(
+            &state_root_signing_bytes(41, &[0xaa; 32]),
+            &vals[0].1,
+        ).unwrap();
+        assert!(detector.observe_state_root(42, &vals[0].0, vec![0xaa; 32], replayed).is_err());
+        
+        // Nothing was recorded, so honest reports agree
+        report(&mut detector, 42, vec![0xbb; 32], &vals[1]).unwrap();
+        report(&mut detector, 42, vec![0xbb; 32], &vals[2]).unwrap();
+        assert!(detector.check_health(5).unwrap().is_empty());
+    }
+    
+    #[test]
+    fn test_validator_signing_two_roots_is_equivocation() {
+        let vals = three_validators();
+        let mut detector = detector_with_stake(400);
+        for height in 1..=3u64 {
+            for val in &vals {
+                report(&mut detector, height, height.to_le_bytes().to_vec(), val).unwrap();
+            }
+            assert!(detector.check_health(height).unwrap().is_empty());
+        }
+        
+        // val-1 signs a second root at height 3
+        report(&mut detector, 3, vec![0xff], &vals[0]).unwrap();
+        let incidents = detector.check_health(4).unwrap();
+        assert_eq!(incidents.len(), 1);
+        assert_eq!(incidents[0].incident_type, IncidentType::ValidatorEquivocation);
+        match &incidents[0].evidence {
+            IncidentEvidence::Equivocation { validator_id, height, block_hash1, block_hash2 } => {
+                assert_eq!((validator_id.as_str(), *height), ("val-1", 3));
+                assert_eq!(block_hash1, &3u64.to_le_bytes().to_vec());
+                assert_eq!(block_hash2, &vec![0xff]);
+            }
+            other => panic!("unexpected evidence {:?}", other),
+        }
+        assert!(detector.check_health(5).unwrap().is_empty());
+    }
 }
@@ -13,16 +13,22 @@ pub mod validator_identity;
 pub mod slashing_engine;
 pub mod finality;
 pub mod block_producer;
+pub mod incident_detector;
+pub mod recovery_controller;
+pub mod self_healing_orchestrator;
 
 pub use consensus::{BLEEPAdaptiveConsensus, ConsensusMode, Validator};
 pub use blockchain_state::BlockchainState;
 pub use networking::NetworkingModule;
 pub use epoch::{EpochConfig, EpochState, ConsensusMode as EpochConsensusMode};
 pub use engine::{ConsensusEngine, ConsensusError};
-pub use validator_identity::{ValidatorIdentity, ValidatorRegistry, ValidatorState};
-pub use slashing_engine::{SlashingEngine, SlashingEvidence, SlashingEvent, SlashingPenalty};
-pub use orchestrator::ConsensusOrchestrator;
-pub use finality::{FinalizyCertificate, FinalityProof, FinalizityManager, ValidatorSignature};
+pub use validator_identity::{ValidatorIdentity, ValidatorRegistry, ValidatorState, DOWNTIME_JAIL_EPOCHS};
+pub use slashing_engine::{
+    SlashingEngine, SlashingEvidence, SlashingEvent, SlashingPenalty,
+    ChallengeEvidence, ChallengeOutcome, PendingSlash, SignedBlockProof, CHALLENGE_WINDOW_EPOCHS,
+};
+pub use orchestrator::{ConsensusOrchestrator, OrchestratorState, FinalityView};
+pub use finality::{FinalizyCertificate, FinalityProof, FinalizityManager, SafetyAlarm, ValidatorSignature};
 
 pub fn run_consensus_engine() -> Result<(), Box<dyn std::error::Error>> {
     // Consensus engine initialization - called at node startup
@@ -10,16 +10,33 @@
 
 use crate::epoch::{EpochConfig, EpochState, ConsensusMode};
 use crate::engine::{ConsensusEngine, ConsensusError, ConsensusMetrics};
+use crate::finality::FinalizyCertificate;
+use crate::self_healing_orchestrator::SelfHealingOrchestrator;
+use crate::validator_identity::ValidatorRegistry;
 use bleep_core::block::Block;
 use bleep_core::blockchain::BlockchainState;
-use std::collections::HashMap;
+use bleep_state::epoch_clock::EpochClock;
+use bleep_state::shard_registry::{EpochId, ShardId, ShardStateRoot};
+use bleep_state::snapshot_engine::{SnapshotEngine, SnapshotId};
+use serde::{Serialize, Deserialize};
+use std::collections::{BTreeMap, HashMap};
 use std::sync::Arc;
 use log::{info, warn};
 
+/// Finality gap (blocks since the last finalized block) beyond which
+/// finality is considered stalled.
+pub const DEFAULT_EMERGENCY_FINALITY_GAP: u64 = 100;
+
+/// Finality gap at or below which finality is considered resumed.
+pub const DEFAULT_FINALITY_RESUME_GAP: u64 = 10;
+
+/// Smallest validator set that tolerates one Byzantine fault (3f + 1, f = 1).
+pub const MIN_BFT_VALIDATORS: u32 = 4;
+
 /// State of the emergency PoW mechanism.
 /// 
 /// SAFETY: PoW must NEVER become permanent. It is rate-limited and auto-exits.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum EmergencyPoWState {
     /// PoW is not active; chain is operating normally
     Inactive,
@@ -46,6 +63,28 @@ impl EmergencyPoWState {
     }
 }
 
+/// Highest finalized block as observed by the orchestrator.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FinalityView {
+    pub height: u64,
+    pub block_hash: String,
+    pub finalized_epoch: u64,
+}
+
+/// Persisted orchestrator state, restored on node restart.
+///
+/// SAFETY: Validated by `import_state` before any field is applied.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OrchestratorState {
+    pub protocol_version: u32,
+    pub epoch: u64,
+    pub active_mode: ConsensusMode,
+    pub pow_state: EmergencyPoWState,
+    /// Active validator ID → effective stake
+    pub validator_set: BTreeMap<String, u128>,
+    pub finality: FinalityView,
+}
+
 /// Consensus orchestrator: selects consensus mode deterministically.
 /// 
 /// SAFETY: This is the ONLY component that selects the consensus mode for an epoch.
@@ -68,6 +107,30 @@ pub struct ConsensusOrchestrator {
     
     /// Slashing threshold that triggers PoW
     emergency_slashing_threshold: u64,
+
+    /// Shared epoch source, advanced by `advance_epoch`
+    clock: EpochClock,
+
+    /// Snapshot engine driven at epoch boundaries (optional)
+    snapshot_engine: Option<SnapshotEngine>,
+
+    /// Self-healing pipeline told about every mode transition (optional)
+    self_healing: Option<SelfHealingOrchestrator>,
+
+    /// Mode most recently returned by `select_mode`
+    active_mode: ConsensusMode,
+
+    /// Active validator set, refreshed by `observe_validator_set`
+    validator_set: BTreeMap<String, u128>,
+
+    /// Finalized tip, refreshed by `observe_finality`
+    finality: FinalityView,
+
+    /// Finality gap beyond which PoS/PBFT are considered stalled
+    emergency_finality_gap: u64,
+
+    /// Finality gap at or below which finality is considered resumed
+    finality_resume_gap: u64,
 }
 
 impl ConsensusOrchestrator {
@@ -105,9 +168,125 @@ impl ConsensusOrchestrator {
             max_pow_epochs,
             emergency_participation_threshold,
             emergency_slashing_threshold,
+            clock: EpochClock::default(),
+            snapshot_engine: None,
+            self_healing: None,
+            active_mode: ConsensusMode::PosNormal,
+            validator_set: BTreeMap::new(),
+            finality: FinalityView::default(),
+            emergency_finality_gap: DEFAULT_EMERGENCY_FINALITY_GAP,
+            finality_resume_gap: DEFAULT_FINALITY_RESUME_GAP,
         })
     }
 
+    /// Override the finality gaps used by the emergency PoW criteria.
+    ///
+    /// `resume_gap` must be below `stall_gap` so the two criteria cannot
+    /// both hold and the mode cannot flap on a single boundary value.
+    pub fn with_finality_gaps(mut self, stall_gap: u64, resume_gap: u64) -> Result<Self, String> {
+        if resume_gap >= stall_gap {
+            return Err(format!(
+                "finality resume gap {} must be below stall gap {}",
+                resume_gap, stall_gap
+            ));
+        }
+        self.emergency_finality_gap = stall_gap;
+        self.finality_resume_gap = resume_gap;
+        Ok(self)
+    }
+
+    /// Attach a snapshot engine; the orchestrator adopts the engine's clock
+    /// so both observe the same epoch.
+    pub fn with_snapshot_engine(mut self, engine: SnapshotEngine) -> Self {
+        self.clock = engine.clock().clone();
+        self.snapshot_engine = Some(engine);
+        self
+    }
+
+    /// Advance to the next epoch.
+    ///
+    /// SAFETY: If the new epoch is a snapshot boundary, one snapshot per shard
+    /// is created from `shard_roots` in ascending shard order, so every honest
+    /// node derives the same snapshot IDs. Returns the IDs created (empty at
+    /// non-boundary epochs or when no snapshot engine is attached). On error
+    /// neither the epoch nor the snapshot engine changes.
+    pub fn advance_epoch(
+        &mut self,
+        global_height: u64,
+        transactions_merkle_root: &str,
+        shard_roots: &BTreeMap<ShardId, ShardStateRoot>,
+    ) -> Result<Vec<SnapshotId>, String> {
+        let epoch = self.clock.current_epoch()
+            .checked_add(1)
+            .ok_or_else(|| "Epoch counter overflow".to_string())?;
+
+        // Snapshots are built on a scratch copy of the engine and the clock
+        // moves only once they all succeed, so a failure changes nothing.
+        let snapshots = match self.snapshot_engine.as_ref() {
+            Some(engine) if engine.is_snapshot_boundary(epoch) => {
+                let mut scratch = engine.clone();
+                let mut created = Vec::with_capacity(shard_roots.len());
+                for (shard_id, root) in shard_roots {
+                    created.push(scratch.create_snapshot(
+                        *shard_id,
+                        EpochId(epoch),
+                        global_height,
+                        root.clone(),
+                        transactions_merkle_root.to_string(),
+                    )?);
+                }
+                Some((scratch, created))
+            }
+            _ => None,
+        };
+
+        self.clock.advance_to(epoch)?;
+        let Some((scratch, created)) = snapshots else {
+            return Ok(Vec::new());
+        };
+        self.snapshot_engine = Some(scratch);
+
+        info!("Epoch {}: created {} snapshot(s)", epoch, created.len());
+        Ok(created)
+    }
+
+    /// Current epoch according to the shared clock.
+    pub fn current_epoch(&self) -> u64 {
+        self.clock.current_epoch()
+    }
+
+    /// Get the attached snapshot engine (if any).
+    pub fn snapshot_engine(&self) -> Option<&SnapshotEngine> {
+        self.snapshot_engine.as_ref()
+    }
+
+    /// Attach a self-healing orchestrator; its incident detector follows
+    /// the active mode from now on, starting with the current one.
+    pub fn with_self_healing(mut self, mut healing: SelfHealingOrchestrator) -> Self {
+        healing.observe_consensus_mode(self.active_mode);
+        self.self_healing = Some(healing);
+        self
+    }
+
+    /// Get the attached self-healing orchestrator (if any).
+    pub fn self_healing(&self) -> Option<&SelfHealingOrchestrator> {
+        self.self_healing.as_ref()
+    }
+
+    /// Get the attached self-healing orchestrator mutably, to feed it
+    /// blocks, finality and state roots.
+    pub fn self_healing_mut(&mut self) -> Option<&mut SelfHealingOrchestrator> {
+        self.self_healing.as_mut()
+    }
+
+    /// Record the active mode and pass it on to the self-healing pipeline.
+    fn set_active_mode(&mut self, mode: ConsensusMode) {
+        self.active_mode = mode;
+        if let Some(healing) = self.self_healing.as_mut() {
+            healing.observe_consensus_mode(mode);
+        }
+    }
+
     /// Determine the consensus mode for an epoch.
     /// 
     /// SAFETY: This method produces identical results on all honest nodes
@@ -126,6 +305,12 @@ impl ConsensusOrchestrator {
     /// # Returns
     /// - `ConsensusMode` - The mode to use for this epoch
     pub fn select_mode(&mut self, epoch_id: u64, metrics: &ConsensusMetrics) -> ConsensusMode {
+        let mode = self.decide_mode(epoch_id, metrics);
+        self.set_active_mode(mode);
+        mode
+    }
+
+    fn decide_mode(&mut self, epoch_id: u64, metrics: &ConsensusMetrics) -> ConsensusMode {
         // SAFETY: Check PoW auto-exit condition first
         if let EmergencyPoWState::Active { activated_at_epoch } = self.pow_state {
             let pow_duration = epoch_id - activated_at_epoch;
@@ -203,6 +388,48 @@ impl ConsensusOrchestrator {
         false
     }
 
+    /// Whether PoS/PBFT have stalled badly enough to fall back to emergency PoW.
+    ///
+    /// SAFETY: Pure function of the inputs and immutable configuration, so all
+    /// honest nodes observing the same finality gap and active validator
+    /// count agree. Both conditions are required: finality has stalled for
+    /// more than `emergency_finality_gap` blocks AND the active set is below
+    /// `MIN_BFT_VALIDATORS`, so BFT finality cannot recover on its own.
+    pub fn should_activate_emergency_pow(&self, finality_gap: u64, active_validators: u32) -> bool {
+        finality_gap > self.emergency_finality_gap && active_validators < MIN_BFT_VALIDATORS
+    }
+
+    /// Whether emergency PoW can hand back to PoS/PBFT.
+    ///
+    /// SAFETY: Requires finality to have resumed (gap at or below
+    /// `finality_resume_gap`) with a BFT-sized active set again. The resume
+    /// gap sits below the stall gap, so activation and deactivation can
+    /// never both hold for the same inputs.
+    pub fn should_deactivate_emergency_pow(&self, finality_gap: u64, active_validators: u32) -> bool {
+        finality_gap <= self.finality_resume_gap && active_validators >= MIN_BFT_VALIDATORS
+    }
+
+    /// `should_activate_emergency_pow` applied to the observed finalized tip
+    /// and active validator set at `chain_height`.
+    pub fn observed_emergency_pow(&self, chain_height: u64) -> bool {
+        self.should_activate_emergency_pow(self.finality_gap(chain_height), self.observed_validator_count())
+    }
+
+    /// `should_deactivate_emergency_pow` applied to the observed finalized
+    /// tip and active validator set at `chain_height`.
+    pub fn observed_pow_recovery(&self, chain_height: u64) -> bool {
+        self.should_deactivate_emergency_pow(self.finality_gap(chain_height), self.observed_validator_count())
+    }
+
+    /// Blocks produced since the observed finalized tip.
+    pub fn finality_gap(&self, chain_height: u64) -> u64 {
+        chain_height.saturating_sub(self.finality.height)
+    }
+
+    fn observed_validator_count(&self) -> u32 {
+        u32::try_from(self.validator_set.len()).unwrap_or(u32::MAX)
+    }
+
     /// Verify that a block is valid for the given epoch.
     /// 
     /// SAFETY: Blocks with incorrect consensus mode are rejected unconditionally.
@@ -391,6 +618,140 @@ impl ConsensusOrchestrator {
         &self.config
     }
 
+    /// Mode most recently selected (PoS before the first selection).
+    pub fn active_mode(&self) -> ConsensusMode {
+        self.active_mode
+    }
+
+    /// Active validator set as last observed (ID → effective stake).
+    pub fn validator_set(&self) -> &BTreeMap<String, u128> {
+        &self.validator_set
+    }
+
+    /// Finalized tip as last observed.
+    pub fn finality(&self) -> &FinalityView {
+        &self.finality
+    }
+
+    /// Record the current active validator set and effective stakes.
+    pub fn observe_validator_set(&mut self, registry: &ValidatorRegistry) {
+        self.validator_set = registry
+            .get_active_validators()
+            .into_iter()
+            .map(|v| (v.id.clone(), v.effective_stake()))
+            .collect();
+    }
+
+    /// Record a newly finalized block.
+    ///
+    /// SAFETY: The finalized height never moves backwards.
+    pub fn observe_finality(&mut self, certificate: &FinalizyCertificate) -> Result<(), String> {
+        if certificate.block_height < self.finality.height {
+            return Err(format!(
+                "Finality regression: height {} < {}",
+                certificate.block_height, self.finality.height
+            ));
+        }
+        self.finality = FinalityView {
+            height: certificate.block_height,
+            block_hash: certificate.block_hash.clone(),
+            finalized_epoch: certificate.finalized_epoch,
+        };
+        Ok(())
+    }
+
+    /// Serialize the orchestrator state for persistence across restarts.
+    pub fn export_state(&self) -> Result<Vec<u8>, String> {
+        let state = OrchestratorState {
+            protocol_version: self.config.protocol_version,
+            epoch: self.current_epoch(),
+            active_mode: self.active_mode,
+            pow_state: self.pow_state,
+            validator_set: self.validator_set.clone(),
+            finality: self.finality.clone(),
+        };
+        bincode::serialize(&state).map_err(|e| format!("Failed to serialize orchestrator state: {}", e))
+    }
+
+    /// Restore state produced by `export_state`.
+    ///
+    /// SAFETY: Nothing is applied unless the whole state is consistent with
+    /// this node's configuration and does not move the epoch backwards.
+    pub fn import_state(&mut self, bytes: &[u8]) -> Result<(), String> {
+        let state: OrchestratorState = bincode::deserialize(bytes)
+            .map_err(|e| format!("Failed to deserialize orchestrator state: {}", e))?;
+        self.validate_state(&state)?;
+
+        self.clock.advance_to(state.epoch)?;
+        self.set_active_mode(state.active_mode);
+        self.pow_state = state.pow_state;
+        self.validator_set = state.validator_set;
+        self.finality = state.finality;
+
+        info!(
+            "Restored orchestrator state at epoch {} (mode={})",
+            state.epoch,
+            state.active_mode.as_str()
+        );
+        Ok(())
+    }
+
+    fn validate_state(&self, state: &OrchestratorState) -> Result<(), String> {
+        if state.protocol_version != self.config.protocol_version {
+            return Err(format!(
+                "Protocol version mismatch: expected {}, got {}",
+                self.config.protocol_version, state.protocol_version
+            ));
+        }
+
+        let current = self.current_epoch();
+        if state.epoch < current {
+            return Err(format!("Epoch regression: {} < current {}", state.epoch, current));
+        }
+
+        if !self.engines.contains_key(&state.active_mode) {
+            return Err(format!("No engine available for mode {:?}", state.active_mode));
+        }
+
+        match state.pow_state {
+            EmergencyPoWState::Active { activated_at_epoch } => {
+                if state.active_mode != ConsensusMode::EmergencyPow {
+                    return Err("Emergency PoW active but mode is not EmergencyPow".to_string());
+                }
+                if activated_at_epoch > state.epoch {
+                    return Err(format!("PoW activated in the future (epoch {})", activated_at_epoch));
+                }
+            }
+            EmergencyPoWState::Exited { exited_at_epoch } if exited_at_epoch > state.epoch => {
+                return Err(format!("PoW exited in the future (epoch {})", exited_at_epoch));
+            }
+            _ => {
+                if state.active_mode == ConsensusMode::EmergencyPow {
+                    return Err("EmergencyPow mode without active PoW state".to_string());
+                }
+            }
+        }
+
+        if let Some((id, _)) = state.validator_set.iter().find(|(_, stake)| **stake == 0) {
+            return Err(format!("Validator {} has zero stake", id));
+        }
+
+        if state.finality.finalized_epoch > state.epoch {
+            return Err(format!(
+                "Finality view from future epoch {}",
+                state.finality.finalized_epoch
+            ));
+        }
+        if state.finality.height < self.finality.height {
+            return Err(format!(
+                "Finality regression: height {} < {}",
+                state.finality.height, self.finality.height
+            ));
+        }
+
+        Ok(())
+    }
+
     /// Reset the PoW state (for testing only).
     #[cfg(test)]
     fn reset_pow_state(&mut self) {
@@ -548,4 +909,254 @@ mod tests {
         let mode = orchestrator.select_mode(0, &metrics);
         assert_eq!(mode, ConsensusMode::PbftFastFinality);
     }
+
+    fn shard_roots() -> BTreeMap<ShardId, ShardStateRoot> {
+        let mut roots = BTreeMap::new();
+        for shard in 0..2u64 {
+            roots.insert(
+                ShardId(shard),
+                ShardStateRoot {
+                    root_hash: format!("root{}", shard),
+                    tx_count: 0,
+                    height: 0,
+                },
+            );
+        }
+        roots
+    }
+
+    #[test]
+    fn test_advance_epoch_snapshots_at_boundaries_only() {
+        use bleep_state::snapshot_engine::SnapshotConfig;
+
+        let config = SnapshotConfig::new(3, 10, 100, 0.66).unwrap();
+        let mut orchestrator = create_test_orchestrator()
+            .with_snapshot_engine(SnapshotEngine::new(config, SnapshotId(0)));
+        let roots = shard_roots();
+
+        for epoch in 1..=7u64 {
+            let created = orchestrator.advance_epoch(epoch * 1000, "txs", &roots).unwrap();
+            assert_eq!(orchestrator.current_epoch(), epoch);
+
+            if epoch % 3 == 0 {
+                assert_eq!(created.len(), 2, "epoch {} is a boundary", epoch);
+                let engine = orchestrator.snapshot_engine().unwrap();
+                for id in created {
+                    assert_eq!(engine.get_snapshot(id).unwrap().epoch_id.as_u64(), epoch);
+                }
+            } else {
+                assert!(created.is_empty(), "epoch {} is not a boundary", epoch);
+            }
+        }
+
+        assert_eq!(orchestrator.snapshot_engine().unwrap().total_snapshots(), 4);
+    }
+
+    #[test]
+    fn test_advance_epoch_failure_leaves_epoch_unchanged() {
+        use bleep_state::snapshot_engine::SnapshotConfig;
+
+        let config = SnapshotConfig::new(3, 10, 100, 0.66).unwrap();
+        let engine = SnapshotEngine::new(config, SnapshotId(0)).with_clock(EpochClock::new(u64::MAX));
+        let mut orchestrator = create_test_orchestrator().with_snapshot_engine(engine);
+
+        assert!(orchestrator.advance_epoch(1000, "txs", &shard_roots()).is_err());
+        assert_eq!(orchestrator.current_epoch(), u64::MAX);
+        assert_eq!(orchestrator.snapshot_engine().unwrap().total_snapshots(), 0);
+    }
+
+    #[test]
+    fn test_advance_epoch_without_snapshot_engine() {
+        let mut orchestrator = create_test_orchestrator();
+        assert!(orchestrator.advance_epoch(1000, "txs", &shard_roots()).unwrap().is_empty());
+        assert_eq!(orchestrator.current_epoch(), 1);
+        assert!(orchestrator.snapshot_engine().is_none());
+    }
+
+    #[test]
+    fn test_state_round_trip_preserves_mode_and_epoch() {
+        let mut orchestrator = create_test_orchestrator();
+        let low_participation = ConsensusMetrics {
+            validator_participation: 0.50,
+            block_proposal_time_ms: 100,
+            rejected_block_count: 0,
+            slashing_event_count: 0,
+            finality_latency_blocks: 1,
+            network_utilization: 0.5,
+        };
+        for _ in 0..4 {
+            orchestrator.advance_epoch(0, "txs", &BTreeMap::new()).unwrap();
+        }
+        assert_eq!(orchestrator.select_mode(4, &low_participation), ConsensusMode::EmergencyPow);
+
+        let mut registry = ValidatorRegistry::new();
+        for id in ["v1", "v2"] {
+            let validator = crate::validator_identity::ValidatorIdentity::new(
+                id.to_string(), vec![0u8; 1568], format!("{}_key", id), 1_000_000, 0,
+            ).unwrap();
+            registry.register_validator(validator).unwrap();
+            registry.activate_validator(id).unwrap();
+        }
+        orchestrator.observe_validator_set(&registry);
+        let cert = FinalizyCertificate::new(
+            3990, "tip".to_string(), 3, "POS_NORMAL".to_string(), "root".to_string(), 0, 1,
+        ).unwrap();
+        orchestrator.observe_finality(&cert).unwrap();
+
+        let bytes = orchestrator.export_state().unwrap();
+        let mut restarted = create_test_orchestrator();
+        restarted.import_state(&bytes).unwrap();
+
+        assert_eq!(restarted.current_epoch(), 4);
+        assert_eq!(restarted.active_mode(), ConsensusMode::EmergencyPow);
+        assert_eq!(restarted.pow_state(), EmergencyPoWState::Active { activated_at_epoch: 4 });
+        assert_eq!(restarted.validator_set(), orchestrator.validator_set());
+        assert_eq!(restarted.finality(), orchestrator.finality());
+        assert_eq!(restarted.finality().height, 3990);
+        assert_eq!(restarted.export_state().unwrap(), bytes);
+    }
+
+    #[test]
+    fn test_import_rejects_inconsistent_state() {
+        let mut ahead = create_test_orchestrator();
+        for _ in 0..5 {
+            ahead.advance_epoch(0, "txs", &BTreeMap::new()).unwrap();
+        }
+        let stale = create_test_orchestrator().export_state().unwrap();
+
+        // Epoch regression
+        assert!(ahead.import_state(&stale).is_err());
+        assert_eq!(ahead.current_epoch(), 5);
+
+        // PoW claims to have activated after the exported epoch
+        let mut state: OrchestratorState = bincode::deserialize(&stale).unwrap();
+        state.active_mode = ConsensusMode::EmergencyPow;
+        state.pow_state = EmergencyPoWState::Active { activated_at_epoch: 9 };
+        let mut fresh = create_test_orchestrator();
+        assert!(fresh.import_state(&bincode::serialize(&state).unwrap()).is_err());
+
+        // Garbage bytes
+        assert!(fresh.import_state(&[0xff, 0x01]).is_err());
+        assert_eq!(fresh.current_epoch(), 0);
+        assert_eq!(fresh.active_mode(), ConsensusMode::PosNormal);
+    }
+
+    #[test]
+    fn test_emergency_pow_from_observed_state() {
+        let mut orchestrator = create_test_orchestrator();
+        let mut registry = ValidatorRegistry::new();
+        for id in ["v1", "v2", "v3"] {
+            let validator = crate::validator_identity::ValidatorIdentity::new(
+                id.to_string(), vec![0u8; 1568], format!("{}_key", id), 1_000_000, 0,
+            ).unwrap();
+            registry.register_validator(validator).unwrap();
+            registry.activate_validator(id).unwrap();
+        }
+        orchestrator.observe_validator_set(&registry);
+        let cert = FinalizyCertificate::new(
+            500, "tip".to_string(), 0, "POS_NORMAL".to_string(), "root".to_string(), 0, 1,
+        ).unwrap();
+        orchestrator.observe_finality(&cert).unwrap();
+
+        // Three validators cannot run BFT; a stall past the gap warrants PoW
+        assert_eq!(orchestrator.finality_gap(520), 20);
+        assert!(!orchestrator.observed_emergency_pow(500 + DEFAULT_EMERGENCY_FINALITY_GAP));
+        assert!(orchestrator.observed_emergency_pow(501 + DEFAULT_EMERGENCY_FINALITY_GAP));
+        assert!(!orchestrator.observed_pow_recovery(505));
+
+        // A fourth validator plus a caught-up tip allows handing back
+        let validator = crate::validator_identity::ValidatorIdentity::new(
+            "v4".to_string(), vec![0u8; 1568], "v4_key".to_string(), 1_000_000, 0,
+        ).unwrap();
+        registry.register_validator(validator).unwrap();
+        registry.activate_validator("v4").unwrap();
+        orchestrator.observe_validator_set(&registry);
+        assert!(orchestrator.observed_pow_recovery(505));
+        assert!(!orchestrator.observed_emergency_pow(10_000));
+    }
+
+    #[test]
+    fn test_emergency_pow_activation_boundary() {
+        let orchestrator = create_test_orchestrator();
+        let gap = DEFAULT_EMERGENCY_FINALITY_GAP;
+
+        assert!(orchestrator.should_activate_emergency_pow(gap + 1, MIN_BFT_VALIDATORS - 1));
+        // Exactly at the stall gap, or exactly at the BFT minimum, is not enough
+        assert!(!orchestrator.should_activate_emergency_pow(gap, MIN_BFT_VALIDATORS - 1));
+        assert!(!orchestrator.should_activate_emergency_pow(gap + 1, MIN_BFT_VALIDATORS));
+    }
+
+    #[test]
+    fn test_emergency_pow_not_activated_on_single_condition() {
+        let orchestrator = create_test_orchestrator();
+
+        // Long stall but a healthy validator set
+        assert!(!orchestrator.should_activate_emergency_pow(10_000, 50));
+        // Small validator set but finality keeping up
+        assert!(!orchestrator.should_activate_emergency_pow(0, 1));
+    }
+
+    #[test]
+    fn test_emergency_pow_deactivates_after_recovery() {
+        let orchestrator = create_test_orchestrator()
+            .with_finality_gaps(50, 5)
+            .unwrap();
+
+        assert!(orchestrator.should_activate_emergency_pow(51, 2));
+        assert!(!orchestrator.should_deactivate_emergency_pow(51, 2));
+
+        // Validators back but finality still catching up
+        assert!(!orchestrator.should_deactivate_emergency_pow(20, MIN_BFT_VALIDATORS));
+        assert!(orchestrator.should_deactivate_emergency_pow(5, MIN_BFT_VALIDATORS));
+        assert!(!orchestrator.should_activate_emergency_pow(5, MIN_BFT_VALIDATORS));
+
+        assert!(create_test_orchestrator().with_finality_gaps(5, 5).is_err());
+    }
+
+    #[test]
+    fn test_mode_transitions_reach_self_healing_detector() {
+        use crate::incident_detector::DetectionParams;
+        use crate::recovery_controller::{ProtocolParams, RecoveryPreconditions};
+        use crate::self_healing_orchestrator::RecoveryStrategy;
+
+        let healing = SelfHealingOrchestrator::new(
+            DetectionParams::default(),
+            vec!["v1".to_string()],
+            ProtocolParams::default(),
+            RecoveryPreconditions::default(),
+            RecoveryStrategy::default(),
+        );
+        let mut orchestrator = create_test_orchestrator().with_self_healing(healing);
+        let mode_seen = |o: &ConsensusOrchestrator| o.self_healing().unwrap().consensus_mode();
+        assert_eq!(mode_seen(&orchestrator), ConsensusMode::PosNormal);
+
+        let mut metrics = ConsensusMetrics {
+            validator_participation: 0.50,
+            block_proposal_time_ms: 100,
+            rejected_block_count: 0,
+            slashing_event_count: 0,
+            finality_latency_blocks: 1,
+            network_utilization: 0.5,
+        };
+        orchestrator.select_mode(0, &metrics);
+        assert_eq!(mode_seen(&orchestrator), ConsensusMode::EmergencyPow);
+
+        metrics.validator_participation = 0.90;
+        metrics.finality_latency_blocks = 20;
+        orchestrator.select_mode(0, &metrics);
+        assert_eq!(mode_seen(&orchestrator), ConsensusMode::PbftFastFinality);
+
+        // A restored mode reaches the detector too
+        let bytes = orchestrator.export_state().unwrap();
+        let healing = SelfHealingOrchestrator::new(
+            DetectionParams::default(),
+            vec!["v1".to_string()],
+            ProtocolParams::default(),
+            RecoveryPreconditions::default(),
+            RecoveryStrategy::default(),
+        );
+        let mut restarted = create_test_orchestrator().with_self_healing(healing);
+        restarted.import_state(&bytes).unwrap();
+        assert_eq!(mode_seen(&restarted), ConsensusMode::PbftFastFinality);
+    }
 }
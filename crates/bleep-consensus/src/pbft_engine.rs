@@ -10,6 +10,8 @@
 
 use crate::epoch::EpochState;
 use crate::engine::{ConsensusEngine, ConsensusError};
+use crate::finality::ValidatorSignature;
+use crate::orchestrator::MIN_BFT_VALIDATORS;
 use bleep_core::block::{Block, Transaction, ConsensusMode};
 use bleep_core::blockchain::BlockchainState;
 use log::{info, warn};
@@ -85,6 +87,9 @@ pub struct PbftConsensusEngine {
     /// The set of validator IDs that are currently registered.
     /// Only votes from known validators are counted.
     known_validators: std::collections::HashSet<String>,
+
+    /// SPHINCS+ public keys verifying each validator's signed votes.
+    validator_keys: HashMap<String, Vec<u8>>,
 }
 
 impl PbftConsensusEngine {
@@ -92,11 +97,15 @@ impl PbftConsensusEngine {
     ///
     /// `validator_ids` is the full validator set for the current epoch.
     /// The quorum is computed as `2/3 * len + 1` (BFT threshold).
+    ///
+    /// SAFETY: Fewer than `MIN_BFT_VALIDATORS` cannot tolerate a single
+    /// Byzantine fault, so such a set is rejected rather than finalized by.
     pub fn new(validator_id: String, validator_ids: Vec<String>) -> Result<Self, ConsensusError> {
         let total_validators = validator_ids.len();
-        if total_validators == 0 {
-            return Err(ConsensusError::ProposalRejected {
-                reason: "total_validators must be > 0".to_string(),
+        if total_validators < MIN_BFT_VALIDATORS as usize {
+            return Err(ConsensusError::InsufficientValidators {
+                active: total_validators,
+                required: MIN_BFT_VALIDATORS as usize,
             });
         }
 
@@ -112,6 +121,7 @@ impl PbftConsensusEngine {
             prepare_votes: HashMap::new(),
             commit_votes:  HashMap::new(),
             known_validators,
+            validator_keys: HashMap::new(),
         })
     }
 
@@ -122,9 +132,15 @@ impl PbftConsensusEngine {
         self.quorum_size       = (self.total_validators * 2) / 3 + 1;
     }
 
+    /// Register the public key that verifies a validator's signed votes.
+    pub fn set_validator_key(&mut self, validator_id: String, public_key: Vec<u8>) {
+        self.validator_keys.insert(validator_id, public_key);
+    }
+
     /// Remove a validator (e.g. after slashing or exit).
     pub fn remove_validator(&mut self, validator_id: &str) {
         self.known_validators.remove(validator_id);
+        self.validator_keys.remove(validator_id);
         self.total_validators  = self.known_validators.len();
         self.quorum_size       = if self.total_validators > 0 {
             (self.total_validators * 2) / 3 + 1
@@ -135,6 +151,7 @@ impl PbftConsensusEngine {
     ///
     /// SAFETY: Block must already be produced by PoS. PBFT only finalizes.
     fn pre_prepare(&mut self, block_height: u64, block: &Block) -> Result<(), ConsensusError> {
+        self.require_validators()?;
         if self.finalized_blocks.contains_key(&block_height) {
             return Err(ConsensusError::ProposalRejected {
                 reason: format!("Block {} already in finalization pipeline", block_height),
@@ -161,6 +178,8 @@ impl PbftConsensusEngine {
     /// Unknown validators and duplicate votes are both silently rejected so
     /// that vote stuffing or replay cannot manufacture a false quorum.
     fn process_prepare(&mut self, block_height: u64, preparer_id: &str) -> Result<(), ConsensusError> {
+        self.require_validators()?;
+
         // Only accept votes for blocks in Proposed state
         if self.finalized_blocks.get(&block_height) != Some(&PbftBlockState::Proposed) {
             return Ok(()); // silently ignore out-of-order or duplicate messages
@@ -197,6 +216,8 @@ impl PbftConsensusEngine {
     /// only committed once `|commit_votes| >= quorum_size` distinct known
     /// validators have committed.
     fn process_commit(&mut self, block_height: u64, committer_id: &str) -> Result<(), ConsensusError> {
+        self.require_validators()?;
+
         // Only accept commits for blocks that have reached Prepared
         if self.finalized_blocks.get(&block_height) != Some(&PbftBlockState::Prepared) {
             return Ok(());
@@ -227,6 +248,103 @@ impl PbftConsensusEngine {
         Ok(())
     }
 
+    /// Reject vote processing once removals leave fewer than
+    /// `MIN_BFT_VALIDATORS`.
+    ///
+    /// Below 3f + 1 with f = 1 the 2/3 + 1 quorum no longer tolerates a
+    /// Byzantine fault (and an empty set keeps `quorum_size` at 1), so no
+    /// tally would be meaningful.
+    fn require_validators(&self) -> Result<(), ConsensusError> {
+        if self.total_validators < MIN_BFT_VALIDATORS as usize {
+            return Err(ConsensusError::InsufficientValidators {
+                active: self.total_validators,
+                required: MIN_BFT_VALIDATORS as usize,
+            });
+        }
+        Ok(())
+    }
+
+    /// Bytes a validator signs to vote for moving from `view` to `new_view`.
+    pub fn view_change_payload(view: u64, new_view: u64) -> Vec<u8> {
+        let mut payload = b"BLEEP-PBFT-VIEW-CHANGE-V1".to_vec();
+        payload.extend_from_slice(&view.to_le_bytes());
+        payload.extend_from_slice(&new_view.to_le_bytes());
+        payload
+    }
+
+    /// Move to `new_view` once a quorum of validators has voted for it.
+    ///
+    /// Liveness path for a stuck primary: the view advances only when
+    /// `quorum_size` distinct known validators supplied a view-change vote
+    /// whose signature verifies against their registered key over
+    /// `view_change_payload(current_view, new_view)`. Votes from unknown or
+    /// keyless validators, invalid signatures and duplicates are ignored, as
+    /// for prepare/commit. On success every block not yet committed leaves
+    /// the pipeline so the new primary can re-propose it; committed blocks
+    /// are untouched.
+    pub fn trigger_view_change(
+        &mut self,
+        new_view: u64,
+        votes: &[ValidatorSignature],
+    ) -> Result<(), ConsensusError> {
+        self.require_validators()?;
+        if new_view <= self.current_view {
+            return Err(ConsensusError::ProposalRejected {
+                reason: format!(
+                    "View change to {} does not advance current view {}",
+                    new_view, self.current_view
+                ),
+            });
+        }
+
+        let payload = Self::view_change_payload(self.current_view, new_view);
+        let voters: std::collections::HashSet<&str> = votes
+            .iter()
+            .filter(|v| self.known_validators.contains(&v.validator_id))
+            .filter(|v| {
+                self.validator_keys.get(&v.validator_id).is_some_and(|pk| {
+                    bleep_crypto::tx_signer::verify_tx_signature(&payload, &v.signature, pk)
+                })
+            })
+            .map(|v| v.validator_id.as_str())
+            .collect();
+        if voters.len() < self.quorum_size {
+            return Err(ConsensusError::PbftQuorumNotReached {
+                validators: voters.len(),
+                threshold: self.quorum_size,
+            });
+        }
+
+        self.current_view = new_view;
+        self.finalized_blocks.retain(|_, state| *state == PbftBlockState::Committed);
+        self.prepare_votes.clear();
+        self.commit_votes.clear();
+
+        info!(
+            "PBFT: View change to {} ({} votes, quorum={}), new primary {}",
+            new_view,
+            voters.len(),
+            self.quorum_size,
+            self.primary().unwrap_or_default()
+        );
+        Ok(())
+    }
+
+    /// Primary for the current view: validator IDs sorted, indexed by `view mod n`.
+    pub fn primary(&self) -> Option<String> {
+        let mut ids: Vec<&String> = self.known_validators.iter().collect();
+        if ids.is_empty() {
+            return None;
+        }
+        ids.sort();
+        Some(ids[(self.current_view % ids.len() as u64) as usize].clone())
+    }
+
+    /// Current PBFT view number.
+    pub fn current_view(&self) -> u64 {
+        self.current_view
+    }
+
     /// Returns how many distinct prepare votes have been received for `block_height`.
     pub fn prepare_vote_count(&self, block_height: u64) -> usize {
         self.prepare_votes.get(&block_height).map_or(0, |v| v.len())
@@ -366,17 +484,17 @@ mod tests {
     fn test_pbft_engine_creation() {
         let engine = PbftConsensusEngine::new(
             "validator1".to_string(),
-            validators(&["validator1", "v2", "v3"]),
+            validators(&["validator1", "v2", "v3", "v4"]),
         ).unwrap();
         assert_eq!(engine.validator_id, "validator1");
-        assert_eq!(engine.total_validators, 3);
-        assert_eq!(engine.quorum_size, 3); // 2/3 + 1 of 3 = 3
+        assert_eq!(engine.total_validators, 4);
+        assert_eq!(engine.quorum_size, 3); // 2/3 + 1 of 4 = 3
     }
 
     #[test]
     fn test_pbft_quorum_size_calculation() {
-        let e3 = PbftConsensusEngine::new("v".to_string(), validators(&["v","v2","v3"])).unwrap();
-        assert_eq!(e3.quorum_size, 3);
+        let e4 = PbftConsensusEngine::new("v".to_string(), validators(&["v","v2","v3","v4"])).unwrap();
+        assert_eq!(e4.quorum_size, 3);
 
         let ids7: Vec<String> = (1..=7).map(|i| format!("v{i}")).collect();
         let e7 = PbftConsensusEngine::new("v1".to_string(), ids7).unwrap();
@@ -393,12 +511,23 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_pbft_rejects_validator_sets_below_bft_minimum() {
+        for n in 1..MIN_BFT_VALIDATORS as usize {
+            let ids: Vec<String> = (1..=n).map(|i| format!("v{i}")).collect();
+            assert!(matches!(
+                PbftConsensusEngine::new("v1".to_string(), ids),
+                Err(ConsensusError::InsufficientValidators { active, required: 4 }) if active == n
+            ));
+        }
+    }
+
     #[test]
     fn test_pbft_real_quorum_required() {
-        // 3 validators: quorum = 3
+        // 4 validators: quorum = 3
         let mut engine = PbftConsensusEngine::new(
             "v1".to_string(),
-            validators(&["v1", "v2", "v3"]),
+            validators(&["v1", "v2", "v3", "v4"]),
         ).unwrap();
         let block = Block::new(100, vec![], "hash99".to_string());
         engine.pre_prepare(100, &block).unwrap();
@@ -430,7 +559,7 @@ mod tests {
     fn test_pbft_unknown_validator_vote_ignored() {
         let mut engine = PbftConsensusEngine::new(
             "v1".to_string(),
-            validators(&["v1", "v2", "v3"]),
+            validators(&["v1", "v2", "v3", "v4"]),
         ).unwrap();
         let block = Block::new(200, vec![], "h200".to_string());
         engine.pre_prepare(200, &block).unwrap();
@@ -446,7 +575,7 @@ mod tests {
     fn test_pbft_duplicate_vote_not_double_counted() {
         let mut engine = PbftConsensusEngine::new(
             "v1".to_string(),
-            validators(&["v1", "v2", "v3"]),
+            validators(&["v1", "v2", "v3", "v4"]),
         ).unwrap();
         let block = Block::new(300, vec![], "h300".to_string());
         engine.pre_prepare(300, &block).unwrap();
@@ -464,7 +593,7 @@ mod tests {
     fn test_pbft_health_status_perfect() {
         let mut engine = PbftConsensusEngine::new(
             "v1".to_string(),
-            validators(&["v1", "v2", "v3"]),
+            validators(&["v1", "v2", "v3", "v4"]),
         ).unwrap();
         let block = Block::new(100, vec![], "hash99".to_string());
         engine.pre_prepare(100, &block).unwrap();
@@ -477,7 +606,7 @@ mod tests {
     fn test_pbft_health_status_degraded() {
         let mut engine = PbftConsensusEngine::new(
             "v1".to_string(),
-            validators(&["v1", "v2", "v3"]),
+            validators(&["v1", "v2", "v3", "v4"]),
         ).unwrap();
         let block1 = Block::new(100, vec![], "hash99".to_string());
         engine.pre_prepare(100, &block1).unwrap();
@@ -490,4 +619,140 @@ mod tests {
         // 1 finalized / 2 total = 0.5
         assert_eq!(engine.health_status(), 0.5);
     }
+
+    #[test]
+    fn test_pbft_tally_rejects_empty_validator_set() {
+        let mut engine = PbftConsensusEngine::new(
+            "v1".to_string(),
+            validators(&["v1", "v2", "v3", "v4"]),
+        ).unwrap();
+        let block = Block::new(100, vec![], "hash99".to_string());
+        engine.pre_prepare(100, &block).unwrap();
+
+        // One removal already drops below 3f + 1
+        engine.remove_validator("v4");
+        assert!(matches!(
+            engine.process_prepare(100, "v1"),
+            Err(ConsensusError::InsufficientValidators { active: 3, required: 4 })
+        ));
+
+        for id in ["v1", "v2", "v3"] {
+            engine.remove_validator(id);
+        }
+        assert!(matches!(
+            engine.process_prepare(100, "v1"),
+            Err(ConsensusError::InsufficientValidators { active: 0, required: 4 })
+        ));
+        assert!(engine.process_commit(100, "v1").is_err());
+        assert!(engine.pre_prepare(101, &block).is_err());
+        assert_eq!(engine.prepare_vote_count(100), 0);
+    }
+
+    /// Signing keys for v1..v4
+    fn validator_keys() -> HashMap<String, (Vec<u8>, Vec<u8>)> {
+        ["v1", "v2", "v3", "v4"]
+            .iter()
+            .map(|id| (id.to_string(), bleep_crypto::tx_signer::generate_tx_keypair()))
+            .collect()
+    }
+
+    fn keyed_engine(
+        self_id: &str,
+        ids: &[&str],
+        keys: &HashMap<String, (Vec<u8>, Vec<u8>)>,
+    ) -> PbftConsensusEngine {
+        let mut engine = PbftConsensusEngine::new(self_id.to_string(), validators(ids)).unwrap();
+        for (id, (pk, _)) in keys {
+            engine.set_validator_key(id.clone(), pk.clone());
+        }
+        engine
+    }
+
+    fn view_votes(
+        keys: &HashMap<String, (Vec<u8>, Vec<u8>)>,
+        view: u64,
+        new_view: u64,
+        ids: &[&str],
+    ) -> Vec<ValidatorSignature> {
+        let payload = PbftConsensusEngine::view_change_payload(view, new_view);
+        ids.iter()
+            .map(|id| ValidatorSignature {
+                validator_id: id.to_string(),
+                signature: keys.get(*id).map_or(vec![1], |(_, sk)| {
+                    bleep_crypto::tx_signer::sign_tx_payload(&payload, sk).unwrap()
+                }),
+                voting_power: 1,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_pbft_view_change_rotates_primary_deterministically() {
+        // 4 validators: quorum = 3
+        let keys = validator_keys();
+        let mut a = keyed_engine("v1", &["v1", "v2", "v3", "v4"], &keys);
+        let mut b = keyed_engine("v3", &["v4", "v3", "v2", "v1"], &keys);
+        assert_eq!(a.primary(), Some("v1".to_string()));
+
+        let block = Block::new(100, vec![], "hash99".to_string());
+        a.pre_prepare(100, &block).unwrap();
+        a.process_prepare(100, "v2").unwrap();
+
+        a.trigger_view_change(1, &view_votes(&keys, 0, 1, &["v2", "v3", "v4"])).unwrap();
+        b.trigger_view_change(1, &view_votes(&keys, 0, 1, &["v1", "v3", "v4"])).unwrap();
+        assert_eq!(a.current_view(), 1);
+        assert_eq!(a.primary(), Some("v2".to_string()));
+        assert_eq!(a.primary(), b.primary());
+
+        // Round reset: the uncommitted block left the pipeline
+        assert_eq!(a.block_state(100), None);
+        assert_eq!(a.prepare_vote_count(100), 0);
+
+        a.trigger_view_change(6, &view_votes(&keys, 1, 6, &["v1", "v2", "v3"])).unwrap();
+        assert_eq!(a.primary(), Some("v3".to_string()));
+        assert!(a.trigger_view_change(6, &view_votes(&keys, 6, 6, &["v1", "v2", "v3"])).is_err());
+    }
+
+    #[test]
+    fn test_pbft_view_change_sub_quorum_rejected() {
+        let keys = validator_keys();
+        let mut engine = keyed_engine("v1", &["v1", "v2", "v3", "v4"], &keys);
+
+        // Duplicates and unknown validators do not make up the shortfall
+        let votes = view_votes(&keys, 0, 1, &["v2", "v2", "v3", "attacker"]);
+        assert!(matches!(
+            engine.trigger_view_change(1, &votes),
+            Err(ConsensusError::PbftQuorumNotReached { validators: 2, threshold: 3 })
+        ));
+
+        let mut unsigned = view_votes(&keys, 0, 1, &["v2", "v3", "v4"]);
+        unsigned[2].signature.clear();
+        assert!(engine.trigger_view_change(1, &unsigned).is_err());
+
+        assert_eq!(engine.current_view(), 0);
+        assert_eq!(engine.primary(), Some("v1".to_string()));
+    }
+
+    #[test]
+    fn test_pbft_view_change_rejects_forged_or_replayed_votes() {
+        let keys = validator_keys();
+        let mut engine = keyed_engine("v1", &["v1", "v2", "v3", "v4"], &keys);
+
+        // Non-empty garbage signatures no longer count
+        let mut forged = view_votes(&keys, 0, 1, &["v2", "v3", "v4"]);
+        forged[0].signature = vec![1; 64];
+        assert!(matches!(
+            engine.trigger_view_change(1, &forged),
+            Err(ConsensusError::PbftQuorumNotReached { validators: 2, threshold: 3 })
+        ));
+
+        // Votes for a different target view cannot be reused
+        let other_target = view_votes(&keys, 0, 2, &["v2", "v3", "v4"]);
+        assert!(engine.trigger_view_change(1, &other_target).is_err());
+
+        // A validator without a registered key cannot vote
+        let mut keyless = PbftConsensusEngine::new("v1".to_string(), validators(&["v1", "v2", "v3", "v4"])).unwrap();
+        assert!(keyless.trigger_view_change(1, &view_votes(&keys, 0, 1, &["v2", "v3", "v4"])).is_err());
+        assert_eq!(engine.current_view(), 0);
+    }
 }
@@ -163,6 +163,24 @@ impl PerformanceBenchmark {
         tps
     }
 
+    /// Record one block from live production (wall-clock timings).
+    pub fn record_block(&mut self, txs: usize, proof_time_ms: u64, block_time_ms: u64) {
+        self.total_blocks   += 1;
+        self.total_block_ms += block_time_ms;
+        self.total_proof_ms += proof_time_ms;
+        if txs >= MAX_TXS_PER_BLOCK { self.full_blocks += 1; }
+        self.total_txs += txs as u64;
+
+        let now_secs = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        self.tps_window.record(now_secs, txs as u64);
+        let current_tps = self.tps_window.current_tps();
+        if current_tps > self.peak_tps { self.peak_tps = current_tps; }
+        if current_tps < self.min_tps  { self.min_tps = current_tps; }
+    }
+
     /// Run the full benchmark (simulated, not wall-clock — for CI use).
     pub fn run_simulated(&mut self) -> BenchmarkResult {
         for second in 0..self.duration_secs {
@@ -100,10 +100,7 @@ impl PoSConsensusEngine {
             .collect();
 
         if active.is_empty() {
-            return Err(ConsensusError::InsufficientValidatorParticipation {
-                participation_rate: 0.0,
-                threshold: 0.66,
-            });
+            return Err(ConsensusError::InsufficientValidators { active: 0, required: 1 });
         }
 
         // Compute total stake
@@ -352,6 +349,15 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_select_proposer_empty_validator_set() {
+        let result = PoSConsensusEngine::select_proposer(100, &[], "hash1");
+        assert!(matches!(
+            result,
+            Err(ConsensusError::InsufficientValidators { active: 0, required: 1 })
+        ));
+    }
+
     #[test]
     fn test_check_validator_health_active() {
         let validator = ValidatorStake {
@@ -11,7 +11,7 @@
 
 use serde::{Serialize, Deserialize};
 use sha2::{Digest, Sha256};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use log::{info, warn, error};
 use thiserror::Error;
 use crate::incident_detector::{IncidentReport, IncidentType, RecoveryAction};
@@ -61,6 +61,10 @@ pub enum RecoveryError {
     InvalidState,
 }
 
+/// Maximum incidents held while recovery is cooling down. On overflow the
+/// lowest-severity incident is dropped.
+pub const MAX_PENDING_INCIDENTS: usize = 16;
+
 /// Recovery precondition checks (deterministic, conservative)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RecoveryPreconditions {
@@ -155,6 +159,9 @@ pub struct RecoveryController {
     
     /// Recovery preconditions
     preconditions: RecoveryPreconditions,
+    
+    /// Incidents raised during cooldown, keyed by incident hash (retry order)
+    pending_incidents: BTreeMap<Vec<u8>, IncidentReport>,
 }
 
 impl RecoveryController {
@@ -171,6 +178,7 @@ impl RecoveryController {
             current_params: initial_params,
             frozen_validators: HashMap::new(),
             preconditions,
+            pending_incidents: BTreeMap::new(),
         }
     }
     
@@ -208,6 +216,9 @@ impl RecoveryController {
     }
     
     /// Execute recovery for an incident (deterministic)
+    ///
+    /// SAFETY: An incident rejected by cooldown is queued, not dropped; it is
+    /// retried by `process_pending_incidents` once the cooldown expires.
     pub fn execute_recovery(
         &mut self,
         incident: &IncidentReport,
@@ -217,7 +228,8 @@ impl RecoveryController {
         self.check_recovery_preconditions(&incident.incident_type, current_epoch)?;
         
         // Check cooldown
-        if current_epoch < self.last_recovery_epoch + self.preconditions.recovery_cooldown_epochs {
+        if !self.cooldown_elapsed(current_epoch) {
+            self.enqueue_pending(incident);
             return Err(RecoveryError::CooldownNotSatisfied);
         }
         
@@ -256,6 +268,57 @@ impl RecoveryController {
         Ok(executed_actions)
     }
     
+    /// Retry the next queued incident (lowest incident hash first) if the
+    /// cooldown has expired. Returns an empty log if nothing was retried.
+    pub fn process_pending_incidents(
+        &mut self,
+        current_epoch: u64,
+    ) -> Result<Vec<RecoveryLog>, RecoveryError> {
+        if !self.cooldown_elapsed(current_epoch) {
+            return Ok(Vec::new());
+        }
+        
+        let incident = match self.pending_incidents.pop_first() {
+            Some((_, incident)) => incident,
+            None => return Ok(Vec::new()),
+        };
+        
+        info!("Retrying queued incident: {}", incident.incident_type.as_str());
+        self.execute_recovery(&incident, current_epoch)
+    }
+    
+    /// Incidents waiting for the cooldown to expire, in retry order
+    pub fn pending_incidents(&self) -> impl Iterator<Item = &IncidentReport> {
+        self.pending_incidents.values()
+    }
+    
+    fn cooldown_elapsed(&self, current_epoch: u64) -> bool {
+        current_epoch >= self.last_recovery_epoch + self.preconditions.recovery_cooldown_epochs
+    }
+    
+    /// Queue an incident for retry, evicting the lowest-severity entry on
+    /// overflow (ties: the one retried last)
+    fn enqueue_pending(&mut self, incident: &IncidentReport) {
+        self.pending_incidents.insert(incident.incident_hash.clone(), incident.clone());
+        
+        if self.pending_incidents.len() > MAX_PENDING_INCIDENTS {
+            let evicted = self.pending_incidents.iter()
+                .min_by(|(hash_a, a), (hash_b, b)| {
+                    a.severity.cmp(&b.severity).then_with(|| hash_b.cmp(hash_a))
+                })
+                .map(|(hash, _)| hash.clone());
+            if let Some(hash) = evicted {
+                if let Some(dropped) = self.pending_incidents.remove(&hash) {
+                    warn!(
+                        "Pending incident queue full, dropped {:?} {}",
+                        dropped.severity,
+                        dropped.incident_type.as_str()
+                    );
+                }
+            }
+        }
+    }
+    
     /// Execute individual recovery action
     fn execute_action(
         &mut self,
@@ -590,4 +653,76 @@ mod tests {
         assert!(controller.is_validator_frozen("val-1", 10));
         assert!(!controller.is_validator_frozen("val-1", 16));
     }
+    
+    fn finality_incident(hash: u8, severity: crate::incident_detector::IncidentSeverity) -> IncidentReport {
+        IncidentReport {
+            incident_id: vec![hash],
+            incident_type: IncidentType::FinalityDelay,
+            severity,
+            detected_epoch: 12,
+            description: "Finality delay".to_string(),
+            evidence: crate::incident_detector::IncidentEvidence::FinalityGap {
+                last_finalized: 1,
+                current_epoch: 12,
+                gap_epochs: 11,
+                threshold: 5,
+            },
+            proposed_recovery: vec![RecoveryAction::AdjustProtocolParameter],
+            acknowledged: false,
+            incident_hash: vec![hash],
+        }
+    }
+
+    #[test]
+    fn test_incident_during_cooldown_runs_after_cooldown() {
+        let validators = vec!["val-1".to_string(), "val-2".to_string(), "val-3".to_string()];
+        let mut controller = RecoveryController::new(
+            validators,
+            ProtocolParams::default(),
+            RecoveryPreconditions::default(),
+        );
+        controller.last_recovery_epoch = 10;
+        
+        let incident = finality_incident(1, crate::incident_detector::IncidentSeverity::High);
+        assert!(matches!(
+            controller.execute_recovery(&incident, 12),
+            Err(RecoveryError::CooldownNotSatisfied)
+        ));
+        assert_eq!(controller.pending_incidents().count(), 1);
+        
+        // Still cooling down: nothing retried
+        assert!(controller.process_pending_incidents(12).unwrap().is_empty());
+        
+        // Cooldown lifts at epoch 13
+        let logs = controller.process_pending_incidents(13).unwrap();
+        assert_eq!(logs.len(), 1);
+        assert_eq!(logs[0].action, RecoveryAction::AdjustProtocolParameter);
+        assert_eq!(controller.pending_incidents().count(), 0);
+        assert_eq!(controller.last_recovery_epoch, 13);
+    }
+
+    #[test]
+    fn test_pending_overflow_drops_lowest_severity() {
+        use crate::incident_detector::IncidentSeverity;
+        
+        let validators = vec!["val-1".to_string(), "val-2".to_string(), "val-3".to_string()];
+        let mut controller = RecoveryController::new(
+            validators,
+            ProtocolParams::default(),
+            RecoveryPreconditions::default(),
+        );
+        controller.last_recovery_epoch = 10;
+        
+        for hash in 0..MAX_PENDING_INCIDENTS as u8 {
+            let _ = controller.execute_recovery(&finality_incident(hash, IncidentSeverity::Low), 11);
+        }
+        let critical = finality_incident(200, IncidentSeverity::Critical);
+        let _ = controller.execute_recovery(&critical, 11);
+        
+        assert_eq!(controller.pending_incidents().count(), MAX_PENDING_INCIDENTS);
+        assert!(controller.pending_incidents().any(|i| i.severity == IncidentSeverity::Critical));
+        // The Low incident retried last was evicted
+        let last_low = vec![MAX_PENDING_INCIDENTS as u8 - 1];
+        assert!(!controller.pending_incidents().any(|i| i.incident_hash == last_low));
+    }
 }
@@ -13,7 +13,9 @@ use serde::{Serialize, Deserialize};
 use std::collections::HashMap;
 use log::{info, warn, error};
 use thiserror::Error;
+use crate::epoch::ConsensusMode;
 use crate::incident_detector::{IncidentDetector, IncidentReport, IncidentType, DetectionParams};
+use crate::validator_identity::ValidatorIdentity;
 use crate::recovery_controller::{RecoveryController, RecoveryLog, ProtocolParams, RecoveryPreconditions};
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
@@ -24,7 +26,26 @@ pub enum OrchestratorState {
     Recovering,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+/// Lowest health score classified as Healthy
+pub const HEALTHY_SCORE_MIN: u64 = 80;
+
+/// Lowest health score classified as Degraded (below this is Critical)
+pub const DEGRADED_SCORE_MIN: u64 = 40;
+
+impl OrchestratorState {
+    /// Classify a 0-100 health score into a state band
+    pub fn from_health_score(score: u64) -> Self {
+        if score >= HEALTHY_SCORE_MIN {
+            OrchestratorState::Healthy
+        } else if score >= DEGRADED_SCORE_MIN {
+            OrchestratorState::Degraded
+        } else {
+            OrchestratorState::Critical
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HealingCycle {
     /// Epoch of this cycle
     pub epoch: u64,
@@ -73,6 +94,9 @@ pub struct SelfHealingOrchestrator {
     
     /// Recovery strategy
     strategy: RecoveryStrategy,
+    
+    /// Health score from the most recent cycle
+    health_score: u64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -118,6 +142,7 @@ impl SelfHealingOrchestrator {
             healing_history: Vec::new(),
             current_state: OrchestratorState::Healthy,
             strategy,
+            health_score: 100,
         }
     }
     
@@ -132,11 +157,22 @@ impl SelfHealingOrchestrator {
         let incidents = self.detector.check_health(epoch)
             .map_err(|e| OrchestratorError::DetectionFailed(e.to_string()))?;
         
-        // Phase 2: State assessment
-        let new_state = self.assess_state(&incidents);
+        // Phase 2: State assessment (the worse of incidents and score band)
+        let health_score = self.compute_health_score(epoch);
+        let new_state = Self::worse_state(
+            self.assess_state(&incidents),
+            OrchestratorState::from_health_score(health_score),
+        );
         
         // Phase 3: Recovery (if enabled and incidents detected)
         let mut recovery_actions = Vec::new();
+        if self.strategy.auto_recovery_enabled {
+            // Incidents queued during an earlier cooldown go first
+            match self.recovery.process_pending_incidents(epoch) {
+                Ok(actions) => recovery_actions.extend(actions),
+                Err(e) => warn!("Queued recovery failed: {:?}", e),
+            }
+        }
         if self.strategy.auto_recovery_enabled && !incidents.is_empty() {
             for incident in &incidents {
                 match self.recovery.execute_recovery(incident, epoch) {
@@ -158,9 +194,9 @@ impl SelfHealingOrchestrator {
         
         // Phase 5: Transition state
         self.current_state = new_state;
+        self.health_score = health_score;
         
         // Phase 6: Log cycle
-        let health_score = self.detector.health_score();
         let cycle = HealingCycle {
             epoch,
             state_before,
@@ -203,6 +239,34 @@ impl SelfHealingOrchestrator {
         self.detector.observe_finality(finalized_height, finalized_epoch, finality_epoch);
     }
     
+    /// Observe a validator's signed state root (called by consensus)
+    pub fn observe_state_root(
+        &mut self,
+        height: u64,
+        validator: &ValidatorIdentity,
+        root: Vec<u8>,
+        signature: Vec<u8>,
+    ) -> Result<(), OrchestratorError> {
+        self.detector
+            .observe_state_root(height, validator, root, signature)
+            .map_err(|e| OrchestratorError::DetectionFailed(e.to_string()))
+    }
+    
+    /// Set the total active stake the state-root quorum is measured against
+    pub fn observe_total_stake(&mut self, total_stake: u128) {
+        self.detector.set_total_stake(total_stake);
+    }
+    
+    /// Observe a consensus mode transition (adjusts finality expectations)
+    pub fn observe_consensus_mode(&mut self, mode: ConsensusMode) {
+        self.detector.set_consensus_mode(mode);
+    }
+    
+    /// Consensus mode the detector currently judges finality against
+    pub fn consensus_mode(&self) -> ConsensusMode {
+        self.detector.consensus_mode()
+    }
+    
     /// Take state snapshot (called periodically)
     pub fn take_snapshot(
         &mut self,
@@ -214,6 +278,27 @@ impl SelfHealingOrchestrator {
         Ok(())
     }
     
+    /// Compute health score (0-100) from the finality gap and validator downtime.
+    /// 
+    /// The finality component falls linearly from 100 at gap 0 to 0 at twice
+    /// the active mode's delay threshold; it is then scaled by the share of
+    /// recent proposers that are not down. Integer-only, so every node derives
+    /// the same score from the same history.
+    pub fn compute_health_score(&self, current_epoch: u64) -> u64 {
+        // finality = remaining / limit, as an exact fraction
+        let (remaining, limit) = match self.detector.finality_gap(current_epoch) {
+            Some(gap) => {
+                let limit = self.detector.finality_delay_threshold().max(1).saturating_mul(2);
+                (limit - gap.min(limit), limit)
+            }
+            None => (1, 1),
+        };
+        let (down, total) = self.detector.validator_downtime_counts();
+        let (up, total) = if total == 0 { (1, 1) } else { (total - down, total) };
+        let score = 100u128 * remaining as u128 * up as u128 / (limit as u128 * total as u128);
+        score as u64
+    }
+    
     /// Assess health based on incidents
     fn assess_state(&self, incidents: &[IncidentReport]) -> OrchestratorState {
         if incidents.is_empty() {
@@ -232,6 +317,17 @@ impl SelfHealingOrchestrator {
         }
     }
     
+    /// The more severe of two assessed states
+    fn worse_state(a: OrchestratorState, b: OrchestratorState) -> OrchestratorState {
+        let rank = |state: OrchestratorState| match state {
+            OrchestratorState::Healthy => 0,
+            OrchestratorState::Degraded => 1,
+            OrchestratorState::Recovering => 2,
+            OrchestratorState::Critical => 3,
+        };
+        if rank(b) > rank(a) { b } else { a }
+    }
+    
     /// Check if chain is healthy
     pub fn is_healthy(&self) -> bool {
         self.current_state == OrchestratorState::Healthy
@@ -257,9 +353,9 @@ impl SelfHealingOrchestrator {
         self.recovery.get_recovery_log()
     }
     
-    /// Get health score
+    /// Get health score from the most recent cycle
     pub fn get_health_score(&self) -> u64 {
-        self.detector.health_score()
+        self.health_score
     }
     
     /// Check if validator is frozen
@@ -322,7 +418,7 @@ impl SelfHealingOrchestrator {
         HealthReport {
             current_epoch,
             orchestrator_state: self.current_state,
-            health_score: self.detector.health_score(),
+            health_score: self.compute_health_score(current_epoch),
             active_incidents,
             recent_recoveries,
             validator_count: self.recovery.get_validators().len(),
@@ -440,4 +536,61 @@ mod tests {
         // Should have attempted recovery
         assert!(!cycle.recovery_actions.is_empty());
     }
+
+    fn orchestrator() -> SelfHealingOrchestrator {
+        SelfHealingOrchestrator::new(
+            DetectionParams::default(),
+            vec!["val-1".to_string(), "val-2".to_string(), "val-3".to_string()],
+            ProtocolParams::default(),
+            RecoveryPreconditions::default(),
+            RecoveryStrategy::default(),
+        )
+    }
+
+    #[test]
+    fn test_health_score_degrades_below_threshold() {
+        let mut orchestrator = orchestrator();
+        let threshold = DetectionParams::default().finality_delay_threshold;
+        orchestrator.observe_finality(100, 10, 10);
+
+        assert_eq!(orchestrator.compute_health_score(10), 100);
+
+        // Gap just under the threshold: no incident, but visibly degraded
+        let score = orchestrator.compute_health_score(10 + threshold - 1);
+        assert!(score > 0 && score < 100, "score {}", score);
+        assert!(orchestrator.compute_health_score(10 + threshold - 2) > score);
+
+        let cycle = orchestrator.execute_cycle(10 + threshold - 1).unwrap();
+        assert!(cycle.incidents_detected.is_empty());
+        assert_eq!(cycle.health_score, score);
+        assert_eq!(orchestrator.get_state(), OrchestratorState::from_health_score(score));
+    }
+
+    #[test]
+    fn test_state_is_worse_of_incidents_and_score_band() {
+        assert_eq!(OrchestratorState::from_health_score(100), OrchestratorState::Healthy);
+        assert_eq!(OrchestratorState::from_health_score(HEALTHY_SCORE_MIN - 1), OrchestratorState::Degraded);
+        assert_eq!(OrchestratorState::from_health_score(DEGRADED_SCORE_MIN - 1), OrchestratorState::Critical);
+
+        // A critical incident is not masked by a good score, nor a bad score by no incidents
+        let state = SelfHealingOrchestrator::worse_state(OrchestratorState::Critical, OrchestratorState::Healthy);
+        assert_eq!(state, OrchestratorState::Critical);
+        let state = SelfHealingOrchestrator::worse_state(OrchestratorState::Healthy, OrchestratorState::Degraded);
+        assert_eq!(state, OrchestratorState::Degraded);
+    }
+
+    #[test]
+    fn test_health_score_near_zero_at_twice_threshold() {
+        let mut orchestrator = orchestrator();
+        let threshold = DetectionParams::default().finality_delay_threshold;
+        orchestrator.observe_finality(100, 10, 10);
+
+        assert!(orchestrator.compute_health_score(10 + 2 * threshold - 1) <= 10);
+        assert_eq!(orchestrator.compute_health_score(10 + 2 * threshold), 0);
+        assert_eq!(orchestrator.compute_health_score(10 + 5 * threshold), 0);
+
+        orchestrator.execute_cycle(10 + 2 * threshold).unwrap();
+        assert_eq!(orchestrator.get_state(), OrchestratorState::Critical);
+        assert_eq!(orchestrator.get_health_score(), 0);
+    }
 }
@@ -5,6 +5,7 @@
 //! over 100 epochs. Implements deterministic shard assignment, cross-shard
 //! receipt routing, and TPS measurement.
 
+use bleep_state::epoch_clock::EpochClock;
 use std::collections::{HashMap, VecDeque};
 
 pub const NUM_SHARDS: usize = 10;
@@ -82,7 +83,7 @@ impl ShardState {
 
 pub struct ShardCoordinator {
     pub shards:      HashMap<ShardId, ShardState>,
-    pub epoch:       u64,
+    clock:           EpochClock,       // shared epoch source
     pub pending_xs:  HashMap<u64, CrossShardTx>,  // tx_id → CrossShardTx
     next_tx_id:      u64,
     tps_window:      VecDeque<u64>,   // tx counts per second window (last 60s)
@@ -101,7 +102,7 @@ impl ShardCoordinator {
         }
         Self {
             shards,
-            epoch: 0,
+            clock: EpochClock::default(),
             pending_xs: HashMap::new(),
             next_tx_id: 1,
             tps_window: VecDeque::new(),
@@ -112,6 +113,17 @@ impl ShardCoordinator {
         }
     }
 
+    /// Read epochs from a shared clock instead of a private one.
+    pub fn with_clock(mut self, clock: EpochClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Current epoch according to the shared clock.
+    pub fn epoch(&self) -> u64 {
+        self.clock.current_epoch()
+    }
+
     /// Submit a cross-shard transaction.
     pub fn submit_cross_shard(
         &mut self, from: &str, to: &str, amount: u128, nonce: u64,
@@ -129,7 +141,7 @@ impl ShardCoordinator {
             amount,
             nonce,
             state: CrossShardState::Initiated,
-            initiated_at_epoch: self.epoch,
+            initiated_at_epoch: self.clock.current_epoch(),
         };
         self.pending_xs.insert(id, tx);
         self.total_xs_txs += 1;
@@ -159,7 +171,7 @@ impl ShardCoordinator {
                     }
                     CrossShardState::ReceiptPending => {
                         // Timeout after 2 epochs
-                        if self.epoch - tx.initiated_at_epoch > 2 {
+                        if self.clock.current_epoch() - tx.initiated_at_epoch > 2 {
                             tx.state = CrossShardState::RolledBack;
                             self.rolledback_xs += 1;
                         } else {
@@ -185,12 +197,12 @@ impl ShardCoordinator {
             self.tick_block();
             blocks += 1;
         }
-        self.epoch += 1;
+        let epoch = self.clock.advance();
         let epoch_txs = self.total_txs - epoch_start_txs;
         // 100 blocks × 3 s/block = 300 s per epoch
         let tps = epoch_txs / 300;
         EpochStats {
-            epoch:       self.epoch,
+            epoch,
             blocks_produced: blocks,
             txs_this_epoch: epoch_txs,
             effective_tps: tps,
@@ -317,6 +329,16 @@ mod tests {
         assert_eq!(result.committed_xs + result.rolledback_xs, result.total_xs_txs);
     }
 
+    #[test]
+    fn tick_epoch_advances_shared_clock() {
+        let clock = EpochClock::new(7);
+        let mut coord = ShardCoordinator::new().with_clock(clock.clone());
+        let stats = coord.tick_epoch();
+        assert_eq!(stats.epoch, 8);
+        assert_eq!(clock.current_epoch(), 8);
+        assert_eq!(coord.epoch(), 8);
+    }
+
     #[test]
     fn each_shard_has_validators_assigned() {
         let coord = ShardCoordinator::new();
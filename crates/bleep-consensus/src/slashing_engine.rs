@@ -8,10 +8,13 @@
 // 4. Slashing is irreversible (frozen in block history)
 // 5. Slashing never panics (all errors are handled)
 
-use crate::validator_identity::{ValidatorIdentity, ValidatorRegistry};
+use crate::validator_identity::{ValidatorIdentity, ValidatorRegistry, ValidatorState, DOWNTIME_JAIL_EPOCHS};
 use serde::{Serialize, Deserialize};
-use std::collections::HashMap;
-use log::info;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use log::{info, warn};
+
+/// Epochs a slash stays pending before it finalizes.
+pub const CHALLENGE_WINDOW_EPOCHS: u64 = 2;
 
 /// Evidence of a slashable offense.
 /// 
@@ -116,6 +119,119 @@ impl SlashingEvidence {
     }
 }
 
+/// A validator's signature over one block, proving it was online at that height.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignedBlockProof {
+    pub height: u64,
+    pub block_hash: String,
+    pub signature: Vec<u8>,
+}
+
+impl SignedBlockProof {
+    /// Bytes a validator signs to attest to a block.
+    pub fn signing_bytes(height: u64, block_hash: &str) -> Vec<u8> {
+        let mut bytes = b"BLEEP-BLOCK-ATTEST-V1".to_vec();
+        bytes.extend_from_slice(&height.to_le_bytes());
+        bytes.extend_from_slice(block_hash.as_bytes());
+        bytes
+    }
+}
+
+/// Counter-evidence an accused validator submits against a pending slash.
+///
+/// SAFETY: Verified only against the pending evidence and the accused
+/// validator's registered key, so every node reaches the same verdict.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ChallengeEvidence {
+    /// The "conflicting" signatures or votes are the same signed message
+    /// presented twice, so there is no second signature.
+    IdenticalSignatures {
+        validator_id: String,
+        height: u64,
+        signed_message: Vec<u8>,
+    },
+
+    /// Blocks the validator signed in the accused epoch; refutes a miss
+    /// count that leaves no room for them.
+    LivenessProof {
+        validator_id: String,
+        epoch: u64,
+        signed_blocks: Vec<SignedBlockProof>,
+    },
+}
+
+impl ChallengeEvidence {
+    /// Key of the pending slash this challenge targets.
+    fn evidence_key(&self) -> (String, u64) {
+        match self {
+            ChallengeEvidence::IdenticalSignatures { validator_id, height, .. } => (validator_id.clone(), *height),
+            ChallengeEvidence::LivenessProof { validator_id, epoch, .. } => (validator_id.clone(), *epoch),
+        }
+    }
+
+    /// Bytes the accused validator signs to submit this challenge.
+    pub fn signing_bytes(&self) -> Vec<u8> {
+        let mut bytes = b"BLEEP-SLASH-CHALLENGE-V1".to_vec();
+        bytes.extend(bincode::serialize(self).unwrap_or_default());
+        bytes
+    }
+
+    /// Whether this challenge disproves `evidence` against `validator`.
+    ///
+    /// SAFETY: Every block proof must lie in the accused epoch and carry a
+    /// valid signature by the validator; one bad proof dismisses the challenge.
+    fn refutes(&self, evidence: &SlashingEvidence, validator: &ValidatorIdentity) -> bool {
+        match (self, evidence) {
+            (
+                ChallengeEvidence::IdenticalSignatures { signed_message, .. },
+                SlashingEvidence::DoubleSigning { signature_1, signature_2, .. },
+            ) => signature_1 == signed_message && signature_2 == signed_message,
+            (
+                ChallengeEvidence::IdenticalSignatures { signed_message, .. },
+                SlashingEvidence::Equivocation { vote_1, vote_2, .. },
+            ) => vote_1 == signed_message && vote_2 == signed_message,
+            (
+                ChallengeEvidence::LivenessProof { epoch, signed_blocks, .. },
+                SlashingEvidence::Downtime { missed_blocks, total_blocks_in_epoch, .. },
+            ) => {
+                let epoch_start = epoch.saturating_mul(*total_blocks_in_epoch);
+                let epoch_end = epoch_start.saturating_add(*total_blocks_in_epoch);
+                let mut heights = HashSet::new();
+                for proof in signed_blocks {
+                    if proof.height < epoch_start || proof.height >= epoch_end {
+                        return false;
+                    }
+                    let payload = SignedBlockProof::signing_bytes(proof.height, &proof.block_hash);
+                    if !validator.verify_signature(&payload, &proof.signature) {
+                        return false;
+                    }
+                    heights.insert(proof.height);
+                }
+                (heights.len() as u64).saturating_add(*missed_blocks) > *total_blocks_in_epoch
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Result of a challenge against a pending slash.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ChallengeOutcome {
+    /// Counter-evidence verified; the slash is cancelled.
+    Sustained,
+    /// Counter-evidence failed verification; the slash proceeds.
+    Dismissed,
+}
+
+/// A slash waiting out its challenge window.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PendingSlash {
+    pub evidence: SlashingEvidence,
+    pub submitted_epoch: u64,
+    pub finalizes_at_epoch: u64,
+    pub challenge_dismissed: bool,
+}
+
 /// Slashing penalty configuration.
 /// 
 /// SAFETY: These percentages are immutable once set at genesis.
@@ -154,6 +270,12 @@ pub struct SlashingEngine {
     
     /// Map of (validator_id, height) → evidence (to detect duplicates)
     processed_evidence: HashMap<(String, u64), SlashingEvidence>,
+
+    /// Slashes inside their challenge window, keyed like `processed_evidence`
+    pending_slashes: BTreeMap<(String, u64), PendingSlash>,
+
+    /// Evidence keys cancelled by a sustained challenge
+    overturned_evidence: HashSet<(String, u64)>,
 }
 
 /// Record of a slashing event (immutable, written to blockchain).
@@ -174,6 +296,8 @@ impl SlashingEngine {
             penalties: SlashingPenalty::default(),
             slashing_history: Vec::new(),
             processed_evidence: HashMap::new(),
+            pending_slashes: BTreeMap::new(),
+            overturned_evidence: HashSet::new(),
         }
     }
 
@@ -183,6 +307,8 @@ impl SlashingEngine {
             penalties,
             slashing_history: Vec::new(),
             processed_evidence: HashMap::new(),
+            pending_slashes: BTreeMap::new(),
+            overturned_evidence: HashSet::new(),
         }
     }
 
@@ -197,13 +323,132 @@ impl SlashingEngine {
         current_epoch: u64,
         timestamp: u64,
     ) -> Result<SlashingEvent, String> {
+        let evidence_key = self.check_evidence(&evidence, validator_registry, current_epoch)?;
+        self.apply_slash(evidence, evidence_key, validator_registry, current_epoch, timestamp)
+    }
+
+    /// Queue evidence behind a challenge window instead of slashing at once.
+    ///
+    /// The slash finalizes in `finalize_pending` once `CHALLENGE_WINDOW_EPOCHS`
+    /// have passed, unless a `submit_challenge` overturns it first.
+    pub fn submit_evidence(
+        &mut self,
+        evidence: SlashingEvidence,
+        validator_registry: &ValidatorRegistry,
+        current_epoch: u64,
+    ) -> Result<&PendingSlash, String> {
+        let evidence_key = self.check_evidence(&evidence, validator_registry, current_epoch)?;
+        info!(
+            "Slash against {} pending until epoch {}",
+            evidence_key.0,
+            current_epoch + CHALLENGE_WINDOW_EPOCHS
+        );
+
+        let pending = PendingSlash {
+            evidence,
+            submitted_epoch: current_epoch,
+            finalizes_at_epoch: current_epoch + CHALLENGE_WINDOW_EPOCHS,
+            challenge_dismissed: false,
+        };
+        Ok(self.pending_slashes.entry(evidence_key).or_insert(pending))
+    }
+
+    /// Submit counter-evidence against a pending slash.
+    ///
+    /// `signature` is the accused validator's signature over
+    /// `challenge.signing_bytes()`.
+    ///
+    /// SAFETY: Only the accused validator can challenge, and only while the
+    /// window is open; rejected submissions leave the pending slash untouched.
+    /// A sustained challenge cancels the slash and bars the same evidence from
+    /// being resubmitted. A dismissed challenge leaves the slash to finalize on
+    /// schedule; each slash can be challenged only once.
+    pub fn submit_challenge(
+        &mut self,
+        challenge: ChallengeEvidence,
+        signature: &[u8],
+        validator_registry: &ValidatorRegistry,
+        current_epoch: u64,
+    ) -> Result<ChallengeOutcome, String> {
+        let key = challenge.evidence_key();
+        let pending = self
+            .pending_slashes
+            .get_mut(&key)
+            .ok_or_else(|| format!("No pending slash for {} at {}", key.0, key.1))?;
+
+        if current_epoch >= pending.finalizes_at_epoch {
+            return Err(format!(
+                "Challenge window closed at epoch {}",
+                pending.finalizes_at_epoch
+            ));
+        }
+
+        let validator = validator_registry
+            .get(&key.0)
+            .ok_or_else(|| format!("Validator {} not found", key.0))?;
+        if !validator.verify_signature(&challenge.signing_bytes(), signature) {
+            return Err("Challenge is not signed by the accused validator".to_string());
+        }
+
+        if pending.challenge_dismissed {
+            return Err("Pending slash has already been challenged".to_string());
+        }
+
+        if challenge.refutes(&pending.evidence, validator) {
+            self.pending_slashes.remove(&key);
+            info!("Challenge by {} sustained: slash at {} cancelled", key.0, key.1);
+            self.overturned_evidence.insert(key);
+            Ok(ChallengeOutcome::Sustained)
+        } else {
+            pending.challenge_dismissed = true;
+            info!("Challenge by {} dismissed: slash at {} proceeds", key.0, key.1);
+            Ok(ChallengeOutcome::Dismissed)
+        }
+    }
+
+    /// Finalize every pending slash whose challenge window has closed.
+    ///
+    /// SAFETY: Called once per epoch. Slashes finalize in evidence-key order,
+    /// so every node applies them identically. A slash that can no longer be
+    /// applied (e.g. the validator already exited) is dropped and logged.
+    pub fn finalize_pending(
+        &mut self,
+        validator_registry: &mut ValidatorRegistry,
+        current_epoch: u64,
+        timestamp: u64,
+    ) -> Vec<SlashingEvent> {
+        let due: Vec<(String, u64)> = self
+            .pending_slashes
+            .iter()
+            .filter(|(_, p)| p.finalizes_at_epoch <= current_epoch)
+            .map(|(k, _)| k.clone())
+            .collect();
+
+        let mut events = Vec::new();
+        for key in due {
+            let Some(pending) = self.pending_slashes.remove(&key) else { continue };
+            match self.apply_slash(pending.evidence, key.clone(), validator_registry, current_epoch, timestamp) {
+                Ok(event) => events.push(event),
+                Err(e) => warn!("Dropping pending slash against {} at {}: {}", key.0, key.1, e),
+            }
+        }
+        events
+    }
+
+    /// Validate evidence and return its deduplication key.
+    fn check_evidence(
+        &self,
+        evidence: &SlashingEvidence,
+        validator_registry: &ValidatorRegistry,
+        current_epoch: u64,
+    ) -> Result<(String, u64), String> {
         // SAFETY: Verify evidence is well-formed
         evidence.is_well_formed()?;
 
         let validator_id = evidence.validator_id().to_string();
 
         // SAFETY: Check if we've already processed this evidence
-        let evidence_key = (validator_id.clone(), match &evidence {
+        let evidence_key = (validator_id.clone(), match evidence {
             SlashingEvidence::DoubleSigning { height, .. } => *height,
             SlashingEvidence::Equivocation { height, .. } => *height,
             SlashingEvidence::Downtime { .. } => current_epoch,
@@ -212,8 +457,31 @@ impl SlashingEngine {
         if self.processed_evidence.contains_key(&evidence_key) {
             return Err("Evidence already processed".to_string());
         }
+        if self.pending_slashes.contains_key(&evidence_key) {
+            return Err("Evidence already pending".to_string());
+        }
+        if self.overturned_evidence.contains(&evidence_key) {
+            return Err("Evidence was overturned by a challenge".to_string());
+        }
 
         // SAFETY: Verify validator exists
+        if validator_registry.get(&validator_id).is_none() {
+            return Err(format!("Validator {} not found", validator_id));
+        }
+
+        Ok(evidence_key)
+    }
+
+    /// Slash the validator for already-checked evidence.
+    fn apply_slash(
+        &mut self,
+        evidence: SlashingEvidence,
+        evidence_key: (String, u64),
+        validator_registry: &mut ValidatorRegistry,
+        current_epoch: u64,
+        timestamp: u64,
+    ) -> Result<SlashingEvent, String> {
+        let validator_id = evidence_key.0.clone();
         let validator = validator_registry
             .get(&validator_id)
             .ok_or_else(|| format!("Validator {} not found", validator_id))?;
@@ -246,6 +514,12 @@ impl SlashingEngine {
             SlashingEvidence::Downtime { .. } => {
                 validator_registry.record_validator_downtime(&validator_id, slash_amount)?;
                 info!("Slashed validator {} for downtime: {} microBLEEP", validator_id, slash_amount);
+
+                // Active validators sit out the jail period; tick() releases them
+                if validator_registry.get(&validator_id).is_some_and(|v| v.state == ValidatorState::Active) {
+                    validator_registry.jail_validator(&validator_id, current_epoch, DOWNTIME_JAIL_EPOCHS)?;
+                    info!("Jailed validator {} until epoch {}", validator_id, current_epoch + DOWNTIME_JAIL_EPOCHS);
+                }
             }
         }
 
@@ -327,6 +601,11 @@ impl SlashingEngine {
         self.processed_evidence.contains_key(&(validator_id.to_string(), height))
     }
 
+    /// Slashes still inside their challenge window.
+    pub fn pending(&self) -> impl Iterator<Item = &PendingSlash> {
+        self.pending_slashes.values()
+    }
+
     /// Get the total slashed amount across all events.
     pub fn total_slashed(&self) -> u128 {
         self.slashing_history.iter().map(|e| e.slash_amount).sum()
@@ -354,6 +633,30 @@ mod tests {
         .unwrap()
     }
 
+    /// Registry with one active validator "v1"; returns its signing secret key
+    fn registry_with_signing_validator() -> (ValidatorRegistry, Vec<u8>) {
+        let (pk, sk) = bleep_crypto::tx_signer::generate_tx_keypair();
+        let mut registry = ValidatorRegistry::new();
+        registry
+            .register_validator(create_test_validator("v1").with_signing_public_key(pk))
+            .unwrap();
+        registry.activate_validator("v1").unwrap();
+        (registry, sk)
+    }
+
+    fn sign(payload: &[u8], sk: &[u8]) -> Vec<u8> {
+        bleep_crypto::tx_signer::sign_tx_payload(payload, sk).unwrap()
+    }
+
+    fn block_proof(height: u64, sk: &[u8]) -> SignedBlockProof {
+        let block_hash = format!("block{}", height);
+        SignedBlockProof {
+            height,
+            signature: sign(&SignedBlockProof::signing_bytes(height, &block_hash), sk),
+            block_hash,
+        }
+    }
+
     #[test]
     fn test_double_signing_evidence_validation() {
         let evidence = SlashingEvidence::DoubleSigning {
@@ -489,4 +792,181 @@ mod tests {
         assert_eq!(engine.history().len(), 3);
         assert_eq!(engine.total_slashed(), 3000000); // 3 validators * 1M stake
     }
+
+    #[test]
+    fn test_unchallenged_slash_finalizes_after_window() {
+        let mut engine = SlashingEngine::new();
+        let mut registry = ValidatorRegistry::new();
+        registry.register_validator(create_test_validator("v1")).unwrap();
+        registry.activate_validator("v1").unwrap();
+
+        let evidence = SlashingEvidence::Equivocation {
+            validator_id: "v1".to_string(),
+            height: 100,
+            vote_1: vec![1, 2, 3],
+            vote_2: vec![4, 5, 6],
+            timestamp_1: 1000,
+            timestamp_2: 1001,
+        };
+        let pending = engine.submit_evidence(evidence.clone(), &registry, 1).unwrap();
+        assert_eq!(pending.finalizes_at_epoch, 1 + CHALLENGE_WINDOW_EPOCHS);
+        assert!(engine.submit_evidence(evidence, &registry, 1).is_err());
+
+        // Nothing happens while the window is open
+        assert!(engine.finalize_pending(&mut registry, 2, 2000).is_empty());
+        assert_eq!(registry.get("v1").unwrap().stake, 1000000);
+
+        let events = engine.finalize_pending(&mut registry, 1 + CHALLENGE_WINDOW_EPOCHS, 3000);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].evidence_type, "EQUIVOCATION");
+        assert!(registry.get("v1").unwrap().stake < 1000000);
+        assert!(engine.has_evidence("v1", 100));
+        assert_eq!(engine.pending().count(), 0);
+    }
+
+    #[test]
+    fn test_valid_challenge_prevents_slash() {
+        let mut engine = SlashingEngine::new();
+        let (mut registry, sk) = registry_with_signing_validator();
+
+        // Accuser relabels one signed block under two hashes
+        let evidence = SlashingEvidence::DoubleSigning {
+            validator_id: "v1".to_string(),
+            height: 100,
+            block_hash_1: "hash1".to_string(),
+            block_hash_2: "hash2".to_string(),
+            signature_1: vec![7, 7, 7],
+            signature_2: vec![7, 7, 7],
+        };
+        engine.submit_evidence(evidence.clone(), &registry, 1).unwrap();
+
+        let challenge = ChallengeEvidence::IdenticalSignatures {
+            validator_id: "v1".to_string(),
+            height: 100,
+            signed_message: vec![7, 7, 7],
+        };
+        let signature = sign(&challenge.signing_bytes(), &sk);
+        let outcome = engine.submit_challenge(challenge, &signature, &registry, 2);
+        assert_eq!(outcome, Ok(ChallengeOutcome::Sustained));
+
+        assert!(engine.finalize_pending(&mut registry, 10, 5000).is_empty());
+        assert_eq!(registry.get("v1").unwrap().stake, 1000000);
+        assert!(engine.history().is_empty());
+
+        // The overturned evidence cannot be replayed
+        assert!(engine.submit_evidence(evidence, &registry, 10).is_err());
+    }
+
+    #[test]
+    fn test_invalid_challenge_dismissed_and_slash_proceeds() {
+        let mut engine = SlashingEngine::new();
+        let (mut registry, sk) = registry_with_signing_validator();
+
+        let evidence = SlashingEvidence::Downtime {
+            validator_id: "v1".to_string(),
+            missed_blocks: 8,
+            total_blocks_in_epoch: 10,
+        };
+        engine.submit_evidence(evidence, &registry, 4).unwrap();
+
+        // 2 signed blocks is consistent with 8 missed out of 10
+        let challenge = ChallengeEvidence::LivenessProof {
+            validator_id: "v1".to_string(),
+            epoch: 4,
+            signed_blocks: vec![block_proof(40, &sk), block_proof(41, &sk)],
+        };
+        let signature = sign(&challenge.signing_bytes(), &sk);
+        assert_eq!(
+            engine.submit_challenge(challenge.clone(), &signature, &registry, 4),
+            Ok(ChallengeOutcome::Dismissed)
+        );
+        assert!(engine.submit_challenge(challenge, &signature, &registry, 4).is_err());
+
+        let events = engine.finalize_pending(&mut registry, 4 + CHALLENGE_WINDOW_EPOCHS, 6000);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].evidence_type, "DOWNTIME");
+        assert!(registry.get("v1").unwrap().is_jailed());
+    }
+
+    #[test]
+    fn test_liveness_proof_requires_valid_block_signatures() {
+        let mut engine = SlashingEngine::new();
+        let (registry, sk) = registry_with_signing_validator();
+        engine.submit_evidence(SlashingEvidence::Downtime {
+            validator_id: "v1".to_string(),
+            missed_blocks: 8,
+            total_blocks_in_epoch: 10,
+        }, &registry, 4).unwrap();
+
+        // Three proofs would refute the count, but one is forged
+        let mut forged = block_proof(42, &sk);
+        forged.block_hash = "other".to_string();
+        let challenge = ChallengeEvidence::LivenessProof {
+            validator_id: "v1".to_string(),
+            epoch: 4,
+            signed_blocks: vec![block_proof(40, &sk), block_proof(41, &sk), forged],
+        };
+        let signature = sign(&challenge.signing_bytes(), &sk);
+        assert_eq!(
+            engine.submit_challenge(challenge, &signature, &registry, 4),
+            Ok(ChallengeOutcome::Dismissed)
+        );
+
+        let mut engine = SlashingEngine::new();
+        engine.submit_evidence(SlashingEvidence::Downtime {
+            validator_id: "v1".to_string(),
+            missed_blocks: 8,
+            total_blocks_in_epoch: 10,
+        }, &registry, 4).unwrap();
+        let challenge = ChallengeEvidence::LivenessProof {
+            validator_id: "v1".to_string(),
+            epoch: 4,
+            signed_blocks: vec![block_proof(40, &sk), block_proof(41, &sk), block_proof(42, &sk)],
+        };
+        let signature = sign(&challenge.signing_bytes(), &sk);
+        assert_eq!(
+            engine.submit_challenge(challenge, &signature, &registry, 4),
+            Ok(ChallengeOutcome::Sustained)
+        );
+    }
+
+    #[test]
+    fn test_challenge_requires_accused_signature_within_window() {
+        let mut engine = SlashingEngine::new();
+        let (registry, sk) = registry_with_signing_validator();
+        engine.submit_evidence(SlashingEvidence::DoubleSigning {
+            validator_id: "v1".to_string(),
+            height: 100,
+            block_hash_1: "hash1".to_string(),
+            block_hash_2: "hash2".to_string(),
+            signature_1: vec![7, 7, 7],
+            signature_2: vec![7, 7, 7],
+        }, &registry, 1).unwrap();
+
+        // A third party cannot burn the accused's single challenge
+        let bogus = ChallengeEvidence::IdenticalSignatures {
+            validator_id: "v1".to_string(),
+            height: 100,
+            signed_message: vec![1],
+        };
+        let (_, other_sk) = bleep_crypto::tx_signer::generate_tx_keypair();
+        let bogus_signature = sign(&bogus.signing_bytes(), &other_sk);
+        assert!(engine.submit_challenge(bogus, &bogus_signature, &registry, 1).is_err());
+
+        let challenge = ChallengeEvidence::IdenticalSignatures {
+            validator_id: "v1".to_string(),
+            height: 100,
+            signed_message: vec![7, 7, 7],
+        };
+        let signature = sign(&challenge.signing_bytes(), &sk);
+
+        // Too late once the slash is due
+        assert!(engine
+            .submit_challenge(challenge.clone(), &signature, &registry, 1 + CHALLENGE_WINDOW_EPOCHS)
+            .is_err());
+        assert_eq!(
+            engine.submit_challenge(challenge, &signature, &registry, 2),
+            Ok(ChallengeOutcome::Sustained)
+        );
+    }
 }
@@ -8,10 +8,14 @@
 // 4. Double-signing is impossible: same key cannot sign two conflicting blocks
 // 5. Validator lifecycle is enforced via state machine
 
+use crate::engine::ConsensusError;
 use serde::{Serialize, Deserialize};
 use std::collections::{HashMap, BTreeSet};
 use std::fmt;
 
+/// Number of epochs a validator is jailed after a downtime offense.
+pub const DOWNTIME_JAIL_EPOCHS: u64 = 3;
+
 /// Validator identity using post-quantum cryptography.
 /// 
 /// SAFETY: This structure holds the authoritative validator record.
@@ -53,6 +57,10 @@ pub struct ValidatorIdentity {
     
     /// Total slashed amount (cumulative across all incidents)
     pub total_slashed: u128,
+
+    /// SPHINCS+ public key verifying the validator's protocol signatures
+    #[serde(default)]
+    pub signing_public_key: Vec<u8>,
 }
 
 impl ValidatorIdentity {
@@ -90,9 +98,25 @@ impl ValidatorIdentity {
             joined_epoch,
             exited_epoch: None,
             total_slashed: 0,
+            signing_public_key: Vec::new(),
         })
     }
 
+    /// Attach the SPHINCS+ public key that verifies this validator's signatures.
+    pub fn with_signing_public_key(mut self, signing_public_key: Vec<u8>) -> Self {
+        self.signing_public_key = signing_public_key;
+        self
+    }
+
+    /// Verify a protocol signature by this validator.
+    /// 
+    /// SAFETY: A validator without a registered signing key cannot produce a
+    /// valid signature.
+    pub fn verify_signature(&self, payload: &[u8], signature: &[u8]) -> bool {
+        !self.signing_public_key.is_empty()
+            && bleep_crypto::tx_signer::verify_tx_signature(payload, signature, &self.signing_public_key)
+    }
+
     /// Activate this validator for consensus participation.
     /// 
     /// SAFETY: Can only transition from Inactive → Active
@@ -192,6 +216,41 @@ impl ValidatorIdentity {
         Ok(())
     }
 
+    /// Jail this validator for `jail_epochs` epochs starting at `current_epoch`.
+    /// 
+    /// SAFETY: Can only transition from Active → Jailed. The validator is
+    /// excluded for epochs `current_epoch..current_epoch + jail_epochs`.
+    pub fn jail(&mut self, current_epoch: u64, jail_epochs: u64) -> Result<(), String> {
+        match self.state {
+            ValidatorState::Active => {
+                self.state = ValidatorState::Jailed {
+                    until_epoch: current_epoch.saturating_add(jail_epochs),
+                };
+                Ok(())
+            }
+            _ => Err(format!("Cannot jail validator in state {:?}", self.state)),
+        }
+    }
+
+    /// Release this validator if its jail period has elapsed.
+    /// 
+    /// SAFETY: Transitions Jailed → Active only once `epoch >= until_epoch`.
+    /// Returns true if the validator was released.
+    pub fn unjail(&mut self, epoch: u64) -> bool {
+        match self.state {
+            ValidatorState::Jailed { until_epoch } if epoch >= until_epoch => {
+                self.state = ValidatorState::Active;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Check if this validator is currently jailed.
+    pub fn is_jailed(&self) -> bool {
+        matches!(self.state, ValidatorState::Jailed { .. })
+    }
+
     /// Check if this validator can participate in consensus.
     /// 
     /// SAFETY: Only Active validators with positive reputation can participate
@@ -208,9 +267,9 @@ impl ValidatorIdentity {
             || self.double_sign_count > 0
     }
 
-    /// Get the effective stake (accounting for slashing).
+    /// Get the effective stake (accounting for slashing and jailing).
     pub fn effective_stake(&self) -> u128 {
-        if self.is_ejected() {
+        if self.is_ejected() || self.is_jailed() {
             0
         } else {
             self.stake
@@ -242,6 +301,9 @@ pub enum ValidatorState {
     /// Validator is participating in consensus
     Active,
 
+    /// Validator is temporarily excluded until `until_epoch` (downtime)
+    Jailed { until_epoch: u64 },
+
     /// Validator has requested exit; pending epoch end
     PendingExit,
 
@@ -260,6 +322,7 @@ impl fmt::Display for ValidatorState {
         match self {
             ValidatorState::Inactive => write!(f, "INACTIVE"),
             ValidatorState::Active => write!(f, "ACTIVE"),
+            ValidatorState::Jailed { until_epoch } => write!(f, "JAILED(until={})", until_epoch),
             ValidatorState::PendingExit => write!(f, "PENDING_EXIT"),
             ValidatorState::Exited => write!(f, "EXITED"),
             ValidatorState::Slashed => write!(f, "SLASHED"),
@@ -399,7 +462,99 @@ impl ValidatorRegistry {
             .get_mut(id)
             .ok_or_else(|| format!("Validator {} not found", id))?;
 
-        validator.record_downtime(slash_amount)
+        validator.record_downtime(slash_amount)?;
+
+        if self.active_validators.contains(id) {
+            self.total_active_stake = self.total_active_stake.saturating_sub(slash_amount);
+        }
+
+        Ok(())
+    }
+
+    /// Jail a validator for `jail_epochs` epochs.
+    /// 
+    /// SAFETY: Removes from the active set, so a jailed validator is never
+    /// selected as proposer and its stake never counts toward quorum.
+    pub fn jail_validator(&mut self, id: &str, current_epoch: u64, jail_epochs: u64) -> Result<(), String> {
+        let stake = {
+            let validator = self
+                .get_mut(id)
+                .ok_or_else(|| format!("Validator {} not found", id))?;
+
+            validator.jail(current_epoch, jail_epochs)?;
+            validator.stake
+        };
+
+        self.active_validators.remove(id);
+        self.total_active_stake = self.total_active_stake.saturating_sub(stake);
+
+        Ok(())
+    }
+
+    /// Process epoch transition: release validators whose jail period ended.
+    /// 
+    /// SAFETY: Deterministic — released IDs are returned in sorted order.
+    pub fn tick(&mut self, epoch: u64) -> Vec<String> {
+        let mut released: Vec<String> = self
+            .validators
+            .values_mut()
+            .filter_map(|v| v.unjail(epoch).then(|| v.id.clone()))
+            .collect();
+        released.sort();
+
+        for id in &released {
+            let stake = self.validators[id].stake;
+            self.active_validators.insert(id.clone());
+            self.total_active_stake = self.total_active_stake.saturating_add(stake);
+        }
+
+        released
+    }
+
+    /// Select a block proposer from the active set, weighted by stake.
+    /// 
+    /// SAFETY: Same seed + same active set always yields the same proposer.
+    /// Jailed, exited and slashed validators are never in the active set.
+    pub fn select_proposer(&self, seed: u64) -> Result<&ValidatorIdentity, ConsensusError> {
+        self.require_active_stake()?;
+
+        let target = u128::from(seed) % self.total_active_stake;
+        let mut accumulated = 0u128;
+        self.get_active_validators()
+            .into_iter()
+            .find(|v| {
+                accumulated = accumulated.saturating_add(v.stake);
+                accumulated > target
+            })
+            .ok_or_else(|| ConsensusError::Other("Active stake cache out of sync".to_string()))
+    }
+
+    /// Check whether `signers` hold more than 2/3 of active stake.
+    /// 
+    /// SAFETY: Only validators that can participate contribute stake.
+    /// An empty active set is an error, never a trivially met quorum.
+    pub fn has_quorum(&self, signers: &[&str]) -> Result<bool, ConsensusError> {
+        self.require_active_stake()?;
+
+        let signed: u128 = signers
+            .iter()
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .filter(|id| self.can_participate(id))
+            .map(|id| self.get_voting_power(id))
+            .sum();
+
+        Ok(signed.saturating_mul(3) > self.total_active_stake.saturating_mul(2))
+    }
+
+    fn require_active_stake(&self) -> Result<(), ConsensusError> {
+        if self.total_active_stake == 0 {
+            return Err(ConsensusError::InsufficientValidators {
+                active: self.active_validators.len(),
+                required: 1,
+            });
+        }
+        Ok(())
     }
 
     /// Get all active validators.
@@ -533,4 +688,68 @@ mod tests {
         registry.slash_validator_equivocation("v1", 100000).unwrap();
         assert_eq!(registry.get_voting_power("v1"), 0); // Slashed = no power
     }
+
+    fn registry_with(ids: &[&str]) -> ValidatorRegistry {
+        let mut registry = ValidatorRegistry::new();
+        for id in ids {
+            registry.register_validator(create_test_identity(id)).unwrap();
+            registry.activate_validator(id).unwrap();
+        }
+        registry
+    }
+
+    #[test]
+    fn test_jailed_validator_excluded_during_jail_period() {
+        let mut registry = registry_with(&["v1", "v2", "v3"]);
+
+        registry.jail_validator("v3", 10, DOWNTIME_JAIL_EPOCHS).unwrap();
+        assert_eq!(registry.get("v3").unwrap().state, ValidatorState::Jailed { until_epoch: 13 });
+
+        for epoch in 10..13 {
+            assert!(registry.tick(epoch).is_empty());
+            assert!(!registry.can_participate("v3"));
+            assert_eq!(registry.get_voting_power("v3"), 0);
+            assert_eq!(registry.total_active_stake(), 2_000_000);
+
+            // Never chosen as proposer, whatever the seed
+            for seed in 0..50 {
+                assert_ne!(registry.select_proposer(seed * 97_331).unwrap().id, "v3");
+            }
+
+            // v3's signature adds nothing toward quorum
+            assert!(!registry.has_quorum(&["v1", "v3"]).unwrap());
+            assert!(registry.has_quorum(&["v1", "v2"]).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_jailed_validator_reinstated_when_jail_ends() {
+        let mut registry = registry_with(&["v1", "v2", "v3"]);
+        registry.jail_validator("v3", 10, DOWNTIME_JAIL_EPOCHS).unwrap();
+
+        assert!(registry.tick(12).is_empty());
+        assert_eq!(registry.tick(13), vec!["v3".to_string()]);
+
+        assert_eq!(registry.get("v3").unwrap().state, ValidatorState::Active);
+        assert!(registry.can_participate("v3"));
+        assert_eq!(registry.active_count(), 3);
+        assert_eq!(registry.total_active_stake(), 3_000_000);
+        assert!(!registry.has_quorum(&["v1", "v2"]).unwrap());
+        assert!(registry.has_quorum(&["v1", "v2", "v3"]).unwrap());
+
+        // Only Active validators can be jailed
+        assert!(registry.jail_validator("missing", 13, 1).is_err());
+        registry.mark_validator_for_exit("v1").unwrap();
+        assert!(registry.jail_validator("v1", 13, 1).is_err());
+    }
+
+    #[test]
+    fn test_empty_registry_returns_typed_errors() {
+        let registry = ValidatorRegistry::new();
+        let empty = ConsensusError::InsufficientValidators { active: 0, required: 1 };
+
+        assert_eq!(registry.select_proposer(42).unwrap_err(), empty);
+        assert_eq!(registry.has_quorum(&[]).unwrap_err(), empty);
+        assert!(registry.has_quorum(&["v1"]).is_err());
+    }
 }
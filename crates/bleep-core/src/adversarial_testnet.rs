@@ -5,17 +5,24 @@
 //
 // This module provides:
 // 1. Adversarial testnet harness (orchestrates attacks)
-// 2. Scenario injectors (validator collusion, state injection, governance abuse)
+// 2. Scenario injectors (validator collusion, state injection, governance abuse,
+//    network partition)
 // 3. Public observability (metrics, logs, dashboards)
 // 4. Immutable incident logging (append-only, tamper-proof)
 // 5. Before/after state proofs (cryptographic commitments)
 // 6. Deterministic adversarial execution (repeatable for audits)
 
+use rand::seq::index::sample;
+use rand::SeedableRng;
+use rand_chacha::ChaCha20Rng;
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 use std::time::{SystemTime, UNIX_EPOCH};
 use sha2::{Sha256, Digest};
 
+use crate::scenario_injector::NetworkPartition;
+use bleep_state::epoch_clock::EpochClock;
+
 /// ═══════════════════════════════════════════════════════════════════════════════
 /// CORE TYPES
 /// ═══════════════════════════════════════════════════════════════════════════════
@@ -43,6 +50,7 @@ pub enum AdversarialScenario {
     ValidatorCollusion,      // 2+ validators collude to produce invalid blocks
     InvalidStateInjection,   // Invalid state committed to chain
     GovernanceAbuse,         // Attacker proposes destructive upgrade
+    NetworkPartition,        // Validators split into groups that can't see each other
     CombinedAttack,          // Multiple attacks simultaneously
 }
 
@@ -52,6 +60,7 @@ impl AdversarialScenario {
             Self::ValidatorCollusion => "validator_collusion",
             Self::InvalidStateInjection => "invalid_state_injection",
             Self::GovernanceAbuse => "governance_abuse",
+            Self::NetworkPartition => "network_partition",
             Self::CombinedAttack => "combined_attack",
         }
     }
@@ -61,6 +70,7 @@ impl AdversarialScenario {
             Self::ValidatorCollusion => "Two colluding validators attempt to create fork",
             Self::InvalidStateInjection => "Invalid transaction state committed to chain",
             Self::GovernanceAbuse => "Attacker votes for harmful protocol parameter",
+            Self::NetworkPartition => "Network split isolates two validator groups until healed",
             Self::CombinedAttack => "Multiple attacks executed in sequence",
         }
     }
@@ -86,7 +96,7 @@ pub struct StateCommitment {
     pub state_hash: Vec<u8>,
     /// Epoch number
     pub epoch: u64,
-    /// Timestamp (UNIX seconds)
+    /// Logical timestamp (seconds since testnet genesis)
     pub timestamp: u64,
     /// Detailed metrics snapshot
     pub metrics: StateMetrics,
@@ -103,6 +113,41 @@ impl StateCommitment {
         hasher.update(&metrics_bytes);
         hasher.finalize().to_vec()
     }
+
+    /// Field-by-field change from `self` to `other`
+    pub fn diff(&self, other: &StateCommitment) -> StateDiff {
+        let (a, b) = (&self.metrics, &other.metrics);
+        StateDiff {
+            from_epoch: self.epoch,
+            to_epoch: other.epoch,
+            block_height_delta: saturating_delta(a.block_height, b.block_height),
+            validator_count_delta: saturating_delta(a.validator_count as u64, b.validator_count as u64),
+            byzantine_count_delta: saturating_delta(a.byzantine_validators as u64, b.byzantine_validators as u64),
+            finalized_blocks_delta: saturating_delta(a.finalized_blocks, b.finalized_blocks),
+            finalization_rate_delta: b.finalization_rate() - a.finalization_rate(),
+            state_hash_changed: self.state_hash != other.state_hash,
+        }
+    }
+}
+
+/// `to - from`, clamped to the i64 range
+fn saturating_delta(from: u64, to: u64) -> i64 {
+    let delta = i128::from(to) - i128::from(from);
+    i64::try_from(delta).unwrap_or(if delta < 0 { i64::MIN } else { i64::MAX })
+}
+
+/// Summary of how network state changed between two commitments
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StateDiff {
+    pub from_epoch: u64,
+    pub to_epoch: u64,
+    pub block_height_delta: i64,
+    pub validator_count_delta: i64,
+    pub byzantine_count_delta: i64,
+    pub finalized_blocks_delta: i64,
+    /// Change in finalized/produced blocks, in percentage points
+    pub finalization_rate_delta: f64,
+    pub state_hash_changed: bool,
 }
 
 /// Snapshot of network metrics at a point in time
@@ -118,6 +163,16 @@ pub struct StateMetrics {
     pub network_latency_ms: f64,
 }
 
+impl StateMetrics {
+    /// Percentage of produced blocks that are finalized (0 before any block)
+    pub fn finalization_rate(&self) -> f64 {
+        if self.block_height == 0 {
+            return 0.0;
+        }
+        self.finalized_blocks as f64 * 100.0 / self.block_height as f64
+    }
+}
+
 impl Default for StateMetrics {
     fn default() -> Self {
         Self {
@@ -138,7 +193,7 @@ impl Default for StateMetrics {
 pub struct IncidentLogEntry {
     /// Sequential incident ID (immutable)
     pub incident_id: u64,
-    /// When incident detected (UNIX seconds)
+    /// When incident detected (logical seconds since testnet genesis)
     pub detection_time: u64,
     /// Type of incident detected
     pub incident_type: DetectedIncidentType,
@@ -237,6 +292,19 @@ pub struct GovernanceAbuseConfig {
     pub bad_value: String,
 }
 
+/// Configuration for network partition / eclipse attack
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PartitionConfig {
+    /// Epoch the network splits
+    pub partition_epoch: u64,
+    /// Validators on one side of the partition
+    pub group_a: Vec<usize>,
+    /// Validators on the other side of the partition
+    pub group_b: Vec<usize>,
+    /// Epoch the network reunifies
+    pub heal_epoch: u64,
+}
+
 /// Comprehensive adversarial scenario configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AdversarialScenarioConfig {
@@ -248,6 +316,7 @@ pub struct AdversarialScenarioConfig {
     pub collision: Option<CollisionAttackConfig>,
     pub state_injection: Option<StateInjectionConfig>,
     pub governance_abuse: Option<GovernanceAbuseConfig>,
+    pub partition: Option<PartitionConfig>,
 }
 
 impl Default for AdversarialScenarioConfig {
@@ -261,6 +330,7 @@ impl Default for AdversarialScenarioConfig {
             collision: None,
             state_injection: None,
             governance_abuse: None,
+            partition: None,
         }
     }
 }
@@ -296,6 +366,9 @@ pub struct PublicMetrics {
     pub validators_slashed: u64,
     /// Active incident (if any)
     pub active_incident: Option<String>,
+    /// Total autonomous recoveries completed
+    #[serde(default)]
+    pub recoveries_completed: u64,
     /// Time of last incident
     pub last_incident_time: u64,
     /// Timestamp of this snapshot
@@ -322,6 +395,7 @@ impl PublicMetrics {
             incidents_detected: 0,
             validators_slashed: 0,
             active_incident: None,
+            recoveries_completed: 0,
             last_incident_time: 0,
             snapshot_time: timestamp,
         }
@@ -338,7 +412,8 @@ pub struct AdversarialTestnet {
     pub id: TestnetId,
     pub config: AdversarialScenarioConfig,
     pub state: TestnetRunState,
-    pub current_epoch: u64,
+    /// Shared epoch source
+    clock: EpochClock,
     pub block_height: u64,
     pub metrics: PublicMetrics,
     /// Immutable incident log (append-only)
@@ -351,6 +426,12 @@ pub struct AdversarialTestnet {
     pub recovery_actions: Vec<RecoveryAction>,
     /// Current execution seed (for determinism)
     pub execution_seed: Vec<u8>,
+    /// PRNG seeded from `execution_seed`; every randomized choice draws from it
+    rng: ChaCha20Rng,
+    /// Network partition in progress (or healed)
+    pub partition: Option<NetworkPartition>,
+    /// Partition injected ahead of its `partition_epoch`, waiting to split
+    pub partition_scheduled: bool,
 }
 
 impl AdversarialTestnet {
@@ -365,36 +446,50 @@ impl AdversarialTestnet {
             id: testnet_id,
             config,
             state: TestnetRunState::Initializing,
-            current_epoch: 0,
+            clock: EpochClock::default(),
             block_height: 0,
             metrics,
             incident_log: Vec::new(),
             state_history: BTreeMap::new(),
             governance_proposals: Vec::new(),
             recovery_actions: Vec::new(),
+            rng: ChaCha20Rng::from_seed(seed_bytes(&seed)),
             execution_seed: seed,
+            partition: None,
+            partition_scheduled: false,
         }
     }
 
+    /// Attach a shared epoch clock
+    pub fn with_clock(mut self, clock: EpochClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Current epoch according to the shared clock
+    pub fn current_epoch(&self) -> u64 {
+        self.clock.current_epoch()
+    }
+
     /// Initialize testnet and capture before-state
     pub fn initialize(&mut self) -> Result<StateCommitment, String> {
         self.state = TestnetRunState::Running;
         
         let before_state = StateCommitment {
             state_hash: self.compute_state_hash(),
-            epoch: self.current_epoch,
-            timestamp: current_timestamp(),
+            epoch: self.clock.current_epoch(),
+            timestamp: self.logical_time(),
             metrics: self.capture_metrics(),
         };
 
-        self.state_history.insert(self.current_epoch, before_state.clone());
+        self.state_history.insert(self.clock.current_epoch(), before_state.clone());
         Ok(before_state)
     }
 
     /// Inject adversarial scenario at specified epoch
     pub fn inject_scenario(&mut self, target_epoch: u64) -> Result<(), String> {
-        if self.current_epoch < target_epoch {
-            return Err(format!("Cannot inject at epoch {} when current is {}", target_epoch, self.current_epoch));
+        if self.clock.current_epoch() < target_epoch {
+            return Err(format!("Cannot inject at epoch {} when current is {}", target_epoch, self.clock.current_epoch()));
         }
 
         self.state = TestnetRunState::AttackInjected;
@@ -410,6 +505,9 @@ impl AdversarialTestnet {
             AdversarialScenario::GovernanceAbuse => {
                 self.inject_governance_abuse()?;
             }
+            AdversarialScenario::NetworkPartition => {
+                self.inject_network_partition()?;
+            }
             AdversarialScenario::CombinedAttack => {
                 self.inject_validator_collusion()?;
                 self.inject_invalid_state()?;
@@ -423,18 +521,25 @@ impl AdversarialTestnet {
     /// Inject validator collusion attack
     fn inject_validator_collusion(&mut self) -> Result<(), String> {
         if let Some(cfg) = &self.config.collision {
+            // Without an explicit set, byzantine_count validators are drawn from the seeded RNG
+            let colluders = if cfg.colluding_validators.is_empty() {
+                sample_sorted(&mut self.rng, self.config.validator_count, self.config.byzantine_count)
+            } else {
+                cfg.colluding_validators.clone()
+            };
+
             // Simulate colluding validators attempting to fork
             let incident = IncidentLogEntry {
                 incident_id: (self.incident_log.len() + 1) as u64,
-                detection_time: current_timestamp(),
+                detection_time: self.logical_time(),
                 incident_type: DetectedIncidentType::ValidatorMisbehavior,
                 description: format!(
                     "Detected validator collusion: {} validators attempting fork at epoch {}",
-                    cfg.colluding_validators.len(),
+                    colluders.len(),
                     cfg.attack_epoch
                 ),
                 state_before: self.capture_state_commitment(),
-                evidence: cfg.colluding_validators
+                evidence: colluders
                     .iter()
                     .map(|v| format!("validator_{}", v))
                     .collect(),
@@ -451,6 +556,8 @@ impl AdversarialTestnet {
             entry.entry_hash = entry.compute_hash();
             
             self.metrics.incidents_detected += 1;
+            self.metrics.active_incident = Some(entry.description.clone());
+            self.metrics.last_incident_time = entry.detection_time;
             self.metrics.validators_slashed += entry.evidence.len();
             
             self.incident_log.push(entry);
@@ -461,19 +568,27 @@ impl AdversarialTestnet {
     /// Inject invalid state attack
     fn inject_invalid_state(&mut self) -> Result<(), String> {
         if let Some(cfg) = &self.config.state_injection {
+            // Corrupted accounts are drawn from the seeded RNG
+            let corrupted = ((SIMULATED_ACCOUNTS as f64) * cfg.corruption_percentage / 100.0)
+                .clamp(0.0, SIMULATED_ACCOUNTS as f64) as usize;
+            let targets = sample_sorted(&mut self.rng, SIMULATED_ACCOUNTS, corrupted);
+
+            let mut evidence = vec![
+                format!("corruption_type:{}", cfg.corruption_type),
+                format!("percentage:{:.1}%", cfg.corruption_percentage),
+            ];
+            evidence.extend(targets.iter().map(|a| format!("account_{}", a)));
+
             let incident = IncidentLogEntry {
                 incident_id: (self.incident_log.len() + 1) as u64,
-                detection_time: current_timestamp(),
+                detection_time: self.logical_time(),
                 incident_type: DetectedIncidentType::StateInvalidity,
                 description: format!(
                     "Detected invalid state injection: {} corruption at epoch {}",
                     cfg.corruption_type, cfg.injection_epoch
                 ),
                 state_before: self.capture_state_commitment(),
-                evidence: vec![
-                    format!("corruption_type:{}", cfg.corruption_type),
-                    format!("percentage:{:.1}%", cfg.corruption_percentage),
-                ],
+                evidence,
                 recovery_actions: vec![
                     "Rolling back to last valid state".to_string(),
                     "Replaying transactions from checkpoint".to_string(),
@@ -487,6 +602,8 @@ impl AdversarialTestnet {
             entry.entry_hash = entry.compute_hash();
             
             self.metrics.incidents_detected += 1;
+            self.metrics.active_incident = Some(entry.description.clone());
+            self.metrics.last_incident_time = entry.detection_time;
             self.incident_log.push(entry);
         }
         Ok(())
@@ -497,7 +614,7 @@ impl AdversarialTestnet {
         if let Some(cfg) = &self.config.governance_abuse {
             let incident = IncidentLogEntry {
                 incident_id: (self.incident_log.len() + 1) as u64,
-                detection_time: current_timestamp(),
+                detection_time: self.logical_time(),
                 incident_type: DetectedIncidentType::GovernanceViolation,
                 description: format!(
                     "Detected governance abuse: Attacker proposed {} = {}",
@@ -521,6 +638,8 @@ impl AdversarialTestnet {
             entry.entry_hash = entry.compute_hash();
             
             self.metrics.incidents_detected += 1;
+            self.metrics.active_incident = Some(entry.description.clone());
+            self.metrics.last_incident_time = entry.detection_time;
             self.incident_log.push(entry);
 
             // Record the governance proposal
@@ -535,20 +654,135 @@ impl AdversarialTestnet {
         Ok(())
     }
 
+    /// Inject network partition: validators split into two groups at
+    /// `partition_epoch` and build separate chains until `heal_epoch`
+    fn inject_network_partition(&mut self) -> Result<(), String> {
+        if let Some(cfg) = &self.config.partition {
+            let validator_count = self.config.validator_count;
+            if let Some(id) = cfg.group_a.iter().chain(&cfg.group_b).find(|&&id| id >= validator_count) {
+                return Err(format!(
+                    "Partition validator {} out of range (testnet has {} validators)",
+                    id, validator_count
+                ));
+            }
+            if self.clock.current_epoch() >= cfg.heal_epoch {
+                return Err(format!(
+                    "Partition heal epoch {} has already passed (current epoch {})",
+                    cfg.heal_epoch, self.clock.current_epoch()
+                ));
+            }
+            if self.clock.current_epoch() < cfg.partition_epoch {
+                // Validated now, split when advance_epoch reaches partition_epoch
+                self.partition_scheduled = true;
+                return Ok(());
+            }
+            self.partition_scheduled = false;
+
+            let mut partition = NetworkPartition::new(
+                cfg.group_a.clone(),
+                cfg.group_b.clone(),
+                cfg.partition_epoch,
+                cfg.heal_epoch,
+                self.block_height,
+                &self.compute_state_hash(),
+            )?;
+            // Groups diverge as soon as each produces a block the other can't see
+            partition.produce_epoch(BLOCKS_PER_EPOCH, &self.execution_seed);
+            if !partition.is_detectable() {
+                return Err("Network partition not detectable".to_string());
+            }
+
+            let incident = IncidentLogEntry {
+                incident_id: (self.incident_log.len() + 1) as u64,
+                detection_time: self.logical_time(),
+                incident_type: DetectedIncidentType::NetworkPartition,
+                description: format!(
+                    "Detected network partition: {} | {} validators diverging at epoch {}",
+                    cfg.group_a.len(),
+                    cfg.group_b.len(),
+                    cfg.partition_epoch
+                ),
+                state_before: self.capture_state_commitment(),
+                evidence: vec![
+                    format!("group_a_tip:{}", hex::encode(&partition.group_a.tip_hash[..8])),
+                    format!("group_b_tip:{}", hex::encode(&partition.group_b.tip_hash[..8])),
+                    format!("fork_height:{}", partition.fork_height),
+                ],
+                recovery_actions: vec![
+                    "Halting finality across partition".to_string(),
+                    format!("Reconciling to canonical chain at epoch {}", cfg.heal_epoch),
+                ],
+                state_after: None,
+                entry_hash: vec![],
+            };
+
+            let mut entry = incident;
+            entry.entry_hash = entry.compute_hash();
+
+            self.metrics.incidents_detected += 1;
+            self.metrics.active_incident = Some(entry.description.clone());
+            self.metrics.last_incident_time = entry.detection_time;
+            self.metrics.validators_offline = cfg.group_a.len().min(cfg.group_b.len());
+            self.metrics.validators_online = self.config.validator_count - self.metrics.validators_offline;
+            self.incident_log.push(entry);
+            self.partition = Some(partition);
+        }
+        Ok(())
+    }
+
+    /// Reunify a partitioned network on one canonical chain
+    pub fn heal_partition(&mut self) -> Result<u64, String> {
+        let partition = self.partition.as_mut()
+            .ok_or_else(|| "No network partition to heal".to_string())?;
+
+        let height = partition.heal();
+        self.block_height = height;
+        self.metrics.block_height = height;
+        self.metrics.validators_online = self.config.validator_count;
+        self.metrics.validators_offline = 0;
+
+        let after = self.capture_state_commitment();
+        if let Some(incident) = self.incident_log.iter_mut()
+            .rev()
+            .find(|i| i.incident_type == DetectedIncidentType::NetworkPartition)
+        {
+            incident.state_after = Some(after);
+        }
+
+        self.state = TestnetRunState::Recovered;
+        self.metrics.run_state = TestnetRunState::Recovered;
+        Ok(height)
+    }
+
     /// Trigger autonomous detection and recovery
+    ///
+    /// Restores the last clean state commitment recorded at or before the
+    /// epoch of the most recent incident and records it as `state_after`.
     pub fn trigger_autonomous_recovery(&mut self) -> Result<(), String> {
-        if self.incident_log.is_empty() {
-            return Err("No incidents to recover from".to_string());
-        }
+        let incident_epoch = match self.incident_log.last() {
+            Some(incident) => incident.state_before.epoch,
+            None => return Err("No incidents to recover from".to_string()),
+        };
 
         self.state = TestnetRunState::DetectionTriggered;
         self.metrics.run_state = TestnetRunState::DetectionTriggered;
 
-        // Update last incident log with recovery state
+        // Last clean commitment (captured at an epoch boundary, before injection)
+        let restored = self.state_history
+            .range(..=incident_epoch)
+            .next_back()
+            .map(|(_, commitment)| commitment.clone())
+            .ok_or_else(|| format!("No clean state commitment at or before epoch {}", incident_epoch))?;
+
+        self.block_height = restored.metrics.block_height;
+        self.metrics.block_height = restored.metrics.block_height;
+
         if let Some(last_incident) = self.incident_log.last_mut() {
-            last_incident.state_after = Some(self.capture_state_commitment());
+            last_incident.state_after = Some(restored);
         }
 
+        self.metrics.recoveries_completed += 1;
+        self.metrics.active_incident = None;
         self.state = TestnetRunState::Recovered;
         self.metrics.run_state = TestnetRunState::Recovered;
         Ok(())
@@ -566,11 +800,11 @@ impl AdversarialTestnet {
     pub fn execute_upgrade(&mut self, from_version: &str, to_version: &str) -> Result<(), String> {
         let recovery = RecoveryAction {
             action_id: format!("upgrade_{}_{}", from_version, to_version),
-            recovery_epoch: self.current_epoch,
+            recovery_epoch: self.clock.current_epoch(),
             action_type: "ProtocolUpgrade".to_string(),
             description: format!("Upgrade from {} to {}", from_version, to_version),
             executed: true,
-            execution_time: current_timestamp(),
+            execution_time: self.logical_time(),
         };
 
         self.propose_governance_recovery(recovery)?;
@@ -579,18 +813,40 @@ impl AdversarialTestnet {
 
     /// Advance epoch and capture state
     pub fn advance_epoch(&mut self) -> Result<(), String> {
-        self.current_epoch += 1;
-        self.block_height += 12; // Assume ~12 blocks per epoch
+        self.clock.advance();
+
+        let split_now = self.partition_scheduled
+            && self.config.partition.as_ref()
+                .is_some_and(|cfg| self.clock.current_epoch() >= cfg.partition_epoch);
+
+        match self.partition.as_mut() {
+            // The split itself produces this epoch's blocks on both sides
+            _ if split_now => {
+                self.inject_network_partition()?;
+                if let Some(partition) = &self.partition {
+                    self.block_height = partition.group_a.height.max(partition.group_b.height);
+                }
+            }
+            Some(partition) if !partition.healed => {
+                if self.clock.current_epoch() >= partition.heal_epoch {
+                    self.heal_partition()?;
+                } else {
+                    partition.produce_epoch(BLOCKS_PER_EPOCH, &self.execution_seed);
+                    self.block_height = partition.group_a.height.max(partition.group_b.height);
+                }
+            }
+            _ => self.block_height += BLOCKS_PER_EPOCH,
+        }
         
         let state = StateCommitment {
             state_hash: self.compute_state_hash(),
-            epoch: self.current_epoch,
-            timestamp: current_timestamp(),
+            epoch: self.clock.current_epoch(),
+            timestamp: self.logical_time(),
             metrics: self.capture_metrics(),
         };
 
-        self.state_history.insert(self.current_epoch, state);
-        self.metrics.current_epoch = self.current_epoch;
+        self.state_history.insert(self.clock.current_epoch(), state);
+        self.metrics.current_epoch = self.clock.current_epoch();
         self.metrics.block_height = self.block_height;
         self.metrics.snapshot_time = current_timestamp();
 
@@ -612,6 +868,36 @@ impl AdversarialTestnet {
         self.incident_log.iter().all(|incident| incident.verify_integrity())
     }
 
+    /// Export the run as a deterministic JSON report (sorted keys) for dashboards
+    pub fn export_report(&self) -> Result<String, String> {
+        let commitment_hex = |c: &StateCommitment| hex::encode(c.compute_commitment());
+
+        let report = ExportedReport {
+            testnet_id: self.id.as_hex(),
+            run_state: self.state,
+            incidents: self.incident_log.clone(),
+            before_commitment: self.state_history.values().next().map(commitment_hex),
+            after_commitment: self.state_history.values().next_back().map(commitment_hex),
+            diff: self.state_history.values().next()
+                .zip(self.state_history.values().next_back())
+                .map(|(before, after)| before.diff(after)),
+            state_history: self.state_history.clone(),
+        };
+
+        let value = serde_json::to_value(&report)
+            .map_err(|e| format!("Failed to serialize report: {}", e))?;
+        serde_json::to_string(&sort_json_keys(value))
+            .map_err(|e| format!("Failed to serialize report: {}", e))
+    }
+
+    /// Re-check every incident's integrity hash from an exported report
+    pub fn verify_exported_report(json: &str) -> bool {
+        match serde_json::from_str::<ExportedReport>(json) {
+            Ok(report) => report.incidents.iter().all(|i| i.verify_integrity()),
+            Err(_) => false,
+        }
+    }
+
     /// Get public metrics for dashboard
     pub fn get_public_metrics(&self) -> PublicMetrics {
         self.metrics.clone()
@@ -621,11 +907,16 @@ impl AdversarialTestnet {
     // INTERNAL HELPER METHODS
     // ─────────────────────────────────────────────────────────────────────────
 
+    /// Deterministic clock: seconds since testnet genesis, derived from the epoch
+    fn logical_time(&self) -> u64 {
+        self.clock.current_epoch() * BLOCKS_PER_EPOCH * BLOCK_TIME_SECS
+    }
+
     fn capture_state_commitment(&self) -> StateCommitment {
         StateCommitment {
             state_hash: self.compute_state_hash(),
-            epoch: self.current_epoch,
-            timestamp: current_timestamp(),
+            epoch: self.clock.current_epoch(),
+            timestamp: self.logical_time(),
             metrics: self.capture_metrics(),
         }
     }
@@ -638,7 +929,7 @@ impl AdversarialTestnet {
             byzantine_validators: self.config.byzantine_count,
             finalized_blocks: self.block_height,
             proposal_acceptance_rate: self.metrics.proposal_acceptance_rate,
-            consensus_rounds: self.current_epoch * 12,
+            consensus_rounds: self.clock.current_epoch() * 12,
             network_latency_ms: self.metrics.avg_block_time * 1000.0,
         }
     }
@@ -646,12 +937,28 @@ impl AdversarialTestnet {
     fn compute_state_hash(&self) -> Vec<u8> {
         let mut hasher = Sha256::new();
         hasher.update(self.block_height.to_le_bytes());
-        hasher.update(self.current_epoch.to_le_bytes());
+        hasher.update(self.clock.current_epoch().to_le_bytes());
         hasher.update(format!("{:?}", self.state).as_bytes());
         hasher.finalize().to_vec()
     }
 }
 
+/// Serialized form of a testnet run, as produced by `export_report`
+#[derive(Serialize, Deserialize)]
+struct ExportedReport {
+    testnet_id: String,
+    run_state: TestnetRunState,
+    incidents: Vec<IncidentLogEntry>,
+    /// Commitment of the earliest recorded state (hex)
+    before_commitment: Option<String>,
+    /// Commitment of the latest recorded state (hex)
+    after_commitment: Option<String>,
+    /// Change from the earliest to the latest recorded state
+    #[serde(default)]
+    diff: Option<StateDiff>,
+    state_history: BTreeMap<u64, StateCommitment>,
+}
+
 /// Governance proposal for recovery
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GovernanceProposal {
@@ -677,6 +984,47 @@ pub struct RecoveryAction {
 // UTILITIES
 // ═══════════════════════════════════════════════════════════════════════════════
 
+/// Blocks produced per epoch by a fully connected network
+const BLOCKS_PER_EPOCH: u64 = 12;
+
+/// Target block time used by the logical clock
+const BLOCK_TIME_SECS: u64 = 12;
+
+/// Account population that state-injection targets are drawn from
+const SIMULATED_ACCOUNTS: usize = 1_000;
+
+/// Expand an execution seed into a 32-byte PRNG seed
+fn seed_bytes(seed: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(b"adversarial_testnet_rng");
+    hasher.update(seed);
+    hasher.finalize().into()
+}
+
+/// Draw `amount` distinct indices below `population`, sorted for stable logs
+fn sample_sorted(rng: &mut ChaCha20Rng, population: usize, amount: usize) -> Vec<usize> {
+    let mut picked = sample(rng, population, amount.min(population)).into_vec();
+    picked.sort_unstable();
+    picked
+}
+
+/// Rebuild every JSON object with its keys in sorted order
+fn sort_json_keys(value: serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(map) => {
+            let sorted: BTreeMap<String, serde_json::Value> = map
+                .into_iter()
+                .map(|(k, v)| (k, sort_json_keys(v)))
+                .collect();
+            serde_json::Value::Object(sorted.into_iter().collect())
+        }
+        serde_json::Value::Array(items) => {
+            serde_json::Value::Array(items.into_iter().map(sort_json_keys).collect())
+        }
+        other => other,
+    }
+}
+
 fn current_timestamp() -> u64 {
     SystemTime::now()
         .duration_since(UNIX_EPOCH)
@@ -738,7 +1086,7 @@ mod tests {
 
         let testnet = AdversarialTestnet::new(config);
         assert_eq!(testnet.state, TestnetRunState::Initializing);
-        assert_eq!(testnet.current_epoch, 0);
+        assert_eq!(testnet.current_epoch(), 0);
     }
 
     #[test]
@@ -815,18 +1163,227 @@ mod tests {
         assert_eq!(testnet.incident_log[0].incident_type, DetectedIncidentType::GovernanceViolation);
     }
 
+    fn partition_testnet() -> AdversarialTestnet {
+        let config = AdversarialScenarioConfig {
+            scenario: AdversarialScenario::NetworkPartition,
+            testnet_id: "test_partition".to_string(),
+            validator_count: 10,
+            byzantine_count: 0,
+            duration_epochs: 100,
+            partition: Some(PartitionConfig {
+                partition_epoch: 0,
+                group_a: vec![0, 1, 2, 3, 4, 5],
+                group_b: vec![6, 7, 8, 9],
+                heal_epoch: 4,
+            }),
+            ..Default::default()
+        };
+
+        let mut testnet = AdversarialTestnet::new(config);
+        testnet.initialize().unwrap();
+        testnet.inject_scenario(0).unwrap();
+        testnet
+    }
+
+    #[test]
+    fn test_network_partition_detected() {
+        let testnet = partition_testnet();
+
+        assert_eq!(testnet.incident_log.len(), 1);
+        assert_eq!(testnet.incident_log[0].incident_type, DetectedIncidentType::NetworkPartition);
+        assert!(testnet.verify_incident_integrity());
+
+        let partition = testnet.partition.as_ref().unwrap();
+        assert!(partition.is_detectable());
+        assert_ne!(partition.group_a.height, partition.group_b.height);
+    }
+
+    #[test]
+    fn test_network_partition_heals_to_same_height() {
+        let mut testnet = partition_testnet();
+
+        for _ in 0..3 {
+            testnet.advance_epoch().unwrap();
+        }
+        assert!(!testnet.partition.as_ref().unwrap().healed);
+
+        // Epoch 4 is the heal epoch
+        testnet.advance_epoch().unwrap();
+
+        let partition = testnet.partition.as_ref().unwrap();
+        assert!(partition.healed);
+        assert!(partition.is_converged());
+        assert_eq!(partition.group_a.height, partition.group_b.height);
+        assert_eq!(testnet.block_height, partition.group_a.height);
+        assert_eq!(testnet.state, TestnetRunState::Recovered);
+        assert!(testnet.incident_log[0].state_after.is_some());
+    }
+
+    #[test]
+    fn test_network_partition_waits_for_partition_epoch() {
+        let config = AdversarialScenarioConfig {
+            scenario: AdversarialScenario::NetworkPartition,
+            testnet_id: "test_partition_scheduled".to_string(),
+            validator_count: 10,
+            partition: Some(PartitionConfig {
+                partition_epoch: 2,
+                group_a: vec![0, 1, 2, 3, 4, 5, 6, 7, 8],
+                group_b: vec![9],
+                heal_epoch: 5,
+            }),
+            ..Default::default()
+        };
+
+        let mut testnet = AdversarialTestnet::new(config);
+        testnet.initialize().unwrap();
+        testnet.inject_scenario(0).unwrap();
+        assert!(testnet.partition.is_none());
+        assert!(testnet.incident_log.is_empty());
+
+        testnet.advance_epoch().unwrap();
+        assert!(testnet.partition.is_none());
+
+        // Epoch 2: the network splits, and the lone validator still forks off
+        testnet.advance_epoch().unwrap();
+        let partition = testnet.partition.as_ref().unwrap();
+        assert!(partition.is_detectable());
+        assert!(partition.group_b.height > partition.fork_height);
+        assert_eq!(testnet.incident_log.len(), 1);
+
+        for _ in 0..3 {
+            testnet.advance_epoch().unwrap();
+        }
+        assert!(testnet.partition.as_ref().unwrap().healed);
+    }
+
+    #[test]
+    fn test_network_partition_rejects_unknown_validators() {
+        let config = AdversarialScenarioConfig {
+            scenario: AdversarialScenario::NetworkPartition,
+            testnet_id: "test_partition_ids".to_string(),
+            validator_count: 4,
+            partition: Some(PartitionConfig {
+                partition_epoch: 0,
+                group_a: vec![0, 1],
+                group_b: vec![2, 7],
+                heal_epoch: 3,
+            }),
+            ..Default::default()
+        };
+
+        let mut testnet = AdversarialTestnet::new(config);
+        testnet.initialize().unwrap();
+        assert!(testnet.inject_scenario(0).is_err());
+        assert!(testnet.partition.is_none());
+    }
+
+    #[test]
+    fn test_autonomous_recovery_restores_pre_incident_state() {
+        let config = AdversarialScenarioConfig {
+            scenario: AdversarialScenario::InvalidStateInjection,
+            testnet_id: "test_recovery".to_string(),
+            state_injection: Some(StateInjectionConfig {
+                injection_epoch: 2,
+                corruption_percentage: 10.0,
+                corruption_type: "BadBalance".to_string(),
+            }),
+            ..Default::default()
+        };
+
+        let mut testnet = AdversarialTestnet::new(config);
+        testnet.initialize().unwrap();
+        testnet.advance_epoch().unwrap();
+        testnet.advance_epoch().unwrap();
+        let clean_root = testnet.state_history[&2].state_hash.clone();
+
+        testnet.inject_scenario(2).unwrap();
+        assert!(testnet.metrics.active_incident.is_some());
+        assert_ne!(testnet.incident_log[0].state_before.state_hash, clean_root);
+
+        testnet.trigger_autonomous_recovery().unwrap();
+
+        let restored = testnet.incident_log[0].state_after.as_ref().unwrap();
+        assert_eq!(restored.state_hash, clean_root);
+        assert_eq!(restored.epoch, 2);
+        assert_eq!(testnet.metrics.recoveries_completed, 1);
+        assert!(testnet.metrics.active_incident.is_none());
+        assert_eq!(testnet.state, TestnetRunState::Recovered);
+    }
+
+    fn randomized_injection(testnet_id: &str) -> AdversarialTestnet {
+        let config = AdversarialScenarioConfig {
+            scenario: AdversarialScenario::CombinedAttack,
+            testnet_id: testnet_id.to_string(),
+            collision: Some(CollisionAttackConfig {
+                colluding_validators: vec![], // drawn from the RNG
+                attack_epoch: 1,
+                attack_duration_blocks: 12,
+                attempt_fork: true,
+            }),
+            state_injection: Some(StateInjectionConfig {
+                injection_epoch: 1,
+                corruption_percentage: 5.0,
+                corruption_type: "BadBalance".to_string(),
+            }),
+            ..Default::default()
+        };
+
+        let mut testnet = AdversarialTestnet::new(config);
+        testnet.initialize().unwrap();
+        testnet.advance_epoch().unwrap();
+        testnet.inject_scenario(1).unwrap();
+        testnet
+    }
+
+    #[test]
+    fn test_seeded_rng_reproduces_randomized_targets() {
+        let run1 = randomized_injection("seeded_rng");
+        let run2 = randomized_injection("seeded_rng");
+        let other = randomized_injection("seeded_rng_other");
+
+        let targets = |t: &AdversarialTestnet| t.incident_log[1].evidence.clone();
+        assert_eq!(targets(&run1).len(), 2 + 50);
+        assert_eq!(run1.incident_log[0].evidence.len(), run1.config.byzantine_count);
+
+        // Same seed: byte-identical incident logs
+        assert_eq!(
+            serde_json::to_vec(&run1.incident_log).unwrap(),
+            serde_json::to_vec(&run2.incident_log).unwrap()
+        );
+
+        // Different seed: different corruption targets
+        assert_ne!(targets(&run1), targets(&other));
+    }
+
     #[test]
     fn test_metrics_update() {
         let config = AdversarialScenarioConfig::default();
         let mut testnet = AdversarialTestnet::new(config);
         
-        let initial_epoch = testnet.current_epoch;
+        let initial_epoch = testnet.current_epoch();
         let _ = testnet.advance_epoch();
         
-        assert_eq!(testnet.current_epoch, initial_epoch + 1);
+        assert_eq!(testnet.current_epoch(), initial_epoch + 1);
         assert_eq!(testnet.metrics.current_epoch, initial_epoch + 1);
     }
 
+    #[test]
+    fn test_testnet_advances_shared_epoch_clock() {
+        let clock = EpochClock::new(5);
+        let mut testnet = AdversarialTestnet::new(AdversarialScenarioConfig::default())
+            .with_clock(clock.clone());
+        testnet.initialize().unwrap();
+        assert!(testnet.state_history.contains_key(&5));
+
+        testnet.advance_epoch().unwrap();
+        assert_eq!(clock.current_epoch(), 6);
+        assert_eq!(testnet.metrics.current_epoch, 6);
+
+        // Epochs advanced elsewhere are observed by the testnet
+        clock.advance();
+        assert_eq!(testnet.current_epoch(), 7);
+    }
+
     #[test]
     fn test_state_history_tracking() {
         let config = AdversarialScenarioConfig::default();
@@ -863,4 +1420,94 @@ mod tests {
 
         assert!(testnet.verify_incident_integrity());
     }
+
+    #[test]
+    fn test_export_report_round_trip() {
+        let config = AdversarialScenarioConfig {
+            scenario: AdversarialScenario::ValidatorCollusion,
+            testnet_id: "test_export".to_string(),
+            validator_count: 20,
+            byzantine_count: 3,
+            duration_epochs: 100,
+            collision: Some(CollisionAttackConfig {
+                colluding_validators: vec![1, 2],
+                attack_epoch: 0,
+                attack_duration_blocks: 24,
+                attempt_fork: true,
+            }),
+            ..Default::default()
+        };
+
+        let mut testnet = AdversarialTestnet::new(config);
+        testnet.initialize().unwrap();
+        testnet.inject_scenario(0).unwrap();
+
+        let json = testnet.export_report().unwrap();
+        assert_eq!(json, testnet.export_report().unwrap());
+        assert!(AdversarialTestnet::verify_exported_report(&json));
+
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["testnet_id"], testnet.id.as_hex());
+        assert_eq!(value["incidents"].as_array().unwrap().len(), 1);
+        assert!(value["before_commitment"].is_string());
+        assert!(value["diff"]["state_hash_changed"].is_boolean());
+
+        // Tampering with an incident breaks its integrity hash
+        let tampered = json.replace("Detected validator collusion", "Nothing happened");
+        assert_ne!(tampered, json);
+        assert!(!AdversarialTestnet::verify_exported_report(&tampered));
+        assert!(!AdversarialTestnet::verify_exported_report("not json"));
+    }
+
+    #[test]
+    fn test_state_commitment_diff_reports_attack_deltas() {
+        let before = StateCommitment {
+            state_hash: vec![1; 32],
+            epoch: 4,
+            timestamp: 576,
+            metrics: StateMetrics {
+                block_height: 100,
+                validator_count: 20,
+                honest_validators: 20,
+                byzantine_validators: 0,
+                finalized_blocks: 90,
+                ..StateMetrics::default()
+            },
+        };
+        let after = StateCommitment {
+            state_hash: vec![2; 32],
+            epoch: 5,
+            timestamp: 720,
+            metrics: StateMetrics {
+                block_height: 120,
+                validator_count: 19,
+                honest_validators: 16,
+                byzantine_validators: 3,
+                finalized_blocks: 96,
+                ..StateMetrics::default()
+            },
+        };
+
+        let diff = before.diff(&after);
+        assert_eq!((diff.from_epoch, diff.to_epoch), (4, 5));
+        assert_eq!(diff.block_height_delta, 20);
+        assert_eq!(diff.validator_count_delta, -1);
+        assert_eq!(diff.byzantine_count_delta, 3);
+        assert_eq!(diff.finalized_blocks_delta, 6);
+        assert!((diff.finalization_rate_delta - (80.0 - 90.0)).abs() < 1e-9);
+        assert!(diff.state_hash_changed);
+
+        // Deltas beyond the i64 range saturate instead of wrapping
+        let mut huge = after.clone();
+        huge.metrics.block_height = u64::MAX;
+        assert_eq!(before.diff(&huge).block_height_delta, i64::MAX);
+        assert_eq!(huge.diff(&before).block_height_delta, i64::MIN);
+
+        let unchanged = before.diff(&before);
+        assert_eq!(unchanged.block_height_delta, 0);
+        assert!(!unchanged.state_hash_changed);
+
+        let json = serde_json::to_string(&diff).unwrap();
+        assert_eq!(serde_json::from_str::<StateDiff>(&json).unwrap(), diff);
+    }
 }
@@ -8,16 +8,12 @@ fn multisig_approve(_sender: &str, _approvers: &[&str]) -> bool { true }
 #[allow(dead_code)]
 fn execute_smart_recovery(_sender: &str, _transaction: &Transaction) {}
 #[allow(dead_code)]
-struct GossipProtocol;
-impl GossipProtocol {
-    pub fn broadcast_message(_msg: &str) {}
-}
-#[allow(dead_code)]
 #[derive(Clone)]
 pub struct Transaction {
     pub sender: String,
 }
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
+use thiserror::Error;
 
 /// Stores lost asset claims for verification
 struct LostAssetRecord {
@@ -44,6 +40,7 @@ impl AntiAssetLoss {
     /// Registers a lost asset for potential recovery
     pub fn report_lost_asset(&mut self, transaction: Transaction) {
         let current_time = chrono::Utc::now().timestamp() as u64;
+        log::info!("Lost asset reported for {}", transaction.sender);
         self.lost_assets.insert(
             transaction.sender.clone(),
             LostAssetRecord {
@@ -52,7 +49,6 @@ impl AntiAssetLoss {
                 request_timestamp: current_time,
             },
         );
-        GossipProtocol::broadcast_message("🔵 Lost asset reported on BLEEP network.");
     }
 
     /// Requests asset recovery with identity verification
@@ -74,7 +70,7 @@ impl AntiAssetLoss {
             }
 
             record.recovery_requested = true;
-            GossipProtocol::broadcast_message("🔵 Recovery request initiated.");
+            log::info!("Recovery request initiated for {}", sender);
             Ok("✅ Recovery request successful. Awaiting validator approval.".to_string())
         } else {
             Err("⚠️ No lost asset record found.".to_string())
@@ -101,7 +97,7 @@ impl AntiAssetLoss {
             // Execute the smart contract to ensure decentralized recovery
             execute_smart_recovery(sender, &record.transaction);
 
-            GossipProtocol::broadcast_message("✅ Asset successfully recovered via blockchain.");
+            log::info!("Asset recovered for {}", sender);
             self.lost_assets.remove(sender);
             Ok("✅ Asset recovery successful.".to_string())
         } else {
@@ -109,3 +105,420 @@ impl AntiAssetLoss {
         }
     }
 }
+
+// ─── Guardian recovery ────────────────────────────────────────────────────────
+
+/// Why a guardian recovery operation was refused.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum RecoveryError {
+    #[error("threshold {threshold} is invalid for {guardians} guardians")]
+    InvalidThreshold { threshold: usize, guardians: usize },
+
+    #[error("account {0} has no guardians designated")]
+    NoGuardians(String),
+
+    #[error("{guardian} is not a guardian of {account}")]
+    NotGuardian { account: String, guardian: String },
+
+    #[error("account {0} already has a pending recovery claim")]
+    ClaimPending(String),
+
+    #[error("no pending recovery claim for {0}")]
+    NoPendingClaim(String),
+
+    #[error("account {0} has no registered key")]
+    UnknownAccount(String),
+
+    #[error("account {0} already has a registered key")]
+    AccountExists(String),
+
+    #[error("signature is not from the current key of {0}")]
+    NotOwner(String),
+
+    #[error("claim for {account} has {approvals} of {threshold} guardian approvals")]
+    BelowThreshold { account: String, approvals: usize, threshold: usize },
+
+    #[error("claim for {account} is time-locked until {unlocks_at}")]
+    TimeLocked { account: String, unlocks_at: u64 },
+}
+
+/// Guardians designated by an account and how many must approve a recovery.
+#[derive(Debug, Clone)]
+pub struct GuardianSet {
+    pub guardians: BTreeSet<String>,
+    pub threshold: usize,
+}
+
+/// Lifecycle of a `RecoveryClaim`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecoveryStatus {
+    Pending,
+    Cancelled,
+    Executed,
+}
+
+/// A guardian-initiated request to move an account's funds to a new key.
+///
+/// The time-lock starts once the M-of-N threshold is reached, giving the
+/// original owner a full window to cancel a claim raised by a malicious
+/// guardian set.
+#[derive(Debug, Clone)]
+pub struct RecoveryClaim {
+    pub account: String,
+    /// Public key the account rotates to on execution
+    pub new_key: Vec<u8>,
+    pub approvals: BTreeSet<String>,
+    pub opened_at: u64,
+    /// Set when the approval threshold is first reached
+    pub unlocks_at: Option<u64>,
+    pub status: RecoveryStatus,
+}
+
+/// M-of-N guardian recovery with a mandatory owner-cancellable time-lock.
+///
+/// All operations take the current time explicitly so claims resolve the
+/// same way on every node. Owner operations are signed (SPHINCS+) by the
+/// account's current key and carry a per-account nonce against replay.
+pub struct GuardianRecovery {
+    guardian_sets: HashMap<String, GuardianSet>,
+    claims: HashMap<String, RecoveryClaim>,
+    account_keys: HashMap<String, Vec<u8>>,
+    owner_nonces: HashMap<String, u64>,
+    time_lock_secs: u64,
+}
+
+impl GuardianRecovery {
+    pub fn new(time_lock_secs: u64) -> Self {
+        Self {
+            guardian_sets: HashMap::new(),
+            claims: HashMap::new(),
+            account_keys: HashMap::new(),
+            owner_nonces: HashMap::new(),
+            time_lock_secs,
+        }
+    }
+
+    /// Registers the initial public key of `account`.
+    ///
+    /// Afterwards the key changes only through an executed recovery.
+    pub fn register_account(&mut self, account: &str, public_key: Vec<u8>) -> Result<(), RecoveryError> {
+        if self.account_keys.contains_key(account) {
+            return Err(RecoveryError::AccountExists(account.to_string()));
+        }
+        self.account_keys.insert(account.to_string(), public_key);
+        Ok(())
+    }
+
+    /// The current public key of `account`.
+    pub fn account_key(&self, account: &str) -> Option<&[u8]> {
+        self.account_keys.get(account).map(Vec::as_slice)
+    }
+
+    /// Nonce the next owner-signed operation on `account` must carry.
+    pub fn owner_nonce(&self, account: &str) -> u64 {
+        self.owner_nonces.get(account).copied().unwrap_or(0)
+    }
+
+    /// Bytes the owner signs to designate `guardians` with `threshold`.
+    pub fn set_guardians_payload(account: &str, guardians: &[&str], threshold: usize, nonce: u64) -> Vec<u8> {
+        let guardians: BTreeSet<&str> = guardians.iter().copied().collect();
+        let mut payload = owner_payload(b"set-guardians", account, nonce);
+        payload.extend_from_slice(&(threshold as u64).to_le_bytes());
+        for guardian in guardians {
+            payload.extend_from_slice(&(guardian.len() as u64).to_le_bytes());
+            payload.extend_from_slice(guardian.as_bytes());
+        }
+        payload
+    }
+
+    /// Bytes the owner signs to cancel the pending claim on `account`.
+    pub fn cancel_payload(account: &str, nonce: u64) -> Vec<u8> {
+        owner_payload(b"cancel", account, nonce)
+    }
+
+    /// Designates (or replaces) the guardians of `account`.
+    ///
+    /// `signature` is the owner's signature over `set_guardians_payload` at
+    /// the current `owner_nonce`.
+    ///
+    /// SAFETY: Refused while a claim is pending so the owner cannot be raced
+    /// by a guardian set swap, and vice versa.
+    pub fn set_guardians(
+        &mut self,
+        account: &str,
+        guardians: &[&str],
+        threshold: usize,
+        signature: &[u8],
+    ) -> Result<(), RecoveryError> {
+        let payload = Self::set_guardians_payload(account, guardians, threshold, self.owner_nonce(account));
+        self.check_owner(account, &payload, signature)?;
+        let guardians: BTreeSet<String> = guardians
+            .iter()
+            .filter(|g| **g != account)
+            .map(|g| g.to_string())
+            .collect();
+        if threshold == 0 || threshold > guardians.len() {
+            return Err(RecoveryError::InvalidThreshold { threshold, guardians: guardians.len() });
+        }
+        if self.pending_claim(account).is_some() {
+            return Err(RecoveryError::ClaimPending(account.to_string()));
+        }
+        self.guardian_sets
+            .insert(account.to_string(), GuardianSet { guardians, threshold });
+        self.bump_nonce(account);
+        Ok(())
+    }
+
+    /// Opens a claim to move `account` to `new_key`; counts as `guardian`'s approval.
+    pub fn open_claim(
+        &mut self,
+        guardian: &str,
+        account: &str,
+        new_key: &[u8],
+        now: u64,
+    ) -> Result<(), RecoveryError> {
+        self.check_guardian(guardian, account)?;
+        if self.pending_claim(account).is_some() {
+            return Err(RecoveryError::ClaimPending(account.to_string()));
+        }
+        self.claims.insert(
+            account.to_string(),
+            RecoveryClaim {
+                account: account.to_string(),
+                new_key: new_key.to_vec(),
+                approvals: BTreeSet::new(),
+                opened_at: now,
+                unlocks_at: None,
+                status: RecoveryStatus::Pending,
+            },
+        );
+        self.approve(guardian, account, now)
+    }
+
+    /// Records `guardian`'s approval, starting the time-lock at threshold.
+    pub fn approve(&mut self, guardian: &str, account: &str, now: u64) -> Result<(), RecoveryError> {
+        self.check_guardian(guardian, account)?;
+        let threshold = self.guardian_sets[account].threshold;
+        let time_lock_secs = self.time_lock_secs;
+        let claim = self
+            .claims
+            .get_mut(account)
+            .filter(|c| c.status == RecoveryStatus::Pending)
+            .ok_or_else(|| RecoveryError::NoPendingClaim(account.to_string()))?;
+
+        claim.approvals.insert(guardian.to_string());
+        if claim.unlocks_at.is_none() && claim.approvals.len() >= threshold {
+            claim.unlocks_at = Some(now.saturating_add(time_lock_secs));
+        }
+        Ok(())
+    }
+
+    /// Cancels the pending claim; only the account owner may do this.
+    ///
+    /// `signature` is the owner's signature over `cancel_payload` at the
+    /// current `owner_nonce`.
+    pub fn cancel(&mut self, account: &str, signature: &[u8]) -> Result<(), RecoveryError> {
+        let payload = Self::cancel_payload(account, self.owner_nonce(account));
+        self.check_owner(account, &payload, signature)?;
+        let claim = self
+            .claims
+            .get_mut(account)
+            .filter(|c| c.status == RecoveryStatus::Pending)
+            .ok_or_else(|| RecoveryError::NoPendingClaim(account.to_string()))?;
+        claim.status = RecoveryStatus::Cancelled;
+        self.bump_nonce(account);
+        Ok(())
+    }
+
+    /// Executes the claim once the threshold is met and the time-lock has
+    /// elapsed, rotating the account to the claimed key and returning it.
+    ///
+    /// SAFETY: The old key stops authorising owner operations immediately.
+    pub fn execute(&mut self, account: &str, now: u64) -> Result<Vec<u8>, RecoveryError> {
+        let threshold = self
+            .guardian_sets
+            .get(account)
+            .map(|set| set.threshold)
+            .ok_or_else(|| RecoveryError::NoGuardians(account.to_string()))?;
+        let claim = self
+            .claims
+            .get_mut(account)
+            .filter(|c| c.status == RecoveryStatus::Pending)
+            .ok_or_else(|| RecoveryError::NoPendingClaim(account.to_string()))?;
+
+        let unlocks_at = match claim.unlocks_at {
+            Some(t) if claim.approvals.len() >= threshold => t,
+            _ => {
+                return Err(RecoveryError::BelowThreshold {
+                    account: account.to_string(),
+                    approvals: claim.approvals.len(),
+                    threshold,
+                })
+            }
+        };
+        if now < unlocks_at {
+            return Err(RecoveryError::TimeLocked { account: account.to_string(), unlocks_at });
+        }
+
+        claim.status = RecoveryStatus::Executed;
+        let new_key = claim.new_key.clone();
+        self.account_keys.insert(account.to_string(), new_key.clone());
+        log::info!("Guardian recovery executed for {}", account);
+        Ok(new_key)
+    }
+
+    /// The claim for `account`, in whatever state it ended.
+    pub fn claim(&self, account: &str) -> Option<&RecoveryClaim> {
+        self.claims.get(account)
+    }
+
+    fn pending_claim(&self, account: &str) -> Option<&RecoveryClaim> {
+        self.claims
+            .get(account)
+            .filter(|c| c.status == RecoveryStatus::Pending)
+    }
+
+    fn check_owner(&self, account: &str, payload: &[u8], signature: &[u8]) -> Result<(), RecoveryError> {
+        let key = self
+            .account_keys
+            .get(account)
+            .ok_or_else(|| RecoveryError::UnknownAccount(account.to_string()))?;
+        if !bleep_crypto::tx_signer::verify_tx_signature(payload, signature, key) {
+            return Err(RecoveryError::NotOwner(account.to_string()));
+        }
+        Ok(())
+    }
+
+    fn bump_nonce(&mut self, account: &str) {
+        let nonce = self.owner_nonces.entry(account.to_string()).or_insert(0);
+        *nonce = nonce.saturating_add(1);
+    }
+
+    fn check_guardian(&self, guardian: &str, account: &str) -> Result<(), RecoveryError> {
+        let set = self
+            .guardian_sets
+            .get(account)
+            .ok_or_else(|| RecoveryError::NoGuardians(account.to_string()))?;
+        if !set.guardians.contains(guardian) {
+            return Err(RecoveryError::NotGuardian {
+                account: account.to_string(),
+                guardian: guardian.to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Domain-separated prefix of every owner-signed payload.
+fn owner_payload(action: &[u8], account: &str, nonce: u64) -> Vec<u8> {
+    let mut payload = b"BLEEP-GUARDIAN-V1:".to_vec();
+    payload.extend_from_slice(action);
+    payload.extend_from_slice(&(account.len() as u64).to_le_bytes());
+    payload.extend_from_slice(account.as_bytes());
+    payload.extend_from_slice(&nonce.to_le_bytes());
+    payload
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bleep_crypto::tx_signer::{generate_tx_keypair, sign_tx_payload};
+
+    const LOCK: u64 = 86_400;
+    const T0: u64 = 1_700_000_000;
+
+    /// Recovery with alice registered and guarded 2-of-3; returns alice's secret key
+    fn recovery() -> (GuardianRecovery, Vec<u8>) {
+        let (pk, sk) = generate_tx_keypair();
+        let mut r = GuardianRecovery::new(LOCK);
+        r.register_account("alice", pk).unwrap();
+        let guardians = ["g1", "g2", "g3"];
+        let payload = GuardianRecovery::set_guardians_payload("alice", &guardians, 2, 0);
+        r.set_guardians("alice", &guardians, 2, &sign_tx_payload(&payload, &sk).unwrap()).unwrap();
+        (r, sk)
+    }
+
+    fn sign_cancel(r: &GuardianRecovery, sk: &[u8]) -> Vec<u8> {
+        let payload = GuardianRecovery::cancel_payload("alice", r.owner_nonce("alice"));
+        sign_tx_payload(&payload, sk).unwrap()
+    }
+
+    #[test]
+    fn test_guardian_recovery_after_time_lock() {
+        let (mut r, old_sk) = recovery();
+        let (new_pk, _) = generate_tx_keypair();
+        r.open_claim("g1", "alice", &new_pk, T0).unwrap();
+        r.approve("g2", "alice", T0 + 10).unwrap();
+
+        assert_eq!(
+            r.execute("alice", T0 + 10 + LOCK - 1),
+            Err(RecoveryError::TimeLocked { account: "alice".into(), unlocks_at: T0 + 10 + LOCK })
+        );
+        assert_eq!(r.execute("alice", T0 + 10 + LOCK), Ok(new_pk.clone()));
+        assert_eq!(r.claim("alice").unwrap().status, RecoveryStatus::Executed);
+
+        // The account rotated: the recovered key is current, the lost one is dead
+        assert_eq!(r.account_key("alice"), Some(new_pk.as_slice()));
+        let payload = GuardianRecovery::set_guardians_payload("alice", &["g4", "g5"], 1, r.owner_nonce("alice"));
+        assert_eq!(
+            r.set_guardians("alice", &["g4", "g5"], 1, &sign_tx_payload(&payload, &old_sk).unwrap()),
+            Err(RecoveryError::NotOwner("alice".into()))
+        );
+    }
+
+    #[test]
+    fn test_owner_cancels_during_time_lock() {
+        let (mut r, sk) = recovery();
+        r.open_claim("g1", "alice", b"attacker-key", T0).unwrap();
+        r.approve("g3", "alice", T0).unwrap();
+
+        // A guardian cannot pose as the owner
+        let (_, guardian_sk) = generate_tx_keypair();
+        let forged = sign_cancel(&r, &guardian_sk);
+        assert_eq!(r.cancel("alice", &forged), Err(RecoveryError::NotOwner("alice".into())));
+        let signature = sign_cancel(&r, &sk);
+        r.cancel("alice", &signature).unwrap();
+
+        assert_eq!(r.execute("alice", T0 + LOCK), Err(RecoveryError::NoPendingClaim("alice".into())));
+        assert_eq!(r.claim("alice").unwrap().status, RecoveryStatus::Cancelled);
+
+        // The cancellation cannot be replayed against a later claim
+        r.open_claim("g1", "alice", b"attacker-key", T0 + LOCK).unwrap();
+        assert_eq!(r.cancel("alice", &signature), Err(RecoveryError::NotOwner("alice".into())));
+    }
+
+    #[test]
+    fn test_guardian_designation_requires_owner_signature() {
+        let (mut r, _) = recovery();
+        let (_, mallory_sk) = generate_tx_keypair();
+        let guardians = ["m1", "m2"];
+        let payload = GuardianRecovery::set_guardians_payload("alice", &guardians, 1, r.owner_nonce("alice"));
+        assert_eq!(
+            r.set_guardians("alice", &guardians, 1, &sign_tx_payload(&payload, &mallory_sk).unwrap()),
+            Err(RecoveryError::NotOwner("alice".into()))
+        );
+        assert!(matches!(r.approve("m1", "alice", T0), Err(RecoveryError::NotGuardian { .. })));
+
+        assert_eq!(
+            r.set_guardians("bob", &guardians, 1, &[]),
+            Err(RecoveryError::UnknownAccount("bob".into()))
+        );
+    }
+
+    #[test]
+    fn test_below_threshold_claim_rejected() {
+        let (mut r, _) = recovery();
+        r.open_claim("g1", "alice", b"alice-new-key", T0).unwrap();
+        // A repeated approval from the same guardian does not count twice
+        r.approve("g1", "alice", T0).unwrap();
+
+        assert_eq!(
+            r.execute("alice", T0 + 10 * LOCK),
+            Err(RecoveryError::BelowThreshold { account: "alice".into(), approvals: 1, threshold: 2 })
+        );
+        assert!(matches!(
+            r.approve("mallory", "alice", T0),
+            Err(RecoveryError::NotGuardian { .. })
+        ));
+    }
+}
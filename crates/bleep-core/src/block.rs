@@ -52,6 +52,12 @@ pub struct Transaction {
     pub amount: u64,
     pub timestamp: u64,
     pub signature: Vec<u8>,
+    /// Sender nonce the transaction was signed at
+    #[serde(default)]
+    pub nonce: u64,
+    /// Network the transaction was signed for
+    #[serde(default)]
+    pub chain_id: u64,
 }
 
 /// Consensus mode enumeration.
@@ -187,61 +193,6 @@ impl Block {
         Ok(())
     }
 
-    /// Verify block signature using quantum-secure verification
-    /// 
-    /// SAFETY: Verifies that the block's validator_signature was created
-    /// by the holder of the corresponding private key. Rejects invalid signatures.
-    /// Uses deterministic verification based on SPHINCS-SHA2.
-    pub fn verify_signature(&self, public_key: &[u8]) -> Result<bool, String> {
-        // Validate public key length (must be exactly 32 bytes)
-        if public_key.len() != 32 {
-            return Err("Public key must be exactly 32 bytes".to_string());
-        }
-        
-        // Empty signature is always invalid
-        if self.validator_signature.is_empty() {
-            return Ok(false);
-        }
-        
-        // Derive public key from bytes
-        let _pub_key = PublicKey::from_bytes(public_key)
-            .map_err(|e| format!("Invalid public key: {}", e))?;
-        
-        // Signature format verification: must contain pub_key_hash + message_hash + signature_proof
-        // Minimum valid signature: 32 + 32 + 1 = 65 bytes
-        if self.validator_signature.len() < 65 {
-            return Ok(false);
-        }
-        
-        // Extract public key hash from stored signature (first 32 bytes)
-        let stored_pub_key_hash = &self.validator_signature[..32];
-        
-        // Verify the public key matches the signature's public key hash
-        let mut hasher = Sha3_256::new();
-        hasher.update(public_key);
-        let expected_hash = hasher.finalize();
-        
-        if stored_pub_key_hash != expected_hash.as_slice() {
-            return Ok(false);
-        }
-        
-        // Extract message hash from stored signature (next 32 bytes)
-        let stored_message_hash = &self.validator_signature[32..64];
-        
-        // Verify the block hash matches the signature's message hash
-        let block_hash = self.compute_hash();
-        let mut message_hasher = Sha3_256::new();
-        message_hasher.update(block_hash.as_bytes());
-        let computed_message_hash = message_hasher.finalize();
-        
-        if stored_message_hash != computed_message_hash.as_slice() {
-            return Ok(false);
-        }
-        
-        // All checks passed
-        Ok(true)
-    }
-
     /// Verify the block signature.
     ///
     /// Accepts three formats:
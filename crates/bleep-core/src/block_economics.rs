@@ -0,0 +1,140 @@
+//! # Block Economics Report
+//!
+//! Per-block fee accounting for node operators. During block assembly the
+//! producer records the fee charged for each included transaction and builds
+//! a `BlockEconomicsReport` for the block.
+//!
+//! The split uses the governance-set `FeeRoutingConfig` (remainder to the
+//! proposer), so a report reconciles with `BleepEconomics::process_block_fees`
+//! for the same block as long as both use the same routing.
+
+use serde::{Deserialize, Serialize};
+
+use bleep_economics::distribution::{FeeDistribution, FeeRoutingConfig};
+
+use crate::block::Block;
+
+/// Fee revenue collected by one block and where it went.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlockEconomicsReport {
+    pub block_index:      u64,
+    pub tx_count:         usize,
+    /// Sum of all transaction fees in the block (µBLEEP).
+    pub total_fees:       u128,
+    /// Fees permanently removed from supply.
+    pub burned:           u128,
+    /// Fees credited to the block proposer.
+    pub proposer_revenue: u128,
+    /// Fees routed to the treasury.
+    pub treasury:         u128,
+}
+
+impl BlockEconomicsReport {
+    /// Build the report for `block` from the fee charged for each of its
+    /// transactions, in block order, split under `routing`.
+    pub fn assemble(
+        block:   &Block,
+        tx_fees: &[u128],
+        routing: &FeeRoutingConfig,
+    ) -> Result<Self, String> {
+        if tx_fees.len() != block.transactions.len() {
+            return Err(format!(
+                "Block {} has {} transactions but {} fees were supplied",
+                block.index,
+                block.transactions.len(),
+                tx_fees.len()
+            ));
+        }
+        let total_fees = tx_fees
+            .iter()
+            .try_fold(0u128, |acc, fee| acc.checked_add(*fee))
+            .ok_or_else(|| format!("Fee total overflow in block {}", block.index))?;
+
+        let dist = FeeDistribution::compute_with(total_fees, routing);
+        Ok(Self {
+            block_index:      block.index,
+            tx_count:         tx_fees.len(),
+            total_fees,
+            burned:           dist.burned,
+            proposer_revenue: dist.validator_reward,
+            treasury:         dist.treasury,
+        })
+    }
+
+    /// True if every collected fee is accounted for exactly once.
+    pub fn is_balanced(&self) -> bool {
+        self.burned
+            .checked_add(self.proposer_revenue)
+            .and_then(|v| v.checked_add(self.treasury))
+            == Some(self.total_fees)
+    }
+
+    /// True if the economics engine settled this block's fees identically.
+    pub fn reconciles_with(&self, settled: &FeeDistribution) -> bool {
+        settled.total_fee == self.total_fees
+            && settled.burned == self.burned
+            && settled.validator_reward == self.proposer_revenue
+            && settled.treasury == self.treasury
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::block::Transaction;
+    use bleep_economics::integration::BleepEconomics;
+
+    fn block_with_txs(n: usize) -> Block {
+        let txs = (0..n)
+            .map(|i| Transaction {
+                sender:    format!("sender{}", i),
+                receiver:  "receiver".to_string(),
+                amount:    100,
+                timestamp: 1_700_000_000 + i as u64,
+                signature: vec![],
+                nonce:     0,
+                chain_id:  0,
+            })
+            .collect();
+        Block::new(7, txs, "prev".to_string())
+    }
+
+    #[test]
+    fn test_report_accounts_for_all_fees() {
+        let block   = block_with_txs(3);
+        let routing = FeeRoutingConfig::default();
+        let report  = BlockEconomicsReport::assemble(&block, &[1_000, 2_001, 7], &routing).unwrap();
+        assert_eq!(report.block_index, 7);
+        assert_eq!(report.tx_count, 3);
+        assert_eq!(report.total_fees, 3_008);
+        assert!(report.is_balanced());
+        assert_eq!(
+            report.burned + report.proposer_revenue + report.treasury,
+            report.total_fees
+        );
+    }
+
+    #[test]
+    fn test_report_matches_process_block_fees() {
+        let block   = block_with_txs(4);
+        let routing = FeeRoutingConfig::new(1_000, 6_000).unwrap();
+        let report  = BlockEconomicsReport::assemble(&block, &[5_000, 333, 12_345, 1], &routing).unwrap();
+
+        let mut econ = BleepEconomics::genesis();
+        econ.set_fee_routing(routing).unwrap();
+        econ.tokenomics.supply_state.total_minted       = 1_000_000_000;
+        econ.tokenomics.supply_state.circulating_supply = 1_000_000_000;
+        let settled = econ.process_block_fees(report.total_fees).unwrap();
+
+        assert!(report.reconciles_with(&settled));
+        assert_eq!(econ.tokenomics.supply_state.total_burned, report.burned);
+    }
+
+    #[test]
+    fn test_report_rejects_fee_count_mismatch() {
+        let block = block_with_txs(2);
+        let routing = FeeRoutingConfig::default();
+        assert!(BlockEconomicsReport::assemble(&block, &[1], &routing).is_err());
+        assert!(BlockEconomicsReport::assemble(&block, &[u128::MAX, 1], &routing).is_err());
+    }
+}
@@ -1,4 +1,21 @@
 use crate::block::Block;
+use thiserror::Error;
+
+/// Default tolerance for block timestamps ahead of the local clock.
+pub const DEFAULT_MAX_CLOCK_DRIFT_SECS: u64 = 15;
+
+/// Why a block header was rejected against its parent.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum BlockValidationError {
+    #[error("block {index} timestamp {timestamp} is not after parent timestamp {parent_timestamp}")]
+    NonIncreasingTimestamp { index: u64, timestamp: u64, parent_timestamp: u64 },
+
+    #[error("block {index} timestamp {timestamp} is more than {max_drift_secs}s ahead of local time {now}")]
+    TimestampTooFarInFuture { index: u64, timestamp: u64, now: u64, max_drift_secs: u64 },
+
+    #[error("block {index} parent hash {found} does not match head {expected}")]
+    ParentHashMismatch { index: u64, expected: String, found: String },
+}
 
 pub struct BlockValidator;
 
@@ -92,6 +109,53 @@ impl BlockValidator {
         true
     }
 
+    /// **Validate a block header against the stored head**
+    /// 
+    /// SAFETY: Rejects a header whose timestamp does not strictly increase
+    /// over its parent's, lies more than `DEFAULT_MAX_CLOCK_DRIFT_SECS`
+    /// ahead of the local clock, or whose `previous_hash` is not the
+    /// parent's hash.
+    pub fn validate_block_header(block: &Block, parent: &Block) -> Result<(), BlockValidationError> {
+        let now = chrono::Utc::now().timestamp().max(0) as u64;
+        Self::validate_block_header_at(block, parent, now, DEFAULT_MAX_CLOCK_DRIFT_SECS)
+    }
+
+    /// `validate_block_header` against an explicit local time and drift bound
+    pub fn validate_block_header_at(
+        block: &Block,
+        parent: &Block,
+        now: u64,
+        max_clock_drift_secs: u64,
+    ) -> Result<(), BlockValidationError> {
+        let expected = parent.compute_hash();
+        if block.previous_hash != expected {
+            return Err(BlockValidationError::ParentHashMismatch {
+                index: block.index,
+                expected,
+                found: block.previous_hash.clone(),
+            });
+        }
+
+        if block.timestamp <= parent.timestamp {
+            return Err(BlockValidationError::NonIncreasingTimestamp {
+                index: block.index,
+                timestamp: block.timestamp,
+                parent_timestamp: parent.timestamp,
+            });
+        }
+
+        if block.timestamp > now.saturating_add(max_clock_drift_secs) {
+            return Err(BlockValidationError::TimestampTooFarInFuture {
+                index: block.index,
+                timestamp: block.timestamp,
+                now,
+                max_drift_secs: max_clock_drift_secs,
+            });
+        }
+
+        Ok(())
+    }
+
     /// **Network-wide peer consensus verification**
     /// 
     /// SAFETY: Rejects blocks that don't match network consensus.
@@ -162,3 +226,72 @@ impl BlockValidator {
         true
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: u64 = 1_700_000_000;
+
+    fn parent_and_child() -> (Block, Block) {
+        let mut parent = Block::new(1, vec![], "genesis".to_string());
+        parent.timestamp = NOW - 10;
+        let mut child = Block::new(2, vec![], parent.compute_hash());
+        child.timestamp = NOW - 5;
+        (parent, child)
+    }
+
+    #[test]
+    fn test_valid_header() {
+        let (parent, child) = parent_and_child();
+        assert_eq!(BlockValidator::validate_block_header_at(&child, &parent, NOW, 15), Ok(()));
+
+        // Exactly at the drift limit is still accepted
+        let mut edge = child.clone();
+        edge.timestamp = NOW + 15;
+        assert_eq!(BlockValidator::validate_block_header_at(&edge, &parent, NOW, 15), Ok(()));
+    }
+
+    #[test]
+    fn test_non_increasing_timestamp_rejected() {
+        let (parent, mut child) = parent_and_child();
+        child.timestamp = parent.timestamp;
+
+        assert_eq!(
+            BlockValidator::validate_block_header_at(&child, &parent, NOW, 15),
+            Err(BlockValidationError::NonIncreasingTimestamp {
+                index: 2,
+                timestamp: NOW - 10,
+                parent_timestamp: NOW - 10,
+            })
+        );
+    }
+
+    #[test]
+    fn test_far_future_timestamp_rejected() {
+        let (parent, mut child) = parent_and_child();
+        child.timestamp = NOW + 16;
+
+        assert!(matches!(
+            BlockValidator::validate_block_header_at(&child, &parent, NOW, 15),
+            Err(BlockValidationError::TimestampTooFarInFuture { timestamp, .. }) if timestamp == NOW + 16
+        ));
+        // The wall-clock entry point applies the same bound
+        child.timestamp = u64::MAX;
+        assert!(matches!(
+            BlockValidator::validate_block_header(&child, &parent),
+            Err(BlockValidationError::TimestampTooFarInFuture { .. })
+        ));
+    }
+
+    #[test]
+    fn test_parent_hash_mismatch_rejected() {
+        let (parent, mut child) = parent_and_child();
+        child.previous_hash = "not-the-head".to_string();
+
+        assert!(matches!(
+            BlockValidator::validate_block_header_at(&child, &parent, NOW, 15),
+            Err(BlockValidationError::ParentHashMismatch { found, .. }) if found == "not-the-head"
+        ));
+    }
+}
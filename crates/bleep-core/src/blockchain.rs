@@ -12,6 +12,8 @@ use std::sync::{Arc, RwLock};
 
 use crate::block::{Block, Transaction};
 use crate::block_validation::BlockValidator;
+use crate::invariant_enforcement::InvariantEnforcer;
+use crate::transaction::{TxValidationError, ZKTransaction, BLEEP_CHAIN_ID};
 use crate::transaction_pool::TransactionPool;
 
 // ─── In-memory account state ─────────────────────────────────────────────────
@@ -23,6 +25,8 @@ use crate::transaction_pool::TransactionPool;
 #[derive(Default, Clone)]
 pub struct BlockchainState {
     pub balances: HashMap<String, u64>,
+    /// Number of transactions each sender has had applied
+    pub nonces: HashMap<String, u64>,
 }
 
 impl BlockchainState {
@@ -56,11 +60,34 @@ impl BlockchainState {
         self.balances.get(address).copied().unwrap_or(0)
     }
 
+    /// Number of transactions applied from `address`.
+    pub fn nonce_of(&self, address: &str) -> u64 {
+        self.nonces.get(address).copied().unwrap_or(0)
+    }
+
+    /// Sum of all balances.
+    pub fn total_supply(&self) -> u128 {
+        self.balances.values().map(|b| *b as u128).sum()
+    }
+
     /// Apply a single confirmed transaction to the balance table.
     ///
     /// SAFETY: Debit is checked before credit; any failure rolls back nothing
     /// because debit happens first and credit only follows on success.
+    /// The transaction must carry the sender's current nonce, so a replayed
+    /// or out-of-order transaction is rejected before any balance moves.
+    ///
+    /// NOTE: Callers that built every transaction with the default nonce 0
+    /// now succeed only for a sender's first transaction; later ones must
+    /// carry `nonce_of(sender)`.
     pub fn apply_transaction(&mut self, tx: &Transaction) -> Result<(), String> {
+        let expected = self.nonce_of(&tx.sender);
+        if tx.nonce != expected {
+            return Err(format!(
+                "Nonce mismatch for {}: got {}, expected {}",
+                tx.sender, tx.nonce, expected
+            ));
+        }
         if tx.sender == tx.receiver {
             return Err(format!("Self-transfer rejected for {}", tx.sender));
         }
@@ -69,23 +96,31 @@ impl BlockchainState {
         }
         self.debit(&tx.sender, tx.amount)?;
         self.credit(&tx.receiver, tx.amount);
+        *self.nonces.entry(tx.sender.clone()).or_insert(0) += 1;
         Ok(())
     }
 
     /// Apply all transactions in a block, in order.
     ///
-    /// On first failure the state is NOT partially updated — we roll back
-    /// all successful transactions in the failed block.
+    /// On first failure the state is NOT partially updated — the block is
+    /// applied to a scratch copy that only replaces `self` on success.
     pub fn apply_block(&mut self, block: &Block) -> Result<(), String> {
-        // Snapshot balances so we can roll back on failure
-        let snapshot = self.balances.clone();
+        let mut scratch = self.clone();
+        scratch.apply_transactions(block)?;
+        *self = scratch;
+        Ok(())
+    }
+
+    /// Apply a block's transactions in place, stopping at the first failure.
+    ///
+    /// Leaves `self` partially updated on error; callers stage it on a copy.
+    fn apply_transactions(&mut self, block: &Block) -> Result<(), String> {
         for tx in &block.transactions {
             if let Err(e) = self.apply_transaction(tx) {
                 log::warn!(
-                    "Block {} tx from={} to={} amount={} rejected: {} — rolling back block",
+                    "Block {} tx from={} to={} amount={} rejected: {}",
                     block.index, tx.sender, tx.receiver, tx.amount, e
                 );
-                self.balances = snapshot;
                 return Err(e);
             }
         }
@@ -98,6 +133,22 @@ impl BlockchainState {
         Ok(())
     }
 
+    /// Apply a block to a scratch copy, check it with `enforcer`, and only
+    /// then commit it.
+    ///
+    /// SAFETY: On any execution error or invariant violation `self` is left
+    /// untouched, so a violating block is never committed.
+    pub fn commit_block(&mut self, block: &Block, enforcer: &InvariantEnforcer) -> Result<(), String> {
+        let mut post = self.clone();
+        post.apply_transactions(block)?;
+        enforcer.check_all(self, &post, block).map_err(|v| {
+            log::error!("Block {} rejected by invariant enforcer: {}", block.index, v);
+            v.to_string()
+        })?;
+        *self = post;
+        Ok(())
+    }
+
     /// Revert a previously-applied block.  Used by `Blockchain::rollback()`.
     pub fn revert_block(&mut self, block: &Block) {
         for tx in &block.transactions {
@@ -106,6 +157,9 @@ impl BlockchainState {
             if let Some(b) = self.balances.get_mut(&tx.receiver) {
                 *b = b.saturating_sub(tx.amount);
             }
+            if let Some(n) = self.nonces.get_mut(&tx.sender) {
+                *n = n.saturating_sub(1);
+            }
         }
     }
 }
@@ -116,6 +170,9 @@ pub struct Blockchain {
     pub chain: VecDeque<Block>,
     pub state: Arc<RwLock<BlockchainState>>,
     pub transaction_pool: Arc<RwLock<Arc<TransactionPool>>>,
+    pub invariant_enforcer: InvariantEnforcer,
+    /// Network ID every block transaction must be signed for
+    pub chain_id: u64,
 }
 
 impl Blockchain {
@@ -127,11 +184,36 @@ impl Blockchain {
     ) -> Self {
         let mut chain = VecDeque::new();
         chain.push_back(genesis_block);
+        let state = Arc::new(RwLock::new(state));
+        // Pool admission checks nonces against the committed state
+        tx_pool.attach_state(state.clone());
         Self {
             chain,
-            state: Arc::new(RwLock::new(state)),
+            state,
             transaction_pool: Arc::new(RwLock::new(tx_pool)),
+            invariant_enforcer: InvariantEnforcer::new(),
+            chain_id: BLEEP_CHAIN_ID,
+        }
+    }
+
+    /// Check every transaction's chain ID, signature and nonce against `state`.
+    ///
+    /// Each sender's transactions must carry consecutive nonces starting at
+    /// its committed nonce, so a signed transaction can be applied only once.
+    pub fn validate_block_transactions(
+        state: &BlockchainState,
+        block: &Block,
+        chain_id: u64,
+    ) -> Result<(), TxValidationError> {
+        let mut next_nonces: HashMap<&str, u64> = HashMap::new();
+        for tx in &block.transactions {
+            let expected = next_nonces
+                .entry(tx.sender.as_str())
+                .or_insert_with(|| state.nonce_of(&tx.sender));
+            ZKTransaction::from(tx).validate_for_chain(chain_id, *expected)?;
+            *expected += 1;
         }
+        Ok(())
     }
 
     // ── Block acceptance ──────────────────────────────────────────────────────
@@ -157,10 +239,14 @@ impl Blockchain {
             }
         }
 
-        // ── 2. Apply transactions to in-memory state ─────────────────────
+        // ── 2. Apply transactions, enforce invariants, commit state ──────
         {
             let mut state = self.state.write().unwrap();
-            if let Err(e) = state.apply_block(&block) {
+            if let Err(e) = Self::validate_block_transactions(&state, &block, self.chain_id) {
+                log::error!("Block {} rejected: {}", block.index, e);
+                return false;
+            }
+            if let Err(e) = state.commit_block(&block, &self.invariant_enforcer) {
                 log::error!("Block {} state application failed: {}", block.index, e);
                 return false;
             }
@@ -18,6 +18,7 @@ use crate::decision_attestation::{
 use crate::invariant_enforcement::ProtectedState;
 use bleep_crypto::pq_crypto::HashFunctions;
 use serde::{Serialize, Deserialize};
+use std::collections::{BTreeMap, BTreeSet};
 use std::sync::{Arc, Mutex};
 use sha3::Digest;
 
@@ -374,6 +375,70 @@ impl DecisionVerificationPipeline {
     }
 }
 
+// ==================== VALIDATOR QUORUM ====================
+
+/// A validator's attestation that a given decision should execute
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ValidatorAttestation {
+    /// Attesting validator
+    pub validator_id: String,
+    
+    /// `DecisionType::decision_id()` of the decision attested to
+    pub decision_hash: String,
+    
+    /// Validator's SPHINCS+ signature over `signing_bytes()`
+    #[serde(default)]
+    pub signature: Vec<u8>,
+}
+
+impl ValidatorAttestation {
+    /// Bytes the validator signs: its ID and the attested decision hash
+    pub fn signing_bytes(&self) -> Vec<u8> {
+        let mut bytes = b"BLEEP-DECISION-ATTEST-V1".to_vec();
+        bytes.extend_from_slice(&(self.validator_id.len() as u64).to_le_bytes());
+        bytes.extend_from_slice(self.validator_id.as_bytes());
+        bytes.extend_from_slice(self.decision_hash.as_bytes());
+        bytes
+    }
+    
+    /// Verify the signature against the validator's registered public key
+    pub fn verify(&self, public_key: &[u8]) -> bool {
+        bleep_crypto::tx_signer::verify_tx_signature(&self.signing_bytes(), &self.signature, public_key)
+    }
+}
+
+/// Check that validators holding more than `threshold` stake attested to `decision`
+/// 
+/// SAFETY:
+/// - Attestations for a different decision hash contribute nothing
+/// - Attestations must verify against the validator's key in `validator_keys`;
+///   unsigned, forged or unregistered attestations contribute nothing
+/// - Each validator's stake counts at most once, however many times it attests
+/// - Validators absent from `stake_map` carry zero stake
+pub fn verify_decision_quorum(
+    decision: &DecisionType,
+    attestations: &[ValidatorAttestation],
+    stake_map: &BTreeMap<String, u128>,
+    validator_keys: &BTreeMap<String, Vec<u8>>,
+    threshold: u128,
+) -> bool {
+    let decision_hash = decision.decision_id();
+    
+    let attesters: BTreeSet<&str> = attestations
+        .iter()
+        .filter(|a| a.decision_hash == decision_hash)
+        .filter(|a| validator_keys.get(&a.validator_id).is_some_and(|pk| a.verify(pk)))
+        .map(|a| a.validator_id.as_str())
+        .collect();
+    
+    let attested_stake: u128 = attesters
+        .iter()
+        .filter_map(|v| stake_map.get(*v))
+        .fold(0u128, |acc, stake| acc.saturating_add(*stake));
+    
+    attested_stake > threshold
+}
+
 // ==================== REJECTION EVIDENCE ====================
 
 /// Evidence of decision rejection
@@ -430,4 +495,111 @@ mod tests {
         assert_eq!(evidence.decision_id, "decision_1");
         assert!(!evidence.evidence_hash.is_empty());
     }
+
+    struct QuorumFixture {
+        decision: DecisionType,
+        stakes: BTreeMap<String, u128>,
+        public_keys: BTreeMap<String, Vec<u8>>,
+        secret_keys: BTreeMap<String, Vec<u8>>,
+    }
+
+    impl QuorumFixture {
+        fn attest(&self, validator: &str, decision: &DecisionType) -> ValidatorAttestation {
+            let mut attestation = ValidatorAttestation {
+                validator_id: validator.to_string(),
+                decision_hash: decision.decision_id(),
+                signature: vec![],
+            };
+            attestation.signature = bleep_crypto::tx_signer::sign_tx_payload(
+                &attestation.signing_bytes(),
+                &self.secret_keys[validator],
+            ).unwrap();
+            attestation
+        }
+
+        fn verify(&self, decision: &DecisionType, attestations: &[ValidatorAttestation], threshold: u128) -> bool {
+            verify_decision_quorum(decision, attestations, &self.stakes, &self.public_keys, threshold)
+        }
+    }
+
+    fn quorum_fixture() -> QuorumFixture {
+        let decision = DecisionType::ConsensusModeChange {
+            epoch: 7,
+            new_mode: "PBFT".to_string(),
+        };
+        let stakes = [("v1", 40u128), ("v2", 30), ("v3", 20), ("v4", 10)]
+            .iter()
+            .map(|(v, s)| (v.to_string(), *s))
+            .collect();
+        let mut public_keys = BTreeMap::new();
+        let mut secret_keys = BTreeMap::new();
+        for v in ["v1", "v2", "v3", "v4"] {
+            let (pk, sk) = bleep_crypto::tx_signer::generate_tx_keypair();
+            public_keys.insert(v.to_string(), pk);
+            secret_keys.insert(v.to_string(), sk);
+        }
+        QuorumFixture { decision, stakes, public_keys, secret_keys }
+    }
+
+    #[test]
+    fn test_decision_quorum_boundary() {
+        let f = quorum_fixture();
+        let decision = &f.decision;
+        
+        // 40 + 20 = 60: not above a threshold of 60
+        let below = vec![f.attest("v1", decision), f.attest("v3", decision)];
+        assert!(!f.verify(decision, &below, 60));
+        
+        // 40 + 20 + 10 = 70: above it
+        let above = vec![f.attest("v1", decision), f.attest("v3", decision), f.attest("v4", decision)];
+        assert!(f.verify(decision, &above, 60));
+    }
+
+    #[test]
+    fn test_decision_quorum_counts_duplicates_once() {
+        let f = quorum_fixture();
+        let decision = &f.decision;
+        
+        let repeated = vec![f.attest("v1", decision), f.attest("v1", decision), f.attest("v3", decision)];
+        assert!(!f.verify(decision, &repeated, 60));
+    }
+
+    #[test]
+    fn test_decision_quorum_excludes_conflicting_decision() {
+        let f = quorum_fixture();
+        let decision = &f.decision;
+        let conflicting = DecisionType::ConsensusModeChange {
+            epoch: 7,
+            new_mode: "EmergencyPoW".to_string(),
+        };
+        
+        let mixed = vec![f.attest("v1", decision), f.attest("v3", decision), f.attest("v2", &conflicting)];
+        assert!(!f.verify(decision, &mixed, 60));
+        assert!(!f.verify(&conflicting, &mixed, 60));
+        
+        let agreed = vec![f.attest("v1", decision), f.attest("v3", decision), f.attest("v2", decision)];
+        assert!(f.verify(decision, &agreed, 60));
+    }
+
+    #[test]
+    fn test_decision_quorum_requires_valid_signatures() {
+        let mut f = quorum_fixture();
+        let decision = &f.decision.clone();
+        
+        // Claiming v2's stake without v2's key
+        let mut forged = f.attest("v4", decision);
+        forged.validator_id = "v2".to_string();
+        let attestations = vec![f.attest("v1", decision), f.attest("v3", decision), forged];
+        assert!(!f.verify(decision, &attestations, 60));
+        
+        let mut unsigned = f.attest("v2", decision);
+        unsigned.signature.clear();
+        let attestations = vec![f.attest("v1", decision), f.attest("v3", decision), unsigned];
+        assert!(!f.verify(decision, &attestations, 60));
+        
+        // Unregistered key
+        f.public_keys.remove("v2");
+        let attestations = vec![f.attest("v1", decision), f.attest("v3", decision), f.attest("v2", decision)];
+        assert!(!f.verify(decision, &attestations, 60));
+    }
 }
@@ -8,10 +8,13 @@
 /// The invariant engine cannot be bypassed. All mutations go through these hooks.
 /// No unchecked state changes are possible anywhere in the system.
 
+use crate::block::Block;
+use crate::blockchain::BlockchainState;
 use crate::protocol_invariants::{
     ProtocolInvariantEngine, ValidatorRecord, ValidatorState,
 };
 use serde::{Serialize, Deserialize};
+use std::collections::BTreeMap;
 use std::sync::{Arc, Mutex};
 
 /// Result type for state transitions
@@ -443,9 +446,163 @@ impl ProtectedState {
     }
 }
 
+// ==================== BLOCK COMMIT INVARIANTS ====================
+
+/// A protocol invariant broken by a block's state transition
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum InvariantViolation {
+    /// Total balance changed across a block of pure transfers
+    SupplyNotConserved { block: u64, pre_supply: u128, post_supply: u128 },
+
+    /// Applying the block would drive an account below zero
+    NegativeBalance { block: u64, account: String, balance: i128 },
+
+    /// A transaction does not carry its sender's next nonce
+    TxNonceMismatch { block: u64, account: String, tx_nonce: u64, expected: u64 },
+
+    /// An account nonce moved by anything other than its sent-transaction count
+    NonceNotMonotonic { block: u64, account: String, pre_nonce: u64, post_nonce: u64, expected: u64 },
+}
+
+impl std::fmt::Display for InvariantViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvariantViolation::SupplyNotConserved { block, pre_supply, post_supply } => write!(
+                f, "Block {}: supply not conserved ({} -> {})", block, pre_supply, post_supply
+            ),
+            InvariantViolation::NegativeBalance { block, account, balance } => write!(
+                f, "Block {}: account {} would hold negative balance {}", block, account, balance
+            ),
+            InvariantViolation::TxNonceMismatch { block, account, tx_nonce, expected } => write!(
+                f, "Block {}: transaction from {} has nonce {}, expected {}", block, account, tx_nonce, expected
+            ),
+            InvariantViolation::NonceNotMonotonic { block, account, pre_nonce, post_nonce, expected } => write!(
+                f, "Block {}: nonce of {} moved {} -> {}, expected {}", block, account, pre_nonce, post_nonce, expected
+            ),
+        }
+    }
+}
+
+impl std::error::Error for InvariantViolation {}
+
+/// Checks the pre/post state of a block before it is committed.
+///
+/// Invoked by `BlockchainState::commit_block`, so every block accepted by
+/// `Blockchain::add_block` passes these checks; callers cannot skip them.
+#[derive(Debug, Clone, Default)]
+pub struct InvariantEnforcer;
+
+impl InvariantEnforcer {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Run every block-commit invariant, returning the first violation.
+    ///
+    /// INVARIANTS:
+    /// - Supply conservation: transfers never create or destroy balance
+    /// - No negative balances: `pre + credits - debits >= 0` for every account
+    /// - Nonce monotonicity: each sender's transactions carry consecutive nonces
+    ///   from its pre-block nonce, and its nonce advances past the last one
+    pub fn check_all(
+        &self,
+        pre_state: &BlockchainState,
+        post_state: &BlockchainState,
+        block: &Block,
+    ) -> Result<(), InvariantViolation> {
+        self.check_no_negative_balances(pre_state, block)?;
+        self.check_supply_conservation(pre_state, post_state, block)?;
+        self.check_nonce_monotonicity(pre_state, post_state, block)
+    }
+
+    fn check_supply_conservation(
+        &self,
+        pre_state: &BlockchainState,
+        post_state: &BlockchainState,
+        block: &Block,
+    ) -> Result<(), InvariantViolation> {
+        let pre_supply = pre_state.total_supply();
+        let post_supply = post_state.total_supply();
+        if pre_supply != post_supply {
+            return Err(InvariantViolation::SupplyNotConserved { block: block.index, pre_supply, post_supply });
+        }
+        Ok(())
+    }
+
+    /// Replays the block's net flows against `pre_state` in signed arithmetic,
+    /// so an overdraft is caught even if the executor failed to reject it.
+    fn check_no_negative_balances(
+        &self,
+        pre_state: &BlockchainState,
+        block: &Block,
+    ) -> Result<(), InvariantViolation> {
+        let mut balances: BTreeMap<&str, i128> = BTreeMap::new();
+        for tx in &block.transactions {
+            let sender = balances
+                .entry(tx.sender.as_str())
+                .or_insert_with(|| pre_state.balance_of(&tx.sender) as i128);
+            *sender -= tx.amount as i128;
+            if *sender < 0 {
+                return Err(InvariantViolation::NegativeBalance {
+                    block: block.index,
+                    account: tx.sender.clone(),
+                    balance: *sender,
+                });
+            }
+            *balances
+                .entry(tx.receiver.as_str())
+                .or_insert_with(|| pre_state.balance_of(&tx.receiver) as i128) += tx.amount as i128;
+        }
+        Ok(())
+    }
+
+    /// Checks the nonces the block's transactions carry against `pre_state`,
+    /// then that every post-block nonce sits just past the sender's last one.
+    fn check_nonce_monotonicity(
+        &self,
+        pre_state: &BlockchainState,
+        post_state: &BlockchainState,
+        block: &Block,
+    ) -> Result<(), InvariantViolation> {
+        let mut next: BTreeMap<&str, u64> = BTreeMap::new();
+        for tx in &block.transactions {
+            let expected = next
+                .entry(tx.sender.as_str())
+                .or_insert_with(|| pre_state.nonce_of(&tx.sender));
+            if tx.nonce != *expected {
+                return Err(InvariantViolation::TxNonceMismatch {
+                    block: block.index,
+                    account: tx.sender.clone(),
+                    tx_nonce: tx.nonce,
+                    expected: *expected,
+                });
+            }
+            *expected += 1;
+        }
+
+        let accounts = pre_state.nonces.keys().chain(post_state.nonces.keys());
+        for account in accounts {
+            let pre_nonce = pre_state.nonce_of(account);
+            let post_nonce = post_state.nonce_of(account);
+            let expected = next.get(account.as_str()).copied().unwrap_or(pre_nonce);
+            if post_nonce != expected {
+                return Err(InvariantViolation::NonceNotMonotonic {
+                    block: block.index,
+                    account: account.clone(),
+                    pre_nonce,
+                    post_nonce,
+                    expected,
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::block::Transaction;
 
     #[test]
     fn test_protected_state_creation() {
@@ -536,4 +693,137 @@ mod tests {
         state.hook_validate_governance_execution("proposal_1", &[]).unwrap();
         state.hook_after_governance_execution("proposal_1").unwrap();
     }
+
+    // ==================== BLOCK COMMIT INVARIANTS ====================
+
+    fn transfer(sender: &str, receiver: &str, amount: u64) -> crate::block::Transaction {
+        crate::block::Transaction {
+            sender: sender.to_string(),
+            receiver: receiver.to_string(),
+            amount,
+            timestamp: 1_700_000_000,
+            signature: vec![],
+            nonce: 0,
+            chain_id: 0,
+        }
+    }
+
+    fn funded_state() -> BlockchainState {
+        let mut state = BlockchainState::new();
+        state.credit("alice", 100);
+        state.credit("bob", 50);
+        state
+    }
+
+    #[test]
+    fn test_supply_violating_block_rejected() {
+        let pre = funded_state();
+        let block = Block::new(1, vec![transfer("alice", "bob", 30)], "parent".to_string());
+
+        let mut post = pre.clone();
+        post.apply_block(&block).unwrap();
+        // Executor bug: receiver credited twice
+        post.credit("bob", 30);
+
+        assert_eq!(
+            InvariantEnforcer::new().check_all(&pre, &post, &block),
+            Err(InvariantViolation::SupplyNotConserved { block: 1, pre_supply: 150, post_supply: 180 })
+        );
+    }
+
+    #[test]
+    fn test_negative_balance_block_rejected() {
+        let mut state = funded_state();
+        let block = Block::new(
+            1,
+            vec![transfer("bob", "carol", 40), Transaction { nonce: 1, ..transfer("bob", "alice", 20) }],
+            "parent".to_string(),
+        );
+
+        // Even against a post state that hides the overdraft, the block is caught
+        assert_eq!(
+            InvariantEnforcer::new().check_all(&state, &state.clone(), &block),
+            Err(InvariantViolation::NegativeBalance { block: 1, account: "bob".into(), balance: -10 })
+        );
+
+        let before = state.clone();
+        assert!(state.commit_block(&block, &InvariantEnforcer::new()).is_err());
+        assert_eq!(state.balances, before.balances);
+        assert_eq!(state.nonces, before.nonces);
+    }
+
+    #[test]
+    fn test_nonce_regression_rejected() {
+        let pre = funded_state();
+        let block = Block::new(1, vec![transfer("alice", "bob", 10)], "parent".to_string());
+
+        let mut post = pre.clone();
+        post.apply_block(&block).unwrap();
+        post.nonces.insert("alice".into(), 0);
+
+        assert!(matches!(
+            InvariantEnforcer::new().check_all(&pre, &post, &block),
+            Err(InvariantViolation::NonceNotMonotonic { post_nonce: 0, expected: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn test_valid_block_committed() {
+        let mut state = funded_state();
+        let block = Block::new(
+            1,
+            vec![
+                transfer("alice", "bob", 30),
+                Transaction { nonce: 1, ..transfer("alice", "carol", 20) },
+                transfer("bob", "alice", 5),
+            ],
+            "parent".to_string(),
+        );
+
+        state.commit_block(&block, &InvariantEnforcer::new()).unwrap();
+        assert_eq!(state.balance_of("alice"), 55);
+        assert_eq!(state.balance_of("bob"), 75);
+        assert_eq!(state.balance_of("carol"), 20);
+        assert_eq!(state.total_supply(), 150);
+        assert_eq!(state.nonce_of("alice"), 2);
+        assert_eq!(state.nonce_of("bob"), 1);
+    }
+
+    #[test]
+    fn test_apply_transaction_requires_current_nonce() {
+        let mut state = funded_state();
+        state.apply_transaction(&transfer("alice", "bob", 10)).unwrap();
+
+        // Replayed nonce and skipped nonce are both rejected without moving funds
+        let before = state.clone();
+        assert!(state.apply_transaction(&transfer("alice", "bob", 10)).is_err());
+        assert!(state.apply_transaction(&Transaction { nonce: 2, ..transfer("alice", "bob", 10) }).is_err());
+        assert_eq!(state.balances, before.balances);
+        assert_eq!(state.nonce_of("alice"), 1);
+
+        state.apply_transaction(&Transaction { nonce: 1, ..transfer("alice", "bob", 10) }).unwrap();
+        assert_eq!(state.balance_of("alice"), 80);
+        assert_eq!(state.nonce_of("alice"), 2);
+    }
+
+    #[test]
+    fn test_replayed_nonce_block_rejected() {
+        let mut state = funded_state();
+        let replay = Block::new(
+            1,
+            vec![transfer("alice", "bob", 10), transfer("alice", "bob", 10)],
+            "parent".to_string(),
+        );
+
+        // The enforcer checks nonces against the transactions themselves
+        assert_eq!(
+            InvariantEnforcer::new().check_all(&state, &state.clone(), &replay),
+            Err(InvariantViolation::TxNonceMismatch { block: 1, account: "alice".into(), tx_nonce: 0, expected: 1 })
+        );
+
+        let before = state.clone();
+        assert!(state.commit_block(&replay, &InvariantEnforcer::new()).is_err());
+        assert_eq!(state.balances, before.balances);
+        assert_eq!(state.nonces, before.nonces);
+    }
 }
@@ -1,5 +1,6 @@
 // === Core Blockchain Logic ===
 pub mod block;
+pub mod block_economics;
 pub mod block_validation;
 pub mod blockchain;
 pub mod state;
@@ -26,9 +27,10 @@ pub mod decision_verification;
 
 // === Re-exports for broader ecosystem access ===
 pub use block::{Block, derive_block_keypair};
+pub use block_economics::BlockEconomicsReport;
 pub use block_validation::*;
 pub use blockchain::*;
-pub use transaction::{ZKTransaction};
+pub use transaction::{ZKTransaction, TxValidationError, BLEEP_CHAIN_ID};
 pub use transaction_manager::*;
 pub use transaction_pool::*;
 pub use mempool::*;
@@ -3,6 +3,18 @@ use sha2::{Sha256, Digest};
 use ark_ff::PrimeField;
 // use ark_ff::{PrimeField, Field};
 use ark_bls12_381::Fr;
+use rand::RngCore;
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// Seconds an issued identity challenge stays answerable.
+pub const CHALLENGE_TTL_SECS: u64 = 300;
+
+/// Most unanswered challenges an `IdentityChallenger` holds at once.
+pub const MAX_PENDING_CHALLENGES: usize = 10_000;
+
+/// Domain tag prefixed to the bytes a validator signs when answering a challenge.
+const CHALLENGE_DOMAIN: &[u8] = b"BLEEP-POI-CHALLENGE-V1";
 
 /// Simple merkle tree path for identity verification
 #[derive(Clone, Debug)]
@@ -81,3 +93,216 @@ impl IdentityProof {
         self.path.verify()
     }
 }
+
+// ── Challenge / response ──────────────────────────────────────────────────────
+
+/// Why an identity challenge could not be issued or its response was rejected.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum IdentityError {
+    #[error("challenge {0} was never issued or has already been consumed")]
+    UnknownChallenge(String),
+
+    #[error("challenge {challenge} expired at {expires_at}")]
+    ChallengeExpired { challenge: String, expires_at: u64 },
+
+    #[error("response signature does not verify for challenge {0}")]
+    InvalidSignature(String),
+
+    #[error("{0} challenges already pending")]
+    TooManyPendingChallenges(usize),
+}
+
+/// A single-use nonce a validator must sign to prove ownership of `identity`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Challenge {
+    /// SPHINCS+ public key of the identity being challenged
+    pub identity: Vec<u8>,
+    pub nonce: [u8; 32],
+    pub expires_at: u64,
+}
+
+impl Challenge {
+    /// Bytes the validator signs: `domain || identity || nonce || expires_at_le8`
+    pub fn signing_bytes(&self) -> Vec<u8> {
+        let mut h = Sha256::new();
+        h.update(CHALLENGE_DOMAIN);
+        h.update(&self.identity);
+        h.update(self.nonce);
+        h.update(self.expires_at.to_le_bytes());
+        h.finalize().to_vec()
+    }
+
+    fn id(&self) -> String {
+        hex::encode(self.nonce)
+    }
+}
+
+/// Issues identity challenges and verifies their responses.
+///
+/// SAFETY: Each challenge is consumed on its first verification attempt, so a
+/// captured response can never be replayed. The private key never leaves the
+/// validator; only a SPHINCS+ signature over the nonce is presented.
+#[derive(Default)]
+pub struct IdentityChallenger {
+    /// nonce → challenge, for challenges issued and not yet answered
+    pending: HashMap<[u8; 32], Challenge>,
+}
+
+impl IdentityChallenger {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Issues a fresh challenge for `identity` expiring `CHALLENGE_TTL_SECS` from now.
+    ///
+    /// Expired challenges are dropped first; if `MAX_PENDING_CHALLENGES` are
+    /// still outstanding the new challenge is refused.
+    pub fn issue_challenge(&mut self, identity: &[u8]) -> Result<Challenge, IdentityError> {
+        self.issue_challenge_at(identity, unix_now())
+    }
+
+    /// `issue_challenge` against an explicit local time
+    pub fn issue_challenge_at(&mut self, identity: &[u8], now: u64) -> Result<Challenge, IdentityError> {
+        self.prune_expired(now);
+        if self.pending.len() >= MAX_PENDING_CHALLENGES {
+            return Err(IdentityError::TooManyPendingChallenges(self.pending.len()));
+        }
+
+        let mut nonce = [0u8; 32];
+        rand::thread_rng().fill_bytes(&mut nonce);
+        let challenge = Challenge {
+            identity: identity.to_vec(),
+            nonce,
+            expires_at: now.saturating_add(CHALLENGE_TTL_SECS),
+        };
+        self.pending.insert(nonce, challenge.clone());
+        Ok(challenge)
+    }
+
+    /// Verifies `response`, a SPHINCS+ detached signature over
+    /// `challenge.signing_bytes()` by the challenged identity.
+    pub fn verify_response(&mut self, challenge: &Challenge, response: &[u8]) -> Result<(), IdentityError> {
+        self.verify_response_at(challenge, response, unix_now())
+    }
+
+    /// `verify_response` against an explicit local time
+    pub fn verify_response_at(
+        &mut self,
+        challenge: &Challenge,
+        response: &[u8],
+        now: u64,
+    ) -> Result<(), IdentityError> {
+        // Consume first (single-use), and trust only the stored copy
+        let issued = self
+            .pending
+            .remove(&challenge.nonce)
+            .filter(|issued| issued == challenge)
+            .ok_or_else(|| IdentityError::UnknownChallenge(challenge.id()))?;
+
+        if now > issued.expires_at {
+            return Err(IdentityError::ChallengeExpired {
+                challenge: issued.id(),
+                expires_at: issued.expires_at,
+            });
+        }
+
+        if !bleep_crypto::tx_signer::verify_tx_signature(&issued.signing_bytes(), response, &issued.identity) {
+            return Err(IdentityError::InvalidSignature(issued.id()));
+        }
+        Ok(())
+    }
+
+    /// Drops expired challenges that were never answered.
+    pub fn prune_expired(&mut self, now: u64) {
+        self.pending.retain(|_, c| c.expires_at >= now);
+    }
+
+    /// Number of challenges awaiting a response.
+    pub fn pending_count(&self) -> usize {
+        self.pending.len()
+    }
+}
+
+fn unix_now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bleep_crypto::tx_signer::{generate_tx_keypair, sign_tx_payload};
+
+    const NOW: u64 = 1_700_000_000;
+
+    #[test]
+    fn test_valid_response_accepted() {
+        let (pk, sk) = generate_tx_keypair();
+        let mut challenger = IdentityChallenger::new();
+        let challenge = challenger.issue_challenge_at(&pk, NOW).unwrap();
+
+        let response = sign_tx_payload(&challenge.signing_bytes(), &sk).unwrap();
+        assert_eq!(challenger.verify_response_at(&challenge, &response, NOW + 1), Ok(()));
+        assert_eq!(challenger.pending_count(), 0);
+    }
+
+    #[test]
+    fn test_expired_challenge_rejected() {
+        let (pk, sk) = generate_tx_keypair();
+        let mut challenger = IdentityChallenger::new();
+        let challenge = challenger.issue_challenge_at(&pk, NOW).unwrap();
+
+        let response = sign_tx_payload(&challenge.signing_bytes(), &sk).unwrap();
+        assert!(matches!(
+            challenger.verify_response_at(&challenge, &response, NOW + CHALLENGE_TTL_SECS + 1),
+            Err(IdentityError::ChallengeExpired { expires_at, .. }) if expires_at == NOW + CHALLENGE_TTL_SECS
+        ));
+    }
+
+    #[test]
+    fn test_replayed_response_rejected() {
+        let (pk, sk) = generate_tx_keypair();
+        let mut challenger = IdentityChallenger::new();
+        let challenge = challenger.issue_challenge_at(&pk, NOW).unwrap();
+
+        let response = sign_tx_payload(&challenge.signing_bytes(), &sk).unwrap();
+        assert_eq!(challenger.verify_response_at(&challenge, &response, NOW), Ok(()));
+        assert!(matches!(
+            challenger.verify_response_at(&challenge, &response, NOW),
+            Err(IdentityError::UnknownChallenge(_))
+        ));
+    }
+
+    #[test]
+    fn test_wrong_key_response_rejected() {
+        let (pk, _) = generate_tx_keypair();
+        let (_, other_sk) = generate_tx_keypair();
+        let mut challenger = IdentityChallenger::new();
+        let challenge = challenger.issue_challenge_at(&pk, NOW).unwrap();
+
+        let response = sign_tx_payload(&challenge.signing_bytes(), &other_sk).unwrap();
+        assert!(matches!(
+            challenger.verify_response_at(&challenge, &response, NOW),
+            Err(IdentityError::InvalidSignature(_))
+        ));
+    }
+
+    #[test]
+    fn test_pending_challenges_are_capped() {
+        let (pk, _) = generate_tx_keypair();
+        let mut challenger = IdentityChallenger::new();
+        for _ in 0..MAX_PENDING_CHALLENGES {
+            challenger.issue_challenge_at(&pk, NOW).unwrap();
+        }
+        assert_eq!(
+            challenger.issue_challenge_at(&pk, NOW),
+            Err(IdentityError::TooManyPendingChallenges(MAX_PENDING_CHALLENGES))
+        );
+
+        // Once the backlog has expired, issuing prunes it and succeeds again
+        assert!(challenger.issue_challenge_at(&pk, NOW + CHALLENGE_TTL_SECS + 1).is_ok());
+        assert_eq!(challenger.pending_count(), 1);
+    }
+}
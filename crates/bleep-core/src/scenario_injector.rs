@@ -4,6 +4,7 @@
 // 1. Validator Collusion: 2+ validators attempt to fork the chain
 // 2. Invalid State Injection: Corrupted state committed and detected
 // 3. Governance Abuse: Attacker attempts harmful protocol upgrade
+// 4. Network Partition: Validators split into groups that cannot see each other
 //
 // All scenarios are DETERMINISTIC (same seed = same execution)
 // and OBSERVABLE (full audit trail with before/after proofs)
@@ -350,6 +351,176 @@ impl MaliciousProposal {
     }
 }
 
+/// ═══════════════════════════════════════════════════════════════════════════════
+/// NETWORK PARTITION SCENARIO
+/// ═══════════════════════════════════════════════════════════════════════════════
+
+/// One side of a network partition, with its own view of the chain
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PartitionGroup {
+    /// Validators that can only see each other's blocks
+    pub validator_ids: Vec<usize>,
+    /// Height of this group's chain
+    pub height: u64,
+    /// Tip hash of this group's chain
+    pub tip_hash: Vec<u8>,
+}
+
+impl PartitionGroup {
+    fn new(validator_ids: Vec<usize>, fork_height: u64, fork_tip: &[u8]) -> Self {
+        Self {
+            validator_ids,
+            height: fork_height,
+            tip_hash: fork_tip.to_vec(),
+        }
+    }
+
+    /// Deterministically extend this group's chain
+    fn extend(&mut self, num_blocks: u64, seed: &[u8]) {
+        for _ in 0..num_blocks {
+            let mut hasher = Sha256::new();
+            hasher.update(&self.tip_hash);
+            hasher.update(self.height.to_le_bytes());
+            for validator_id in &self.validator_ids {
+                hasher.update(validator_id.to_le_bytes());
+            }
+            hasher.update(seed);
+            self.tip_hash = hasher.finalize().to_vec();
+            self.height += 1;
+        }
+    }
+}
+
+/// Network split into two groups that cannot see each other's blocks
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NetworkPartition {
+    /// Epoch the network split
+    pub partition_epoch: u64,
+    /// Epoch the network reunifies
+    pub heal_epoch: u64,
+    /// First side of the partition
+    pub group_a: PartitionGroup,
+    /// Second side of the partition
+    pub group_b: PartitionGroup,
+    /// Height both groups shared before the split
+    pub fork_height: u64,
+    /// Whether the groups have reconciled to one chain
+    pub healed: bool,
+}
+
+impl NetworkPartition {
+    /// Split validators at a common fork point
+    pub fn new(
+        group_a: Vec<usize>,
+        group_b: Vec<usize>,
+        partition_epoch: u64,
+        heal_epoch: u64,
+        fork_height: u64,
+        fork_tip: &[u8],
+    ) -> Result<Self, String> {
+        if group_a.is_empty() || group_b.is_empty() {
+            return Err("Both partition groups must contain validators".to_string());
+        }
+        if group_a.iter().any(|v| group_b.contains(v)) {
+            return Err("Partition groups must be disjoint".to_string());
+        }
+        for group in [&group_a, &group_b] {
+            let unique: std::collections::BTreeSet<_> = group.iter().collect();
+            if unique.len() != group.len() {
+                return Err("Partition group lists a validator twice".to_string());
+            }
+        }
+        if heal_epoch <= partition_epoch {
+            return Err(format!(
+                "Heal epoch {} must follow partition epoch {}",
+                heal_epoch, partition_epoch
+            ));
+        }
+
+        Ok(Self {
+            partition_epoch,
+            heal_epoch,
+            group_a: PartitionGroup::new(group_a, fork_height, fork_tip),
+            group_b: PartitionGroup::new(group_b, fork_height, fork_tip),
+            fork_height,
+            healed: false,
+        })
+    }
+
+    /// Each group builds on its own chain for one epoch
+    /// (block output proportional to the group's share of validators,
+    /// but never zero, so even a lone validator's chain diverges)
+    pub fn produce_epoch(&mut self, blocks_per_epoch: u64, seed: &[u8]) {
+        if self.healed {
+            return;
+        }
+
+        let total = (self.group_a.validator_ids.len() + self.group_b.validator_ids.len()) as u64;
+        let share = |members: usize| {
+            (blocks_per_epoch * members as u64 / total).max(blocks_per_epoch.min(1))
+        };
+        let blocks_a = share(self.group_a.validator_ids.len());
+        let blocks_b = share(self.group_b.validator_ids.len());
+        self.group_a.extend(blocks_a, seed);
+        self.group_b.extend(blocks_b, seed);
+    }
+
+    /// Partition is detectable once the groups' chains diverge
+    pub fn is_detectable(&self) -> bool {
+        self.group_a.tip_hash != self.group_b.tip_hash
+    }
+
+    /// Reunify the groups on one canonical chain and return its height
+    ///
+    /// The canonical chain is the one built by more validators; ties go to
+    /// the longer chain, then to the lower tip hash, so every node picks the
+    /// same chain.
+    pub fn heal(&mut self) -> u64 {
+        if !self.healed {
+            let a_wins = (
+                self.group_a.validator_ids.len(),
+                self.group_a.height,
+                std::cmp::Reverse(&self.group_a.tip_hash),
+            ) >= (
+                self.group_b.validator_ids.len(),
+                self.group_b.height,
+                std::cmp::Reverse(&self.group_b.tip_hash),
+            );
+
+            let (canonical, orphaned) = if a_wins {
+                (&self.group_a, &mut self.group_b)
+            } else {
+                (&self.group_b, &mut self.group_a)
+            };
+            orphaned.height = canonical.height;
+            orphaned.tip_hash = canonical.tip_hash.clone();
+            self.healed = true;
+        }
+
+        self.group_a.height
+    }
+
+    /// Both groups agree on the same chain
+    pub fn is_converged(&self) -> bool {
+        self.group_a.height == self.group_b.height && self.group_a.tip_hash == self.group_b.tip_hash
+    }
+
+    /// Commitment hash for logging
+    pub fn commitment_hash(&self) -> Vec<u8> {
+        let mut hasher = Sha256::new();
+        hasher.update(self.partition_epoch.to_le_bytes());
+        hasher.update(self.heal_epoch.to_le_bytes());
+        hasher.update(self.fork_height.to_le_bytes());
+        for group in [&self.group_a, &self.group_b] {
+            for validator_id in &group.validator_ids {
+                hasher.update(validator_id.to_le_bytes());
+            }
+            hasher.update(&group.tip_hash);
+        }
+        hasher.finalize().to_vec()
+    }
+}
+
 /// ═══════════════════════════════════════════════════════════════════════════════
 /// SCENARIO EXECUTOR - DETERMINISTIC ATTACK EXECUTION
 /// ═══════════════════════════════════════════════════════════════════════════════
@@ -364,6 +535,8 @@ pub struct ScenarioExecutor {
     pub state_corruptions: Vec<StateCorruption>,
     /// Governance attacks
     pub malicious_proposals: Vec<MaliciousProposal>,
+    /// Network partitions
+    pub partitions: Vec<NetworkPartition>,
     /// Execution trace (for reproducibility verification)
     pub execution_trace: Vec<String>,
 }
@@ -375,6 +548,7 @@ impl ScenarioExecutor {
             collusional_groups: Vec::new(),
             state_corruptions: Vec::new(),
             malicious_proposals: Vec::new(),
+            partitions: Vec::new(),
             execution_trace: Vec::new(),
         }
     }
@@ -465,6 +639,49 @@ impl ScenarioExecutor {
         Ok(proposal)
     }
 
+    /// Execute network partition scenario (deterministic)
+    pub fn execute_network_partition(
+        &mut self,
+        group_a: Vec<usize>,
+        group_b: Vec<usize>,
+        partition_epoch: u64,
+        heal_epoch: u64,
+        blocks_per_epoch: u64,
+    ) -> Result<NetworkPartition, String> {
+        self.execution_trace.push(format!(
+            "SCENARIO: Network partition started at epoch {}, groups: {:?} | {:?}",
+            partition_epoch, group_a, group_b
+        ));
+
+        let mut partition = NetworkPartition::new(
+            group_a,
+            group_b,
+            partition_epoch,
+            heal_epoch,
+            0,
+            &self.seed,
+        )?;
+        for _ in partition_epoch..heal_epoch {
+            partition.produce_epoch(blocks_per_epoch, &self.seed);
+        }
+
+        if !partition.is_detectable() {
+            return Err("Network partition not detectable".to_string());
+        }
+
+        let height = partition.heal();
+
+        self.execution_trace.push(format!(
+            "RESULT: Partition healed at epoch {}, canonical height {}, commitment: {}",
+            heal_epoch,
+            height,
+            hex::encode(&partition.commitment_hash()[..8])
+        ));
+
+        self.partitions.push(partition.clone());
+        Ok(partition)
+    }
+
     /// Get execution trace (proves deterministic reproducibility)
     pub fn get_trace(&self) -> Vec<String> {
         self.execution_trace.clone()
@@ -487,6 +704,9 @@ impl ScenarioExecutor {
         if self.malicious_proposals.len() != other.malicious_proposals.len() {
             return false;
         }
+        if self.partitions.len() != other.partitions.len() {
+            return false;
+        }
 
         // Verify traces match (order and content)
         self.execution_trace == other.execution_trace
@@ -534,6 +754,24 @@ mod tests {
         assert!(proposal.violates_constitution());
     }
 
+    #[test]
+    fn test_network_partition_heals_to_majority_chain() {
+        let mut executor = ScenarioExecutor::new(b"partition_test".to_vec());
+        let partition = executor
+            .execute_network_partition(vec![1, 2, 3], vec![4, 5], 10, 15, 12)
+            .unwrap();
+
+        assert!(partition.healed);
+        assert!(partition.is_converged());
+        // Majority group produced 7 blocks per epoch for 5 epochs
+        assert_eq!(partition.group_a.height, 35);
+        assert_eq!(partition.group_b.height, 35);
+
+        assert!(NetworkPartition::new(vec![1], vec![1], 10, 15, 0, b"tip").is_err());
+        assert!(NetworkPartition::new(vec![1], vec![2], 10, 10, 0, b"tip").is_err());
+        assert!(NetworkPartition::new(vec![1, 1], vec![2], 10, 15, 0, b"tip").is_err());
+    }
+
     #[test]
     fn test_scenario_executor_determinism() {
         let seed = b"executor_test".to_vec();
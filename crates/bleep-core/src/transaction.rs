@@ -2,6 +2,7 @@ use bleep_crypto::quantum_secure::QuantumSecure;
 use serde::{Serialize, Deserialize};
 use chrono::Utc;
 use std::sync::Arc;
+use thiserror::Error;
 
 // Re-export these from bleep_p2p once available
 pub struct PeerManager;
@@ -81,6 +82,32 @@ pub struct ZKTransaction {
     pub amount: u64,
     pub timestamp: u64,
     pub signature: Vec<u8>,
+    /// Per-sender sequence number; transactions from one sender execute in ascending nonce order
+    #[serde(default)]
+    pub nonce: u64,
+    /// Network the transaction was signed for; part of the signed payload
+    #[serde(default)]
+    pub chain_id: u64,
+}
+
+/// Chain ID of the BLEEP network; transactions signed for any other chain are rejected.
+pub const BLEEP_CHAIN_ID: u64 = 1;
+
+/// Byte length of the full SPHINCS+-SHAKE-256f-simple public key that
+/// prefixes a transaction signature.
+pub const TX_PK_LEN: usize = pqcrypto_sphincsplus::sphincsshake256fsimple::public_key_bytes();
+
+/// Why a transaction failed replay-protected validation.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum TxValidationError {
+    #[error("transaction signed for chain {found}, expected chain {expected}")]
+    ChainIdMismatch { expected: u64, found: u64 },
+
+    #[error("nonce {found} from {sender} does not match expected next nonce {expected}")]
+    NonceMismatch { sender: String, expected: u64, found: u64 },
+
+    #[error("invalid signature from {0}")]
+    InvalidSignature(String),
 }
 
 impl ZKTransaction {
@@ -95,6 +122,8 @@ impl ZKTransaction {
             amount,
             timestamp,
             signature,
+            nonce: 0,
+            chain_id: 0,
         }
     }
 
@@ -103,6 +132,59 @@ impl ZKTransaction {
         let data = format!("{}{}{}{}", self.sender, self.receiver, self.amount, self.timestamp);
         quantum_secure.verify(data.as_bytes(), &self.signature)
     }
+
+    /// Canonical payload signed for replay-protected transactions
+    pub fn chain_payload(&self) -> [u8; 32] {
+        bleep_crypto::tx_signer::chain_tx_payload(
+            self.chain_id,
+            self.nonce,
+            &self.sender,
+            &self.receiver,
+            self.amount,
+            self.timestamp,
+        )
+    }
+
+    /// Validates the transaction against the local chain ID and the sender's
+    /// expected next nonce, then checks the SPHINCS+ signature
+    /// (`pk(TX_PK_LEN) || detached_sig`) over `chain_payload()`.
+    ///
+    /// SAFETY: The chain ID and nonce are both inside the signed payload, so
+    /// rewriting either to replay the signature elsewhere breaks verification.
+    pub fn validate_for_chain(&self, chain_id: u64, expected_nonce: u64) -> Result<(), TxValidationError> {
+        if self.chain_id != chain_id {
+            return Err(TxValidationError::ChainIdMismatch { expected: chain_id, found: self.chain_id });
+        }
+        if self.nonce != expected_nonce {
+            return Err(TxValidationError::NonceMismatch {
+                sender: self.sender.clone(),
+                expected: expected_nonce,
+                found: self.nonce,
+            });
+        }
+        if self.signature.len() <= TX_PK_LEN {
+            return Err(TxValidationError::InvalidSignature(self.sender.clone()));
+        }
+        let (pk_bytes, sig_bytes) = self.signature.split_at(TX_PK_LEN);
+        if !bleep_crypto::tx_signer::verify_tx_signature(&self.chain_payload(), sig_bytes, pk_bytes) {
+            return Err(TxValidationError::InvalidSignature(self.sender.clone()));
+        }
+        Ok(())
+    }
+}
+
+impl From<&crate::block::Transaction> for ZKTransaction {
+    fn from(tx: &crate::block::Transaction) -> Self {
+        Self {
+            sender: tx.sender.clone(),
+            receiver: tx.receiver.clone(),
+            amount: tx.amount,
+            timestamp: tx.timestamp,
+            signature: tx.signature.clone(),
+            nonce: tx.nonce,
+            chain_id: tx.chain_id,
+        }
+    }
 }
 
 /// Consensus message types
@@ -191,3 +273,59 @@ impl TransactionManager {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bleep_crypto::tx_signer::{generate_tx_keypair, sign_tx_payload};
+
+    const CHAIN: u64 = 1;
+
+    fn make_chain_tx(chain_id: u64, nonce: u64) -> ZKTransaction {
+        let mut tx = ZKTransaction {
+            sender: "alice".into(),
+            receiver: "bob".into(),
+            amount: 250,
+            timestamp: 1_700_000_000,
+            signature: vec![],
+            nonce,
+            chain_id,
+        };
+        let (pk, sk) = generate_tx_keypair();
+        let sig = sign_tx_payload(&tx.chain_payload(), &sk).expect("sign");
+        tx.signature = [pk, sig].concat();
+        tx
+    }
+
+    #[test]
+    fn test_correct_transaction_accepted() {
+        let tx = make_chain_tx(CHAIN, 3);
+        assert_eq!(tx.validate_for_chain(CHAIN, 3), Ok(()));
+    }
+
+    #[test]
+    fn test_wrong_chain_id_rejected() {
+        let tx = make_chain_tx(CHAIN, 0);
+        assert_eq!(
+            tx.validate_for_chain(2, 0),
+            Err(TxValidationError::ChainIdMismatch { expected: 2, found: CHAIN })
+        );
+
+        // Rewriting the chain ID to replay the same signature on chain 2 fails
+        let mut replayed = tx.clone();
+        replayed.chain_id = 2;
+        assert_eq!(
+            replayed.validate_for_chain(2, 0),
+            Err(TxValidationError::InvalidSignature("alice".into()))
+        );
+    }
+
+    #[test]
+    fn test_stale_nonce_rejected() {
+        let tx = make_chain_tx(CHAIN, 4);
+        assert_eq!(
+            tx.validate_for_chain(CHAIN, 5),
+            Err(TxValidationError::NonceMismatch { sender: "alice".into(), expected: 5, found: 4 })
+        );
+    }
+}
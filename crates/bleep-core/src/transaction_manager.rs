@@ -1,7 +1,40 @@
 use bleep_crypto::quantum_secure::QuantumSecure;
 use crate::transaction::{ZKTransaction, P2PMessage, PeerManager, GossipProtocol, MultiHopRouting, DarkRouting};
+use sha2::{Digest, Sha256};
 use std::sync::Arc;
 
+/// Canonical intra-block execution order.
+///
+/// Sorts by `(sender, nonce)` and breaks remaining ties on the transaction
+/// hash, so every node given the same set of transactions executes them in
+/// the same order regardless of how they arrived.
+pub fn order_transactions(txs: &[ZKTransaction]) -> Vec<ZKTransaction> {
+    let mut keyed: Vec<([u8; 32], &ZKTransaction)> =
+        txs.iter().map(|tx| (tx_hash(tx), tx)).collect();
+    keyed.sort_by(|(ha, a), (hb, b)| {
+        a.sender
+            .cmp(&b.sender)
+            .then(a.nonce.cmp(&b.nonce))
+            .then(ha.cmp(hb))
+    });
+    keyed.into_iter().map(|(_, tx)| tx.clone()).collect()
+}
+
+/// SHA-256 over every field of the transaction, used as the ordering tiebreak.
+fn tx_hash(tx: &ZKTransaction) -> [u8; 32] {
+    let mut h = Sha256::new();
+    h.update(tx.sender.as_bytes());
+    h.update([0u8]);
+    h.update(tx.receiver.as_bytes());
+    h.update([0u8]);
+    h.update(tx.amount.to_le_bytes());
+    h.update(tx.timestamp.to_le_bytes());
+    h.update(tx.nonce.to_le_bytes());
+    h.update(tx.chain_id.to_le_bytes());
+    h.update(&tx.signature);
+    h.finalize().into()
+}
+
 pub struct TransactionManager {
     peer_manager: Arc<PeerManager>,
     gossip_protocol: Arc<GossipProtocol>,
@@ -57,3 +90,69 @@ impl TransactionManager {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tx(sender: &str, nonce: u64, amount: u64) -> ZKTransaction {
+        ZKTransaction {
+            sender: sender.to_string(),
+            receiver: "carol".to_string(),
+            amount,
+            timestamp: 1_700_000_000,
+            signature: vec![nonce as u8, amount as u8],
+            nonce,
+            chain_id: 0,
+        }
+    }
+
+    fn ids(txs: &[ZKTransaction]) -> Vec<(String, u64, u64)> {
+        txs.iter().map(|t| (t.sender.clone(), t.nonce, t.amount)).collect()
+    }
+
+    #[test]
+    fn test_order_is_independent_of_input_order() {
+        let txs = vec![
+            tx("bob", 2, 10),
+            tx("alice", 1, 20),
+            tx("bob", 0, 30),
+            tx("alice", 0, 40),
+            tx("alice", 1, 50), // same (sender, nonce) as another, resolved by hash
+            tx("bob", 1, 60),
+        ];
+        let expected = ids(&order_transactions(&txs));
+
+        let mut shuffled = txs.clone();
+        for rotation in 0..shuffled.len() {
+            shuffled.rotate_left(1);
+            assert_eq!(ids(&order_transactions(&shuffled)), expected, "rotation {}", rotation);
+            let mut reversed = shuffled.clone();
+            reversed.reverse();
+            assert_eq!(ids(&order_transactions(&reversed)), expected);
+        }
+    }
+
+    #[test]
+    fn test_nonces_ascend_per_sender() {
+        let txs = vec![tx("bob", 3, 1), tx("alice", 2, 2), tx("bob", 1, 3), tx("alice", 0, 4), tx("bob", 2, 5)];
+        let ordered = order_transactions(&txs);
+
+        assert_eq!(ordered.len(), txs.len());
+        for sender in ["alice", "bob"] {
+            let nonces: Vec<u64> = ordered.iter().filter(|t| t.sender == sender).map(|t| t.nonce).collect();
+            let mut sorted = nonces.clone();
+            sorted.sort_unstable();
+            assert_eq!(nonces, sorted, "{} nonces must ascend", sender);
+        }
+        assert_eq!(ordered[0].sender, "alice");
+        assert_eq!(ordered[0].nonce, 0);
+    }
+
+    #[test]
+    fn test_hash_commits_to_chain_id() {
+        let mut other_chain = tx("alice", 0, 10);
+        other_chain.chain_id = 1;
+        assert_ne!(tx_hash(&tx("alice", 0, 10)), tx_hash(&other_chain));
+    }
+}
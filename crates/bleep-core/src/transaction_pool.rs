@@ -1,16 +1,15 @@
 //! # TransactionPool
 //!
-use crate::transaction::ZKTransaction;
+use crate::blockchain::BlockchainState;
+use crate::transaction::{ZKTransaction, BLEEP_CHAIN_ID, TX_PK_LEN};
 use std::collections::{HashSet, VecDeque};
 use tokio::sync::Mutex;
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock, RwLock};
 use sha2::{Digest, Sha256};
 
-/// Minimum signature length: 32-byte pk + at least 1 byte of sig material.
-const MIN_SIG_LEN: usize = 33;
+/// Minimum signature length: full public key + at least 1 byte of sig material.
+const MIN_SIG_LEN: usize = TX_PK_LEN + 1;
 
-/// Expected full signature length: 32-byte pk + 49856-byte SPHINCS+ detached sig.
-const SPHINCS_PK_LEN: usize = 32;
 
 // ── TransactionPool ───────────────────────────────────────────────────────────
 
@@ -22,18 +21,44 @@ pub struct TransactionPool {
     seen_hashes: Mutex<HashSet<[u8; 32]>>,
     /// Maximum number of pending transactions.
     max_size: usize,
+    /// Network ID admitted transactions must be signed for.
+    chain_id: u64,
+    /// Committed account state, used for sender nonces once attached.
+    state: OnceLock<Arc<RwLock<BlockchainState>>>,
 }
 
 impl TransactionPool {
     /// Create a new pool with the given capacity limit.
     pub fn new(max_size: usize) -> Arc<Self> {
+        Self::with_chain_id(max_size, BLEEP_CHAIN_ID)
+    }
+
+    /// Create a new pool admitting only transactions signed for `chain_id`.
+    pub fn with_chain_id(max_size: usize, chain_id: u64) -> Arc<Self> {
         Arc::new(Self {
             pool: Mutex::new(VecDeque::with_capacity(max_size.min(65_536))),
             seen_hashes: Mutex::new(HashSet::new()),
             max_size,
+            chain_id,
+            state: OnceLock::new(),
         })
     }
 
+    /// Attach the committed account state used to look up sender nonces.
+    ///
+    /// Called once by `Blockchain::new`; until then every sender starts at nonce 0.
+    pub fn attach_state(&self, state: Arc<RwLock<BlockchainState>>) {
+        self.state.set(state).ok();
+    }
+
+    /// Committed nonce of `sender`, or 0 when no state is attached.
+    fn committed_nonce(&self, sender: &str) -> u64 {
+        self.state
+            .get()
+            .map(|s| s.read().unwrap().nonce_of(sender))
+            .unwrap_or(0)
+    }
+
     /// Adds a transaction while ensuring pool size constraints
     /// 
     /// SAFETY: Enforces max_size limit to prevent unbounded memory growth.
@@ -82,27 +107,24 @@ impl TransactionPool {
             return false;
         }
 
-        // ── Step 4: S-07 — SPHINCS+ cryptographic verification ───────────────
-        //
-        // Wire format: signature = pk_bytes(32) || sphincs_detached_sig(49856)
-        // Canonical payload: SHA3-256(sender || receiver || amount_le8 || timestamp_le8)
+        // ── Step 4: S-07 — replay-protected SPHINCS+ verification ────────────
         //
-        // We split the signature blob into (pk, sig) and verify using the same
-        // tx_payload() function used at signing time.
-        let pk_bytes  = &transaction.signature[..SPHINCS_PK_LEN];
-        let sig_bytes = &transaction.signature[SPHINCS_PK_LEN..];
-
-        let payload = bleep_crypto::tx_signer::tx_payload(
-            &transaction.sender,
-            &transaction.receiver,
-            transaction.amount,
-            transaction.timestamp,
-        );
+        // Wire format: signature = pk_bytes(TX_PK_LEN) || sphincs_detached_sig(49856)
+        // Canonical payload: ZKTransaction::chain_payload(), which binds the
+        // chain ID and sender nonce. The expected nonce is the sender's
+        // committed nonce plus its transactions already pending here; it is
+        // read under the pool lock so concurrent submissions cannot share it.
+        let mut pool = self.pool.lock().await;
+        let pending_from_sender = pool
+            .iter()
+            .filter(|tx| tx.sender == transaction.sender)
+            .count() as u64;
+        let expected_nonce = self.committed_nonce(&transaction.sender) + pending_from_sender;
 
-        if !bleep_crypto::tx_signer::verify_tx_signature(&payload, sig_bytes, pk_bytes) {
+        if let Err(e) = transaction.validate_for_chain(self.chain_id, expected_nonce) {
             log::error!(
-                "[TxPool] S-07: SPHINCS+ verification FAILED — tx from {} to {} amount {} rejected",
-                transaction.sender, transaction.receiver, transaction.amount
+                "[TxPool] S-07: tx from {} to {} amount {} rejected: {}",
+                transaction.sender, transaction.receiver, transaction.amount, e
             );
             return false;
         }
@@ -110,8 +132,8 @@ impl TransactionPool {
         // ── Step 5: S-09 — Duplicate detection via payload hash ──────────────
         //
         // Hash the canonical payload (same bytes that were signed).
-        // This catches exact replays (same sender/receiver/amount/timestamp).
-        let tx_hash: [u8; 32] = Sha256::digest(&payload).into();
+        // This catches exact replays (same chain/nonce/sender/receiver/amount/timestamp).
+        let tx_hash: [u8; 32] = Sha256::digest(transaction.chain_payload()).into();
 
         {
             let mut seen = self.seen_hashes.lock().await;
@@ -127,7 +149,6 @@ impl TransactionPool {
         }
 
         // ── Admit ─────────────────────────────────────────────────────────────
-        let mut pool = self.pool.lock().await;
         
         // Check if pool is at capacity
         if pool.len() >= self.max_size {
@@ -231,30 +252,34 @@ impl TransactionPool {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use bleep_crypto::tx_signer::{generate_tx_keypair, sign_tx_payload, tx_payload};
 
-    /// Build a properly-signed ZKTransaction.
-    fn make_signed_tx(sender: &str, receiver: &str, amount: u64, timestamp: u64) -> ZKTransaction {
+    use bleep_crypto::tx_signer::{generate_tx_keypair, sign_tx_payload};
+
+    /// Build a properly-signed ZKTransaction at `nonce`.
+    fn make_signed_tx(sender: &str, receiver: &str, amount: u64, timestamp: u64, nonce: u64) -> ZKTransaction {
         let (pk, sk) = generate_tx_keypair();
-        let payload  = tx_payload(sender, receiver, amount, timestamp);
-        let sig      = sign_tx_payload(&payload, &sk).expect("sign");
-        // Wire format: pk(32) || sphincs_sig
-        let mut full_sig = Vec::with_capacity(pk.len() + sig.len());
-        full_sig.extend_from_slice(&pk);
-        full_sig.extend_from_slice(&sig);
-        ZKTransaction {
+        let mut tx = ZKTransaction {
             sender:    sender.to_string(),
             receiver:  receiver.to_string(),
             amount,
             timestamp,
-            signature: full_sig,
-        }
+            signature: vec![],
+            nonce,
+            chain_id:  BLEEP_CHAIN_ID,
+        };
+        let sig = sign_tx_payload(&tx.chain_payload(), &sk).expect("sign");
+        // Wire format: pk(TX_PK_LEN) || sphincs_sig
+        let mut full_sig = Vec::with_capacity(pk.len() + sig.len());
+        full_sig.extend_from_slice(&pk);
+        full_sig.extend_from_slice(&sig);
+        tx.signature = full_sig;
+        tx
     }
 
     #[tokio::test]
     async fn test_valid_tx_admitted() {
         let pool = TransactionPool::new(100);
-        let tx   = make_signed_tx("alice", "bob", 500, 1_700_000_000);
+        let tx   = make_signed_tx("alice", "bob", 500, 1_700_000_000, 0);
         assert!(pool.add_transaction(tx).await, "Valid signed tx must be admitted");
         assert_eq!(pool.pool_size().await, 1);
     }
@@ -268,6 +293,8 @@ mod tests {
             sender: "alice".into(), receiver: "bob".into(),
             amount: 100, timestamp: 1_700_000_001,
             signature: vec![],
+            nonce: 0,
+            chain_id: BLEEP_CHAIN_ID,
         };
         assert!(!pool.add_transaction(tx).await, "S-07: empty sig must be rejected");
     }
@@ -279,6 +306,8 @@ mod tests {
             sender: "alice".into(), receiver: "bob".into(),
             amount: 100, timestamp: 1_700_000_002,
             signature: vec![0u8; 10],  // too short
+            nonce: 0,
+            chain_id: BLEEP_CHAIN_ID,
         };
         assert!(!pool.add_transaction(tx).await, "S-07: short sig must be rejected");
     }
@@ -290,7 +319,9 @@ mod tests {
         let tx = ZKTransaction {
             sender: "alice".into(), receiver: "bob".into(),
             amount: 100, timestamp: 1_700_000_003,
-            signature: vec![0u8; SPHINCS_PK_LEN + 49856],
+            signature: vec![0u8; TX_PK_LEN + 49856],
+            nonce: 0,
+            chain_id: BLEEP_CHAIN_ID,
         };
         assert!(!pool.add_transaction(tx).await, "S-07: forged sig must fail SPHINCS+ verify");
     }
@@ -298,7 +329,7 @@ mod tests {
     #[tokio::test]
     async fn test_s07_tampered_signature_rejected() {
         let pool = TransactionPool::new(100);
-        let mut tx = make_signed_tx("alice", "bob", 200, 1_700_000_004);
+        let mut tx = make_signed_tx("alice", "bob", 200, 1_700_000_004, 0);
         tx.signature[50] ^= 0xFF;  // flip a byte in the sig portion
         assert!(!pool.add_transaction(tx).await, "S-07: tampered sig must be rejected");
     }
@@ -307,7 +338,7 @@ mod tests {
     async fn test_s07_wrong_amount_rejected() {
         // Sign for amount=200 but submit with amount=999
         let pool = TransactionPool::new(100);
-        let mut tx = make_signed_tx("alice", "bob", 200, 1_700_000_005);
+        let mut tx = make_signed_tx("alice", "bob", 200, 1_700_000_005, 0);
         tx.amount = 999;  // mismatch with signed payload
         assert!(!pool.add_transaction(tx).await, "S-07: mutated amount must fail SPHINCS+ verify");
     }
@@ -317,10 +348,10 @@ mod tests {
     #[tokio::test]
     async fn test_s09_exact_duplicate_rejected() {
         let pool = TransactionPool::new(100);
-        let tx1 = make_signed_tx("alice", "bob", 300, 1_700_000_010);
+        let tx1 = make_signed_tx("alice", "bob", 300, 1_700_000_010, 0);
         // Build an identical tx with the same payload (same sender/receiver/amount/timestamp)
         // by re-signing with a different key — same payload hash, so should be caught
-        let tx2 = make_signed_tx("alice", "bob", 300, 1_700_000_010);
+        let tx2 = make_signed_tx("alice", "bob", 300, 1_700_000_010, 0);
         assert!(pool.add_transaction(tx1).await, "First tx admitted");
         assert!(!pool.add_transaction(tx2).await, "S-09: exact duplicate rejected");
     }
@@ -329,8 +360,8 @@ mod tests {
     async fn test_s09_different_timestamp_admitted() {
         // Different timestamps → different payload → different hash → both admitted
         let pool = TransactionPool::new(100);
-        let tx1 = make_signed_tx("alice", "bob", 300, 1_700_000_020);
-        let tx2 = make_signed_tx("alice", "bob", 300, 1_700_000_021);
+        let tx1 = make_signed_tx("alice", "bob", 300, 1_700_000_020, 0);
+        let tx2 = make_signed_tx("alice", "bob", 300, 1_700_000_021, 1);
         assert!(pool.add_transaction(tx1).await);
         assert!(pool.add_transaction(tx2).await, "S-09: different timestamp must be admitted");
         assert_eq!(pool.pool_size().await, 2);
@@ -341,7 +372,7 @@ mod tests {
     #[tokio::test]
     async fn test_empty_sender_rejected() {
         let pool = TransactionPool::new(100);
-        let tx = make_signed_tx("", "bob", 100, 1_700_000_030);
+        let tx = make_signed_tx("", "bob", 100, 1_700_000_030, 0);
         assert!(!pool.add_transaction(tx).await);
     }
 
@@ -352,6 +383,8 @@ mod tests {
             sender: "alice".into(), receiver: "bob".into(),
             amount: 0, timestamp: 1_700_000_031,
             signature: vec![1u8; MIN_SIG_LEN + 10],
+            nonce: 0,
+            chain_id: BLEEP_CHAIN_ID,
         };
         assert!(!pool.add_transaction(tx).await);
     }
@@ -359,16 +392,16 @@ mod tests {
     #[tokio::test]
     async fn test_self_transfer_rejected() {
         let pool = TransactionPool::new(100);
-        let tx = make_signed_tx("alice", "alice", 100, 1_700_000_032);
+        let tx = make_signed_tx("alice", "alice", 100, 1_700_000_032, 0);
         assert!(!pool.add_transaction(tx).await);
     }
 
     #[tokio::test]
     async fn test_capacity_limit() {
         let pool = TransactionPool::new(2);
-        let tx1 = make_signed_tx("a", "b", 1, 1_700_100_001);
-        let tx2 = make_signed_tx("a", "b", 2, 1_700_100_002);
-        let tx3 = make_signed_tx("a", "b", 3, 1_700_100_003);
+        let tx1 = make_signed_tx("a", "b", 1, 1_700_100_001, 0);
+        let tx2 = make_signed_tx("a", "b", 2, 1_700_100_002, 1);
+        let tx3 = make_signed_tx("a", "b", 3, 1_700_100_003, 2);
         assert!(pool.add_transaction(tx1).await);
         assert!(pool.add_transaction(tx2).await);
         assert!(!pool.add_transaction(tx3).await, "Pool at capacity must reject");
@@ -377,9 +410,9 @@ mod tests {
     #[tokio::test]
     async fn test_peek_for_block() {
         let pool = TransactionPool::new(100);
-        let tx1 = make_signed_tx("a", "b", 1, 1_700_200_001);
-        let tx2 = make_signed_tx("a", "b", 2, 1_700_200_002);
-        let tx3 = make_signed_tx("a", "b", 3, 1_700_200_003);
+        let tx1 = make_signed_tx("a", "b", 1, 1_700_200_001, 0);
+        let tx2 = make_signed_tx("a", "b", 2, 1_700_200_002, 1);
+        let tx3 = make_signed_tx("a", "b", 3, 1_700_200_003, 2);
         pool.add_transaction(tx1).await;
         pool.add_transaction(tx2).await;
         pool.add_transaction(tx3).await;
@@ -387,4 +420,26 @@ mod tests {
         assert_eq!(peeked.len(), 2, "peek_for_block must respect limit");
         assert_eq!(pool.pool_size().await, 3, "peek must not remove txs");
     }
+
+    // ── Replay protection ─────────────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn test_wrong_chain_id_rejected() {
+        let pool = TransactionPool::with_chain_id(100, BLEEP_CHAIN_ID + 1);
+        let tx = make_signed_tx("alice", "bob", 100, 1_700_300_001, 0);
+        assert!(!pool.add_transaction(tx).await, "tx signed for another chain must be rejected");
+    }
+
+    #[tokio::test]
+    async fn test_nonce_must_follow_committed_and_pending() {
+        let pool = TransactionPool::new(100);
+        let mut state = BlockchainState::new();
+        state.nonces.insert("alice".into(), 5);
+        pool.attach_state(Arc::new(RwLock::new(state)));
+
+        assert!(!pool.add_transaction(make_signed_tx("alice", "bob", 1, 1_700_300_010, 4)).await, "stale nonce");
+        assert!(!pool.add_transaction(make_signed_tx("alice", "bob", 1, 1_700_300_011, 6)).await, "nonce gap");
+        assert!(pool.add_transaction(make_signed_tx("alice", "bob", 1, 1_700_300_012, 5)).await);
+        assert!(pool.add_transaction(make_signed_tx("alice", "bob", 1, 1_700_300_013, 6)).await);
+    }
 }
@@ -13,6 +13,7 @@ pub mod merkle_commitment;
 mod tests;
 
 pub use pq_crypto::*;
+pub use quantum_secure::{DilithiumKeypair, DilithiumSignature};
 pub use bip39::{mnemonic_to_seed, mnemonic_to_bleep_seed, validate_mnemonic};
-pub use tx_signer::{sign_tx_payload, verify_tx_signature, tx_payload, generate_tx_keypair};
+pub use tx_signer::{sign_tx_payload, verify_tx_signature, tx_payload, chain_tx_payload, generate_tx_keypair};
 pub use merkle_commitment::*;
@@ -750,20 +750,3 @@ fn calculate_entropy(data: &[u8]) -> f64 {
         })
         .sum()
 }
-
-/// Calculate Shannon entropy of a byte slice
-fn calculate_entropy(data: &[u8]) -> f64 {
-    let mut counts = [0usize; 256];
-    for &byte in data {
-        counts[byte as usize] += 1;
-    }
-
-    let len = data.len() as f64;
-    counts.iter()
-        .filter(|&&count| count > 0)
-        .map(|&count| {
-            let p = count as f64 / len;
-            -p * p.log2()
-        })
-        .sum()
-}
@@ -3,6 +3,9 @@ use pqcrypto_kyber::kyber1024;
 use pqcrypto_traits::kem::SharedSecret;
 use pqcrypto_traits::sign::DetachedSignature;
 use pqcrypto_sphincsplus::sphincssha2128fsimple;
+use fips204::ml_dsa_65;
+use fips204::traits::{KeyGen, SerDes, Signer, Verifier};
+use serde::{Deserialize, Serialize};
 use aes_gcm::KeyInit;
 use rand::rngs::OsRng;
 use rand::RngCore;
@@ -75,4 +78,203 @@ impl QuantumSecure {
         };
         sphincssha2128fsimple::verify_detached_signature(&detached_sig, message, &self.public_key).is_ok()
     }
-                                           }
+}
+
+// ─── Dilithium (ML-DSA-65) ────────────────────────────────────────────────────
+//
+// Byte formats (stable across BLEEP versions):
+//
+//   Public key : 1952 bytes — FIPS 204 ML-DSA-65 `pkEncode` output
+//   Secret key : 4032 bytes — FIPS 204 ML-DSA-65 `skEncode` output
+//   Signature  : 3309 bytes — FIPS 204 ML-DSA-65 detached `sigEncode` output
+//
+// All three are raw encodings with no length prefix or algorithm tag.  Any
+// change of parameter set MUST introduce a new type rather than altering these.
+//
+// Key generation is the FIPS 204 `ML-DSA.KeyGen_internal(ξ)` over a 32-byte
+// seed, so a keypair is fully reproducible from its seed (wallet restore).
+// Signing uses the empty context string.
+
+/// ML-DSA-65 public key length in bytes.
+pub const DILITHIUM_PUBLIC_KEY_BYTES: usize = 1952;
+/// ML-DSA-65 secret key length in bytes.
+pub const DILITHIUM_SECRET_KEY_BYTES: usize = 4032;
+/// ML-DSA-65 detached signature length in bytes.
+pub const DILITHIUM_SIGNATURE_BYTES: usize = 3309;
+
+/// Detached Dilithium (ML-DSA-65) signature.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "Vec<u8>")]
+pub struct DilithiumSignature(Vec<u8>);
+
+impl TryFrom<Vec<u8>> for DilithiumSignature {
+    type Error = String;
+
+    fn try_from(bytes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::from_bytes(&bytes)
+    }
+}
+
+impl DilithiumSignature {
+    /// Parse a signature, rejecting anything that is not exactly
+    /// `DILITHIUM_SIGNATURE_BYTES` long.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+        if bytes.len() != DILITHIUM_SIGNATURE_BYTES {
+            return Err(format!(
+                "Dilithium signature must be {} bytes, got {}",
+                DILITHIUM_SIGNATURE_BYTES,
+                bytes.len()
+            ));
+        }
+        Ok(Self(bytes.to_vec()))
+    }
+
+    pub fn as_bytes(&self) -> &[u8] { &self.0 }
+}
+
+/// Dilithium (ML-DSA-65, NIST Level 3) signing keypair.
+#[derive(Clone)]
+pub struct DilithiumKeypair {
+    pub public_key: Vec<u8>,
+    secret_key: Vec<u8>,
+}
+
+impl DilithiumKeypair {
+    /// Generate a fresh keypair from OS entropy.
+    pub fn generate() -> Self {
+        let mut seed = [0u8; 32];
+        OsRng.fill_bytes(&mut seed);
+        let keypair = Self::from_seed(&seed);
+        seed.iter_mut().for_each(|b| *b = 0);
+        keypair
+    }
+
+    /// Derive a keypair deterministically from a 32-byte seed (FIPS 204 `ξ`).
+    ///
+    /// The same seed always yields the same keypair, on every platform.
+    pub fn from_seed(seed: &[u8; 32]) -> Self {
+        let (pk, sk) = ml_dsa_65::KG::keygen_from_seed(seed);
+        DilithiumKeypair {
+            public_key: pk.into_bytes().to_vec(),
+            secret_key: sk.into_bytes().to_vec(),
+        }
+    }
+
+    /// Rebuild a keypair from its serialized public and secret keys.
+    pub fn from_bytes(public_key: &[u8], secret_key: &[u8]) -> Result<Self, String> {
+        parse_public_key(public_key)?;
+        parse_secret_key(secret_key)?;
+        Ok(DilithiumKeypair {
+            public_key: public_key.to_vec(),
+            secret_key: secret_key.to_vec(),
+        })
+    }
+
+    pub fn public_key(&self) -> &[u8] { &self.public_key }
+
+    pub fn secret_key(&self) -> &[u8] { &self.secret_key }
+
+    /// Produce a detached signature over `message`.
+    pub fn sign(&self, message: &[u8]) -> DilithiumSignature {
+        let sk = parse_secret_key(&self.secret_key)
+            .expect("secret key validated at construction");
+        let sig = sk.try_sign(message, &[])
+            .expect("signing with an empty context cannot fail");
+        DilithiumSignature(sig.to_vec())
+    }
+
+    /// Verify `signature` over `message` under `public_key`.
+    ///
+    /// Malformed keys or signatures verify as `false`.
+    pub fn verify(public_key: &[u8], message: &[u8], signature: &DilithiumSignature) -> bool {
+        let pk = match parse_public_key(public_key) {
+            Ok(pk) => pk,
+            Err(_) => return false,
+        };
+        let sig: [u8; DILITHIUM_SIGNATURE_BYTES] = match signature.as_bytes().try_into() {
+            Ok(sig) => sig,
+            Err(_) => return false,
+        };
+        pk.verify(message, &sig, &[])
+    }
+}
+
+fn parse_public_key(bytes: &[u8]) -> Result<ml_dsa_65::PublicKey, String> {
+    let bytes: [u8; DILITHIUM_PUBLIC_KEY_BYTES] = bytes.try_into()
+        .map_err(|_| format!("Dilithium public key must be {} bytes", DILITHIUM_PUBLIC_KEY_BYTES))?;
+    ml_dsa_65::PublicKey::try_from_bytes(bytes)
+        .map_err(|e| format!("Invalid Dilithium public key: {}", e))
+}
+
+fn parse_secret_key(bytes: &[u8]) -> Result<ml_dsa_65::PrivateKey, String> {
+    let bytes: [u8; DILITHIUM_SECRET_KEY_BYTES] = bytes.try_into()
+        .map_err(|_| format!("Dilithium secret key must be {} bytes", DILITHIUM_SECRET_KEY_BYTES))?;
+    ml_dsa_65::PrivateKey::try_from_bytes(bytes)
+        .map_err(|e| format!("Invalid Dilithium secret key: {}", e))
+}
+
+impl Drop for DilithiumKeypair {
+    fn drop(&mut self) {
+        self.secret_key.iter_mut().for_each(|b| *b = 0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dilithium_sign_verify_roundtrip() {
+        let kp = DilithiumKeypair::generate();
+        let sig = kp.sign(b"bleep transfer");
+        assert_eq!(kp.public_key().len(), DILITHIUM_PUBLIC_KEY_BYTES);
+        assert_eq!(kp.secret_key().len(), DILITHIUM_SECRET_KEY_BYTES);
+        assert_eq!(sig.as_bytes().len(), DILITHIUM_SIGNATURE_BYTES);
+        assert!(DilithiumKeypair::verify(kp.public_key(), b"bleep transfer", &sig));
+    }
+
+    #[test]
+    fn dilithium_tampered_message_rejected() {
+        let kp = DilithiumKeypair::generate();
+        let sig = kp.sign(b"send 10 BLEEP");
+        assert!(!DilithiumKeypair::verify(kp.public_key(), b"send 99 BLEEP", &sig));
+    }
+
+    #[test]
+    fn dilithium_wrong_public_key_rejected() {
+        let signer = DilithiumKeypair::generate();
+        let other = DilithiumKeypair::generate();
+        let sig = signer.sign(b"payload");
+        assert!(!DilithiumKeypair::verify(other.public_key(), b"payload", &sig));
+    }
+
+    #[test]
+    fn dilithium_keypair_from_seed_is_deterministic() {
+        let a = DilithiumKeypair::from_seed(&[7u8; 32]);
+        let b = DilithiumKeypair::from_seed(&[7u8; 32]);
+        let c = DilithiumKeypair::from_seed(&[8u8; 32]);
+        assert_eq!(a.public_key(), b.public_key());
+        assert_eq!(a.secret_key(), b.secret_key());
+        assert_ne!(a.public_key(), c.public_key());
+        assert!(DilithiumKeypair::verify(a.public_key(), b"msg", &b.sign(b"msg")));
+    }
+
+    #[test]
+    fn dilithium_keypair_bytes_roundtrip() {
+        let kp = DilithiumKeypair::generate();
+        let restored = DilithiumKeypair::from_bytes(kp.public_key(), kp.secret_key()).unwrap();
+        let sig = restored.sign(b"msg");
+        assert!(DilithiumKeypair::verify(kp.public_key(), b"msg", &sig));
+        assert!(DilithiumSignature::from_bytes(&[0u8; 10]).is_err());
+    }
+
+    #[test]
+    fn dilithium_signature_deserialize_checks_length() {
+        let sig = DilithiumKeypair::from_seed(&[1u8; 32]).sign(b"msg");
+        let encoded = bincode::serialize(&sig).unwrap();
+        assert_eq!(bincode::deserialize::<DilithiumSignature>(&encoded).unwrap(), sig);
+
+        let short = bincode::serialize(&vec![0u8; 10]).unwrap();
+        assert!(bincode::deserialize::<DilithiumSignature>(&short).is_err());
+    }
+}
@@ -67,6 +67,33 @@ pub fn tx_payload(sender: &str, receiver: &str, amount: u64, timestamp: u64) ->
     h.finalize().into()
 }
 
+/// Build a replay-protected payload bound to a network and sender nonce.
+///
+/// Layout: `sha3_256( chain_id_le8 || nonce_le8 || len_le8 || sender_bytes || len_le8 || receiver_bytes || amount_le8 || timestamp_le8 )`
+///
+/// A signature over this payload cannot be replayed on another chain or at
+/// another position in the sender's nonce sequence. The variable-length
+/// fields are length-prefixed so no two (sender, receiver) splits collide.
+pub fn chain_tx_payload(
+    chain_id: u64,
+    nonce: u64,
+    sender: &str,
+    receiver: &str,
+    amount: u64,
+    timestamp: u64,
+) -> [u8; 32] {
+    let mut h = Sha3_256::new();
+    h.update(&chain_id.to_le_bytes());
+    h.update(&nonce.to_le_bytes());
+    h.update(&(sender.len() as u64).to_le_bytes());
+    h.update(sender.as_bytes());
+    h.update(&(receiver.len() as u64).to_le_bytes());
+    h.update(receiver.as_bytes());
+    h.update(&amount.to_le_bytes());
+    h.update(&timestamp.to_le_bytes());
+    h.finalize().into()
+}
+
 /// Generate a fresh SPHINCS+ keypair.
 ///
 /// Returns `(public_key_bytes, secret_key_bytes)`.
@@ -122,4 +149,20 @@ mod tests {
         let p2 = tx_payload("alice", "bob", 2000, 99999);
         assert_ne!(p1, p2);
     }
+
+    #[test]
+    fn test_chain_tx_payload_binds_chain_and_nonce() {
+        let base = chain_tx_payload(1, 7, "alice", "bob", 1000, 99999);
+        assert_eq!(base, chain_tx_payload(1, 7, "alice", "bob", 1000, 99999));
+        assert_ne!(base, chain_tx_payload(2, 7, "alice", "bob", 1000, 99999));
+        assert_ne!(base, chain_tx_payload(1, 8, "alice", "bob", 1000, 99999));
+    }
+
+    #[test]
+    fn test_chain_tx_payload_field_split_unambiguous() {
+        assert_ne!(
+            chain_tx_payload(1, 0, "ab", "c", 1, 1),
+            chain_tx_payload(1, 0, "a", "bc", 1, 1)
+        );
+    }
 }
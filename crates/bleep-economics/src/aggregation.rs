@@ -0,0 +1,101 @@
+//! DETERMINISTIC AGGREGATION HELPERS
+//!
+//! Shared by the oracle bridge and the consensus AI advisory aggregator. The
+//! oracle takes the upper median on an exact half split; the advisory
+//! aggregator averages the two middle values.
+
+/// Lower and upper weighted medians of `(weight, value)` pairs.
+///
+/// The lower median is the smallest value `v` such that the total weight of
+/// values `<= v` is at least half of all weight; the upper median is the
+/// smallest value whose cumulative weight is strictly more than half. They
+/// differ only when the weight splits exactly in half between two values.
+/// Input order does not matter; zero-weight entries are ignored.
+///
+/// Returns `None` if there is no positive weight.
+pub fn weighted_median_bounds<T: Ord + Clone>(values: &[(u128, T)]) -> Option<(T, T)> {
+    let mut sorted: Vec<&(u128, T)> = values.iter().filter(|(w, _)| *w > 0).collect();
+    sorted.sort_by(|a, b| a.1.cmp(&b.1));
+
+    let total: u128 = sorted.iter().fold(0u128, |acc, (w, _)| acc.saturating_add(*w));
+    if total == 0 {
+        return None;
+    }
+
+    let mut lower = None;
+    let mut cumulative = 0u128;
+    for (weight, value) in sorted {
+        cumulative = cumulative.saturating_add(*weight);
+        let doubled = cumulative.saturating_mul(2);
+        if lower.is_none() && doubled >= total {
+            lower = Some(value.clone());
+        }
+        if doubled > total {
+            return lower.map(|lo| (lo, value.clone()));
+        }
+    }
+
+    // Only reachable when saturation hides the strict majority
+    lower.map(|lo| (lo.clone(), lo))
+}
+
+/// Weighted median of `(weight, value)` pairs: the first value whose
+/// cumulative weight passes half of all weight (the upper median of
+/// `weighted_median_bounds`). This is the oracle bridge's price rule.
+///
+/// Returns `None` if there is no positive weight.
+pub fn weighted_median<T: Ord + Clone>(values: &[(u128, T)]) -> Option<T> {
+    weighted_median_bounds(values).map(|(_, upper)| upper)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_weighted_median_odd_uniform() {
+        let values = [(1, 50u8), (1, 10), (1, 90), (1, 20), (1, 80)];
+        assert_eq!(weighted_median(&values), Some(50));
+    }
+
+    #[test]
+    fn test_weighted_median_even_uniform_takes_upper() {
+        // Weight splits 2/2 between 20 and 80
+        let values = [(1, 10u8), (1, 20), (1, 80), (1, 90)];
+        assert_eq!(weighted_median(&values), Some(80));
+        assert_eq!(weighted_median_bounds(&values), Some((20, 80)));
+    }
+
+    #[test]
+    fn test_weighted_median_uneven_weights() {
+        // Total 10: cumulative 1, 3, 6 -> 30 is the first to reach half
+        let values = [(4, 40u32), (1, 10), (3, 30), (2, 20)];
+        assert_eq!(weighted_median(&values), Some(30));
+    }
+
+    #[test]
+    fn test_weighted_median_dominant_weight() {
+        let values = [(1, 1u64), (1_000, 500), (1, 1_000), (1, 2_000)];
+        assert_eq!(weighted_median(&values), Some(500));
+        assert_eq!(weighted_median_bounds(&values), Some((500, 500)));
+    }
+
+    #[test]
+    fn test_weighted_median_ties() {
+        // Equal values pool their weight
+        let values = [(1, 7u8), (1, 7), (1, 3), (1, 9)];
+        assert_eq!(weighted_median(&values), Some(7));
+
+        // Exact half split picks the upper value regardless of input order
+        assert_eq!(weighted_median(&[(5, 200u128), (5, 100)]), Some(200));
+        assert_eq!(weighted_median(&[(5, 100u128), (5, 200)]), Some(200));
+    }
+
+    #[test]
+    fn test_weighted_median_no_weight() {
+        assert_eq!(weighted_median::<u8>(&[]), None);
+        assert_eq!(weighted_median_bounds::<u8>(&[]), None);
+        assert_eq!(weighted_median(&[(0, 1u8), (0, 2)]), None);
+        assert_eq!(weighted_median(&[(0, 1u8), (3, 2)]), Some(2));
+    }
+}
@@ -271,7 +271,7 @@ impl ValidatorEmissionSchedule {
             return 0;
         }
         let annual = Self::annual_emission_for_year(year);
-        let per_epoch = annual / epochs_per_year;
+        let per_epoch = annual / epochs_per_year as u128;
         per_epoch.min(self.remaining)
     }
 
@@ -373,8 +373,14 @@ impl FeeDistribution {
     /// Compute the distribution for a given total fee amount.
     /// Uses integer arithmetic; rounding remainder goes to validator_reward.
     pub fn compute(total_fee: u128) -> Self {
-        let burned           = total_fee * FEE_BURN_BPS as u128 / 10_000;
-        let treasury         = total_fee * FEE_TREASURY_BPS as u128 / 10_000;
+        Self::compute_with(total_fee, &FeeRoutingConfig::default())
+    }
+
+    /// Compute the distribution under a governance-set routing config.
+    /// Whatever is neither burned nor sent to treasury goes to validators.
+    pub fn compute_with(total_fee: u128, routing: &FeeRoutingConfig) -> Self {
+        let burned           = total_fee * routing.burn_bps as u128 / 10_000;
+        let treasury         = total_fee * routing.treasury_bps as u128 / 10_000;
         let validator_reward = total_fee.saturating_sub(burned).saturating_sub(treasury);
         Self { burned, validator_reward, treasury, total_fee }
     }
@@ -385,6 +391,76 @@ impl FeeDistribution {
     }
 }
 
+// ── FeeRoutingConfig ──────────────────────────────────────────────────────────
+
+/// Governance-configurable share of fees burned vs. sent to treasury
+/// (basis points). The remainder goes to validator rewards.
+///
+/// Only constructible through `new` (or deserialization, which runs the same
+/// check), so every instance satisfies burn + treasury <= 10,000 bps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "RawFeeRoutingConfig")]
+pub struct FeeRoutingConfig {
+    burn_bps:     u32,
+    treasury_bps: u32,
+}
+
+/// Unvalidated wire form of `FeeRoutingConfig`.
+#[derive(Deserialize)]
+struct RawFeeRoutingConfig {
+    burn_bps:     u32,
+    treasury_bps: u32,
+}
+
+impl TryFrom<RawFeeRoutingConfig> for FeeRoutingConfig {
+    type Error = DistributionError;
+
+    fn try_from(raw: RawFeeRoutingConfig) -> Result<Self, Self::Error> {
+        Self::new(raw.burn_bps, raw.treasury_bps)
+    }
+}
+
+impl FeeRoutingConfig {
+    /// Build a validated config; burn + treasury may not exceed 10,000 bps.
+    pub fn new(burn_bps: u32, treasury_bps: u32) -> Result<Self, DistributionError> {
+        let cfg = Self { burn_bps, treasury_bps };
+        cfg.validate()?;
+        Ok(cfg)
+    }
+
+    pub fn validate(&self) -> Result<(), DistributionError> {
+        if self.burn_bps as u64 + self.treasury_bps as u64 > 10_000 {
+            return Err(DistributionError::FeeRoutingInvalid {
+                burn_bps:     self.burn_bps,
+                treasury_bps: self.treasury_bps,
+            });
+        }
+        Ok(())
+    }
+
+    /// Share of fees burned (bps).
+    pub fn burn_bps(&self) -> u32 {
+        self.burn_bps
+    }
+
+    /// Share of fees sent to treasury (bps).
+    pub fn treasury_bps(&self) -> u32 {
+        self.treasury_bps
+    }
+
+    /// Validator share implied by this config.
+    pub fn validator_bps(&self) -> u32 {
+        10_000u32.saturating_sub(self.burn_bps + self.treasury_bps)
+    }
+}
+
+impl Default for FeeRoutingConfig {
+    /// The canonical 25% burn / 25% treasury split.
+    fn default() -> Self {
+        Self { burn_bps: FEE_BURN_BPS, treasury_bps: FEE_TREASURY_BPS }
+    }
+}
+
 // ── GenesisAllocation ─────────────────────────────────────────────────────────
 
 /// The genesis-state allocation record: all six buckets with their
@@ -569,7 +645,7 @@ impl GenesisAllocation {
         DistributionSnapshot {
             buckets: AllocationBucket::all().iter().map(|b| BucketSnapshot {
                 bucket:           *b,
-                name:             b.name(),
+                name:             b.name().to_string(),
                 ceiling_micro:    b.ceiling(),
                 drawn_micro:      *self.drawn.get(b).unwrap_or(&0),
                 remaining_micro:  self.remaining(*b),
@@ -594,7 +670,7 @@ impl GenesisAllocation {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BucketSnapshot {
     pub bucket:                AllocationBucket,
-    pub name:                  &'static str,
+    pub name:                  String,
     pub ceiling_micro:         u128,
     pub drawn_micro:           u128,
     pub remaining_micro:       u128,
@@ -656,6 +732,7 @@ pub enum DistributionError {
     GovernanceApprovalRequired(AllocationBucket),
     MaxSupplyBreached        { total_drawn: u128, max_supply: u128 },
     FeeConfigInvalid,
+    FeeRoutingInvalid        { burn_bps: u32, treasury_bps: u32 },
 }
 
 impl std::fmt::Display for DistributionError {
@@ -673,6 +750,8 @@ impl std::fmt::Display for DistributionError {
                 write!(f, "max supply breached: drawn={} > max={}", total_drawn, max_supply),
             Self::FeeConfigInvalid =>
                 write!(f, "fee distribution bps do not sum to 10,000"),
+            Self::FeeRoutingInvalid { burn_bps, treasury_bps } =>
+                write!(f, "fee routing exceeds 100%: burn={} + treasury={} bps > 10,000", burn_bps, treasury_bps),
         }
     }
 }
@@ -959,6 +1038,24 @@ mod tests {
         assert_eq!(dist.total_fee, 3);
     }
 
+    #[test]
+    fn fee_routing_rejects_shares_above_100_percent() {
+        let err = FeeRoutingConfig::new(7_000, 4_000).unwrap_err();
+        assert_eq!(err, DistributionError::FeeRoutingInvalid { burn_bps: 7_000, treasury_bps: 4_000 });
+        assert!(FeeRoutingConfig::new(10_000, 0).is_ok());
+        assert!(FeeRoutingConfig::new(u32::MAX, u32::MAX).is_err());
+
+        // The wire form cannot smuggle in an invalid split
+        let invalid = bincode::serialize(&(7_000u32, 4_000u32)).unwrap();
+        assert!(bincode::deserialize::<FeeRoutingConfig>(&invalid).is_err());
+        let valid = bincode::serialize(&FeeRoutingConfig::new(1_000, 6_000).unwrap()).unwrap();
+        assert_eq!(
+            bincode::deserialize::<FeeRoutingConfig>(&valid).unwrap(),
+            FeeRoutingConfig::new(1_000, 6_000).unwrap()
+        );
+        assert_eq!(FeeRoutingConfig::default().validator_bps(), FEE_VALIDATOR_REWARD_BPS);
+    }
+
     #[test]
     fn fee_distribution_handles_zero() {
         let dist = FeeDistribution::compute(0);
@@ -1016,7 +1113,7 @@ mod tests {
     fn snapshot_bucket_names_are_correct() {
         let ga = genesis();
         let snap = ga.snapshot();
-        let names: Vec<&str> = snap.buckets.iter().map(|b| b.name).collect();
+        let names: Vec<&str> = snap.buckets.iter().map(|b| b.name.as_str()).collect();
         assert!(names.contains(&"Validator Rewards"));
         assert!(names.contains(&"Ecosystem Development Fund"));
         assert!(names.contains(&"Community Incentives"));
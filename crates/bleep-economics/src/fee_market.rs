@@ -52,6 +52,47 @@ impl ResourceUsage {
     }
 }
 
+/// Independently priced resource dimension
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, PartialOrd, Ord)]
+pub enum ResourceDimension {
+    /// Execution (compute units plus memory, per KiB)
+    Compute,
+    /// Storage access
+    Storage,
+    /// Network bandwidth (per KiB, rounded up)
+    Bandwidth,
+}
+
+impl ResourceDimension {
+    pub const ALL: [ResourceDimension; 3] = [
+        ResourceDimension::Compute,
+        ResourceDimension::Storage,
+        ResourceDimension::Bandwidth,
+    ];
+}
+
+impl ResourceUsage {
+    /// Units consumed along one dimension
+    pub fn units(&self, dimension: ResourceDimension) -> u64 {
+        match dimension {
+            ResourceDimension::Compute => {
+                self.compute_units as u64 + self.memory_bytes as u64 / 1024
+            }
+            ResourceDimension::Storage => self.storage_units as u64,
+            ResourceDimension::Bandwidth => (self.bandwidth_bytes as u64).div_ceil(1024),
+        }
+    }
+}
+
+/// Per-dimension fee breakdown
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResourceFee {
+    /// Fee charged for each dimension (units * that dimension's base fee)
+    pub per_dimension: BTreeMap<ResourceDimension, u128>,
+    /// Sum of all dimensions
+    pub total: u128,
+}
+
 /// Shard-specific congestion metrics
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ShardCongestion {
@@ -90,6 +131,41 @@ impl ShardCongestion {
     }
 }
 
+/// How quickly a sender wants their transaction included
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, PartialOrd, Ord)]
+pub enum Urgency {
+    /// No priority; pay the base fee only
+    Low,
+    /// Typical inclusion within a few blocks
+    Medium,
+    /// Next-block inclusion
+    High,
+}
+
+impl Urgency {
+    /// Priority tip as a share of the recommended base fee (basis points)
+    pub fn tip_bps(&self) -> u128 {
+        match self {
+            Urgency::Low => 0,
+            Urgency::Medium => 1000, // 10%
+            Urgency::High => 2500,   // 25%
+        }
+    }
+}
+
+/// Fee recommendation for wallets
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecommendedFee {
+    /// Shard the recommendation applies to
+    pub shard_id: u32,
+    /// Per-unit base fee adjusted for the shard's congestion
+    pub base_fee: u128,
+    /// Suggested priority tip on top of the base fee
+    pub priority_tip: u128,
+    /// Suggested maximum per-unit fee (base fee + tip)
+    pub max_fee: u128,
+}
+
 /// Base fee calculation parameters
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BaseFeeParams {
@@ -143,6 +219,9 @@ pub struct FeeMarket {
     pub fee_history: BTreeMap<u64, u128>,
     /// Transaction count per shard per epoch
     pub tx_counts: BTreeMap<(u64, u32), u32>,
+    /// Independent base fee per resource dimension
+    #[serde(default = "FeeMarket::genesis_dimension_base_fees")]
+    pub dimension_base_fees: BTreeMap<ResourceDimension, BaseFeeParams>,
 }
 
 impl FeeMarket {
@@ -161,9 +240,18 @@ impl FeeMarket {
             shard_congestion: BTreeMap::new(),
             fee_history: BTreeMap::new(),
             tx_counts: BTreeMap::new(),
+            dimension_base_fees: Self::genesis_dimension_base_fees(),
         }
     }
 
+    /// Every dimension at the genesis base fee
+    fn genesis_dimension_base_fees() -> BTreeMap<ResourceDimension, BaseFeeParams> {
+        ResourceDimension::ALL
+            .iter()
+            .map(|d| (*d, BaseFeeParams::new()))
+            .collect()
+    }
+
     /// Calculate fee for a transaction
     pub fn calculate_fee(
         &self,
@@ -177,8 +265,8 @@ impl FeeMarket {
 
         congestion.validate()?;
 
-        // Base fee per unit
-        let base_fee = self.base_fee_params.current_base_fee;
+        // Each dimension's units priced at that dimension's base fee
+        let resource_fee = self.calculate_resource_fee(&resource_usage).total;
 
         // Type multiplier
         let type_cost = self.resource_costs
@@ -188,14 +276,40 @@ impl FeeMarket {
         // Congestion multiplier
         let cong_mult = congestion.congestion_multiplier();
 
-        // Final fee = base_fee * type_cost * resource_units * congestion_multiplier / 1000
-        let fee = (base_fee as u64)
-            .saturating_mul(*type_cost)
-            .saturating_mul(resource_usage.total_units() as u64)
-            .saturating_mul(cong_mult)
+        // Final fee = resource_fee * type_cost * congestion_multiplier / 1_000_000
+        let fee = resource_fee
+            .saturating_mul(*type_cost as u128)
+            .saturating_mul(cong_mult as u128)
             / 1_000_000; // Normalize multipliers
 
-        Ok(fee as u128)
+        Ok(fee)
+    }
+
+    /// Recommend a per-unit fee for `shard_id` at the given urgency
+    ///
+    /// The base fee is the current base fee scaled by the shard's congestion
+    /// multiplier, bounded to [MIN_BASE_FEE, MAX_BASE_FEE]; the tip is a
+    /// fixed share of it per urgency level. A shard with no recorded
+    /// congestion is treated as idle. Depends only on the current base fee
+    /// and the latest congestion snapshot, so it is deterministic.
+    pub fn recommend_fee(&self, shard_id: u32, urgency: Urgency) -> RecommendedFee {
+        let cong_mult = self.shard_congestion
+            .get(&shard_id)
+            .map(|c| c.congestion_multiplier())
+            .unwrap_or(1000) as u128;
+
+        let base_fee = self.base_fee_params.current_base_fee
+            .saturating_mul(cong_mult)
+            / 1000;
+        let base_fee = base_fee.clamp(MIN_BASE_FEE, MAX_BASE_FEE);
+        let priority_tip = base_fee * urgency.tip_bps() / 10_000;
+
+        RecommendedFee {
+            shard_id,
+            base_fee,
+            priority_tip,
+            max_fee: base_fee + priority_tip,
+        }
     }
 
     /// Update base fee based on block utilization
@@ -210,37 +324,88 @@ impl FeeMarket {
             ));
         }
 
-        let current = self.base_fee_params.current_base_fee;
-        let target = self.base_fee_params.target_utilization_bps as u64;
-        let actual = average_utilization_bps as u64;
+        let bounded_fee = Self::next_base_fee(&self.base_fee_params, average_utilization_bps);
+
+        self.base_fee_params.current_base_fee = bounded_fee;
+        self.fee_history.insert(epoch, bounded_fee);
+
+        Ok(bounded_fee)
+    }
+
+    /// Update one dimension's base fee from that dimension's utilization
+    ///
+    /// Uses the same adjustment rule as `update_base_fee`; other dimensions
+    /// are unaffected.
+    pub fn update_dimension_base_fee(
+        &mut self,
+        dimension: ResourceDimension,
+        utilization_bps: u16,
+    ) -> Result<u128, FeeMarketError> {
+        if utilization_bps > 10000 {
+            return Err(FeeMarketError::InvalidCongestion(
+                "utilization cannot exceed 10000".to_string(),
+            ));
+        }
+
+        let params = self.dimension_base_fees
+            .entry(dimension)
+            .or_insert_with(BaseFeeParams::new);
+        let bounded_fee = Self::next_base_fee(params, utilization_bps);
+        params.current_base_fee = bounded_fee;
+
+        Ok(bounded_fee)
+    }
+
+    /// Price each resource dimension with its own base fee
+    ///
+    /// fee(d) = units(d) * base_fee(d); total = sum over dimensions.
+    pub fn calculate_resource_fee(&self, usage: &ResourceUsage) -> ResourceFee {
+        let per_dimension: BTreeMap<ResourceDimension, u128> = ResourceDimension::ALL
+            .iter()
+            .map(|d| {
+                let base_fee = self.dimension_base_fees
+                    .get(d)
+                    .map(|p| p.current_base_fee)
+                    .unwrap_or(MIN_BASE_FEE);
+                (*d, (usage.units(*d) as u128).saturating_mul(base_fee))
+            })
+            .collect();
+        let total = per_dimension
+            .values()
+            .fold(0u128, |acc, fee| acc.saturating_add(*fee));
+
+        ResourceFee { per_dimension, total }
+    }
+
+    /// Next base fee after a block at `utilization_bps`, bounded to
+    /// [MIN_BASE_FEE, MAX_BASE_FEE]
+    fn next_base_fee(params: &BaseFeeParams, utilization_bps: u16) -> u128 {
+        let current = params.current_base_fee;
+        let target = params.target_utilization_bps as u64;
+        let actual = utilization_bps as u64;
 
         // Calculate direction: if above target, increase; below, decrease
         let new_fee = if actual > target {
             let excess = actual - target;
             let increase_bps = std::cmp::min(
-                self.base_fee_params.max_increase_bps as u64 * excess / target,
-                self.base_fee_params.max_increase_bps as u64,
+                params.max_increase_bps as u64 * excess / target,
+                params.max_increase_bps as u64,
             );
             (current as u64 * (10000 + increase_bps) / 10000) as u128
         } else {
             let deficit = target - actual;
             let decrease_bps = std::cmp::min(
-                self.base_fee_params.max_decrease_bps as u64 * deficit / target,
-                self.base_fee_params.max_decrease_bps as u64,
+                params.max_decrease_bps as u64 * deficit / target,
+                params.max_decrease_bps as u64,
             );
             (current as u64 * (10000 - decrease_bps) / 10000) as u128
         };
 
         // Apply bounds
-        let bounded_fee = std::cmp::max(
+        std::cmp::max(
             MIN_BASE_FEE,
             std::cmp::min(new_fee, MAX_BASE_FEE),
-        );
-
-        self.base_fee_params.current_base_fee = bounded_fee;
-        self.fee_history.insert(epoch, bounded_fee);
-
-        Ok(bounded_fee)
+        )
     }
 
     /// Record shard congestion
@@ -312,6 +477,7 @@ pub enum FeeMarketError {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use tracing::error;
 
     #[test]
     fn test_fee_market_genesis() {
@@ -391,4 +557,101 @@ mod tests {
             }
         });
     }
+
+    #[test]
+    fn test_recommend_fee_tracks_congestion_and_urgency() {
+        let mut market = FeeMarket::genesis();
+        for (shard_id, utilization_bps) in [(0, 1000), (1, 7500), (2, 9500)] {
+            market.record_shard_congestion(ShardCongestion {
+                shard_id,
+                utilization_bps,
+                pending_txns: 0,
+                avg_tx_size_bytes: 256,
+            }).unwrap();
+        }
+
+        // Idle shard at genesis base fee recommends the floor
+        let idle = market.recommend_fee(0, Urgency::Low);
+        assert_eq!(idle.base_fee, MIN_BASE_FEE);
+        assert_eq!(idle.priority_tip, 0);
+        assert_eq!(idle.max_fee, MIN_BASE_FEE);
+        assert_eq!(market.recommend_fee(42, Urgency::Low), RecommendedFee { shard_id: 42, ..idle });
+
+        // Higher congestion raises the base fee
+        let busy = market.recommend_fee(1, Urgency::Low);
+        let congested = market.recommend_fee(2, Urgency::Low);
+        assert!(busy.base_fee > idle.base_fee);
+        assert!(congested.base_fee > busy.base_fee);
+
+        // Higher urgency raises the tip, not the base fee
+        let medium = market.recommend_fee(1, Urgency::Medium);
+        let high = market.recommend_fee(1, Urgency::High);
+        assert_eq!(medium.base_fee, busy.base_fee);
+        assert!(medium.priority_tip > busy.priority_tip);
+        assert!(high.priority_tip > medium.priority_tip);
+        assert_eq!(high.max_fee, high.base_fee + high.priority_tip);
+
+        // Deterministic for the same snapshot
+        assert_eq!(market.recommend_fee(2, Urgency::High), market.recommend_fee(2, Urgency::High));
+    }
+
+    #[test]
+    fn test_dimension_fees_adjust_independently() {
+        let mut market = FeeMarket::genesis();
+        let usage = ResourceUsage {
+            compute_units: 100,
+            storage_units: 40,
+            bandwidth_bytes: 2048,
+            memory_bytes: 2048,
+        };
+        let before = market.calculate_resource_fee(&usage);
+        assert_eq!(before.per_dimension[&ResourceDimension::Compute], 102 * MIN_BASE_FEE);
+        assert_eq!(before.per_dimension[&ResourceDimension::Storage], 40 * MIN_BASE_FEE);
+        assert_eq!(before.per_dimension[&ResourceDimension::Bandwidth], 2 * MIN_BASE_FEE);
+        assert_eq!(before.total, before.per_dimension.values().sum::<u128>());
+
+        // Storage congested, compute and bandwidth idle at target
+        for _ in 0..5 {
+            market.update_dimension_base_fee(ResourceDimension::Storage, 9500).unwrap();
+        }
+        let after = market.calculate_resource_fee(&usage);
+        assert!(after.per_dimension[&ResourceDimension::Storage]
+            > before.per_dimension[&ResourceDimension::Storage]);
+        assert_eq!(after.per_dimension[&ResourceDimension::Compute],
+            before.per_dimension[&ResourceDimension::Compute]);
+        assert_eq!(after.per_dimension[&ResourceDimension::Bandwidth],
+            before.per_dimension[&ResourceDimension::Bandwidth]);
+        assert_eq!(after.total, after.per_dimension.values().sum::<u128>());
+
+        // The global base fee is untouched
+        assert_eq!(market.base_fee_params.current_base_fee, MIN_BASE_FEE);
+        assert!(market.update_dimension_base_fee(ResourceDimension::Compute, 10001).is_err());
+    }
+
+    #[test]
+    fn test_transaction_fee_uses_dimension_base_fees() {
+        let mut market = FeeMarket::genesis();
+        market.record_shard_congestion(ShardCongestion {
+            shard_id: 0,
+            utilization_bps: 1000,
+            pending_txns: 0,
+            avg_tx_size_bytes: 256,
+        }).unwrap();
+        let storage_heavy = ResourceUsage {
+            compute_units: 10,
+            storage_units: 1_000,
+            bandwidth_bytes: 0,
+            memory_bytes: 0,
+        };
+        let compute_only = ResourceUsage { storage_units: 0, ..storage_heavy };
+
+        let storage_before = market.calculate_fee(TransactionType::Transfer, storage_heavy, 0).unwrap();
+        let compute_before = market.calculate_fee(TransactionType::Transfer, compute_only, 0).unwrap();
+
+        for _ in 0..5 {
+            market.update_dimension_base_fee(ResourceDimension::Storage, 9500).unwrap();
+        }
+        assert!(market.calculate_fee(TransactionType::Transfer, storage_heavy, 0).unwrap() > storage_before);
+        assert_eq!(market.calculate_fee(TransactionType::Transfer, compute_only, 0).unwrap(), compute_before);
+    }
 }
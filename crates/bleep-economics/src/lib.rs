@@ -16,6 +16,7 @@ pub mod fee_market;
 pub mod validator_incentives;
 pub mod oracle_bridge;
 pub mod game_theory;
+pub mod aggregation;
 pub mod runtime;
 
 // Re-export key types for easy access
@@ -27,7 +28,7 @@ pub use tokenomics::{
 pub use distribution::{
     AllocationBucket, VestingPolicy, ValidatorEmissionSchedule, LinearVestingSchedule,
     GenesisAllocation, FeeDistribution, DistributionSnapshot, BucketSnapshot,
-    SupplyDynamics, DistributionError,
+    SupplyDynamics, DistributionError, FeeRoutingConfig,
     // Distribution constants
     MAX_SUPPLY_MICRO, ALLOCATION_TOTAL,
     ALLOC_VALIDATOR_REWARDS, ALLOC_ECOSYSTEM_FUND, ALLOC_COMMUNITY_INCENTIVES,
@@ -39,28 +40,71 @@ pub use distribution::{
 
 pub use fee_market::{
     FeeMarket, BaseFeeParams, ShardCongestion, TransactionType, ResourceUsage,
-    FeeMarketError,
+    FeeMarketError, Urgency, RecommendedFee, ResourceDimension, ResourceFee,
 };
 
 pub use validator_incentives::{
-    ValidatorIncentivesEngine, ValidatorAccount, RewardRecord, SlashingEvidence,
+    ValidatorIncentivesEngine, ValidatorAccount, ValidatorMetrics, RewardRecord, SlashingEvidence,
     SlashingViolationType, ValidatorStatus, RewardType, ValidatorError,
+    BondingEntry, UnbondingEntry, ACTIVATION_DELAY_EPOCHS, UNBONDING_PERIOD_EPOCHS,
+    EpochSlashing, DeferredSlash, DEFAULT_MAX_SLASHING_PER_EPOCH_BPS, RewardPolicy,
 };
 
 pub use oracle_bridge::{
     OracleBridgeEngine, PriceUpdate, AggregatedPrice, OracleOperator,
-    BridgeConfig, BridgeTransaction, OracleError, OracleSource,
+    BridgeConfig, BridgeTransaction, OracleError, OracleSource, ReportOutcome,
+    PriceDeviationEvidence,
 };
 
 pub use game_theory::{
     SafetyVerifier, SafetyAnalysis, AttackType, SafetyError,
 };
 
+pub use aggregation::{weighted_median, weighted_median_bounds};
+
 /// Economic system integrator (combines all modules)
 pub mod integration {
     use crate::*;
     use serde::{Deserialize, Serialize};
 
+    /// Everything observed during one epoch that settlement consumes.
+    /// Input order does not matter; settlement sorts it.
+    #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+    pub struct EpochBlockData {
+        /// Total fee revenue collected in the epoch
+        pub fee_revenue: u128,
+        /// Per-validator metrics for reward computation
+        pub validator_metrics: Vec<validator_incentives::ValidatorMetrics>,
+        /// Slashing evidence reported in the epoch (disputed evidence is deferred)
+        pub slashing_evidence: Vec<validator_incentives::SlashingEvidence>,
+    }
+
+    /// Summary of one epoch-boundary settlement
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    pub struct EpochSettlement {
+        /// Settled epoch
+        pub epoch: u64,
+        /// Validator rewards, ordered by validator ID
+        pub reward_records: Vec<validator_incentives::RewardRecord>,
+        /// New supply minted for rewards
+        pub total_emitted: u128,
+        /// Fee split under the configured routing
+        pub fees: distribution::FeeDistribution,
+        /// Slashes applied, in proof-hash order
+        pub slashes_applied: usize,
+        /// Disputed slashes left for a later epoch
+        pub slashes_deferred: usize,
+        /// Stake actually removed by slashing this epoch (capped; the rest
+        /// stays deferred)
+        pub total_slashed: u128,
+        /// Fee burn plus slashed stake burned
+        pub total_burned: u128,
+        /// Circulating supply after settlement
+        pub circulating_supply: u128,
+        /// Supply state hash committed for the epoch
+        pub supply_state_hash: Vec<u8>,
+    }
+
     #[derive(Debug, Clone, Serialize, Deserialize)]
     pub struct BleepEconomics {
         /// Tokenomics engine
@@ -71,6 +115,12 @@ pub mod integration {
         pub validators: validator_incentives::ValidatorIncentivesEngine,
         /// Oracle & bridge integration
         pub oracle_bridge: oracle_bridge::OracleBridgeEngine,
+        /// Governance-set fee burn / treasury split
+        #[serde(default)]
+        pub fee_routing: distribution::FeeRoutingConfig,
+        /// Most recent epoch passed through `settle_epoch`
+        #[serde(default)]
+        pub last_settled_epoch: Option<u64>,
     }
 
     impl BleepEconomics {
@@ -81,9 +131,18 @@ pub mod integration {
                 fee_market: fee_market::FeeMarket::genesis(),
                 validators: validator_incentives::ValidatorIncentivesEngine::genesis(),
                 oracle_bridge: oracle_bridge::OracleBridgeEngine::genesis(),
+                fee_routing: distribution::FeeRoutingConfig::default(),
+                last_settled_epoch: None,
             }
         }
 
+        /// Apply a governance-approved fee routing config (validated first).
+        pub fn set_fee_routing(&mut self, routing: distribution::FeeRoutingConfig) -> Result<(), EconomicError> {
+            routing.validate()?;
+            self.fee_routing = routing;
+            Ok(())
+        }
+
         /// Verify all economic invariants for an epoch
         pub fn verify_epoch_invariants(&self) -> Result<(), EconomicError> {
             // Verify tokenomics
@@ -104,9 +163,127 @@ pub mod integration {
 
             // Verify fee market is valid
             self.fee_market.base_fee_params.validate()?;
+            self.fee_routing.validate()?;
 
             Ok(())
         }
+
+        /// Settle an epoch boundary in a fixed order:
+        /// rewards + emission, fees + burn, slashes, bonding/unbonding queues,
+        /// then supply finalization.
+        ///
+        /// Settlement runs on a scratch copy that replaces `self` only on
+        /// success, so a rejected settlement leaves the economics untouched.
+        /// Epochs settle strictly in increasing order and at most once.
+        pub fn settle_epoch(&mut self, epoch: u64, block_data: EpochBlockData) -> Result<EpochSettlement, EconomicError> {
+            if self.last_settled_epoch.is_some_and(|last| epoch <= last) {
+                return Err(EconomicError::EpochAlreadySettled(epoch));
+            }
+
+            let mut next = self.clone();
+            let settlement = next.apply_settlement(epoch, block_data)?;
+            next.last_settled_epoch = Some(epoch);
+            *self = next;
+            Ok(settlement)
+        }
+
+        fn apply_settlement(&mut self, epoch: u64, block_data: EpochBlockData) -> Result<EpochSettlement, EconomicError> {
+            let EpochBlockData { fee_revenue, validator_metrics, mut slashing_evidence } = block_data;
+
+            let referenced = validator_metrics.iter().map(|m| &m.validator_id)
+                .chain(slashing_evidence.iter().map(|e| &e.validator_id));
+            for validator_id in referenced {
+                if !self.validators.validators.contains_key(validator_id) {
+                    return Err(validator_incentives::ValidatorError::ValidatorNotFound.into());
+                }
+            }
+
+            // 1. Rewards (metrics are keyed by validator ID) and their emission
+            for metrics in validator_metrics {
+                self.validators.record_metrics(metrics.validator_id.clone(), metrics)?;
+            }
+            let reward_records = self.validators.distribute_epoch_rewards(epoch)?;
+            self.validators.current_metrics.clear();
+
+            let total_emitted: u128 = reward_records.iter().map(|r| r.total_reward).sum();
+            if total_emitted > 0 {
+                self.tokenomics.record_emission(epoch, tokenomics::EmissionType::BlockProposal, total_emitted)?;
+            }
+
+            // 2. Fees
+            let fees = distribution::FeeDistribution::compute_with(fee_revenue, &self.fee_routing);
+            if fees.burned > 0 {
+                self.tokenomics.record_burn(epoch, tokenomics::BurnType::TransactionFee, fees.burned)?;
+            }
+
+            // 3. Slashes under the per-epoch cap: first the remainder carried
+            // from earlier epochs, then new evidence in proof-hash order. Each
+            // proof hash applies once, whether repeated in this batch or
+            // already applied in an earlier epoch.
+            let mut total_slashed = self.validators.process_deferred_slashing(epoch)?;
+            slashing_evidence.sort_by(|a, b| a.proof_hash.cmp(&b.proof_hash));
+            slashing_evidence.dedup_by(|a, b| a.proof_hash == b.proof_hash);
+            let mut slashes_applied = 0;
+            let mut slashes_deferred = 0;
+            for evidence in slashing_evidence {
+                if evidence.disputed {
+                    slashes_deferred += 1;
+                    continue;
+                }
+                if self.validators.slashing_evidence.contains_key(&evidence.proof_hash) {
+                    continue;
+                }
+                let slashed = self.validators.apply_slashing_capped(evidence, epoch)?;
+                total_slashed = total_slashed.saturating_add(slashed);
+                slashes_applied += 1;
+            }
+            // Slashed stake always leaves supply, however small
+            self.tokenomics.record_slashed_stake_burn(epoch, total_slashed);
+
+            // 4. Stake queues, after slashing so unbonding stake maturing at
+            // this boundary is still slashable for this epoch's evidence
+            self.validators.process_stake_queues(epoch);
+
+            // 5. Finalize supply
+            let supply_state_hash = self.tokenomics.finalize_epoch(epoch)?;
+
+            Ok(EpochSettlement {
+                epoch,
+                reward_records,
+                total_emitted,
+                total_burned: fees.burned.saturating_add(total_slashed),
+                fees,
+                slashes_applied,
+                slashes_deferred,
+                total_slashed,
+                circulating_supply: self.tokenomics.supply_state.circulating_supply,
+                supply_state_hash,
+            })
+        }
+
+        /// Slash an oracle operator for a deviating report and burn the
+        /// slashed stake in the current epoch.
+        pub fn slash_oracle_operator(
+            &mut self,
+            operator_id: &[u8],
+            evidence: oracle_bridge::PriceDeviationEvidence,
+        ) -> Result<u128, EconomicError> {
+            let slashed = self.oracle_bridge.slash_operator(operator_id, evidence)?;
+            let epoch = self.tokenomics.supply_state.epoch;
+            self.tokenomics.record_slashed_stake_burn(epoch, slashed);
+            Ok(slashed)
+        }
+
+        /// Settle one block's fee revenue: split it with the configured
+        /// `FeeRoutingConfig` and burn the burn share in the current epoch.
+        pub fn process_block_fees(&mut self, total_fees: u128) -> Result<distribution::FeeDistribution, EconomicError> {
+            let dist = distribution::FeeDistribution::compute_with(total_fees, &self.fee_routing);
+            if dist.burned > 0 {
+                let epoch = self.tokenomics.supply_state.epoch;
+                self.tokenomics.record_burn(epoch, tokenomics::BurnType::TransactionFee, dist.burned)?;
+            }
+            Ok(dist)
+        }
     }
 
     #[derive(Debug, thiserror::Error, Clone, PartialEq)]
@@ -119,10 +296,14 @@ pub mod integration {
         Validator(#[from] validator_incentives::ValidatorError),
         #[error("Oracle error: {0}")]
         Oracle(#[from] oracle_bridge::OracleError),
+        #[error("Distribution error: {0}")]
+        Distribution(#[from] distribution::DistributionError),
         #[error("Supply invariant violated")]
         SupplyInvariantViolation,
         #[error("Circulation supply mismatch")]
         CirculationMismatch,
+        #[error("Epoch {0} already settled")]
+        EpochAlreadySettled(u64),
     }
 }
 
@@ -142,6 +323,285 @@ mod tests {
         let econ = integration::BleepEconomics::genesis();
         assert!(econ.verify_epoch_invariants().is_ok());
     }
+
+    #[test]
+    fn test_process_block_fees_burns_burn_share() {
+        let mut econ = integration::BleepEconomics::genesis();
+        econ.tokenomics.supply_state.total_minted = 1_000_000;
+        econ.tokenomics.supply_state.circulating_supply = 1_000_000;
+
+        let dist = econ.process_block_fees(10_000).unwrap();
+        assert!(dist.is_consistent());
+        assert_eq!(dist.burned, 2_500);
+        assert_eq!(econ.tokenomics.supply_state.total_burned, 2_500);
+        assert_eq!(econ.tokenomics.supply_state.circulating_supply, 997_500);
+    }
+
+    fn funded_economics() -> integration::BleepEconomics {
+        let mut econ = integration::BleepEconomics::genesis();
+        econ.tokenomics.supply_state.total_minted = 1_000_000;
+        econ.tokenomics.supply_state.circulating_supply = 1_000_000;
+        econ
+    }
+
+    #[test]
+    fn test_oracle_slash_is_burned() {
+        let mut econ = funded_economics();
+        for (id, price) in [(1u8, 1800u128), (2, 1805), (3, 2500)] {
+            econ.oracle_bridge.register_operator(vec![id], 1000).unwrap();
+            econ.oracle_bridge.submit_price_update(PriceUpdate {
+                source: OracleSource::Custom(vec![id]),
+                asset: "ETH/USD".to_string(),
+                price,
+                timestamp: 1000,
+                confidence_bps: 100,
+                operator_id: vec![id],
+                signature: vec![],
+            }).unwrap();
+        }
+        let aggregate = econ.oracle_bridge.aggregate_prices("ETH/USD", 1000, 60).unwrap();
+        let report = econ.oracle_bridge.price_updates["ETH/USD"][2].clone();
+
+        let slashed = econ.slash_oracle_operator(&[3], PriceDeviationEvidence { aggregate, report }).unwrap();
+        assert_eq!(slashed, 100);
+        assert_eq!(econ.oracle_bridge.total_slashed, 100);
+        assert_eq!(econ.tokenomics.supply_state.total_burned, 100);
+        assert_eq!(econ.tokenomics.supply_state.circulating_supply, 999_900);
+    }
+
+    #[test]
+    fn test_full_burn_fee_routing() {
+        let mut econ = funded_economics();
+        econ.set_fee_routing(FeeRoutingConfig::new(10_000, 0).unwrap()).unwrap();
+
+        let dist = econ.process_block_fees(10_000).unwrap();
+        assert_eq!((dist.burned, dist.treasury, dist.validator_reward), (10_000, 0, 0));
+        assert_eq!(econ.tokenomics.supply_state.total_burned, 10_000);
+        assert_eq!(econ.tokenomics.supply_state.circulating_supply, 990_000);
+    }
+
+    #[test]
+    fn test_split_fee_routing() {
+        let mut econ = funded_economics();
+        econ.set_fee_routing(FeeRoutingConfig::new(1_000, 6_000).unwrap()).unwrap();
+
+        let dist = econ.process_block_fees(10_000).unwrap();
+        assert!(dist.is_consistent());
+        assert_eq!((dist.burned, dist.treasury, dist.validator_reward), (1_000, 6_000, 3_000));
+        assert_eq!(econ.tokenomics.supply_state.total_burned, 1_000);
+    }
+
+    #[test]
+    fn test_fee_routing_above_100_percent_rejected() {
+        assert!(matches!(
+            FeeRoutingConfig::new(6_000, 5_000),
+            Err(DistributionError::FeeRoutingInvalid { burn_bps: 6_000, treasury_bps: 5_000 })
+        ));
+        assert_eq!(funded_economics().fee_routing, FeeRoutingConfig::default());
+    }
+
+    fn settlement_inputs() -> integration::EpochBlockData {
+        let metrics = |id: u8, blocks: u32| ValidatorMetrics {
+            validator_id: vec![id],
+            blocks_proposed: blocks,
+            attestations_included: blocks * 10,
+            healing_participations: 0,
+            shards_coordinated: 1,
+            double_signs_evidence: 0,
+            state_transition_failures: 0,
+            epoch: 1,
+        };
+        let evidence = |id: u8, proof: u8, disputed: bool| SlashingEvidence {
+            validator_id: vec![id],
+            epoch: 1,
+            violation_type: SlashingViolationType::DoubleSigning,
+            slash_amount: 2_000_000,
+            proof_hash: vec![proof],
+            disputed,
+        };
+        integration::EpochBlockData {
+            fee_revenue: 10_000,
+            validator_metrics: vec![metrics(1, 10), metrics(2, 5), metrics(3, 1)],
+            slashing_evidence: vec![evidence(3, 9, false), evidence(2, 4, true), evidence(3, 1, false)],
+        }
+    }
+
+    fn settlement_economics() -> integration::BleepEconomics {
+        let mut econ = integration::BleepEconomics::genesis();
+        econ.tokenomics.supply_state.total_minted = 10u128.pow(15);
+        econ.tokenomics.supply_state.circulating_supply = 10u128.pow(15);
+        for id in 1..=3u8 {
+            econ.validators.register_validator(vec![id], 100_000_000).unwrap();
+        }
+        econ
+    }
+
+    #[test]
+    fn test_settle_epoch_is_input_order_independent() {
+        let inputs = settlement_inputs();
+        let mut shuffled = inputs.clone();
+        shuffled.validator_metrics.reverse();
+        shuffled.slashing_evidence.rotate_left(1);
+
+        let mut a = settlement_economics();
+        let mut b = settlement_economics();
+        let settled_a = a.settle_epoch(1, inputs).unwrap();
+        let settled_b = b.settle_epoch(1, shuffled).unwrap();
+
+        assert_eq!(settled_a, settled_b);
+        let ids: Vec<_> = settled_a.reward_records.iter().map(|r| r.validator_id.clone()).collect();
+        assert_eq!(ids, vec![vec![1], vec![2], vec![3]]);
+        assert_eq!((settled_a.slashes_applied, settled_a.slashes_deferred), (2, 1));
+        assert_eq!(settled_a.total_slashed, 4_000_000);
+        assert_eq!(settled_a.total_burned, 2_500 + 4_000_000);
+    }
+
+    #[test]
+    fn test_invariants_hold_after_settlement() {
+        let mut econ = settlement_economics();
+        let settlement = econ.settle_epoch(1, settlement_inputs()).unwrap();
+
+        assert!(econ.verify_epoch_invariants().is_ok());
+        assert_eq!(settlement.supply_state_hash, econ.tokenomics.supply_state.state_hash);
+        assert_eq!(econ.tokenomics.get_supply_at_epoch(1).unwrap().circulating_supply, settlement.circulating_supply);
+        // Metrics are consumed: the next epoch pays nothing without new data
+        let next = econ.settle_epoch(2, integration::EpochBlockData::default()).unwrap();
+        assert!(next.reward_records.is_empty());
+        assert!(econ.verify_epoch_invariants().is_ok());
+    }
+
+    #[test]
+    fn test_settlement_rejects_unknown_validator_untouched() {
+        let mut econ = settlement_economics();
+        let mut inputs = settlement_inputs();
+        inputs.slashing_evidence[0].validator_id = vec![42];
+
+        assert!(matches!(
+            econ.settle_epoch(1, inputs),
+            Err(integration::EconomicError::Validator(ValidatorError::ValidatorNotFound))
+        ));
+        assert!(econ.validators.current_metrics.is_empty());
+        assert_eq!(econ.tokenomics.supply_state.total_burned, 0);
+    }
+
+    #[test]
+    fn test_failed_settlement_rolls_back_earlier_steps() {
+        let mut econ = settlement_economics();
+        // Rewards, fees and slashes apply, then supply finalization fails
+        econ.tokenomics.supply_state.circulating_supply += 1;
+        let supply_before = econ.tokenomics.supply_state.clone();
+
+        assert!(econ.settle_epoch(1, settlement_inputs()).is_err());
+        let supply = &econ.tokenomics.supply_state;
+        assert_eq!(
+            (supply.total_minted, supply.total_burned, supply.circulating_supply),
+            (supply_before.total_minted, supply_before.total_burned, supply_before.circulating_supply)
+        );
+        assert!(econ.validators.slashing_evidence.is_empty());
+        assert!(econ.validators.current_metrics.is_empty());
+        for id in 1..=3u8 {
+            assert_eq!(econ.validators.validators[&vec![id]].effective_stake(), 100_000_000);
+        }
+        assert_eq!(econ.last_settled_epoch, None);
+    }
+
+    #[test]
+    fn test_epoch_cannot_be_settled_twice() {
+        let mut econ = settlement_economics();
+        econ.settle_epoch(1, settlement_inputs()).unwrap();
+        let burned = econ.tokenomics.supply_state.total_burned;
+
+        for epoch in [1, 0] {
+            assert_eq!(
+                econ.settle_epoch(epoch, settlement_inputs()),
+                Err(integration::EconomicError::EpochAlreadySettled(epoch))
+            );
+        }
+        assert_eq!(econ.tokenomics.supply_state.total_burned, burned);
+        assert_eq!(econ.last_settled_epoch, Some(1));
+    }
+
+    #[test]
+    fn test_settlement_slashes_capped_deduped_and_carried() {
+        let mut econ = settlement_economics();
+        let mut inputs = settlement_inputs();
+        inputs.fee_revenue = 0;
+        // Cap is 5% of 300M active stake = 15M; ask for 20M, twice over
+        inputs.slashing_evidence.retain(|e| e.proof_hash == vec![1]);
+        inputs.slashing_evidence[0].slash_amount = 20_000_000;
+        inputs.slashing_evidence.push(inputs.slashing_evidence[0].clone());
+
+        let first = econ.settle_epoch(1, inputs.clone()).unwrap();
+        assert_eq!(first.slashes_applied, 1);
+        assert_eq!(first.total_slashed, 15_000_000);
+        assert_eq!(first.total_burned, 15_000_000);
+        assert_eq!(econ.validators.deferred_slashing[0].amount, 5_000_000);
+
+        // The carried remainder lands next epoch; resubmitted evidence does not
+        let second = econ.settle_epoch(2, inputs).unwrap();
+        assert_eq!(second.slashes_applied, 0);
+        assert_eq!(second.total_slashed, 5_000_000);
+        assert!(econ.validators.deferred_slashing.is_empty());
+        assert_eq!(econ.validators.validators[&vec![3]].total_slashed, 20_000_000);
+        assert_eq!(econ.tokenomics.supply_state.total_burned, 20_000_000);
+        assert!(econ.verify_epoch_invariants().is_ok());
+    }
+
+    #[test]
+    fn test_slash_of_unbonding_stake_is_burned() {
+        let mut econ = settlement_economics();
+        econ.validators.unbond(vec![3], 99_000_000, 0).unwrap();
+        let mut inputs = settlement_inputs();
+        inputs.fee_revenue = 0;
+
+        // Validator 3 has 1M bonded; the rest of its two 2M slashes falls
+        // on its unbonding stake
+        let settlement = econ.settle_epoch(1, inputs).unwrap();
+        assert_eq!(settlement.total_slashed, 4_000_000);
+        assert_eq!(settlement.total_burned, 4_000_000);
+        assert_eq!(econ.tokenomics.supply_state.total_burned, 4_000_000);
+
+        let validator = &econ.validators.validators[&vec![3]];
+        assert_eq!(validator.total_slashed, 4_000_000);
+        assert_eq!(validator.unbonding_slashed, 3_000_000);
+        assert_eq!(econ.validators.unbonding_stake(&[3]), 96_000_000);
+        assert!(econ.verify_epoch_invariants().is_ok());
+    }
+
+    #[test]
+    fn test_settlement_processes_stake_queues() {
+        let mut econ = settlement_economics();
+        let activation = econ.validators.bond(vec![1], 5_000_000, 0).unwrap();
+        let withdrawable = econ.validators.unbond(vec![2], 1_000_000, 0).unwrap();
+
+        for epoch in 0..activation {
+            econ.settle_epoch(epoch, integration::EpochBlockData::default()).unwrap();
+        }
+        assert_eq!(econ.validators.validators[&vec![1]].stake, 100_000_000);
+
+        econ.settle_epoch(activation, integration::EpochBlockData::default()).unwrap();
+        assert_eq!(econ.validators.validators[&vec![1]].stake, 105_000_000);
+
+        for epoch in activation + 1..=withdrawable {
+            econ.settle_epoch(epoch, integration::EpochBlockData::default()).unwrap();
+        }
+        assert_eq!(econ.validators.withdraw(&[2]), Ok(1_000_000));
+    }
+
+    #[test]
+    fn test_small_slash_is_still_burned() {
+        let mut econ = settlement_economics();
+        let mut inputs = settlement_inputs();
+        inputs.fee_revenue = 0;
+        inputs.slashing_evidence.retain(|e| e.proof_hash == vec![1]);
+        inputs.slashing_evidence[0].slash_amount = 500;
+
+        let settlement = econ.settle_epoch(1, inputs).unwrap();
+        assert_eq!(settlement.total_slashed, 500);
+        assert_eq!(settlement.total_burned, 500);
+        assert_eq!(econ.tokenomics.supply_state.total_burned, 500);
+        assert!(econ.verify_epoch_invariants().is_ok());
+    }
 }
 
 pub use runtime::{
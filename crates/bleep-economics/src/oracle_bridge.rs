@@ -4,10 +4,37 @@
 /// Multi-source aggregation, cryptographic commitments, and slashing for false data.
 
 use serde::{Deserialize, Serialize};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use sha2::{Sha256, Digest};
 use thiserror::Error;
 
+use crate::aggregation::weighted_median;
+
+/// Maximum operator reputation (also the maximum aggregation weight)
+pub const MAX_REPUTATION: u16 = 10_000;
+
+/// Reputation gained per accurate report
+pub const REPUTATION_REWARD: u16 = 200;
+
+/// Reputation retained after an outlier report (basis points)
+pub const OUTLIER_RETAIN_BPS: u32 = 7_500;
+
+/// Reputation retained after a stale report (basis points)
+pub const STALE_RETAIN_BPS: u32 = 9_000;
+
+/// Deviation from the finalized median beyond which a report is slashable (basis points)
+pub const MAX_REPORT_DEVIATION_BPS: u128 = 500;
+
+/// Share of an operator's slashing balance taken per slashable report (basis points)
+pub const ORACLE_SLASH_BPS: u128 = 1_000;
+
+/// Default source-chain confirmations before a bridge transaction is acted on
+pub const DEFAULT_BRIDGE_CONFIRMATIONS: u64 = 12;
+
+fn default_bridge_confirmations() -> u64 {
+    DEFAULT_BRIDGE_CONFIRMATIONS
+}
+
 /// Oracle data source identifier
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, PartialOrd, Ord)]
 pub enum OracleSource {
@@ -113,8 +140,25 @@ pub struct OracleOperator {
     pub disputed_updates: u64,
     /// Slashing balance (reserved)
     pub slashing_balance: u128,
-    /// Reputation score (0-10000)
+    /// Reputation score (0-10000), used as the aggregation weight
     pub reputation_score: u16,
+    /// Reports judged to be price outliers
+    #[serde(default)]
+    pub outlier_reports: u64,
+    /// Reports judged to be stale
+    #[serde(default)]
+    pub stale_reports: u64,
+}
+
+/// How an operator's report compared with the aggregate
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ReportOutcome {
+    /// Report agreed with the aggregate
+    Accurate,
+    /// Report deviated from the aggregate beyond tolerance
+    Outlier,
+    /// Report was too old to be used
+    Stale,
 }
 
 impl OracleOperator {
@@ -126,6 +170,44 @@ impl OracleOperator {
             self.accepted_updates as f64 / self.updates_submitted as f64
         }
     }
+
+    /// Apply a report outcome: accurate reports earn a fixed reward (capped),
+    /// outliers and stale reports decay the score multiplicatively.
+    pub fn apply_outcome(&mut self, outcome: ReportOutcome) {
+        self.reputation_score = match outcome {
+            ReportOutcome::Accurate => self.reputation_score
+                .saturating_add(REPUTATION_REWARD)
+                .min(MAX_REPUTATION),
+            ReportOutcome::Outlier => {
+                self.outlier_reports += 1;
+                (self.reputation_score as u32 * OUTLIER_RETAIN_BPS / 10_000) as u16
+            }
+            ReportOutcome::Stale => {
+                self.stale_reports += 1;
+                (self.reputation_score as u32 * STALE_RETAIN_BPS / 10_000) as u16
+            }
+        };
+    }
+}
+
+/// Evidence that an operator's report deviated from a finalized aggregate
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PriceDeviationEvidence {
+    /// The finalized aggregate the report is judged against
+    pub aggregate: AggregatedPrice,
+    /// The operator's report
+    pub report: PriceUpdate,
+}
+
+impl PriceDeviationEvidence {
+    /// Deviation of the report from the aggregate median (basis points)
+    pub fn deviation_bps(&self) -> u128 {
+        let median = self.aggregate.median_price;
+        if median == 0 {
+            return u128::MAX;
+        }
+        self.report.price.abs_diff(median).saturating_mul(10_000) / median
+    }
 }
 
 /// Bridge configuration (for cross-chain settlement)
@@ -141,6 +223,9 @@ pub struct BridgeConfig {
     pub rate_limit: u32,
     /// Kill switch enabled (can pause bridge)
     pub kill_switch_enabled: bool,
+    /// Source-chain confirmations required before a transaction is actionable
+    #[serde(default = "default_bridge_confirmations")]
+    pub required_confirmations: u64,
 }
 
 impl BridgeConfig {
@@ -151,6 +236,9 @@ impl BridgeConfig {
         if self.rate_limit == 0 {
             return Err(OracleError::InvalidBridgeConfig);
         }
+        if self.required_confirmations == 0 {
+            return Err(OracleError::InvalidBridgeConfig);
+        }
         Ok(())
     }
 }
@@ -170,6 +258,9 @@ pub struct BridgeTransaction {
     pub light_client_proof: Vec<u8>,
     /// Recipient address
     pub recipient: Vec<u8>,
+    /// Source-chain block height the transaction was included at
+    #[serde(default)]
+    pub source_height: u64,
     /// Status
     pub status: BridgeStatus,
 }
@@ -201,6 +292,16 @@ pub struct OracleBridgeEngine {
     pub bridge_transactions: BTreeMap<Vec<u8>, BridgeTransaction>,
     /// Disputed price updates (flagged for slashing)
     pub disputed_updates: BTreeMap<Vec<u8>, DisputeRecord>,
+    /// Every aggregate produced (evidence must reference one), mapped to the
+    /// hashes of the fresh reports that went into it
+    #[serde(default)]
+    pub finalized_aggregates: BTreeMap<Vec<u8>, BTreeSet<Vec<u8>>>,
+    /// Hashes of reports already slashed
+    #[serde(default)]
+    pub slashed_reports: BTreeSet<Vec<u8>>,
+    /// Operator stake removed by slashing, to be burned by the caller
+    #[serde(default)]
+    pub total_slashed: u128,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -227,6 +328,9 @@ impl OracleBridgeEngine {
             bridge_configs: BTreeMap::new(),
             bridge_transactions: BTreeMap::new(),
             disputed_updates: BTreeMap::new(),
+            finalized_aggregates: BTreeMap::new(),
+            slashed_reports: BTreeSet::new(),
+            total_slashed: 0,
         }
     }
 
@@ -250,6 +354,8 @@ impl OracleBridgeEngine {
                 disputed_updates: 0,
                 slashing_balance,
                 reputation_score: 5000, // Start at 50%
+                outlier_reports: 0,
+                stale_reports: 0,
             },
         );
 
@@ -306,7 +412,21 @@ impl OracleBridgeEngine {
         Ok(())
     }
 
-    /// Aggregate prices from multiple sources (median, not mean-vulnerable)
+    /// Record how an operator's report compared with the aggregate
+    pub fn update_operator_reputation(
+        &mut self,
+        operator_id: &[u8],
+        outcome: ReportOutcome,
+    ) -> Result<u16, OracleError> {
+        let op = self.operators
+            .get_mut(operator_id)
+            .ok_or(OracleError::OperatorNotRegistered)?;
+        op.apply_outcome(outcome);
+        Ok(op.reputation_score)
+    }
+
+    /// Aggregate prices from multiple sources (median, not mean-vulnerable).
+    /// Each update is weighted by its operator's reputation score.
     pub fn aggregate_prices(
         &mut self,
         asset: &str,
@@ -327,23 +447,40 @@ impl OracleBridgeEngine {
             return Err(OracleError::InsufficientSources);
         }
 
-        // Calculate median price
-        let mut prices: Vec<u128> = fresh_updates
+        // (price, confidence, weight) per fresh update
+        let weighted: Vec<(u128, u16, u128)> = fresh_updates
             .iter()
-            .map(|u| u.price)
+            .map(|u| {
+                let weight = self.operators
+                    .get(&u.operator_id)
+                    .map(|op| op.reputation_score as u128)
+                    .unwrap_or(0);
+                (u.price, u.confidence_bps, weight)
+            })
             .collect();
-        prices.sort();
-        let median_price = prices[prices.len() / 2];
 
-        // Calculate mean price
-        let mean_price: u128 = prices.iter().sum::<u128>() / prices.len() as u128;
+        let total_weight: u128 = weighted.iter().map(|&(_, _, w)| w).sum();
+        if total_weight == 0 {
+            return Err(OracleError::InsufficientSources);
+        }
+
+        // Reputation-weighted median
+        let by_weight: Vec<(u128, u128)> = weighted.iter().map(|&(p, _, w)| (w, p)).collect();
+        let median_price = weighted_median(&by_weight).ok_or(OracleError::InsufficientSources)?;
+
+        // Weighted mean price
+        let mean_price: u128 = weighted
+            .iter()
+            .map(|&(price, _, w)| price.saturating_mul(w))
+            .fold(0u128, |acc, x| acc.saturating_add(x))
+            / total_weight;
 
         // Weighted confidence
-        let confidence_bps: u16 = fresh_updates
+        let confidence_bps = (weighted
             .iter()
-            .map(|u| u.confidence_bps as u32)
-            .sum::<u32>() as u16
-            / fresh_updates.len() as u16;
+            .map(|&(_, c, w)| c as u128 * w)
+            .sum::<u128>()
+            / total_weight) as u16;
 
         let mut agg = AggregatedPrice {
             asset: asset.to_string(),
@@ -356,11 +493,61 @@ impl OracleBridgeEngine {
         };
 
         agg.aggregation_hash = agg.compute_hash();
+        let inputs = fresh_updates.iter().map(|u| u.compute_hash()).collect();
+        self.finalized_aggregates.insert(agg.aggregation_hash.clone(), inputs);
         self.aggregated_prices.insert(asset.to_string(), agg.clone());
 
         Ok(agg)
     }
 
+    /// Slash an operator whose report deviated from a finalized aggregate
+    /// by more than `MAX_REPORT_DEVIATION_BPS`.
+    ///
+    /// The evidence must reference an aggregate this engine produced and one
+    /// of that aggregate's inputs (a report from the operator, fresh within
+    /// the aggregate's time window), so anyone can re-check the slash later.
+    /// Each report is slashable once. Returns the amount taken from the
+    /// operator's slashing balance, which is added to `total_slashed` for the
+    /// caller to burn.
+    pub fn slash_operator(
+        &mut self,
+        operator_id: &[u8],
+        evidence: PriceDeviationEvidence,
+    ) -> Result<u128, OracleError> {
+        if !self.operators.contains_key(operator_id) {
+            return Err(OracleError::OperatorNotRegistered);
+        }
+
+        evidence.aggregate.verify()?;
+        let inputs = self.finalized_aggregates
+            .get(&evidence.aggregate.aggregation_hash)
+            .ok_or(OracleError::UnknownAggregate)?;
+
+        let report = &evidence.report;
+        let report_hash = report.compute_hash();
+        if report.operator_id != operator_id || !inputs.contains(&report_hash) {
+            return Err(OracleError::UnknownReport);
+        }
+        if self.slashed_reports.contains(&report_hash) {
+            return Err(OracleError::ReportAlreadySlashed);
+        }
+
+        let deviation_bps = evidence.deviation_bps();
+        if deviation_bps <= MAX_REPORT_DEVIATION_BPS {
+            return Err(OracleError::ReportWithinTolerance { deviation_bps });
+        }
+
+        let op = self.operators
+            .get_mut(operator_id)
+            .ok_or(OracleError::OperatorNotRegistered)?;
+        let slashed = op.slashing_balance * ORACLE_SLASH_BPS / 10_000;
+        op.slashing_balance -= slashed;
+        self.slashed_reports.insert(report_hash);
+        self.total_slashed = self.total_slashed.saturating_add(slashed);
+
+        Ok(slashed)
+    }
+
     /// Dispute a price update (marks operator for potential slashing)
     pub fn dispute_price_update(
         &mut self,
@@ -430,13 +617,90 @@ impl OracleBridgeEngine {
             return Err(OracleError::InvalidBridgeAmount);
         }
 
+        // A tracked transaction is never re-recorded, or its release could repeat
+        if self.bridge_transactions.contains_key(&tx.tx_id) {
+            return Err(OracleError::DuplicateBridgeTransaction);
+        }
+
         // Verify light client proof (would be checked against consensus)
-        // For now, just record it
+        // For now, just record it; finality is decided by the engine alone
+        let mut tx = tx;
+        tx.status = BridgeStatus::Pending;
         self.bridge_transactions.insert(tx.tx_id.clone(), tx);
 
         Ok(())
     }
 
+    /// Whether `tx` has enough source-chain confirmations to be acted on.
+    /// The inclusion block counts as the first confirmation; unknown source
+    /// chains are never confirmed.
+    pub fn is_confirmed(&self, tx: &BridgeTransaction, current_source_height: u64) -> bool {
+        let config = match self.bridge_configs.get(&tx.source_chain) {
+            Some(config) => config,
+            None => return false,
+        };
+        if tx.source_height > current_source_height {
+            return false;
+        }
+        let confirmations = current_source_height - tx.source_height + 1;
+        confirmations >= config.required_confirmations
+    }
+
+    /// Advance a bridge's verified light-client head. The head never moves
+    /// backwards, so a transaction that became final stays final.
+    pub fn advance_light_client(&mut self, chain_id: u64, height: u64) -> Result<(), OracleError> {
+        let config = self.bridge_configs
+            .get_mut(&chain_id)
+            .ok_or(OracleError::BridgeNotRegistered)?;
+        if height < config.light_client_height {
+            return Err(OracleError::LightClientRegression);
+        }
+        config.light_client_height = height;
+        Ok(())
+    }
+
+    /// Whether a tracked transaction is confirmed at its source chain's
+    /// light-client head (see `is_confirmed`). Unknown transactions or chains
+    /// are never final.
+    pub fn is_final(&self, tx_id: &[u8]) -> bool {
+        let tx = match self.bridge_transactions.get(tx_id) {
+            Some(tx) => tx,
+            None => return false,
+        };
+        self.bridge_configs
+            .get(&tx.source_chain)
+            .map_or(false, |config| self.is_confirmed(tx, config.light_client_height))
+    }
+
+    /// Release a final transaction's funds to its recipient.
+    ///
+    /// Refused until `is_final`, and only once per transaction. Returns the
+    /// released amount.
+    pub fn release_funds(&mut self, tx_id: &[u8]) -> Result<u128, OracleError> {
+        let tx = self.bridge_transactions
+            .get(tx_id)
+            .ok_or(OracleError::BridgeTransactionNotFound)?;
+        let config = self.bridge_configs
+            .get(&tx.source_chain)
+            .ok_or(OracleError::BridgeNotRegistered)?;
+        if tx.status == BridgeStatus::Finalized {
+            return Err(OracleError::AlreadyReleased);
+        }
+        if !self.is_confirmed(tx, config.light_client_height) {
+            return Err(OracleError::NotFinal {
+                confirmations: (config.light_client_height + 1).saturating_sub(tx.source_height),
+                required: config.required_confirmations,
+            });
+        }
+
+        let tx = self.bridge_transactions
+            .get_mut(tx_id)
+            .ok_or(OracleError::BridgeTransactionNotFound)?;
+
+        tx.status = BridgeStatus::Finalized;
+        Ok(tx.amount)
+    }
+
     /// Get operator reputation
     pub fn get_operator_reputation(&self, operator_id: &[u8]) -> Result<u16, OracleError> {
         self.operators
@@ -472,11 +736,30 @@ pub enum OracleError {
     InvalidBridgeAmount,
     #[error("Invalid oracle operator signature")]
     InvalidSignature,
+    #[error("Bridge transaction not found")]
+    BridgeTransactionNotFound,
+    #[error("Bridge transaction not final ({confirmations}/{required} confirmations)")]
+    NotFinal { confirmations: u64, required: u64 },
+    #[error("Bridge transaction funds already released")]
+    AlreadyReleased,
+    #[error("Bridge transaction already tracked")]
+    DuplicateBridgeTransaction,
+    #[error("Light client height cannot move backwards")]
+    LightClientRegression,
+    #[error("Aggregate was not finalized by this engine")]
+    UnknownAggregate,
+    #[error("Report was not submitted by this operator for this asset")]
+    UnknownReport,
+    #[error("Report already slashed")]
+    ReportAlreadySlashed,
+    #[error("Report within tolerance ({deviation_bps} bps from median)")]
+    ReportWithinTolerance { deviation_bps: u128 },
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use tracing::error;
 
     #[test]
     fn test_price_update_hash() {
@@ -557,6 +840,92 @@ mod tests {
         assert_eq!(agg.source_count, 3);
     }
 
+    #[test]
+    fn test_slash_operator_for_outlier_report() {
+        let mut engine = OracleBridgeEngine::genesis();
+        let ts = 1000u64;
+        let mut reports = Vec::new();
+        for (id, price) in [(1u8, 1800u128), (2, 1805), (3, 2500)] {
+            engine.register_operator(vec![id], 1000).unwrap();
+            let report = PriceUpdate {
+                source: OracleSource::Custom(vec![id]),
+                asset: "ETH/USD".to_string(),
+                price,
+                timestamp: ts,
+                confidence_bps: 100,
+                operator_id: vec![id],
+                signature: vec![],
+            };
+            engine.submit_price_update(report.clone()).unwrap();
+            reports.push(report);
+        }
+        let aggregate = engine.aggregate_prices("ETH/USD", ts, 60).unwrap();
+        let evidence = |report: &PriceUpdate| PriceDeviationEvidence {
+            aggregate: aggregate.clone(),
+            report: report.clone(),
+        };
+
+        // Outlier is slashed once
+        assert_eq!(engine.slash_operator(&[3], evidence(&reports[2])), Ok(100));
+        assert_eq!(engine.operators[&vec![3]].slashing_balance, 900);
+        assert_eq!(
+            engine.slash_operator(&[3], evidence(&reports[2])),
+            Err(OracleError::ReportAlreadySlashed)
+        );
+
+        // In-band report is not
+        assert!(matches!(
+            engine.slash_operator(&[1], evidence(&reports[0])),
+            Err(OracleError::ReportWithinTolerance { .. })
+        ));
+        assert_eq!(engine.operators[&vec![1]].slashing_balance, 1000);
+
+        // Report must be the accused operator's own
+        assert_eq!(engine.slash_operator(&[2], evidence(&reports[2])), Err(OracleError::UnknownReport));
+
+        // Aggregate must be one the engine finalized
+        let mut forged = evidence(&reports[1]);
+        forged.aggregate.median_price = 10_000;
+        forged.aggregate.aggregation_hash = forged.aggregate.compute_hash();
+        assert_eq!(engine.slash_operator(&[2], forged), Err(OracleError::UnknownAggregate));
+        assert_eq!(engine.total_slashed, 100);
+    }
+
+    #[test]
+    fn test_slash_requires_report_among_aggregate_inputs() {
+        let mut engine = OracleBridgeEngine::genesis();
+        for (id, price) in [(1u8, 1800u128), (2, 1805), (3, 1810)] {
+            engine.register_operator(vec![id], 1000).unwrap();
+            engine.submit_price_update(PriceUpdate {
+                source: OracleSource::Custom(vec![id]),
+                asset: "ETH/USD".to_string(),
+                price,
+                timestamp: 1000,
+                confidence_bps: 100,
+                operator_id: vec![id],
+                signature: vec![],
+            }).unwrap();
+        }
+        let aggregate = engine.aggregate_prices("ETH/USD", 1000, 60).unwrap();
+
+        // A wild report submitted after the aggregate was never one of its inputs
+        let late = PriceUpdate {
+            source: OracleSource::Custom(vec![3]),
+            asset: "ETH/USD".to_string(),
+            price: 9000,
+            timestamp: 5000,
+            confidence_bps: 100,
+            operator_id: vec![3],
+            signature: vec![],
+        };
+        engine.submit_price_update(late.clone()).unwrap();
+        let evidence = PriceDeviationEvidence { aggregate, report: late };
+
+        assert_eq!(engine.slash_operator(&[3], evidence), Err(OracleError::UnknownReport));
+        assert_eq!(engine.operators[&vec![3]].slashing_balance, 1000);
+        assert_eq!(engine.total_slashed, 0);
+    }
+
     #[test]
     fn test_bridge_registration() {
         let mut engine = OracleBridgeEngine::genesis();
@@ -566,8 +935,178 @@ mod tests {
             max_tx_size: 1024,
             rate_limit: 100,
             kill_switch_enabled: false,
+            required_confirmations: DEFAULT_BRIDGE_CONFIRMATIONS,
         };
 
         assert!(engine.register_bridge(1, config).is_ok());
     }
+
+    fn bridge_tx_from(source_chain: u64, source_height: u64) -> BridgeTransaction {
+        BridgeTransaction {
+            source_chain,
+            dest_chain: 0,
+            tx_id: vec![1],
+            amount: 100,
+            light_client_proof: vec![],
+            recipient: vec![2],
+            status: BridgeStatus::Pending,
+            source_height,
+        }
+    }
+
+    fn engine_with_bridge(required_confirmations: u64) -> OracleBridgeEngine {
+        let mut engine = OracleBridgeEngine::genesis();
+        engine.register_bridge(1, BridgeConfig {
+            remote_chain_id: 1,
+            light_client_height: 100,
+            max_tx_size: 1024,
+            rate_limit: 100,
+            kill_switch_enabled: false,
+            required_confirmations,
+        }).unwrap();
+        engine
+    }
+
+    #[test]
+    fn test_bridge_tx_below_confirmations_not_actionable() {
+        let engine = engine_with_bridge(6);
+        let tx = bridge_tx_from(1, 100);
+
+        // Inclusion block + 4 more = 5 confirmations
+        assert!(!engine.is_confirmed(&tx, 104));
+        // Source head behind the inclusion height (reorg / stale view)
+        assert!(!engine.is_confirmed(&tx, 99));
+        // Unknown source chain
+        assert!(!engine.is_confirmed(&bridge_tx_from(7, 100), 1_000));
+    }
+
+    #[test]
+    fn test_bridge_tx_at_confirmations_actionable() {
+        let engine = engine_with_bridge(6);
+        let tx = bridge_tx_from(1, 100);
+
+        assert!(engine.is_confirmed(&tx, 105));
+        assert!(engine.is_confirmed(&tx, 500));
+    }
+
+    /// Bridge 1 <-> 1, so the transaction is tracked under the registered config
+    fn tracked_bridge_tx(engine: &mut OracleBridgeEngine) -> Vec<u8> {
+        let mut tx = bridge_tx_from(1, 100);
+        tx.dest_chain = 1;
+        engine.finalize_bridge_transaction(tx.clone()).unwrap();
+        tx.tx_id
+    }
+
+    #[test]
+    fn test_bridge_tx_final_after_required_confirmations() {
+        let mut engine = engine_with_bridge(3);
+        let tx_id = tracked_bridge_tx(&mut engine);
+
+        // Light-client head starts at the inclusion height (1 confirmation)
+        assert!(!engine.is_final(&tx_id));
+        engine.advance_light_client(1, 101).unwrap();
+        assert!(!engine.is_final(&tx_id));
+        engine.advance_light_client(1, 102).unwrap();
+        assert!(engine.is_final(&tx_id));
+        assert_eq!(engine.release_funds(&tx_id), Ok(100));
+        assert_eq!(engine.bridge_transactions[&tx_id].status, BridgeStatus::Finalized);
+        assert_eq!(engine.release_funds(&tx_id), Err(OracleError::AlreadyReleased));
+    }
+
+    #[test]
+    fn test_bridge_release_withheld_below_threshold() {
+        let mut engine = engine_with_bridge(3);
+        let tx_id = tracked_bridge_tx(&mut engine);
+
+        engine.advance_light_client(1, 101).unwrap();
+        assert!(!engine.is_final(&tx_id));
+        assert_eq!(
+            engine.release_funds(&tx_id),
+            Err(OracleError::NotFinal { confirmations: 2, required: 3 })
+        );
+        assert_eq!(engine.bridge_transactions[&tx_id].status, BridgeStatus::Pending);
+        assert_eq!(engine.advance_light_client(1, 100), Err(OracleError::LightClientRegression));
+
+        assert!(!engine.is_final(&[9]));
+        assert_eq!(engine.release_funds(&[9]), Err(OracleError::BridgeTransactionNotFound));
+    }
+
+    #[test]
+    fn test_bridge_tx_submission_cannot_claim_finality() {
+        let mut engine = engine_with_bridge(3);
+
+        // Submitter-supplied status is discarded
+        let mut tx = bridge_tx_from(1, 100);
+        tx.dest_chain = 1;
+        tx.status = BridgeStatus::Finalized;
+        engine.finalize_bridge_transaction(tx.clone()).unwrap();
+        assert_eq!(engine.bridge_transactions[&tx.tx_id].status, BridgeStatus::Pending);
+
+        engine.advance_light_client(1, 102).unwrap();
+        assert_eq!(engine.release_funds(&tx.tx_id), Ok(100));
+
+        // Re-submitting a released transaction cannot reset it for a second release
+        assert_eq!(
+            engine.finalize_bridge_transaction(tx.clone()),
+            Err(OracleError::DuplicateBridgeTransaction)
+        );
+        assert_eq!(engine.release_funds(&tx.tx_id), Err(OracleError::AlreadyReleased));
+    }
+
+    fn submit_eth_price(engine: &mut OracleBridgeEngine, operator_id: Vec<u8>, price: u128) {
+        engine.submit_price_update(PriceUpdate {
+            source: OracleSource::Custom(operator_id.clone()),
+            asset: "ETH/USD".to_string(),
+            price,
+            timestamp: 1000,
+            confidence_bps: 100,
+            operator_id,
+            signature: vec![],
+        }).unwrap();
+    }
+
+    #[test]
+    fn test_outlier_operator_weight_decays() {
+        let mut engine = OracleBridgeEngine::genesis();
+        for id in 1..=3u8 {
+            engine.register_operator(vec![id], 1000).unwrap();
+        }
+        submit_eth_price(&mut engine, vec![1], 1_000);
+        submit_eth_price(&mut engine, vec![2], 1_010);
+        submit_eth_price(&mut engine, vec![3], 4_000);
+        let before = engine.aggregate_prices("ETH/USD", 1000, 1000).unwrap();
+        assert_eq!(before.mean_price, 2_003);
+
+        let mut last = engine.get_operator_reputation(&[3]).unwrap();
+        for _ in 0..5 {
+            let score = engine.update_operator_reputation(&[3], ReportOutcome::Outlier).unwrap();
+            assert!(score < last);
+            last = score;
+        }
+        assert_eq!(engine.operators[&vec![3u8]].outlier_reports, 5);
+
+        // The outlier now carries far less weight in the mean
+        let after = engine.aggregate_prices("ETH/USD", 1000, 1000).unwrap();
+        assert!(after.mean_price < before.mean_price);
+        assert_eq!(after.median_price, 1_010);
+    }
+
+    #[test]
+    fn test_reliable_operator_weight_grows_to_cap() {
+        let mut engine = OracleBridgeEngine::genesis();
+        engine.register_operator(vec![1], 1000).unwrap();
+
+        let mut last = engine.get_operator_reputation(&[1]).unwrap();
+        for _ in 0..40 {
+            let score = engine.update_operator_reputation(&[1], ReportOutcome::Accurate).unwrap();
+            assert!(score >= last);
+            last = score;
+        }
+        assert_eq!(last, MAX_REPUTATION);
+
+        assert_eq!(
+            engine.update_operator_reputation(&[9], ReportOutcome::Accurate),
+            Err(OracleError::OperatorNotRegistered)
+        );
+    }
 }
@@ -12,26 +12,19 @@
 //!   ├─► OracleBridgeEngine::aggregate_prices("BLEEP/USD", ts, 300)
 //!   │    → AggregatedPrice (median from ≥3 operators)
 //!   │
-//!   ├─► ValidatorIncentivesEngine::compute_epoch_rewards(epoch)
-//!   │    → Vec<RewardRecord>  (per-validator)
-//!   │
-//!   ├─► CanonicalTokenomicsEngine::record_emission(epoch, BlockProposal, total)
-//!   │   CanonicalTokenomicsEngine::record_burn(epoch, TransactionFee, burn_amt)
-//!   │
-//!   └─► CanonicalTokenomicsEngine::finalize_epoch(epoch)  → state_hash
+//!   └─► BleepEconomics::settle_epoch(epoch, EpochBlockData)
+//!        rewards + emission, fee burn, slashes, finalize_epoch
+//!        → EpochSettlement (reward records, burn totals, state_hash)
 //! ```
 
-use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
 use tracing::{info, warn};
 
 use crate::{
-    CanonicalTokenomicsEngine, EmissionType, BurnType, TokenomicsError,
-    FeeMarket, ShardCongestion, TransactionType, ResourceUsage, FeeMarketError,
-    ValidatorIncentivesEngine, ValidatorMetrics, ValidatorError, RewardRecord,
-    OracleBridgeEngine, PriceUpdate, OracleSource, OracleError,
-    integration::{BleepEconomics, EconomicError},
-    distribution::FeeDistribution,
+    TokenomicsError, ShardCongestion, FeeMarketError,
+    ValidatorMetrics, ValidatorError, RewardRecord,
+    PriceUpdate, OracleError,
+    integration::{BleepEconomics, EconomicError, EpochBlockData},
 };
 
 // ── Public re-exports for convenience ────────────────────────────────────────
@@ -87,6 +80,8 @@ pub enum RuntimeError {
     Validator(#[from] ValidatorError),
     #[error("Oracle error: {0}")]
     Oracle(#[from] OracleError),
+    #[error("Epoch settlement error: {0}")]
+    Settlement(#[from] EconomicError),
     #[error("Economic invariant violation: {0}")]
     InvariantViolation(String),
 }
@@ -111,12 +106,12 @@ impl BleepEconomicsRuntime {
             epoch_history: std::collections::VecDeque::with_capacity(100),
         };
         // Register the default BLEEP/USD shard on the fee market
-        runtime.state.fee_market.record_shard_congestion(0, ShardCongestion {
+        runtime.state.fee_market.record_shard_congestion(ShardCongestion {
             shard_id: 0,
-            pending_txs: 0,
-            utilisation_bps: 5000,
-            gas_price_multiplier: 1000,
-        });
+            utilization_bps: 5000,
+            pending_txns: 0,
+            avg_tx_size_bytes: 0,
+        }).expect("default shard congestion is valid");
         runtime
     }
 
@@ -153,12 +148,12 @@ impl BleepEconomicsRuntime {
         info!("  💹 New base fee: {} µBLEEP", new_base_fee);
 
         // ── 2. Record shard utilisation ───────────────────────────────────────
-        self.state.fee_market.record_shard_congestion(0, ShardCongestion {
+        self.state.fee_market.record_shard_congestion(ShardCongestion {
             shard_id: 0,
-            pending_txs: (input.block_count * 100) as u32,
-            utilisation_bps: input.avg_utilisation_bps,
-            gas_price_multiplier: (new_base_fee / 1000).max(1000) as u64,
-        });
+            utilization_bps: input.avg_utilisation_bps,
+            pending_txns: input.block_count.saturating_mul(100).min(u32::MAX as u64) as u32,
+            avg_tx_size_bytes: 0,
+        })?;
 
         // ── 3. Oracle price aggregation ───────────────────────────────────────
         // Ingest any new oracle updates supplied with this epoch input
@@ -186,74 +181,39 @@ impl BleepEconomicsRuntime {
             }
         };
 
-        // ── 4. Validator metrics + reward computation ─────────────────────────
-        for metrics in &input.validator_metrics {
-            if let Err(e) = self.state.validators.record_metrics(
-                metrics.validator_id.clone(),
-                metrics.clone(),
-            ) {
-                warn!("  ⚠️  Validator metrics skipped ({}): {}", hex::encode(&metrics.validator_id[..4.min(metrics.validator_id.len())]), e);
-            }
-        }
-        let reward_records = match self.state.validators.compute_epoch_rewards(epoch) {
-            Ok(records) => {
-                let total: u128 = records.iter().map(|r| r.total_reward).sum();
-                info!("  🏆 Validator rewards: {} records, {} µBLEEP total", records.len(), total);
-                records
-            }
-            Err(e) => {
-                warn!("  ⚠️  Reward computation failed: {} — using empty records", e);
-                vec![]
-            }
-        };
-
-        // ── 5. Tokenomics: emission ────────────────────────────────────────────
-        let total_emitted: u128 = reward_records.iter().map(|r| r.total_reward).sum();
-        if total_emitted > 0 {
-            self.state.tokenomics.record_emission(
-                epoch,
-                EmissionType::BlockProposal,
-                total_emitted,
-            )?;
+        // ── 4. Settlement: rewards + emission, fee burn, supply finalization ─
+        // Metrics for unregistered validators are dropped here so one bad
+        // report cannot fail the whole settlement.
+        let validator_metrics: Vec<ValidatorMetrics> = input.validator_metrics.into_iter()
+            .filter(|metrics| {
+                let known = self.state.validators.validators.contains_key(&metrics.validator_id);
+                if !known {
+                    warn!("  ⚠️  Validator metrics skipped ({}): {}",
+                          hex::encode(&metrics.validator_id[..4.min(metrics.validator_id.len())]),
+                          ValidatorError::ValidatorNotFound);
+                }
+                known
+            })
+            .collect();
+        let settlement = self.state.settle_epoch(epoch, EpochBlockData {
+            fee_revenue: input.fee_revenue,
+            validator_metrics,
+            slashing_evidence: Vec::new(),
+        })?;
+        info!("  🏆 Validator rewards: {} records, {} µBLEEP total",
+              settlement.reward_records.len(), settlement.total_emitted);
+        if settlement.fees.burned > 0 {
+            info!("  🔥 Fee burn: {} µBLEEP (of {} µBLEEP revenue)", settlement.fees.burned, input.fee_revenue);
         }
-
-        // ── 6. Tokenomics: fee distribution (25% burn / 50% validators / 25% treasury)
-        // Uses the canonical FeeDistribution model from distribution.rs.
-        let fee_dist = FeeDistribution::compute(input.fee_revenue);
-        debug_assert!(fee_dist.is_consistent(), "fee distribution invariant broken");
-
-        let total_burned = if fee_dist.burned > 0 {
-            self.state.tokenomics.record_burn(epoch, BurnType::TransactionFee, fee_dist.burned)?;
-            info!("  🔥 Fee burn: {} µBLEEP (25% of {} µBLEEP revenue)", fee_dist.burned, input.fee_revenue);
-            fee_dist.burned
-        } else {
-            0
-        };
-
-        // Validator reward top-up from fee revenue (50% of fees)
-        if fee_dist.validator_reward > 0 {
-            // Record as additional emission from the fee validator share bucket.
-            // This does NOT draw from the ValidatorRewards allocation pool —
-            // it comes from fee revenue already in circulation, so we add it
-            // directly to the emission record without a supply-cap check.
-            self.state.tokenomics.record_emission(
-                epoch, EmissionType::BlockProposal, fee_dist.validator_reward
-            ).unwrap_or_else(|e| warn!("  ⚠️  Fee validator emission skipped: {}", e));
-            info!("  💰 Validator fee share: {} µBLEEP (50% of fees)", fee_dist.validator_reward);
+        if settlement.fees.validator_reward > 0 {
+            info!("  💰 Validator fee share: {} µBLEEP", settlement.fees.validator_reward);
         }
-
-        // Treasury allocation from fee revenue (25% of fees)
-        if fee_dist.treasury > 0 {
-            info!("  🏛  Treasury fee share: {} µBLEEP (25% of fees)", fee_dist.treasury);
-            // Treasury share is tracked via burn_records with a separate type.
-            // In production this routes to the Foundation Treasury bucket in GenesisAllocation.
-            self.state.tokenomics.record_burn(epoch, BurnType::ProposalRejection, 0)
-                .unwrap_or(()); // no-op placeholder; real routing in distribution engine
+        if settlement.fees.treasury > 0 {
+            info!("  🏛  Treasury fee share: {} µBLEEP", settlement.fees.treasury);
         }
 
-        // ── 7. Finalize epoch (snapshot + hash) ───────────────────────────────
-        let supply_state_hash = self.state.tokenomics.finalize_epoch(epoch)?;
-        let circulating_supply = self.state.tokenomics.supply_state.circulating_supply;
+        let supply_state_hash = settlement.supply_state_hash;
+        let circulating_supply = settlement.circulating_supply;
 
         info!("  ✅ Epoch {} finalized: supply={} µBLEEP, hash={}",
               epoch, circulating_supply,
@@ -269,10 +229,10 @@ impl BleepEconomicsRuntime {
         let output = EpochOutput {
             epoch,
             new_base_fee,
-            total_emitted,
-            total_burned,
+            total_emitted: settlement.total_emitted,
+            total_burned: settlement.total_burned,
             circulating_supply,
-            reward_records,
+            reward_records: settlement.reward_records,
             supply_state_hash,
             bleep_usd_price,
         };
@@ -321,6 +281,7 @@ impl BleepEconomicsRuntime {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::OracleSource;
 
     fn make_metrics(id_byte: u8, blocks: u32) -> ValidatorMetrics {
         ValidatorMetrics {
@@ -423,6 +384,32 @@ mod tests {
                 "Expected fee to stay same or increase under high load");
     }
 
+    #[test]
+    fn test_epoch_is_settled_once_through_settlement() {
+        let mut rt = BleepEconomicsRuntime::genesis();
+        rt.register_validator(vec![1u8; 8], 1_000_000).unwrap();
+        let input = EpochInput {
+            epoch: 1,
+            block_count: 100,
+            fee_revenue: 5_000_000,
+            avg_utilisation_bps: 5000,
+            // Metrics for an unregistered validator are skipped, not fatal
+            validator_metrics: vec![make_metrics(1, 100), make_metrics(9, 100)],
+            oracle_updates: vec![],
+        };
+
+        let out = rt.process_epoch(input.clone()).unwrap();
+        assert_eq!(out.reward_records.len(), 1);
+        assert_eq!(rt.state.last_settled_epoch, Some(1));
+
+        let minted = rt.total_minted();
+        assert!(matches!(
+            rt.process_epoch(input),
+            Err(RuntimeError::Settlement(EconomicError::EpochAlreadySettled(1)))
+        ));
+        assert_eq!(rt.total_minted(), minted);
+    }
+
     #[test]
     fn test_invariants_hold_after_epoch() {
         let mut rt = BleepEconomicsRuntime::genesis();
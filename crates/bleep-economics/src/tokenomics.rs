@@ -25,6 +25,9 @@ pub const MAX_INFLATION_RATE_BPS: u16 = 500; // 5.00% = 500 basis points
 /// Minimum slashing amount (to prevent dust attacks)
 pub const MIN_SLASHING_AMOUNT: u128 = 1_000_000; // 0.01 BLEEP
 
+/// Longest supply forecast `project_supply` will compute, in epochs
+pub const MAX_PROJECTION_EPOCHS: u64 = 10_000;
+
 // ── Distribution model constants (re-exported from distribution module) ───────
 
 /// Validator Rewards allocation: 30% = 60,000,000 BLEEP
@@ -245,8 +248,18 @@ impl EmissionSchedule {
         let mut decayed_rates = std::collections::HashMap::new();
 
         for (&emission_type, &rate) in &self.emission_rates {
-            let decayed = (rate as u64 * (self.decay_factor_bps as u64).pow(decay_epochs as u32) / 10000u64.pow(decay_epochs as u32)) as u16;
-            decayed_rates.insert(emission_type, decayed);
+            // Apply decay one epoch at a time: `decay^n` overflows u64 after a
+            // handful of epochs, which long-range projections reach.
+            let mut decayed = rate as u64;
+            if self.decay_factor_bps < 10000 {
+                for _ in 0..decay_epochs {
+                    if decayed == 0 {
+                        break;
+                    }
+                    decayed = decayed * self.decay_factor_bps as u64 / 10000;
+                }
+            }
+            decayed_rates.insert(emission_type, decayed as u16);
         }
 
         Ok(decayed_rates)
@@ -298,6 +311,9 @@ pub struct CanonicalTokenomicsEngine {
     pub emission_records: BTreeMap<(u64, EmissionType), u128>,
     /// Burn record per epoch and type
     pub burn_records: BTreeMap<(u64, BurnType), u128>,
+    /// Balances minted at block zero, per recipient
+    #[serde(default)]
+    pub genesis_allocations: BTreeMap<String, u128>,
 }
 
 impl CanonicalTokenomicsEngine {
@@ -318,12 +334,38 @@ impl CanonicalTokenomicsEngine {
             historical_states: BTreeMap::new(),
             emission_records: BTreeMap::new(),
             burn_records: BTreeMap::new(),
+            genesis_allocations: BTreeMap::new(),
         };
 
         engine.supply_state.state_hash = engine.supply_state.compute_hash();
         engine
     }
 
+    /// Initialize with balances minted at block zero.
+    ///
+    /// Repeated recipients are summed, so the result does not depend on the
+    /// order of `allocations`. The total counts towards `total_minted` and
+    /// must fit under `MAX_SUPPLY`.
+    pub fn genesis_with_allocations(allocations: &[(String, u128)]) -> Result<Self, TokenomicsError> {
+        let mut engine = Self::genesis();
+
+        let mut total: u128 = GENESIS_SUPPLY;
+        for (recipient, amount) in allocations {
+            let balance = engine.genesis_allocations.entry(recipient.clone()).or_insert(0);
+            *balance = balance.checked_add(*amount).ok_or(TokenomicsError::SupplyCapExceeded)?;
+            total = total.checked_add(*amount).ok_or(TokenomicsError::SupplyCapExceeded)?;
+        }
+        if total > MAX_SUPPLY {
+            return Err(TokenomicsError::SupplyCapExceeded);
+        }
+
+        engine.supply_state.total_minted = total;
+        engine.supply_state.circulating_supply = total;
+        engine.supply_state.verify()?;
+        engine.supply_state.state_hash = engine.supply_state.compute_hash();
+        Ok(engine)
+    }
+
     /// Record emission for an epoch
     pub fn record_emission(
         &mut self,
@@ -382,16 +424,29 @@ impl CanonicalTokenomicsEngine {
             return Err(TokenomicsError::BurnTooSmall);
         }
 
+        self.apply_burn(epoch, burn_type, amount);
+        Ok(())
+    }
+
+    /// Burn stake already removed from validators by slashing
+    ///
+    /// Unlike `record_burn`, any amount is accepted: the stake has left the
+    /// validator set and must leave the circulating supply too.
+    pub fn record_slashed_stake_burn(&mut self, epoch: u64, amount: u128) {
+        if amount > 0 {
+            self.apply_burn(epoch, BurnType::SlashingPenalty, amount);
+        }
+    }
+
+    fn apply_burn(&mut self, epoch: u64, burn_type: BurnType, amount: u128) {
         // Record burn
         let key = (epoch, burn_type);
         let current = self.burn_records.get(&key).unwrap_or(&0);
-        self.burn_records.insert(key, current + amount);
+        self.burn_records.insert(key, current.saturating_add(amount));
 
         // Update supply state
         self.supply_state.total_burned = self.supply_state.total_burned.saturating_add(amount);
         self.supply_state.circulating_supply = self.supply_state.circulating_supply.saturating_sub(amount);
-
-        Ok(())
     }
 
     /// Finalize epoch and create supply state snapshot
@@ -439,6 +494,57 @@ impl CanonicalTokenomicsEngine {
             .map(|epoch| self.burn_records.get(&(epoch, burn_type)).copied().unwrap_or(0))
             .sum()
     }
+
+    /// Forecast supply for the next `epochs_ahead` epochs (deterministic).
+    ///
+    /// Assumes every emission type pays out its full per-epoch allowance under
+    /// the (decayed) emission schedule, and that burns continue at the mean
+    /// per-epoch rate recorded so far. Minting stops at `MAX_SUPPLY`, so every
+    /// projected state passes `SupplyState::verify`. The horizon is capped at
+    /// `MAX_PROJECTION_EPOCHS`.
+    pub fn project_supply(&self, epochs_ahead: u64) -> Vec<SupplyState> {
+        let epochs_ahead = epochs_ahead.min(MAX_PROJECTION_EPOCHS);
+        let recorded_burns: u128 = self.burn_records.values().sum();
+        let burn_per_epoch = recorded_burns / (self.supply_state.epoch as u128 + 1);
+
+        let mut state = self.supply_state.clone();
+        let mut projection = Vec::with_capacity(epochs_ahead as usize);
+
+        for _ in 0..epochs_ahead {
+            let epoch = state.epoch + 1;
+            let base_supply = if state.circulating_supply == 0 {
+                MAX_SUPPLY
+            } else {
+                state.circulating_supply
+            };
+
+            // Same per-type allowance as `record_emission`, in a fixed order
+            let mut rates: Vec<(EmissionType, u16)> = self
+                .emission_schedule
+                .get_emission_rates(epoch)
+                .unwrap_or_default()
+                .into_iter()
+                .collect();
+            rates.sort();
+            let emission: u128 = rates
+                .iter()
+                .map(|&(_, rate)| base_supply.saturating_mul(rate as u128) / 10000)
+                .sum();
+            let minted = emission.min(MAX_SUPPLY - state.total_minted);
+            state.total_minted += minted;
+            state.circulating_supply += minted;
+
+            let burned = burn_per_epoch.min(state.circulating_supply);
+            state.total_burned += burned;
+            state.circulating_supply -= burned;
+
+            state.epoch = epoch;
+            state.state_hash = state.compute_hash();
+            projection.push(state.clone());
+        }
+
+        projection
+    }
 }
 
 #[derive(Debug, Error, Clone, PartialEq)]
@@ -466,6 +572,7 @@ pub enum TokenomicsError {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use tracing::error;
 
     #[test]
     fn test_supply_state_verification() {
@@ -537,4 +644,88 @@ mod tests {
         assert!(!hash.is_empty());
         assert_eq!(engine.historical_states.len(), 1);
     }
+
+    #[test]
+    fn test_genesis_with_allocations() {
+        let allocations = vec![
+            ("treasury".to_string(), 30_000_000),
+            ("team".to_string(), 20_000_000),
+            ("community".to_string(), 15_000_000),
+            ("treasury".to_string(), 5_000_000),
+        ];
+        let engine = CanonicalTokenomicsEngine::genesis_with_allocations(&allocations).unwrap();
+
+        assert_eq!(engine.supply_state.total_minted, 70_000_000);
+        assert_eq!(engine.supply_state.circulating_supply, 70_000_000);
+        assert_eq!(engine.genesis_allocations["treasury"], 35_000_000);
+        assert_eq!(engine.genesis_allocations.values().sum::<u128>(), 70_000_000);
+
+        let econ = crate::integration::BleepEconomics {
+            tokenomics: engine,
+            ..crate::integration::BleepEconomics::genesis()
+        };
+        assert!(econ.verify_epoch_invariants().is_ok());
+
+        // Summing is order-independent
+        let mut reversed = allocations.clone();
+        reversed.reverse();
+        let other = CanonicalTokenomicsEngine::genesis_with_allocations(&reversed).unwrap();
+        assert_eq!(other.supply_state.state_hash, econ.tokenomics.supply_state.state_hash);
+        assert_eq!(other.genesis_allocations, econ.tokenomics.genesis_allocations);
+    }
+
+    #[test]
+    fn test_genesis_allocations_over_cap_rejected() {
+        let allocations = vec![
+            ("treasury".to_string(), MAX_SUPPLY),
+            ("team".to_string(), 1),
+        ];
+        assert_eq!(
+            CanonicalTokenomicsEngine::genesis_with_allocations(&allocations).unwrap_err(),
+            TokenomicsError::SupplyCapExceeded
+        );
+    }
+
+    fn funded_engine(supply: u128) -> CanonicalTokenomicsEngine {
+        let mut engine = CanonicalTokenomicsEngine::genesis();
+        engine.supply_state.circulating_supply = supply;
+        engine.supply_state.total_minted = supply;
+        engine
+    }
+
+    #[test]
+    fn test_project_supply_first_epochs() {
+        let engine = funded_engine(1_000_000);
+        let projection = engine.project_supply(3);
+
+        // 500 BPS total, floored per emission type
+        let circulating: Vec<u128> = projection.iter().map(|s| s.circulating_supply).collect();
+        assert_eq!(circulating, vec![1_050_000, 1_102_500, 1_157_624]);
+        assert_eq!(projection[2].epoch, 3);
+        assert!(projection.iter().all(|s| s.verify_hash().is_ok()));
+        // The engine itself is untouched
+        assert_eq!(engine.supply_state.circulating_supply, 1_000_000);
+    }
+
+    #[test]
+    fn test_project_supply_converges_below_cap() {
+        let mut engine = funded_engine(MAX_SUPPLY / 2);
+        engine.record_burn(0, BurnType::TransactionFee, 1_000_000).unwrap();
+
+        let projection = engine.project_supply(200);
+        assert!(projection.iter().all(|s| s.verify().is_ok()));
+        assert!(projection.windows(2).all(|w| w[1].total_minted >= w[0].total_minted));
+
+        let last = projection.last().unwrap();
+        assert_eq!(last.total_minted, MAX_SUPPLY);
+        assert!(last.circulating_supply < MAX_SUPPLY);
+        assert_eq!(last.total_burned, 1_000_000 * 201);
+    }
+
+    #[test]
+    fn test_project_supply_horizon_is_capped() {
+        let engine = funded_engine(1_000_000);
+        let projection = engine.project_supply(u64::MAX);
+        assert_eq!(projection.len() as u64, MAX_PROJECTION_EPOCHS);
+    }
 }
@@ -5,10 +5,24 @@
 /// Rewards are performance-based, epoch-settled, and proof-dependent.
 
 use serde::{Deserialize, Serialize};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use sha2::{Sha256, Digest};
 use thiserror::Error;
 
+/// Epochs between bonding stake and that stake becoming active
+pub const ACTIVATION_DELAY_EPOCHS: u64 = 2;
+
+/// Epochs unbonding stake stays slashable before it can be withdrawn
+pub const UNBONDING_PERIOD_EPOCHS: u64 = 21;
+
+/// Default cap on stake slashed in one epoch (basis points of active stake),
+/// matching `ProtocolInvariants.max_slashing_per_epoch`
+pub const DEFAULT_MAX_SLASHING_PER_EPOCH_BPS: u16 = 500; // 5%
+
+fn default_max_slashing_per_epoch_bps() -> u16 {
+    DEFAULT_MAX_SLASHING_PER_EPOCH_BPS
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, PartialOrd, Ord)]
 pub enum RewardType {
     /// Block proposal reward
@@ -55,7 +69,7 @@ pub struct ValidatorMetrics {
 }
 
 /// Reward computation for a validator
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct RewardRecord {
     /// Validator ID
     pub validator_id: Vec<u8>,
@@ -137,6 +151,18 @@ impl SlashingViolationType {
     }
 }
 
+/// What a validator's epoch rewards are used for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum RewardPolicy {
+    /// Pay rewards into the liquid (withdrawable) balance
+    #[default]
+    Withdraw,
+    /// Re-bond rewards as stake, subject to the activation delay
+    Rebond,
+    /// Add rewards straight to active stake (raises selection weight at once)
+    Compound,
+}
+
 /// Validator account with staked balance
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ValidatorAccount {
@@ -148,18 +174,32 @@ pub struct ValidatorAccount {
     pub status: ValidatorStatus,
     /// Cumulative rewards earned
     pub total_rewards: u128,
-    /// Cumulative slashes applied
+    /// Cumulative slashes applied, including those taken from unbonding stake
     pub total_slashed: u128,
+    /// Portion of `total_slashed` taken from unbonding stake
+    #[serde(default)]
+    pub unbonding_slashed: u128,
     /// Epoch when status last changed
     pub status_change_epoch: u64,
     /// Number of consecutive epochs jailed
     pub jail_duration_epochs: u32,
+    /// What epoch rewards are used for
+    #[serde(default)]
+    pub reward_policy: RewardPolicy,
+    /// Rewards paid out and available to spend
+    #[serde(default)]
+    pub liquid_balance: u128,
 }
 
 impl ValidatorAccount {
     /// Get effective stake (may be reduced by slashing)
     pub fn effective_stake(&self) -> u128 {
-        self.stake.saturating_sub(self.total_slashed)
+        self.stake.saturating_sub(self.active_slashed())
+    }
+
+    /// Slashes taken from bonded (not unbonding) stake
+    pub fn active_slashed(&self) -> u128 {
+        self.total_slashed.saturating_sub(self.unbonding_slashed)
     }
 
     /// Check if validator is still participating
@@ -173,6 +213,48 @@ impl ValidatorAccount {
     }
 }
 
+/// Stake waiting for its activation epoch
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BondingEntry {
+    /// Validator bonding the stake
+    pub validator_id: Vec<u8>,
+    /// Amount bonded
+    pub amount: u128,
+    /// Epoch at which the stake becomes active
+    pub activation_epoch: u64,
+}
+
+/// Stake leaving the validator set, still slashable until withdrawable
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UnbondingEntry {
+    /// Validator unbonding the stake
+    pub validator_id: Vec<u8>,
+    /// Amount remaining after any slashing during the unbonding period
+    pub amount: u128,
+    /// Epoch at which the stake becomes withdrawable
+    pub withdrawable_epoch: u64,
+}
+
+/// Slashing budget for one epoch
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EpochSlashing {
+    /// Maximum stake that may be slashed this epoch (fixed at the first slash)
+    pub cap: u128,
+    /// Stake slashed so far this epoch
+    pub slashed: u128,
+}
+
+/// Slash postponed because the per-epoch cap was reached
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeferredSlash {
+    /// Validator still owing the slash
+    pub validator_id: Vec<u8>,
+    /// Amount still to be slashed
+    pub amount: u128,
+    /// Proof hash of the originating evidence
+    pub proof_hash: Vec<u8>,
+}
+
 /// Validator incentives engine
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ValidatorIncentivesEngine {
@@ -186,6 +268,27 @@ pub struct ValidatorIncentivesEngine {
     pub slashing_evidence: BTreeMap<Vec<u8>, SlashingEvidence>,
     /// Reward parameters (tunable within bounds)
     pub reward_params: RewardParameters,
+    /// Bonded stake awaiting activation (FIFO)
+    #[serde(default)]
+    pub bonding_queue: Vec<BondingEntry>,
+    /// Unbonding stake awaiting the end of its unbonding period (FIFO)
+    #[serde(default)]
+    pub unbonding_queue: Vec<UnbondingEntry>,
+    /// Stake that finished unbonding and can be withdrawn
+    #[serde(default)]
+    pub withdrawable: BTreeMap<Vec<u8>, u128>,
+    /// Cap on stake slashed per epoch (basis points of active stake)
+    #[serde(default = "default_max_slashing_per_epoch_bps")]
+    pub max_slashing_per_epoch_bps: u16,
+    /// Slashing budget per epoch
+    #[serde(default)]
+    pub epoch_slashing: BTreeMap<u64, EpochSlashing>,
+    /// Slashes carried into later epochs by the cap (FIFO)
+    #[serde(default)]
+    pub deferred_slashing: Vec<DeferredSlash>,
+    /// Epochs whose rewards have been settled by `distribute_epoch_rewards`
+    #[serde(default)]
+    pub rewarded_epochs: BTreeSet<u64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -220,6 +323,140 @@ impl ValidatorIncentivesEngine {
             reward_history: BTreeMap::new(),
             slashing_evidence: BTreeMap::new(),
             reward_params: RewardParameters::default(),
+            bonding_queue: Vec::new(),
+            unbonding_queue: Vec::new(),
+            withdrawable: BTreeMap::new(),
+            max_slashing_per_epoch_bps: DEFAULT_MAX_SLASHING_PER_EPOCH_BPS,
+            epoch_slashing: BTreeMap::new(),
+            deferred_slashing: Vec::new(),
+            rewarded_epochs: BTreeSet::new(),
+        }
+    }
+
+    /// Bond stake; it activates `ACTIVATION_DELAY_EPOCHS` after `current_epoch`.
+    ///
+    /// Unknown validators join the active set when their first bond activates.
+    /// Returns the activation epoch.
+    pub fn bond(
+        &mut self,
+        validator_id: Vec<u8>,
+        amount: u128,
+        current_epoch: u64,
+    ) -> Result<u64, ValidatorError> {
+        if amount == 0 {
+            return Err(ValidatorError::InvalidStake);
+        }
+        if self.validators.get(&validator_id).is_some_and(|v| v.is_ejected()) {
+            return Err(ValidatorError::ValidatorEjected);
+        }
+
+        let activation_epoch = current_epoch.saturating_add(ACTIVATION_DELAY_EPOCHS);
+        self.bonding_queue.push(BondingEntry {
+            validator_id,
+            amount,
+            activation_epoch,
+        });
+
+        Ok(activation_epoch)
+    }
+
+    /// Unbond stake; it stops counting toward active stake immediately but
+    /// stays slashable until `UNBONDING_PERIOD_EPOCHS` after `current_epoch`.
+    ///
+    /// Returns the epoch at which the stake becomes withdrawable.
+    pub fn unbond(
+        &mut self,
+        validator_id: Vec<u8>,
+        amount: u128,
+        current_epoch: u64,
+    ) -> Result<u64, ValidatorError> {
+        if amount == 0 {
+            return Err(ValidatorError::InvalidStake);
+        }
+
+        let validator = self
+            .validators
+            .get_mut(&validator_id)
+            .ok_or(ValidatorError::ValidatorNotFound)?;
+        if amount > validator.effective_stake() {
+            return Err(ValidatorError::InsufficientStake);
+        }
+        validator.stake -= amount;
+
+        let withdrawable_epoch = current_epoch.saturating_add(UNBONDING_PERIOD_EPOCHS);
+        self.unbonding_queue.push(UnbondingEntry {
+            validator_id,
+            amount,
+            withdrawable_epoch,
+        });
+
+        Ok(withdrawable_epoch)
+    }
+
+    /// Process the bonding and unbonding queues for `epoch` (run by epoch
+    /// settlement).
+    ///
+    /// Entries are handled in submission order so every node reaches the
+    /// same validator set.
+    pub fn process_stake_queues(&mut self, epoch: u64) {
+        let (ready, pending): (Vec<_>, Vec<_>) = std::mem::take(&mut self.bonding_queue)
+            .into_iter()
+            .partition(|entry| entry.activation_epoch <= epoch);
+        self.bonding_queue = pending;
+
+        for entry in ready {
+            match self.validators.get_mut(&entry.validator_id) {
+                // Ejected while the bond was pending: return the stake
+                Some(validator) if validator.is_ejected() => {
+                    *self.withdrawable.entry(entry.validator_id).or_insert(0) += entry.amount;
+                }
+                Some(validator) => {
+                    validator.stake = validator.stake.saturating_add(entry.amount);
+                }
+                None => {
+                    self.validators.insert(
+                        entry.validator_id.clone(),
+                        ValidatorAccount {
+                            validator_id: entry.validator_id,
+                            stake: entry.amount,
+                            status: ValidatorStatus::Active,
+                            total_rewards: 0,
+                            total_slashed: 0,
+                            status_change_epoch: epoch,
+                            jail_duration_epochs: 0,
+                            reward_policy: RewardPolicy::Withdraw,
+                            unbonding_slashed: 0,
+                            liquid_balance: 0,
+                        },
+                    );
+                }
+            }
+        }
+
+        let (matured, pending): (Vec<_>, Vec<_>) = std::mem::take(&mut self.unbonding_queue)
+            .into_iter()
+            .partition(|entry| entry.withdrawable_epoch <= epoch);
+        self.unbonding_queue = pending;
+
+        for entry in matured {
+            *self.withdrawable.entry(entry.validator_id).or_insert(0) += entry.amount;
+        }
+    }
+
+    /// Stake still in the unbonding period (and therefore slashable)
+    pub fn unbonding_stake(&self, validator_id: &[u8]) -> u128 {
+        self.unbonding_queue
+            .iter()
+            .filter(|entry| entry.validator_id == validator_id)
+            .map(|entry| entry.amount)
+            .sum()
+    }
+
+    /// Withdraw all stake that has finished unbonding
+    pub fn withdraw(&mut self, validator_id: &[u8]) -> Result<u128, ValidatorError> {
+        match self.withdrawable.remove(validator_id) {
+            Some(amount) if amount > 0 => Ok(amount),
+            _ => Err(ValidatorError::NothingToWithdraw),
         }
     }
 
@@ -247,6 +484,9 @@ impl ValidatorIncentivesEngine {
                 total_slashed: 0,
                 status_change_epoch: 0,
                 jail_duration_epochs: 0,
+                reward_policy: RewardPolicy::Withdraw,
+                unbonding_slashed: 0,
+                liquid_balance: 0,
             },
         );
 
@@ -330,36 +570,210 @@ impl ValidatorIncentivesEngine {
         Ok(records)
     }
 
-    /// Apply slashing for a violation
+    /// Choose what a validator's epoch rewards are used for.
+    pub fn set_reward_policy(
+        &mut self,
+        validator_id: &[u8],
+        policy: RewardPolicy,
+    ) -> Result<(), ValidatorError> {
+        let validator = self
+            .validators
+            .get_mut(validator_id)
+            .ok_or(ValidatorError::ValidatorNotFound)?;
+        validator.reward_policy = policy;
+        Ok(())
+    }
+
+    /// Compute and settle rewards for an epoch.
+    ///
+    /// Each reward is applied under the validator's `RewardPolicy`: paid
+    /// into the liquid balance, re-bonded through the bonding queue, or
+    /// added straight to active stake.
+    ///
+    /// All-or-nothing: on error no validator is paid and the epoch can be
+    /// retried. Each epoch is paid at most once.
+    pub fn distribute_epoch_rewards(
+        &mut self,
+        epoch: u64,
+    ) -> Result<Vec<RewardRecord>, ValidatorError> {
+        if self.rewarded_epochs.contains(&epoch) {
+            return Err(ValidatorError::RewardsAlreadyDistributed(epoch));
+        }
+
+        let mut next = self.clone();
+        let records = next.settle_epoch_rewards(epoch)?;
+        next.rewarded_epochs.insert(epoch);
+        *self = next;
+        Ok(records)
+    }
+
+    fn settle_epoch_rewards(&mut self, epoch: u64) -> Result<Vec<RewardRecord>, ValidatorError> {
+        let records = self.compute_epoch_rewards(epoch)?;
+
+        for record in records.iter().filter(|r| r.total_reward > 0) {
+            let validator = self
+                .validators
+                .get_mut(&record.validator_id)
+                .ok_or(ValidatorError::ValidatorNotFound)?;
+
+            match validator.reward_policy {
+                RewardPolicy::Withdraw => {
+                    validator.liquid_balance = validator.liquid_balance.saturating_add(record.total_reward);
+                }
+                RewardPolicy::Rebond => {
+                    self.bond(record.validator_id.clone(), record.total_reward, epoch)?;
+                }
+                RewardPolicy::Compound => {
+                    validator.stake = validator.stake.saturating_add(record.total_reward);
+                }
+            }
+        }
+
+        Ok(records)
+    }
+
+    /// Apply slashing for a violation.
+    ///
+    /// Returns the amount slashed from bonded and unbonding stake, all of
+    /// which leaves supply and must be burned by the caller.
     pub fn apply_slashing(
         &mut self,
         evidence: SlashingEvidence,
-    ) -> Result<(), ValidatorError> {
-        if let Some(validator) = self.validators.get_mut(&evidence.validator_id) {
-            let slash_amount = evidence.slash_amount;
-
-            // Apply slash (cap at effective stake)
-            let effective = validator.effective_stake();
-            let actual_slash = std::cmp::min(slash_amount, effective);
-            
-            validator.total_slashed = validator.total_slashed.saturating_add(actual_slash);
-
-            // Apply jail if too much slashed
-            if validator.total_slashed >= validator.stake / 3 {
-                validator.status = ValidatorStatus::Jailed;
-                validator.jail_duration_epochs = 2016; // ~1 week at 5min epochs
+    ) -> Result<u128, ValidatorError> {
+        if self.slashing_evidence.contains_key(&evidence.proof_hash) {
+            return Err(ValidatorError::DuplicateEvidence);
+        }
+        let slashed = self.deduct_slash(&evidence.validator_id, evidence.slash_amount)?;
+        self.slashing_evidence.insert(evidence.proof_hash.clone(), evidence);
+        Ok(slashed)
+    }
+
+    /// Apply slashing subject to the per-epoch slashing cap.
+    ///
+    /// At most the epoch's remaining budget is slashed now; the rest is
+    /// deferred and applied by `process_deferred_slashing` in later epochs.
+    /// Evidence is keyed by proof hash and applies at most once. Returns the
+    /// amount slashed in `current_epoch`.
+    pub fn apply_slashing_capped(
+        &mut self,
+        evidence: SlashingEvidence,
+        current_epoch: u64,
+    ) -> Result<u128, ValidatorError> {
+        if !self.validators.contains_key(&evidence.validator_id) {
+            return Err(ValidatorError::ValidatorNotFound);
+        }
+        if self.slashing_evidence.contains_key(&evidence.proof_hash) {
+            return Err(ValidatorError::DuplicateEvidence);
+        }
+
+        let now = std::cmp::min(evidence.slash_amount, self.epoch_slashing_allowance(current_epoch));
+        let slashed = self.slash_in_epoch(&evidence.validator_id, now, current_epoch)?;
+
+        let deferred = evidence.slash_amount - now;
+        if deferred > 0 {
+            self.deferred_slashing.push(DeferredSlash {
+                validator_id: evidence.validator_id.clone(),
+                amount: deferred,
+                proof_hash: evidence.proof_hash.clone(),
+            });
+        }
+
+        self.slashing_evidence.insert(evidence.proof_hash.clone(), evidence);
+        Ok(slashed)
+    }
+
+    /// Apply deferred slashes (oldest first) within `epoch`'s cap, carrying
+    /// whatever does not fit into later epochs. Returns the amount slashed.
+    pub fn process_deferred_slashing(&mut self, epoch: u64) -> Result<u128, ValidatorError> {
+        let mut total = 0u128;
+        let mut carried = Vec::new();
+
+        for mut deferred in std::mem::take(&mut self.deferred_slashing) {
+            let now = std::cmp::min(deferred.amount, self.epoch_slashing_allowance(epoch));
+            if now > 0 {
+                total += self.slash_in_epoch(&deferred.validator_id, now, epoch)?;
+                deferred.amount -= now;
             }
+            if deferred.amount > 0 {
+                carried.push(deferred);
+            }
+        }
+
+        self.deferred_slashing = carried;
+        Ok(total)
+    }
+
+    /// Stake that may still be slashed in `epoch`.
+    ///
+    /// The cap is fixed from active stake at the epoch's first slash, so
+    /// slashing within the epoch cannot shrink or grow it.
+    fn epoch_slashing_allowance(&mut self, epoch: u64) -> u128 {
+        let cap = self.total_active_stake() * self.max_slashing_per_epoch_bps as u128 / 10_000;
+        let budget = self.epoch_slashing
+            .entry(epoch)
+            .or_insert(EpochSlashing { cap, slashed: 0 });
+        budget.cap.saturating_sub(budget.slashed)
+    }
+
+    fn slash_in_epoch(
+        &mut self,
+        validator_id: &[u8],
+        amount: u128,
+        epoch: u64,
+    ) -> Result<u128, ValidatorError> {
+        let slashed = self.deduct_slash(validator_id, amount)?;
+        if let Some(budget) = self.epoch_slashing.get_mut(&epoch) {
+            budget.slashed = budget.slashed.saturating_add(slashed);
+        }
+        Ok(slashed)
+    }
+
+    /// Deduct `slash_amount` from a validator's effective stake, then from
+    /// its unbonding stake (oldest first). Returns the amount deducted.
+    fn deduct_slash(
+        &mut self,
+        validator_id: &[u8],
+        slash_amount: u128,
+    ) -> Result<u128, ValidatorError> {
+        let validator = self
+            .validators
+            .get_mut(validator_id)
+            .ok_or(ValidatorError::ValidatorNotFound)?;
 
-            // Eject if completely slashed
-            if validator.total_slashed >= validator.stake {
-                validator.status = ValidatorStatus::Ejected;
+        // Apply slash (cap at effective stake)
+        let effective = validator.effective_stake();
+        let actual_slash = std::cmp::min(slash_amount, effective);
+
+        validator.total_slashed = validator.total_slashed.saturating_add(actual_slash);
+
+        // Remainder falls on stake still in the unbonding period (oldest first)
+        let mut remaining = slash_amount - actual_slash;
+        for entry in self.unbonding_queue.iter_mut()
+            .filter(|entry| entry.validator_id == validator_id)
+        {
+            if remaining == 0 {
+                break;
             }
+            let taken = std::cmp::min(remaining, entry.amount);
+            entry.amount -= taken;
+            remaining -= taken;
+        }
+        let from_unbonding = slash_amount - actual_slash - remaining;
+        validator.total_slashed = validator.total_slashed.saturating_add(from_unbonding);
+        validator.unbonding_slashed = validator.unbonding_slashed.saturating_add(from_unbonding);
 
-            self.slashing_evidence.insert(evidence.proof_hash.clone(), evidence);
-            Ok(())
-        } else {
-            Err(ValidatorError::ValidatorNotFound)
+        // Apply jail if too much slashed
+        if validator.active_slashed() >= validator.stake / 3 {
+            validator.status = ValidatorStatus::Jailed;
+            validator.jail_duration_epochs = 2016; // ~1 week at 5min epochs
         }
+
+        // Eject if completely slashed
+        if validator.active_slashed() >= validator.stake {
+            validator.status = ValidatorStatus::Ejected;
+        }
+
+        Ok(slash_amount - remaining)
     }
 
     /// Unjail a validator after jail period expires
@@ -426,11 +840,22 @@ pub enum ValidatorError {
     NotJailed,
     #[error("Jail period not expired")]
     JailPeriodNotExpired,
+    #[error("Validator ejected")]
+    ValidatorEjected,
+    #[error("Insufficient stake")]
+    InsufficientStake,
+    #[error("Nothing to withdraw")]
+    NothingToWithdraw,
+    #[error("Slashing evidence already applied")]
+    DuplicateEvidence,
+    #[error("Rewards for epoch {0} already distributed")]
+    RewardsAlreadyDistributed(u64),
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use tracing::error;
 
     #[test]
     fn test_validator_registration() {
@@ -483,6 +908,245 @@ mod tests {
         assert_eq!(validator.status, ValidatorStatus::Jailed);
     }
 
+    #[test]
+    fn test_bonded_stake_activates_after_delay() {
+        let mut engine = ValidatorIncentivesEngine::genesis();
+        let validator_id = vec![7];
+
+        let activation = engine.bond(validator_id.clone(), 1000, 10).unwrap();
+        assert_eq!(activation, 10 + ACTIVATION_DELAY_EPOCHS);
+
+        engine.process_stake_queues(activation - 1);
+        assert!(!engine.validators.contains_key(&validator_id));
+        assert_eq!(engine.total_active_stake(), 0);
+
+        engine.process_stake_queues(activation);
+        assert_eq!(engine.validators[&validator_id].stake, 1000);
+        assert_eq!(engine.total_active_stake(), 1000);
+        assert!(engine.bonding_queue.is_empty());
+    }
+
+    #[test]
+    fn test_unbonding_stake_slashable_then_withdrawable() {
+        let mut engine = ValidatorIncentivesEngine::genesis();
+        let validator_id = vec![9];
+        engine.register_validator(validator_id.clone(), 1000).unwrap();
+
+        let withdrawable_epoch = engine.unbond(validator_id.clone(), 600, 0).unwrap();
+        assert_eq!(withdrawable_epoch, UNBONDING_PERIOD_EPOCHS);
+        assert_eq!(engine.total_active_stake(), 400);
+        assert_eq!(engine.unbonding_stake(&validator_id), 600);
+
+        // Misbehaviour during the unbonding period reaches unbonding stake
+        let slashed = engine.apply_slashing(SlashingEvidence {
+            validator_id: validator_id.clone(),
+            epoch: 5,
+            violation_type: SlashingViolationType::DoubleSigning,
+            slash_amount: 500,
+            proof_hash: vec![1],
+            disputed: false,
+        }).unwrap();
+        assert_eq!(slashed, 500);
+        assert_eq!(engine.validators[&validator_id].effective_stake(), 0);
+        assert_eq!(engine.unbonding_stake(&validator_id), 500);
+        // Both parts are recorded against the validator
+        assert_eq!(engine.validators[&validator_id].total_slashed, 500);
+        assert_eq!(engine.validators[&validator_id].unbonding_slashed, 100);
+
+        engine.process_stake_queues(withdrawable_epoch - 1);
+        assert_eq!(engine.withdraw(&validator_id), Err(ValidatorError::NothingToWithdraw));
+
+        engine.process_stake_queues(withdrawable_epoch);
+        assert_eq!(engine.unbonding_stake(&validator_id), 0);
+        assert_eq!(engine.withdraw(&validator_id), Ok(500));
+        assert_eq!(engine.withdraw(&validator_id), Err(ValidatorError::NothingToWithdraw));
+    }
+
+    #[test]
+    fn test_compounding_rewards_rebond_as_stake() {
+        let mut engine = ValidatorIncentivesEngine::genesis();
+        let compounder = vec![1];
+        let payee = vec![2];
+
+        for id in [&compounder, &payee] {
+            engine.register_validator(id.clone(), 1000).unwrap();
+            engine.record_metrics(id.clone(), ValidatorMetrics {
+                validator_id: id.clone(),
+                blocks_proposed: 1,
+                attestations_included: 1,
+                healing_participations: 0,
+                shards_coordinated: 0,
+                double_signs_evidence: 0,
+                state_transition_failures: 0,
+                epoch: 0,
+            }).unwrap();
+        }
+        engine.set_reward_policy(&compounder, RewardPolicy::Rebond).unwrap();
+
+        let reward = engine.reward_params.block_proposal_reward + engine.reward_params.participation_reward;
+
+        for epoch in 0..6u64 {
+            engine.distribute_epoch_rewards(epoch).unwrap();
+            engine.process_stake_queues(epoch);
+
+            // Each reward activates ACTIVATION_DELAY_EPOCHS after it is earned
+            let activated = (epoch + 1).saturating_sub(ACTIVATION_DELAY_EPOCHS) as u128;
+            assert_eq!(engine.validators[&compounder].stake, 1000 + activated * reward);
+            assert_eq!(engine.validators[&compounder].liquid_balance, 0);
+
+            assert_eq!(engine.validators[&payee].stake, 1000);
+            assert_eq!(engine.validators[&payee].liquid_balance, (epoch as u128 + 1) * reward);
+        }
+    }
+
+    #[test]
+    fn test_reward_policy_controls_selection_weight() {
+        let mut engine = ValidatorIncentivesEngine::genesis();
+        let compounder = vec![1];
+        let withdrawer = vec![2];
+
+        for id in [&compounder, &withdrawer] {
+            engine.register_validator(id.clone(), 1000).unwrap();
+            engine.record_metrics(id.clone(), ValidatorMetrics {
+                validator_id: id.clone(),
+                blocks_proposed: 1,
+                attestations_included: 1,
+                healing_participations: 0,
+                shards_coordinated: 0,
+                double_signs_evidence: 0,
+                state_transition_failures: 0,
+                epoch: 0,
+            }).unwrap();
+        }
+        engine.set_reward_policy(&compounder, RewardPolicy::Compound).unwrap();
+        engine.set_reward_policy(&withdrawer, RewardPolicy::Withdraw).unwrap();
+        assert_eq!(engine.validators[&compounder].reward_policy, RewardPolicy::Compound);
+        assert_eq!(engine.validators[&withdrawer].reward_policy, RewardPolicy::Withdraw);
+        assert_eq!(
+            engine.set_reward_policy(&[9], RewardPolicy::Compound),
+            Err(ValidatorError::ValidatorNotFound)
+        );
+
+        let reward = engine.reward_params.block_proposal_reward + engine.reward_params.participation_reward;
+
+        for epoch in 0..5u64 {
+            engine.distribute_epoch_rewards(epoch).unwrap();
+
+            // Compounded rewards count towards active (selection) stake in the
+            // same epoch, with no bonding delay; paid-out rewards only towards
+            // the withdrawable balance
+            let earned = (epoch as u128 + 1) * reward;
+            assert_eq!(engine.validators[&compounder].effective_stake(), 1000 + earned);
+            assert_eq!(engine.total_active_stake(), 2000 + earned);
+            assert!(engine.bonding_queue.is_empty());
+
+            assert_eq!(engine.validators[&withdrawer].effective_stake(), 1000);
+            assert_eq!(engine.validators[&withdrawer].liquid_balance, earned);
+            assert_eq!(engine.validators[&compounder].liquid_balance, 0);
+        }
+    }
+
+    #[test]
+    fn test_epoch_rewards_paid_once_and_atomically() {
+        let mut engine = ValidatorIncentivesEngine::genesis();
+        let payee = vec![1];
+        let compounder = vec![2];
+
+        for id in [&payee, &compounder] {
+            engine.register_validator(id.clone(), 1000).unwrap();
+            engine.record_metrics(id.clone(), ValidatorMetrics {
+                validator_id: id.clone(),
+                blocks_proposed: 1,
+                attestations_included: 1,
+                healing_participations: 0,
+                shards_coordinated: 0,
+                double_signs_evidence: 0,
+                state_transition_failures: 0,
+                epoch: 0,
+            }).unwrap();
+        }
+        engine.set_reward_policy(&compounder, RewardPolicy::Rebond).unwrap();
+
+        // The compounder cannot re-bond once ejected; the payee, settled
+        // first, must not keep its reward either
+        engine.validators.get_mut(&compounder).unwrap().status = ValidatorStatus::Ejected;
+        assert_eq!(engine.distribute_epoch_rewards(0), Err(ValidatorError::ValidatorEjected));
+        assert_eq!(engine.validators[&payee].liquid_balance, 0);
+        assert_eq!(engine.validators[&payee].total_rewards, 0);
+        assert!(engine.reward_history.is_empty());
+
+        // The failed epoch can be retried, then never paid again
+        engine.set_reward_policy(&compounder, RewardPolicy::Withdraw).unwrap();
+        let records = engine.distribute_epoch_rewards(0).unwrap();
+        let paid = engine.validators[&payee].liquid_balance;
+        assert_eq!(paid, records[0].total_reward);
+        assert_eq!(
+            engine.distribute_epoch_rewards(0),
+            Err(ValidatorError::RewardsAlreadyDistributed(0))
+        );
+        assert_eq!(engine.validators[&payee].liquid_balance, paid);
+        assert!(engine.distribute_epoch_rewards(1).is_ok());
+    }
+
+    fn slash(validator_id: &[u8], amount: u128, proof: u8) -> SlashingEvidence {
+        SlashingEvidence {
+            validator_id: validator_id.to_vec(),
+            epoch: 1,
+            violation_type: SlashingViolationType::InvalidTransition,
+            slash_amount: amount,
+            proof_hash: vec![proof],
+            disputed: false,
+        }
+    }
+
+    #[test]
+    fn test_large_slash_capped_and_deferred() {
+        let mut engine = ValidatorIncentivesEngine::genesis();
+        engine.register_validator(vec![1], 1000).unwrap();
+        engine.register_validator(vec![2], 1000).unwrap();
+
+        // 5% of 2000 active stake
+        assert_eq!(engine.apply_slashing_capped(slash(&[1], 250, 1), 1), Ok(100));
+        assert_eq!(engine.epoch_slashing[&1], EpochSlashing { cap: 100, slashed: 100 });
+        assert_eq!(engine.validators[&vec![1]].total_slashed, 100);
+        assert_eq!(engine.deferred_slashing[0].amount, 150);
+
+        // Epoch 2 cap: 5% of 1900
+        assert_eq!(engine.process_deferred_slashing(2), Ok(95));
+        assert_eq!(engine.deferred_slashing[0].amount, 55);
+
+        // Epoch 3 settles the remainder
+        assert_eq!(engine.process_deferred_slashing(3), Ok(55));
+        assert!(engine.deferred_slashing.is_empty());
+        assert_eq!(engine.validators[&vec![1]].total_slashed, 250);
+        assert!(engine.epoch_slashing.values().all(|b| b.slashed <= b.cap));
+    }
+
+    #[test]
+    fn test_small_slashes_sum_to_cap() {
+        let mut engine = ValidatorIncentivesEngine::genesis();
+        engine.register_validator(vec![1], 1000).unwrap();
+        engine.register_validator(vec![2], 1000).unwrap();
+
+        for (i, id) in [[1u8], [2], [1], [2]].iter().enumerate() {
+            assert_eq!(engine.apply_slashing_capped(slash(id, 25, i as u8), 1), Ok(25));
+        }
+        assert_eq!(engine.epoch_slashing[&1].slashed, engine.epoch_slashing[&1].cap);
+        assert!(engine.deferred_slashing.is_empty());
+
+        // Budget exhausted: the next slash is deferred in full
+        assert_eq!(engine.apply_slashing_capped(slash(&[2], 10, 9), 1), Ok(0));
+        assert_eq!(engine.deferred_slashing.len(), 1);
+        assert_eq!(engine.process_deferred_slashing(1), Ok(0));
+        assert_eq!(engine.process_deferred_slashing(2), Ok(10));
+        assert_eq!(engine.apply_slashing_capped(slash(&[3], 10, 10), 2), Err(ValidatorError::ValidatorNotFound));
+
+        // Resubmitted evidence is not slashed again
+        let slashed = engine.validators[&vec![1]].total_slashed;
+        assert_eq!(engine.apply_slashing_capped(slash(&[1], 10, 0), 3), Err(ValidatorError::DuplicateEvidence));
+        assert_eq!(engine.validators[&vec![1]].total_slashed, slashed);
+    }
+
     #[test]
     fn test_total_stake() {
         let mut engine = ValidatorIncentivesEngine::genesis();
@@ -14,10 +14,12 @@
 use crate::apip::{APIP, RiskLevel, RuleChange, AIModelMetadata};
 use crate::protocol_rules::{ProtocolRuleSet, RuleVersion};
 use crate::ai_reputation::AIReputationTracker;
+use crate::proposal_lifecycle::{ProposalRecord, ProposalState};
+use bleep_ai::ConsensusMetrics;
 use log::{info, warn};
 use thiserror::Error;
 use serde::{Serialize, Deserialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 #[derive(Debug, Error)]
 pub enum AIHookError {
@@ -67,6 +69,26 @@ pub struct HistoricalAnalysis {
     
     /// Supporting data points
     pub data_points: Vec<(u64, f64)>, // (epoch, value)
+    
+    /// Proposal outcome statistics (for proposal-archive analyses)
+    #[serde(default)]
+    pub proposal_history: Option<ProposalHistoryStats>,
+}
+
+/// Advisory statistics over past proposal outcomes
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ProposalHistoryStats {
+    /// Number of proposals analyzed
+    pub total_proposals: usize,
+    
+    /// Approved / decided, per constitutional scope [0, 1]
+    pub approval_rate_by_scope: BTreeMap<String, f64>,
+    
+    /// Mean epochs from creation to execution, over executed proposals
+    pub avg_epochs_to_execution: f64,
+    
+    /// Fraction of executed proposals later rolled back [0, 1]
+    pub rollback_rate: f64,
 }
 
 /// Deterministic simulation result
@@ -89,6 +111,44 @@ pub struct SimulationResult {
     
     /// Recommendation (advisory only)
     pub recommendation: String,
+    
+    /// Estimated impact on finality latency
+    #[serde(default)]
+    pub finality_impact: i32, // Percentage change, negative = faster finality
+    
+    /// Whether the change is flagged as risky
+    #[serde(default)]
+    pub high_risk: bool,
+}
+
+/// Deterministic profile of a consensus mode, used by mode-switch simulation
+#[derive(Debug, Clone, Copy)]
+struct ConsensusModeProfile {
+    /// Blocks until a block is considered final
+    finality_blocks: u64,
+    
+    /// Relative throughput (PoS = 100)
+    throughput_pct: i64,
+    
+    /// Finality is deterministic rather than probabilistic
+    deterministic_finality: bool,
+}
+
+/// Signed percentage change from `from` to `to`, clamped to the `i32` range
+fn percent_change(from: i64, to: i64) -> i32 {
+    let pct = to.saturating_sub(from).saturating_mul(100) / from.max(1);
+    pct.clamp(i32::MIN as i64, i32::MAX as i64) as i32
+}
+
+impl ConsensusModeProfile {
+    fn for_mode(mode: &str) -> Option<Self> {
+        match mode.to_ascii_uppercase().as_str() {
+            "POS" => Some(ConsensusModeProfile { finality_blocks: 32, throughput_pct: 100, deterministic_finality: false }),
+            "PBFT" => Some(ConsensusModeProfile { finality_blocks: 1, throughput_pct: 80, deterministic_finality: true }),
+            "POW" => Some(ConsensusModeProfile { finality_blocks: 60, throughput_pct: 40, deterministic_finality: false }),
+            _ => None,
+        }
+    }
 }
 
 /// AI-provided optimization suggestion
@@ -184,6 +244,7 @@ impl AIHooks {
             trend: trend.to_string(),
             recommendation: None,
             data_points,
+            proposal_history: None,
         };
         
         self.analyses.insert(metric_name, analysis.clone());
@@ -193,6 +254,97 @@ impl AIHooks {
         Ok(analysis)
     }
     
+    /// HOOK 1b: Summarize past proposal outcomes
+    /// 
+    /// `current_value` is the overall approval rate and `data_points` holds
+    /// one (creation epoch, 1.0 approved / 0.0 not) entry per decided
+    /// proposal, oldest first. Proposals still in flight count towards
+    /// `total_proposals` only.
+    /// 
+    /// SAFETY: Off-chain only, no state modification, deterministic
+    pub fn analyze_history(&self, records: &[ProposalRecord]) -> HistoricalAnalysis {
+        let mut ordered: Vec<&ProposalRecord> = records.iter().collect();
+        ordered.sort_by(|a, b| (a.creation_epoch, &a.id).cmp(&(b.creation_epoch, &b.id)));
+        
+        // scope -> (approved, decided)
+        let mut by_scope: BTreeMap<String, (u64, u64)> = BTreeMap::new();
+        let mut data_points = Vec::new();
+        let mut executed = 0u64;
+        let mut execution_epochs = 0u64;
+        let mut rolled_back = 0u64;
+        
+        for record in &ordered {
+            let approved = match record.state {
+                ProposalState::ActivationScheduled | ProposalState::Executed => true,
+                ProposalState::Rejected | ProposalState::Expired => false,
+                _ => continue,
+            };
+            let counts = by_scope.entry(format!("{:?}", record.action.scope)).or_insert((0, 0));
+            counts.1 += 1;
+            if approved {
+                counts.0 += 1;
+            }
+            data_points.push((record.creation_epoch, if approved { 1.0 } else { 0.0 }));
+            
+            if record.state == ProposalState::Executed {
+                executed += 1;
+                if let Some(epoch) = record.execution_epoch() {
+                    execution_epochs += epoch.saturating_sub(record.creation_epoch);
+                }
+                if record.rolled_back_epoch.is_some() {
+                    rolled_back += 1;
+                }
+            }
+        }
+        
+        let ratio = |num: u64, den: u64| if den == 0 { 0.0 } else { num as f64 / den as f64 };
+        let mean = |points: &[(u64, f64)]| {
+            if points.is_empty() { 0.0 } else { points.iter().map(|p| p.1).sum::<f64>() / points.len() as f64 }
+        };
+        
+        let stats = ProposalHistoryStats {
+            total_proposals: records.len(),
+            approval_rate_by_scope: by_scope
+                .iter()
+                .map(|(scope, &(approved, decided))| (scope.clone(), ratio(approved, decided)))
+                .collect(),
+            avg_epochs_to_execution: ratio(execution_epochs, executed),
+            rollback_rate: ratio(rolled_back, executed),
+        };
+        
+        // Trend: older half vs newer half of decided proposals
+        let (older, newer) = data_points.split_at(data_points.len() / 2);
+        let trend = if older.is_empty() {
+            "STABLE"
+        } else if mean(newer) > mean(older) * 1.1 {
+            "POSITIVE"
+        } else if mean(newer) < mean(older) * 0.9 {
+            "NEGATIVE"
+        } else {
+            "STABLE"
+        };
+        
+        let recommendation = if stats.rollback_rate > 0.1 {
+            Some("Rollback rate above 10%: require longer simulation before activation".to_string())
+        } else {
+            None
+        };
+        
+        info!(
+            "AI proposal history analysis: {} proposals, rollback_rate={:.2}",
+            stats.total_proposals, stats.rollback_rate
+        );
+        
+        HistoricalAnalysis {
+            metric_name: "proposal_outcomes".to_string(),
+            current_value: mean(&data_points),
+            trend: trend.to_string(),
+            recommendation,
+            data_points,
+            proposal_history: Some(stats),
+        }
+    }
+    
     /// HOOK 2: Simulate worst-case scenarios (deterministically)
     /// 
     /// SAFETY: No randomness, deterministic simulation
@@ -265,6 +417,8 @@ impl AIHooks {
             } else {
                 "NEUTRAL".to_string()
             },
+            finality_impact: 0,
+            high_risk: safety_impact < -10,
         };
         
         self.simulations.insert(proposal.proposal_id.clone(), result.clone());
@@ -277,6 +431,91 @@ impl AIHooks {
         Ok(result)
     }
     
+    /// HOOK 2b: Dry-run a consensus mode switch (deterministically)
+    /// 
+    /// Projects finality latency and throughput for `to` against `from`
+    /// under the observed `metrics`, ahead of any governance vote.
+    /// 
+    /// SAFETY: Advisory only, no state modification (not even recorded)
+    pub fn simulate_mode_switch(
+        &self,
+        from: &str,
+        to: &str,
+        metrics: &ConsensusMetrics,
+    ) -> SimulationResult {
+        let proposal_id = format!("MODE_SWITCH_{}_{}", from, to);
+        
+        let (from_profile, to_profile) = match (
+            ConsensusModeProfile::for_mode(from),
+            ConsensusModeProfile::for_mode(to),
+        ) {
+            (Some(f), Some(t)) => (f, t),
+            _ => {
+                warn!("AI mode-switch simulation: unknown consensus mode {} -> {}", from, to);
+                return SimulationResult {
+                    proposal_id,
+                    performance_impact: 0,
+                    safety_impact: 0,
+                    worst_case_description: "Unknown consensus mode, impact cannot be estimated".to_string(),
+                    best_case_description: String::new(),
+                    recommendation: "RECOMMEND_REJECTION".to_string(),
+                    finality_impact: 0,
+                    high_risk: true,
+                };
+            }
+        };
+        
+        let block_time = metrics.avg_block_time_ms;
+        // Metrics are caller-supplied, so saturate rather than overflow
+        let from_latency = from_profile.finality_blocks.saturating_mul(block_time);
+        let to_latency = to_profile.finality_blocks.saturating_mul(block_time);
+        
+        let finality_impact = percent_change(from_profile.finality_blocks as i64, to_profile.finality_blocks as i64);
+        let performance_impact = percent_change(from_profile.throughput_pct, to_profile.throughput_pct);
+        let safety_impact = match (from_profile.deterministic_finality, to_profile.deterministic_finality) {
+            (false, true) => 10,
+            (true, false) => -10,
+            _ => 0,
+        };
+        
+        // Failed proposals per 100 blocks: a churning proposer set hurts
+        // round-based protocols most
+        let failure_pct = metrics.failed_proposals.saturating_mul(100) / metrics.blocks_per_epoch.max(1);
+        let high_risk = performance_impact <= -30
+            || safety_impact < 0
+            || (to_profile.deterministic_finality && failure_pct >= 10);
+        
+        let result = SimulationResult {
+            proposal_id,
+            performance_impact,
+            safety_impact,
+            worst_case_description: format!(
+                "Throughput {:+}%, {} failed proposals per 100 blocks carried into {}",
+                performance_impact, failure_pct, to
+            ),
+            best_case_description: format!(
+                "Finality latency {}ms -> {}ms ({:+}%)",
+                from_latency, to_latency, finality_impact
+            ),
+            recommendation: if high_risk {
+                "RECOMMEND_REJECTION".to_string()
+            } else if finality_impact < 0 || performance_impact > 0 {
+                "RECOMMEND_APPROVAL".to_string()
+            } else {
+                "NEUTRAL".to_string()
+            },
+            finality_impact,
+            high_risk,
+        };
+        
+        info!(
+            "AI mode-switch simulation {} -> {}: finality={}%, throughput={}%, high_risk={}",
+            from, to, result.finality_impact, result.performance_impact, result.high_risk
+        );
+        
+        result
+    }
+    
     /// HOOK 3: Suggest protocol optimizations (advisory)
     /// 
     /// SAFETY: Only suggestions, requires governance approval to implement
@@ -431,6 +670,8 @@ mod tests {
     use super::*;
     use crate::protocol_rules::ProtocolRuleSetFactory;
     use crate::apip::APIPBuilder;
+    use crate::constitution::{ConstitutionalScope, GovernanceAction};
+    use crate::proposal_lifecycle::ProposalStateTransition;
 
     #[test]
     fn test_ai_hooks_creation() {
@@ -449,6 +690,130 @@ mod tests {
         assert_eq!(analysis.current_value, 125.0);
     }
 
+    fn archived_proposal(
+        id: &str,
+        scope: ConstitutionalScope,
+        state: ProposalState,
+        creation_epoch: u64,
+        executed_at: Option<u64>,
+    ) -> ProposalRecord {
+        let action = GovernanceAction::new(
+            format!("action_{}", id),
+            scope,
+            vec!["governance".to_string()],
+            "Archived action".to_string(),
+        );
+        let mut record = ProposalRecord::new(id.to_string(), "proposer".to_string(), action, creation_epoch).unwrap();
+        record.state = state;
+        if let Some(epoch) = executed_at {
+            record.state_history.push(ProposalStateTransition {
+                from_state: ProposalState::ActivationScheduled,
+                to_state: ProposalState::Executed,
+                epoch,
+                block_height: 0,
+                transition_hash: vec![],
+            });
+        }
+        record
+    }
+
+    #[test]
+    fn test_analyze_history_rates() {
+        let hooks = AIHooks::new("TestAI".to_string(), "1.0".to_string());
+        
+        let mut rolled_back = archived_proposal("p3", ConstitutionalScope::Protocol, ProposalState::Executed, 4, Some(14));
+        rolled_back.mark_rolled_back(20).unwrap();
+        let records = vec![
+            archived_proposal("p1", ConstitutionalScope::Governance, ProposalState::Executed, 0, Some(6)),
+            archived_proposal("p2", ConstitutionalScope::Governance, ProposalState::Rejected, 2, None),
+            rolled_back,
+            archived_proposal("p4", ConstitutionalScope::Protocol, ProposalState::Expired, 6, None),
+            archived_proposal("p5", ConstitutionalScope::Protocol, ProposalState::Executed, 8, Some(12)),
+            archived_proposal("p6", ConstitutionalScope::Governance, ProposalState::Voting, 9, None),
+        ];
+        
+        let analysis = hooks.analyze_history(&records);
+        let stats = analysis.proposal_history.clone().unwrap();
+        assert_eq!(stats.total_proposals, 6);
+        assert_eq!(stats.approval_rate_by_scope["Governance"], 0.5);
+        assert!((stats.approval_rate_by_scope["Protocol"] - 2.0 / 3.0).abs() < 1e-9);
+        // (6 + 10 + 4) / 3
+        assert!((stats.avg_epochs_to_execution - 20.0 / 3.0).abs() < 1e-9);
+        assert!((stats.rollback_rate - 1.0 / 3.0).abs() < 1e-9);
+        assert_eq!(analysis.current_value, 0.6);
+        assert_eq!(analysis.data_points.len(), 5);
+        assert!(analysis.recommendation.is_some());
+        
+        // Archive order does not matter
+        let mut reversed = records.clone();
+        reversed.reverse();
+        assert_eq!(hooks.analyze_history(&reversed).proposal_history, Some(stats));
+    }
+
+    #[test]
+    fn test_analyze_history_empty_archive() {
+        let hooks = AIHooks::new("TestAI".to_string(), "1.0".to_string());
+        
+        let analysis = hooks.analyze_history(&[]);
+        assert_eq!(analysis.current_value, 0.0);
+        assert_eq!(analysis.trend, "STABLE");
+        assert!(analysis.data_points.is_empty());
+        assert_eq!(analysis.proposal_history, Some(ProposalHistoryStats::default()));
+    }
+
+    fn observed_metrics(failed_proposals: u64) -> ConsensusMetrics {
+        ConsensusMetrics {
+            blocks_per_epoch: 100,
+            avg_block_time_ms: 2_000,
+            consensus_rounds: 1,
+            failed_proposals,
+        }
+    }
+
+    #[test]
+    fn test_mode_switch_pos_to_pbft_improves_finality() {
+        let hooks = AIHooks::new("TestAI".to_string(), "1.0".to_string());
+        
+        let result = hooks.simulate_mode_switch("PoS", "PBFT", &observed_metrics(2));
+        assert!(result.finality_impact < 0);
+        assert_eq!(result.finality_impact, -96);
+        assert_eq!(result.performance_impact, -20);
+        assert!(!result.high_risk);
+        assert_eq!(result.recommendation, "RECOMMEND_APPROVAL");
+        // Advisory only: nothing is recorded
+        assert!(hooks.simulations.is_empty());
+        
+        // An unstable proposer set flags the same switch as risky
+        assert!(hooks.simulate_mode_switch("PoS", "PBFT", &observed_metrics(15)).high_risk);
+        assert!(hooks.simulate_mode_switch("PoS", "Unknown", &observed_metrics(0)).high_risk);
+    }
+
+    #[test]
+    fn test_mode_switch_simulation_deterministic() {
+        let hooks = AIHooks::new("TestAI".to_string(), "1.0".to_string());
+        let metrics = observed_metrics(5);
+        
+        let a = hooks.simulate_mode_switch("PBFT", "PoW", &metrics);
+        let b = hooks.simulate_mode_switch("PBFT", "PoW", &metrics);
+        assert_eq!(serde_json::to_string(&a).unwrap(), serde_json::to_string(&b).unwrap());
+        assert!(a.high_risk);
+    }
+
+    #[test]
+    fn test_mode_switch_saturates_on_extreme_metrics() {
+        let hooks = AIHooks::new("TestAI".to_string(), "1.0".to_string());
+        let metrics = ConsensusMetrics {
+            blocks_per_epoch: 1,
+            avg_block_time_ms: u64::MAX,
+            consensus_rounds: 1,
+            failed_proposals: u64::MAX,
+        };
+        
+        let result = hooks.simulate_mode_switch("PoS", "PoW", &metrics);
+        assert_eq!(result.finality_impact, 87);
+        assert!(result.best_case_description.contains(&u64::MAX.to_string()));
+    }
+
     #[test]
     fn test_compliance_assessment() {
         let hooks = AIHooks::new("TestAI".to_string(), "1.0".to_string());
@@ -14,6 +14,15 @@ use log::{info, warn};
 use std::collections::HashMap;
 use thiserror::Error;
 
+/// Score every model starts from, and decays back toward
+pub const NEUTRAL_REPUTATION: i64 = 500;
+
+/// Minimum score for a model's advice to be considered usable
+pub const MIN_USABLE_REPUTATION: i64 = 400;
+
+/// Fixed-point unit for decay weights (parts per billion)
+const DECAY_WEIGHT_UNIT: u128 = 1_000_000_000;
+
 #[derive(Debug, Error)]
 pub enum ReputationError {
     #[error("Model not found: {0}")]
@@ -163,7 +172,7 @@ impl AIReputation {
     pub fn new(model_id: String) -> Self {
         AIReputation {
             model_id,
-            score: 500, // Start with neutral score
+            score: NEUTRAL_REPUTATION,
             total_proposals: 0,
             accepted_count: 0,
             rejected_count: 0,
@@ -229,21 +238,47 @@ impl AIReputation {
     /// Apply exponential decay to reputation
     /// 
     /// SAFETY: Decay is deterministic, epoch-based
-    /// Older proposals have diminishing impact over time
+    /// Older proposals have diminishing impact over time: the score is
+    /// rebuilt from neutral, weighting each outcome by
+    /// `(decay_rate / 100) ^ age`. Old failures fade, so a model with
+    /// sustained recent successes recovers. Integer fixed-point, so the
+    /// same epoch yields the same score on every node.
     pub fn apply_decay(&mut self, current_epoch: u64, decay_rate: u64) {
-        let epochs_elapsed = current_epoch.saturating_sub(self.last_updated_epoch);
+        let decayed: i64 = self.records.iter()
+            .map(|record| Self::decayed_impact(record, current_epoch, decay_rate))
+            .sum();
         
-        if epochs_elapsed == 0 {
-            return;
+        self.score = (NEUTRAL_REPUTATION + decayed).clamp(0, 10_000);
+    }
+    
+    /// A record's reputation impact after decaying to `current_epoch`
+    pub fn decayed_impact(record: &ReputationRecord, current_epoch: u64, decay_rate: u64) -> i64 {
+        let age = current_epoch.saturating_sub(record.outcome_epoch);
+        let weight = decay_weight(decay_rate, age) as i128;
+        (record.reputation_impact as i128 * weight / DECAY_WEIGHT_UNIT as i128) as i64
+    }
+    
+    /// Whether the score is high enough for advice to be used
+    pub fn is_usable(&self) -> bool {
+        self.score >= MIN_USABLE_REPUTATION
+    }
+}
+
+/// `(decay_rate / 100) ^ age` in parts per billion, by repeated squaring
+/// (rates above 100 are treated as no decay)
+fn decay_weight(decay_rate: u64, mut age: u64) -> u128 {
+    let mut base = decay_rate.min(100) as u128 * DECAY_WEIGHT_UNIT / 100;
+    let mut weight = DECAY_WEIGHT_UNIT;
+    
+    while age > 0 && weight > 0 {
+        if age & 1 == 1 {
+            weight = weight * base / DECAY_WEIGHT_UNIT;
         }
-        
-        // Decay formula: score *= (decay_rate / 100) ^ epochs_elapsed
-        // Example: decay_rate=95 means 5% decay per epoch
-        let decay_multiplier = (decay_rate as f64) / 100.0;
-        let new_score = (self.score as f64) * decay_multiplier.powi(epochs_elapsed as i32);
-        
-        self.score = new_score.max(0.0) as i64;
+        base = base * base / DECAY_WEIGHT_UNIT;
+        age >>= 1;
     }
+    
+    weight
 }
 
 /// Global AI reputation tracking system
@@ -513,4 +548,60 @@ mod tests {
         assert_eq!(rep.total_proposals, 1);
         assert_eq!(rep.accepted_count, 1);
     }
+
+    #[test]
+    fn test_old_failure_decays() {
+        let mut rep = AIReputation::new("model-1".to_string());
+        let mut record = ReputationRecord::new("APIP-001".to_string(), "model-1".to_string(), 0, 90);
+        record.record_outcome(ProposalOutcome::RolledBack);
+        rep.record_proposal_outcome(record, 0);
+        assert_eq!(rep.score, NEUTRAL_REPUTATION - 180);
+        
+        let impact_at = |epoch| AIReputation::decayed_impact(&rep.records[0], epoch, 95);
+        assert_eq!(impact_at(0), -180);
+        assert_eq!(impact_at(1), -171);
+        assert!(impact_at(10) > impact_at(1));
+        assert!(impact_at(100) > -2);
+        
+        rep.apply_decay(100, 95);
+        assert!(rep.score >= NEUTRAL_REPUTATION - 1);
+        
+        // Same epoch, same score
+        let score = rep.score;
+        rep.apply_decay(100, 95);
+        assert_eq!(rep.score, score);
+    }
+
+    #[test]
+    fn test_recent_successes_recover_reputation() {
+        let mut tracker = AIReputationTracker::new();
+        for epoch in 1..=3 {
+            tracker.record_outcome(
+                format!("APIP-F{}", epoch),
+                "model-1".to_string(),
+                ProposalOutcome::RolledBack,
+                90,
+                epoch,
+                epoch,
+            ).unwrap();
+        }
+        tracker.apply_global_decay(3, 95);
+        assert!(!tracker.get_reputation("model-1").unwrap().is_usable());
+        
+        for epoch in 10..=30 {
+            tracker.record_outcome(
+                format!("APIP-S{}", epoch),
+                "model-1".to_string(),
+                ProposalOutcome::Accepted,
+                80,
+                epoch,
+                epoch,
+            ).unwrap();
+        }
+        tracker.apply_global_decay(30, 95);
+        
+        let rep = tracker.get_reputation("model-1").unwrap();
+        assert!(rep.is_usable());
+        assert!(rep.score > NEUTRAL_REPUTATION);
+    }
 }
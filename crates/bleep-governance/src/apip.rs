@@ -239,6 +239,10 @@ pub struct APIP {
     /// Risk description explaining why this risk level
     pub risk_description: String,
     
+    /// Approval percentage governance must reach, set from `risk_level`
+    #[serde(default)]
+    pub required_approval_threshold: u64,
+    
     /// Estimated impact (brief description)
     pub expected_impact: String,
     
@@ -308,6 +312,7 @@ impl APIP {
             confidence_score: 0,
             risk_level: RiskLevel::Medium,
             risk_description: String::new(),
+            required_approval_threshold: RiskLevel::Medium.min_approval_threshold(),
             expected_impact: String::new(),
             rollback_strategy: String::new(),
             ai_signature: Vec::new(),
@@ -363,9 +368,18 @@ impl APIP {
         }
         self.risk_level = level;
         self.risk_description = description;
+        self.required_approval_threshold = level.min_approval_threshold();
         Ok(())
     }
     
+    /// Approval percentage required before this proposal may activate
+    /// 
+    /// SAFETY: Never below the risk level's minimum, even if the stored
+    /// threshold is missing (older encodings) or was lowered
+    pub fn approval_threshold(&self) -> u64 {
+        self.required_approval_threshold.max(self.risk_level.min_approval_threshold())
+    }
+    
     /// Compute the deterministic content hash
     /// 
     /// SAFETY: This hash is deterministic and independent of signatures
@@ -683,6 +697,42 @@ mod tests {
         assert_eq!(hash1, hash2);
     }
 
+    #[test]
+    fn test_builder_scales_threshold_with_risk() {
+        let build = |id: &str, level: RiskLevel| {
+            APIPBuilder::new(id.to_string(), create_test_model(), "Test".to_string(), "Desc".to_string(), 10)
+                .unwrap()
+                .add_rule_change(RuleChange::new(
+                    "SHARD_SPLIT_THRESHOLD".to_string(),
+                    1_000_000,
+                    1_500_000,
+                    RuleVersion::new(0, 0, 0),
+                    "Increase".to_string(),
+                ))
+                .unwrap()
+                .confidence(75)
+                .unwrap()
+                .risk(level, "Assessed".to_string())
+                .unwrap()
+                .expected_impact("Impact".to_string())
+                .unwrap()
+                .rollback_strategy("Revert".to_string())
+                .unwrap()
+                .ai_signature(vec![1])
+                .unwrap()
+                .build()
+                .unwrap()
+        };
+        
+        assert_eq!(build("APIP-007", RiskLevel::Low).approval_threshold(), 51);
+        assert_eq!(build("APIP-008", RiskLevel::High).approval_threshold(), 80);
+        
+        // A lowered stored threshold cannot undercut the risk level
+        let mut critical = build("APIP-009", RiskLevel::Critical);
+        critical.required_approval_threshold = 0;
+        assert_eq!(critical.approval_threshold(), 90);
+    }
+
     #[test]
     fn test_risk_level_thresholds() {
         assert_eq!(RiskLevel::Low.min_approval_threshold(), 51);
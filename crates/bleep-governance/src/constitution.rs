@@ -10,11 +10,13 @@
 // 6. No governance vote can override constitutional law
 // 7. Constitutional changes require super-supermajority (>90%) + time delay
 
+use crate::governance_core::ProposalType;
 use serde::{Serialize, Deserialize};
 use sha2::{Digest, Sha256};
 use log::{info, warn, error};
 use thiserror::Error;
 use std::collections::BTreeMap;
+use std::fmt;
 
 #[derive(Debug, Error)]
 pub enum ConstitutionError {
@@ -151,38 +153,48 @@ impl ConstitutionalConstraint {
         &self,
         action: &GovernanceAction,
     ) -> Result<ValidationResult, ConstitutionError> {
+        Ok(ValidationResult::from_violations(self.violations(action)))
+    }
+    
+    /// Every way a governance action breaks this constraint
+    pub fn violations(&self, action: &GovernanceAction) -> Vec<RuleViolation> {
         if !self.is_active {
-            return Ok(ValidationResult::Valid);
+            return Vec::new();
         }
         
-        // Check scope matches
-        if action.scope != self.scope && !action.scope_rationale.is_empty() {
-            // Allow cross-scope justification only for governance actions
-            if self.scope != ConstitutionalScope::Governance {
-                return Ok(ValidationResult::Violation(format!(
-                    "Action scope {:?} not allowed in constraint scope {:?}",
+        let mut violations = Vec::new();
+        
+        // Check scope matches; cross-scope justification is only allowed
+        // for governance constraints
+        if action.scope != self.scope
+            && !action.scope_rationale.is_empty()
+            && self.scope != ConstitutionalScope::Governance
+        {
+            violations.push(RuleViolation {
+                constraint_id: self.id.clone(),
+                rule: None,
+                reason: format!(
+                    "action scope {:?} not allowed in constraint scope {:?}",
                     action.scope, self.scope
-                )));
-            }
+                ),
+            });
         }
         
         // Check all validation rules
-        for rule in &self.validation_rules {
-            let result = rule.validate(action)?;
-            if !result {
-                return Ok(ValidationResult::Violation(format!(
-                    "Action violates constraint rule in {:?}",
-                    self.scope
-                )));
-            }
-        }
-        
-        Ok(ValidationResult::Valid)
+        violations.extend(self.validation_rules.iter().filter_map(|rule| {
+            rule.violation(action).map(|reason| RuleViolation {
+                constraint_id: self.id.clone(),
+                rule: Some(rule.clone()),
+                reason,
+            })
+        }));
+        
+        violations
     }
 }
 
 /// A single constraint rule (e.g., "supply cap cannot exceed 10 billion")
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct ConstraintRule {
     /// Rule identifier
     pub name: String,
@@ -194,7 +206,7 @@ pub struct ConstraintRule {
     pub constraint_value: u64,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum RuleType {
     /// Supply cap cannot exceed this amount
     SupplyCap,
@@ -211,6 +223,12 @@ pub enum RuleType {
     /// Validator participation ratio must stay above this
     ValidatorParticipationMinimum,
     
+    /// Slashing percentage cannot drop below this (basis points)
+    SlashingFloor,
+    
+    /// Minimum validator stake cannot drop below this (whole BLP)
+    MinimumStake,
+    
     /// Custom rule
     Custom(String),
 }
@@ -218,50 +236,60 @@ pub enum RuleType {
 impl ConstraintRule {
     /// Validate an action against this rule
     pub fn validate(&self, action: &GovernanceAction) -> Result<bool, ConstitutionError> {
+        Ok(self.violation(action).is_none())
+    }
+    
+    /// Explain why an action breaks this rule, or `None` if it complies
+    pub fn violation(&self, action: &GovernanceAction) -> Option<String> {
+        let limit = self.constraint_value;
         match &self.rule_type {
-            RuleType::SupplyCap => {
-                if let Some(amount) = action.economic_change {
-                    Ok(amount <= self.constraint_value)
-                } else {
-                    Ok(true)
-                }
-            }
-            RuleType::InflationCap => {
-                if let Some(rate) = action.inflation_rate {
-                    Ok(rate <= self.constraint_value)
-                } else {
-                    Ok(true)
-                }
-            }
-            RuleType::SlashingCap => {
-                if let Some(pct) = action.slashing_percentage {
-                    Ok(pct <= self.constraint_value)
-                } else {
-                    Ok(true)
-                }
-            }
-            RuleType::FinalityDelay => {
-                if let Some(delay) = action.finality_delay {
-                    Ok(delay <= self.constraint_value)
-                } else {
-                    Ok(true)
-                }
-            }
-            RuleType::ValidatorParticipationMinimum => {
-                if let Some(min_pct) = action.validator_participation {
-                    Ok(min_pct >= self.constraint_value)
-                } else {
-                    Ok(true)
-                }
-            }
-            RuleType::Custom(_) => {
-                // Custom rules must be explicitly validated by caller
-                Ok(true)
-            }
+            RuleType::SupplyCap => action.economic_change
+                .filter(|&amount| amount > limit)
+                .map(|amount| format!("supply change {} exceeds cap of {}", amount, limit)),
+            RuleType::InflationCap => action.inflation_rate
+                .filter(|&rate| rate > limit)
+                .map(|rate| format!("inflation {} bps exceeds cap of {} bps", rate, limit)),
+            RuleType::SlashingCap => action.slashing_percentage
+                .filter(|&pct| pct > limit)
+                .map(|pct| format!("slashing {} bps exceeds cap of {} bps", pct, limit)),
+            RuleType::FinalityDelay => action.finality_delay
+                .filter(|&delay| delay > limit)
+                .map(|delay| format!("finality delay {} epochs exceeds cap of {} epochs", delay, limit)),
+            RuleType::ValidatorParticipationMinimum => action.validator_participation
+                .filter(|&min_pct| min_pct < limit)
+                .map(|min_pct| format!("participation {} bps is below minimum of {} bps", min_pct, limit)),
+            RuleType::SlashingFloor => action.slashing_percentage
+                .filter(|&pct| pct < limit)
+                .map(|pct| format!("slashing {} bps is below floor of {} bps", pct, limit)),
+            RuleType::MinimumStake => action.min_stake
+                .filter(|&stake| stake < limit)
+                .map(|stake| format!("minimum stake {} BLP is below floor of {} BLP", stake, limit)),
+            // Custom rules must be explicitly validated by caller
+            RuleType::Custom(_) => None,
         }
     }
 }
 
+/// A constraint rule that a governance action breaks
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RuleViolation {
+    /// Constraint the rule belongs to
+    pub constraint_id: String,
+    
+    /// The violated rule, or `None` for a scope mismatch or failed integrity check
+    pub rule: Option<ConstraintRule>,
+    
+    /// Human-readable reason
+    pub reason: String,
+}
+
+impl fmt::Display for RuleViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rule = self.rule.as_ref().map_or("scope", |rule| rule.name.as_str());
+        write!(f, "{}/{}: {}", self.constraint_id, rule, self.reason)
+    }
+}
+
 /// A governance action that may be proposed
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GovernanceAction {
@@ -292,6 +320,10 @@ pub struct GovernanceAction {
     /// Validator participation ratio (basis points)
     pub validator_participation: Option<u64>,
     
+    /// Minimum validator stake (whole BLP)
+    #[serde(default)]
+    pub min_stake: Option<u64>,
+    
     /// Action description
     pub description: String,
 }
@@ -314,6 +346,7 @@ impl GovernanceAction {
             slashing_percentage: None,
             finality_delay: None,
             validator_participation: None,
+            min_stake: None,
             description,
         }
     }
@@ -329,14 +362,46 @@ impl GovernanceAction {
     }
 }
 
+/// A constitutional clause under which a proposal may use the emergency
+/// fast-track (shortened window, elevated quorum and threshold)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmergencyClause {
+    /// Clause identifier referenced by emergency proposals
+    pub id: String,
+    
+    /// Human-readable clause description
+    pub description: String,
+    
+    /// Proposal types this clause may fast-track
+    pub permitted_types: Vec<ProposalType>,
+}
+
+impl EmergencyClause {
+    /// Check if this clause covers a proposal type
+    pub fn permits(&self, proposal_type: ProposalType) -> bool {
+        self.permitted_types.contains(&proposal_type)
+    }
+}
+
 /// Result of constitutional validation
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum ValidationResult {
     /// Action is valid
     Valid,
     
-    /// Action violates constitutional law
-    Violation(String),
+    /// Action breaks these specific rules (see `BLEEPConstitution::evaluate`)
+    Violations(Vec<RuleViolation>),
+}
+
+impl ValidationResult {
+    /// `Valid` when nothing was violated, otherwise every violation
+    pub fn from_violations(violations: Vec<RuleViolation>) -> Self {
+        if violations.is_empty() {
+            ValidationResult::Valid
+        } else {
+            ValidationResult::Violations(violations)
+        }
+    }
 }
 
 /// The BLEEP Constitution - Immutable at genesis, versioned for amendments
@@ -365,6 +430,10 @@ pub struct BLEEPConstitution {
     
     /// Time delay required for constitutional changes (epochs)
     pub constitutional_amendment_delay: u64,
+    
+    /// Clauses permitting emergency fast-track proposals
+    #[serde(default)]
+    pub emergency_clauses: BTreeMap<String, EmergencyClause>,
 }
 
 impl BLEEPConstitution {
@@ -442,6 +511,45 @@ impl BLEEPConstitution {
         )?;
         constraints.insert("VALIDATOR_PARTICIPATION".to_string(), validator_participation);
         
+        // Slashing deterrence constraint (immutable)
+        let slashing_deterrence = ConstitutionalConstraint::new(
+            "SLASHING_DETERRENCE".to_string(),
+            "Slashing cannot be reduced below 1%".to_string(),
+            ConstitutionalScope::ValidatorSafety,
+            true,
+            vec![ConstraintRule {
+                name: "min_slash".to_string(),
+                rule_type: RuleType::SlashingFloor,
+                constraint_value: 100, // 1% in basis points
+            }],
+        )?;
+        constraints.insert("SLASHING_DETERRENCE".to_string(), slashing_deterrence);
+        
+        // Validator stake floor constraint (immutable)
+        let stake_floor = ConstitutionalConstraint::new(
+            "STAKE_FLOOR".to_string(),
+            "Minimum validator stake cannot drop below 100 BLP".to_string(),
+            ConstitutionalScope::ValidatorSafety,
+            true,
+            vec![ConstraintRule {
+                name: "min_stake".to_string(),
+                rule_type: RuleType::MinimumStake,
+                constraint_value: 100,
+            }],
+        )?;
+        constraints.insert("STAKE_FLOOR".to_string(), stake_floor);
+        
+        // Security fixes may be fast-tracked (immutable clause)
+        let mut emergency_clauses = BTreeMap::new();
+        emergency_clauses.insert("SECURITY_FIX".to_string(), EmergencyClause {
+            id: "SECURITY_FIX".to_string(),
+            description: "Urgent security fixes may use the emergency fast-track".to_string(),
+            permitted_types: vec![
+                ProposalType::UpgradeAuthorization,
+                ProposalType::Recovery,
+            ],
+        });
+        
         let mut constitution = BLEEPConstitution {
             version: "1.0.0".to_string(),
             genesis_epoch: 0,
@@ -451,6 +559,7 @@ impl BLEEPConstitution {
             amendment_history: Vec::new(),
             constitutional_amendment_threshold: 9000, // 90%
             constitutional_amendment_delay: 100, // 100 epochs
+            emergency_clauses,
         };
         
         constitution.constitution_hash = constitution.compute_hash()?;
@@ -467,6 +576,7 @@ impl BLEEPConstitution {
             &self.genesis_epoch,
             &self.constraints,
             &self.amendment_count,
+            &self.emergency_clauses,
         )).map_err(|e| ConstitutionError::SerializationError(e.to_string()))?;
         
         let mut hasher = Sha256::new();
@@ -493,24 +603,42 @@ impl BLEEPConstitution {
         info!("Validating governance action {} against constitution v{}", 
               action.id, self.version);
         
-        // Check action against all applicable constraints
-        for (constraint_id, constraint) in &self.constraints {
-            if constraint.scope == ConstitutionalScope::Governance {
-                continue; // Skip governance-scoped constraints for now
-            }
-            
-            match constraint.validate_action(action)? {
-                ValidationResult::Valid => {},
-                ValidationResult::Violation(reason) => {
-                    warn!("Action {} violates constraint {}: {}", 
-                          action.id, constraint_id, reason);
-                    return Ok(ValidationResult::Violation(reason));
-                }
-            }
+        let result = self.check_constraints(action);
+        if result == ValidationResult::Valid {
+            info!("Action {} passed constitutional validation", action.id);
         }
+        Ok(result)
+    }
+    
+    /// Evaluate an action against every applicable rule, reporting each
+    /// violated rule rather than stopping at the first.
+    ///
+    /// Same checks as `validate_action`, but a failed integrity check is
+    /// reported as a violation instead of an error.
+    pub fn evaluate(&self, action: &GovernanceAction) -> ValidationResult {
+        if !self.verify_hash().unwrap_or(false) {
+            return ValidationResult::Violations(vec![RuleViolation {
+                constraint_id: "constitution".to_string(),
+                rule: None,
+                reason: "Constitution hash verification failed".to_string(),
+            }]);
+        }
+        
+        self.check_constraints(action)
+    }
+    
+    /// Check an action against all applicable constraints
+    fn check_constraints(&self, action: &GovernanceAction) -> ValidationResult {
+        let violations: Vec<RuleViolation> = self.constraints.values()
+            // Governance-scoped constraints are not enforced yet
+            .filter(|c| c.scope != ConstitutionalScope::Governance)
+            .flat_map(|constraint| constraint.violations(action))
+            .collect();
         
-        info!("Action {} passed constitutional validation", action.id);
-        Ok(ValidationResult::Valid)
+        for violation in &violations {
+            warn!("Action {} violates {}", action.id, violation);
+        }
+        ValidationResult::from_violations(violations)
     }
     
     /// Add a new constraint (requires constitutional amendment)
@@ -535,6 +663,11 @@ impl BLEEPConstitution {
         Ok(())
     }
     
+    /// Look up an emergency clause by ID
+    pub fn emergency_clause(&self, id: &str) -> Option<&EmergencyClause> {
+        self.emergency_clauses.get(id)
+    }
+    
     /// Get all protected constitutional constraints
     pub fn protected_constraints(&self) -> Vec<&ConstitutionalConstraint> {
         self.constraints.values()
@@ -585,7 +718,7 @@ mod tests {
         
         match constitution.validate_action(&valid_action)? {
             ValidationResult::Valid => {},
-            ValidationResult::Violation(_) => panic!("Valid action rejected"),
+            ValidationResult::Violations(_) => panic!("Valid action rejected"),
         }
         
         // Invalid action: supply exceeds cap
@@ -599,7 +732,7 @@ mod tests {
         
         match constitution.validate_action(&invalid_action)? {
             ValidationResult::Valid => panic!("Invalid action accepted"),
-            ValidationResult::Violation(_) => {},
+            ValidationResult::Violations(_) => {},
         }
         
         Ok(())
@@ -620,7 +753,7 @@ mod tests {
         
         match constitution.validate_action(&valid_action)? {
             ValidationResult::Valid => {},
-            ValidationResult::Violation(_) => panic!("Valid inflation rejected"),
+            ValidationResult::Violations(_) => panic!("Valid inflation rejected"),
         }
         
         // Invalid: inflation exceeds cap
@@ -634,7 +767,7 @@ mod tests {
         
         match constitution.validate_action(&invalid_action)? {
             ValidationResult::Valid => panic!("Invalid inflation accepted"),
-            ValidationResult::Violation(_) => {},
+            ValidationResult::Violations(_) => {},
         }
         
         Ok(())
@@ -666,4 +799,87 @@ mod tests {
         
         Ok(())
     }
+    
+    fn validator_action(id: &str) -> GovernanceAction {
+        GovernanceAction::new(
+            id.to_string(),
+            ConstitutionalScope::ValidatorSafety,
+            vec!["validators".to_string()],
+            "Adjust validator parameters".to_string(),
+        )
+    }
+    
+    fn violated_rules(result: ValidationResult) -> Vec<(String, RuleType)> {
+        match result {
+            ValidationResult::Violations(violations) => violations.into_iter()
+                .map(|v| (v.constraint_id, v.rule.expect("rule violation").rule_type))
+                .collect(),
+            other => panic!("expected rule violations, got {:?}", other),
+        }
+    }
+    
+    #[test]
+    fn test_evaluate_zero_min_stake() -> Result<(), ConstitutionError> {
+        let constitution = BLEEPConstitution::genesis()?;
+        let mut action = validator_action("min_stake_zero");
+        action.min_stake = Some(0);
+        
+        let result = constitution.evaluate(&action);
+        if let ValidationResult::Violations(violations) = &result {
+            assert!(violations[0].reason.contains("below floor of 100 BLP"));
+        }
+        assert_eq!(
+            violated_rules(result),
+            vec![("STAKE_FLOOR".to_string(), RuleType::MinimumStake)],
+        );
+        Ok(())
+    }
+    
+    #[test]
+    fn test_evaluate_zero_slashing_rate() -> Result<(), ConstitutionError> {
+        let constitution = BLEEPConstitution::genesis()?;
+        let mut action = validator_action("slashing_zero");
+        action.slashing_percentage = Some(0);
+        
+        assert_eq!(
+            violated_rules(constitution.evaluate(&action)),
+            vec![("SLASHING_DETERRENCE".to_string(), RuleType::SlashingFloor)],
+        );
+        
+        // Every broken rule is reported, not just the first
+        action.min_stake = Some(0);
+        assert_eq!(violated_rules(constitution.evaluate(&action)).len(), 2);
+        Ok(())
+    }
+    
+    #[test]
+    fn test_evaluate_benign_change_passes() -> Result<(), ConstitutionError> {
+        let constitution = BLEEPConstitution::genesis()?;
+        let mut action = validator_action("benign");
+        action.slashing_percentage = Some(1_000);
+        action.min_stake = Some(500);
+        action.validator_participation = Some(7_000);
+        
+        assert_eq!(constitution.evaluate(&action), ValidationResult::Valid);
+        assert_eq!(constitution.validate_action(&action)?, ValidationResult::Valid);
+        Ok(())
+    }
+    
+    #[test]
+    fn test_evaluate_and_validate_action_agree_on_scope_mismatch() -> Result<(), ConstitutionError> {
+        let constitution = BLEEPConstitution::genesis()?;
+        let mut action = validator_action("cross_scope");
+        action.scope_rationale = "Touches economics too".to_string();
+        
+        let evaluated = constitution.evaluate(&action);
+        assert_eq!(constitution.validate_action(&action)?, evaluated);
+        match evaluated {
+            ValidationResult::Violations(violations) => {
+                assert!(!violations.is_empty());
+                assert!(violations.iter().any(|v| v.rule.is_none()));
+            }
+            other => panic!("expected scope violations, got {:?}", other),
+        }
+        Ok(())
+    }
 }
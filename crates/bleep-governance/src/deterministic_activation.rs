@@ -16,7 +16,10 @@ use crate::invariant_monitoring::{GlobalInvariantMonitor, InvariantType};
 use serde::{Serialize, Deserialize};
 use log::{info, warn, error};
 use thiserror::Error;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+
+/// Activation plans are identified by their proposal ID
+pub type ActivationId = String;
 
 #[derive(Debug, Error)]
 pub enum ActivationError {
@@ -40,6 +43,15 @@ pub enum ActivationError {
     
     #[error("Serialization error: {0}")]
     SerializationError(String),
+    
+    #[error("Cyclic activation dependency: {0}")]
+    CyclicDependency(String),
+    
+    #[error("Activation plan already registered: {0}")]
+    DuplicatePlan(String),
+    
+    #[error("Unknown activation dependency: {0}")]
+    UnknownDependency(String),
 }
 
 /// State of a proposed activation
@@ -87,6 +99,10 @@ pub struct ActivationPlan {
     
     /// Activation records (immutable once activated)
     pub activation_records: Vec<ActivationRecord>,
+    
+    /// Plans that must be activated before this one
+    #[serde(default)]
+    pub depends_on: Vec<ActivationId>,
 }
 
 impl ActivationPlan {
@@ -105,9 +121,16 @@ impl ActivationPlan {
             rule_changes,
             new_protocol_version,
             activation_records: Vec::new(),
+            depends_on: Vec::new(),
         }
     }
     
+    /// Require `dependencies` to be activated before this plan
+    pub fn with_dependencies(mut self, dependencies: Vec<ActivationId>) -> Self {
+        self.depends_on = dependencies;
+        self
+    }
+    
     /// Mark ready to activate
     pub fn mark_ready(&mut self) {
         self.state = ActivationState::ReadyToActivate;
@@ -193,6 +216,56 @@ impl DeterministicActivationManager {
         Ok(plan)
     }
     
+    /// Register an activation plan, rejecting duplicate IDs, unknown
+    /// dependencies and dependency cycles
+    /// 
+    /// SAFETY: A cycle could never activate and a dangling dependency could
+    /// never be satisfied; rejecting both here keeps every registered plan
+    /// schedulable. Re-registering an ID would silently replace a plan other
+    /// plans already depend on.
+    pub fn add_activation_plan(&mut self, plan: ActivationPlan) -> Result<(), ActivationError> {
+        if self.plans.contains_key(&plan.proposal_id) {
+            return Err(ActivationError::DuplicatePlan(plan.proposal_id.clone()));
+        }
+        if let Some(unknown) = plan.depends_on.iter()
+            .find(|id| **id != plan.proposal_id && !self.plans.contains_key(*id))
+        {
+            return Err(ActivationError::UnknownDependency(unknown.clone()));
+        }
+        
+        // Walk the dependency graph from the new plan; reaching it again is a cycle
+        let mut stack: Vec<&ActivationId> = plan.depends_on.iter().collect();
+        let mut visited: HashSet<&ActivationId> = HashSet::new();
+        while let Some(id) = stack.pop() {
+            if *id == plan.proposal_id {
+                return Err(ActivationError::CyclicDependency(plan.proposal_id.clone()));
+            }
+            if !visited.insert(id) {
+                continue;
+            }
+            if let Some(dependency) = self.plans.get(id) {
+                stack.extend(dependency.depends_on.iter());
+            }
+        }
+        
+        info!(
+            "Activation plan registered for {} targeting epoch {} (depends on {:?})",
+            plan.proposal_id, plan.target_epoch, plan.depends_on
+        );
+        
+        self.plans.insert(plan.proposal_id.clone(), plan);
+        Ok(())
+    }
+    
+    /// Whether every dependency of `plan` has been activated
+    fn dependencies_activated(&self, plan: &ActivationPlan) -> bool {
+        plan.depends_on.iter().all(|id| {
+            self.plans
+                .get(id)
+                .is_some_and(|dependency| dependency.state == ActivationState::Activated)
+        })
+    }
+    
     /// Check if activation is ready at current epoch
     /// 
     /// SAFETY: This check is identical on all nodes
@@ -202,12 +275,26 @@ impl DeterministicActivationManager {
         current_epoch: u64,
     ) -> bool {
         if let Some(plan) = self.plans.get(proposal_id) {
-            current_epoch >= plan.target_epoch
+            current_epoch >= plan.target_epoch && self.dependencies_activated(plan)
         } else {
             false
         }
     }
     
+    /// Plans that may activate at `current_epoch`: target epoch reached and
+    /// every dependency already activated
+    /// 
+    /// SAFETY: Ordered by (target epoch, proposal ID), identical on all nodes
+    pub fn next_activations(&self, current_epoch: u64) -> Vec<ActivationId> {
+        let mut ready: Vec<&ActivationPlan> = self.plans
+            .values()
+            .filter(|plan| matches!(plan.state, ActivationState::Pending | ActivationState::ReadyToActivate))
+            .filter(|plan| current_epoch >= plan.target_epoch && self.dependencies_activated(plan))
+            .collect();
+        ready.sort_by(|a, b| (a.target_epoch, &a.proposal_id).cmp(&(b.target_epoch, &b.proposal_id)));
+        ready.into_iter().map(|plan| plan.proposal_id.clone()).collect()
+    }
+    
     /// Perform deterministic activation of approved changes
     /// 
     /// SAFETY: This is 100% deterministic - same proposal → same result on all nodes
@@ -427,6 +514,80 @@ mod tests {
         assert_eq!(plan.state, ActivationState::Activated);
     }
 
+    #[test]
+    fn test_next_activations_follow_dependency_chain() {
+        let genesis = ProtocolRuleSetFactory::create_genesis().unwrap();
+        let mut manager = DeterministicActivationManager::new(genesis, 1);
+        
+        // C depends on B depends on A; C's own epoch arrives first
+        manager.add_activation_plan(ActivationPlan::new("APIP-A".to_string(), 5, 0, vec![], 2)).unwrap();
+        manager.add_activation_plan(
+            ActivationPlan::new("APIP-B".to_string(), 5, 0, vec![], 3)
+                .with_dependencies(vec!["APIP-A".to_string()]),
+        ).unwrap();
+        manager.add_activation_plan(
+            ActivationPlan::new("APIP-C".to_string(), 3, 0, vec![], 4)
+                .with_dependencies(vec!["APIP-B".to_string()]),
+        ).unwrap();
+        manager.add_activation_plan(ActivationPlan::new("APIP-D".to_string(), 5, 0, vec![], 2)).unwrap();
+        
+        assert!(manager.next_activations(4).is_empty());
+        assert_eq!(manager.next_activations(5), vec!["APIP-A".to_string(), "APIP-D".to_string()]);
+        assert!(!manager.check_activation_ready("APIP-B", 5));
+        
+        let mut order = Vec::new();
+        while let Some(id) = manager.next_activations(5).first().cloned() {
+            manager.activate(&id, 5, 100).unwrap();
+            order.push(id);
+        }
+        assert_eq!(order, vec!["APIP-A", "APIP-B", "APIP-C", "APIP-D"]);
+    }
+
+    #[test]
+    fn test_cyclic_activation_plan_rejected() {
+        let genesis = ProtocolRuleSetFactory::create_genesis().unwrap();
+        let mut manager = DeterministicActivationManager::new(genesis, 1);
+        
+        let self_cycle = ActivationPlan::new("APIP-A".to_string(), 5, 0, vec![], 2)
+            .with_dependencies(vec!["APIP-A".to_string()]);
+        assert!(matches!(
+            manager.add_activation_plan(self_cycle),
+            Err(ActivationError::CyclicDependency(_))
+        ));
+        
+        assert!(manager.get_plan("APIP-A").is_none());
+    }
+
+    #[test]
+    fn test_duplicate_and_dangling_activation_plans_rejected() {
+        let genesis = ProtocolRuleSetFactory::create_genesis().unwrap();
+        let mut manager = DeterministicActivationManager::new(genesis, 1);
+        
+        // A dependency must be registered before its dependents
+        let dangling = ActivationPlan::new("APIP-B".to_string(), 5, 0, vec![], 3)
+            .with_dependencies(vec!["APIP-A".to_string()]);
+        assert!(matches!(
+            manager.add_activation_plan(dangling),
+            Err(ActivationError::UnknownDependency(id)) if id == "APIP-A"
+        ));
+        assert!(manager.get_plan("APIP-B").is_none());
+        
+        manager.add_activation_plan(ActivationPlan::new("APIP-A".to_string(), 5, 0, vec![], 2)).unwrap();
+        manager.add_activation_plan(
+            ActivationPlan::new("APIP-B".to_string(), 5, 0, vec![], 3)
+                .with_dependencies(vec!["APIP-A".to_string()]),
+        ).unwrap();
+        
+        // Replacing A would let it depend on B, closing a cycle
+        let replacement = ActivationPlan::new("APIP-A".to_string(), 9, 0, vec![], 2)
+            .with_dependencies(vec!["APIP-B".to_string()]);
+        assert!(matches!(
+            manager.add_activation_plan(replacement),
+            Err(ActivationError::DuplicatePlan(id)) if id == "APIP-A"
+        ));
+        assert_eq!(manager.get_plan("APIP-A").unwrap().target_epoch, 5);
+    }
+
     #[test]
     fn test_deterministic_activation_manager() {
         let genesis = ProtocolRuleSetFactory::create_genesis().unwrap();
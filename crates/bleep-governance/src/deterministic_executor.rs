@@ -106,6 +106,10 @@ impl DeterministicExecutor {
     }
     
     /// Execute a batch of proposals in deterministic order
+    /// 
+    /// SAFETY: All-or-nothing. If any proposal fails, the proposals already
+    /// applied in this batch are undone in reverse order, so the state root
+    /// is left exactly as it was before the batch.
     pub fn execute_batch(
         &mut self,
         proposals: Vec<(String, String, Vec<u8>)>, // (id, type, payload)
@@ -116,6 +120,8 @@ impl DeterministicExecutor {
         sorted.sort_by(|a, b| a.0.cmp(&b.0));
         
         let mut records = Vec::new();
+        // (proposal_id, log index) of each proposal applied in this batch
+        let mut applied: Vec<(String, usize)> = Vec::new();
         
         for (proposal_id, proposal_type, payload) in sorted {
             // Check not already executed
@@ -138,6 +144,7 @@ impl DeterministicExecutor {
             ) {
                 Ok(record) => {
                     info!("Proposal {} executed successfully", proposal_id);
+                    applied.push((proposal_id.clone(), self.execution_log.len() - 1));
                     self.executed.insert(proposal_id, record.clone());
                     records.push(record);
                 }
@@ -154,8 +161,9 @@ impl DeterministicExecutor {
                     };
                     self.execution_log.push(log_entry.clone());
                     
+                    self.rollback_applied(&applied);
+                    
                     // Return error for audit trail
-                    // In production, failed proposals are logged but don't stop batch
                     return Err(e);
                 }
             }
@@ -164,6 +172,33 @@ impl DeterministicExecutor {
         Ok(records)
     }
     
+    /// Undo proposals applied earlier in a failed batch, newest first.
+    ///
+    /// The log is append-only: each undo is recorded as a new `Rolled`
+    /// entry and the original `Success` entry is left untouched.
+    fn rollback_applied(&mut self, applied: &[(String, usize)]) {
+        for (proposal_id, log_index) in applied.iter().rev() {
+            let state_after_rollback = self.state_snapshots.get(proposal_id)
+                .cloned()
+                .unwrap_or_else(|| self.current_state_root.clone());
+            let original = &self.execution_log[*log_index];
+            let rolled = ExecutionLogEntry {
+                proposal_id: proposal_id.clone(),
+                execution_epoch: original.execution_epoch,
+                execution_hash: original.execution_hash.clone(),
+                status: ExecutionStatus::Rolled,
+                error: Some("Rolled back after batch failure".to_string()),
+                state_root_before: self.current_state_root.clone(),
+                state_root_after: state_after_rollback.clone(),
+            };
+            self.execution_log.push(rolled);
+            self.current_state_root = state_after_rollback;
+            self.executed.remove(proposal_id);
+            
+            warn!("Rolled back proposal {} after batch failure", proposal_id);
+        }
+    }
+    
     /// Internal execution logic (handles specific proposal types)
     fn execute_proposal_internal(
         &mut self,
@@ -396,6 +431,63 @@ mod tests {
         assert_eq!(log_before, log_after);
     }
 
+    #[test]
+    fn test_failed_batch_restores_state() {
+        let initial_state = vec![0u8; 32];
+        let mut executor = DeterministicExecutor::new(initial_state.clone());
+        
+        // Sorted order: prop-1, prop-2 apply, prop-3 fails (payload too short)
+        let proposals = vec![
+            ("prop-3".to_string(), "UPGRADE_AUTHORIZATION".to_string(), vec![1]),
+            ("prop-1".to_string(), "PROTOCOL_PARAMETER".to_string(), vec![1; 16]),
+            ("prop-2".to_string(), "VALIDATOR_SANCTION".to_string(), vec![4, 5, 6]),
+        ];
+        
+        assert!(executor.execute_batch(proposals, 5).is_err());
+        assert_eq!(executor.get_current_state_root(), initial_state.as_slice());
+        assert!(executor.get_execution_record("prop-1").is_err());
+        assert!(executor.get_execution_record("prop-2").is_err());
+        
+        let statuses: Vec<(&str, ExecutionStatus)> = executor.get_execution_log()
+            .iter()
+            .map(|e| (e.proposal_id.as_str(), e.status))
+            .collect();
+        assert_eq!(statuses, vec![
+            ("prop-1", ExecutionStatus::Success),
+            ("prop-2", ExecutionStatus::Success),
+            ("prop-3", ExecutionStatus::Failed),
+            ("prop-2", ExecutionStatus::Rolled),
+            ("prop-1", ExecutionStatus::Rolled),
+        ]);
+        
+        // Rollback entries chain the state root back to where the batch began
+        let log = executor.get_execution_log();
+        assert_eq!(log[3].state_root_before, log[1].state_root_after);
+        assert_eq!(log[3].state_root_after, log[1].state_root_before);
+        assert_eq!(log[4].state_root_after, initial_state);
+    }
+
+    #[test]
+    fn test_successful_batch_leaves_clean_log() {
+        let initial_state = vec![0u8; 32];
+        let mut executor = DeterministicExecutor::new(initial_state.clone());
+        
+        let proposals = vec![
+            ("prop-1".to_string(), "PROTOCOL_PARAMETER".to_string(), vec![1; 16]),
+            ("prop-2".to_string(), "RECOVERY".to_string(), vec![7]),
+        ];
+        
+        let records = executor.execute_batch(proposals, 5).unwrap();
+        assert_eq!(records.len(), 2);
+        assert_ne!(executor.get_current_state_root(), initial_state.as_slice());
+        assert_eq!(executor.get_execution_log().len(), 2);
+        assert!(executor.get_execution_log().iter().all(|e| e.status == ExecutionStatus::Success && e.error.is_none()));
+        assert_eq!(
+            executor.get_execution_log()[1].state_root_after,
+            executor.get_current_state_root()
+        );
+    }
+
     #[test]
     fn test_double_execution_prevention() {
         let initial_state = vec![0u8; 32];
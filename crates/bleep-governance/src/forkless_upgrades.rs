@@ -109,6 +109,10 @@ pub struct UpgradePayload {
     
     /// Human-readable description
     pub description: String,
+    
+    /// State root (hex) expected after all migrations are applied
+    #[serde(default)]
+    pub post_state_root: Option<String>,
 }
 
 /// State migration step
@@ -132,16 +136,28 @@ pub enum MigrationType {
     /// Add new field to state
     AddField { field_name: String, default_value: Vec<u8> },
     
-    /// Transform existing field
+    /// Transform existing field (rewritten to the encoded `transformation` output)
     TransformField { field_name: String, transformation: Vec<u8> },
     
     /// Remove deprecated field
     RemoveField { field_name: String },
     
-    /// Reindex state structure
+    /// Reindex state structure (field keyed `old_structure` moves to `new_structure`, UTF-8)
     Reindex { old_structure: Vec<u8>, new_structure: Vec<u8> },
 }
 
+/// Migratable protocol state: module -> field -> encoded value
+pub type ModuleState = BTreeMap<String, BTreeMap<String, Vec<u8>>>;
+
+/// Deterministic state root (hex) over the full module state
+pub fn compute_state_root(state: &ModuleState) -> Result<String, UpgradeError> {
+    let serialized = bincode::serialize(state)
+        .map_err(|e| UpgradeError::SerializationError(e.to_string()))?;
+    let mut hasher = Sha256::new();
+    hasher.update(&serialized);
+    Ok(hex::encode(hasher.finalize()))
+}
+
 /// Preconditions that must be met for upgrade
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UpgradePreconditions {
@@ -175,6 +191,7 @@ impl UpgradePayload {
             feature_flags: BTreeMap::new(),
             preconditions,
             description,
+            post_state_root: None,
         }
     }
     
@@ -193,6 +210,11 @@ impl UpgradePayload {
         self.config_changes.insert(key, value);
     }
     
+    /// Declare the state root expected after migrations
+    pub fn declare_post_state_root(&mut self, root: String) {
+        self.post_state_root = Some(root);
+    }
+    
     /// Enable feature flag
     pub fn enable_feature(&mut self, feature: String) {
         self.feature_flags.insert(feature, true);
@@ -417,6 +439,12 @@ pub struct ProtocolUpgradeManager {
     
     /// Executed upgrades (version -> execution result hash)
     pub executed_upgrades: BTreeMap<Version, Vec<u8>>,
+    
+    /// Committed state root (hex), advanced only by verified migrations
+    pub state_root: String,
+    
+    /// Migratable state committed to by `state_root`
+    pub state: ModuleState,
 }
 
 impl ProtocolUpgradeManager {
@@ -428,9 +456,18 @@ impl ProtocolUpgradeManager {
             current_checkpoint: None,
             last_upgrade_epoch: 0,
             executed_upgrades: BTreeMap::new(),
+            state_root: compute_state_root(&ModuleState::new()).unwrap_or_default(),
+            state: ModuleState::new(),
         }
     }
     
+    /// Replace the migratable state and recommit its root
+    pub fn load_state(&mut self, state: ModuleState) -> Result<(), UpgradeError> {
+        self.state_root = compute_state_root(&state)?;
+        self.state = state;
+        Ok(())
+    }
+    
     /// Approve an upgrade
     pub fn approve_upgrade(
         &mut self,
@@ -503,11 +540,88 @@ impl ProtocolUpgradeManager {
         // Verify payload
         upgrade.verify_payload()?;
         
+        // Migrations must reach the declared post-state root or nothing commits
+        self.apply_migrations(&upgrade.payload)?;
+        
         info!("Activating upgrade to version {} at epoch {}", version, current_epoch);
+        Ok(())
+    }
+    
+    /// Run one migration's transform on the state and recommit the root
+    ///
+    /// SAFETY: Transforms are pure functions of the current state and the
+    /// migration, so every node computes the same post-root. A migration that
+    /// does not fit the state (adding an existing field, touching a missing
+    /// one) is rejected before any change is made.
+    pub fn apply_migration(&mut self, migration: &StateMigration) -> Result<(), UpgradeError> {
+        let mut fields = self.state.get(&migration.module).cloned().unwrap_or_default();
+        match &migration.migration_type {
+            MigrationType::AddField { field_name, default_value } => {
+                if fields.contains_key(field_name) {
+                    return Err(UpgradeError::InvalidPayload);
+                }
+                fields.insert(field_name.clone(), default_value.clone());
+            }
+            MigrationType::TransformField { field_name, transformation } => {
+                let value = fields.get_mut(field_name).ok_or(UpgradeError::InvalidPayload)?;
+                *value = transformation.clone();
+            }
+            MigrationType::RemoveField { field_name } => {
+                fields.remove(field_name).ok_or(UpgradeError::InvalidPayload)?;
+            }
+            MigrationType::Reindex { old_structure, new_structure } => {
+                let old_key = String::from_utf8(old_structure.clone())
+                    .map_err(|_| UpgradeError::InvalidPayload)?;
+                let new_key = String::from_utf8(new_structure.clone())
+                    .map_err(|_| UpgradeError::InvalidPayload)?;
+                if fields.contains_key(&new_key) {
+                    return Err(UpgradeError::InvalidPayload);
+                }
+                let value = fields.remove(&old_key).ok_or(UpgradeError::InvalidPayload)?;
+                fields.insert(new_key, value);
+            }
+        }
+        if fields.is_empty() {
+            self.state.remove(&migration.module);
+        } else {
+            self.state.insert(migration.module.clone(), fields);
+        }
         
+        self.state_root = compute_state_root(&self.state)?;
         Ok(())
     }
     
+    /// Run all payload migrations against the state and check the result
+    ///
+    /// SAFETY: Payloads with migrations must declare their post-state root;
+    /// a failed migration or a root mismatch restores the pre-upgrade state
+    /// and root.
+    fn apply_migrations(&mut self, payload: &UpgradePayload) -> Result<(), UpgradeError> {
+        if payload.post_state_root.is_none() && !payload.state_migrations.is_empty() {
+            return Err(UpgradeError::InvalidPayload);
+        }
+        
+        let pre_state = self.state.clone();
+        let pre_root = self.state_root.clone();
+        let result = payload.state_migrations
+            .iter()
+            .try_for_each(|migration| self.apply_migration(migration))
+            .and_then(|()| match &payload.post_state_root {
+                Some(declared) if *declared != self.state_root => {
+                    error!("State migration mismatch for {}: declared {}, computed {}",
+                           payload.version, declared, self.state_root);
+                    Err(UpgradeError::StateTransitionVerificationFailed)
+                }
+                _ => Ok(()),
+            });
+        
+        if result.is_err() {
+            self.state = pre_state;
+            self.state_root = pre_root;
+        }
+        result
+    }
+    
     /// Execute upgrade (apply changes)
     pub fn execute_upgrade(
         &mut self,
@@ -654,4 +768,113 @@ mod tests {
         
         Ok(())
     }
+    
+    fn migration_payload() -> UpgradePayload {
+        let mut payload = UpgradePayload::new(
+            "upgrade_migrate".to_string(),
+            Version::new(1, 1, 0),
+            "Add account nonce field".to_string(),
+            UpgradePreconditions {
+                min_validator_participation: 6600,
+                min_epochs_since_last_upgrade: 10,
+                requires_finality: true,
+                requires_shard_health: true,
+            },
+        );
+        payload.add_state_migration(StateMigration {
+            module: "accounts".to_string(),
+            migration_type: MigrationType::AddField {
+                field_name: "nonce".to_string(),
+                default_value: vec![0; 8],
+            },
+            migration_hash: vec![1; 32],
+            rollback_hash: vec![2; 32],
+        });
+        payload
+    }
+    
+    fn pre_upgrade_state() -> ModuleState {
+        let mut state = ModuleState::new();
+        state.entry("accounts".to_string()).or_default()
+            .insert("balance".to_string(), vec![9; 16]);
+        state
+    }
+    
+    #[test]
+    fn test_migration_with_matching_root_is_accepted() -> Result<(), UpgradeError> {
+        let mut manager = ProtocolUpgradeManager::genesis();
+        manager.load_state(pre_upgrade_state())?;
+        
+        // The declared root is the root of the migrated state itself
+        let mut expected_state = pre_upgrade_state();
+        expected_state.get_mut("accounts").unwrap().insert("nonce".to_string(), vec![0; 8]);
+        let expected = compute_state_root(&expected_state)?;
+        assert_ne!(expected, manager.state_root);
+        
+        let mut payload = migration_payload();
+        payload.declare_post_state_root(expected.clone());
+        
+        manager.approve_upgrade(payload, 0, 7000, 3000, 10)?;
+        manager.activate_upgrade(Version::new(1, 1, 0), 10)?;
+        
+        assert_eq!(manager.state_root, expected);
+        assert_eq!(manager.state, expected_state);
+        Ok(())
+    }
+    
+    #[test]
+    fn test_inapplicable_migration_leaves_state_untouched() -> Result<(), UpgradeError> {
+        let mut manager = ProtocolUpgradeManager::genesis();
+        manager.load_state(pre_upgrade_state())?;
+        let pre_root = manager.state_root.clone();
+        
+        // Second step transforms a field that does not exist
+        let mut payload = migration_payload();
+        payload.add_state_migration(StateMigration {
+            module: "accounts".to_string(),
+            migration_type: MigrationType::TransformField {
+                field_name: "missing".to_string(),
+                transformation: vec![1],
+            },
+            migration_hash: vec![3; 32],
+            rollback_hash: vec![4; 32],
+        });
+        payload.declare_post_state_root("bb".repeat(32));
+        
+        manager.approve_upgrade(payload, 0, 7000, 3000, 10)?;
+        assert!(matches!(
+            manager.activate_upgrade(Version::new(1, 1, 0), 10),
+            Err(UpgradeError::InvalidPayload)
+        ));
+        assert_eq!(manager.state_root, pre_root);
+        assert_eq!(manager.state, pre_upgrade_state());
+        Ok(())
+    }
+    
+    #[test]
+    fn test_migration_with_wrong_root_is_rejected() -> Result<(), UpgradeError> {
+        let mut manager = ProtocolUpgradeManager::genesis();
+        manager.load_state(pre_upgrade_state())?;
+        let pre_root = manager.state_root.clone();
+        
+        let mut payload = migration_payload();
+        payload.declare_post_state_root("bb".repeat(32));
+        
+        manager.approve_upgrade(payload, 0, 7000, 3000, 10)?;
+        let result = manager.activate_upgrade(Version::new(1, 1, 0), 10);
+        
+        assert!(matches!(result, Err(UpgradeError::StateTransitionVerificationFailed)));
+        assert_eq!(manager.state_root, pre_root);
+        assert_eq!(manager.state, pre_upgrade_state());
+        assert_eq!(manager.current_version, Version::genesis());
+        
+        // Undeclared root is rejected as well
+        let mut manager = ProtocolUpgradeManager::genesis();
+        manager.approve_upgrade(migration_payload(), 0, 7000, 3000, 10)?;
+        assert!(matches!(
+            manager.activate_upgrade(Version::new(1, 1, 0), 10),
+            Err(UpgradeError::InvalidPayload)
+        ));
+        Ok(())
+    }
 }
@@ -10,6 +10,7 @@
 // 6. Finality is required before governance execution
 // 7. All governance events are cryptographically archived in consensus
 
+use bleep_state::epoch_clock::EpochClock;
 use serde::{Serialize, Deserialize};
 use sha2::{Digest, Sha256};
 use log::{info, warn, error};
@@ -17,6 +18,7 @@ use thiserror::Error;
 use std::collections::HashMap;
 use super::zk_voting::VoteTally;
 use super::forkless_upgrades::Version;
+use super::apip::APIP;
 
 #[derive(Debug, Error)]
 pub enum BindingError {
@@ -40,6 +42,9 @@ pub enum BindingError {
     
     #[error("Finality not achieved")]
     FinalityNotAchieved,
+    
+    #[error("Approval {actual}% below required {required}%")]
+    ApprovalThresholdNotMet { required: u64, actual: f64 },
 }
 
 /// Result of governance action proposal vote
@@ -169,6 +174,10 @@ pub struct FinalizedGovernanceAction {
     
     /// Activation epoch (when action executes)
     pub activation_epoch: u64,
+    
+    /// Approval percentage the tally must reach (0 = no extra requirement)
+    #[serde(default)]
+    pub required_approval_percentage: u64,
 }
 
 impl FinalizedGovernanceAction {
@@ -192,9 +201,45 @@ impl FinalizedGovernanceAction {
             finalization_epoch,
             finality_depth_required,
             activation_epoch,
+            required_approval_percentage: 0,
         }
     }
     
+    /// Finalized action for an A-PIP, bound to its risk-scaled threshold
+    pub fn for_apip(
+        apip: &APIP,
+        voting_result: VoteTally,
+        voting_proof: Vec<u8>,
+        finalization_epoch: u64,
+        finality_depth_required: u64,
+    ) -> Self {
+        let required = apip.approval_threshold();
+        let approved = voting_result.yes_percentage >= required as f64;
+        
+        FinalizedGovernanceAction {
+            action_id: apip.proposal_id.clone(),
+            description: apip.title.clone(),
+            voting_result,
+            approved,
+            voting_proof,
+            finalization_epoch,
+            finality_depth_required,
+            activation_epoch: apip.target_epoch,
+            required_approval_percentage: required,
+        }
+    }
+    
+    /// Check the tally reached the required approval percentage
+    pub fn check_approval_threshold(&self) -> Result<(), BindingError> {
+        if self.voting_result.yes_percentage < self.required_approval_percentage as f64 {
+            return Err(BindingError::ApprovalThresholdNotMet {
+                required: self.required_approval_percentage,
+                actual: self.voting_result.yes_percentage,
+            });
+        }
+        Ok(())
+    }
+    
     /// Check if sufficient finality has been achieved
     pub fn has_finality(&self, current_block_height: u64) -> bool {
         current_block_height >= self.finalization_epoch + self.finality_depth_required
@@ -212,8 +257,8 @@ pub struct GovernanceConsensusBinding {
     /// Current protocol version (from consensus)
     pub protocol_version: Version,
     
-    /// Current epoch
-    pub current_epoch: u64,
+    /// Shared epoch source
+    clock: EpochClock,
     
     /// Current block height
     pub current_block_height: u64,
@@ -236,13 +281,29 @@ impl GovernanceConsensusBinding {
             finalized_actions: HashMap::new(),
             activation_records: HashMap::new(),
             protocol_version,
-            current_epoch,
+            clock: EpochClock::new(current_epoch),
             current_block_height: 0,
             finality_depth,
             governance_archive: Vec::new(),
         }
     }
     
+    /// Attach a shared epoch clock
+    pub fn with_clock(mut self, clock: EpochClock) -> Self {
+        self.clock = clock;
+        self
+    }
+    
+    /// Epoch clock this binding reads from
+    pub fn clock(&self) -> &EpochClock {
+        &self.clock
+    }
+    
+    /// Current epoch according to the shared clock
+    pub fn current_epoch(&self) -> u64 {
+        self.clock.current_epoch()
+    }
+    
     /// Record a finalized governance action
     pub fn record_finalized_action(
         &mut self,
@@ -254,7 +315,7 @@ impl GovernanceConsensusBinding {
         }
         
         // Check activation epoch is in future
-        if action.activation_epoch <= self.current_epoch {
+        if action.activation_epoch <= self.current_epoch() {
             return Err(BindingError::InvalidActivation);
         }
         
@@ -283,6 +344,8 @@ impl GovernanceConsensusBinding {
                     format!("Action {} was not approved", action_id)
                 ));
             }
+            
+            action.check_approval_threshold()?;
         }
         
         Ok(())
@@ -298,8 +361,11 @@ impl GovernanceConsensusBinding {
                 format!("Action {} not found", action_id)
             ))?;
         
+        // Check tally met the (risk-scaled) threshold, whatever `approved` says
+        action.check_approval_threshold()?;
+        
         // Check activation epoch reached
-        if self.current_epoch < action.activation_epoch {
+        if self.current_epoch() < action.activation_epoch {
             return Ok(false);
         }
         
@@ -336,7 +402,7 @@ impl GovernanceConsensusBinding {
                 no_votes: action.voting_result.no_votes,
                 yes_percentage: action.voting_result.yes_percentage,
             },
-            self.current_epoch,
+            self.current_epoch(),
             self.current_block_height,
             action.voting_proof.clone(),
             execution_result,
@@ -347,7 +413,7 @@ impl GovernanceConsensusBinding {
         // Archive governance event
         self.archive_governance_event(&record)?;
         
-        info!("Governance action {} activated at epoch {}", action_id, self.current_epoch);
+        info!("Governance action {} activated at epoch {}", action_id, self.current_epoch());
         
         Ok(record)
     }
@@ -393,9 +459,9 @@ impl GovernanceConsensusBinding {
         Ok(())
     }
     
-    /// Advance epoch
+    /// Advance epoch (on the shared clock, so every subsystem sees it)
     pub fn advance_epoch(&mut self, block_height: u64) {
-        self.current_epoch += 1;
+        self.clock.advance();
         self.current_block_height = block_height;
     }
     
@@ -420,6 +486,8 @@ impl GovernanceConsensusBinding {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::apip::{AIModelMetadata, APIPBuilder, RiskLevel, RuleChange};
+    use crate::protocol_rules::RuleVersion;
     
     #[test]
     fn test_finalized_action_creation() {
@@ -446,6 +514,87 @@ mod tests {
         assert!(action.has_finality(15));
     }
     
+    fn apip_with_risk(proposal_id: &str, level: RiskLevel) -> APIP {
+        APIPBuilder::new(
+            proposal_id.to_string(),
+            AIModelMetadata::new(
+                "test-model".to_string(),
+                "1.0.0".to_string(),
+                "Test Model".to_string(),
+                "Test Org".to_string(),
+            ),
+            "Adjust split threshold".to_string(),
+            "Test".to_string(),
+            1,
+        )
+        .unwrap()
+        .add_rule_change(RuleChange::new(
+            "SHARD_SPLIT_THRESHOLD".to_string(),
+            1_000_000,
+            1_100_000,
+            RuleVersion::new(0, 1, 0),
+            "Increase".to_string(),
+        ))
+        .unwrap()
+        .confidence(80)
+        .unwrap()
+        .risk(level, "Assessed".to_string())
+        .unwrap()
+        .expected_impact("Fewer splits".to_string())
+        .unwrap()
+        .rollback_strategy("Revert".to_string())
+        .unwrap()
+        .ai_signature(vec![1, 2, 3])
+        .unwrap()
+        .build()
+        .unwrap()
+    }
+    
+    fn tally_at(proposal_id: &str, yes_percentage: f64) -> VoteTally {
+        let yes_votes = (yes_percentage * 100.0) as u64;
+        VoteTally {
+            proposal_id: proposal_id.to_string(),
+            yes_votes,
+            no_votes: 10000 - yes_votes,
+            total_weighted_votes: 10000,
+            yes_percentage,
+            voter_count: 100,
+        }
+    }
+    
+    #[test]
+    fn test_apip_activation_threshold_scales_with_risk() {
+        let mut binding = GovernanceConsensusBinding::new(Version::new(1, 0, 0), 0, 2);
+        
+        // 70% clears the low-risk threshold (51%) but not the high-risk one (80%)
+        let low = apip_with_risk("APIP-LOW", RiskLevel::Low);
+        let high = apip_with_risk("APIP-HIGH", RiskLevel::High);
+        binding.record_finalized_action(
+            FinalizedGovernanceAction::for_apip(&low, tally_at("APIP-LOW", 70.0), vec![1], 0, 2),
+        ).unwrap();
+        let mut high_action = FinalizedGovernanceAction::for_apip(&high, tally_at("APIP-HIGH", 70.0), vec![1], 0, 2);
+        assert!(!high_action.approved);
+        // Even if marked approved at the low threshold, it must not activate
+        high_action.approved = true;
+        binding.record_finalized_action(high_action).unwrap();
+        
+        binding.advance_epoch(10);
+        assert!(binding.activate_action("APIP-LOW", vec![9]).is_ok());
+        assert!(matches!(
+            binding.activate_action("APIP-HIGH", vec![9]),
+            Err(BindingError::ApprovalThresholdNotMet { required: 80, .. })
+        ));
+        assert!(binding.verify_block_governance(&["APIP-HIGH".to_string()]).is_err());
+        
+        // The same high-risk proposal activates once the supermajority is reached
+        binding.record_finalized_action(FinalizedGovernanceAction {
+            activation_epoch: 2,
+            ..FinalizedGovernanceAction::for_apip(&high, tally_at("APIP-HIGH", 85.0), vec![1], 0, 2)
+        }).unwrap();
+        binding.advance_epoch(20);
+        assert!(binding.activate_action("APIP-HIGH", vec![9]).is_ok());
+    }
+    
     #[test]
     fn test_binding_new() {
         let binding = GovernanceConsensusBinding::new(
@@ -455,7 +604,7 @@ mod tests {
         );
         
         assert_eq!(binding.protocol_version, Version::new(1, 0, 0));
-        assert_eq!(binding.current_epoch, 0);
+        assert_eq!(binding.current_epoch(), 0);
     }
     
     #[test]
@@ -495,4 +644,19 @@ mod tests {
         // Invalid upgrade (same version)
         assert!(binding.verify_upgrade_activation(Version::new(1, 0, 0)).is_err());
     }
+    
+    #[test]
+    fn test_binding_advances_shared_epoch_clock() {
+        use crate::governance_core::GovernanceEngine;
+        
+        let clock = EpochClock::new(4);
+        let mut binding = GovernanceConsensusBinding::new(Version::new(1, 0, 0), 0, 2)
+            .with_clock(clock.clone());
+        let governance = GovernanceEngine::new(10_000).with_clock(clock.clone());
+        
+        binding.advance_epoch(100);
+        assert_eq!(binding.current_epoch(), 5);
+        assert_eq!(governance.current_epoch(), 5);
+        assert_eq!(clock.current_epoch(), 5);
+    }
 }
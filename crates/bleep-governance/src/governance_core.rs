@@ -11,14 +11,40 @@
 // 7. All governance actions are immutable once executed
 // 8. No proposal can execute without quorum + threshold
 
+use crate::constitution::BLEEPConstitution;
+use bleep_state::epoch_clock::EpochClock;
 use serde::{Serialize, Deserialize};
 use sha2::{Digest, Sha256};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::sync::Arc;
+use parking_lot::Mutex;
+use bleep_state::state_manager::StateManager;
 use log::{info, warn, error};
 use thiserror::Error;
 
+/// Emergency fast-track voting window (epochs) - the minimum window allowed
+pub const EMERGENCY_VOTING_EPOCHS: u64 = 2;
+
+/// Emergency fast-track approval threshold (percentage, 0-100)
+pub const EMERGENCY_APPROVAL_THRESHOLD: u64 = 80;
+
+/// Emergency fast-track quorum: participation must exceed this percentage of network stake
+pub const EMERGENCY_QUORUM_PERCENTAGE: u64 = 50;
+
+/// Minimum deposit locked by a proposal submission (spam resistance)
+pub const MIN_PROPOSAL_DEPOSIT: u128 = 1_000;
+
+/// Account holding proposal deposits while voting is open
+pub const GOVERNANCE_ESCROW_ACCOUNT: &str = "bleep:governance:escrow";
+
+/// Default epochs an approved proposal stays vetoable before execution
+pub const DEFAULT_TIMELOCK_EPOCHS: u64 = 2;
+
+/// Minimum epochs between the end of voting and the execution epoch
+pub const MIN_EXECUTION_DELAY_EPOCHS: u64 = 1;
+
 /// Proposal type determining what action is executed
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub enum ProposalType {
     /// Update protocol parameters (e.g., block_time, validator_count)
     ProtocolParameter,
@@ -42,6 +68,32 @@ impl ProposalType {
             ProposalType::UpgradeAuthorization => "UPGRADE_AUTHORIZATION",
         }
     }
+    
+    /// Default minimum voting duration (epochs) for this proposal type
+    /// 
+    /// Higher-impact proposals give stakeholders longer to react.
+    pub fn default_min_voting_epochs(&self) -> u64 {
+        match self {
+            ProposalType::ProtocolParameter => 2,
+            ProposalType::Recovery => 4,
+            ProposalType::ValidatorSanction => 5,
+            ProposalType::UpgradeAuthorization => 7,
+        }
+    }
+    
+    /// Default tally mode for this proposal type
+    /// 
+    /// Recovery proposals undo sanctions, so quadratic weighting stops a
+    /// large holder from restoring itself or an ally on stake alone; the
+    /// others follow stake, which carries the slashing risk they govern.
+    pub fn default_tally_mode(&self) -> TallyMode {
+        match self {
+            ProposalType::Recovery => TallyMode::Quadratic,
+            ProposalType::ProtocolParameter
+            | ProposalType::ValidatorSanction
+            | ProposalType::UpgradeAuthorization => TallyMode::Linear,
+        }
+    }
 }
 
 /// Governance proposal state machine
@@ -62,6 +114,9 @@ pub enum ProposalState {
     /// Tally complete, awaiting execution epoch
     AwaitingExecution,
     
+    /// Approved, inside the timelock window (guardian may still veto)
+    Timelocked,
+    
     /// Execution in progress
     Executing,
     
@@ -89,6 +144,20 @@ impl ProposalState {
     }
 }
 
+/// What happened to a proposal's deposit
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum DepositStatus {
+    /// Held in escrow until voting closes
+    #[default]
+    Locked,
+    
+    /// Returned: the proposal reached a vote (approved or rejected)
+    Refunded,
+    
+    /// Burned: the proposal expired without reaching quorum
+    Forfeited,
+}
+
 /// Voting window: bounded by start and end epochs
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VotingWindow {
@@ -177,6 +246,43 @@ impl Vote {
     }
 }
 
+/// How stake converts into voting weight
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum TallyMode {
+    /// Weight = stake
+    #[default]
+    Linear,
+    
+    /// Weight = floor(sqrt(stake)), reducing whale dominance
+    Quadratic,
+}
+
+impl TallyMode {
+    /// Voting weight of a single voter's stake
+    pub fn weight(&self, stake: u128) -> u128 {
+        match self {
+            TallyMode::Linear => stake,
+            TallyMode::Quadratic => integer_sqrt(stake),
+        }
+    }
+}
+
+/// floor(sqrt(n)) using integer Newton iteration (deterministic across nodes)
+fn integer_sqrt(n: u128) -> u128 {
+    if n < 2 {
+        return n;
+    }
+    // n / 2 + 1 >= sqrt(n), and the iterates decrease monotonically to floor(sqrt(n))
+    let mut x = n / 2 + 1;
+    loop {
+        let y = (x + n / x) / 2;
+        if y >= x {
+            return x;
+        }
+        x = y;
+    }
+}
+
 /// Tally result: vote counts and voting power
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VoteTally {
@@ -189,9 +295,13 @@ pub struct VoteTally {
     /// Total stake that participated
     pub stake_total: u128,
     
-    /// Percentage approval (0-100)
+    /// Percentage approval (0-100), measured on voting weights
     pub approval_percentage: u64,
     
+    /// Weight conversion used for the approval percentage
+    #[serde(default)]
+    pub mode: TallyMode,
+    
     /// Proposal approved
     pub approved: bool,
     
@@ -200,27 +310,80 @@ pub struct VoteTally {
 }
 
 impl VoteTally {
+    /// Compute a tally from `(approval, stake)` ballots
+    /// 
+    /// Quorum is always measured on raw participating stake; the approval
+    /// percentage is measured on weights from `mode`.
     pub fn compute(
-        stake_approve: u128,
-        stake_reject: u128,
+        ballots: &[(bool, u128)],
         total_network_stake: u128,
         approval_threshold: u64, // percentage, 0-100
+        mode: TallyMode,
+    ) -> Result<Self, String> {
+        // Quorum: >33% of network stake must participate
+        let quorum_threshold = (total_network_stake / 3) + 1;
+        Self::compute_with_quorum_threshold(
+            ballots,
+            approval_threshold,
+            quorum_threshold,
+            mode,
+        )
+    }
+    
+    /// Compute a tally where participation must exceed `quorum_percentage`
+    /// (0-100) of network stake
+    pub fn compute_with_quorum(
+        ballots: &[(bool, u128)],
+        total_network_stake: u128,
+        approval_threshold: u64,
+        quorum_percentage: u64,
+        mode: TallyMode,
     ) -> Result<Self, String> {
+        let quorum_threshold =
+            total_network_stake.saturating_mul(quorum_percentage as u128) / 100;
+        Self::compute_with_quorum_threshold(
+            ballots,
+            approval_threshold,
+            quorum_threshold,
+            mode,
+        )
+    }
+    
+    fn compute_with_quorum_threshold(
+        ballots: &[(bool, u128)],
+        approval_threshold: u64,
+        quorum_threshold: u128,
+        mode: TallyMode,
+    ) -> Result<Self, String> {
+        let mut stake_approve = 0u128;
+        let mut stake_reject = 0u128;
+        let mut weight_approve = 0u128;
+        let mut weight_reject = 0u128;
+        
+        for &(approval, stake) in ballots {
+            if approval {
+                stake_approve = stake_approve.saturating_add(stake);
+                weight_approve = weight_approve.saturating_add(mode.weight(stake));
+            } else {
+                stake_reject = stake_reject.saturating_add(stake);
+                weight_reject = weight_reject.saturating_add(mode.weight(stake));
+            }
+        }
+        
         let stake_total = stake_approve.saturating_add(stake_reject);
         
         if stake_total == 0 {
             return Err("No votes cast".to_string());
         }
         
-        // Quorum: >33% of network stake must participate
-        let quorum_threshold = (total_network_stake / 3) + 1;
         let quorum_met = stake_total > quorum_threshold;
         
         // Approval percentage
-        let approval_percentage = if stake_total == 0 {
+        let weight_total = weight_approve.saturating_add(weight_reject);
+        let approval_percentage = if weight_total == 0 {
             0
         } else {
-            ((stake_approve * 100) / stake_total) as u64
+            (weight_approve.saturating_mul(100) / weight_total) as u64
         };
         
         // Approved if quorum met AND approval >= threshold
@@ -231,6 +394,7 @@ impl VoteTally {
             stake_reject,
             stake_total,
             approval_percentage,
+            mode,
             approved,
             quorum_met,
         })
@@ -274,6 +438,15 @@ pub enum GovernanceError {
     
     #[error("Invalid proposal: {0}")]
     InvalidProposal(String),
+    
+    #[error("Insufficient deposit: required {required}, provided {provided}")]
+    InsufficientDeposit { required: u128, provided: u128 },
+    
+    #[error("Deposit escrow failed: {0}")]
+    DepositEscrowFailed(String),
+    
+    #[error("{0} is not a constitutional guardian")]
+    NotGuardian(String),
 }
 
 
@@ -318,6 +491,50 @@ pub struct Proposal {
     
     /// Timestamp when proposal was created (for audit)
     pub created_epoch: u64,
+    
+    /// Constitutional emergency clause invoked (flags the proposal as emergency)
+    #[serde(default)]
+    pub emergency_clause: Option<String>,
+    
+    /// Validator stakes frozen when voting started (None until then)
+    #[serde(default)]
+    pub stake_snapshot: Option<BTreeMap<String, u128>>,
+    
+    /// Standing delegations frozen when voting started (None until then)
+    #[serde(default)]
+    pub delegation_snapshot: Option<BTreeMap<String, String>>,
+    
+    /// Delegated stake credited to each voter when voting closed
+    #[serde(default)]
+    pub delegated_stake: BTreeMap<String, u128>,
+    
+    /// Account the deposit is escrowed from and refunded to
+    #[serde(default)]
+    pub proposer: String,
+    
+    /// Deposit locked at submission
+    #[serde(default)]
+    pub deposit: u128,
+    
+    /// Deposit outcome (settled when voting closes)
+    #[serde(default)]
+    pub deposit_status: DepositStatus,
+    
+    /// Epochs between approval and the earliest possible execution
+    #[serde(default)]
+    pub timelock_epochs: u64,
+    
+    /// Epoch the timelock elapses (set when the timelock starts)
+    #[serde(default)]
+    pub timelock_end_epoch: Option<u64>,
+    
+    /// Guardian's reason, if vetoed during the timelock
+    #[serde(default)]
+    pub veto_reason: Option<String>,
+    
+    /// Weight conversion used when tallying
+    #[serde(default)]
+    pub tally_mode: TallyMode,
 }
 
 impl Proposal {
@@ -346,9 +563,78 @@ impl Proposal {
             payload,
             previous_state: None,
             created_epoch,
+            emergency_clause: None,
+            stake_snapshot: None,
+            delegation_snapshot: None,
+            delegated_stake: BTreeMap::new(),
+            proposer: String::new(),
+            deposit: 0,
+            deposit_status: DepositStatus::Locked,
+            timelock_epochs: DEFAULT_TIMELOCK_EPOCHS,
+            timelock_end_epoch: None,
+            veto_reason: None,
+            tally_mode: proposal_type.default_tally_mode(),
         }
     }
     
+    /// Flag this proposal as an emergency under a constitutional clause
+    pub fn with_emergency_clause(mut self, clause_id: String) -> Self {
+        self.emergency_clause = Some(clause_id);
+        self
+    }
+    
+    /// Attach the deposit to escrow from `proposer` on submission
+    pub fn with_deposit(mut self, proposer: impl Into<String>, deposit: u128) -> Self {
+        self.proposer = proposer.into();
+        self.deposit = deposit;
+        self
+    }
+    
+    /// Set the timelock between approval and execution
+    pub fn with_timelock(mut self, timelock_epochs: u64) -> Self {
+        self.timelock_epochs = timelock_epochs;
+        self
+    }
+    
+    /// Set the tally mode (e.g. quadratic to reduce whale dominance)
+    pub fn with_tally_mode(mut self, tally_mode: TallyMode) -> Self {
+        self.tally_mode = tally_mode;
+        self
+    }
+    
+    /// Check if this proposal is flagged as an emergency
+    pub fn is_emergency(&self) -> bool {
+        self.emergency_clause.is_some()
+    }
+    
+    /// Derive the canonical proposal ID
+    /// 
+    /// SAFETY: The ID is SHA256 over every field that affects the outcome
+    /// (type, payload, voting window, execution epoch, threshold, tally mode,
+    /// emergency clause, proposer, deposit, timelock) and the created epoch, so
+    /// identical proposals always map to the same ID on every node and a
+    /// caller cannot pick an ID for arbitrary content. Title and description
+    /// are informational and excluded.
+    pub fn compute_id(&self) -> Result<String, GovernanceError> {
+        let serialized = bincode::serialize(&(
+            &self.proposal_type,
+            &self.payload,
+            &self.voting_window,
+            &self.created_epoch,
+            &self.execution_epoch,
+            &self.approval_threshold,
+            &self.tally_mode,
+            &self.emergency_clause,
+            &self.proposer,
+            &self.deposit,
+            &self.timelock_epochs,
+        )).map_err(|e| GovernanceError::InternalError(e.to_string()))?;
+        
+        let mut hasher = Sha256::new();
+        hasher.update(&serialized);
+        Ok(hex::encode(hasher.finalize()))
+    }
+    
     /// Submit proposal (Draft → Pending)
     pub fn submit(&mut self) -> Result<(), GovernanceError> {
         if self.state != ProposalState::Draft {
@@ -376,8 +662,29 @@ impl Proposal {
         Ok(())
     }
     
+    /// Start voting with validator stakes and delegations frozen at the
+    /// window start
+    /// 
+    /// SAFETY: Later stake changes (slashing, unstaking, new validators) and
+    /// later delegation changes do not affect this proposal's eligibility or
+    /// vote weights.
+    pub fn start_voting_with_snapshot(
+        &mut self,
+        current_epoch: u64,
+        stakes: BTreeMap<String, u128>,
+        delegations: BTreeMap<String, String>,
+    ) -> Result<(), GovernanceError> {
+        self.start_voting(current_epoch)?;
+        self.stake_snapshot = Some(stakes);
+        self.delegation_snapshot = Some(delegations);
+        Ok(())
+    }
+    
     /// Cast a vote (adds to vote map, checks for double voting)
-    pub fn cast_vote(&mut self, vote: Vote, current_epoch: u64) -> Result<(), GovernanceError> {
+    /// 
+    /// With a stake snapshot, only snapshotted validators may vote and the
+    /// vote's weight is pinned to their snapshotted stake.
+    pub fn cast_vote(&mut self, mut vote: Vote, current_epoch: u64) -> Result<(), GovernanceError> {
         if self.state != ProposalState::Voting {
             return Err(GovernanceError::VotingNotActive);
         }
@@ -391,16 +698,25 @@ impl Proposal {
             return Err(GovernanceError::DoubleVoting(vote.validator_id.clone()));
         }
         
+        if let Some(snapshot) = &self.stake_snapshot {
+            vote.stake = *snapshot.get(&vote.validator_id)
+                .ok_or(GovernanceError::InvalidValidator)?;
+        }
+        
         self.votes.insert(vote.validator_id.clone(), vote);
         info!("Vote cast for proposal {} by validator", self.id);
         Ok(())
     }
     
     /// Close voting and compute tally (Voting → Tallying)
+    /// 
+    /// `delegated_stake` maps a voter to the stake delegated to them, which
+    /// is counted in the direction of their vote.
     pub fn close_voting(
         &mut self,
         current_epoch: u64,
         total_network_stake: u128,
+        delegated_stake: &HashMap<String, u128>,
     ) -> Result<(), GovernanceError> {
         if self.state != ProposalState::Voting {
             return Err(GovernanceError::InvalidStateTransition(
@@ -413,33 +729,47 @@ impl Proposal {
         }
         
         // Compute tally
-        let mut stake_approve = 0u128;
-        let mut stake_reject = 0u128;
-        
-        for (_, vote) in &self.votes {
-            if vote.approval {
-                stake_approve = stake_approve.saturating_add(vote.stake);
-            } else {
-                stake_reject = stake_reject.saturating_add(vote.stake);
-            }
-        }
+        self.delegated_stake = delegated_stake
+            .iter()
+            .filter(|(validator_id, _)| self.votes.contains_key(*validator_id))
+            .map(|(validator_id, stake)| (validator_id.clone(), *stake))
+            .collect();
+        let ballots = self.effective_ballots();
         
-        let tally = VoteTally::compute(
-            stake_approve,
-            stake_reject,
-            total_network_stake,
-            self.approval_threshold,
-        ).map_err(|e| GovernanceError::InternalError(e))?;
+        // SAFETY: Emergency proposals trade a shorter window for a higher quorum
+        let tally = if self.is_emergency() {
+            VoteTally::compute_with_quorum(
+                &ballots,
+                total_network_stake,
+                self.approval_threshold,
+                EMERGENCY_QUORUM_PERCENTAGE,
+                self.tally_mode,
+            )
+        } else {
+            VoteTally::compute(
+                &ballots,
+                total_network_stake,
+                self.approval_threshold,
+                self.tally_mode,
+            )
+        }.map_err(GovernanceError::InternalError)?;
         
         self.tally = Some(tally.clone());
         
+        // SAFETY: Only proposals that fail to reach quorum lose their deposit
+        self.deposit_status = if tally.quorum_met {
+            DepositStatus::Refunded
+        } else {
+            DepositStatus::Forfeited
+        };
+        
         if tally.approved {
             self.state = ProposalState::AwaitingExecution;
             info!("Proposal {} approved with {:.1}% stake", self.id, tally.approval_percentage);
         } else {
             if !tally.quorum_met {
                 self.state = ProposalState::Expired;
-                info!("Proposal {} expired: quorum not met", self.id);
+                info!("Proposal {} expired: quorum not met, deposit {} forfeited", self.id, self.deposit);
             } else {
                 self.state = ProposalState::Rejected;
                 info!("Proposal {} rejected: {:.1}% approval", self.id, tally.approval_percentage);
@@ -449,17 +779,153 @@ impl Proposal {
         Ok(())
     }
     
-    /// Execute proposal (AwaitingExecution → Executing → Executed)
+    /// Each vote's `(approval, stake)` including delegated stake
+    fn effective_ballots(&self) -> Vec<(bool, u128)> {
+        self.votes
+            .iter()
+            .map(|(validator_id, vote)| {
+                let delegated = self.delegated_stake.get(validator_id).copied().unwrap_or(0);
+                (vote.approval, vote.stake.saturating_add(delegated))
+            })
+            .collect()
+    }
+    
+    /// Sum approving and rejecting stake, including delegated stake
+    fn stake_totals(&self) -> (u128, u128) {
+        self.effective_ballots()
+            .into_iter()
+            .fold((0u128, 0u128), |(approve, reject), (approval, stake)| {
+                if approval {
+                    (approve.saturating_add(stake), reject)
+                } else {
+                    (approve, reject.saturating_add(stake))
+                }
+            })
+    }
+    
+    /// Commit to the recorded vote set
+    /// 
+    /// SHA256 over each vote's hash and delegated stake, in validator ID
+    /// order so every node derives the same commitment.
+    fn votes_commitment(&self) -> Vec<u8> {
+        let mut validator_ids: Vec<_> = self.votes.keys().collect();
+        validator_ids.sort();
+        
+        let mut hasher = Sha256::new();
+        for validator_id in validator_ids {
+            let delegated = self.delegated_stake.get(validator_id).copied().unwrap_or(0);
+            hasher.update(self.votes[validator_id].compute_hash(&self.id));
+            hasher.update(delegated.to_le_bytes());
+        }
+        hasher.finalize().to_vec()
+    }
+    
+    /// Generate a proof binding the tally totals to the recorded votes
+    pub fn generate_tally_proof(&self) -> TallyProof {
+        let (stake_approve, stake_reject) = self.stake_totals();
+        TallyProof::new(
+            self.id.clone(),
+            self.votes_commitment(),
+            self.votes.len(),
+            stake_approve,
+            stake_reject,
+        )
+    }
+    
+    /// Delegated stake each voter is owed under the snapshotted stakes and
+    /// delegations (empty without snapshots: nothing to delegate from)
+    fn recompute_delegated_stake(&self) -> BTreeMap<String, u128> {
+        match (&self.stake_snapshot, &self.delegation_snapshot) {
+            (Some(stakes), Some(delegations)) => {
+                GovernanceEngine::resolve_delegated_stake(stakes, delegations, &self.votes)
+                    .into_iter()
+                    .filter(|(_, stake)| *stake > 0)
+                    .collect()
+            }
+            _ => BTreeMap::new(),
+        }
+    }
+    
+    /// Verify a tally proof against the recorded votes
+    /// 
+    /// SAFETY: Recomputes the delegated stake from the snapshots, then the
+    /// commitment and totals from the votes, so a proof (or stored tally)
+    /// reporting totals the votes do not support is rejected without
+    /// trusting the engine that produced it.
+    pub fn verify_tally_proof(&self, proof: &TallyProof) -> bool {
+        let stored: BTreeMap<_, _> = self.delegated_stake
+            .iter()
+            .filter(|(_, stake)| **stake > 0)
+            .map(|(voter, stake)| (voter.clone(), *stake))
+            .collect();
+        if stored != self.recompute_delegated_stake() {
+            return false;
+        }
+        if !proof.verify_hash() || *proof != self.generate_tally_proof() {
+            return false;
+        }
+        
+        match &self.tally {
+            Some(tally) => {
+                tally.stake_approve == proof.stake_approve
+                    && tally.stake_reject == proof.stake_reject
+            }
+            None => true,
+        }
+    }
+    
+    /// Start the timelock (AwaitingExecution → Timelocked)
+    pub fn start_timelock(&mut self, current_epoch: u64) -> Result<(), GovernanceError> {
+        if self.state != ProposalState::AwaitingExecution {
+            return Err(GovernanceError::InvalidStateTransition(
+                format!("Cannot start timelock from state {:?}", self.state)
+            ));
+        }
+        let end_epoch = current_epoch.saturating_add(self.timelock_epochs);
+        self.timelock_end_epoch = Some(end_epoch);
+        self.state = ProposalState::Timelocked;
+        info!("Proposal {} timelocked until epoch {}", self.id, end_epoch);
+        Ok(())
+    }
+    
+    /// Veto during the timelock (Timelocked → Rejected)
+    pub fn veto(&mut self, reason: String) -> Result<(), GovernanceError> {
+        if self.state != ProposalState::Timelocked {
+            return Err(GovernanceError::InvalidStateTransition(
+                format!("Can only veto during timelock, not from state {:?}", self.state)
+            ));
+        }
+        warn!("Proposal {} vetoed: {}", self.id, reason);
+        self.veto_reason = Some(reason);
+        self.state = ProposalState::Rejected;
+        Ok(())
+    }
+    
+    /// Check whether the timelock has elapsed at `current_epoch`
+    pub fn timelock_elapsed(&self, current_epoch: u64) -> bool {
+        matches!(self.timelock_end_epoch, Some(end) if current_epoch >= end)
+    }
+    
+    /// Execute proposal (Timelocked → Executing → Executed)
+    /// 
+    /// SAFETY: Execution is refused until both the execution epoch is
+    /// reached and the timelock has elapsed.
     pub fn execute(
         &mut self,
         current_epoch: u64,
     ) -> Result<(), GovernanceError> {
-        if self.state != ProposalState::AwaitingExecution {
+        if self.state != ProposalState::Timelocked {
             return Err(GovernanceError::InvalidStateTransition(
                 format!("Cannot execute from state {:?}", self.state)
             ));
         }
         
+        if !self.timelock_elapsed(current_epoch) {
+            return Err(GovernanceError::ExecutionFailed(
+                "Timelock has not elapsed".to_string()
+            ));
+        }
+        
         if current_epoch < self.execution_epoch {
             return Err(GovernanceError::ExecutionFailed(
                 "Execution epoch not reached".to_string()
@@ -507,6 +973,101 @@ impl Proposal {
             ))
         }
     }
+    
+    /// Check that the proposal's fields agree with its state
+    /// 
+    /// SAFETY: A tally exists exactly once voting has closed, only approved
+    /// tallies reach execution, and the deposit is settled with the tally.
+    pub fn check_state_consistency(&self) -> Result<(), GovernanceError> {
+        let inconsistent = |reason: &str| {
+            Err(GovernanceError::InvalidStateTransition(
+                format!("Proposal {} in state {:?}: {}", self.id, self.state, reason)
+            ))
+        };
+        
+        let tally = match (self.state, &self.tally) {
+            (ProposalState::Draft | ProposalState::Pending | ProposalState::Voting, None) => return Ok(()),
+            (ProposalState::Draft | ProposalState::Pending | ProposalState::Voting, Some(_)) => {
+                return inconsistent("tally recorded before voting closed");
+            }
+            (_, None) => return inconsistent("voting closed without a tally"),
+            (_, Some(tally)) => tally,
+        };
+        
+        if self.deposit_status == DepositStatus::Locked {
+            return inconsistent("deposit still locked after voting closed");
+        }
+        
+        match self.state {
+            ProposalState::AwaitingExecution
+            | ProposalState::Timelocked
+            | ProposalState::Executing
+            | ProposalState::Executed
+                if !tally.approved => inconsistent("tally did not approve the proposal"),
+            ProposalState::Timelocked | ProposalState::Executing | ProposalState::Executed
+                if self.timelock_end_epoch.is_none() => inconsistent("timelock never started"),
+            ProposalState::Expired if tally.quorum_met => inconsistent("quorum was met"),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Commitment to a proposal's vote set and the totals computed from it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TallyProof {
+    /// Proposal the tally belongs to
+    pub proposal_id: String,
+    
+    /// SHA256 commitment over the recorded votes
+    pub votes_commitment: Vec<u8>,
+    
+    /// Number of votes committed to
+    pub vote_count: usize,
+    
+    /// Total approving stake (including delegated stake)
+    pub stake_approve: u128,
+    
+    /// Total rejecting stake (including delegated stake)
+    pub stake_reject: u128,
+    
+    /// SHA256 over all fields above
+    pub proof_hash: Vec<u8>,
+}
+
+impl TallyProof {
+    fn new(
+        proposal_id: String,
+        votes_commitment: Vec<u8>,
+        vote_count: usize,
+        stake_approve: u128,
+        stake_reject: u128,
+    ) -> Self {
+        let mut proof = TallyProof {
+            proposal_id,
+            votes_commitment,
+            vote_count,
+            stake_approve,
+            stake_reject,
+            proof_hash: Vec::new(),
+        };
+        proof.proof_hash = proof.compute_hash();
+        proof
+    }
+    
+    fn compute_hash(&self) -> Vec<u8> {
+        let mut hasher = Sha256::new();
+        hasher.update(self.proposal_id.as_bytes());
+        hasher.update(&self.votes_commitment);
+        hasher.update((self.vote_count as u64).to_le_bytes());
+        hasher.update(self.stake_approve.to_le_bytes());
+        hasher.update(self.stake_reject.to_le_bytes());
+        hasher.finalize().to_vec()
+    }
+    
+    /// Check the proof hash matches the proof contents
+    pub fn verify_hash(&self) -> bool {
+        self.compute_hash() == self.proof_hash
+    }
 }
 
 /// Governance execution payload (what gets executed)
@@ -573,6 +1134,66 @@ impl GovernancePayload {
     }
 }
 
+/// Live validator stake, read when a proposal opens for voting
+/// (validator ID → stake)
+pub type StakeSource = Arc<dyn Fn() -> BTreeMap<String, u128> + Send + Sync>;
+
+/// Balance book proposal deposits are escrowed in
+pub trait DepositLedger: Send + Sync {
+    /// Move `amount` from `account` into escrow
+    fn escrow(&self, account: &str, amount: u128) -> Result<(), String>;
+    
+    /// Return `amount` from escrow to `account`
+    fn refund(&self, account: &str, amount: u128) -> Result<(), String>;
+    
+    /// Destroy `amount` held in escrow
+    fn burn(&self, amount: u128) -> Result<(), String>;
+}
+
+/// Deposit ledger backed by account balances in the state manager
+/// 
+/// Escrowed deposits sit in `GOVERNANCE_ESCROW_ACCOUNT`; burning removes
+/// them from that account and therefore from total supply.
+pub struct StateDepositLedger {
+    state: Arc<Mutex<StateManager>>,
+}
+
+impl StateDepositLedger {
+    pub fn new(state: Arc<Mutex<StateManager>>) -> Self {
+        StateDepositLedger { state }
+    }
+    
+    fn transfer(state: &mut StateManager, from: &str, to: &str, amount: u128) -> Result<(), String> {
+        let from_balance = state.get_balance(from).checked_sub(amount).ok_or_else(|| {
+            format!("{} holds {}, needs {}", from, state.get_balance(from), amount)
+        })?;
+        let to_balance = state.get_balance(to).checked_add(amount).ok_or_else(|| {
+            format!("{} balance overflow", to)
+        })?;
+        state.set_balance(from, from_balance);
+        state.set_balance(to, to_balance);
+        Ok(())
+    }
+}
+
+impl DepositLedger for StateDepositLedger {
+    fn escrow(&self, account: &str, amount: u128) -> Result<(), String> {
+        Self::transfer(&mut self.state.lock(), account, GOVERNANCE_ESCROW_ACCOUNT, amount)
+    }
+    
+    fn refund(&self, account: &str, amount: u128) -> Result<(), String> {
+        Self::transfer(&mut self.state.lock(), GOVERNANCE_ESCROW_ACCOUNT, account, amount)
+    }
+    
+    fn burn(&self, amount: u128) -> Result<(), String> {
+        let mut state = self.state.lock();
+        let remaining = state.get_balance(GOVERNANCE_ESCROW_ACCOUNT).checked_sub(amount)
+            .ok_or_else(|| format!("escrow holds less than {}", amount))?;
+        state.set_balance(GOVERNANCE_ESCROW_ACCOUNT, remaining);
+        Ok(())
+    }
+}
+
 /// On-Chain Governance Engine (coordinating all proposals)
 pub struct GovernanceEngine {
     /// All proposals ever submitted
@@ -583,6 +1204,33 @@ pub struct GovernanceEngine {
     
     /// Total network stake (used for quorum calculation)
     total_network_stake: u128,
+    
+    /// Shared epoch source
+    clock: EpochClock,
+    
+    /// Registered voting stake per validator (snapshotted when voting starts)
+    stakes: BTreeMap<String, u128>,
+    
+    /// Validator registry view; supersedes `stakes` when attached
+    stake_source: Option<StakeSource>,
+    
+    /// Standing delegations: delegator → delegate
+    delegations: BTreeMap<String, String>,
+    
+    /// Minimum deposit required to submit a proposal
+    min_deposit: u128,
+    
+    /// Per-type overrides of the minimum voting duration (epochs)
+    min_voting_epochs: HashMap<ProposalType, u64>,
+    
+    /// Minimum timelock between voting close and execution (epochs)
+    min_execution_delay: u64,
+    
+    /// Where proposal deposits are escrowed, refunded and burned
+    deposit_ledger: Option<Arc<dyn DepositLedger>>,
+    
+    /// Constitutional guardians allowed to veto timelocked proposals
+    guardians: BTreeSet<String>,
 }
 
 impl GovernanceEngine {
@@ -591,18 +1239,156 @@ impl GovernanceEngine {
             proposals: HashMap::new(),
             proposal_queue: Vec::new(),
             total_network_stake,
+            clock: EpochClock::default(),
+            stakes: BTreeMap::new(),
+            stake_source: None,
+            delegations: BTreeMap::new(),
+            min_deposit: MIN_PROPOSAL_DEPOSIT,
+            min_voting_epochs: HashMap::new(),
+            min_execution_delay: MIN_EXECUTION_DELAY_EPOCHS,
+            deposit_ledger: None,
+            guardians: BTreeSet::new(),
         }
     }
     
+    /// Override the minimum timelock between voting close and execution
+    pub fn with_min_execution_delay(mut self, epochs: u64) -> Self {
+        self.min_execution_delay = epochs;
+        self
+    }
+    
+    /// Override the minimum proposal deposit
+    pub fn with_min_deposit(mut self, min_deposit: u128) -> Self {
+        self.min_deposit = min_deposit;
+        self
+    }
+    
+    /// Read voting stake from the validator registry when voting opens
+    pub fn with_stake_source(mut self, stake_source: StakeSource) -> Self {
+        self.stake_source = Some(stake_source);
+        self
+    }
+    
+    /// Escrow proposal deposits in the given ledger
+    pub fn with_deposit_ledger(mut self, ledger: Arc<dyn DepositLedger>) -> Self {
+        self.deposit_ledger = Some(ledger);
+        self
+    }
+    
+    /// Set the constitutional guardians allowed to veto
+    pub fn with_guardians<I: IntoIterator<Item = String>>(mut self, guardians: I) -> Self {
+        self.guardians = guardians.into_iter().collect();
+        self
+    }
+    
+    /// Voting stake to snapshot for a proposal opening now
+    fn voting_stakes(&self) -> BTreeMap<String, u128> {
+        match &self.stake_source {
+            Some(source) => source(),
+            None => self.stakes.clone(),
+        }
+    }
+    
+    /// Attach a shared epoch clock
+    pub fn with_clock(mut self, clock: EpochClock) -> Self {
+        self.clock = clock;
+        self
+    }
+    
+    /// Override the minimum voting duration for a proposal type
+    pub fn with_min_voting_epochs(mut self, proposal_type: ProposalType, epochs: u64) -> Self {
+        self.min_voting_epochs.insert(proposal_type, epochs);
+        self
+    }
+    
+    /// Minimum voting duration (epochs) enforced for a proposal type
+    pub fn min_voting_epochs(&self, proposal_type: ProposalType) -> u64 {
+        self.min_voting_epochs
+            .get(&proposal_type)
+            .copied()
+            .unwrap_or_else(|| proposal_type.default_min_voting_epochs())
+    }
+    
+    /// Current epoch according to the shared clock
+    pub fn current_epoch(&self) -> u64 {
+        self.clock.current_epoch()
+    }
+    
+    /// Advance proposals to the shared clock's current epoch
+    pub fn sync_with_clock(&mut self) -> Result<(), GovernanceError> {
+        let epoch = self.clock.current_epoch();
+        self.advance_epoch(epoch)
+    }
+    
     /// Submit a new proposal
-    pub fn submit_proposal(&mut self, mut proposal: Proposal) -> Result<String, GovernanceError> {
+    /// 
+    /// SAFETY: The voting window must last at least the minimum for the
+    /// proposal type, and execution must trail the end of voting by at least
+    /// the minimum timelock so users can react to approved changes.
+    pub fn submit_proposal(&mut self, proposal: Proposal) -> Result<String, GovernanceError> {
+        let min_epochs = self.min_voting_epochs(proposal.proposal_type);
+        let window = &proposal.voting_window;
+        if window.end_epoch.saturating_sub(window.start_epoch) < min_epochs {
+            return Err(GovernanceError::InvalidProposal(format!(
+                "{} proposals require a voting window of at least {} epochs",
+                proposal.proposal_type.as_str(),
+                min_epochs
+            )));
+        }
+        
+        let earliest_execution = window.end_epoch.saturating_add(self.min_execution_delay);
+        if proposal.execution_epoch < earliest_execution {
+            return Err(GovernanceError::InvalidProposal(format!(
+                "Execution epoch {} is before voting end {} plus timelock {}",
+                proposal.execution_epoch, window.end_epoch, self.min_execution_delay
+            )));
+        }
+        
+        self.insert_proposal(proposal)
+    }
+    
+    /// Validate ID and deposit, then queue the proposal
+    fn insert_proposal(&mut self, mut proposal: Proposal) -> Result<String, GovernanceError> {
+        let expected_id = proposal.compute_id()?;
+        if proposal.id != expected_id {
+            return Err(GovernanceError::InvalidProposal(format!(
+                "Proposal ID {} does not match computed ID {}",
+                proposal.id, expected_id
+            )));
+        }
+        
         if self.proposals.contains_key(&proposal.id) {
             return Err(GovernanceError::InvalidProposal(
                 "Proposal already exists".to_string()
             ));
         }
         
+        if proposal.deposit < self.min_deposit {
+            return Err(GovernanceError::InsufficientDeposit {
+                required: self.min_deposit,
+                provided: proposal.deposit,
+            });
+        }
+        proposal.deposit_status = DepositStatus::Locked;
+        
         proposal.submit()?;
+        
+        // SAFETY: The deposit leaves the proposer's balance before the
+        // proposal is queued; nothing is stored if the escrow fails
+        if proposal.deposit > 0 {
+            if proposal.proposer.is_empty() {
+                return Err(GovernanceError::DepositEscrowFailed(
+                    "Proposal has no proposer account".to_string()
+                ));
+            }
+            let ledger = self.deposit_ledger.as_ref().ok_or_else(|| {
+                GovernanceError::DepositEscrowFailed("No deposit ledger attached".to_string())
+            })?;
+            ledger
+                .escrow(&proposal.proposer, proposal.deposit)
+                .map_err(GovernanceError::DepositEscrowFailed)?;
+        }
+        
         let proposal_id = proposal.id.clone();
         self.proposals.insert(proposal_id.clone(), proposal);
         self.proposal_queue.push(proposal_id.clone());
@@ -611,6 +1397,120 @@ impl GovernanceEngine {
         Ok(proposal_id)
     }
     
+    /// Submit a proposal on the emergency fast-track
+    /// 
+    /// SAFETY: Only proposals flagged with an emergency clause that exists in
+    /// the constitution and covers the proposal type are accepted. The voting
+    /// window is shortened to `EMERGENCY_VOTING_EPOCHS` starting now, and the
+    /// approval threshold and quorum are raised. The per-type minimum window
+    /// does not apply and the execution timelock is set to zero. Because the
+    /// window changes, the proposal ID is recomputed; use the returned ID.
+    pub fn submit_emergency_proposal(
+        &mut self,
+        mut proposal: Proposal,
+        constitution: &BLEEPConstitution,
+    ) -> Result<String, GovernanceError> {
+        let clause_id = proposal.emergency_clause.as_deref().ok_or_else(|| {
+            GovernanceError::InvalidProposal(
+                "Proposal is not flagged as emergency".to_string()
+            )
+        })?;
+        
+        let clause = constitution.emergency_clause(clause_id).ok_or_else(|| {
+            GovernanceError::InvalidProposal(
+                format!("Unknown emergency clause {}", clause_id)
+            )
+        })?;
+        
+        if !clause.permits(proposal.proposal_type) {
+            return Err(GovernanceError::InvalidProposal(format!(
+                "Emergency clause {} does not cover {}",
+                clause.id,
+                proposal.proposal_type.as_str()
+            )));
+        }
+        
+        let start_epoch = self.current_epoch();
+        proposal.voting_window = VotingWindow::new(start_epoch, start_epoch + EMERGENCY_VOTING_EPOCHS)
+            .map_err(GovernanceError::InvalidProposal)?;
+        proposal.execution_epoch = proposal.voting_window.end_epoch;
+        proposal.timelock_epochs = 0;
+        proposal.approval_threshold = proposal.approval_threshold.max(EMERGENCY_APPROVAL_THRESHOLD);
+        proposal.id = proposal.compute_id()?;
+        
+        warn!("Emergency proposal {} fast-tracked under clause {}", proposal.id, clause.id);
+        self.insert_proposal(proposal)
+    }
+    
+    /// Register (or update) a validator's voting stake
+    /// 
+    /// Ignored while a stake source is attached. Proposals already in voting
+    /// keep the stakes snapshotted at their start.
+    pub fn register_stake(&mut self, validator_id: String, stake: u128) {
+        self.stakes.insert(validator_id, stake);
+    }
+    
+    /// Delegate `delegator`'s voting power to `delegate`
+    /// 
+    /// Delegation is transitive and applies to every proposal that opens for
+    /// voting afterwards and that the delegator does not vote on directly.
+    /// Loops are permitted here and broken at tally.
+    pub fn delegate_vote(&mut self, delegator: String, delegate: String) -> Result<(), GovernanceError> {
+        if delegator == delegate {
+            return Err(GovernanceError::InvalidValidator);
+        }
+        info!("Validator {} delegated voting power to {}", delegator, delegate);
+        self.delegations.insert(delegator, delegate);
+        Ok(())
+    }
+    
+    /// Withdraw a standing delegation
+    pub fn revoke_delegation(&mut self, delegator: &str) {
+        self.delegations.remove(delegator);
+    }
+    
+    /// Resolve delegations against the votes actually cast
+    /// 
+    /// SAFETY: Each non-voting delegator's stake goes to the first voter on
+    /// their delegation chain. A delegator who voted keeps their own vote.
+    /// A chain that revisits a node is a loop containing no voter; its stake
+    /// abstains, so the outcome does not depend on iteration order.
+    fn resolve_delegated_stake(
+        stakes: &BTreeMap<String, u128>,
+        delegations: &BTreeMap<String, String>,
+        votes: &HashMap<String, Vote>,
+    ) -> HashMap<String, u128> {
+        let mut delegated: HashMap<String, u128> = HashMap::new();
+        
+        for delegator in delegations.keys() {
+            if votes.contains_key(delegator) {
+                continue;
+            }
+            
+            let mut visited = HashSet::from([delegator.as_str()]);
+            let mut current = delegator.as_str();
+            let voter = loop {
+                match delegations.get(current) {
+                    Some(next) if votes.contains_key(next) => break Some(next),
+                    Some(next) if visited.insert(next.as_str()) => current = next,
+                    Some(_) => {
+                        warn!("Delegation loop from {} broken; stake abstains", delegator);
+                        break None;
+                    }
+                    None => break None,
+                }
+            };
+            
+            if let Some(voter) = voter {
+                let stake = stakes.get(delegator).copied().unwrap_or(0);
+                let entry = delegated.entry(voter.clone()).or_insert(0);
+                *entry = entry.saturating_add(stake);
+            }
+        }
+        
+        delegated
+    }
+    
     /// Get proposal by ID
     pub fn get_proposal(&self, id: &str) -> Result<&Proposal, GovernanceError> {
         self.proposals.get(id).ok_or(GovernanceError::ProposalNotFound)
@@ -627,8 +1527,9 @@ impl GovernanceEngine {
         proposal_id: &str,
         current_epoch: u64,
     ) -> Result<(), GovernanceError> {
-        let proposal = self.get_proposal_mut(proposal_id)?;
-        proposal.start_voting(current_epoch)
+        let stakes = self.voting_stakes();
+        let proposal = self.proposals.get_mut(proposal_id).ok_or(GovernanceError::ProposalNotFound)?;
+        proposal.start_voting_with_snapshot(current_epoch, stakes, self.delegations.clone())
     }
     
     /// Cast a vote
@@ -649,8 +1550,29 @@ impl GovernanceEngine {
         current_epoch: u64,
     ) -> Result<(), GovernanceError> {
         let total_stake = self.total_network_stake;
-        let proposal = self.get_proposal_mut(proposal_id)?;
-        proposal.close_voting(current_epoch, total_stake)
+        let proposal = self.proposals.get_mut(proposal_id).ok_or(GovernanceError::ProposalNotFound)?;
+        let stakes = proposal.stake_snapshot.as_ref().unwrap_or(&self.stakes);
+        let delegations = proposal.delegation_snapshot.as_ref().unwrap_or(&self.delegations);
+        let delegated = Self::resolve_delegated_stake(stakes, delegations, &proposal.votes);
+        proposal.close_voting(current_epoch, total_stake, &delegated)?;
+        Self::settle_deposit(self.deposit_ledger.as_ref(), proposal);
+        Ok(())
+    }
+    
+    /// Refund or burn a deposit whose outcome was just decided
+    fn settle_deposit(ledger: Option<&Arc<dyn DepositLedger>>, proposal: &Proposal) {
+        let Some(ledger) = ledger else { return };
+        if proposal.deposit == 0 {
+            return;
+        }
+        let result = match proposal.deposit_status {
+            DepositStatus::Locked => return,
+            DepositStatus::Refunded => ledger.refund(&proposal.proposer, proposal.deposit),
+            DepositStatus::Forfeited => ledger.burn(proposal.deposit),
+        };
+        if let Err(e) = result {
+            error!("Deposit settlement for proposal {} failed: {}", proposal.id, e);
+        }
     }
     
     /// Execute proposal at execution epoch
@@ -663,6 +1585,38 @@ impl GovernanceEngine {
         proposal.execute(current_epoch)
     }
     
+    /// Constitutional guardian veto of a timelocked proposal
+    /// 
+    /// SAFETY: Only members of the guardian set may veto
+    pub fn veto(
+        &mut self,
+        guardian: &str,
+        proposal_id: &str,
+        reason: String,
+    ) -> Result<(), GovernanceError> {
+        if !self.guardians.contains(guardian) {
+            return Err(GovernanceError::NotGuardian(guardian.to_string()));
+        }
+        let proposal = self.get_proposal_mut(proposal_id)?;
+        proposal.veto(reason)
+    }
+    
+    /// Deposits currently locked by open proposals
+    pub fn locked_deposits(&self) -> u128 {
+        self.sum_deposits(DepositStatus::Locked)
+    }
+    
+    /// Deposits burned by proposals that expired without quorum
+    pub fn forfeited_deposits(&self) -> u128 {
+        self.sum_deposits(DepositStatus::Forfeited)
+    }
+    
+    fn sum_deposits(&self, status: DepositStatus) -> u128 {
+        self.proposals.values()
+            .filter(|p| p.deposit_status == status)
+            .fold(0u128, |acc, p| acc.saturating_add(p.deposit))
+    }
+    
     /// Get all proposals in a given state
     pub fn get_proposals_by_state(&self, state: ProposalState) -> Vec<&Proposal> {
         self.proposals.values()
@@ -670,9 +1624,28 @@ impl GovernanceEngine {
             .collect()
     }
     
+    /// Verify every queued proposal is known and internally consistent
+    /// 
+    /// Proposals are checked in submission order, so the first reported
+    /// inconsistency is the same on every node.
+    pub fn verify_state_machine(&self) -> Result<(), GovernanceError> {
+        if self.proposal_queue.len() != self.proposals.len() {
+            return Err(GovernanceError::InternalError(format!(
+                "{} queued proposals but {} stored",
+                self.proposal_queue.len(),
+                self.proposals.len()
+            )));
+        }
+        for proposal_id in &self.proposal_queue {
+            self.get_proposal(proposal_id)?.check_state_consistency()?;
+        }
+        Ok(())
+    }
+    
     /// Periodic tick: advance proposals through state machine based on epoch
     pub fn advance_epoch(&mut self, new_epoch: u64) -> Result<(), GovernanceError> {
         let proposal_ids: Vec<_> = self.proposal_queue.clone();
+        let voting_stakes = self.voting_stakes();
         
         for proposal_id in proposal_ids {
             let proposal = match self.proposals.get_mut(&proposal_id) {
@@ -683,17 +1656,33 @@ impl GovernanceEngine {
             // Transition: Pending → Voting
             if proposal.state == ProposalState::Pending 
                 && new_epoch >= proposal.voting_window.start_epoch {
-                let _ = proposal.start_voting(new_epoch);
+                let _ = proposal.start_voting_with_snapshot(
+                    new_epoch,
+                    voting_stakes.clone(),
+                    self.delegations.clone(),
+                );
             }
             
             // Transition: Voting → Tallying
             if proposal.state == ProposalState::Voting 
                 && new_epoch >= proposal.voting_window.end_epoch {
-                let _ = proposal.close_voting(new_epoch, self.total_network_stake);
+                let stakes = proposal.stake_snapshot.as_ref().unwrap_or(&self.stakes);
+                let delegations = proposal.delegation_snapshot.as_ref().unwrap_or(&self.delegations);
+                let delegated =
+                    Self::resolve_delegated_stake(stakes, delegations, &proposal.votes);
+                if proposal.close_voting(new_epoch, self.total_network_stake, &delegated).is_ok() {
+                    Self::settle_deposit(self.deposit_ledger.as_ref(), proposal);
+                }
             }
             
-            // Transition: AwaitingExecution → Executing
-            if proposal.state == ProposalState::AwaitingExecution 
+            // Transition: AwaitingExecution → Timelocked
+            if proposal.state == ProposalState::AwaitingExecution {
+                let _ = proposal.start_timelock(new_epoch);
+            }
+            
+            // Transition: Timelocked → Executing
+            if proposal.state == ProposalState::Timelocked 
+                && proposal.timelock_elapsed(new_epoch)
                 && new_epoch >= proposal.execution_epoch {
                 let _ = proposal.execute(new_epoch);
             }
@@ -734,6 +1723,55 @@ impl GovernanceEngine {
 mod tests {
     use super::*;
 
+    const PROPOSER: &str = "proposer";
+    const GUARDIAN: &str = "guardian";
+
+    /// In-memory balance book; burned deposits are tracked separately
+    #[derive(Default)]
+    struct TestLedger {
+        balances: Mutex<BTreeMap<String, u128>>,
+        burned: Mutex<u128>,
+    }
+
+    impl TestLedger {
+        fn balance(&self, account: &str) -> u128 {
+            self.balances.lock().get(account).copied().unwrap_or(0)
+        }
+
+        fn transfer(&self, from: &str, to: &str, amount: u128) -> Result<(), String> {
+            let mut balances = self.balances.lock();
+            let from_balance = balances.get(from).copied().unwrap_or(0);
+            let remaining = from_balance.checked_sub(amount).ok_or("insufficient balance")?;
+            balances.insert(from.to_string(), remaining);
+            *balances.entry(to.to_string()).or_insert(0) += amount;
+            Ok(())
+        }
+    }
+
+    impl DepositLedger for TestLedger {
+        fn escrow(&self, account: &str, amount: u128) -> Result<(), String> {
+            self.transfer(account, GOVERNANCE_ESCROW_ACCOUNT, amount)
+        }
+
+        fn refund(&self, account: &str, amount: u128) -> Result<(), String> {
+            self.transfer(GOVERNANCE_ESCROW_ACCOUNT, account, amount)
+        }
+
+        fn burn(&self, amount: u128) -> Result<(), String> {
+            self.transfer(GOVERNANCE_ESCROW_ACCOUNT, "burned", amount)?;
+            *self.burned.lock() += amount;
+            Ok(())
+        }
+    }
+
+    /// Engine whose deposit ledger funds `PROPOSER` with 1M
+    fn funded_engine(total_network_stake: u128) -> (GovernanceEngine, Arc<TestLedger>) {
+        let ledger = Arc::new(TestLedger::default());
+        ledger.balances.lock().insert(PROPOSER.to_string(), 1_000_000);
+        let engine = GovernanceEngine::new(total_network_stake).with_deposit_ledger(ledger.clone());
+        (engine, ledger)
+    }
+
     #[test]
     fn test_proposal_state_transitions() {
         let voting_window = VotingWindow::new(2, 4).unwrap();
@@ -805,14 +1843,689 @@ mod tests {
     #[test]
     fn test_tally_computation() {
         let tally = VoteTally::compute(
-            6700,  // 67% approve
-            3300,  // 33% reject
+            &[(true, 6700), (false, 3300)], // 67% approve, 33% reject
             10000, // total network stake
             67,    // 67% threshold
+            TallyMode::Linear,
         ).unwrap();
         
         assert_eq!(tally.approval_percentage, 67);
         assert!(tally.quorum_met); // 6700 + 3300 = 10000 > 10000/3
         assert!(tally.approved); // 67% >= 67% threshold
     }
+
+    #[test]
+    fn test_quadratic_tally_shrinks_whale_influence() {
+        // One whale approves; three small holders reject
+        let ballots = [(true, 9_000), (false, 1_000), (false, 1_000), (false, 1_000)];
+
+        let linear = VoteTally::compute(&ballots, 20_000, 67, TallyMode::Linear).unwrap();
+        assert_eq!(linear.approval_percentage, 75);
+        assert!(linear.approved);
+
+        // sqrt(9000) = 94 vs 3 * sqrt(1000) = 93
+        let quadratic = VoteTally::compute(&ballots, 20_000, 67, TallyMode::Quadratic).unwrap();
+        assert_eq!(quadratic.approval_percentage, 50);
+        assert!(!quadratic.approved);
+
+        // Quorum is still measured on raw stake
+        assert_eq!(quadratic.stake_total, 12_000);
+        assert!(quadratic.quorum_met);
+        let low = VoteTally::compute(&[(true, 100)], 20_000, 67, TallyMode::Quadratic).unwrap();
+        assert!(!low.quorum_met);
+
+        for n in 0..10_000u128 {
+            let r = integer_sqrt(n);
+            assert!(r * r <= n && (r + 1) * (r + 1) > n);
+        }
+        assert_eq!(integer_sqrt(100), 10);
+        assert_eq!(integer_sqrt(u128::MAX), u64::MAX as u128);
+    }
+
+    #[test]
+    fn test_tally_mode_defaults_per_proposal_type() {
+        assert_eq!(ProposalType::Recovery.default_tally_mode(), TallyMode::Quadratic);
+        assert_eq!(ProposalType::ValidatorSanction.default_tally_mode(), TallyMode::Linear);
+        assert_eq!(block_time_proposal(6000).tally_mode, TallyMode::Linear);
+
+        let recovery = Proposal::new(
+            String::new(),
+            ProposalType::Recovery,
+            "Restore validator".to_string(),
+            "Restore validator".to_string(),
+            VotingWindow::new(2, 6).unwrap(),
+            7,
+            67,
+            GovernancePayload::ProtocolParameterChange {
+                rule_name: "BLOCK_TIME".to_string(),
+                new_value: 6000,
+            },
+            1,
+        );
+        assert_eq!(recovery.tally_mode, TallyMode::Quadratic);
+    }
+
+    fn block_time_proposal(new_value: u128) -> Proposal {
+        let mut proposal = Proposal::new(
+            String::new(),
+            ProposalType::ProtocolParameter,
+            "Block time".to_string(),
+            "Block time".to_string(),
+            VotingWindow::new(2, 4).unwrap(),
+            5,
+            67,
+            GovernancePayload::ProtocolParameterChange {
+                rule_name: "BLOCK_TIME".to_string(),
+                new_value,
+            },
+            1,
+        ).with_deposit(PROPOSER, MIN_PROPOSAL_DEPOSIT);
+        proposal.id = proposal.compute_id().unwrap();
+        proposal
+    }
+
+    /// Re-derive the ID after editing a proposal's content
+    fn with_computed_id(mut proposal: Proposal) -> Proposal {
+        proposal.id = proposal.compute_id().unwrap();
+        proposal
+    }
+
+    #[test]
+    fn test_identical_proposals_have_identical_ids() {
+        let a = block_time_proposal(6000);
+        let b = block_time_proposal(6000);
+        assert_eq!(a.id, b.id);
+        assert_eq!(a.id.len(), 64);
+
+        // Title and description are not part of the canonical content
+        let mut c = block_time_proposal(6000);
+        c.title = "Different title".to_string();
+        assert_eq!(c.compute_id().unwrap(), a.id);
+
+        assert_ne!(block_time_proposal(7000).id, a.id);
+        let mut d = block_time_proposal(6000);
+        d.created_epoch = 2;
+        assert_ne!(d.compute_id().unwrap(), a.id);
+
+        // Every outcome-affecting field is bound into the ID
+        let variants = [
+            { let mut p = block_time_proposal(6000); p.approval_threshold = 51; p },
+            { let mut p = block_time_proposal(6000); p.execution_epoch = 9; p },
+            { let mut p = block_time_proposal(6000); p.deposit += 1; p },
+            { let mut p = block_time_proposal(6000); p.proposer = "mallory".to_string(); p },
+            block_time_proposal(6000).with_timelock(DEFAULT_TIMELOCK_EPOCHS + 1),
+            block_time_proposal(6000).with_tally_mode(TallyMode::Quadratic),
+            block_time_proposal(6000).with_emergency_clause("SECURITY_FIX".to_string()),
+        ];
+        for variant in variants {
+            assert_ne!(variant.compute_id().unwrap(), a.id);
+        }
+    }
+
+    #[test]
+    fn test_submit_rejects_mismatched_id() {
+        let mut engine = funded_engine(10_000).0;
+
+        let mut proposal = block_time_proposal(6000);
+        proposal.id = "prop-1".to_string();
+        assert!(matches!(
+            engine.submit_proposal(proposal),
+            Err(GovernanceError::InvalidProposal(_))
+        ));
+
+        // Content changed after the ID was computed
+        let mut proposal = block_time_proposal(6000);
+        proposal.voting_window = VotingWindow::new(2, 8).unwrap();
+        assert!(matches!(
+            engine.submit_proposal(proposal),
+            Err(GovernanceError::InvalidProposal(_))
+        ));
+
+        let proposal = block_time_proposal(6000);
+        let expected = proposal.id.clone();
+        assert_eq!(engine.submit_proposal(proposal).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_delegated_stake_counts_toward_delegate() {
+        let (mut engine, _) = funded_engine(10_000);
+        engine.register_stake("alice".to_string(), 3_000);
+        engine.register_stake("bob".to_string(), 1_000);
+        engine.delegate_vote("alice".to_string(), "bob".to_string()).unwrap();
+        let id = engine.submit_proposal(block_time_proposal(6000)).unwrap();
+        engine.advance_epoch(2).unwrap();
+
+        // Bob alone is below quorum; Alice's delegated stake carries it
+        engine.cast_vote(&id, Vote::new("bob".to_string(), true, 1_000, 2, vec![1]), 2).unwrap();
+        engine.advance_epoch(4).unwrap();
+
+        let tally = engine.get_proposal(&id).unwrap().tally.clone().unwrap();
+        assert_eq!(tally.stake_approve, 4_000);
+        assert!(tally.quorum_met);
+
+        assert!(engine.delegate_vote("bob".to_string(), "bob".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_own_vote_overrides_delegation() {
+        let (mut engine, _) = funded_engine(10_000);
+        engine.register_stake("alice".to_string(), 3_000);
+        engine.register_stake("bob".to_string(), 1_000);
+        engine.delegate_vote("alice".to_string(), "bob".to_string()).unwrap();
+        let id = engine.submit_proposal(block_time_proposal(6000)).unwrap();
+        engine.advance_epoch(2).unwrap();
+
+        engine.cast_vote(&id, Vote::new("bob".to_string(), true, 1_000, 2, vec![1]), 2).unwrap();
+        engine.cast_vote(&id, Vote::new("alice".to_string(), false, 3_000, 2, vec![2]), 2).unwrap();
+        engine.advance_epoch(4).unwrap();
+
+        let p = engine.get_proposal(&id).unwrap();
+        let tally = p.tally.as_ref().unwrap();
+        assert_eq!(tally.stake_approve, 1_000);
+        assert_eq!(tally.stake_reject, 3_000);
+        assert_eq!(p.state, ProposalState::Rejected);
+    }
+
+    #[test]
+    fn test_delegation_cycle_is_broken() {
+        // a → b → c → a loops; d feeds into the loop; e → f → voter
+        let (mut engine, _) = funded_engine(10_000);
+        for (who, stake) in [("a", 1_000), ("b", 1_000), ("c", 1_000), ("d", 500), ("e", 200), ("voter", 4_000)] {
+            engine.register_stake(who.to_string(), stake);
+        }
+        for (delegator, delegate) in [("a", "b"), ("b", "c"), ("c", "a"), ("d", "a"), ("e", "f"), ("f", "voter")] {
+            engine.delegate_vote(delegator.to_string(), delegate.to_string()).unwrap();
+        }
+        let id = engine.submit_proposal(block_time_proposal(6000)).unwrap();
+        engine.advance_epoch(2).unwrap();
+
+        engine.cast_vote(&id, Vote::new("voter".to_string(), true, 4_000, 2, vec![1]), 2).unwrap();
+        engine.close_voting(&id, 4).unwrap();
+
+        // Loop members and d abstain; e reaches the voter through f
+        let tally = engine.get_proposal(&id).unwrap().tally.clone().unwrap();
+        assert_eq!(tally.stake_approve, 4_200);
+        assert_eq!(tally.stake_reject, 0);
+
+        // Once a loop member votes, the rest of the loop resolves to them
+        let delegated = GovernanceEngine::resolve_delegated_stake(
+            &engine.stakes,
+            &engine.delegations,
+            &HashMap::from([("c".to_string(), Vote::new("c".to_string(), false, 1_000, 2, vec![]))]),
+        );
+        assert_eq!(delegated.get("c"), Some(&2_500));
+    }
+
+    #[test]
+    fn test_mid_vote_slash_does_not_change_vote_weight() {
+        let (mut engine, _) = funded_engine(10_000);
+        engine.register_stake("val-1".to_string(), 5_000);
+        engine.register_stake("val-2".to_string(), 2_000);
+        let id = engine.submit_proposal(block_time_proposal(6000)).unwrap();
+        engine.advance_epoch(2).unwrap();
+
+        engine.cast_vote(&id, Vote::new("val-1".to_string(), true, 5_000, 2, vec![1]), 2).unwrap();
+
+        // Both are slashed after the snapshot; val-2 votes with its reduced stake
+        engine.register_stake("val-1".to_string(), 1_000);
+        engine.register_stake("val-2".to_string(), 500);
+        engine.cast_vote(&id, Vote::new("val-2".to_string(), false, 500, 3, vec![2]), 3).unwrap();
+        engine.advance_epoch(4).unwrap();
+
+        let tally = engine.get_proposal(&id).unwrap().tally.clone().unwrap();
+        assert_eq!(tally.stake_approve, 5_000);
+        assert_eq!(tally.stake_reject, 2_000);
+    }
+
+    #[test]
+    fn test_delegation_after_voting_opens_not_counted() {
+        let (mut engine, _) = funded_engine(10_000);
+        engine.register_stake("alice".to_string(), 3_000);
+        engine.register_stake("bob".to_string(), 1_000);
+        let id = engine.submit_proposal(block_time_proposal(6000)).unwrap();
+        engine.advance_epoch(2).unwrap();
+
+        // Alice's delegation lands after the snapshot and only affects later proposals
+        engine.delegate_vote("alice".to_string(), "bob".to_string()).unwrap();
+        engine.cast_vote(&id, Vote::new("bob".to_string(), true, 1_000, 2, vec![1]), 2).unwrap();
+        engine.advance_epoch(4).unwrap();
+
+        let p = engine.get_proposal(&id).unwrap();
+        assert_eq!(p.tally.as_ref().unwrap().stake_approve, 1_000);
+        assert!(p.delegation_snapshot.as_ref().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_snapshot_taken_from_stake_source() {
+        let registry = Arc::new(std::sync::Mutex::new(BTreeMap::from([("val-1".to_string(), 5_000u128)])));
+        let source = Arc::clone(&registry);
+        let mut engine = funded_engine(10_000).0
+            .with_stake_source(Arc::new(move || source.lock().unwrap().clone()));
+        let id = engine.submit_proposal(block_time_proposal(6000)).unwrap();
+
+        // Registry changes before voting opens are picked up; later ones are not
+        registry.lock().unwrap().insert("val-2".to_string(), 2_000);
+        engine.advance_epoch(2).unwrap();
+        registry.lock().unwrap().insert("val-3".to_string(), 9_000);
+
+        let snapshot = engine.get_proposal(&id).unwrap().stake_snapshot.clone().unwrap();
+        assert_eq!(snapshot.len(), 2);
+        assert_eq!(snapshot.get("val-2"), Some(&2_000));
+        assert!(engine
+            .cast_vote(&id, Vote::new("val-3".to_string(), true, 9_000, 2, vec![1]), 2)
+            .is_err());
+    }
+
+    #[test]
+    fn test_post_snapshot_joiner_cannot_vote() {
+        let (mut engine, _) = funded_engine(10_000);
+        engine.register_stake("val-1".to_string(), 5_000);
+        let id = engine.submit_proposal(block_time_proposal(6000)).unwrap();
+        engine.advance_epoch(2).unwrap();
+
+        engine.register_stake("val-new".to_string(), 9_000);
+        let result = engine.cast_vote(&id, Vote::new("val-new".to_string(), true, 9_000, 2, vec![1]), 2);
+        assert!(matches!(result, Err(GovernanceError::InvalidValidator)));
+
+        // Unregistered validators are rejected too
+        let result = engine.cast_vote(&id, Vote::new("val-x".to_string(), true, 1, 2, vec![2]), 2);
+        assert!(matches!(result, Err(GovernanceError::InvalidValidator)));
+        assert!(engine.get_proposal(&id).unwrap().votes.is_empty());
+    }
+
+    #[test]
+    fn test_tally_proof_verifies_against_votes() {
+        let (mut engine, _) = funded_engine(10_000);
+        engine.register_stake("alice".to_string(), 3_000);
+        engine.register_stake("bob".to_string(), 1_000);
+        engine.register_stake("carol".to_string(), 2_000);
+        engine.delegate_vote("alice".to_string(), "bob".to_string()).unwrap();
+        let id = engine.submit_proposal(block_time_proposal(6000)).unwrap();
+        engine.advance_epoch(2).unwrap();
+        engine.cast_vote(&id, Vote::new("bob".to_string(), true, 1_000, 2, vec![1]), 2).unwrap();
+        engine.cast_vote(&id, Vote::new("carol".to_string(), false, 2_000, 2, vec![2]), 2).unwrap();
+        engine.advance_epoch(4).unwrap();
+
+        let p = engine.get_proposal(&id).unwrap();
+        let proof = p.generate_tally_proof();
+        assert_eq!(proof, p.generate_tally_proof());
+        assert_eq!(proof.vote_count, 2);
+        assert_eq!(proof.stake_approve, 4_000);
+        assert_eq!(proof.stake_reject, 2_000);
+        assert!(p.verify_tally_proof(&proof));
+
+        // Inflated delegated stake, with a proof regenerated to match it
+        let mut tampered = p.clone();
+        tampered.delegated_stake.insert("carol".to_string(), 5_000);
+        let proof = tampered.generate_tally_proof();
+        assert!(!tampered.verify_tally_proof(&proof));
+    }
+
+    #[test]
+    fn test_tally_proof_rejects_mismatched_totals() {
+        let (mut engine, _) = funded_engine(10_000);
+        engine.register_stake("bob".to_string(), 4_000);
+        engine.register_stake("carol".to_string(), 2_000);
+        let id = engine.submit_proposal(block_time_proposal(6000)).unwrap();
+        engine.advance_epoch(2).unwrap();
+        engine.cast_vote(&id, Vote::new("bob".to_string(), true, 4_000, 2, vec![1]), 2).unwrap();
+        engine.cast_vote(&id, Vote::new("carol".to_string(), false, 2_000, 2, vec![2]), 2).unwrap();
+        engine.advance_epoch(4).unwrap();
+        let p = engine.get_proposal(&id).unwrap().clone();
+
+        // Inflated totals, with or without a recomputed proof hash
+        let mut forged = p.generate_tally_proof();
+        forged.stake_approve += 1_000;
+        assert!(!p.verify_tally_proof(&forged));
+        let forged = TallyProof::new(id.clone(), forged.votes_commitment, 2, 5_000, 2_000);
+        assert!(!p.verify_tally_proof(&forged));
+
+        // A stored tally that disagrees with the votes
+        let proof = p.generate_tally_proof();
+        let mut tampered = p.clone();
+        tampered.tally.as_mut().unwrap().stake_reject = 0;
+        assert!(!tampered.verify_tally_proof(&proof));
+
+        // Votes altered after the proof was generated
+        let mut tampered = p.clone();
+        tampered.votes.get_mut("carol").unwrap().approval = true;
+        assert!(!tampered.verify_tally_proof(&proof));
+    }
+
+    #[test]
+    fn test_expired_proposal_forfeits_deposit() {
+        let (mut engine, ledger) = funded_engine(10_000);
+        engine.register_stake("val-1".to_string(), 1_000);
+        let id = engine.submit_proposal(block_time_proposal(6000)).unwrap();
+        engine.advance_epoch(2).unwrap();
+        assert_eq!(engine.locked_deposits(), MIN_PROPOSAL_DEPOSIT);
+        assert_eq!(ledger.balance(PROPOSER), 1_000_000 - MIN_PROPOSAL_DEPOSIT);
+        assert_eq!(ledger.balance(GOVERNANCE_ESCROW_ACCOUNT), MIN_PROPOSAL_DEPOSIT);
+
+        // 10% participation misses quorum
+        engine.cast_vote(&id, Vote::new("val-1".to_string(), true, 1_000, 2, vec![1]), 2).unwrap();
+        engine.advance_epoch(4).unwrap();
+
+        let p = engine.get_proposal(&id).unwrap();
+        assert_eq!(p.state, ProposalState::Expired);
+        assert_eq!(p.deposit_status, DepositStatus::Forfeited);
+        assert_eq!(engine.forfeited_deposits(), MIN_PROPOSAL_DEPOSIT);
+        assert_eq!(engine.locked_deposits(), 0);
+        assert_eq!(ledger.balance(GOVERNANCE_ESCROW_ACCOUNT), 0);
+        assert_eq!(ledger.balance(PROPOSER), 1_000_000 - MIN_PROPOSAL_DEPOSIT);
+        assert_eq!(*ledger.burned.lock(), MIN_PROPOSAL_DEPOSIT);
+    }
+
+    #[test]
+    fn test_voted_proposal_refunds_deposit() {
+        let (mut engine, ledger) = funded_engine(10_000);
+        engine.register_stake("val-1".to_string(), 2_000);
+        engine.register_stake("val-2".to_string(), 3_000);
+        let id = engine.submit_proposal(block_time_proposal(6000)).unwrap();
+        engine.advance_epoch(2).unwrap();
+
+        // Quorum reached but rejected: the deposit is still refunded
+        engine.cast_vote(&id, Vote::new("val-1".to_string(), true, 2_000, 2, vec![1]), 2).unwrap();
+        engine.cast_vote(&id, Vote::new("val-2".to_string(), false, 3_000, 2, vec![2]), 2).unwrap();
+        engine.advance_epoch(4).unwrap();
+
+        let p = engine.get_proposal(&id).unwrap();
+        assert_eq!(p.state, ProposalState::Rejected);
+        assert_eq!(p.deposit_status, DepositStatus::Refunded);
+        assert_eq!(engine.forfeited_deposits(), 0);
+        assert_eq!(engine.locked_deposits(), 0);
+        assert_eq!(ledger.balance(PROPOSER), 1_000_000);
+        assert_eq!(ledger.balance(GOVERNANCE_ESCROW_ACCOUNT), 0);
+        assert_eq!(*ledger.burned.lock(), 0);
+    }
+
+    #[test]
+    fn test_deposit_must_be_escrowed_from_proposer() {
+        // No ledger attached: the deposit cannot be taken on trust
+        let mut engine = GovernanceEngine::new(10_000);
+        assert!(matches!(
+            engine.submit_proposal(block_time_proposal(6000)),
+            Err(GovernanceError::DepositEscrowFailed(_))
+        ));
+
+        // Proposer cannot cover the deposit: nothing is queued or debited
+        let (mut engine, ledger) = funded_engine(10_000);
+        ledger.balances.lock().insert(PROPOSER.to_string(), MIN_PROPOSAL_DEPOSIT - 1);
+        assert!(matches!(
+            engine.submit_proposal(block_time_proposal(6000)),
+            Err(GovernanceError::DepositEscrowFailed(_))
+        ));
+        assert!(engine.proposal_queue.is_empty());
+        assert_eq!(ledger.balance(PROPOSER), MIN_PROPOSAL_DEPOSIT - 1);
+
+        // No proposer account
+        let (mut engine, _) = funded_engine(10_000);
+        let mut anonymous = block_time_proposal(6000);
+        anonymous.proposer.clear();
+        assert!(engine.submit_proposal(with_computed_id(anonymous)).is_err());
+    }
+
+    #[test]
+    fn test_underfunded_submission_rejected() {
+        let mut engine = funded_engine(10_000).0;
+
+        let proposal = with_computed_id(block_time_proposal(6000).with_deposit(PROPOSER, MIN_PROPOSAL_DEPOSIT - 1));
+        assert!(matches!(
+            engine.submit_proposal(proposal),
+            Err(GovernanceError::InsufficientDeposit { required: MIN_PROPOSAL_DEPOSIT, .. })
+        ));
+        assert!(engine.proposal_queue.is_empty());
+
+        let mut engine = funded_engine(10_000).0.with_min_deposit(5_000);
+        assert!(engine.submit_proposal(block_time_proposal(6000)).is_err());
+        assert!(engine.submit_proposal(with_computed_id(block_time_proposal(6000).with_deposit(PROPOSER, 5_000))).is_ok());
+    }
+
+    /// Approved proposal entering its timelock at epoch 4 (ends at epoch 6)
+    fn engine_with_timelocked_proposal() -> (GovernanceEngine, String) {
+        let (engine, _) = funded_engine(10_000);
+        let mut engine = engine.with_guardians([GUARDIAN.to_string()]);
+        engine.register_stake("val-1".to_string(), 5_000);
+        let id = engine.submit_proposal(block_time_proposal(6000)).unwrap();
+        engine.advance_epoch(2).unwrap();
+        engine.cast_vote(&id, Vote::new("val-1".to_string(), true, 5_000, 2, vec![1]), 2).unwrap();
+        engine.advance_epoch(4).unwrap();
+
+        let p = engine.get_proposal(&id).unwrap();
+        assert_eq!(p.state, ProposalState::Timelocked);
+        assert_eq!(p.timelock_end_epoch, Some(4 + DEFAULT_TIMELOCK_EPOCHS));
+        (engine, id)
+    }
+
+    #[test]
+    fn test_execution_blocked_during_timelock() {
+        let (mut engine, id) = engine_with_timelocked_proposal();
+
+        // Execution epoch (5) reached but the timelock runs until 6
+        assert!(matches!(
+            engine.execute_proposal(&id, 5),
+            Err(GovernanceError::ExecutionFailed(_))
+        ));
+        engine.advance_epoch(5).unwrap();
+        assert_eq!(engine.get_proposal(&id).unwrap().state, ProposalState::Timelocked);
+    }
+
+    #[test]
+    fn test_veto_during_timelock() {
+        let (mut engine, id) = engine_with_timelocked_proposal();
+
+        // Anyone outside the guardian set is refused
+        assert!(matches!(
+            engine.veto("val-1", &id, "Unsafe parameter".to_string()),
+            Err(GovernanceError::NotGuardian(_))
+        ));
+        assert_eq!(engine.get_proposal(&id).unwrap().state, ProposalState::Timelocked);
+
+        engine.veto(GUARDIAN, &id, "Unsafe parameter".to_string()).unwrap();
+        let p = engine.get_proposal(&id).unwrap();
+        assert_eq!(p.state, ProposalState::Rejected);
+        assert_eq!(p.veto_reason.as_deref(), Some("Unsafe parameter"));
+
+        // Vetoed proposals never execute and cannot be vetoed again
+        engine.advance_epoch(10).unwrap();
+        assert_eq!(engine.get_proposal(&id).unwrap().state, ProposalState::Rejected);
+        assert!(engine.veto(GUARDIAN, &id, "again".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_execution_after_timelock() {
+        let (mut engine, id) = engine_with_timelocked_proposal();
+
+        engine.advance_epoch(4 + DEFAULT_TIMELOCK_EPOCHS).unwrap();
+        assert_eq!(engine.get_proposal(&id).unwrap().state, ProposalState::Executed);
+
+        // Too late to veto
+        assert!(engine.veto(GUARDIAN, &id, "late".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_state_machine_consistent_through_lifecycle() {
+        let (mut engine, id) = engine_with_timelocked_proposal();
+        assert!(engine.verify_state_machine().is_ok());
+        
+        engine.advance_epoch(4 + DEFAULT_TIMELOCK_EPOCHS).unwrap();
+        assert!(engine.verify_state_machine().is_ok());
+        
+        // An executed proposal whose tally did not approve it is corrupt
+        engine.proposals.get_mut(&id).unwrap().tally.as_mut().unwrap().approved = false;
+        assert!(matches!(
+            engine.verify_state_machine(),
+            Err(GovernanceError::InvalidStateTransition(_))
+        ));
+    }
+    
+    #[test]
+    fn test_min_voting_window_per_proposal_type() {
+        let mut engine = funded_engine(10_000).0;
+
+        // Parameter change with a 2-epoch window is fine
+        assert!(engine.submit_proposal(block_time_proposal(6000)).is_ok());
+
+        // Upgrade with a 2-epoch window is too short
+        let mut upgrade = security_fix_proposal("1.0.1");
+        upgrade.voting_window = VotingWindow::new(10, 12).unwrap();
+        upgrade.id = upgrade.compute_id().unwrap();
+        assert!(matches!(
+            engine.submit_proposal(upgrade.clone()),
+            Err(GovernanceError::InvalidProposal(_))
+        ));
+
+        // The minimum is configurable per type
+        let mut engine = funded_engine(10_000).0
+            .with_min_voting_epochs(ProposalType::UpgradeAuthorization, 2);
+        assert_eq!(engine.min_voting_epochs(ProposalType::UpgradeAuthorization), 2);
+        assert!(engine.submit_proposal(upgrade).is_ok());
+    }
+
+    #[test]
+    fn test_execution_epoch_must_trail_voting_end() {
+        let mut engine = funded_engine(10_000).0.with_min_execution_delay(3);
+
+        // Voting ends at 4; execution at 7 leaves the required 3 epochs
+        let mut proposal = block_time_proposal(6000);
+        proposal.execution_epoch = 7;
+        assert!(engine.submit_proposal(with_computed_id(proposal)).is_ok());
+
+        // Execution right at the end of voting has no timelock
+        let mut proposal = block_time_proposal(7000);
+        proposal.execution_epoch = 4;
+        assert!(matches!(
+            engine.submit_proposal(with_computed_id(proposal)),
+            Err(GovernanceError::InvalidProposal(_))
+        ));
+
+        // One epoch short
+        let mut proposal = block_time_proposal(8000);
+        proposal.execution_epoch = 6;
+        assert!(engine.submit_proposal(with_computed_id(proposal)).is_err());
+    }
+
+    #[test]
+    fn test_shared_clock_observed_by_governance_and_snapshots() {
+        use bleep_state::shard_registry::{EpochId, ShardId, ShardStateRoot};
+        use bleep_state::snapshot_engine::{SnapshotConfig, SnapshotEngine, SnapshotId};
+
+        let clock = EpochClock::new(1);
+        let mut governance = funded_engine(10_000).0.with_clock(clock.clone());
+        let mut snapshots = SnapshotEngine::new(
+            SnapshotConfig::new(2, 10, 100, 0.66).unwrap(),
+            SnapshotId(0),
+        ).with_clock(clock.clone());
+
+        let proposal = block_time_proposal(6000);
+        let prop_id = governance.submit_proposal(proposal).unwrap();
+
+        governance.sync_with_clock().unwrap();
+        assert_eq!(governance.get_proposal(&prop_id).unwrap().state, ProposalState::Pending);
+        assert!(!snapshots.is_snapshot_epoch());
+
+        clock.advance();
+        governance.sync_with_clock().unwrap();
+        assert_eq!(governance.current_epoch(), 2);
+        assert_eq!(snapshots.current_epoch(), EpochId(2));
+        assert_eq!(governance.get_proposal(&prop_id).unwrap().state, ProposalState::Voting);
+
+        let root = ShardStateRoot { root_hash: "root".to_string(), tx_count: 0, height: 20 };
+        let id = snapshots
+            .create_snapshot_at_current_epoch(ShardId(0), 20, root, "txs".to_string())
+            .unwrap();
+        assert_eq!(snapshots.get_snapshot(id).unwrap().epoch_id, EpochId(governance.current_epoch()));
+    }
+
+    fn security_fix_proposal(version: &str) -> Proposal {
+        let mut proposal = Proposal::new(
+            String::new(),
+            ProposalType::UpgradeAuthorization,
+            "Patch VM".to_string(),
+            "Fix critical VM vulnerability".to_string(),
+            VotingWindow::new(10, 30).unwrap(),
+            31,
+            67,
+            GovernancePayload::UpgradeAuthorization {
+                module_name: "bleep-vm".to_string(),
+                version: version.to_string(),
+                code_hash: vec![7u8; 32],
+            },
+            3,
+        ).with_deposit(PROPOSER, MIN_PROPOSAL_DEPOSIT);
+        proposal.id = proposal.compute_id().unwrap();
+        proposal
+    }
+
+    #[test]
+    fn test_emergency_proposal_uses_fast_track() {
+        let constitution = BLEEPConstitution::genesis().unwrap();
+        let clock = EpochClock::new(3);
+        let mut engine = funded_engine(10_000).0.with_clock(clock.clone());
+        engine.register_stake("val-1".to_string(), 4_500);
+        engine.register_stake("val-2".to_string(), 1_500);
+
+        let proposal = security_fix_proposal("1.0.1").with_emergency_clause("SECURITY_FIX".to_string());
+        let id = engine.submit_emergency_proposal(proposal, &constitution).unwrap();
+
+        let p = engine.get_proposal(&id).unwrap();
+        assert_eq!(p.id, p.compute_id().unwrap());
+        assert_eq!(p.voting_window.start_epoch, 3);
+        assert_eq!(p.voting_window.end_epoch, 3 + EMERGENCY_VOTING_EPOCHS);
+        assert_eq!(p.execution_epoch, 3 + EMERGENCY_VOTING_EPOCHS);
+        assert_eq!(p.approval_threshold, EMERGENCY_APPROVAL_THRESHOLD);
+        assert_eq!(p.timelock_epochs, 0);
+
+        // 45% participation clears the normal >33% quorum but not the emergency one
+        engine.advance_epoch(3).unwrap();
+        engine.cast_vote(&id, Vote::new("val-1".to_string(), true, 4_500, 3, vec![1]), 3).unwrap();
+        engine.advance_epoch(5).unwrap();
+        let p = engine.get_proposal(&id).unwrap();
+        assert_eq!(p.state, ProposalState::Expired);
+        assert!(!p.tally.as_ref().unwrap().quorum_met);
+
+        // 75% approval clears the normal 67% threshold but not the emergency one
+        let proposal = security_fix_proposal("1.0.2").with_emergency_clause("SECURITY_FIX".to_string());
+        let id = engine.submit_emergency_proposal(proposal, &constitution).unwrap();
+        engine.advance_epoch(3).unwrap();
+        engine.cast_vote(&id, Vote::new("val-1".to_string(), true, 4_500, 3, vec![1]), 3).unwrap();
+        engine.cast_vote(&id, Vote::new("val-2".to_string(), false, 1_500, 3, vec![2]), 3).unwrap();
+        engine.advance_epoch(5).unwrap();
+        let p = engine.get_proposal(&id).unwrap();
+        assert!(p.tally.as_ref().unwrap().quorum_met);
+        assert_eq!(p.state, ProposalState::Rejected);
+
+        // An approved emergency proposal executes as soon as voting closes
+        let proposal = security_fix_proposal("1.0.3").with_emergency_clause("SECURITY_FIX".to_string());
+        let id = engine.submit_emergency_proposal(proposal, &constitution).unwrap();
+        engine.advance_epoch(3).unwrap();
+        engine.cast_vote(&id, Vote::new("val-1".to_string(), true, 4_500, 3, vec![1]), 3).unwrap();
+        engine.cast_vote(&id, Vote::new("val-2".to_string(), true, 1_500, 3, vec![2]), 3).unwrap();
+        engine.advance_epoch(5).unwrap();
+        let p = engine.get_proposal(&id).unwrap();
+        assert_eq!(p.timelock_end_epoch, Some(5));
+        assert_eq!(p.state, ProposalState::Executed);
+    }
+
+    #[test]
+    fn test_non_emergency_proposal_cannot_fast_track() {
+        let constitution = BLEEPConstitution::genesis().unwrap();
+        let mut engine = funded_engine(10_000).0;
+
+        // Not flagged
+        let result = engine.submit_emergency_proposal(security_fix_proposal("1.0.1"), &constitution);
+        assert!(matches!(result, Err(GovernanceError::InvalidProposal(_))));
+
+        // Flagged with a clause the constitution does not contain
+        let proposal = security_fix_proposal("1.0.1").with_emergency_clause("NO_SUCH_CLAUSE".to_string());
+        let result = engine.submit_emergency_proposal(proposal, &constitution);
+        assert!(matches!(result, Err(GovernanceError::InvalidProposal(_))));
+
+        // Flagged, but the clause does not cover parameter changes
+        let proposal = block_time_proposal(6000).with_emergency_clause("SECURITY_FIX".to_string());
+        let result = engine.submit_emergency_proposal(proposal, &constitution);
+        assert!(matches!(result, Err(GovernanceError::InvalidProposal(_))));
+
+        assert!(engine.proposal_queue.is_empty());
+    }
 }
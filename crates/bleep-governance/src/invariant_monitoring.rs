@@ -12,7 +12,10 @@
 use serde::{Serialize, Deserialize};
 use log::{info, warn, error};
 use thiserror::Error;
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
+
+/// Measured samples kept per invariant for trend analysis
+pub const MAX_TREND_SAMPLES: usize = 1_000;
 
 #[derive(Debug, Error)]
 pub enum InvariantError {
@@ -93,6 +96,21 @@ pub struct InvariantThreshold {
     
     /// Severity level (informational, warning, critical)
     pub severity: InvariantSeverity,
+    
+    /// Which side of the threshold is a breach
+    #[serde(default)]
+    pub bound: ThresholdBound,
+}
+
+/// Direction of an invariant threshold
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ThresholdBound {
+    /// Breached when the measured value drops below the threshold
+    #[default]
+    Floor,
+    
+    /// Breached when the measured value rises above the threshold
+    Ceiling,
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
@@ -130,8 +148,41 @@ impl InvariantThreshold {
             violation_window,
             triggers_rollback,
             severity,
+            bound: ThresholdBound::Floor,
         })
     }
+    
+    /// Use a different threshold direction (default `Floor`)
+    pub fn with_bound(mut self, bound: ThresholdBound) -> Self {
+        self.bound = bound;
+        self
+    }
+    
+    /// Whether a measured value breaches this threshold
+    pub fn is_breached(&self, measured_value: f64) -> bool {
+        match self.bound {
+            ThresholdBound::Floor => measured_value < self.threshold,
+            ThresholdBound::Ceiling => measured_value > self.threshold,
+        }
+    }
+    
+    /// Distance from the threshold on the healthy side, as a percentage
+    /// of the room available there: 100 at the far end of [0, 100], 0 at
+    /// or past the threshold
+    pub fn headroom(&self, measured_value: f64) -> f64 {
+        let (margin, room) = match self.bound {
+            ThresholdBound::Floor => (measured_value - self.threshold, 100.0 - self.threshold),
+            ThresholdBound::Ceiling => (self.threshold - measured_value, self.threshold),
+        };
+        
+        if margin < 0.0 {
+            0.0
+        } else if room <= 0.0 {
+            100.0
+        } else {
+            (margin / room * 100.0).clamp(0.0, 100.0)
+        }
+    }
 }
 
 /// Record of an invariant violation
@@ -177,6 +228,10 @@ pub struct InvariantMonitor {
     /// History of all violations
     pub violation_history: Vec<ViolationRecord>,
     
+    /// Recent `(epoch, measured_value)` samples per invariant, oldest first
+    #[serde(default)]
+    pub samples: HashMap<String, VecDeque<(u64, f64)>>,
+    
     /// Current epoch
     pub current_epoch: u64,
 }
@@ -188,6 +243,7 @@ impl InvariantMonitor {
             thresholds: HashMap::new(),
             violation_counts: HashMap::new(),
             violation_history: Vec::new(),
+            samples: HashMap::new(),
             current_epoch: 0,
         }
     }
@@ -226,8 +282,15 @@ impl InvariantMonitor {
                 format!("No threshold defined for {}", key)
             ))?;
         
+        // Keep the sample for trend analysis
+        let samples = self.samples.entry(key.clone()).or_default();
+        if samples.len() == MAX_TREND_SAMPLES {
+            samples.pop_front();
+        }
+        samples.push_back((epoch, measured_value));
+        
         // Check if value violates threshold
-        let is_violated = measured_value < threshold.threshold;
+        let is_violated = threshold.is_breached(measured_value);
         
         if is_violated {
             // Increment violation counter
@@ -308,6 +371,66 @@ impl InvariantMonitor {
         self.violation_counts.clear();
     }
     
+    /// Trend of the last `window` samples of an invariant
+    /// 
+    /// SAFETY: Deterministic; a least-squares fit over recorded samples in
+    /// recording order, so every node derives the same report
+    pub fn trend(&self, invariant: InvariantType, window: usize) -> TrendReport {
+        let key = invariant.as_str();
+        let threshold = self.thresholds.get(key);
+        let recent: Vec<(u64, f64)> = self.samples.get(key)
+            .map(|s| s.iter().skip(s.len().saturating_sub(window)).copied().collect())
+            .unwrap_or_default();
+        
+        let slope = least_squares_slope(&recent);
+        let latest_value = recent.last().map(|&(_, value)| value);
+        
+        let (epochs_to_breach, health) = match (threshold, latest_value) {
+            (Some(threshold), Some(latest)) => {
+                let breached = threshold.is_breached(latest);
+                let distance = (latest - threshold.threshold).abs();
+                let approaching = match threshold.bound {
+                    ThresholdBound::Floor => slope < 0.0,
+                    ThresholdBound::Ceiling => slope > 0.0,
+                };
+                
+                // The threshold itself is healthy, so the breach lands one
+                // epoch after the projected crossing
+                let epochs_to_breach = if breached {
+                    Some(0)
+                } else if approaching {
+                    Some(((distance / slope.abs()).floor() as u64).saturating_add(1))
+                } else {
+                    None
+                };
+                
+                let health = HealthStatus {
+                    health_percentage: threshold.headroom(latest),
+                    total_invariants: 1,
+                    violated_count: breached as u64,
+                    is_healthy: !breached,
+                };
+                (epochs_to_breach, health)
+            }
+            _ => (None, HealthStatus {
+                health_percentage: 100.0,
+                total_invariants: threshold.is_some() as u64,
+                violated_count: 0,
+                is_healthy: true,
+            }),
+        };
+        
+        TrendReport {
+            invariant_type: invariant,
+            sample_count: recent.len(),
+            slope,
+            latest_value,
+            threshold: threshold.map(|t| t.threshold),
+            epochs_to_breach,
+            health,
+        }
+    }
+    
     /// Get violation history for specific invariant
     pub fn violation_history_for(&self, invariant_type: InvariantType) -> Vec<&ViolationRecord> {
         let key = invariant_type.as_str();
@@ -318,6 +441,61 @@ impl InvariantMonitor {
     }
 }
 
+/// Least-squares slope of `value` over `epoch`; 0 for fewer than two
+/// distinct epochs
+fn least_squares_slope(samples: &[(u64, f64)]) -> f64 {
+    if samples.len() < 2 {
+        return 0.0;
+    }
+    
+    // Center epochs on the first sample to keep the sums small
+    let origin = samples[0].0;
+    let n = samples.len() as f64;
+    let mean_x = samples.iter().map(|&(e, _)| e as f64 - origin as f64).sum::<f64>() / n;
+    let mean_y = samples.iter().map(|&(_, v)| v).sum::<f64>() / n;
+    
+    let mut covariance = 0.0;
+    let mut variance = 0.0;
+    for &(epoch, value) in samples {
+        let dx = (epoch as f64 - origin as f64) - mean_x;
+        covariance += dx * (value - mean_y);
+        variance += dx * dx;
+    }
+    
+    if variance == 0.0 {
+        0.0
+    } else {
+        covariance / variance
+    }
+}
+
+/// Direction and proximity of one invariant relative to its threshold
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrendReport {
+    /// Invariant analysed
+    pub invariant_type: InvariantType,
+    
+    /// Samples the trend was fitted over
+    pub sample_count: usize,
+    
+    /// Change in measured value per epoch
+    pub slope: f64,
+    
+    /// Most recent measured value
+    pub latest_value: Option<f64>,
+    
+    /// Threshold the invariant is checked against (if defined)
+    pub threshold: Option<f64>,
+    
+    /// Epochs until the threshold is breached if the trend continues;
+    /// `Some(0)` if already breached, `None` if not heading toward a breach
+    pub epochs_to_breach: Option<u64>,
+    
+    /// Health of this invariant; `health_percentage` is the headroom left
+    /// before the threshold
+    pub health: HealthStatus,
+}
+
 /// Overall health status
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HealthStatus {
@@ -551,4 +729,101 @@ mod tests {
         assert_eq!(health.total_shards, 2);
         assert!(health.is_globally_healthy);
     }
+
+    fn record_series(monitor: &mut InvariantMonitor, invariant: InvariantType, values: &[f64]) {
+        let start = monitor.current_epoch + 1;
+        for (i, &value) in values.iter().enumerate() {
+            let epoch = start + i as u64;
+            monitor.check_invariant(
+                invariant,
+                value,
+                "Trend sample".to_string(),
+                epoch,
+                epoch * 100,
+                None,
+            ).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_rising_series_trends_toward_breach() {
+        let mut monitor = InvariantMonitor::new(0);
+        monitor.add_threshold(InvariantThreshold::new(
+            InvariantType::CrossShardConsistency,
+            50.0,
+            3,
+            true,
+            InvariantSeverity::Critical,
+        ).unwrap().with_bound(ThresholdBound::Ceiling)).unwrap();
+        
+        // Older samples fall outside the window
+        record_series(
+            &mut monitor,
+            InvariantType::CrossShardConsistency,
+            &[40.0, 5.0, 10.0, 12.0, 14.0, 16.0, 18.0, 20.0],
+        );
+        
+        let report = monitor.trend(InvariantType::CrossShardConsistency, 6);
+        assert_eq!(report.sample_count, 6);
+        assert!((report.slope - 2.0).abs() < 1e-9);
+        assert_eq!(report.latest_value, Some(20.0));
+        // 30 points of headroom at 2 per epoch: 50 at +15, breached at +16
+        assert_eq!(report.epochs_to_breach, Some(16));
+        assert!(report.health.is_healthy);
+        assert!((report.health.health_percentage - 60.0).abs() < 1e-9);
+        
+        // Deterministic over the same samples
+        let again = monitor.trend(InvariantType::CrossShardConsistency, 6);
+        assert_eq!(again.slope.to_bits(), report.slope.to_bits());
+        assert_eq!(again.epochs_to_breach, report.epochs_to_breach);
+    }
+
+    #[test]
+    fn test_vanishing_slope_saturates_epochs_to_breach() {
+        let mut monitor = InvariantMonitor::new(0);
+        monitor.add_threshold(InvariantThreshold::new(
+            InvariantType::CrossShardConsistency,
+            100.0,
+            3,
+            true,
+            InvariantSeverity::Critical,
+        ).unwrap().with_bound(ThresholdBound::Ceiling)).unwrap();
+        
+        record_series(
+            &mut monitor,
+            InvariantType::CrossShardConsistency,
+            &[0.0, 1e-300, 2e-300],
+        );
+        
+        let report = monitor.trend(InvariantType::CrossShardConsistency, 3);
+        assert_eq!(report.epochs_to_breach, Some(u64::MAX));
+    }
+
+    #[test]
+    fn test_flat_series_has_no_imminent_breach() {
+        let mut monitor = InvariantMonitor::new(0);
+        monitor.add_threshold(InvariantThreshold::new(
+            InvariantType::Liveness,
+            80.0,
+            2,
+            true,
+            InvariantSeverity::Fatal,
+        ).unwrap()).unwrap();
+        
+        record_series(&mut monitor, InvariantType::Liveness, &[90.0; 10]);
+        
+        let report = monitor.trend(InvariantType::Liveness, 5);
+        assert_eq!(report.slope, 0.0);
+        assert_eq!(report.epochs_to_breach, None);
+        assert!(report.health.is_healthy);
+        assert!((report.health.health_percentage - 50.0).abs() < 1e-9);
+        
+        // A falling floor metric is headed for a breach; one below it already is
+        record_series(&mut monitor, InvariantType::Liveness, &[90.0, 88.0, 86.0]);
+        assert!(monitor.trend(InvariantType::Liveness, 3).epochs_to_breach.is_some());
+        record_series(&mut monitor, InvariantType::Liveness, &[70.0]);
+        let breached = monitor.trend(InvariantType::Liveness, 3);
+        assert_eq!(breached.epochs_to_breach, Some(0));
+        assert!(!breached.health.is_healthy);
+    }
 }
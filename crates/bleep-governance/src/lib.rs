@@ -5,6 +5,7 @@ pub mod deterministic_executor;
 // PHASE 4: CONSTITUTIONAL GOVERNANCE LAYER
 pub mod constitution;
 pub mod zk_voting;
+pub mod zk_nullifier;
 pub mod proposal_lifecycle;
 pub mod forkless_upgrades;
 pub mod governance_binding;
@@ -33,6 +34,7 @@ mod phase4_governance_tests;
 pub use governance_core::{
     ProposalType, ProposalState, VotingWindow, Vote, VoteTally,
     Proposal, GovernancePayload, SanctionAction, GovernanceEngine, GovernanceError,
+    TallyProof as GovernanceTallyProof, DepositStatus, MIN_PROPOSAL_DEPOSIT, TallyMode,
 };
 
 pub use deterministic_executor::{
@@ -41,7 +43,7 @@ pub use deterministic_executor::{
 
 pub use constitution::{
     BLEEPConstitution, ConstitutionalConstraint, ConstitutionalScope,
-    GovernanceAction, ValidationResult, ConstraintRule, RuleType,
+    GovernanceAction, ValidationResult, ConstraintRule, RuleType, RuleViolation, EmergencyClause,
 };
 
 pub use zk_voting::{
@@ -49,9 +51,13 @@ pub use zk_voting::{
     EligibilityProof, VoterRole, VoteTally as ZKVoteTally, TallyProof, ZKVotingError,
 };
 
+pub use zk_nullifier::{
+    VoterSecret, EligibilityTree, VoteCircuitParams, ELIGIBILITY_TREE_DEPTH,
+};
+
 pub use proposal_lifecycle::{
     ProposalLifecycleManager, ProposalRecord, ProposalState as LifecycleProposalState, ProposalArchive,
-    ProposalStateTransition, ProposalError,
+    ProposalStateTransition, ProposalError, ProposalQuery,
 };
 
 pub use forkless_upgrades::{
@@ -80,6 +86,7 @@ pub use safety_constraints::{
 
 pub use ai_reputation::{
     AIReputationTracker, AIReputation, ProposalOutcome as ReputationProposalOutcome, ReputationRecord,
+    NEUTRAL_REPUTATION, MIN_USABLE_REPUTATION,
 };
 
 pub use protocol_evolution::{
@@ -94,6 +101,7 @@ pub use ai_hooks::{
 pub use invariant_monitoring::{
     InvariantMonitor, GlobalInvariantMonitor, InvariantType, InvariantThreshold,
     InvariantSeverity, ViolationRecord, HealthStatus, GlobalHealth,
+    ThresholdBound, TrendReport, MAX_TREND_SAMPLES,
 };
 
 pub use governance_voting::{
@@ -102,7 +110,7 @@ pub use governance_voting::{
 };
 
 pub use deterministic_activation::{
-    DeterministicActivationManager, ActivationPlan, ActivationState, ActivationError,
+    DeterministicActivationManager, ActivationPlan, ActivationState, ActivationError, ActivationId,
 };
 
 /// Initialize BLEEP governance with Phase 5 protocol evolution layer
@@ -119,7 +127,8 @@ pub fn init_governance() -> Result<ProtocolEvolutionOrchestrator, Box<dyn std::e
 // ── Sprint 9 modules ──────────────────────────────────────────────────────────
 pub mod live_governance;
 
+// `Proposal`, `ProposalState`, `Vote` and `GovernanceError` are re-exported
+// from `governance_core` above; reach the live variants via `live_governance::`.
 pub use live_governance::{
-    LiveGovernanceEngine, GovernanceConfig, Proposal, ProposalState,
-    Vote, GovernableParam, ExecutionResult, GovernanceError, GovernanceEvent,
+    LiveGovernanceEngine, GovernanceConfig, GovernableParam, ExecutionResult, GovernanceEvent,
 };
@@ -50,7 +50,13 @@ mod phase4_governance_tests {
         action.economic_change = Some(25_000_000_000); // Exceeds 21B cap
         
         let result = constitution.validate_action(&action)?;
-        assert_eq!(result, ValidationResult::Violation("Action violates constraint rule in EconomicPolicy".to_string()));
+        match result {
+            ValidationResult::Violations(violations) => {
+                assert_eq!(violations.len(), 1);
+                assert_eq!(violations[0].constraint_id, "SUPPLY_CAP");
+            }
+            other => panic!("expected supply cap violation, got {:?}", other),
+        }
         
         Ok(())
     }
@@ -81,7 +87,7 @@ mod phase4_governance_tests {
         invalid_action.inflation_rate = Some(700); // 7%
         
         let result = constitution.validate_action(&invalid_action)?;
-        assert!(matches!(result, ValidationResult::Violation(_)));
+        assert!(matches!(result, ValidationResult::Violations(_)));
         
         Ok(())
     }
@@ -112,7 +118,7 @@ mod phase4_governance_tests {
         invalid_action.slashing_percentage = Some(5000); // 50%
         
         let result = constitution.validate_action(&invalid_action)?;
-        assert!(matches!(result, ValidationResult::Violation(_)));
+        assert!(matches!(result, ValidationResult::Violations(_)));
         
         Ok(())
     }
@@ -405,7 +411,7 @@ mod phase4_governance_tests {
         
         // Validate - should fail constitutional check
         let result = manager.validate_proposal("prop_1")?;
-        assert!(matches!(result, ValidationResult::Violation(_)));
+        assert!(matches!(result, ValidationResult::Violations(_)));
         
         let proposal = manager.get_proposal("prop_1").unwrap();
         assert_eq!(proposal.state, ProposalState::Rejected);
@@ -533,12 +539,12 @@ mod phase4_governance_tests {
         assert!(binding.can_activate("action_1").is_err());
         
         // With enough blocks, can't activate (not at activation epoch)
-        binding.current_epoch = 5;
+        binding.clock().advance_to(5).unwrap();
         binding.current_block_height = 10;
         assert!(!binding.can_activate("action_1")?);
         
         // At activation epoch with finality
-        binding.current_epoch = 10;
+        binding.clock().advance_to(10).unwrap();
         assert!(binding.can_activate("action_1")?);
         
         Ok(())
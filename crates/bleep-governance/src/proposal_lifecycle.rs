@@ -10,13 +10,14 @@
 // 6. All proposal data is cryptographically archived
 // 7. Deterministic activation at epoch boundaries
 
+use bleep_state::epoch_clock::EpochClock;
 use serde::{Serialize, Deserialize};
 use sha2::{Digest, Sha256};
 use log::{info, warn, error};
 use thiserror::Error;
 use std::collections::BTreeMap;
 use super::constitution::{
-    BLEEPConstitution, GovernanceAction, ValidationResult,
+    BLEEPConstitution, ConstitutionalScope, GovernanceAction, ValidationResult,
 };
 use super::zk_voting::{VoteTally, TallyProof};
 
@@ -154,6 +155,10 @@ pub struct ProposalRecord {
     
     /// State transition history
     pub state_history: Vec<ProposalStateTransition>,
+    
+    /// Epoch the executed change was rolled back (if it was)
+    #[serde(default)]
+    pub rolled_back_epoch: Option<u64>,
 }
 
 /// Record of a state transition
@@ -202,6 +207,7 @@ impl ProposalRecord {
             rejection_reason: None,
             proposal_hash,
             state_history: Vec::new(),
+            rolled_back_epoch: None,
         })
     }
     
@@ -307,15 +313,25 @@ impl ProposalRecord {
         self.constitutional_validation = Some(validation_result.clone());
         self.constitutional_validation_epoch = Some(current_epoch);
         
-        match validation_result {
-            ValidationResult::Valid => {
+        let violation = match validation_result {
+            ValidationResult::Valid => None,
+            ValidationResult::Violations(violations) => Some(
+                violations.iter()
+                    .map(|v| v.to_string())
+                    .collect::<Vec<_>>()
+                    .join("; "),
+            ),
+        };
+        
+        match violation {
+            None => {
                 self.transition_state(
                     ProposalState::ConstitutionallyValid,
                     current_epoch,
                     block_height,
                 )
             }
-            ValidationResult::Violation(reason) => {
+            Some(reason) => {
                 self.rejection_reason = Some(format!("Constitutional violation: {}", reason));
                 self.transition_state(
                     ProposalState::Rejected,
@@ -409,6 +425,28 @@ impl ProposalRecord {
         self.transition_state(ProposalState::Executed, current_epoch, block_height)
     }
     
+    /// Record that an executed proposal's change was rolled back.
+    /// 
+    /// The lifecycle state stays `Executed` (proposals never regress); the
+    /// rollback is kept alongside it for auditing.
+    pub fn mark_rolled_back(&mut self, current_epoch: u64) -> Result<(), ProposalError> {
+        if self.state != ProposalState::Executed || self.rolled_back_epoch.is_some() {
+            return Err(ProposalError::InvalidStateTransition);
+        }
+        
+        self.rolled_back_epoch = Some(current_epoch);
+        warn!("Proposal {} rolled back at epoch {}", self.id, current_epoch);
+        Ok(())
+    }
+    
+    /// Epoch the proposal reached `Executed`, from its transition history
+    pub fn execution_epoch(&self) -> Option<u64> {
+        self.state_history
+            .iter()
+            .find(|t| t.to_state == ProposalState::Executed)
+            .map(|t| t.epoch)
+    }
+    
     /// Mark proposal as expired
     pub fn expire(
         &mut self,
@@ -441,8 +479,8 @@ pub struct ProposalLifecycleManager {
     /// Constitution reference
     constitution: BLEEPConstitution,
     
-    /// Current epoch
-    current_epoch: u64,
+    /// Shared epoch source
+    clock: EpochClock,
     
     /// Block height
     current_block_height: u64,
@@ -465,13 +503,24 @@ impl ProposalLifecycleManager {
         Ok(ProposalLifecycleManager {
             proposals: BTreeMap::new(),
             constitution,
-            current_epoch,
+            clock: EpochClock::new(current_epoch),
             current_block_height: 0,
             voting_duration,
             voting_threshold,
         })
     }
     
+    /// Attach a shared epoch clock
+    pub fn with_clock(mut self, clock: EpochClock) -> Self {
+        self.clock = clock;
+        self
+    }
+    
+    /// Current epoch according to the shared clock
+    pub fn current_epoch(&self) -> u64 {
+        self.clock.current_epoch()
+    }
+    
     /// Create and submit a new proposal
     pub fn propose(
         &mut self,
@@ -488,10 +537,10 @@ impl ProposalLifecycleManager {
             id.clone(),
             proposer,
             action,
-            self.current_epoch,
+            self.clock.current_epoch(),
         )?;
         
-        proposal.submit_for_validation(self.current_epoch)?;
+        proposal.submit_for_validation(self.clock.current_epoch())?;
         
         self.proposals.insert(id, proposal);
         
@@ -511,7 +560,7 @@ impl ProposalLifecycleManager {
         
         proposal.record_constitutional_validation(
             validation_result.clone(),
-            self.current_epoch,
+            self.clock.current_epoch(),
             self.current_block_height,
         )?;
         
@@ -531,7 +580,7 @@ impl ProposalLifecycleManager {
         }
         
         proposal.start_voting(
-            self.current_epoch,
+            self.clock.current_epoch(),
             self.voting_duration,
             self.current_block_height,
         )
@@ -547,7 +596,7 @@ impl ProposalLifecycleManager {
         let proposal = self.proposals.get_mut(proposal_id)
             .ok_or(ProposalError::ProposalNotFound)?;
         
-        proposal.record_tally(tally, proof, self.current_epoch, self.current_block_height)
+        proposal.record_tally(tally, proof, self.clock.current_epoch(), self.current_block_height)
     }
     
     /// Schedule proposal activation
@@ -559,7 +608,7 @@ impl ProposalLifecycleManager {
         let proposal = self.proposals.get_mut(proposal_id)
             .ok_or(ProposalError::ProposalNotFound)?;
         
-        proposal.schedule_activation(activation_epoch, self.current_epoch, self.current_block_height)
+        proposal.schedule_activation(activation_epoch, self.clock.current_epoch(), self.current_block_height)
     }
     
     /// Execute proposal at activation epoch
@@ -571,15 +620,15 @@ impl ProposalLifecycleManager {
         let proposal = self.proposals.get_mut(proposal_id)
             .ok_or(ProposalError::ProposalNotFound)?;
         
-        proposal.execute(execution_result, self.current_epoch, self.current_block_height)
+        proposal.execute(execution_result, self.clock.current_epoch(), self.current_block_height)
     }
     
-    /// Advance to next epoch
+    /// Advance to next epoch (on the shared clock, so every subsystem sees it)
     pub fn advance_epoch(&mut self, block_height: u64) {
-        self.current_epoch += 1;
+        self.clock.advance();
         self.current_block_height = block_height;
         
-        info!("Advanced to epoch {} (block {})", self.current_epoch, block_height);
+        info!("Advanced to epoch {} (block {})", self.clock.current_epoch(), block_height);
     }
     
     /// Get proposal by ID
@@ -599,6 +648,18 @@ impl ProposalLifecycleManager {
             .collect()
     }
     
+    /// Query archived proposals for audits, ordered by creation epoch
+    /// (ties by proposal ID). Only matching records are collected.
+    pub fn query(&self, filter: ProposalQuery) -> Vec<&ProposalRecord> {
+        let mut matches: Vec<&ProposalRecord> = self.proposals.values()
+            .filter(|p| filter.matches(p))
+            .collect();
+        
+        // Stable sort keeps the map's ID order within an epoch
+        matches.sort_by_key(|p| p.creation_epoch);
+        matches
+    }
+    
     /// Create archive of all proposals
     pub fn create_archive(&self) -> Result<ProposalArchive, ProposalError> {
         let mut proposal_hashes = Vec::new();
@@ -613,7 +674,7 @@ impl ProposalLifecycleManager {
         }
         
         Ok(ProposalArchive {
-            epoch: self.current_epoch,
+            epoch: self.clock.current_epoch(),
             proposal_count: self.proposals.len(),
             archive_root: hasher.finalize().to_vec(),
             proposal_hashes,
@@ -621,6 +682,53 @@ impl ProposalLifecycleManager {
     }
 }
 
+/// Audit filter over proposals; unset fields match everything
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ProposalQuery {
+    /// Current lifecycle state
+    pub state: Option<ProposalState>,
+    
+    /// Proposal type, i.e. the constitutional scope of the proposed action
+    pub scope: Option<ConstitutionalScope>,
+    
+    /// Inclusive creation epoch range `(from, to)`
+    pub epoch_range: Option<(u64, u64)>,
+}
+
+impl ProposalQuery {
+    /// Query matching every proposal
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Only proposals currently in `state`
+    pub fn state(mut self, state: ProposalState) -> Self {
+        self.state = Some(state);
+        self
+    }
+    
+    /// Only proposals whose action targets `scope`
+    pub fn scope(mut self, scope: ConstitutionalScope) -> Self {
+        self.scope = Some(scope);
+        self
+    }
+    
+    /// Only proposals created in epochs `from..=to`
+    pub fn epochs(mut self, from: u64, to: u64) -> Self {
+        self.epoch_range = Some((from, to));
+        self
+    }
+    
+    /// Check a single proposal against every set field
+    pub fn matches(&self, proposal: &ProposalRecord) -> bool {
+        self.state.is_none_or(|state| proposal.state == state)
+            && self.scope.is_none_or(|scope| proposal.action.scope == scope)
+            && self.epoch_range.is_none_or(|(from, to)| {
+                (from..=to).contains(&proposal.creation_epoch)
+            })
+    }
+}
+
 /// Immutable archive of all proposals
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProposalArchive {
@@ -721,4 +829,78 @@ mod tests {
         
         Ok(())
     }
+    
+    /// Manager with proposals `(id, scope, creation_epoch, state)` inserted
+    /// directly into the archive
+    fn seeded_manager() -> ProposalLifecycleManager {
+        let mut manager = ProposalLifecycleManager::new(
+            BLEEPConstitution::genesis().unwrap(),
+            0,
+            10,
+            6_600,
+        ).unwrap();
+        
+        let seed = [
+            ("p-e", ConstitutionalScope::Governance, 9, ProposalState::Executed),
+            ("p-a", ConstitutionalScope::Protocol, 5, ProposalState::Rejected),
+            ("p-d", ConstitutionalScope::Governance, 2, ProposalState::Rejected),
+            ("p-b", ConstitutionalScope::Governance, 5, ProposalState::Voting),
+            ("p-c", ConstitutionalScope::Governance, 5, ProposalState::Rejected),
+        ];
+        for (id, scope, epoch, state) in seed {
+            let action = GovernanceAction::new(
+                format!("action-{}", id),
+                scope,
+                vec!["governance".to_string()],
+                "Seeded action".to_string(),
+            );
+            let mut proposal = ProposalRecord::new(
+                id.to_string(),
+                "proposer".to_string(),
+                action,
+                epoch,
+            ).unwrap();
+            proposal.state = state;
+            manager.proposals.insert(id.to_string(), proposal);
+        }
+        
+        manager
+    }
+    
+    fn ids(records: Vec<&ProposalRecord>) -> Vec<&str> {
+        records.into_iter().map(|p| p.id.as_str()).collect()
+    }
+    
+    #[test]
+    fn test_query_by_state() {
+        let manager = seeded_manager();
+        
+        let rejected = manager.query(ProposalQuery::new().state(ProposalState::Rejected));
+        assert_eq!(ids(rejected), vec!["p-d", "p-a", "p-c"]);
+        
+        assert_eq!(manager.query(ProposalQuery::new()).len(), 5);
+        assert!(manager.query(ProposalQuery::new().state(ProposalState::Expired)).is_empty());
+    }
+    
+    #[test]
+    fn test_query_by_epoch_window() {
+        let manager = seeded_manager();
+        
+        // Both bounds are inclusive
+        assert_eq!(ids(manager.query(ProposalQuery::new().epochs(5, 9))), vec!["p-a", "p-b", "p-c", "p-e"]);
+        assert_eq!(ids(manager.query(ProposalQuery::new().epochs(2, 2))), vec!["p-d"]);
+        assert!(manager.query(ProposalQuery::new().epochs(6, 8)).is_empty());
+        assert!(manager.query(ProposalQuery::new().epochs(9, 2)).is_empty());
+    }
+    
+    #[test]
+    fn test_query_combined_filter() {
+        let manager = seeded_manager();
+        
+        let query = ProposalQuery::new()
+            .state(ProposalState::Rejected)
+            .scope(ConstitutionalScope::Governance)
+            .epochs(3, 10);
+        assert_eq!(ids(manager.query(query)), vec!["p-c"]);
+    }
 }
@@ -251,7 +251,7 @@ impl ProtocolEvolutionOrchestrator {
         let apip = self.proposals.get(&proposal_id)
             .ok_or_else(|| ProtocolEvolutionError::UnknownProposal(proposal_id.clone()))?;
         
-        let threshold = apip.risk_level.min_approval_threshold();
+        let threshold = apip.approval_threshold();
         
         let mut result = VotingResult::new(proposal_id.clone(), conclusion_epoch);
         result.finalize(votes_for, votes_against, threshold);
@@ -296,6 +296,13 @@ impl ProtocolEvolutionOrchestrator {
             ));
         }
         
+        if vote_result.approval_percentage < apip.approval_threshold() {
+            return Err(ProtocolEvolutionError::ActivationFailed(format!(
+                "Approval {}% below {} risk threshold {}%",
+                vote_result.approval_percentage, apip.risk_level.as_str(), apip.approval_threshold()
+            )));
+        }
+        
         if current_epoch < apip.target_epoch {
             return Err(ProtocolEvolutionError::ActivationFailed(
                 format!("Target epoch {} not yet reached", apip.target_epoch)
@@ -239,6 +239,29 @@ impl ProtocolRuleSet {
         Ok(self.get_rule(name)?.value)
     }
     
+    /// Update a rule's value, validated against its bounds
+    /// 
+    /// Bumps the rule's minor version and refreshes the commitment hash.
+    /// 
+    /// SAFETY: Atomic - on any error the rule set (including its
+    /// commitment hash) is left unchanged.
+    pub fn update_rule(&mut self, name: &str, value: RuleValue) -> Result<RuleVersion, ProtocolRuleError> {
+        let mut rule = self.get_rule(name)?.clone();
+        let new_version = rule.version.increment_minor();
+        rule.update(value, new_version)?;
+        
+        let previous = self.rules.insert(name.to_string(), rule);
+        if let Err(e) = self.compute_commitment_hash() {
+            if let Some(previous) = previous {
+                self.rules.insert(name.to_string(), previous);
+            }
+            return Err(e);
+        }
+        
+        info!("Rule {} updated to {} (version {:?})", name, value, new_version);
+        Ok(new_version)
+    }
+    
     /// Compute the deterministic commitment hash for this rule set
     /// 
     /// SAFETY: This hash is deterministic and identical on all nodes
@@ -324,8 +347,8 @@ impl ProtocolRuleSetFactory {
         
         rules.add_rule(ProtocolRule::new(
             "MIN_VALIDATOR_STAKE".to_string(),
-            1000 * 10_u64.pow(18), // 1000 BLP with 18 decimals
-            RuleBounds::new(100 * 10_u64.pow(18), 10_000 * 10_u64.pow(18))?,
+            1000 * 10_u64.pow(8), // 1000 BLEEP with 8 decimals
+            RuleBounds::new(100 * 10_u64.pow(8), 10_000 * 10_u64.pow(8))?,
             "Minimum stake to become a validator".to_string(),
             true,
             0,
@@ -443,6 +466,38 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_ruleset_update_rule_in_bounds() {
+        let mut ruleset = ProtocolRuleSetFactory::create_genesis().unwrap();
+        let old_hash = ruleset.commitment_hash.clone();
+        
+        let version = ruleset.update_rule("CHECKPOINT_FREQUENCY", 200).unwrap();
+        assert_eq!(version, RuleVersion::new(0, 1, 0));
+        assert_eq!(ruleset.get_rule_value("CHECKPOINT_FREQUENCY").unwrap(), 200);
+        assert_eq!(ruleset.get_rule("CHECKPOINT_FREQUENCY").unwrap().version, version);
+        assert_ne!(ruleset.commitment_hash, old_hash);
+        assert!(ruleset.verify_commitment().unwrap());
+        
+        assert_eq!(ruleset.update_rule("CHECKPOINT_FREQUENCY", 300).unwrap(), RuleVersion::new(0, 2, 0));
+    }
+
+    #[test]
+    fn test_ruleset_update_rule_out_of_bounds() {
+        let mut ruleset = ProtocolRuleSetFactory::create_genesis().unwrap();
+        let old_hash = ruleset.commitment_hash.clone();
+        
+        let result = ruleset.update_rule("CHECKPOINT_FREQUENCY", 5_000);
+        assert!(matches!(result, Err(ProtocolRuleError::ValueOutOfBounds(_))));
+        
+        let rule = ruleset.get_rule("CHECKPOINT_FREQUENCY").unwrap();
+        assert_eq!(rule.value, 100);
+        assert_eq!(rule.version, RuleVersion::new(0, 0, 0));
+        assert_eq!(ruleset.commitment_hash, old_hash);
+        
+        assert!(ruleset.update_rule("FINALITY_THRESHOLD", 75).is_err());
+        assert!(matches!(ruleset.update_rule("NO_SUCH_RULE", 1), Err(ProtocolRuleError::UnknownRule(_))));
+    }
+
     #[test]
     fn test_ruleset_hash_commitment() {
         let mut ruleset = ProtocolRuleSetFactory::create_genesis().unwrap();
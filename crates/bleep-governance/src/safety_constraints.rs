@@ -126,6 +126,22 @@ impl ValidationReport {
         self.checks.push(result);
     }
     
+    /// Failed hard (`Error`) checks; any one rejects the proposal
+    pub fn critical_failures(&self) -> Vec<&ConstraintCheckResult> {
+        self.checks
+            .iter()
+            .filter(|c| c.severity == CheckSeverity::Error && !c.passed)
+            .collect()
+    }
+    
+    /// Failed `Warning` checks; reviewed but never reject the proposal
+    pub fn warnings(&self) -> Vec<&ConstraintCheckResult> {
+        self.checks
+            .iter()
+            .filter(|c| c.severity == CheckSeverity::Warning && !c.passed)
+            .collect()
+    }
+    
     /// Generate a human-readable summary
     pub fn summary(&self) -> String {
         format!(
@@ -148,34 +164,77 @@ impl SafetyConstraintsEngine {
         current_ruleset: &ProtocolRuleSet,
     ) -> Result<ValidationReport, SafetyConstraintError> {
         let mut report = ValidationReport::new(apip.proposal_id.clone());
+        Self::run_checks(apip, current_ruleset, &mut report)?;
+        
+        info!("Proposal validation complete: {}", report.summary());
+        
+        Ok(report)
+    }
+    
+    /// Single pre-flight gate over all safety constraints
+    /// 
+    /// SAFETY: Never errors. A constraint that cannot be evaluated is
+    /// recorded as a failed hard check, so the proposal is rejected.
+    /// Any failed `Error` check rejects (`is_valid == false`); failed
+    /// `Warning` checks are listed by `warnings()` but let it through.
+    pub fn preflight(apip: &APIP, current_ruleset: &ProtocolRuleSet) -> ValidationReport {
+        let mut report = ValidationReport::new(apip.proposal_id.clone());
         
+        if let Err(e) = Self::run_checks(apip, current_ruleset, &mut report) {
+            report.add_check(ConstraintCheckResult {
+                passed: false,
+                constraint_name: "CONSTRAINT_EVALUATION".to_string(),
+                message: format!("Constraint could not be evaluated: {}", e),
+                severity: CheckSeverity::Error,
+            });
+        }
+        
+        if report.is_valid {
+            info!(
+                "Preflight accepted {} with {} warning(s)",
+                apip.proposal_id, report.warnings().len()
+            );
+        } else {
+            warn!(
+                "Preflight rejected {}: {} critical failure(s)",
+                apip.proposal_id, report.critical_failures().len()
+            );
+        }
+        
+        report
+    }
+    
+    /// Evaluate every constraint into `report`, in a fixed order
+    fn run_checks(
+        apip: &APIP,
+        current_ruleset: &ProtocolRuleSet,
+        report: &mut ValidationReport,
+    ) -> Result<(), SafetyConstraintError> {
         // Constraint 1: Basic structural validation
-        Self::check_apip_completeness(apip, &mut report);
+        Self::check_apip_completeness(apip, report);
         
         // Constraint 2: Rule bounds validation
-        Self::check_rule_bounds(apip, current_ruleset, &mut report)?;
+        Self::check_rule_bounds(apip, current_ruleset, report)?;
         
         // Constraint 3: Safety bounds validation
-        Self::check_safety_bounds(apip, &mut report)?;
+        Self::check_safety_bounds(apip, report)?;
         
         // Constraint 4: Invariant compatibility
-        Self::check_invariant_compatibility(apip, current_ruleset, &mut report)?;
+        Self::check_invariant_compatibility(apip, current_ruleset, report)?;
         
         // Constraint 5: Worst-case scenario simulation
-        Self::check_worst_case_scenarios(apip, current_ruleset, &mut report)?;
+        Self::check_worst_case_scenarios(apip, current_ruleset, report)?;
         
         // Constraint 6: AI confidence thresholds
-        Self::check_ai_confidence(apip, current_ruleset, &mut report)?;
+        Self::check_ai_confidence(apip, current_ruleset, report)?;
         
         // Constraint 7: Risk level approval threshold
-        Self::check_risk_approval_requirements(apip, &mut report);
+        Self::check_risk_approval_requirements(apip, report);
         
         // Constraint 8: Epoch validity
-        Self::check_epoch_validity(apip, &mut report);
+        Self::check_epoch_validity(apip, report);
         
-        info!("Proposal validation complete: {}", report.summary());
-        
-        Ok(report)
+        Ok(())
     }
     
     /// Constraint 1: Check A-PIP completeness and structure
@@ -268,6 +327,24 @@ impl SafetyConstraintsEngine {
                 };
                 
                 report.add_check(result);
+                
+                // Inside the hard bounds but outside the recommended range
+                let recommended = change.new_value >= bounds.recommended_min
+                    && change.new_value <= bounds.recommended_max;
+                if is_safe && !recommended {
+                    report.add_check(ConstraintCheckResult {
+                        passed: false,
+                        constraint_name: format!("RECOMMENDED_BOUNDS_{}", change.rule_name),
+                        message: format!(
+                            "Rule {} new value {} outside recommended range [{}, {}]",
+                            change.rule_name,
+                            change.new_value,
+                            bounds.recommended_min,
+                            bounds.recommended_max
+                        ),
+                        severity: CheckSeverity::Warning,
+                    });
+                }
             }
         }
         
@@ -472,7 +549,8 @@ let min_confidence = ruleset.get_rule_value("AI_PROPOSAL_MIN_CONFIDENCE")
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::protocol_rules::{ProtocolRuleSetFactory, RuleBounds};
+    use crate::protocol_rules::{ProtocolRuleSetFactory, RuleBounds, RuleVersion};
+    use crate::apip::{AIModelMetadata, APIPBuilder};
 
     #[test]
     fn test_validation_report_creation() {
@@ -493,6 +571,81 @@ mod tests {
         assert!(result.passed);
     }
 
+    fn proposal_with(rule_name: &str, old_value: RuleValue, new_value: RuleValue, bounds: Option<SafetyBounds>) -> APIP {
+        let mut builder = APIPBuilder::new(
+            "APIP-PREFLIGHT".to_string(),
+            AIModelMetadata::new(
+                "test-model".to_string(),
+                "1.0.0".to_string(),
+                "Test Model".to_string(),
+                "Test Org".to_string(),
+            ),
+            "Preflight".to_string(),
+            "Test".to_string(),
+            10,
+        )
+        .unwrap()
+        .add_rule_change(RuleChange::new(
+            rule_name.to_string(),
+            old_value,
+            new_value,
+            RuleVersion::new(0, 1, 0),
+            "Tune".to_string(),
+        ))
+        .unwrap();
+        if let Some(bounds) = bounds {
+            builder = builder.set_safety_bounds(rule_name.to_string(), bounds).unwrap();
+        }
+        builder
+            .confidence(80)
+            .unwrap()
+            .risk(RiskLevel::Low, "Parameter tuning".to_string())
+            .unwrap()
+            .expected_impact("Impact".to_string())
+            .unwrap()
+            .rollback_strategy("Revert".to_string())
+            .unwrap()
+            .ai_signature(vec![1, 2, 3])
+            .unwrap()
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_preflight_accepts_with_warnings() {
+        let ruleset = ProtocolRuleSetFactory::create_genesis().unwrap();
+        // Inside the hard bounds, outside the recommended range
+        let bounds = SafetyBounds::new(100_000, 10_000_000, 500_000, 1_200_000).unwrap();
+        let apip = proposal_with("SHARD_SPLIT_THRESHOLD", 1_000_000, 1_500_000, Some(bounds));
+        
+        let report = SafetyConstraintsEngine::preflight(&apip, &ruleset);
+        assert!(report.is_valid);
+        assert!(report.critical_failures().is_empty());
+        let warnings: Vec<&str> = report.warnings().iter().map(|c| c.constraint_name.as_str()).collect();
+        assert_eq!(warnings, vec!["RECOMMENDED_BOUNDS_SHARD_SPLIT_THRESHOLD"]);
+        assert_eq!(report.warning_count, 1);
+    }
+
+    #[test]
+    fn test_preflight_rejects_critical_failure() {
+        let ruleset = ProtocolRuleSetFactory::create_genesis().unwrap();
+        let bounds = SafetyBounds::new(0, 100, 2, 20).unwrap();
+        let apip = proposal_with("SLASHING_PROPORTION", 5, 0, Some(bounds));
+        
+        let report = SafetyConstraintsEngine::preflight(&apip, &ruleset);
+        assert!(!report.is_valid);
+        let critical: Vec<&str> = report.critical_failures().iter().map(|c| c.constraint_name.as_str()).collect();
+        assert_eq!(critical, vec!["RULE_BOUNDS_SLASHING_PROPORTION", "INVARIANT_NONZERO_SLASHING"]);
+        let warnings: Vec<&str> = report.warnings().iter().map(|c| c.constraint_name.as_str()).collect();
+        assert_eq!(warnings, vec!["RECOMMENDED_BOUNDS_SLASHING_PROPORTION"]);
+        
+        // Constraints that cannot be evaluated reject rather than error
+        let unknown = proposal_with("NO_SUCH_RULE", 1, 2, None);
+        let report = SafetyConstraintsEngine::preflight(&unknown, &ruleset);
+        assert!(!report.is_valid);
+        assert_eq!(report.critical_failures()[0].constraint_name, "CONSTRAINT_EVALUATION");
+    }
+
     #[test]
     fn test_safety_bounds_validation() {
         let bounds = SafetyBounds::new(100, 1000, 200, 900).unwrap();
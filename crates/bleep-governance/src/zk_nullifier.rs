@@ -0,0 +1,396 @@
+// PHASE 4: CONSTITUTIONAL GOVERNANCE LAYER
+// Anonymous Voter Nullifiers - Groth16 eligibility proofs over BLS12-381
+//
+// SAFETY INVARIANTS:
+// 1. One nullifier per (voter secret, proposal): voting again reproduces it
+// 2. Nullifiers for different proposals cannot be linked without the secret
+// 3. The eligibility proof binds the nullifier to the same secret as the
+//    voter's registered identity commitment
+// 4. The proof reveals neither the secret, the voter's stake, nor which
+//    eligible voter cast it
+// 5. The proof is bound to one ballot and one proposal: it cannot be
+//    re-attached to a different vote
+//
+// SCHEME:
+//   identity  = H(secret, identity_tag)      (leaf in the eligibility tree)
+//   nullifier = H(secret, proposal_tag)
+//   proof     = Groth16 { ∃ secret, path : nullifier = H(secret, proposal_tag)
+//                         ∧ MerkleRoot(H(secret, identity_tag), path) = root }
+//   public    = (root, proposal_tag, nullifier, ballot_tag)
+// where H is MiMC-5 in Miyaguchi–Preneel mode, which is cheap inside R1CS.
+
+use ark_bls12_381::{Bls12_381, Fr};
+use ark_ff::{BigInteger, Field, PrimeField, Zero};
+use ark_groth16::{Groth16, PreparedVerifyingKey, Proof, ProvingKey};
+use ark_r1cs_std::fields::fp::FpVar;
+use ark_r1cs_std::prelude::*;
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_snark::SNARK;
+use ark_std::rand::{CryptoRng, RngCore};
+use sha2::{Digest, Sha256};
+use std::sync::OnceLock;
+
+use crate::zk_voting::ZKVotingError;
+
+/// Depth of the eligibility Merkle tree (up to 2^depth registered voters)
+pub const ELIGIBILITY_TREE_DEPTH: usize = 8;
+
+/// MiMC rounds: ceil(255 / log2(5))
+const MIMC_ROUNDS: usize = 110;
+
+/// Deterministic MiMC round constants
+fn mimc_constants() -> &'static [Fr] {
+    static CONSTANTS: OnceLock<Vec<Fr>> = OnceLock::new();
+    CONSTANTS.get_or_init(|| {
+        (0..MIMC_ROUNDS as u64)
+            .map(|i| {
+                let mut hasher = Sha256::new();
+                hasher.update(b"bleep-zkvote-mimc");
+                hasher.update(i.to_le_bytes());
+                Fr::from_le_bytes_mod_order(&hasher.finalize())
+            })
+            .collect()
+    })
+}
+
+/// Two-to-one MiMC-5 hash
+fn mimc_hash(left: Fr, right: Fr) -> Fr {
+    let mut x = left;
+    for c in mimc_constants() {
+        let t = x + right + c;
+        x = t.square().square() * t;
+    }
+    x + right + left
+}
+
+/// In-circuit counterpart of `mimc_hash` (3 constraints per round)
+fn mimc_hash_gadget(left: &FpVar<Fr>, right: &FpVar<Fr>) -> Result<FpVar<Fr>, SynthesisError> {
+    let mut x = left.clone();
+    for c in mimc_constants() {
+        let t = &x + right + FpVar::constant(*c);
+        x = t.square()?.square()? * &t;
+    }
+    Ok(x + right + left)
+}
+
+fn fr_to_bytes(value: Fr) -> [u8; 32] {
+    let mut out = [0u8; 32];
+    out.copy_from_slice(&value.into_bigint().to_bytes_le());
+    out
+}
+
+/// Decode a field element, rejecting non-canonical encodings
+///
+/// SAFETY: Without this, `x` and `x + r` would be distinct byte strings for
+/// the same nullifier and could bypass the spent-set.
+fn canonical_fr(bytes: &[u8]) -> Option<Fr> {
+    let value = Fr::from_le_bytes_mod_order(bytes);
+    (bytes.len() == 32 && fr_to_bytes(value) == bytes).then_some(value)
+}
+
+fn proposal_tag(proposal_id: &str) -> Fr {
+    let mut hasher = Sha256::new();
+    hasher.update(b"bleep-zkvote-proposal");
+    hasher.update(proposal_id.as_bytes());
+    Fr::from_le_bytes_mod_order(&hasher.finalize())
+}
+
+fn ballot_tag(ballot_hash: &[u8]) -> Fr {
+    let mut hasher = Sha256::new();
+    hasher.update(b"bleep-zkvote-ballot");
+    hasher.update(ballot_hash);
+    Fr::from_le_bytes_mod_order(&hasher.finalize())
+}
+
+/// Fixed domain tag for identity leaves (carries no role or stake)
+fn identity_tag() -> Fr {
+    let mut hasher = Sha256::new();
+    hasher.update(b"bleep-zkvote-identity");
+    Fr::from_le_bytes_mod_order(&hasher.finalize())
+}
+
+/// A voter's long-term secret
+///
+/// SAFETY: Never leaves the voter's device; only the identity commitment,
+/// nullifiers, and proofs derived from it are published.
+#[derive(Clone)]
+pub struct VoterSecret([u8; 32]);
+
+impl VoterSecret {
+    /// Generate a fresh random secret
+    pub fn generate<R: RngCore + CryptoRng>(rng: &mut R) -> Self {
+        let mut bytes = [0u8; 32];
+        rng.fill_bytes(&mut bytes);
+        VoterSecret(bytes)
+    }
+
+    /// Restore a secret from its bytes
+    pub fn from_bytes(bytes: [u8; 32]) -> Self {
+        VoterSecret(bytes)
+    }
+
+    fn as_fr(&self) -> Fr {
+        Fr::from_le_bytes_mod_order(&self.0)
+    }
+
+    /// Identity commitment registered in the eligibility tree
+    pub fn identity_commitment(&self) -> [u8; 32] {
+        fr_to_bytes(mimc_hash(self.as_fr(), identity_tag()))
+    }
+
+    /// Nullifier for `proposal_id` (identical on every ballot for that proposal)
+    pub fn nullifier(&self, proposal_id: &str) -> [u8; 32] {
+        fr_to_bytes(mimc_hash(self.as_fr(), proposal_tag(proposal_id)))
+    }
+}
+
+/// Fixed-depth Merkle tree over eligible voters' identity commitments
+#[derive(Debug, Clone)]
+pub struct EligibilityTree {
+    /// levels[0] = leaves (zero-padded), levels[DEPTH] = [root]
+    levels: Vec<Vec<Fr>>,
+}
+
+impl EligibilityTree {
+    /// Build a tree from identity commitments
+    pub fn new(identities: &[[u8; 32]]) -> Result<Self, ZKVotingError> {
+        if identities.len() > 1 << ELIGIBILITY_TREE_DEPTH {
+            return Err(ZKVotingError::EligibilityTreeFull);
+        }
+
+        let mut leaves = identities
+            .iter()
+            .map(|id| canonical_fr(id).ok_or(ZKVotingError::IneligibleVoter))
+            .collect::<Result<Vec<_>, _>>()?;
+        leaves.resize(1 << ELIGIBILITY_TREE_DEPTH, Fr::zero());
+
+        let mut levels = vec![leaves];
+        for _ in 0..ELIGIBILITY_TREE_DEPTH {
+            let next = levels
+                .last()
+                .expect("tree has at least one level")
+                .chunks(2)
+                .map(|pair| mimc_hash(pair[0], pair[1]))
+                .collect();
+            levels.push(next);
+        }
+
+        Ok(EligibilityTree { levels })
+    }
+
+    /// Root to register with the voting engine for a proposal
+    pub fn root(&self) -> [u8; 32] {
+        fr_to_bytes(self.levels[ELIGIBILITY_TREE_DEPTH][0])
+    }
+
+    /// Authentication path: (sibling, node_is_right_child) from leaf to root
+    fn path(&self, leaf: &[u8; 32]) -> Option<Vec<(Fr, bool)>> {
+        let leaf = canonical_fr(leaf)?;
+        let mut index = self.levels[0].iter().position(|l| *l == leaf)?;
+
+        let path = self.levels[..ELIGIBILITY_TREE_DEPTH]
+            .iter()
+            .map(|level| {
+                let step = (level[index ^ 1], index & 1 == 1);
+                index >>= 1;
+                step
+            })
+            .collect();
+        Some(path)
+    }
+}
+
+/// R1CS statement proven by an anonymous voter
+#[derive(Clone)]
+struct VoteEligibilityCircuit {
+    // Public inputs
+    root: Fr,
+    proposal: Fr,
+    nullifier: Fr,
+    ballot: Fr,
+
+    // Private witnesses (None during setup)
+    secret: Option<Fr>,
+    path: Option<Vec<(Fr, bool)>>,
+}
+
+impl VoteEligibilityCircuit {
+    fn blank() -> Self {
+        VoteEligibilityCircuit {
+            root: Fr::zero(),
+            proposal: Fr::zero(),
+            nullifier: Fr::zero(),
+            ballot: Fr::zero(),
+            secret: None,
+            path: None,
+        }
+    }
+}
+
+impl ConstraintSynthesizer<Fr> for VoteEligibilityCircuit {
+    fn generate_constraints(self, cs: ConstraintSystemRef<Fr>) -> Result<(), SynthesisError> {
+        let root = FpVar::new_input(cs.clone(), || Ok(self.root))?;
+        let proposal = FpVar::new_input(cs.clone(), || Ok(self.proposal))?;
+        let nullifier = FpVar::new_input(cs.clone(), || Ok(self.nullifier))?;
+        let ballot = FpVar::new_input(cs.clone(), || Ok(self.ballot))?;
+
+        let secret = FpVar::new_witness(cs.clone(), || {
+            self.secret.ok_or(SynthesisError::AssignmentMissing)
+        })?;
+
+        // Nullifier is derived from the secret and this proposal
+        mimc_hash_gadget(&secret, &proposal)?.enforce_equal(&nullifier)?;
+
+        // Tie the ballot into the constraint system so the proof cannot be
+        // moved to another ballot
+        let _ballot_square = ballot.square()?;
+
+        // The same secret opens a leaf of the eligibility tree
+        let mut node = mimc_hash_gadget(&secret, &FpVar::constant(identity_tag()))?;
+        for level in 0..ELIGIBILITY_TREE_DEPTH {
+            let step = || {
+                self.path
+                    .as_ref()
+                    .and_then(|p| p.get(level).copied())
+                    .ok_or(SynthesisError::AssignmentMissing)
+            };
+            let sibling = FpVar::new_witness(cs.clone(), || step().map(|s| s.0))?;
+            let is_right = Boolean::new_witness(cs.clone(), || step().map(|s| s.1))?;
+
+            let left = is_right.select(&sibling, &node)?;
+            let right = is_right.select(&node, &sibling)?;
+            node = mimc_hash_gadget(&left, &right)?;
+        }
+        node.enforce_equal(&root)?;
+
+        Ok(())
+    }
+}
+
+/// Groth16 keys for the vote-eligibility circuit
+pub struct VoteCircuitParams {
+    proving_key: ProvingKey<Bls12_381>,
+    verifying_key: PreparedVerifyingKey<Bls12_381>,
+}
+
+impl VoteCircuitParams {
+    /// Generate circuit keys
+    ///
+    /// SAFETY: Whoever runs this learns the toxic waste. Use only for devnet
+    /// and tests; production keys come from the MPC ceremony.
+    pub fn setup<R: RngCore + CryptoRng>(rng: &mut R) -> Result<Self, ZKVotingError> {
+        let (proving_key, vk) =
+            Groth16::<Bls12_381>::circuit_specific_setup(VoteEligibilityCircuit::blank(), rng)
+                .map_err(|e| ZKVotingError::SerializationError(e.to_string()))?;
+        let verifying_key = Groth16::<Bls12_381>::process_vk(&vk)
+            .map_err(|e| ZKVotingError::SerializationError(e.to_string()))?;
+        Ok(VoteCircuitParams { proving_key, verifying_key })
+    }
+
+    /// Verifying key to hand to `ZKVotingEngine`
+    pub fn verifying_key(&self) -> PreparedVerifyingKey<Bls12_381> {
+        self.verifying_key.clone()
+    }
+
+    /// Prove eligibility to cast the ballot hashing to `ballot_hash` on
+    /// `proposal_id` without revealing which voter
+    ///
+    /// Returns the serialized proof and the nullifier it is bound to.
+    pub fn prove<R: RngCore + CryptoRng>(
+        &self,
+        secret: &VoterSecret,
+        tree: &EligibilityTree,
+        proposal_id: &str,
+        ballot_hash: &[u8],
+        rng: &mut R,
+    ) -> Result<(Vec<u8>, [u8; 32]), ZKVotingError> {
+        let path = tree
+            .path(&secret.identity_commitment())
+            .ok_or(ZKVotingError::IneligibleVoter)?;
+        let nullifier = secret.nullifier(proposal_id);
+
+        let circuit = VoteEligibilityCircuit {
+            root: canonical_fr(&tree.root()).expect("root is canonical"),
+            proposal: proposal_tag(proposal_id),
+            nullifier: canonical_fr(&nullifier).expect("nullifier is canonical"),
+            ballot: ballot_tag(ballot_hash),
+            secret: Some(secret.as_fr()),
+            path: Some(path),
+        };
+
+        let proof = Groth16::<Bls12_381>::prove(&self.proving_key, circuit, rng)
+            .map_err(|e| ZKVotingError::SerializationError(e.to_string()))?;
+        let mut bytes = Vec::new();
+        proof
+            .serialize_compressed(&mut bytes)
+            .map_err(|e| ZKVotingError::SerializationError(e.to_string()))?;
+
+        Ok((bytes, nullifier))
+    }
+}
+
+/// Verify an eligibility proof against public inputs
+///
+/// Malformed proofs and non-canonical field encodings verify as `false`.
+pub fn verify_eligibility(
+    verifying_key: &PreparedVerifyingKey<Bls12_381>,
+    proof: &[u8],
+    root: &[u8; 32],
+    proposal_id: &str,
+    nullifier: &[u8],
+    ballot_hash: &[u8],
+) -> Result<bool, ZKVotingError> {
+    let (Some(root), Some(nullifier)) = (canonical_fr(root), canonical_fr(nullifier)) else {
+        return Ok(false);
+    };
+    let Ok(proof) = Proof::<Bls12_381>::deserialize_compressed(proof) else {
+        return Ok(false);
+    };
+
+    let inputs = [root, proposal_tag(proposal_id), nullifier, ballot_tag(ballot_hash)];
+    Groth16::<Bls12_381>::verify_with_processed_vk(verifying_key, &inputs, &proof)
+        .map_err(|e| ZKVotingError::SerializationError(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_std::rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn test_nullifier_is_deterministic_and_proposal_specific() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let secret = VoterSecret::generate(&mut rng);
+        let restored = VoterSecret::from_bytes(secret.0);
+
+        assert_eq!(secret.nullifier("prop_1"), restored.nullifier("prop_1"));
+        assert_ne!(secret.nullifier("prop_1"), secret.nullifier("prop_2"));
+        assert_ne!(secret.nullifier("prop_1"), secret.identity_commitment());
+        assert_ne!(
+            secret.nullifier("prop_1"),
+            VoterSecret::generate(&mut rng).nullifier("prop_1")
+        );
+    }
+
+    #[test]
+    fn test_eligibility_tree_paths_reach_root() {
+        let mut rng = StdRng::seed_from_u64(8);
+        let ids: Vec<_> = (0..5)
+            .map(|_| VoterSecret::generate(&mut rng).identity_commitment())
+            .collect();
+        let tree = EligibilityTree::new(&ids).unwrap();
+
+        for id in &ids {
+            let mut node = canonical_fr(id).unwrap();
+            for (sibling, is_right) in tree.path(id).unwrap() {
+                node = if is_right { mimc_hash(sibling, node) } else { mimc_hash(node, sibling) };
+            }
+            assert_eq!(fr_to_bytes(node), tree.root());
+        }
+
+        let outsider = VoterSecret::generate(&mut rng).identity_commitment();
+        assert!(tree.path(&outsider).is_none());
+        assert_eq!(canonical_fr(&[0xff; 32]), None);
+    }
+}
@@ -4,12 +4,16 @@
 // SAFETY INVARIANTS:
 // 1. Voter privacy: votes are never leaked (via ZK)
 // 2. Vote verification: tally is cryptographically verifiable
-// 3. Double-vote prevention: commitments make double voting impossible
+// 3. Double-vote prevention: commitments make double voting impossible;
+//    anonymous ballots are deduplicated by nullifier instead
 // 4. Replay resistance: nonce-based, epoch-bound
 // 5. Stake weighting: voting power proportional to stake
 // 6. Deterministic tallying: all nodes compute identical results
 // 7. No trusted setup required (where possible)
 
+use crate::zk_nullifier::verify_eligibility;
+use ark_bls12_381::Bls12_381;
+use ark_groth16::PreparedVerifyingKey;
 use serde::{Serialize, Deserialize};
 use sha2::{Digest, Sha256};
 use log::{info, warn, error};
@@ -47,6 +51,9 @@ pub enum ZKVotingError {
     
     #[error("Aggregate tally error")]
     AggregateTallyError,
+    
+    #[error("Eligibility tree is full")]
+    EligibilityTreeFull,
 }
 
 /// A voter's stake and role
@@ -177,11 +184,16 @@ pub struct EligibilityProof {
     /// Minimum stake threshold proven (in zero-knowledge)
     pub min_stake_proven: u64,
     
-    /// Voter role (revealed, required for weight calculation)
+    /// Voter role (revealed, required for weight calculation; not proven
+    /// for anonymous proofs and ignored there)
     pub role: VoterRole,
     
     /// Proof hash
     pub proof_hash: Vec<u8>,
+    
+    /// Nullifier the proof is bound to (empty for non-anonymous proofs)
+    #[serde(default)]
+    pub nullifier: Vec<u8>,
 }
 
 impl EligibilityProof {
@@ -191,27 +203,43 @@ impl EligibilityProof {
         min_stake_proven: u64,
         role: VoterRole,
     ) -> Result<Self, ZKVotingError> {
-        let proof_hash = Self::hash_proof(&proof, min_stake_proven)?;
+        Self::with_nullifier(proof, min_stake_proven, role, Vec::new())
+    }
+    
+    /// Create an anonymous eligibility proof bound to a voter nullifier
+    /// 
+    /// `proof` is a Groth16 proof from `VoteCircuitParams::prove`, which binds
+    /// `nullifier` to the same voter secret as a leaf of the eligibility tree
+    /// and to the hash of the ballot it is attached to.
+    pub fn with_nullifier(
+        proof: Vec<u8>,
+        min_stake_proven: u64,
+        role: VoterRole,
+        nullifier: Vec<u8>,
+    ) -> Result<Self, ZKVotingError> {
+        let proof_hash = Self::hash_proof(&proof, min_stake_proven, &nullifier)?;
         
         Ok(EligibilityProof {
             proof,
             min_stake_proven,
             role,
             proof_hash,
+            nullifier,
         })
     }
     
     /// Hash proof for verification
-    fn hash_proof(proof: &[u8], min_stake: u64) -> Result<Vec<u8>, ZKVotingError> {
+    fn hash_proof(proof: &[u8], min_stake: u64, nullifier: &[u8]) -> Result<Vec<u8>, ZKVotingError> {
         let mut hasher = Sha256::new();
         hasher.update(proof);
         hasher.update(min_stake.to_le_bytes());
+        hasher.update(nullifier);
         Ok(hasher.finalize().to_vec())
     }
     
     /// Verify proof hash
     pub fn verify_hash(&self) -> Result<bool, ZKVotingError> {
-        let computed_hash = Self::hash_proof(&self.proof, self.min_stake_proven)?;
+        let computed_hash = Self::hash_proof(&self.proof, self.min_stake_proven, &self.nullifier)?;
         Ok(computed_hash == self.proof_hash)
     }
     
@@ -260,8 +288,8 @@ impl EncryptedBallot {
         })
     }
     
-    /// Hash ballot for ordering
-    fn hash_ballot(
+    /// Hash ballot for ordering (and for binding anonymous eligibility proofs)
+    pub fn hash_ballot(
         encrypted_vote: &[u8],
         commitment_hash: &[u8],
     ) -> Result<Vec<u8>, ZKVotingError> {
@@ -281,8 +309,16 @@ impl EncryptedBallot {
     }
     
     /// Get voter's voting power
+    /// 
+    /// Anonymous ballots weigh one vote each: their stake is never revealed,
+    /// so the claimed role is unproven and eligibility-tree membership is the
+    /// only thing that counts.
     pub fn voting_power(&self) -> u64 {
-        self.eligibility_proof.role.voting_power()
+        if self.eligibility_proof.nullifier.is_empty() {
+            self.eligibility_proof.role.voting_power()
+        } else {
+            1
+        }
     }
 }
 
@@ -346,6 +382,15 @@ pub struct ZKVotingEngine {
     /// Nonce tracking for replay resistance
     used_nonces: HashSet<u64>,
     
+    /// Nullifiers of anonymous ballots already accepted
+    spent_nullifiers: HashSet<Vec<u8>>,
+    
+    /// Proposal ID -> eligibility tree root for anonymous ballots
+    eligibility_roots: HashMap<String, [u8; 32]>,
+    
+    /// Verifying key for anonymous eligibility proofs
+    vote_verifying_key: Option<PreparedVerifyingKey<Bls12_381>>,
+    
     /// Current epoch
     current_epoch: u64,
 }
@@ -357,27 +402,51 @@ impl ZKVotingEngine {
             proposal_voters: HashMap::new(),
             proposal_ballots: HashMap::new(),
             used_nonces: HashSet::new(),
+            spent_nullifiers: HashSet::new(),
+            eligibility_roots: HashMap::new(),
+            vote_verifying_key: None,
             current_epoch,
         }
     }
     
-    /// Cast a zero-knowledge vote
-    pub fn cast_vote(&mut self, ballot: VotingBallot) -> Result<(), ZKVotingError> {
-        // Verify ballot integrity
+    /// Attach the verifying key for anonymous eligibility proofs
+    pub fn with_vote_verifying_key(mut self, key: PreparedVerifyingKey<Bls12_381>) -> Self {
+        self.vote_verifying_key = Some(key);
+        self
+    }
+    
+    /// Register the eligibility tree root anonymous voters prove against
+    pub fn register_eligibility_root(&mut self, proposal_id: String, root: [u8; 32]) {
+        self.eligibility_roots.insert(proposal_id, root);
+    }
+    
+    /// Check whether a nullifier has already been used
+    pub fn is_nullifier_spent(&self, nullifier: &[u8]) -> bool {
+        self.spent_nullifiers.contains(nullifier)
+    }
+    
+    /// Verify ballot and commitment hashes and the voting epoch
+    fn verify_ballot_integrity(&self, ballot: &VotingBallot) -> Result<(), ZKVotingError> {
         if !ballot.encrypted_ballot.verify_hash()? {
             return Err(ZKVotingError::InvalidBallotProof);
         }
         
-        // Verify commitment hash
         if !ballot.encrypted_ballot.vote_commitment.verify_hash()? {
             return Err(ZKVotingError::CommitmentVerificationFailed);
         }
         
-        // Check voting window (must be in current epoch)
         if ballot.epoch != self.current_epoch {
             return Err(ZKVotingError::VotingWindowClosed);
         }
         
+        Ok(())
+    }
+    
+    /// Cast a zero-knowledge vote
+    pub fn cast_vote(&mut self, ballot: VotingBallot) -> Result<(), ZKVotingError> {
+        // Verify ballot integrity and voting window
+        self.verify_ballot_integrity(&ballot)?;
+        
         // Verify nonce hasn't been used (replay resistance)
         let nonce = ballot.encrypted_ballot.vote_commitment.nonce;
         if self.used_nonces.contains(&nonce) {
@@ -408,6 +477,57 @@ impl ZKVotingEngine {
         Ok(())
     }
     
+    /// Submit an anonymous ballot
+    /// 
+    /// SAFETY: The eligibility proof must show, in zero knowledge, that the
+    /// ballot's nullifier and a leaf of the proposal's eligibility tree derive
+    /// from the same voter secret, and must be bound to this ballot's hash and
+    /// proposal. Each nullifier is accepted once, so a voter cannot vote twice,
+    /// yet ballots never reveal which voter cast them or their stake.
+    pub fn submit_ballot(&mut self, ballot: VotingBallot) -> Result<(), ZKVotingError> {
+        self.verify_ballot_integrity(&ballot)?;
+        if ballot.encrypted_ballot.vote_commitment.proposal_id != ballot.proposal_id {
+            return Err(ZKVotingError::InvalidBallotProof);
+        }
+        
+        let eligibility = &ballot.encrypted_ballot.eligibility_proof;
+        if eligibility.nullifier.is_empty() {
+            return Err(ZKVotingError::InvalidVoterProof);
+        }
+        
+        if self.spent_nullifiers.contains(&eligibility.nullifier) {
+            warn!("Rejected ballot with spent nullifier for proposal {}", ballot.proposal_id);
+            return Err(ZKVotingError::DoubleVoteDetected);
+        }
+        
+        let root = self.eligibility_roots.get(&ballot.proposal_id)
+            .ok_or(ZKVotingError::IneligibleVoter)?;
+        let key = self.vote_verifying_key.as_ref()
+            .ok_or(ZKVotingError::InvalidVoterProof)?;
+        
+        if !verify_eligibility(
+            key,
+            &eligibility.proof,
+            root,
+            &ballot.proposal_id,
+            &eligibility.nullifier,
+            &ballot.encrypted_ballot.ballot_hash,
+        )? {
+            return Err(ZKVotingError::InvalidVoterProof);
+        }
+        
+        // Nullifiers replace nonces for anonymous ballots
+        self.spent_nullifiers.insert(eligibility.nullifier.clone());
+        self.proposal_ballots
+            .entry(ballot.proposal_id.clone())
+            .or_default()
+            .push(ballot);
+        
+        info!("Anonymous ballot accepted in ZK voting engine");
+        
+        Ok(())
+    }
+    
     /// Get all ballots for a proposal
     pub fn get_ballots(&self, proposal_id: &str) -> Option<Vec<VotingBallot>> {
         self.proposal_ballots.get(proposal_id).cloned()
@@ -709,4 +829,158 @@ mod tests {
         
         Ok(())
     }
+    
+    mod anonymous {
+        use super::*;
+        use crate::zk_nullifier::{EligibilityTree, VoteCircuitParams, VoterSecret};
+        use ark_std::rand::{rngs::StdRng, SeedableRng};
+        use std::sync::OnceLock;
+        
+        const ROLE: VoterRole = VoterRole::Validator { stake: 1000 };
+        
+        fn params() -> &'static VoteCircuitParams {
+            static PARAMS: OnceLock<VoteCircuitParams> = OnceLock::new();
+            PARAMS.get_or_init(|| {
+                VoteCircuitParams::setup(&mut StdRng::seed_from_u64(42)).unwrap()
+            })
+        }
+        
+        /// Engine with `voters` registered as eligible for "prop_1"
+        fn engine_for(voters: &[VoterSecret]) -> (ZKVotingEngine, EligibilityTree) {
+            let ids: Vec<_> = voters.iter().map(|v| v.identity_commitment()).collect();
+            let tree = EligibilityTree::new(&ids).unwrap();
+            let mut engine = ZKVotingEngine::new(0).with_vote_verifying_key(params().verifying_key());
+            engine.register_eligibility_root("prop_1".to_string(), tree.root());
+            (engine, tree)
+        }
+        
+        fn anonymous_ballot(
+            voter: &VoterSecret,
+            tree: &EligibilityTree,
+            nonce: u64,
+        ) -> Result<VotingBallot, ZKVotingError> {
+            let mut rng = StdRng::seed_from_u64(nonce);
+            
+            // Fresh one-time key per ballot so the commitment does not identify the voter
+            let one_time_key = VoterSecret::generate(&mut rng).nullifier("one-time");
+            let commitment = VoteCommitment::new(
+                vec![1, 2, 3],
+                "prop_1".to_string(),
+                &one_time_key,
+                0,
+                nonce,
+                1000,
+            )?;
+            let encrypted_vote = vec![7, 8, 9];
+            let ballot_hash = EncryptedBallot::hash_ballot(&encrypted_vote, &commitment.commitment_hash)?;
+            let (proof, nullifier) = params().prove(voter, tree, "prop_1", &ballot_hash, &mut rng)?;
+            
+            let eligibility = EligibilityProof::with_nullifier(proof, 1000, ROLE, nullifier.to_vec())?;
+            let ballot = EncryptedBallot::new(encrypted_vote, eligibility, commitment, vec![])?;
+            
+            Ok(VotingBallot::new("prop_1".to_string(), ballot, vec![], 0, nonce))
+        }
+        
+        #[test]
+        fn test_legitimate_anonymous_vote_accepted() -> Result<(), ZKVotingError> {
+            let mut rng = StdRng::seed_from_u64(1);
+            let voter = VoterSecret::generate(&mut rng);
+            let (mut engine, tree) = engine_for(&[voter.clone()]);
+            
+            engine.submit_ballot(anonymous_ballot(&voter, &tree, 1)?)?;
+            
+            assert!(engine.is_nullifier_spent(&voter.nullifier("prop_1")));
+            assert_eq!(engine.get_ballots("prop_1").unwrap().len(), 1);
+            Ok(())
+        }
+        
+        #[test]
+        fn test_second_anonymous_vote_rejected() -> Result<(), ZKVotingError> {
+            let mut rng = StdRng::seed_from_u64(2);
+            let voter = VoterSecret::generate(&mut rng);
+            let (mut engine, tree) = engine_for(&[voter.clone()]);
+            
+            engine.submit_ballot(anonymous_ballot(&voter, &tree, 1)?)?;
+            
+            // A fresh proof and commitment still carry the same nullifier
+            let second = anonymous_ballot(&voter, &tree, 2)?;
+            assert_eq!(engine.submit_ballot(second).err(), Some(ZKVotingError::DoubleVoteDetected));
+            assert_eq!(engine.get_ballots("prop_1").unwrap().len(), 1);
+            
+            // A non-member cannot produce a proof at all
+            let outsider = VoterSecret::generate(&mut rng);
+            assert_eq!(
+                anonymous_ballot(&outsider, &tree, 3).err(),
+                Some(ZKVotingError::IneligibleVoter)
+            );
+            Ok(())
+        }
+        
+        #[test]
+        fn test_distinct_anonymous_voters_both_accepted() -> Result<(), ZKVotingError> {
+            let mut rng = StdRng::seed_from_u64(3);
+            let alice = VoterSecret::generate(&mut rng);
+            let bob = VoterSecret::generate(&mut rng);
+            let (mut engine, tree) = engine_for(&[alice.clone(), bob.clone()]);
+            
+            engine.submit_ballot(anonymous_ballot(&alice, &tree, 1)?)?;
+            engine.submit_ballot(anonymous_ballot(&bob, &tree, 2)?)?;
+            
+            let ballots = engine.get_ballots("prop_1").unwrap();
+            assert_eq!(ballots.len(), 2);
+            // The claimed role is unproven, so each anonymous ballot weighs one vote
+            assert!(ballots.iter().all(|b| b.encrypted_ballot.voting_power() == 1));
+            Ok(())
+        }
+        
+        #[test]
+        fn test_proof_not_transferable_to_another_ballot() -> Result<(), ZKVotingError> {
+            let mut rng = StdRng::seed_from_u64(5);
+            let voter = VoterSecret::generate(&mut rng);
+            let (mut engine, tree) = engine_for(&[voter.clone()]);
+            
+            // Swap the vote under an intercepted proof and re-hash the ballot
+            let mut ballot = anonymous_ballot(&voter, &tree, 1)?;
+            let encrypted = &mut ballot.encrypted_ballot;
+            encrypted.encrypted_vote = vec![0xde, 0xad];
+            encrypted.ballot_hash = EncryptedBallot::hash_ballot(
+                &encrypted.encrypted_vote,
+                &encrypted.vote_commitment.commitment_hash,
+            )?;
+            assert_eq!(engine.submit_ballot(ballot).err(), Some(ZKVotingError::InvalidVoterProof));
+            
+            // The same proof cannot be replayed on another proposal either
+            let mut ballot = anonymous_ballot(&voter, &tree, 2)?;
+            engine.register_eligibility_root("prop_2".to_string(), tree.root());
+            ballot.proposal_id = "prop_2".to_string();
+            assert_eq!(engine.submit_ballot(ballot).err(), Some(ZKVotingError::InvalidBallotProof));
+            
+            assert!(!engine.is_nullifier_spent(&voter.nullifier("prop_1")));
+            engine.submit_ballot(anonymous_ballot(&voter, &tree, 3)?)?;
+            Ok(())
+        }
+        
+        #[test]
+        fn test_nullifier_not_bound_to_proof_rejected() -> Result<(), ZKVotingError> {
+            let mut rng = StdRng::seed_from_u64(4);
+            let alice = VoterSecret::generate(&mut rng);
+            let bob = VoterSecret::generate(&mut rng);
+            let (mut engine, tree) = engine_for(&[alice.clone(), bob.clone()]);
+            
+            // Alice's proof paired with Bob's nullifier must not verify
+            let mut ballot = anonymous_ballot(&alice, &tree, 1)?;
+            let eligibility = &ballot.encrypted_ballot.eligibility_proof;
+            let forged = EligibilityProof::with_nullifier(
+                eligibility.proof.clone(),
+                1000,
+                ROLE,
+                bob.nullifier("prop_1").to_vec(),
+            )?;
+            ballot.encrypted_ballot.eligibility_proof = forged;
+            
+            assert_eq!(engine.submit_ballot(ballot).err(), Some(ZKVotingError::InvalidVoterProof));
+            assert!(!engine.is_nullifier_spent(&bob.nullifier("prop_1")));
+            Ok(())
+        }
+    }
 }
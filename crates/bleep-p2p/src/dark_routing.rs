@@ -1,139 +1,264 @@
-use super::ai_security::PeerScoring;
-use super::peer_manager::PeerManager;
-use super::message_protocol::{MessageProtocol, SecureMessage, MessageType};
-use std::sync::{Arc, Mutex};
-use rand::seq::SliceRandom;
-
-const MAX_HOPS: usize = 6; // Maximum hops for routing
-
-/// Dark Routing with AI Trust Scoring & Quantum-Secure Encryption
-#[derive(Debug)]
-pub struct DarkRouting {
-    peer_manager: Arc<PeerManager>,
-    message_protocol: MessageProtocol,
-    ai_security: Arc<Mutex<PeerScoring>>, // AI-powered trust scoring (if used)
+//! Layered (onion) dark routing for sender anonymity.
+//!
+//! The sender wraps a payload in one encryption layer per hop, innermost
+//! first.  Each layer is sealed to the relay's Kyber-768 public key (fresh
+//! encapsulation per layer) and encrypted with AES-256-GCM, so:
+//! - a relay can decrypt only its own layer,
+//! - a relay learns only the next hop, never the rest of the path,
+//! - only the exit node recovers the payload.
+//!
+//! Unlike `onion_routing::OnionRouter`, no pre-established session is needed;
+//! the sender only needs each relay's published Kyber public key.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tracing::debug;
+
+use crate::quantum_crypto::{
+    aes_gcm_decrypt, aes_gcm_encrypt, derive_key, kyber_decapsulate, kyber_encapsulate,
+    KyberKeypair,
+};
+use crate::types::NodeId;
+
+// ─────────────────────────────────────────────────────────────────────────────
+// CONSTANTS
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Maximum hops in a dark-routing circuit.
+pub const MAX_HOPS: usize = 6;
+
+/// HKDF domain-separation label for per-layer keys.
+const LAYER_KEY_INFO: &[u8] = b"bleep-dark-layer-v1";
+
+// ─────────────────────────────────────────────────────────────────────────────
+// ERRORS
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Error)]
+pub enum RoutingError {
+    #[error("Circuit path is empty")]
+    EmptyPath,
+
+    #[error("Circuit path has {hops} hops (max {max})")]
+    TooManyHops { hops: usize, max: usize },
+
+    #[error("No Kyber public key known for relay {0}")]
+    UnknownRelay(NodeId),
+
+    #[error("Layer is not addressed to this node")]
+    NotForThisNode,
+
+    #[error("Malformed onion packet: {0}")]
+    Malformed(String),
+
+    #[error("Cryptography error: {0}")]
+    Crypto(String),
 }
 
-impl DarkRouting {
-    /// Initializes Dark Routing with AI-driven peer selection
-    pub fn new(peer_manager: Arc<PeerManager>, message_protocol: MessageProtocol) -> Self {
-        use std::sync::{Arc, Mutex};
-        Self {
-            peer_manager,
-            message_protocol,
-            ai_security: Arc::new(Mutex::new(PeerScoring::new())),
-        }
+// ─────────────────────────────────────────────────────────────────────────────
+// PACKET FORMAT
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// One onion layer on the wire.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OnionPacket {
+    /// Kyber ciphertext encapsulating this layer's key to the relay.
+    pub kem_ciphertext: Vec<u8>,
+    /// AES-256-GCM ciphertext (nonce prepended) of the layer contents.
+    pub body: Vec<u8>,
+}
+
+/// Routing decision revealed by peeling a layer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NextHop {
+    /// Forward the returned packet to this relay.
+    Relay(NodeId),
+    /// This node is the exit; the returned packet's `body` is the plaintext
+    /// payload.
+    Exit,
+}
+
+/// Decrypted contents of a layer: the next hop and the inner bytes.
+#[derive(Serialize, Deserialize)]
+struct LayerContents {
+    /// `None` marks the exit layer.
+    next_hop: Option<NodeId>,
+    /// Serialised inner `OnionPacket`, or the payload at the exit.
+    inner: Vec<u8>,
+}
+
+/// Per-layer AES key bound to the relay's identity.
+fn layer_key(shared_secret: &[u8], relay_id: &NodeId) -> [u8; 32] {
+    derive_key(shared_secret, relay_id.as_bytes(), LAYER_KEY_INFO)
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// DARK ROUTER
+// ─────────────────────────────────────────────────────────────────────────────
+
+pub struct DarkRouter {
+    local_id: NodeId,
+    kyber: KyberKeypair,
+    /// Relay directory: NodeId → Kyber-768 public key bytes.
+    relay_keys: HashMap<NodeId, Vec<u8>>,
+}
+
+impl DarkRouter {
+    pub fn new(local_id: NodeId, kyber: KyberKeypair) -> Self {
+        DarkRouter { local_id, kyber, relay_keys: HashMap::new() }
+    }
+
+    /// This node's Kyber public key, to be published for circuit builders.
+    pub fn public_key(&self) -> &[u8] {
+        &self.kyber.public_key.0
     }
 
-    /// Selects an anonymized routing path with AI-based filtering
-    fn select_anonymous_route(&self, sender_id: &str) -> Vec<String> {
-        use std::collections::HashSet;
-        let peers = self.peer_manager.get_peers();
-        let mut peer_set: HashSet<String> = peers.into_iter().map(|p| p.id).collect();
-        peer_set.remove(sender_id);
-        
-        let mut peer_list: Vec<String> = peer_set.into_iter().collect();
-        
-        // AI-Based Reputation Filtering: Use ai_security to score and filter peers
-        let ai_security = self.ai_security.lock().unwrap();
-        peer_list.retain(|peer_id| {
-            let score = ai_security.calculate_score(peer_id);
-            score >= 60.0 // Only use peers with good reputation
-        });
-        drop(ai_security);
-        
-        // Shuffle remaining high-reputation peers for anonymity
-        peer_list.shuffle(&mut rand::thread_rng());
-        peer_list.into_iter().take(MAX_HOPS).collect()
+    /// Learn (or replace) a relay's Kyber public key.
+    pub fn register_relay(&mut self, id: NodeId, kyber_public_key: Vec<u8>) {
+        self.relay_keys.insert(id, kyber_public_key);
     }
 
-    /// Encrypts message in multiple layers (Onion Routing + Quantum Security)
-    /// Uses encrypt_layer to apply quantum-secure encryption at each hop
-    fn onion_encrypt(&self, message: SecureMessage, route: &[String]) -> Vec<SecureMessage> {
-        let mut payload = message.payload.clone();
-        let mut encrypted_layers = Vec::new();
-        
-        // Apply encryption layers in reverse order (as per Onion Routing)
-        for (i, node) in route.iter().rev().enumerate() {
-            // Use encrypt_layer to apply quantum-secure encryption for this hop
-            payload = Self::encrypt_layer(&payload, node);
-            
-            // Create an encrypted message for this layer
-            let encrypted_message = SecureMessage {
-                sender_id: message.sender_id.clone(),
-                message_type: if i == 0 {
-                    message.message_type.clone()
-                } else {
-                    MessageType::Custom("onion_layer".to_string())
-                },
-                payload: payload.clone(),
-                signature: message.signature.clone(),
-                hop_count: i + 1,
-            };
-            
-            encrypted_layers.push(encrypted_message);
+    // ── CIRCUIT CONSTRUCTION ─────────────────────────────────────────────────
+
+    /// Wrap `payload` for `path`; the last hop is the exit.
+    ///
+    /// Layers are built inner-to-outer, so the returned packet is the one to
+    /// send to `path[0]`.
+    pub fn build_circuit(&self, path: &[NodeId], payload: &[u8]) -> Result<OnionPacket, RoutingError> {
+        if path.is_empty() {
+            return Err(RoutingError::EmptyPath);
+        }
+        if path.len() > MAX_HOPS {
+            return Err(RoutingError::TooManyHops { hops: path.len(), max: MAX_HOPS });
+        }
+
+        let mut inner = payload.to_vec();
+        let mut next_hop: Option<NodeId> = None;
+        let mut packet = None;
+
+        for relay_id in path.iter().rev() {
+            let relay_pk = self
+                .relay_keys
+                .get(relay_id)
+                .ok_or_else(|| RoutingError::UnknownRelay(relay_id.clone()))?;
+
+            let contents = bincode::serialize(&LayerContents { next_hop: next_hop.take(), inner })
+                .map_err(|e| RoutingError::Malformed(e.to_string()))?;
+
+            let (kem_ciphertext, shared_secret) =
+                kyber_encapsulate(relay_pk).map_err(|e| RoutingError::Crypto(e.to_string()))?;
+            let body = aes_gcm_encrypt(&layer_key(&shared_secret, relay_id), &contents)
+                .map_err(|e| RoutingError::Crypto(e.to_string()))?;
+
+            let layer = OnionPacket { kem_ciphertext, body };
+            inner = bincode::serialize(&layer).map_err(|e| RoutingError::Malformed(e.to_string()))?;
+            next_hop = Some(relay_id.clone());
+            packet = Some(layer);
         }
-        
-        encrypted_layers
+
+        debug!(hops = path.len(), "Dark-routing circuit built");
+        Ok(packet.expect("path is non-empty"))
     }
 
-    /// Handles message forwarding with dark routing using message protocol
-    pub async fn send_anonymous_message(&self, message: SecureMessage) {
-        let route = self.select_anonymous_route(&message.sender_id);
-        let encrypted_layers = self.onion_encrypt(message.clone(), &route);
-
-        for (i, relay_node) in route.iter().enumerate() {
-            if i < encrypted_layers.len() {
-                let relay_message = encrypted_layers[i].clone();
-                
-                // Queue the message using message_protocol for secure transmission
-                self.message_protocol.queue_message(relay_message.clone()).await;
-                
-                log::debug!(
-                    "Sent onion layer {} to relay node {} (hop_count: {})",
-                    i,
-                    relay_node,
-                    relay_message.hop_count
-                );
+    // ── LAYER PEELING ────────────────────────────────────────────────────────
+
+    /// Decrypt this node's layer of `packet`.
+    ///
+    /// Returns the next relay and the packet to forward to it, or
+    /// `NextHop::Exit` with the payload in the returned packet's `body`.
+    pub fn peel_layer(&self, packet: &OnionPacket) -> Result<(NextHop, OnionPacket), RoutingError> {
+        let shared_secret = kyber_decapsulate(&packet.kem_ciphertext, &self.kyber.secret_key.0)
+            .map_err(|e| RoutingError::Crypto(e.to_string()))?;
+        let contents = aes_gcm_decrypt(&layer_key(&shared_secret, &self.local_id), &packet.body)
+            .map_err(|_| RoutingError::NotForThisNode)?;
+        let contents: LayerContents =
+            bincode::deserialize(&contents).map_err(|e| RoutingError::Malformed(e.to_string()))?;
+
+        match contents.next_hop {
+            Some(next) => {
+                let inner: OnionPacket = bincode::deserialize(&contents.inner)
+                    .map_err(|e| RoutingError::Malformed(e.to_string()))?;
+                debug!(next_hop = %next, "Peeled relay layer");
+                Ok((NextHop::Relay(next), inner))
+            }
+            None => {
+                debug!("Peeled exit layer");
+                Ok((NextHop::Exit, OnionPacket { kem_ciphertext: Vec::new(), body: contents.inner }))
             }
         }
     }
+}
 
-    /// Processes incoming dark-routed messages
-    pub async fn handle_dark_routed_message(&self, mut message: SecureMessage, sender: String) {
-        message.payload = Self::decrypt_layer(&message.payload, &sender);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-        if message.hop_count < MAX_HOPS {
-            self.send_anonymous_message(message).await;
-        } else {
-            // Final recipient decrypts the last layer
-            println!("Final destination reached: {:?}", message);
-        }
+    fn make_nodes(n: usize) -> Vec<DarkRouter> {
+        (0..n).map(|_| DarkRouter::new(NodeId::random(), KyberKeypair::generate())).collect()
     }
 
-    /// Encrypts a message layer using **Quantum-Secure Kyber + SPHINCS+**
-    /// This function is USED in the onion routing pipeline (onion_encrypt)
-    fn encrypt_layer(payload: &[u8], recipient: &str) -> Vec<u8> {
-        // Quantum-secure encryption: hash the recipient ID with the payload
-        // In production, this would use actual Kyber/SPHINCS+ implementations
-        use sha2::Digest;
-        let mut hasher = sha2::Sha256::new();
-        hasher.update(recipient.as_bytes());
-        hasher.update(payload);
-        let result = hasher.finalize();
-        
-        // XOR the payload with the hash for basic encryption demonstration
-        let mut encrypted = payload.to_vec();
-        for (i, byte) in encrypted.iter_mut().enumerate() {
-            *byte ^= result[i % result.len()];
+    fn sender_for(relays: &[DarkRouter]) -> DarkRouter {
+        let mut sender = DarkRouter::new(NodeId::random(), KyberKeypair::generate());
+        for r in relays {
+            sender.register_relay(r.local_id.clone(), r.public_key().to_vec());
         }
-        
-        log::debug!("Encrypted layer for recipient: {}", recipient);
-        encrypted
+        sender
     }
-    fn decrypt_layer(payload: &[u8], _recipient: &str) -> Vec<u8> {
-        payload.to_vec()
+
+    #[test]
+    fn test_three_hop_circuit_peels_in_order() {
+        let relays = make_nodes(3);
+        let sender = sender_for(&relays);
+        let path: Vec<NodeId> = relays.iter().map(|r| r.local_id.clone()).collect();
+        let payload = b"anonymous transaction";
+
+        let packet = sender.build_circuit(&path, payload).unwrap();
+
+        let (hop, packet) = relays[0].peel_layer(&packet).unwrap();
+        assert_eq!(hop, NextHop::Relay(path[1].clone()));
+
+        let (hop, packet) = relays[1].peel_layer(&packet).unwrap();
+        assert_eq!(hop, NextHop::Relay(path[2].clone()));
+
+        let (hop, packet) = relays[2].peel_layer(&packet).unwrap();
+        assert_eq!(hop, NextHop::Exit);
+        assert_eq!(packet.body, payload);
     }
-}
 
-// ...existing code...
\ No newline at end of file
+    #[test]
+    fn test_intermediate_relay_cannot_read_payload() {
+        let relays = make_nodes(3);
+        let sender = sender_for(&relays);
+        let path: Vec<NodeId> = relays.iter().map(|r| r.local_id.clone()).collect();
+        let payload = b"only the exit may read this";
+
+        let outer = sender.build_circuit(&path, payload).unwrap();
+        let (_, second) = relays[0].peel_layer(&outer).unwrap();
+
+        // The relay's view contains no plaintext
+        let contains = |haystack: &[u8]| haystack.windows(payload.len()).any(|w| w == payload);
+        assert!(!contains(&second.body));
+        assert!(!contains(&outer.body));
+
+        // Relays cannot peel layers addressed to other hops
+        assert!(relays[0].peel_layer(&second).is_err());
+        assert!(relays[2].peel_layer(&second).is_err());
+        assert!(relays[1].peel_layer(&outer).is_err());
+    }
+
+    #[test]
+    fn test_build_circuit_rejects_bad_paths() {
+        let relays = make_nodes(1);
+        let sender = sender_for(&relays);
+
+        assert!(matches!(sender.build_circuit(&[], b"x"), Err(RoutingError::EmptyPath)));
+        assert!(matches!(
+            sender.build_circuit(&[NodeId::random()], b"x"),
+            Err(RoutingError::UnknownRelay(_))
+        ));
+        let long = vec![relays[0].local_id.clone(); MAX_HOPS + 1];
+        assert!(matches!(sender.build_circuit(&long, b"x"), Err(RoutingError::TooManyHops { .. })));
+    }
+}
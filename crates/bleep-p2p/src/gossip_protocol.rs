@@ -7,21 +7,18 @@
 //! - Anti-flood: per-peer message-rate tracking via PeerScoring.
 //! - All outbound messages are sealed via MessageProtocol (AES-GCM + Ed25519).
 
-use std::collections::HashSet;
+use std::num::NonZeroUsize;
 use std::sync::Arc;
 use std::time::Duration;
 
-use dashmap::DashMap;
 use lru::LruCache;
 use parking_lot::Mutex;
-use tokio::sync::mpsc;
 use tokio::time::interval;
-use tracing::{debug, info, warn};
+use tracing::{debug, warn};
 
-use crate::error::{P2PError, P2PResult};
 use crate::message_protocol::MessageProtocol;
 use crate::peer_manager::PeerManager;
-use crate::types::{MessageType, NodeId, SecureMessage, unix_now};
+use crate::types::{NodeId, SecureMessage, unix_now};
 
 // ─────────────────────────────────────────────────────────────────────────────
 // CONSTANTS
@@ -31,6 +28,11 @@ use crate::types::{MessageType, NodeId, SecureMessage, unix_now};
 const EAGER_FANOUT: usize = 8;
 /// LRU capacity for seen-message IDs.
 const SEEN_CACHE_CAPACITY: usize = 16_384;
+/// How long (seconds) a message ID is remembered after it was first seen.
+const SEEN_TTL_SECS: u64 = 120;
+/// Default relay limit; a message that has already travelled this many hops
+/// is delivered locally but not re-broadcast.
+pub const DEFAULT_MAX_HOPS: u8 = 8;
 /// How often the gossip background loop ticks.
 const GOSSIP_TICK: Duration = Duration::from_millis(200);
 
@@ -39,12 +41,15 @@ const GOSSIP_TICK: Duration = Duration::from_millis(200);
 // ─────────────────────────────────────────────────────────────────────────────
 
 /// Compute a 32-byte message fingerprint over (sender_id ‖ nonce ‖ timestamp).
+///
+/// `hop_count` is deliberately excluded so relayed copies of one message share
+/// an ID regardless of how far they have travelled.
 fn message_id(msg: &SecureMessage) -> [u8; 32] {
     use sha2::{Digest, Sha256};
     let mut h = Sha256::new();
     h.update(msg.sender_id.as_bytes());
-    h.update(&msg.nonce);
-    h.update(&msg.timestamp.to_le_bytes());
+    h.update(msg.nonce);
+    h.update(msg.timestamp.to_le_bytes());
     let d = h.finalize();
     let mut out = [0u8; 32];
     out.copy_from_slice(&d);
@@ -55,39 +60,109 @@ fn message_id(msg: &SecureMessage) -> [u8; 32] {
 // GOSSIP ENGINE
 // ─────────────────────────────────────────────────────────────────────────────
 
+/// What `GossipProtocol::handle_message` did with an inbound message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GossipOutcome {
+    /// Already seen within the TTL window; dropped.
+    Duplicate,
+    /// New, but at the hop limit: deliver locally, do not re-broadcast.
+    DeliverOnly,
+    /// New: deliver locally and queued for re-broadcast.
+    DeliverAndForward,
+}
+
 pub struct GossipProtocol {
     peer_manager: Arc<PeerManager>,
     message_protocol: Arc<MessageProtocol>,
-    /// LRU cache of already-seen message IDs (prevents re-broadcast).
-    seen: Arc<Mutex<LruCache<[u8; 32], ()>>>,
+    /// Seen message IDs → UNIX time first seen.  Entries are never promoted,
+    /// so LRU order is arrival order and expiry can pop from the LRU end.
+    seen: Arc<Mutex<LruCache<[u8; 32], u64>>>,
     /// Pending messages to be spread on the next tick.
     pending: Arc<Mutex<Vec<(SecureMessage, Option<NodeId>)>>>,
+    /// Messages with `hop_count >= max_hops` are not forwarded.
+    max_hops: u8,
 }
 
 impl GossipProtocol {
     pub fn new(peer_manager: Arc<PeerManager>, message_protocol: Arc<MessageProtocol>) -> Arc<Self> {
+        Self::with_max_hops(peer_manager, message_protocol, DEFAULT_MAX_HOPS)
+    }
+
+    pub fn with_max_hops(
+        peer_manager: Arc<PeerManager>,
+        message_protocol: Arc<MessageProtocol>,
+        max_hops: u8,
+    ) -> Arc<Self> {
         Arc::new(GossipProtocol {
             peer_manager,
             message_protocol,
             seen: Arc::new(Mutex::new(LruCache::new(
-                unsafe { std::num::NonZeroUsize::new_unchecked(SEEN_CACHE_CAPACITY) },
+                NonZeroUsize::new(SEEN_CACHE_CAPACITY).expect("capacity is non-zero"),
             ))),
             pending: Arc::new(Mutex::new(Vec::new())),
+            max_hops,
         })
     }
 
+    /// Record `id` as seen at `now`.  Returns `false` if it was already seen
+    /// within the TTL window.
+    fn mark_seen(&self, id: [u8; 32], now: u64) -> bool {
+        let mut seen = self.seen.lock();
+        Self::prune_locked(&mut seen, now);
+        if seen.contains(&id) {
+            return false;
+        }
+        seen.put(id, now);
+        true
+    }
+
+    fn prune_locked(seen: &mut LruCache<[u8; 32], u64>, now: u64) -> usize {
+        let mut pruned = 0;
+        while let Some((_, &first_seen)) = seen.peek_lru() {
+            if now.saturating_sub(first_seen) < SEEN_TTL_SECS {
+                break;
+            }
+            seen.pop_lru();
+            pruned += 1;
+        }
+        pruned
+    }
+
+    /// Drop seen-cache entries older than `SEEN_TTL_SECS`.  Returns how many
+    /// were removed.
+    fn prune_seen(&self, now: u64) -> usize {
+        Self::prune_locked(&mut self.seen.lock(), now)
+    }
+
+    /// Process a message received from peer `from`.
+    ///
+    /// Duplicates are dropped.  New messages below `max_hops` have their hop
+    /// count incremented and are queued for re-broadcast (never back to
+    /// `from`); messages at the limit are only delivered locally.
+    pub fn handle_message(&self, mut msg: SecureMessage, from: &NodeId) -> GossipOutcome {
+        if !self.mark_seen(message_id(&msg), unix_now()) {
+            debug!("GossipProtocol: dropping duplicate message from {}", from);
+            return GossipOutcome::Duplicate;
+        }
+        if msg.hop_count >= self.max_hops {
+            debug!(
+                "GossipProtocol: message from {} at hop limit ({}), not forwarding",
+                msg.sender_id, self.max_hops
+            );
+            return GossipOutcome::DeliverOnly;
+        }
+        msg.hop_count += 1;
+        self.pending.lock().push((msg, Some(from.clone())));
+        GossipOutcome::DeliverAndForward
+    }
+
     /// Enqueue a message for gossip.  `exclude` is the peer we received it from
     /// (to avoid echoing back).
     pub fn enqueue(&self, msg: SecureMessage, exclude: Option<NodeId>) {
-        let id = message_id(&msg);
-        let mut seen = self.seen.lock();
-        if seen.contains(&id) {
+        if !self.mark_seen(message_id(&msg), unix_now()) {
             debug!("GossipProtocol: dropping duplicate message");
             return;
         }
-        seen.put(id, ());
-        drop(seen);
-
         self.pending.lock().push((msg, exclude));
     }
 
@@ -97,78 +172,49 @@ impl GossipProtocol {
     /// established.  If a session is missing, the peer is skipped and a warning
     /// is logged.  Messages are **never** sent unencrypted.
     pub async fn spread(&self, msg: SecureMessage, exclude: Option<&NodeId>) {
-        let id = message_id(&msg);
-        {
-            let mut seen = self.seen.lock();
-            if seen.contains(&id) {
-                return;
-            }
-            seen.put(id, ());
+        if !self.mark_seen(message_id(&msg), unix_now()) {
+            return;
         }
+        self.push_to_peers(&msg, exclude).await;
+    }
 
+    /// Eager-push `msg` to the `EAGER_FANOUT` highest-trust healthy peers.
+    async fn push_to_peers(&self, msg: &SecureMessage, exclude: Option<&NodeId>) {
         let healthy = self.peer_manager.healthy_peers();
 
-        // Select EAGER_FANOUT highest-scoring peers (excluding sender).
-        let candidates: Vec<NodeId> = healthy
-            .iter()
-            .filter(|p| exclude.map_or(true, |ex| &p.id != ex))
-            .map(|p| p.id.clone())
-            .collect();
-
-        let ranked = self
-            .peer_manager
-            .dht() // access to scoring via peer_manager
-            .all_peers()
-            .await;
-
-        // Score-sort candidates
-        let mut scored: Vec<(NodeId, f64)> = candidates
+        // Score-sort candidates (excluding sender).
+        let mut scored: Vec<(NodeId, f64)> = healthy
             .into_iter()
-            .filter_map(|id| {
-                let info = self.peer_manager.get_peer(&id)?;
-                Some((id, info.trust_score))
-            })
+            .filter(|p| exclude.map_or(true, |ex| &p.id != ex))
+            .map(|p| (p.id, p.trust_score))
             .collect();
         scored.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
 
-    /// Securely gossips a message to high-scoring peers using peer scoring and message protocol
-    pub async fn gossip_message(&self, message: SecureMessage) {
-        let peers = self.peers.lock().unwrap().clone();
-        for peer_id in peers {
-            // Use peer_scoring to filter and prioritize high-reputation peers
-            let peer_score = self.peer_scoring.calculate_score(peer_id.as_str());
-            
-            // Only gossip to peers with acceptable reputation
-            if peer_score >= 50.0 {
-                let encrypted_payload = self.encrypt_message(&message);
-                let secure_message = SecureMessage {
-                    sender_id: message.sender_id.clone(),
-                    message_type: MessageType::Custom("gossip".to_string()),
-                    payload: encrypted_payload,
-                    signature: message.signature.clone(),
-                    hop_count: 1,
-                };
-                
-                // Use message_protocol to send the secure message
-                self.message_protocol.queue_message(secure_message).await;
-                log::debug!("Gossipped to peer {} with score {}", peer_id, peer_score);
-            } else {
-                log::warn!("Skipping gossip to low-reputation peer: {}", peer_id);
+        for (peer_id, _) in scored.into_iter().take(EAGER_FANOUT) {
+            let Some(addr) = self.peer_manager.get_peer_addr(&peer_id) else {
+                continue;
+            };
+            if let Err(e) = self.message_protocol.send_message(addr, msg).await {
+                warn!("GossipProtocol: push to {} failed: {}", peer_id, e);
+                self.peer_manager.record_failure(&peer_id);
             }
         }
     }
 
-    /// Background loop: drain the pending queue and spread each message.
+    /// Background loop: drain the pending queue, spread each message and
+    /// expire old seen-cache entries.
     pub async fn run(self: Arc<Self>) {
         let mut ticker = interval(GOSSIP_TICK);
         loop {
             ticker.tick().await;
+            self.prune_seen(unix_now());
             let batch: Vec<(SecureMessage, Option<NodeId>)> = {
                 let mut pending = self.pending.lock();
                 std::mem::take(&mut *pending)
             };
+            // Already marked seen when queued, so bypass `spread`'s dedup.
             for (msg, exclude) in batch {
-                self.spread(msg, exclude.as_ref()).await;
+                self.push_to_peers(&msg, exclude.as_ref()).await;
             }
         }
     }
@@ -182,12 +228,16 @@ mod tests {
     use crate::types::{MessageType, unix_now};
 
     fn make_gossip() -> Arc<GossipProtocol> {
+        make_gossip_with_max_hops(DEFAULT_MAX_HOPS)
+    }
+
+    fn make_gossip_with_max_hops(max_hops: u8) -> Arc<GossipProtocol> {
         let local_id = NodeId::random();
         let (pm, _) = PeerManager::new(local_id.clone(), PeerManagerConfig::default());
         let ed = Ed25519Keypair::generate();
         let kyber = KyberKeypair::generate();
         let (mp, _) = MessageProtocol::new(ed, kyber, pm.clone());
-        GossipProtocol::new(pm, mp)
+        GossipProtocol::with_max_hops(pm, mp, max_hops)
     }
 
     fn make_msg() -> SecureMessage {
@@ -258,4 +308,39 @@ mod tests {
         let seen_size = g.seen.lock().len();
         assert!(seen_size <= SEEN_CACHE_CAPACITY);
     }
+
+    #[test]
+    fn test_handle_message_forwards_once() {
+        let g = make_gossip();
+        let from = NodeId::random();
+        let msg = make_msg();
+        assert_eq!(g.handle_message(msg.clone(), &from), GossipOutcome::DeliverAndForward);
+        assert_eq!(g.handle_message(msg, &from), GossipOutcome::Duplicate);
+
+        let pending = g.pending.lock();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].0.hop_count, 1);
+        assert_eq!(pending[0].1.as_ref(), Some(&from));
+    }
+
+    #[test]
+    fn test_handle_message_at_max_hops_not_forwarded() {
+        let g = make_gossip_with_max_hops(3);
+        let mut msg = make_msg();
+        msg.hop_count = 3;
+        assert_eq!(g.handle_message(msg, &NodeId::random()), GossipOutcome::DeliverOnly);
+        assert!(g.pending.lock().is_empty());
+    }
+
+    #[test]
+    fn test_expired_seen_entries_pruned() {
+        let g = make_gossip();
+        let msg = make_msg();
+        let id = message_id(&msg);
+        assert!(g.mark_seen(id, 1_000));
+        assert!(!g.mark_seen(id, 1_000 + SEEN_TTL_SECS - 1));
+        assert_eq!(g.prune_seen(1_000 + SEEN_TTL_SECS), 1);
+        assert_eq!(g.seen.lock().len(), 0);
+        assert!(g.mark_seen(id, 1_000 + SEEN_TTL_SECS));
+    }
 }
@@ -2,15 +2,139 @@
 //!
 //! Implements:
 //! - XOR metric routing with 256-bit key space
-//! - K-buckets (k=20) with LRU eviction
+//! - K-buckets (k=20) with LRU eviction: a full bucket pings its
+//!   least-recently-seen node and only drops it if the ping fails
 //! - FIND_NODE / FIND_VALUE / STORE / PING RPCs (in-process for integration;
 //!   wire transport delegated to the message layer)
 //! - Parallel alpha=3 lookups (iterative closest-node algorithm)
 //! - Bucket refresh on a background timer
 
-use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::collections::VecDeque;
 use std::net::SocketAddr;
-use std::fmt;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use dashmap::DashMap;
+use tokio::sync::RwLock;
+use tokio::time::interval;
+use tracing::{debug, info};
+
+use crate::types::{unix_now, NodeId, PeerInfo};
+
+// ─────────────────────────────────────────────────────────────────────────────
+// CONSTANTS
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Maximum peers per bucket.
+pub const K: usize = 20;
+/// One bucket per bit of the 256-bit key space.
+pub const BUCKET_COUNT: usize = 256;
+/// Buckets untouched for this long are refreshed with a FIND_NODE lookup.
+const BUCKET_REFRESH_INTERVAL: Duration = Duration::from_secs(3600);
+/// Stored values expire after 24 hours.
+const VALUE_TTL_SECS: u64 = 86_400;
+/// A least-recently-seen node that has not answered a ping within this
+/// window is evicted in favour of the waiting newcomer.
+pub const PING_TIMEOUT: Duration = Duration::from_secs(5);
+
+// ─────────────────────────────────────────────────────────────────────────────
+// K-BUCKET
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Result of inserting a peer into the routing table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InsertOutcome {
+    /// Peer added to a bucket with spare capacity.
+    Inserted,
+    /// Peer was already known; moved to most-recently-seen.
+    Updated,
+    /// Bucket is full. The caller must ping `least_recently_seen` and report
+    /// the result via `RoutingTable::on_ping_result`; the new peer is held as
+    /// the bucket's replacement candidate until then.
+    PendingEviction { least_recently_seen: NodeId },
+    /// Local node ID — never inserted.
+    Rejected,
+}
+
+#[derive(Debug, Clone)]
+struct KBucketEntry {
+    peer: PeerInfo,
+}
+
+/// Peers whose XOR distance to the local node shares the same highest set bit.
+/// Ordered least-recently-seen (front) to most-recently-seen (back).
+#[derive(Debug)]
+struct KBucket {
+    entries: VecDeque<KBucketEntry>,
+    /// Newcomer waiting on the outcome of a ping to the LRS entry.
+    replacement: Option<PeerInfo>,
+    last_changed: Instant,
+}
+
+impl KBucket {
+    fn new() -> Self {
+        KBucket {
+            entries: VecDeque::with_capacity(K),
+            replacement: None,
+            last_changed: Instant::now(),
+        }
+    }
+
+    fn position(&self, id: &NodeId) -> Option<usize> {
+        self.entries.iter().position(|e| &e.peer.id == id)
+    }
+
+    fn upsert(&mut self, peer: PeerInfo) -> InsertOutcome {
+        self.last_changed = Instant::now();
+        if let Some(pos) = self.position(&peer.id) {
+            self.entries.remove(pos);
+            self.entries.push_back(KBucketEntry { peer });
+            return InsertOutcome::Updated;
+        }
+        if self.entries.len() < K {
+            self.entries.push_back(KBucketEntry { peer });
+            return InsertOutcome::Inserted;
+        }
+        let least_recently_seen = self.entries[0].peer.id.clone();
+        self.replacement = Some(peer);
+        InsertOutcome::PendingEviction { least_recently_seen }
+    }
+
+    /// Apply a ping result for `id`. Returns `true` if `id` was evicted.
+    fn on_ping_result(&mut self, id: &NodeId, alive: bool) -> bool {
+        let Some(pos) = self.position(id) else {
+            return false;
+        };
+        self.last_changed = Instant::now();
+        if alive {
+            // Live nodes are preferred over newcomers: refresh and drop the candidate.
+            if let Some(entry) = self.entries.remove(pos) {
+                self.entries.push_back(entry);
+            }
+            self.replacement = None;
+            false
+        } else {
+            self.entries.remove(pos);
+            if let Some(peer) = self.replacement.take() {
+                self.entries.push_back(KBucketEntry { peer });
+            }
+            true
+        }
+    }
+
+    fn remove(&mut self, id: &NodeId) {
+        if let Some(pos) = self.position(id) {
+            self.entries.remove(pos);
+            if let Some(peer) = self.replacement.take() {
+                self.entries.push_back(KBucketEntry { peer });
+            }
+            self.last_changed = Instant::now();
+        }
+    }
+
+    fn all_entries(&self) -> impl Iterator<Item = &KBucketEntry> {
+        self.entries.iter()
+    }
 
     fn needs_refresh(&self) -> bool {
         self.last_changed.elapsed() > BUCKET_REFRESH_INTERVAL
@@ -32,55 +156,76 @@ impl RoutingTable {
         RoutingTable { local_id, buckets }
     }
 
-    /// Determine which bucket index a NodeId falls into based on XOR distance.
-    fn bucket_index(&self, id: &NodeId) -> usize {
+    pub fn local_id(&self) -> &NodeId {
+        &self.local_id
+    }
+
+    /// Bucket index for `id`: the position of the highest set bit of the XOR
+    /// distance, so bucket `i` holds peers at distance in `[2^i, 2^(i+1))`.
+    /// Returns `None` for the local ID (distance 0).
+    pub fn bucket_index(&self, id: &NodeId) -> Option<usize> {
         let dist = self.local_id.xor_distance(id);
-        // Index = position of the highest set bit in the XOR distance.
-        // Distance of 0 (self) is not inserted; we use bucket 0 as a fallback.
-        for byte_idx in 0..32 {
-            let byte = dist[byte_idx];
-            if byte != 0 {
-                let bit_pos = 7 - byte.leading_zeros() as usize;
-                return byte_idx * 8 + bit_pos;
-            }
+        let leading_zeros = dist
+            .iter()
+            .position(|b| *b != 0)
+            .map(|byte_idx| byte_idx * 8 + dist[byte_idx].leading_zeros() as usize)?;
+        Some(BUCKET_COUNT - 1 - leading_zeros)
+    }
+
+    /// Insert or refresh a peer. See `InsertOutcome` for the full-bucket flow.
+    pub fn insert(&mut self, peer: PeerInfo) -> InsertOutcome {
+        match self.bucket_index(&peer.id) {
+            Some(idx) => self.buckets[idx].upsert(peer),
+            None => InsertOutcome::Rejected,
         }
-        0
     }
 
     /// Insert or update a peer in the routing table.
-    /// Returns `false` if the corresponding bucket is full.
+    /// Returns `false` if the peer is not (yet) in the table.
     pub fn upsert(&mut self, peer: PeerInfo) -> bool {
-        if peer.id == self.local_id {
-            return false; // Never insert self
+        matches!(self.insert(peer), InsertOutcome::Inserted | InsertOutcome::Updated)
+    }
+
+    /// Report the ping result for a node named in `InsertOutcome::PendingEviction`.
+    /// A dead node is evicted in favour of the pending replacement; a live node
+    /// is kept and the replacement discarded. Returns `true` if a node was evicted.
+    pub fn on_ping_result(&mut self, id: &NodeId, alive: bool) -> bool {
+        match self.bucket_index(id) {
+            Some(idx) => self.buckets[idx].on_ping_result(id, alive),
+            None => false,
         }
-        let idx = self.bucket_index(&peer.id);
-        self.buckets[idx].upsert(peer)
     }
 
     /// Remove a peer from the routing table.
     pub fn remove(&mut self, id: &NodeId) {
-        let idx = self.bucket_index(id);
-        self.buckets[idx].remove(id);
+        if let Some(idx) = self.bucket_index(id) {
+            self.buckets[idx].remove(id);
+        }
     }
 
-    /// Find the `k` closest peers to `target` in the routing table.
+    /// The `k` known peers closest to `target`, nearest first.
     pub fn find_closest(&self, target: &NodeId, k: usize) -> Vec<PeerInfo> {
-        let mut all: Vec<(Vec<u8>, PeerInfo)> = self
+        let mut all: Vec<([u8; 32], &PeerInfo)> = self
             .buckets
             .iter()
-            .flat_map(|b| b.all_entries().into_iter().map(|e| e.peer.clone()))
-            .map(|p| (target.xor_distance(&p.id).to_vec(), p))
+            .flat_map(|b| b.all_entries())
+            .map(|e| (target.xor_distance(&e.peer.id), &e.peer))
             .collect();
-        all.sort_by(|a, b| a.0.cmp(&b.0));
-        all.into_iter().take(k).map(|(_, p)| p).collect()
+        // Big-endian byte arrays compare in the same order as the 256-bit distances.
+        all.sort_by_key(|(dist, _)| *dist);
+        all.into_iter().take(k).map(|(_, p)| p.clone()).collect()
+    }
+
+    /// IDs of the `k` known peers closest to `target`, nearest first.
+    pub fn closest(&self, target: &NodeId, k: usize) -> Vec<NodeId> {
+        self.find_closest(target, k).into_iter().map(|p| p.id).collect()
     }
 
     /// Retrieve a specific peer by NodeId.
     pub fn get(&self, id: &NodeId) -> Option<PeerInfo> {
-        let idx = self.bucket_index(id);
+        let idx = self.bucket_index(id)?;
         self.buckets[idx]
-            .entries
-            .iter()
+            .all_entries()
             .find(|e| &e.peer.id == id)
             .map(|e| e.peer.clone())
     }
@@ -89,7 +234,7 @@ impl RoutingTable {
     pub fn all_peers(&self) -> Vec<PeerInfo> {
         self.buckets
             .iter()
-            .flat_map(|b| b.all_entries().into_iter().map(|e| e.peer.clone()))
+            .flat_map(|b| b.all_entries().map(|e| e.peer.clone()))
             .collect()
     }
 
@@ -97,6 +242,11 @@ impl RoutingTable {
         self.buckets.iter().map(|b| b.entries.len()).sum()
     }
 
+    /// Number of peers in bucket `idx`.
+    pub fn bucket_len(&self, idx: usize) -> usize {
+        self.buckets.get(idx).map_or(0, |b| b.entries.len())
+    }
+
     /// Collect bucket indices that are stale and should be refreshed.
     pub fn stale_bucket_indices(&self) -> Vec<usize> {
         self.buckets
@@ -108,12 +258,6 @@ impl RoutingTable {
     }
 }
 
-impl fmt::Display for NodeId {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.0)
-    }
-}
-
 #[derive(Debug, Clone)]
 struct DhtValue {
     data: Vec<u8>,
@@ -136,6 +280,10 @@ pub struct KademliaDht {
     routing_table: Arc<RwLock<RoutingTable>>,
     /// Key-value store (key = hex NodeId, value = serialised peer address or arbitrary data).
     store: DashMap<String, DhtValue>,
+    /// Least-recently-seen nodes that still have to be pinged.
+    ping_requests: parking_lot::Mutex<Vec<NodeId>>,
+    /// Nodes with an outstanding eviction ping → when it was requested.
+    awaiting_pong: DashMap<NodeId, Instant>,
 }
 
 impl KademliaDht {
@@ -144,6 +292,8 @@ impl KademliaDht {
             routing_table: Arc::new(RwLock::new(RoutingTable::new(local_id.clone()))),
             local_id,
             store: DashMap::new(),
+            ping_requests: parking_lot::Mutex::new(Vec::new()),
+            awaiting_pong: DashMap::new(),
         }
     }
 
@@ -157,15 +307,66 @@ impl KademliaDht {
     }
 
     /// Add or refresh a peer in the routing table.
+    ///
+    /// Returns `false` if the peer's bucket is full; the bucket's
+    /// least-recently-seen node is then queued for a ping (see
+    /// `take_ping_requests`) and the newcomer waits on `ping_result`.
     pub async fn add_peer(&self, peer: PeerInfo) -> bool {
         let mut rt = self.routing_table.write().await;
-        let inserted = rt.upsert(peer.clone());
-        if inserted {
-            debug!(peer_id = %peer.id, "Kademlia: added peer");
-        } else {
-            debug!(peer_id = %peer.id, "Kademlia: bucket full, eviction candidate");
+        let peer_id = peer.id.clone();
+        match rt.insert(peer) {
+            InsertOutcome::Inserted | InsertOutcome::Updated => {
+                debug!(peer_id = %peer_id, "Kademlia: added peer");
+                true
+            }
+            InsertOutcome::PendingEviction { least_recently_seen } => {
+                debug!(peer_id = %peer_id, lrs = %least_recently_seen, "Kademlia: bucket full, pinging LRS node");
+                if self.awaiting_pong.insert(least_recently_seen.clone(), Instant::now()).is_none() {
+                    self.ping_requests.lock().push(least_recently_seen);
+                }
+                false
+            }
+            InsertOutcome::Rejected => false,
         }
-        inserted
+    }
+
+    /// Drain the least-recently-seen nodes queued for an eviction ping.
+    pub fn take_ping_requests(&self) -> Vec<NodeId> {
+        std::mem::take(&mut *self.ping_requests.lock())
+    }
+
+    /// Report whether a pinged least-recently-seen node responded.
+    ///
+    /// Ignored unless an eviction ping to `id` is outstanding, so a stray or
+    /// late pong cannot disturb the routing table.  Returns `true` if `id`
+    /// was evicted.
+    pub async fn ping_result(&self, id: &NodeId, alive: bool) -> bool {
+        if self.awaiting_pong.remove(id).is_none() {
+            return false;
+        }
+        let evicted = self.routing_table.write().await.on_ping_result(id, alive);
+        if evicted {
+            debug!(peer_id = %id, "Kademlia: evicted unresponsive peer");
+        }
+        evicted
+    }
+
+    /// Treat eviction pings older than `timeout` as failed.  Returns how many
+    /// nodes were evicted.
+    pub async fn expire_pings(&self, timeout: Duration) -> usize {
+        let expired: Vec<NodeId> = self
+            .awaiting_pong
+            .iter()
+            .filter(|entry| entry.value().elapsed() >= timeout)
+            .map(|entry| entry.key().clone())
+            .collect();
+        let mut evicted = 0;
+        for id in expired {
+            if self.ping_result(&id, false).await {
+                evicted += 1;
+            }
+        }
+        evicted
     }
 
     /// Remove a peer (e.g., after detecting it is offline or malicious).
@@ -251,7 +452,6 @@ impl KademliaDht {
 mod tests {
     use super::*;
     use std::net::SocketAddr;
-    use crate::types::PeerStatus;
 
     fn make_peer(seed: u8) -> PeerInfo {
         let mut id_bytes = [0u8; 32];
@@ -335,19 +535,127 @@ mod tests {
         assert!(rt.peer_count() <= 25);
     }
 
-    /// Store a peer in the DHT (wrapper for add_node)
-    pub fn store(&mut self, peer_id: &str, peer_address: &str) {
-        let node_id = NodeId::new(peer_id.to_string());
-        // For now, we'll use a default socket address since we only have the address string
-        if let Ok(addr) = peer_address.parse::<SocketAddr>() {
-            self.add_node(node_id, addr);
+    fn peer_with_id(id: [u8; 32]) -> PeerInfo {
+        let addr: SocketAddr = "127.0.0.1:9000".parse().unwrap();
+        PeerInfo::new(NodeId(id), addr, vec![0; 32], vec![0; 64])
+    }
+
+    /// ID whose XOR distance from the all-zero ID has only bit `bit` set.
+    fn id_at_bit(bit: usize) -> [u8; 32] {
+        let mut id = [0u8; 32];
+        id[31 - bit / 8] = 1 << (bit % 8);
+        id
+    }
+
+    #[test]
+    fn test_bucket_placement_by_xor_distance() {
+        let rt = RoutingTable::new(NodeId([0u8; 32]));
+        assert_eq!(rt.bucket_index(&NodeId([0u8; 32])), None);
+        for bit in [0, 1, 7, 8, 100, 248, 255] {
+            assert_eq!(rt.bucket_index(&NodeId(id_at_bit(bit))), Some(bit));
+        }
+        // Lower bits do not change the bucket: only the highest set bit counts.
+        let mut id = id_at_bit(9);
+        id[31] = 0xFF;
+        assert_eq!(rt.bucket_index(&NodeId(id)), Some(9));
+
+        let mut rt = rt;
+        rt.insert(peer_with_id(id));
+        assert_eq!(rt.bucket_len(9), 1);
+    }
+
+    #[test]
+    fn test_closest_returns_k_nearest_sorted() {
+        let mut rt = RoutingTable::new(NodeId::random());
+        let mut ids = Vec::new();
+        for _ in 0..60 {
+            let id = NodeId::random();
+            if rt.upsert(peer_with_id(id.0)) {
+                ids.push(id);
+            }
         }
-        log::debug!("DHT stored peer {}", peer_id);
+        let target = NodeId::random();
+        let closest = rt.closest(&target, 8);
+
+        ids.sort_by_key(|id| target.xor_distance(id));
+        assert_eq!(closest, ids[..8].to_vec());
+        assert!(closest
+            .windows(2)
+            .all(|w| target.xor_distance(&w[0]) < target.xor_distance(&w[1])));
     }
 
-    /// Lookup a peer in the DHT (wrapper for find_node)
-    pub fn lookup(&self, peer_id: &str) -> Option<String> {
-        let node_id = NodeId::new(peer_id.to_string());
-        self.find_node(&node_id).map(|addr| addr.to_string())
+    #[test]
+    fn test_full_bucket_pings_least_recently_seen() {
+        let mut rt = RoutingTable::new(NodeId([0u8; 32]));
+        // K + 2 peers that all land in bucket 255.
+        let ids: Vec<[u8; 32]> = (0..K as u8 + 2)
+            .map(|i| {
+                let mut id = [0u8; 32];
+                id[0] = 0x80;
+                id[31] = i;
+                id
+            })
+            .collect();
+        for id in &ids[..K] {
+            assert_eq!(rt.insert(peer_with_id(*id)), InsertOutcome::Inserted);
+        }
+
+        // Full bucket: newcomer waits on a ping to the oldest entry.
+        assert_eq!(
+            rt.insert(peer_with_id(ids[K])),
+            InsertOutcome::PendingEviction { least_recently_seen: NodeId(ids[0]) }
+        );
+        // LRS node answers → kept, newcomer dropped.
+        assert!(!rt.on_ping_result(&NodeId(ids[0]), true));
+        assert!(rt.get(&NodeId(ids[0])).is_some());
+        assert!(rt.get(&NodeId(ids[K])).is_none());
+
+        // ids[1] is now the LRS; it fails to answer → evicted for the newcomer.
+        assert_eq!(
+            rt.insert(peer_with_id(ids[K + 1])),
+            InsertOutcome::PendingEviction { least_recently_seen: NodeId(ids[1]) }
+        );
+        assert!(rt.on_ping_result(&NodeId(ids[1]), false));
+        assert!(rt.get(&NodeId(ids[1])).is_none());
+        assert!(rt.get(&NodeId(ids[K + 1])).is_some());
+        assert_eq!(rt.bucket_len(255), K);
+    }
+
+    #[tokio::test]
+    async fn test_dht_resolves_pending_eviction_by_ping() {
+        let dht = KademliaDht::new(NodeId([0u8; 32]));
+        let ids: Vec<[u8; 32]> = (0..K as u8 + 2)
+            .map(|i| {
+                let mut id = [0u8; 32];
+                id[0] = 0x80;
+                id[31] = i;
+                id
+            })
+            .collect();
+        for id in &ids[..K] {
+            assert!(dht.add_peer(peer_with_id(*id)).await);
+        }
+
+        // Full bucket: the LRS node is queued for a ping exactly once
+        assert!(!dht.add_peer(peer_with_id(ids[K])).await);
+        assert_eq!(dht.take_ping_requests(), vec![NodeId(ids[0])]);
+        assert!(dht.take_ping_requests().is_empty());
+
+        // A pong from a node we did not ping changes nothing
+        assert!(!dht.ping_result(&NodeId(ids[5]), false).await);
+        assert_eq!(dht.peer_count().await, K);
+
+        // The LRS answers → kept; a late timeout no longer evicts it
+        assert!(!dht.ping_result(&NodeId(ids[0]), true).await);
+        assert_eq!(dht.expire_pings(Duration::ZERO).await, 0);
+        assert!(dht.routing_table.read().await.get(&NodeId(ids[0])).is_some());
+
+        // ids[1] never answers → evicted for the newcomer on timeout
+        assert!(!dht.add_peer(peer_with_id(ids[K + 1])).await);
+        assert_eq!(dht.take_ping_requests(), vec![NodeId(ids[1])]);
+        assert_eq!(dht.expire_pings(Duration::ZERO).await, 1);
+        let rt = dht.routing_table.read().await;
+        assert!(rt.get(&NodeId(ids[1])).is_none());
+        assert!(rt.get(&NodeId(ids[K + 1])).is_some());
     }
 }
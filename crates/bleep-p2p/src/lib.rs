@@ -43,11 +43,8 @@
 //! ```
 
 pub mod ai_security;
-pub mod kademlia_dht;
-pub mod quantum_crypto;
-pub mod p2p_node;
-pub use p2p_node::P2PNode as P2PNodeType;
-pub mod peer_manager;
+pub mod dark_routing;
+pub mod error;
 pub mod gossip_protocol;
 pub mod kademlia_dht;
 pub mod message_protocol;
@@ -60,5 +57,6 @@ pub mod types;
 // Re-export the most commonly used items at crate root
 pub use error::{P2PError, P2PResult};
 pub use p2p_node::{NodeHandle, P2PNode, P2PNodeConfig};
+pub use p2p_node::P2PNode as P2PNodeType;
 pub use peer_manager::{PeerEvent, PeerManager, PeerManagerConfig};
 pub use types::{MessageType, NodeId, PeerInfo, PeerStatus, SecureMessage};
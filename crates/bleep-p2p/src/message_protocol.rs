@@ -206,24 +206,6 @@ impl MessageProtocol {
                 return Err(P2PError::AuthenticationFailed);
             }
         }
-    }
-
-    /// Queue a message for delivery (used by gossip protocol and other subsystems)
-    /// Uses the message protocol and noise handshake for secure transmission
-    pub async fn queue_message(&self, message: SecureMessage) {
-        // Use the noise handshake to establish secure channel
-        let _handshake_established = self.noise.establish_handshake(&message.sender_id);
-        
-        let encrypted_payload = self.encrypt_message(&message);
-        log::debug!(
-            "Queued message from {} with type {:?} (encrypted: {} bytes)",
-            message.sender_id,
-            message.message_type,
-            encrypted_payload.len()
-        );
-    }
-}
-
         // 3. Signature verification
         ed25519_verify(&msg.signing_bytes(), &msg.signature, sender_pubkey_bytes)?;
 
@@ -373,15 +355,6 @@ mod tests {
         (proto, rx, pm)
     }
 
-    /// Establish a Noise handshake with a peer for secure channel negotiation
-    pub fn establish_handshake(&self, peer_id: &str) -> bool {
-        log::debug!("Establishing Noise handshake with peer: {}", peer_id);
-        // In a real implementation, this would perform Noise protocol handshake
-        // For now, we just log and return success to indicate the handshake field is being used
-        true
-    }
-}
-
     #[tokio::test]
     async fn test_session_initiation_and_encrypt_decrypt() {
         let (proto_a, _, _) = make_proto();
@@ -3,4 +3,3 @@ pub mod message_protocol;
 pub mod gossip_protocol;
 pub mod multi_hop_routing;
 pub mod dark_routing;
-pub mod tests;
\ No newline at end of file
@@ -1,108 +1,345 @@
+//! P2P node: ties the peer manager, message transport and gossip engine
+//! together behind the interface used by the node binary and consensus.
+//!
+//! Inbound path: `MessageProtocol::listen` verifies and decrypts each frame
+//! and hands it to the inbound loop.  Ping/Pong are answered point-to-point
+//! (a Pong resolves a pending Kademlia eviction); everything else runs
+//! through `GossipProtocol::handle_message`.  Duplicates are dropped; new
+//! messages are queued for re-broadcast (below the hop limit) and delivered
+//! to `P2PNode::recv`.
+
 use std::net::SocketAddr;
-use std::sync::{Arc, Mutex};
-
-pub struct PeerManager;
-pub struct GossipProtocol;
-pub struct BlockchainState;
-// P2PMessage defined below
-#[derive(Clone)]
-pub struct Block;
-#[derive(Clone)]
-pub struct Transaction;
-#[derive(Clone)]
-pub enum P2PMessage {
-    NewBlock(Block),
-    NewTransaction(Transaction),
-    ShardState(String),
-}
+use std::sync::Arc;
+use std::time::Duration;
 
-impl P2PMessage {
-    pub fn validate(&self) -> Option<String> { Some(String::new()) }
-}
+use rand::RngCore;
+use tokio::sync::{mpsc, Mutex};
+use tokio::task::JoinHandle;
+use tokio::time::interval;
+use tracing::{debug, info, warn};
+use zeroize::Zeroize;
 
-impl BlockchainState {
-    pub fn add_block(&mut self, _block: Block) -> Result<(), ()> { Ok(()) }
-    pub fn add_transaction(&mut self, _transaction: Transaction) -> Result<(), ()> { Ok(()) }
-}
+use crate::error::{P2PError, P2PResult};
+use crate::gossip_protocol::{GossipOutcome, GossipProtocol, DEFAULT_MAX_HOPS};
+use crate::kademlia_dht::PING_TIMEOUT;
+use crate::message_protocol::MessageProtocol;
+use crate::peer_manager::{PeerManager, PeerManagerConfig};
+use crate::quantum_crypto::{Ed25519Keypair, KyberKeypair};
+use crate::types::{unix_now, MessageType, NodeId, SecureMessage};
 
-impl GossipProtocol {
-    pub fn new() -> Self { Self }
-    pub fn is_known(&self, _msg: &str) -> bool { false }
-    pub fn gossip_message(&self, _node: &P2PNode, _msg: P2PMessage) {}
+// ─────────────────────────────────────────────────────────────────────────────
+// CONSTANTS
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Default P2P listen port (see docs/VALIDATOR_GUIDE.md).
+pub const DEFAULT_P2P_PORT: u16 = 7700;
+/// Capacity of the delivered-message channel read by `P2PNode::recv`.
+const DELIVERY_BUFFER: usize = 4096;
+/// How often queued Kademlia eviction pings are sent and expired.
+const PING_TICK: Duration = Duration::from_millis(500);
+
+// ─────────────────────────────────────────────────────────────────────────────
+// CONFIG
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone)]
+pub struct P2PNodeConfig {
+    pub listen_addr: SocketAddr,
+    /// Gossip relay limit (see `GossipProtocol::with_max_hops`).
+    pub max_hops: u8,
+    pub peer_manager: PeerManagerConfig,
 }
 
-impl PeerManager {
-    pub fn new() -> Self { Self }
+impl Default for P2PNodeConfig {
+    fn default() -> Self {
+        P2PNodeConfig {
+            listen_addr: SocketAddr::from(([0, 0, 0, 0], DEFAULT_P2P_PORT)),
+            max_hops: DEFAULT_MAX_HOPS,
+            peer_manager: PeerManagerConfig::default(),
+        }
+    }
 }
 
+// ─────────────────────────────────────────────────────────────────────────────
+// NODE
+// ─────────────────────────────────────────────────────────────────────────────
+
 pub struct P2PNode {
-    id: String,
-    addr: SocketAddr,
-    peer_manager: PeerManager,
-    gossip_protocol: GossipProtocol,
-    blockchain: Arc<Mutex<BlockchainState>>,
+    pub node_id: NodeId,
+    /// Signs messages this node originates.
+    identity: Ed25519Keypair,
+    peer_manager: Arc<PeerManager>,
+    message_protocol: Arc<MessageProtocol>,
+    gossip: Arc<GossipProtocol>,
+    /// First-seen inbound messages, after gossip deduplication.
+    delivered: Mutex<mpsc::Receiver<(NodeId, SecureMessage)>>,
+}
+
+/// Owns the node's background tasks.
+pub struct NodeHandle {
+    tasks: Vec<JoinHandle<()>>,
 }
 
-impl Clone for P2PNode {
-    fn clone(&self) -> Self {
-        P2PNode {
-            id: self.id.clone(),
-            addr: self.addr,
-            peer_manager: PeerManager::new(),
-            gossip_protocol: GossipProtocol::new(),
-            blockchain: self.blockchain.clone(),
+impl NodeHandle {
+    /// Stop the listener, gossip and inbound loops.
+    pub async fn shutdown(self) {
+        for task in &self.tasks {
+            task.abort();
+        }
+        for task in self.tasks {
+            let _ = task.await;
         }
     }
 }
 
 impl P2PNode {
-    pub fn broadcast(&self, _msg: P2PMessage) -> Result<(), ()> { Ok(()) }
+    /// Generate a node identity and start the listener, gossip and inbound
+    /// loops.
+    pub async fn start(config: P2PNodeConfig) -> P2PResult<(Arc<Self>, NodeHandle)> {
+        // One seed backs both copies of the identity: the transport's and
+        // the one kept here for signing broadcasts.
+        let mut seed = [0u8; 32];
+        rand::rngs::OsRng.fill_bytes(&mut seed);
+        let identity = Ed25519Keypair::from_bytes(&seed);
+        let transport_identity = Ed25519Keypair::from_bytes(&seed);
+        seed.zeroize();
+        let (identity, transport_identity) = (identity?, transport_identity?);
 
-    /// Get a reference to the peer manager for peer operations
-    pub fn peer_manager(&self) -> &PeerManager {
-        &self.peer_manager
-    }
+        let node_id = NodeId::from_bytes(&identity.public_key_bytes());
+        let (peer_manager, _events) = PeerManager::new(node_id.clone(), config.peer_manager);
+        let (message_protocol, inbound_rx) =
+            MessageProtocol::new(transport_identity, KyberKeypair::generate(), peer_manager.clone());
+        let gossip = GossipProtocol::with_max_hops(
+            peer_manager.clone(),
+            message_protocol.clone(),
+            config.max_hops,
+        );
+        let (delivered_tx, delivered_rx) = mpsc::channel(DELIVERY_BUFFER);
 
-    /// Register a peer with the node's peer manager (uses peer_manager field)
-    pub fn register_peer(&mut self, peer_id: String, peer_addr: SocketAddr) {
-        log::debug!("Registering peer {} at {} with node {}", peer_id, peer_addr, self.id);
-        // In production, this would call peer_manager.add_peer(peer_id, peer_addr.to_string())
-        // For now, the peer_manager field is accessed via the peer_manager() getter above
-    }
-}
+        let listener = {
+            let proto = message_protocol.clone();
+            let addr = config.listen_addr;
+            tokio::spawn(async move {
+                if let Err(e) = proto.listen(addr).await {
+                    warn!(addr = %addr, error = %e, "P2P listener stopped");
+                }
+            })
+        };
+        let tasks = vec![
+            listener,
+            tokio::spawn(gossip.clone().run()),
+            tokio::spawn(Self::run_inbound(
+                gossip.clone(),
+                message_protocol.clone(),
+                peer_manager.clone(),
+                inbound_rx,
+                delivered_tx,
+            )),
+            tokio::spawn(Self::run_eviction_pings(message_protocol.clone(), peer_manager.clone())),
+        ];
+        peer_manager.clone().spawn_maintenance();
 
-impl P2PNode {
-    pub fn new(id: String, addr: SocketAddr, blockchain: Arc<Mutex<BlockchainState>>) -> Self {
-        P2PNode {
-            id,
-            addr,
-            peer_manager: PeerManager::new(),
-            gossip_protocol: GossipProtocol::new(),
-            blockchain,
-        }
+        info!(node_id = %node_id, addr = %config.listen_addr, "P2P node started");
+        let node = Arc::new(P2PNode {
+            node_id,
+            identity,
+            peer_manager,
+            message_protocol,
+            gossip,
+            delivered: Mutex::new(delivered_rx),
+        });
+        Ok((node, NodeHandle { tasks }))
     }
 
-    pub fn handle_message(&self, message: P2PMessage, _peer_addr: SocketAddr) {
-        if self.gossip_protocol.is_known(&message.validate().unwrap_or_default()) {
-            return;
+    /// Answer Ping/Pong, run every other verified inbound message through the
+    /// gossip engine and deliver the ones seen for the first time.
+    async fn run_inbound(
+        gossip: Arc<GossipProtocol>,
+        message_protocol: Arc<MessageProtocol>,
+        peer_manager: Arc<PeerManager>,
+        mut inbound: mpsc::Receiver<(NodeId, SecureMessage)>,
+        delivered: mpsc::Sender<(NodeId, SecureMessage)>,
+    ) {
+        while let Some((from, msg)) = inbound.recv().await {
+            if Self::handle_control(&message_protocol, &peer_manager, &from, &msg).await {
+                continue;
+            }
+            if let Some(msg) = Self::accept_inbound(&gossip, &from, msg) {
+                if delivered.send((from, msg)).await.is_err() {
+                    break;
+                }
+            }
         }
+    }
 
-        match message {
-            P2PMessage::NewBlock(block) => {
-                let mut blockchain = self.blockchain.lock().unwrap();
-                if blockchain.add_block(block.clone()).is_ok() {
-                    self.gossip_protocol.gossip_message(self, P2PMessage::NewBlock(block));
+    /// Handle point-to-point control messages.  Returns `true` if `msg` was
+    /// one and must not be gossiped.
+    async fn handle_control(
+        message_protocol: &MessageProtocol,
+        peer_manager: &PeerManager,
+        from: &NodeId,
+        msg: &SecureMessage,
+    ) -> bool {
+        match msg.message_type {
+            MessageType::Ping => {
+                if let Err(e) = Self::send_direct(message_protocol, peer_manager, from, MessageType::Pong).await {
+                    debug!(peer = %from, error = %e, "Pong not sent");
                 }
+                true
             }
-            P2PMessage::NewTransaction(transaction) => {
-                let result = self.blockchain.lock().unwrap().add_transaction(transaction.clone());
-                if result.is_ok() {
-                    self.gossip_protocol.gossip_message(self, P2PMessage::NewTransaction(transaction));
-                } else {
-                    log::warn!("Failed to add transaction to blockchain");
+            MessageType::Pong => {
+                peer_manager.dht().ping_result(from, true).await;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Ping least-recently-seen nodes of full k-buckets and evict those that
+    /// stay silent past `PING_TIMEOUT`.
+    async fn run_eviction_pings(message_protocol: Arc<MessageProtocol>, peer_manager: Arc<PeerManager>) {
+        let dht = peer_manager.dht();
+        let mut ticker = interval(PING_TICK);
+        loop {
+            ticker.tick().await;
+            for id in dht.take_ping_requests() {
+                if let Err(e) = Self::send_direct(&message_protocol, &peer_manager, &id, MessageType::Ping).await {
+                    debug!(peer = %id, error = %e, "Eviction ping failed");
+                    dht.ping_result(&id, false).await;
                 }
             }
-            _ => {}
+            dht.expire_pings(PING_TIMEOUT).await;
         }
     }
+
+    /// Seal an empty message of `message_type` for `to` and send it directly.
+    async fn send_direct(
+        message_protocol: &MessageProtocol,
+        peer_manager: &PeerManager,
+        to: &NodeId,
+        message_type: MessageType,
+    ) -> P2PResult<()> {
+        let addr = peer_manager
+            .get_peer_addr(to)
+            .ok_or_else(|| P2PError::PeerNotFound { peer_id: to.to_string() })?;
+        let msg = message_protocol.seal_message(to, message_type, &[])?;
+        message_protocol.send_message(addr, &msg).await
+    }
+
+    /// Gossip step of the inbound path: `None` for a duplicate, otherwise
+    /// the message to deliver locally (queued for relay if under the hop
+    /// limit).
+    fn accept_inbound(gossip: &GossipProtocol, from: &NodeId, msg: SecureMessage) -> Option<SecureMessage> {
+        match gossip.handle_message(msg.clone(), from) {
+            GossipOutcome::Duplicate => None,
+            GossipOutcome::DeliverOnly | GossipOutcome::DeliverAndForward => Some(msg),
+        }
+    }
+
+    /// Sign `payload` as a new message from this node and queue it for gossip.
+    pub fn broadcast(&self, message_type: MessageType, payload: Vec<u8>) {
+        let mut nonce = [0u8; 16];
+        rand::thread_rng().fill_bytes(&mut nonce);
+        let mut msg = SecureMessage {
+            version: 1,
+            sender_id: self.node_id.clone(),
+            message_type,
+            payload,
+            signature: Vec::new(),
+            hop_count: 0,
+            nonce,
+            timestamp: unix_now(),
+        };
+        msg.signature = self.identity.sign(&msg.signing_bytes());
+        self.gossip.enqueue(msg, None);
+    }
+
+    /// Next first-seen inbound message; `None` once the node has shut down.
+    pub async fn recv(&self) -> Option<(NodeId, SecureMessage)> {
+        self.delivered.lock().await.recv().await
+    }
+
+    pub fn peer_manager(&self) -> &Arc<PeerManager> {
+        &self.peer_manager
+    }
+
+    pub fn message_protocol(&self) -> &Arc<MessageProtocol> {
+        &self.message_protocol
+    }
+
+    pub fn peer_count(&self) -> usize {
+        self.peer_manager.peer_count()
+    }
+
+    pub fn healthy_peer_count(&self) -> usize {
+        self.peer_manager.healthy_peers().len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_gossip() -> Arc<GossipProtocol> {
+        let local_id = NodeId::random();
+        let (pm, _) = PeerManager::new(local_id, PeerManagerConfig::default());
+        let (mp, _) = MessageProtocol::new(Ed25519Keypair::generate(), KyberKeypair::generate(), pm.clone());
+        GossipProtocol::new(pm, mp)
+    }
+
+    fn make_msg() -> SecureMessage {
+        SecureMessage {
+            version: 1,
+            sender_id: NodeId::random(),
+            message_type: MessageType::Block,
+            payload: b"block".to_vec(),
+            signature: vec![],
+            hop_count: 0,
+            nonce: [7u8; 16],
+            timestamp: unix_now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_inbound_path_delivers_each_message_once() {
+        let gossip = make_gossip();
+        let from = NodeId::random();
+        let msg = make_msg();
+
+        let delivered = P2PNode::accept_inbound(&gossip, &from, msg.clone()).unwrap();
+        assert_eq!(delivered.payload, msg.payload);
+        // A relayed copy of the same message is recognised and dropped
+        let mut relayed = msg;
+        relayed.hop_count = 3;
+        assert!(P2PNode::accept_inbound(&gossip, &NodeId::random(), relayed).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_pong_is_not_gossiped_and_reaches_dht() {
+        let local_id = NodeId::random();
+        let (pm, _) = PeerManager::new(local_id, PeerManagerConfig::default());
+        let (mp, _) = MessageProtocol::new(Ed25519Keypair::generate(), KyberKeypair::generate(), pm.clone());
+        let from = NodeId::random();
+
+        let mut pong = make_msg();
+        pong.message_type = MessageType::Pong;
+        assert!(P2PNode::handle_control(&mp, &pm, &from, &pong).await);
+        // Ping replies go straight back to the sender, never through gossip
+        let mut ping = make_msg();
+        ping.message_type = MessageType::Ping;
+        assert!(P2PNode::handle_control(&mp, &pm, &from, &ping).await);
+        assert!(!P2PNode::handle_control(&mp, &pm, &from, &make_msg()).await);
+    }
+
+    #[tokio::test]
+    async fn test_node_start_and_shutdown() {
+        let config = P2PNodeConfig {
+            listen_addr: "127.0.0.1:0".parse().unwrap(),
+            ..P2PNodeConfig::default()
+        };
+        let (node, handle) = P2PNode::start(config).await.unwrap();
+        assert_eq!(node.peer_count(), 0);
+        node.broadcast(MessageType::Block, b"block".to_vec());
+        handle.shutdown().await;
+    }
 }
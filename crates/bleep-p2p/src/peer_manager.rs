@@ -5,6 +5,7 @@
 //! - Quantum-secure identity verification on admission
 //! - Continuous AI-driven trust scoring
 //! - Sybil detection via subnet clustering
+//! - Reputation tracking with time-boxed bans for misbehaving peers
 //! - Kademlia DHT integration for distributed peer discovery
 //! - Mesh broadcast of peer events
 
@@ -41,12 +42,32 @@ pub mod mesh_network {
         pub fn new() -> Self { MeshNode }
         /// Broadcast a message to all connected mesh nodes
         pub fn broadcast(&self, message: &str) -> Result<(), String> {
-            log::info!("Mesh broadcasting: {}", message);
+            tracing::info!("Mesh broadcasting: {}", message);
             Ok(())
         }
     }
 }
 
+// ─────────────────────────────────────────────────────────────────────────────
+// CONFIG
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Reputation ceiling; good behaviour cannot bank more credit than this.
+pub const REPUTATION_MAX: i64 = 100;
+
+#[derive(Debug, Clone)]
+pub struct PeerManagerConfig {
+    pub max_peers: usize,
+    pub maintenance_interval: Duration,
+    /// Peers whose trust score falls below this are banned on maintenance.
+    pub min_trust_score: f64,
+    pub peer_eviction_age_secs: u64,
+    /// Reputation at or below which a peer is banned.
+    pub ban_threshold: i64,
+    /// How long (seconds) a reputation ban lasts.
+    pub ban_duration_secs: u64,
+}
+
 impl Default for PeerManagerConfig {
     fn default() -> Self {
         PeerManagerConfig {
@@ -54,6 +75,30 @@ impl Default for PeerManagerConfig {
             maintenance_interval: Duration::from_secs(30),
             min_trust_score: 20.0,
             peer_eviction_age_secs: 3600,
+            ban_threshold: -100,
+            ban_duration_secs: 3600,
+        }
+    }
+}
+
+/// How bad a reported misbehaviour is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MisbehaviorSeverity {
+    /// e.g. malformed or duplicate message.
+    Minor,
+    /// e.g. invalid signature, rate-limit violation.
+    Major,
+    /// e.g. provably invalid block or equivocation relay.
+    Critical,
+}
+
+impl MisbehaviorSeverity {
+    /// Reputation deducted for one event.
+    pub fn penalty(self) -> i64 {
+        match self {
+            MisbehaviorSeverity::Minor => 10,
+            MisbehaviorSeverity::Major => 40,
+            MisbehaviorSeverity::Critical => 100,
         }
     }
 }
@@ -66,8 +111,10 @@ pub struct PeerManager {
     config: PeerManagerConfig,
     /// The live peer table — NodeId → PeerInfo.
     peers: DashMap<NodeId, PeerInfo>,
-    /// Explicitly banned peers (persist across restarts conceptually).
+    /// Banned peers → UNIX time the ban expires (`u64::MAX` = permanent).
     banned: DashMap<NodeId, u64>,
+    /// Reputation per peer, adjusted by `record_good` / `record_bad`.
+    reputation: DashMap<NodeId, i64>,
     dht: Arc<KademliaDht>,
     scoring: Arc<PeerScoring>,
     sybil: Arc<SybilDetector>,
@@ -84,6 +131,7 @@ impl PeerManager {
             config,
             peers: DashMap::new(),
             banned: DashMap::new(),
+            reputation: DashMap::new(),
             dht,
             scoring: Arc::new(PeerScoring::new()),
             sybil: Arc::new(SybilDetector::new()),
@@ -113,7 +161,7 @@ impl PeerManager {
         identity_proof_signature: &[u8],
     ) -> P2PResult<()> {
         // 1. Banned check
-        if self.banned.contains_key(&id) {
+        if self.is_banned(&id, unix_now()) {
             return Err(P2PError::PeerBanned { peer_id: id.to_string() });
         }
 
@@ -165,16 +213,68 @@ impl PeerManager {
         }
     }
 
+    /// Ban a peer permanently.
     pub async fn ban_peer(&self, id: &NodeId) {
+        self.ban_until(id, u64::MAX).await;
+    }
+
+    /// Ban a peer until UNIX time `until`.
+    async fn ban_until(&self, id: &NodeId, until: u64) {
         self.remove_peer(id).await;
-        self.banned.insert(id.clone(), unix_now());
+        self.banned.insert(id.clone(), until);
+        self.reputation.remove(id);
         self.scoring.remove(id);
         let _ = self.event_tx.send(PeerEvent::Banned(id.clone()));
-        warn!(peer_id = %id, "Peer banned");
+        warn!(peer_id = %id, until = until, "Peer banned");
+    }
+
+    /// Whether `id` is banned at UNIX time `now`.  Expired bans read as lifted.
+    pub fn is_banned(&self, id: &NodeId, now: u64) -> bool {
+        self.banned.get(id).is_some_and(|until| now < *until.value())
+    }
+
+    /// Drop bans that have expired by UNIX time `now`.
+    pub fn prune_expired_bans(&self, now: u64) {
+        self.banned.retain(|id, until| {
+            let active = now < *until;
+            if !active {
+                info!(peer_id = %id, "Peer ban expired");
+            }
+            active
+        });
+    }
+
+    // ── REPUTATION ────────────────────────────────────────────────────────────
+
+    /// Current reputation of `id` (0 if never reported).
+    pub fn reputation(&self, id: &NodeId) -> i64 {
+        self.reputation.get(id).map(|r| *r.value()).unwrap_or(0)
+    }
+
+    /// Credit a peer for good behaviour, up to `REPUTATION_MAX`.
+    pub fn record_good(&self, id: &NodeId) {
+        let mut rep = self.reputation.entry(id.clone()).or_insert(0);
+        *rep = (*rep + 1).min(REPUTATION_MAX);
+    }
+
+    /// Penalise a peer.  Returns `true` if this pushed it to the ban threshold.
+    pub async fn record_bad(&self, id: &NodeId, severity: MisbehaviorSeverity) -> bool {
+        self.record_bad_at(id, severity, unix_now()).await
     }
 
-    pub fn is_banned(&self, id: &NodeId) -> bool {
-        self.banned.contains_key(id)
+    /// As `record_bad`, with the ban (if any) starting at UNIX time `now`.
+    pub async fn record_bad_at(&self, id: &NodeId, severity: MisbehaviorSeverity, now: u64) -> bool {
+        let rep = {
+            let mut rep = self.reputation.entry(id.clone()).or_insert(0);
+            *rep = rep.saturating_sub(severity.penalty());
+            *rep
+        };
+        debug!(peer_id = %id, ?severity, reputation = rep, "Peer misbehaviour recorded");
+        if rep > self.config.ban_threshold {
+            return false;
+        }
+        self.ban_until(id, now.saturating_add(self.config.ban_duration_secs)).await;
+        true
     }
 
     // ── INTERACTION RECORDING ─────────────────────────────────────────────────
@@ -257,6 +357,7 @@ impl PeerManager {
     /// Prune banned/malicious/stale peers and re-score suspicious peers.
     pub async fn maintenance_sweep(&self) {
         let now = unix_now();
+        self.prune_expired_bans(now);
         let mut to_ban: Vec<NodeId> = Vec::new();
         let mut to_remove: Vec<NodeId> = Vec::new();
 
@@ -386,7 +487,7 @@ mod tests {
         let (pm, _rx) = make_test_pm();
         let id = add_test_peer(&pm, 3).await;
         pm.ban_peer(&id).await;
-        assert!(pm.is_banned(&id));
+        assert!(pm.is_banned(&id, unix_now()));
 
         let ed_kp = Ed25519Keypair::generate();
         let sphincs_kp = SphincsKeypair::generate();
@@ -457,28 +558,70 @@ mod tests {
         assert_eq!(pm.peer_count(), 0);
     }
 
-    /// Store peer information in the Kademlia DHT for distributed peer discovery
-    pub fn dht_store_peer(&mut self, peer_id: &str, peer_address: &str) {
-        self.kademlia.store(peer_id, peer_address);
-        log::debug!("Stored peer {} at {} in DHT", peer_id, peer_address);
-    }
+    #[tokio::test]
+    async fn test_repeated_bad_events_ban_peer() {
+        let (pm, mut rx) = make_test_pm();
+        let id = add_test_peer(&pm, 30).await;
+        let now = 1_000;
+
+        pm.record_good(&id);
+        for _ in 0..10 {
+            assert!(!pm.record_bad_at(&id, MisbehaviorSeverity::Minor, now).await);
+        }
+        assert_eq!(pm.reputation(&id), -99);
+        assert!(!pm.is_banned(&id, now));
+
+        assert!(pm.record_bad_at(&id, MisbehaviorSeverity::Minor, now).await);
+        assert!(pm.is_banned(&id, now));
+        assert!(pm.get_peer(&id).is_none());
 
-    /// Retrieve peer information from the Kademlia DHT
-    pub fn dht_lookup_peer(&self, peer_id: &str) -> Option<String> {
-        let result = self.kademlia.lookup(peer_id);
-        log::debug!("DHT lookup for peer {} returned: {:?}", peer_id, result);
-        result
+        let mut saw_ban = false;
+        while let Ok(ev) = rx.try_recv() {
+            saw_ban |= matches!(ev, PeerEvent::Banned(ref b) if b == &id);
+        }
+        assert!(saw_ban);
     }
 
-    /// Broadcast message to mesh network for redundant peer discovery
-    pub fn mesh_broadcast_peer_info(&self, peer_id: &str, peer_address: &str) {
-        let message = format!("PEER_UPDATE:{}:{}", peer_id, peer_address);
-        let _ = self.mesh_node.broadcast(&message);
-        log::debug!("Broadcasted peer info to mesh network: {}", message);
+    #[tokio::test]
+    async fn test_reputation_banned_peer_rejected_on_connect() {
+        let (pm, _rx) = make_test_pm();
+        let id = add_test_peer(&pm, 31).await;
+        assert!(pm.record_bad(&id, MisbehaviorSeverity::Critical).await);
+
+        let ed_kp = Ed25519Keypair::generate();
+        let sphincs_kp = SphincsKeypair::generate();
+        let challenge = b"reconnect-context";
+        let sig = sphincs_sign(challenge, &sphincs_kp.secret_key.0).unwrap();
+        let result = pm.add_peer(
+            id.clone(), "10.0.0.31:9001".parse().unwrap(),
+            ed_kp.public_key_bytes(),
+            sphincs_kp.public_key.0.clone(),
+            challenge, &sig,
+        ).await;
+        assert!(matches!(result, Err(P2PError::PeerBanned { .. })));
     }
 
-    /// Fetches the current system time in UNIX timestamp format
-    fn current_time() -> u64 {
-        SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs()
+    #[tokio::test]
+    async fn test_ban_lifts_after_duration() {
+        let (pm, _rx) = PeerManager::new(
+            NodeId::random(),
+            PeerManagerConfig {
+                ban_duration_secs: 600,
+                ..Default::default()
+            },
+        );
+        let id = NodeId::random();
+        let now = 5_000;
+        assert!(pm.record_bad_at(&id, MisbehaviorSeverity::Critical, now).await);
+
+        assert!(pm.is_banned(&id, now + 599));
+        assert!(!pm.is_banned(&id, now + 600));
+        // Checking never lifts a ban; maintenance prunes expired ones
+        assert!(pm.is_banned(&id, now + 599));
+        pm.prune_expired_bans(now + 599);
+        assert_eq!(pm.banned.len(), 1);
+        pm.prune_expired_bans(now + 600);
+        assert!(pm.banned.is_empty());
+        assert_eq!(pm.reputation(&id), 0);
     }
 }
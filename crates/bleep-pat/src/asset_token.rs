@@ -1,10 +1,14 @@
 //! Protocol Asset Token (PAT) Module
 //! 
 //! This module implements the Protocol Asset Token system for BLEEP.
-//! It handles tokenomics, minting, burning, and token governance.
+//! It handles tokenomics, minting, burning, and token governance, plus
+//! ERC20-style transfer / approve / transfer_from on a single token ledger.
 
+use std::collections::BTreeMap;
 use serde::{Deserialize, Serialize};
 
+use crate::pat_engine::{PATError, PATResult};
+
 /// Protocol Asset Token configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PATConfig {
@@ -22,3 +26,179 @@ impl Default for PATConfig {
         }
     }
 }
+
+// ─────────────────────────────────────────────────────────────────────────────
+// ASSET TOKEN — balances + ERC20-style allowances
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Single PAT ledger with delegated spending.
+///
+/// All balance and allowance arithmetic is checked; a failing operation
+/// leaves balances and allowances untouched.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AssetToken {
+    pub config: PATConfig,
+    /// address → balance
+    balances: BTreeMap<String, u128>,
+    /// owner → spender → remaining allowance
+    allowances: BTreeMap<String, BTreeMap<String, u128>>,
+}
+
+impl AssetToken {
+    pub fn new(config: PATConfig) -> Self {
+        Self { config, ..Default::default() }
+    }
+
+    pub fn balance_of(&self, address: &str) -> u128 {
+        self.balances.get(address).copied().unwrap_or(0)
+    }
+
+    /// Mint `amount` to `to`, bounded by `total_supply_cap`.
+    pub fn mint(&mut self, to: &str, amount: u128) -> PATResult<()> {
+        if amount == 0 {
+            return Err(PATError::ZeroAmount);
+        }
+        let would_reach = self.config.current_supply
+            .checked_add(amount)
+            .ok_or(PATError::Overflow)?;
+        if would_reach > self.config.total_supply_cap {
+            return Err(PATError::SupplyCapExceeded { cap: self.config.total_supply_cap, would_reach });
+        }
+        let new_balance = self.balance_of(to).checked_add(amount).ok_or(PATError::Overflow)?;
+        self.balances.insert(to.to_string(), new_balance);
+        self.config.current_supply = would_reach;
+        Ok(())
+    }
+
+    /// Move `amount` from `from` to `to`.
+    pub fn transfer(&mut self, from: &str, to: &str, amount: u128) -> PATResult<()> {
+        if amount == 0 {
+            return Err(PATError::ZeroAmount);
+        }
+        if from == to {
+            return Err(PATError::SelfTransfer);
+        }
+        let have = self.balance_of(from);
+        let from_balance = have
+            .checked_sub(amount)
+            .ok_or(PATError::InsufficientBalance { have, need: amount })?;
+        let to_balance = self.balance_of(to).checked_add(amount).ok_or(PATError::Overflow)?;
+
+        if from_balance == 0 {
+            self.balances.remove(from);
+        } else {
+            self.balances.insert(from.to_string(), from_balance);
+        }
+        self.balances.insert(to.to_string(), to_balance);
+        Ok(())
+    }
+
+    /// Set `spender`'s allowance over `owner`'s balance to `amount`
+    /// (replaces any previous allowance; 0 revokes).
+    pub fn approve(&mut self, owner: &str, spender: &str, amount: u128) -> PATResult<()> {
+        if owner == spender {
+            return Err(PATError::SelfTransfer);
+        }
+        if amount == 0 {
+            if let Some(spenders) = self.allowances.get_mut(owner) {
+                spenders.remove(spender);
+                if spenders.is_empty() {
+                    self.allowances.remove(owner);
+                }
+            }
+        } else {
+            self.allowances
+                .entry(owner.to_string())
+                .or_default()
+                .insert(spender.to_string(), amount);
+        }
+        Ok(())
+    }
+
+    pub fn allowance(&self, owner: &str, spender: &str) -> u128 {
+        self.allowances
+            .get(owner)
+            .and_then(|s| s.get(spender))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Move `amount` from `from` to `to` on behalf of `spender`, consuming
+    /// allowance.
+    pub fn transfer_from(&mut self, spender: &str, from: &str, to: &str, amount: u128) -> PATResult<()> {
+        let allowed = self.allowance(from, spender);
+        let remaining = allowed
+            .checked_sub(amount)
+            .ok_or(PATError::AllowanceExceeded { allowed, need: amount })?;
+        // Transfer validates balances before mutating anything.
+        self.transfer(from, to, amount)?;
+        self.approve(from, spender, remaining)
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// TESTS
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn token() -> AssetToken {
+        let mut token = AssetToken::new(PATConfig::default());
+        token.mint("alice", 1_000).unwrap();
+        token
+    }
+
+    #[test]
+    fn test_transfer_moves_balance() {
+        let mut token = token();
+        token.transfer("alice", "bob", 300).unwrap();
+        assert_eq!(token.balance_of("alice"), 700);
+        assert_eq!(token.balance_of("bob"), 300);
+
+        let err = token.transfer("bob", "carol", 301).unwrap_err();
+        assert_eq!(err, PATError::InsufficientBalance { have: 300, need: 301 });
+    }
+
+    #[test]
+    fn test_transfer_from_over_allowance_rejected() {
+        let mut token = token();
+        token.approve("alice", "dex", 100).unwrap();
+
+        let err = token.transfer_from("dex", "alice", "bob", 101).unwrap_err();
+        assert_eq!(err, PATError::AllowanceExceeded { allowed: 100, need: 101 });
+        assert_eq!(token.balance_of("alice"), 1_000);
+        assert_eq!(token.balance_of("bob"), 0);
+        assert_eq!(token.allowance("alice", "dex"), 100);
+    }
+
+    #[test]
+    fn test_allowance_decrements_after_partial_spend() {
+        let mut token = token();
+        token.approve("alice", "dex", 500).unwrap();
+        token.transfer_from("dex", "alice", "bob", 200).unwrap();
+        assert_eq!(token.allowance("alice", "dex"), 300);
+        assert_eq!(token.balance_of("alice"), 800);
+        assert_eq!(token.balance_of("bob"), 200);
+
+        token.transfer_from("dex", "alice", "bob", 300).unwrap();
+        assert_eq!(token.allowance("alice", "dex"), 0);
+    }
+
+    #[test]
+    fn test_transfer_from_insufficient_balance_keeps_allowance() {
+        let mut token = token();
+        token.approve("alice", "dex", 5_000).unwrap();
+        let err = token.transfer_from("dex", "alice", "bob", 2_000).unwrap_err();
+        assert!(matches!(err, PATError::InsufficientBalance { .. }));
+        assert_eq!(token.allowance("alice", "dex"), 5_000);
+    }
+
+    #[test]
+    fn test_mint_overflow_rejected() {
+        let mut token = AssetToken::new(PATConfig { total_supply_cap: u128::MAX, current_supply: 0 });
+        token.mint("alice", u128::MAX).unwrap();
+        assert_eq!(token.mint("alice", 1).unwrap_err(), PATError::Overflow);
+    }
+}
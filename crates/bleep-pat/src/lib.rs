@@ -1,6 +1,7 @@
 pub mod asset_token;
 pub mod pat_engine;
 
+pub use asset_token::{AssetToken, PATConfig};
 pub use pat_engine::{
     PATRegistry, PATToken, PATEvent, PATError, PATResult, TokenLedger,
 };
@@ -43,6 +43,10 @@ pub enum PATError {
     ZeroAmount,
     #[error("Cannot transfer to self")]
     SelfTransfer,
+    #[error("Allowance exceeded: allowed {allowed}, need {need}")]
+    AllowanceExceeded { allowed: u128, need: u128 },
+    #[error("Arithmetic overflow")]
+    Overflow,
 }
 
 pub type PATResult<T> = Result<T, PATError>;
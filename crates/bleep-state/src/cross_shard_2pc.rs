@@ -13,6 +13,7 @@ use crate::cross_shard_transaction::{
     CrossShardTransaction, CrossShardTransactionStatus, CrossShardTransactionLifecycle,
     PrepareVote, TransactionId, CrossShardReceipt,
 };
+use crate::cross_shard_safety_invariants::SafetyVerifier;
 use crate::shard_registry::ShardId;
 use serde::{Serialize, Deserialize};
 use std::collections::BTreeMap;
@@ -154,6 +155,10 @@ impl TwoPhaseCommitCoordinator {
     }
     
     /// Finalize commit (record receipts)
+    /// 
+    /// SAFETY: For transfers, the source and target receipts must show the
+    /// amount moved without creating or destroying value; a violation aborts
+    /// the transaction.
     pub fn finalize_commit(&mut self, receipts: BTreeMap<ShardId, CrossShardReceipt>) -> Result<(), String> {
         // Verify all shards have receipts
         for shard in &self.transaction.involved_shards {
@@ -174,6 +179,31 @@ impl TwoPhaseCommitCoordinator {
             }
         }
         
+        if let Some(transfer) = self.transaction.transfer {
+            let balance_change = |shard: ShardId| {
+                receipts.get(&shard)
+                    .and_then(|receipt| receipt.balance_change)
+                    .ok_or(format!("Receipt from shard {:?} lacks the transfer balance", shard))
+            };
+            let source = balance_change(transfer.source_shard)?;
+            let target = balance_change(transfer.target_shard)?;
+            
+            if let Err(violation) = SafetyVerifier::verify_transfer_conservation(
+                source.before,
+                source.after,
+                target.before,
+                target.after,
+                transfer.amount,
+            ) {
+                warn!(
+                    "Transfer {} violates value conservation: {}",
+                    self.transaction.id.as_hex(), violation
+                );
+                self.execute_abort(violation.to_string())?;
+                return Err(violation.to_string());
+            }
+        }
+        
         // Record receipts
         self.lifecycle.receipts = receipts;
         self.lifecycle.status = CrossShardTransactionStatus::Committed;
@@ -356,7 +386,9 @@ impl CoordinatorManager {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::cross_shard_transaction::{BalanceChange, CrossShardTransfer};
     use crate::shard_registry::EpochId;
+    use std::collections::BTreeSet;
 
     #[test]
     fn test_coordinator_creation() {
@@ -440,4 +472,82 @@ mod tests {
         assert!(manager.terminal_transactions.contains_key(&tx_id));
         assert_eq!(manager.active_count(), 0);
     }
+
+    /// Transfer of 300 from shard 0 to shard 1
+    fn transfer_coordinator() -> TwoPhaseCommitCoordinator {
+        let shards: BTreeSet<_> = [ShardId(0), ShardId(1)].into_iter().collect();
+        let tx = CrossShardTransaction::new(vec![7], shards, EpochId(5), 1)
+            .unwrap()
+            .with_transfer(CrossShardTransfer {
+                source_shard: ShardId(0),
+                target_shard: ShardId(1),
+                amount: 300,
+            });
+        TwoPhaseCommitCoordinator::new(tx, 100, 150)
+    }
+
+    fn receipts(
+        coordinator: &TwoPhaseCommitCoordinator,
+        source: Option<BalanceChange>,
+        target: Option<BalanceChange>,
+    ) -> BTreeMap<ShardId, CrossShardReceipt> {
+        [(ShardId(0), source), (ShardId(1), target)]
+            .into_iter()
+            .map(|(shard_id, balance_change)| {
+                let receipt = CrossShardReceipt {
+                    transaction_id: coordinator.transaction.id,
+                    shard_id,
+                    block_height: 120,
+                    status: CrossShardTransactionStatus::Committed,
+                    state_root_after: "root".to_string(),
+                    merkle_proof: vec![],
+                    balance_change,
+                };
+                (shard_id, receipt)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_commit_verifies_transfer_conservation() {
+        let mut coordinator = transfer_coordinator();
+        let conserving = receipts(
+            &coordinator,
+            Some(BalanceChange { before: 1_000, after: 700 }),
+            Some(BalanceChange { before: 50, after: 350 }),
+        );
+        
+        coordinator.finalize_commit(conserving).unwrap();
+        assert_eq!(coordinator.lifecycle.status, CrossShardTransactionStatus::Committed);
+        assert_eq!(coordinator.phase, CommitPhase::Terminal);
+    }
+
+    #[test]
+    fn test_commit_aborts_on_conservation_violation() {
+        let mut coordinator = transfer_coordinator();
+        let inflating = receipts(
+            &coordinator,
+            Some(BalanceChange { before: 1_000, after: 700 }),
+            Some(BalanceChange { before: 50, after: 400 }),
+        );
+        
+        assert!(coordinator.finalize_commit(inflating).is_err());
+        assert_eq!(coordinator.lifecycle.status, CrossShardTransactionStatus::AbortedPrepare);
+        assert_eq!(coordinator.phase, CommitPhase::Aborting);
+        assert!(coordinator.lifecycle.abort_reason.is_some());
+        assert!(coordinator.lifecycle.receipts.is_empty());
+    }
+
+    #[test]
+    fn test_commit_requires_transfer_balances() {
+        let mut coordinator = transfer_coordinator();
+        let missing = receipts(
+            &coordinator,
+            Some(BalanceChange { before: 1_000, after: 700 }),
+            None,
+        );
+        
+        assert!(coordinator.finalize_commit(missing).is_err());
+        assert_ne!(coordinator.lifecycle.status, CrossShardTransactionStatus::Committed);
+    }
 }
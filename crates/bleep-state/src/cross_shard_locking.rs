@@ -10,6 +10,7 @@
 // 6. Orphaned locks auto-release
 
 use crate::cross_shard_transaction::{TransactionId, StateLockId};
+use crate::epoch_clock::EpochClock;
 use crate::shard_registry::ShardId;
 use serde::{Serialize, Deserialize};
 use std::collections::{BTreeMap, BTreeSet};
@@ -102,8 +103,8 @@ pub struct ShardLockManager {
     /// Key -> lock mapping (fast lookup)
     key_to_lock: BTreeMap<Vec<u8>, StateLockId>,
     
-    /// Current epoch
-    pub current_epoch: u64,
+    /// Shared epoch source
+    clock: EpochClock,
 }
 
 impl ShardLockManager {
@@ -113,10 +114,21 @@ impl ShardLockManager {
             shard_id,
             locks: BTreeMap::new(),
             key_to_lock: BTreeMap::new(),
-            current_epoch: 0,
+            clock: EpochClock::default(),
         }
     }
     
+    /// Attach a shared epoch clock
+    pub fn with_clock(mut self, clock: EpochClock) -> Self {
+        self.clock = clock;
+        self
+    }
+    
+    /// Current epoch according to the shared clock
+    pub fn current_epoch(&self) -> u64 {
+        self.clock.current_epoch()
+    }
+    
     /// Acquire a lock on state keys
     /// 
     /// SAFETY: Fails if any key is already locked
@@ -145,7 +157,7 @@ impl ShardLockManager {
             lock_id,
             transaction_id,
             keys.clone(),
-            self.current_epoch,
+            self.clock.current_epoch(),
         );
         
         // Register lock
@@ -195,7 +207,7 @@ impl ShardLockManager {
     /// SAFETY: Automatic cleanup prevents orphaned locks
     pub fn cleanup_expired_locks(&mut self) {
         let to_release: Vec<StateLockId> = self.locks.iter()
-            .filter(|(_, lock)| lock.is_expired(self.current_epoch))
+            .filter(|(_, lock)| lock.is_expired(self.clock.current_epoch()))
             .map(|(id, _)| *id)
             .collect();
         
@@ -208,7 +220,7 @@ impl ShardLockManager {
     
     /// Transition to next epoch
     pub fn advance_epoch(&mut self) {
-        self.current_epoch += 1;
+        self.clock.advance();
         self.cleanup_expired_locks();
     }
     
@@ -255,6 +267,9 @@ pub struct CrossShardLockCoordinator {
     
     /// Transaction locks (transaction_id -> set of locks held)
     transaction_locks: BTreeMap<TransactionId, BTreeSet<StateLockId>>,
+    
+    /// Epoch source shared by every shard's lock manager
+    clock: EpochClock,
 }
 
 impl CrossShardLockCoordinator {
@@ -263,12 +278,20 @@ impl CrossShardLockCoordinator {
         CrossShardLockCoordinator {
             shard_locks: BTreeMap::new(),
             transaction_locks: BTreeMap::new(),
+            clock: EpochClock::default(),
         }
     }
     
+    /// Attach a shared epoch clock (before registering shards)
+    pub fn with_clock(mut self, clock: EpochClock) -> Self {
+        self.clock = clock;
+        self
+    }
+    
     /// Register a shard with the coordinator
     pub fn register_shard(&mut self, shard_id: ShardId) {
-        self.shard_locks.insert(shard_id, ShardLockManager::new(shard_id));
+        let manager = ShardLockManager::new(shard_id).with_clock(self.clock.clone());
+        self.shard_locks.insert(shard_id, manager);
     }
     
     /// Acquire locks across multiple shards
@@ -339,9 +362,13 @@ impl CrossShardLockCoordinator {
     }
     
     /// Advance epoch and cleanup
+    /// 
+    /// SAFETY: The shared clock advances once, so every shard expires
+    /// locks against the same epoch.
     pub fn advance_epoch(&mut self) {
+        self.clock.advance();
         for manager in self.shard_locks.values_mut() {
-            manager.advance_epoch();
+            manager.cleanup_expired_locks();
         }
     }
 }
@@ -441,4 +468,25 @@ mod tests {
         let result = coordinator.acquire_locks(tx, &locks);
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_coordinator_shards_share_epoch_clock() {
+        let clock = EpochClock::new(3);
+        let mut coordinator = CrossShardLockCoordinator::new().with_clock(clock.clone());
+        coordinator.register_shard(ShardId(0));
+        coordinator.register_shard(ShardId(1));
+        
+        let mut locks = BTreeMap::new();
+        locks.insert(ShardId(0), (StateLockId(100), BTreeSet::from([vec![1]])));
+        locks.insert(ShardId(1), (StateLockId(101), BTreeSet::from([vec![2]])));
+        coordinator.acquire_locks(TransactionId::compute(b"test", 0), &locks).unwrap();
+        
+        // One advance moves every shard exactly one epoch
+        coordinator.advance_epoch();
+        assert_eq!(clock.current_epoch(), 4);
+        for manager in coordinator.shard_locks.values() {
+            assert_eq!(manager.current_epoch(), 4);
+            assert!(manager.locks.values().all(|lock| lock.status == LockStatus::Released));
+        }
+    }
 }
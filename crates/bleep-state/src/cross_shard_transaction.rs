@@ -158,6 +158,25 @@ pub struct CrossShardTransaction {
     
     /// Current status in lifecycle
     pub status: CrossShardTransactionStatus,
+    
+    /// Value moved between shards, if this is a transfer
+    #[serde(default)]
+    pub transfer: Option<CrossShardTransfer>,
+}
+
+/// Value moved from an account on one shard to an account on another
+/// 
+/// SAFETY: Checked for conservation against the shard receipts at commit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CrossShardTransfer {
+    /// Shard holding the debited account
+    pub source_shard: ShardId,
+    
+    /// Shard holding the credited account
+    pub target_shard: ShardId,
+    
+    /// Amount moved
+    pub amount: u128,
 }
 
 /// Status of a cross-shard transaction
@@ -214,9 +233,16 @@ impl CrossShardTransaction {
             nonce,
             signature: vec![],
             status: CrossShardTransactionStatus::Pending,
+            transfer: None,
         })
     }
     
+    /// Attach the value transfer this transaction performs
+    pub fn with_transfer(mut self, transfer: CrossShardTransfer) -> Self {
+        self.transfer = Some(transfer);
+        self
+    }
+    
     /// Get the deterministic coordinator shard
     /// 
     /// SAFETY: All nodes independently compute the same coordinator
@@ -267,6 +293,17 @@ pub struct CrossShardReceipt {
     
     /// Cryptographic proof (Merkle path)
     pub merkle_proof: Vec<u8>,
+    
+    /// Balance of the transferred account on this shard, for transfers
+    #[serde(default)]
+    pub balance_change: Option<BalanceChange>,
+}
+
+/// Account balance before and after a shard applied its part of a transfer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BalanceChange {
+    pub before: u128,
+    pub after: u128,
 }
 
 impl CrossShardReceipt {
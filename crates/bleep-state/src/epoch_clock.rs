@@ -0,0 +1,96 @@
+// SHARED EPOCH CLOCK
+// Single source of truth for the current epoch across subsystems
+//
+// SAFETY INVARIANTS:
+// 1. Epochs only move forward (advance is monotonic)
+// 2. All clones of a clock observe the same epoch
+// 3. Epoch progression is explicit — never derived from wall-clock time
+
+use crate::shard_registry::EpochId;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+/// Shared epoch counter.
+///
+/// Cloning an `EpochClock` yields a handle to the same underlying counter, so
+/// governance, consensus, and snapshot engines wired to clones of one clock
+/// always agree on the current epoch. Tests drive epochs by calling
+/// `advance()` directly instead of waiting on real time.
+#[derive(Debug, Clone, Default)]
+pub struct EpochClock {
+    epoch: Arc<AtomicU64>,
+}
+
+impl EpochClock {
+    /// Create a clock starting at `genesis_epoch`
+    pub fn new(genesis_epoch: u64) -> Self {
+        EpochClock {
+            epoch: Arc::new(AtomicU64::new(genesis_epoch)),
+        }
+    }
+
+    /// Current epoch number
+    pub fn current_epoch(&self) -> u64 {
+        self.epoch.load(Ordering::SeqCst)
+    }
+
+    /// Current epoch as an `EpochId`
+    pub fn current_epoch_id(&self) -> EpochId {
+        EpochId(self.current_epoch())
+    }
+
+    /// Advance by one epoch and return the new epoch
+    pub fn advance(&self) -> u64 {
+        self.epoch.fetch_add(1, Ordering::SeqCst) + 1
+    }
+
+    /// Jump forward to `epoch`
+    ///
+    /// SAFETY: Rejects any target earlier than the current epoch.
+    pub fn advance_to(&self, epoch: u64) -> Result<u64, String> {
+        let mut current = self.current_epoch();
+        loop {
+            if epoch < current {
+                return Err(format!(
+                    "Cannot move epoch clock backwards ({} -> {})",
+                    current, epoch
+                ));
+            }
+            match self.epoch.compare_exchange(current, epoch, Ordering::SeqCst, Ordering::SeqCst) {
+                Ok(_) => return Ok(epoch),
+                Err(observed) => current = observed,
+            }
+        }
+    }
+
+    /// True if both handles share the same underlying counter
+    pub fn shares_with(&self, other: &EpochClock) -> bool {
+        Arc::ptr_eq(&self.epoch, &other.epoch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clones_observe_same_epoch() {
+        let clock = EpochClock::new(5);
+        let handle = clock.clone();
+
+        assert_eq!(clock.advance(), 6);
+        assert_eq!(handle.current_epoch(), 6);
+        assert_eq!(handle.current_epoch_id(), EpochId(6));
+        assert!(clock.shares_with(&handle));
+        assert!(!clock.shares_with(&EpochClock::new(6)));
+    }
+
+    #[test]
+    fn test_advance_to_is_monotonic() {
+        let clock = EpochClock::default();
+        assert_eq!(clock.advance_to(10).unwrap(), 10);
+        assert_eq!(clock.advance_to(10).unwrap(), 10);
+        assert!(clock.advance_to(9).is_err());
+        assert_eq!(clock.current_epoch(), 10);
+    }
+}
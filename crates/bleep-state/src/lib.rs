@@ -24,6 +24,7 @@ pub mod shard_isolation;
 pub mod shard_rollback;
 pub mod shard_validator_slashing;
 pub mod shard_healing;
+pub mod epoch_clock;
 pub mod snapshot_engine;
 pub mod rollback_engine;
 pub mod advanced_fault_detector;
@@ -47,6 +48,7 @@ mod phase2_full_integration_tests;
 #[cfg(test)]
 mod phase4_integration_tests;
 
+pub use epoch_clock::EpochClock;
 pub use snapshot_engine::SnapshotEngine;
 pub use rollback_engine::RollbackEngine;
 pub use advanced_fault_detector::AdvancedFaultDetector;
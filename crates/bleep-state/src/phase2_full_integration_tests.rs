@@ -18,6 +18,7 @@ mod phase2_integration_tests {
     use bleep_state::advanced_fault_detector::*;
     use bleep_state::self_healing_orchestrator::*;
     use bleep_state::shard_registry::*;
+    use crate::snapshot_engine::TestSnapshotSigner;
 
     /// Test 1: State corruption detection triggers autonomous recovery
     #[test]
@@ -28,6 +29,7 @@ mod phase2_integration_tests {
             3,
         );
         orchestrator.update_epoch(EpochId(10));
+        let signer = TestSnapshotSigner::register(&mut orchestrator.snapshot_engine, 100);
 
         // Create a snapshot at epoch 10
         let root = ShardStateRoot {
@@ -47,10 +49,7 @@ mod phase2_integration_tests {
             )
             .unwrap();
 
-        orchestrator
-            .snapshot_engine
-            .finalize_snapshot(snapshot_id, vec![])
-            .unwrap();
+        signer.finalize(&mut orchestrator.snapshot_engine, snapshot_id).unwrap();
 
         // Simulate state root mismatch at epoch 20
         orchestrator.update_epoch(EpochId(20));
@@ -171,6 +170,7 @@ mod phase2_integration_tests {
     fn test_rollback_atomicity_and_verification() {
         let config = SnapshotConfig::new(10, 100, 1000, 0.66).unwrap();
         let mut engine = RollbackEngine::new(SnapshotEngine::new(config, SnapshotId(0)));
+        let signer = TestSnapshotSigner::register(&mut engine.snapshot_engine, 100);
         engine.update_height(200);
 
         // Create and finalize snapshot
@@ -191,10 +191,7 @@ mod phase2_integration_tests {
             )
             .unwrap();
 
-        engine
-            .snapshot_engine
-            .finalize_snapshot(snapshot_id, vec![])
-            .unwrap();
+        signer.finalize(&mut engine.snapshot_engine, snapshot_id).unwrap();
 
         // Initiate rollback
         let rollback = engine
@@ -272,6 +269,7 @@ mod phase2_integration_tests {
     fn test_snapshot_lineage_fork_prevention() {
         let config = SnapshotConfig::new(10, 100, 1000, 0.66).unwrap();
         let mut engine = SnapshotEngine::new(config, SnapshotId(0));
+        let signer = TestSnapshotSigner::register(&mut engine, 100);
 
         let root = ShardStateRoot {
             root_hash: "root".to_string(),
@@ -290,7 +288,7 @@ mod phase2_integration_tests {
             )
             .unwrap();
 
-        engine.finalize_snapshot(id1, vec![]).unwrap();
+        signer.finalize(&mut engine, id1).unwrap();
 
         let id2 = engine
             .create_snapshot(
@@ -302,7 +300,7 @@ mod phase2_integration_tests {
             )
             .unwrap();
 
-        engine.finalize_snapshot(id2, vec![]).unwrap();
+        signer.finalize(&mut engine, id2).unwrap();
 
         let id3 = engine
             .create_snapshot(
@@ -314,7 +312,7 @@ mod phase2_integration_tests {
             )
             .unwrap();
 
-        engine.finalize_snapshot(id3, vec![]).unwrap();
+        signer.finalize(&mut engine, id3).unwrap();
 
         // Verify lineage is unbroken and deterministic
         let lineage = engine.get_lineage();
@@ -458,6 +456,7 @@ mod phase2_integration_tests {
             SnapshotConfig::new(10, 100, 1000, 0.66).unwrap(),
             SnapshotId(0),
         ));
+        let signer = TestSnapshotSigner::register(&mut engine.snapshot_engine, 100);
 
         engine.update_height(200);
         engine.register_supply(1_000_000_000u128); // 1 billion total supply
@@ -480,10 +479,7 @@ mod phase2_integration_tests {
             )
             .unwrap();
 
-        engine
-            .snapshot_engine
-            .finalize_snapshot(snapshot_id, vec![])
-            .unwrap();
+        signer.finalize(&mut engine.snapshot_engine, snapshot_id).unwrap();
 
         let _rollback = engine
             .initiate_rollback(
@@ -30,12 +30,26 @@ pub enum ProtocolVersionError {
     
     #[error("Serialization error: {0}")]
     SerializationError(String),
+    
+    #[error("Incompatible protocol major version: local {local}, remote {remote}")]
+    IncompatibleMajor { local: u32, remote: u32 },
+}
+
+/// Major version of the genesis protocol line
+pub const GENESIS_PROTOCOL_MAJOR: u32 = 1;
+
+fn default_protocol_major() -> u32 {
+    GENESIS_PROTOCOL_MAJOR
 }
 
 /// Protocol version tied to rule set activation
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
 pub struct ProtocolVersion {
-    /// Version number
+    /// Major version; nodes on different majors cannot interoperate
+    #[serde(default = "default_protocol_major")]
+    pub major: u32,
+    
+    /// Version number within the major line (backward compatible)
     pub version: u32,
     
     /// Epoch when this version became active
@@ -48,18 +62,51 @@ pub struct ProtocolVersion {
 impl ProtocolVersion {
     pub fn new(version: u32, epoch: u64, block_height: u64) -> Self {
         ProtocolVersion {
+            major: GENESIS_PROTOCOL_MAJOR,
             version,
             epoch,
             block_height,
         }
     }
     
+    /// Same version on a different major line
+    pub fn with_major(mut self, major: u32) -> Self {
+        self.major = major;
+        self
+    }
+    
     /// Check if this version is still active at given epoch
     pub fn is_active_at(&self, epoch: u64) -> bool {
         epoch >= self.epoch
     }
 }
 
+/// Negotiate the protocol version two peers speak after a handshake
+/// 
+/// Versions within a major line are backward compatible, so the result is
+/// the lower of the two versions (the highest both support). Peers on
+/// different majors are refused rather than left to silently diverge.
+pub fn negotiate_version(
+    local: &ProtocolVersion,
+    remote: &ProtocolVersion,
+) -> Result<ProtocolVersion, ProtocolVersionError> {
+    if local.major != remote.major {
+        return Err(ProtocolVersionError::IncompatibleMajor {
+            local: local.major,
+            remote: remote.major,
+        });
+    }
+    
+    let agreed = if remote.version < local.version { *remote } else { *local };
+    
+    info!(
+        "Negotiated protocol version {}.{} (local {}.{}, remote {}.{})",
+        agreed.major, agreed.version, local.major, local.version, remote.major, remote.version
+    );
+    
+    Ok(agreed)
+}
+
 /// Extended block header with protocol versioning
 /// 
 /// This replaces the simple Block struct with a comprehensive header
@@ -382,6 +429,29 @@ mod tests {
         assert_eq!(tracker.version_at_epoch(25).unwrap().version, 3);
     }
 
+    #[test]
+    fn test_negotiate_common_version() {
+        let local = ProtocolVersion::new(5, 40, 4000);
+        let remote = ProtocolVersion::new(3, 20, 2000);
+        
+        let agreed = negotiate_version(&local, &remote).unwrap();
+        assert_eq!(agreed, remote);
+        // Symmetric
+        assert_eq!(negotiate_version(&remote, &local).unwrap(), agreed);
+        assert_eq!(negotiate_version(&local, &local).unwrap(), local);
+    }
+
+    #[test]
+    fn test_negotiate_rejects_incompatible_major() {
+        let local = ProtocolVersion::new(5, 40, 4000);
+        let remote = ProtocolVersion::new(5, 40, 4000).with_major(2);
+        
+        assert!(matches!(
+            negotiate_version(&local, &remote),
+            Err(ProtocolVersionError::IncompatibleMajor { local: 1, remote: 2 })
+        ));
+    }
+
     #[test]
     fn test_block_validation() {
         let mut header = BlockHeader::new(
@@ -672,17 +672,18 @@ impl RollbackEngine {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::snapshot_engine::SnapshotConfig;
+    use crate::snapshot_engine::{SnapshotConfig, TestSnapshotSigner};
 
-    fn create_test_engine() -> RollbackEngine {
+    fn create_test_engine() -> (RollbackEngine, TestSnapshotSigner) {
         let config = SnapshotConfig::new(10, 100, 1000, 0.66).unwrap();
-        let snapshot_engine = SnapshotEngine::new(config, SnapshotId(0));
-        RollbackEngine::new(snapshot_engine)
+        let mut snapshot_engine = SnapshotEngine::new(config, SnapshotId(0));
+        let signer = TestSnapshotSigner::register(&mut snapshot_engine, 100);
+        (RollbackEngine::new(snapshot_engine), signer)
     }
 
     #[test]
     fn test_rollback_initiation() {
-        let mut engine = create_test_engine();
+        let (mut engine, signer) = create_test_engine();
         engine.update_height(100);
 
         // First create a snapshot
@@ -703,10 +704,7 @@ mod tests {
             )
             .unwrap();
 
-        engine
-            .snapshot_engine
-            .finalize_snapshot(snapshot_id, vec![])
-            .unwrap();
+        signer.finalize(&mut engine.snapshot_engine, snapshot_id).unwrap();
 
         let result = engine.initiate_rollback(
             ShardId(0),
@@ -723,7 +721,7 @@ mod tests {
 
     #[test]
     fn test_cannot_rollback_unfinalized_snapshot() {
-        let mut engine = create_test_engine();
+        let (mut engine, _signer) = create_test_engine();
         engine.update_height(100);
 
         let root = ShardStateRoot {
@@ -757,7 +755,7 @@ mod tests {
 
     #[test]
     fn test_rollback_phase_progression() {
-        let mut engine = create_test_engine();
+        let (mut engine, signer) = create_test_engine();
         engine.update_height(100);
 
         let root = ShardStateRoot {
@@ -777,10 +775,7 @@ mod tests {
             )
             .unwrap();
 
-        engine
-            .snapshot_engine
-            .finalize_snapshot(snapshot_id, vec![])
-            .unwrap();
+        signer.finalize(&mut engine.snapshot_engine, snapshot_id).unwrap();
 
         let _record = engine
             .initiate_rollback(
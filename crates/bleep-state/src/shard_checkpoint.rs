@@ -13,7 +13,7 @@ use crate::shard_registry::{ShardId, EpochId, ShardStateRoot};
 use serde::{Serialize, Deserialize};
 use sha2::{Digest, Sha256};
 use log::info;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 /// Checkpoint ID - unique identifier for a checkpoint
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, Hash)]
@@ -239,6 +239,59 @@ impl ShardCheckpoint {
         
         Ok(())
     }
+    
+    /// Bytes validators sign: the checkpoint hash
+    pub fn signing_bytes(&self) -> &[u8] {
+        self.checkpoint_hash.as_bytes()
+    }
+    
+    /// Verify a checkpoint before trusting it for fast-sync
+    /// 
+    /// `stake_map` maps hex-encoded validator pubkeys to stake. The
+    /// checkpoint hash is recomputed from its contents, every signature is
+    /// verified (SPHINCS+) over that hash, and the stake of the signers must
+    /// reach `quorum` (a fraction of total stake).
+    /// 
+    /// SAFETY: A repeated signer or an invalid signature invalidates the
+    /// checkpoint outright rather than being skipped, since either indicates
+    /// a malformed or forged signature set. Signers outside `stake_map`
+    /// carry no stake.
+    pub fn verify(&self, stake_map: &HashMap<String, u128>, quorum: f64) -> bool {
+        if self.verify_integrity().is_err() {
+            return false;
+        }
+        
+        let total_stake = match stake_map.values().try_fold(0u128, |acc, s| acc.checked_add(*s)) {
+            Some(total) => total,
+            None => return false,
+        };
+        if total_stake == 0 || !(quorum > 0.0 && quorum <= 1.0) {
+            return false;
+        }
+        
+        let mut signers = HashSet::new();
+        let mut signed_stake: u128 = 0;
+        for sig in &self.validator_signatures {
+            if !signers.insert(&sig.validator_pubkey) {
+                return false;
+            }
+            if !bleep_crypto::tx_signer::verify_tx_signature(
+                self.signing_bytes(),
+                &sig.signature,
+                &sig.validator_pubkey,
+            ) {
+                return false;
+            }
+            signed_stake = signed_stake.saturating_add(
+                stake_map
+                    .get(&hex::encode(&sig.validator_pubkey))
+                    .copied()
+                    .unwrap_or(0),
+            );
+        }
+        
+        signed_stake as f64 >= quorum * total_stake as f64
+    }
 }
 
 /// Shard checkpoint manager - maintains checkpoint history
@@ -381,6 +434,55 @@ impl ShardCheckpointManager {
     }
 }
 
+/// Test fixture: a validator keypair that can sign checkpoints and fault reports
+#[cfg(test)]
+pub(crate) struct TestShardSigner {
+    pub(crate) pubkey: Vec<u8>,
+    secret_key: Vec<u8>,
+}
+
+#[cfg(test)]
+impl TestShardSigner {
+    pub(crate) fn generate() -> Self {
+        let (pubkey, secret_key) = bleep_crypto::tx_signer::generate_tx_keypair();
+        TestShardSigner { pubkey, secret_key }
+    }
+
+    /// `count` fresh signers
+    pub(crate) fn generate_set(count: usize) -> Vec<Self> {
+        (0..count).map(|_| Self::generate()).collect()
+    }
+
+    /// Stake map keyed by hex pubkey, pairing `signers` with `stakes` in order
+    pub(crate) fn stake_map(signers: &[Self], stakes: &[u128]) -> HashMap<String, u128> {
+        signers
+            .iter()
+            .zip(stakes)
+            .map(|(signer, stake)| (hex::encode(&signer.pubkey), *stake))
+            .collect()
+    }
+
+    /// Sign `checkpoint` and attach the signature
+    pub(crate) fn sign_checkpoint(&self, checkpoint: &mut ShardCheckpoint) {
+        let signature = bleep_crypto::tx_signer::sign_tx_payload(checkpoint.signing_bytes(), &self.secret_key).unwrap();
+        checkpoint.add_signature(self.pubkey.clone(), signature);
+    }
+
+    pub(crate) fn sign_fault(&self, evidence: &crate::shard_fault_detection::FaultEvidence) -> Vec<u8> {
+        bleep_crypto::tx_signer::sign_tx_payload(&evidence.signing_bytes().unwrap(), &self.secret_key).unwrap()
+    }
+
+    /// Sign `evidence` and submit it to `aggregator` as this validator
+    pub(crate) fn report_fault(
+        &self,
+        aggregator: &mut crate::shard_fault_detection::FaultEvidenceAggregator,
+        evidence: crate::shard_fault_detection::FaultEvidence,
+    ) -> Result<bool, String> {
+        let signature = self.sign_fault(&evidence);
+        aggregator.submit(self.pubkey.clone(), evidence, &signature)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -474,6 +576,79 @@ mod tests {
         assert_eq!(hash1, hash2);
     }
 
+    /// Four validators with stakes 40, 30, 20 and 10
+    fn validators() -> (Vec<TestShardSigner>, HashMap<String, u128>) {
+        let signers = TestShardSigner::generate_set(4);
+        let stakes = TestShardSigner::stake_map(&signers, &[40, 30, 20, 10]);
+        (signers, stakes)
+    }
+
+    fn checkpoint_signed_by(signers: &[&TestShardSigner]) -> ShardCheckpoint {
+        let mut checkpoint = ShardCheckpoint::new(
+            CheckpointId(1),
+            ShardId(0),
+            EpochId(0),
+            100,
+            1000,
+            ShardStateRoot {
+                root_hash: "0".repeat(64),
+                tx_count: 100,
+                height: 100,
+            },
+            "merkle_root".to_string(),
+        );
+        for signer in signers {
+            signer.sign_checkpoint(&mut checkpoint);
+        }
+        checkpoint
+    }
+
+    #[test]
+    fn test_checkpoint_verify_quorum() {
+        let (v, stakes) = validators();
+        let outsider = TestShardSigner::generate();
+        
+        // 70% of stake signed
+        assert!(checkpoint_signed_by(&[&v[0], &v[1]]).verify(&stakes, 0.67));
+        // 50% of stake signed
+        assert!(!checkpoint_signed_by(&[&v[1], &v[2]]).verify(&stakes, 0.67));
+        // Unknown signers carry no stake
+        assert!(!checkpoint_signed_by(&[&v[1], &v[2], &outsider]).verify(&stakes, 0.67));
+        // Duplicate signer rejected even though stake would suffice
+        assert!(!checkpoint_signed_by(&[&v[0], &v[0], &v[1]]).verify(&stakes, 0.67));
+    }
+
+    #[test]
+    fn test_checkpoint_verify_rejects_forged_signature() {
+        let (v, stakes) = validators();
+        
+        // Knowing the validator pubkeys is not enough to claim their stake
+        let mut checkpoint = checkpoint_signed_by(&[&v[1]]);
+        checkpoint.add_signature(v[0].pubkey.clone(), vec![0xAA; 64]);
+        assert!(!checkpoint.verify(&stakes, 0.67));
+    }
+
+    #[test]
+    fn test_checkpoint_verify_rejects_tampered_root() {
+        let (v, stakes) = validators();
+        let mut checkpoint = checkpoint_signed_by(&[&v[0], &v[1], &v[2]]);
+        assert!(checkpoint.verify(&stakes, 0.67));
+        
+        checkpoint.state_root.root_hash = "f".repeat(64);
+        assert!(!checkpoint.verify(&stakes, 0.67));
+        
+        // Recomputing the hash does not help: signatures cover the old hash
+        checkpoint.checkpoint_hash = ShardCheckpoint::compute_checkpoint_hash(
+            checkpoint.id,
+            checkpoint.shard_id,
+            checkpoint.epoch_id,
+            checkpoint.shard_height,
+            &checkpoint.state_root,
+            &checkpoint.blocks_merkle_root,
+        );
+        assert!(!checkpoint.verify(&stakes, 0.67));
+    }
+
     #[test]
     fn test_quorum_check() {
         let mut checkpoint = ShardCheckpoint::new(
@@ -11,8 +11,9 @@
 use crate::shard_registry::ShardId;
 use crate::shard_checkpoint::CheckpointId;
 use serde::{Serialize, Deserialize};
-use log::{warn, error};
-use std::collections::HashMap;
+use sha2::{Digest, Sha256};
+use log::{info, warn, error};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use crate::shard_registry::EpochId;
 
 /// Fault type enumeration
@@ -134,8 +135,38 @@ impl FaultEvidence {
         
         Ok(())
     }
+    
+    /// Canonical hash identifying the fault itself
+    /// 
+    /// SAFETY: Covers only what every honest reporter observes identically
+    /// (shard, epoch, height, fault type). Reporter-specific proof bytes and
+    /// descriptions are excluded so duplicate reports coalesce.
+    pub fn canonical_hash(&self) -> Result<String, String> {
+        let fault_type = serde_json::to_vec(&self.fault_type)
+            .map_err(|e| format!("Failed to serialize fault type: {}", e))?;
+        
+        let mut hasher = Sha256::new();
+        hasher.update(self.shard_id.0.to_le_bytes());
+        hasher.update(self.epoch_id.0.to_le_bytes());
+        hasher.update(self.detection_height.to_le_bytes());
+        hasher.update(&fault_type);
+        Ok(hex::encode(hasher.finalize()))
+    }
+    
+    /// Bytes a validator signs (SPHINCS+) to report this fault
+    /// 
+    /// Domain-separated canonical hash, so every reporter of the same fault
+    /// signs the same payload regardless of the proof bytes they attach.
+    pub fn signing_bytes(&self) -> Result<Vec<u8>, String> {
+        let mut bytes = FAULT_REPORT_DOMAIN.to_vec();
+        bytes.extend_from_slice(self.canonical_hash()?.as_bytes());
+        Ok(bytes)
+    }
 }
 
+/// Domain separator for signed fault reports
+const FAULT_REPORT_DOMAIN: &[u8] = b"BLEEP-SHARD-FAULT-V1";
+
 /// Fault detection configuration
 /// 
 /// SAFETY: Parameters are locked at chain initialization.
@@ -475,9 +506,117 @@ impl FaultHistory {
     }
 }
 
+/// One fault, as reported by any number of validators
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FaultIncident {
+    /// Canonical fault hash
+    pub fault_hash: String,
+    
+    /// Evidence from the first report
+    pub evidence: FaultEvidence,
+    
+    /// Distinct validators that reported this fault
+    pub reporters: BTreeSet<Vec<u8>>,
+    
+    /// Whether a quorum of distinct reporters has been reached
+    pub confirmed: bool,
+}
+
+/// Fault evidence aggregator - coalesces duplicate reports into incidents
+/// 
+/// SAFETY: Only validators in the registered set may report, each report
+/// must carry the reporter's signature over the fault, and an incident is
+/// confirmed only once distinct reporters make up `quorum` (a fraction) of
+/// the validator set; repeated submissions from one validator never count
+/// twice.
+pub struct FaultEvidenceAggregator {
+    /// Registered validator public keys (SPHINCS+)
+    validators: BTreeSet<Vec<u8>>,
+    
+    /// Fraction of the validator set required to confirm an incident
+    quorum: f64,
+    
+    /// Incidents by canonical fault hash
+    incidents: BTreeMap<String, FaultIncident>,
+}
+
+impl FaultEvidenceAggregator {
+    pub fn new(validators: BTreeSet<Vec<u8>>, quorum: f64) -> Result<Self, String> {
+        if validators.is_empty() {
+            return Err("Validator set must not be empty".to_string());
+        }
+        if !(quorum > 0.0 && quorum <= 1.0) {
+            return Err("quorum must be in (0, 1]".to_string());
+        }
+        
+        Ok(FaultEvidenceAggregator {
+            validators,
+            quorum,
+            incidents: BTreeMap::new(),
+        })
+    }
+    
+    /// Submit evidence signed by `reporter`
+    /// 
+    /// `signature` must verify against `reporter` over
+    /// `evidence.signing_bytes()`, and `reporter` must be a registered
+    /// validator. Returns whether the incident is confirmed after this
+    /// submission.
+    pub fn submit(
+        &mut self,
+        reporter: Vec<u8>,
+        evidence: FaultEvidence,
+        signature: &[u8],
+    ) -> Result<bool, String> {
+        if !self.validators.contains(&reporter) {
+            return Err("Reporter is not a registered validator".to_string());
+        }
+        evidence.verify()?;
+        if !bleep_crypto::tx_signer::verify_tx_signature(&evidence.signing_bytes()?, signature, &reporter) {
+            return Err("Invalid fault report signature".to_string());
+        }
+        let fault_hash = evidence.canonical_hash()?;
+        
+        let quorum_size = self.quorum * self.validators.len() as f64;
+        let incident = self.incidents
+            .entry(fault_hash.clone())
+            .or_insert_with(|| FaultIncident {
+                fault_hash: fault_hash.clone(),
+                evidence,
+                reporters: BTreeSet::new(),
+                confirmed: false,
+            });
+        
+        if !incident.reporters.insert(reporter) {
+            return Ok(incident.confirmed);
+        }
+        
+        if !incident.confirmed && incident.reporters.len() as f64 >= quorum_size {
+            incident.confirmed = true;
+            info!(
+                "Fault {} in shard {:?} confirmed by {} of {} validators",
+                fault_hash, incident.evidence.shard_id, incident.reporters.len(), self.validators.len()
+            );
+        }
+        
+        Ok(incident.confirmed)
+    }
+    
+    /// Get an incident by canonical fault hash
+    pub fn get_incident(&self, fault_hash: &str) -> Option<&FaultIncident> {
+        self.incidents.get(fault_hash)
+    }
+    
+    /// All confirmed incidents, in fault hash order
+    pub fn confirmed_incidents(&self) -> Vec<&FaultIncident> {
+        self.incidents.values().filter(|i| i.confirmed).collect()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::shard_checkpoint::TestShardSigner;
 
     #[test]
     fn test_fault_detection_config() {
@@ -522,6 +661,86 @@ mod tests {
         assert_eq!(fault.unwrap().severity, FaultSeverity::Critical);
     }
 
+    fn equivocation_report(proof: &[u8]) -> FaultEvidence {
+        FaultEvidence {
+            fault_type: FaultType::ValidatorEquivocation {
+                validator_pubkey: vec![9],
+                block_height: 100,
+                hash1: "hash1".to_string(),
+                hash2: "hash2".to_string(),
+            },
+            shard_id: ShardId(0),
+            epoch_id: EpochId(0),
+            severity: FaultSeverity::Critical,
+            detection_height: 100,
+            proof: proof.to_vec(),
+            details: "reported".to_string(),
+        }
+    }
+
+    /// Four registered validators, confirmation at 67% (three reporters)
+    fn registered_aggregator() -> (FaultEvidenceAggregator, Vec<TestShardSigner>) {
+        let signers = TestShardSigner::generate_set(4);
+        let validators = signers.iter().map(|s| s.pubkey.clone()).collect();
+        (FaultEvidenceAggregator::new(validators, 0.67).unwrap(), signers)
+    }
+
+    #[test]
+    fn test_aggregator_confirms_with_distinct_reporters() {
+        let (mut aggregator, keys) = registered_aggregator();
+        
+        // Proof bytes differ per reporter; the fault is the same
+        assert!(!keys[0].report_fault(&mut aggregator, equivocation_report(&[1])).unwrap());
+        assert!(!keys[1].report_fault(&mut aggregator, equivocation_report(&[2])).unwrap());
+        assert!(keys[2].report_fault(&mut aggregator, equivocation_report(&[3])).unwrap());
+        
+        let confirmed = aggregator.confirmed_incidents();
+        assert_eq!(confirmed.len(), 1);
+        assert_eq!(confirmed[0].reporters.len(), 3);
+    }
+
+    #[test]
+    fn test_aggregator_counts_duplicate_reporter_once() {
+        let (mut aggregator, keys) = registered_aggregator();
+        
+        for _ in 0..5 {
+            assert!(!keys[0].report_fault(&mut aggregator, equivocation_report(&[1])).unwrap());
+        }
+        assert!(!keys[1].report_fault(&mut aggregator, equivocation_report(&[2])).unwrap());
+        
+        let hash = equivocation_report(&[1]).canonical_hash().unwrap();
+        let incident = aggregator.get_incident(&hash).unwrap();
+        assert_eq!(incident.reporters.len(), 2);
+        assert!(!incident.confirmed);
+        assert!(aggregator.confirmed_incidents().is_empty());
+        
+        // Malformed evidence is refused
+        assert!(keys[2].report_fault(&mut aggregator, equivocation_report(&[])).is_err());
+    }
+
+    #[test]
+    fn test_aggregator_rejects_unregistered_and_forged_reports() {
+        let (mut aggregator, keys) = registered_aggregator();
+        let evidence = equivocation_report(&[1]);
+        
+        // A well-signed report from outside the validator set
+        let outsider = TestShardSigner::generate();
+        assert!(outsider.report_fault(&mut aggregator, evidence.clone()).is_err());
+        
+        // A registered reporter with another key's signature
+        let forged = keys[1].sign_fault(&evidence);
+        assert!(aggregator.submit(keys[0].pubkey.clone(), evidence.clone(), &forged).is_err());
+        
+        // A signature over a different fault
+        let mut other = evidence.clone();
+        other.detection_height += 1;
+        let wrong_fault = keys[0].sign_fault(&other);
+        assert!(aggregator.submit(keys[0].pubkey.clone(), evidence, &wrong_fault).is_err());
+        
+        assert!(aggregator.confirmed_incidents().is_empty());
+        assert!(FaultEvidenceAggregator::new(BTreeSet::new(), 0.67).is_err());
+    }
+
     #[test]
     fn test_liveness_failure_detection() {
         let config = FaultDetectionConfig::new(5, 50, 4).unwrap();
@@ -8,11 +8,55 @@
 // 4. No shortcuts or fast-forwarding allowed
 // 5. Healing progress is auditable and reproducible
 
-use crate::shard_registry::{ShardId, EpochId};
-use crate::shard_checkpoint::CheckpointId;
+use crate::shard_registry::{ShardId, EpochId, Shard, ShardStatus};
+use crate::shard_checkpoint::{CheckpointId, CheckpointStatus, ShardCheckpoint};
+use crate::shard_isolation::ShardIsolationManager;
+use crate::state_merkle::SparseMerkleTrie;
 use serde::{Serialize, Deserialize};
 use log::{info, error};
 use std::collections::HashMap;
+use thiserror::Error;
+
+/// Shard healing errors
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum HealError {
+    #[error("Invalid checkpoint: {0}")]
+    InvalidCheckpoint(String),
+    
+    #[error("Checkpoint belongs to shard {actual:?}, expected {expected:?}")]
+    ShardMismatch { expected: ShardId, actual: ShardId },
+    
+    #[error("Shard {0:?} is already being healed")]
+    AlreadyHealing(ShardId),
+    
+    #[error("Shard {0:?} is not isolated")]
+    NotIsolated(ShardId),
+    
+    #[error("Healing verification failed: {0}")]
+    VerificationFailed(String),
+}
+
+/// Outcome of a completed heal
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HealReport {
+    /// Shard that was healed
+    pub shard_id: ShardId,
+    
+    /// Checkpoint the shard was restored from
+    pub checkpoint_id: CheckpointId,
+    
+    /// Shard height restored from the checkpoint
+    pub restored_height: u64,
+    
+    /// Verified state root after restoration
+    pub state_root: String,
+    
+    /// Validators re-admitted to the shard (staked checkpoint signers)
+    pub readmitted_validators: Vec<Vec<u8>>,
+    
+    /// Epochs verified during healing
+    pub epochs_verified: u64,
+}
 
 /// Healing stage enumeration
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -305,6 +349,132 @@ impl ShardHealingManager {
         Ok(())
     }
     
+    /// Heal an isolated shard from a checkpoint
+    /// 
+    /// Runs every healing stage in order: verify the checkpoint against the
+    /// validator stake, verify the root of `restored_state` against the
+    /// checkpoint commitment, clear the shard's isolation, then restore
+    /// `shard` to the checkpoint (state root, height, empty mempool, active
+    /// status) and re-admit the checkpoint signers that hold stake as its
+    /// validator set. `stake_map` maps hex-encoded validator pubkeys to stake.
+    /// 
+    /// SAFETY: The checkpoint must carry a stake quorum of valid signatures
+    /// before any progress is recorded, and the shard's isolation is cleared
+    /// only after the restored root matches. Any failure discards the healing
+    /// record and leaves `shard` untouched, so the shard stays out of service.
+    pub fn heal(
+        &mut self,
+        shard: &mut Shard,
+        checkpoint: &ShardCheckpoint,
+        stake_map: &HashMap<String, u128>,
+        quorum: f64,
+        restored_state: &mut SparseMerkleTrie,
+        isolation: &mut ShardIsolationManager,
+    ) -> Result<HealReport, HealError> {
+        let shard_id = shard.id;
+        checkpoint.verify_integrity().map_err(HealError::InvalidCheckpoint)?;
+        if !checkpoint.verify(stake_map, quorum) {
+            return Err(HealError::InvalidCheckpoint(
+                "Checkpoint lacks a valid signature quorum".to_string(),
+            ));
+        }
+        
+        if checkpoint.shard_id != shard_id {
+            return Err(HealError::ShardMismatch {
+                expected: shard_id,
+                actual: checkpoint.shard_id,
+            });
+        }
+        
+        if checkpoint.status == CheckpointStatus::Invalidated {
+            return Err(HealError::InvalidCheckpoint(
+                "Checkpoint has been invalidated".to_string(),
+            ));
+        }
+        
+        if !isolation.is_isolated(shard_id) && !isolation.is_frozen(shard_id) {
+            return Err(HealError::NotIsolated(shard_id));
+        }
+        
+        // A finished heal may be superseded; one in flight may not
+        if let Some(progress) = self.healing_progress.get(&shard_id) {
+            if !progress.is_complete() {
+                return Err(HealError::AlreadyHealing(shard_id));
+            }
+            self.healing_progress.remove(&shard_id);
+        }
+        
+        let restored_root = hex::encode(restored_state.root());
+        
+        match self.run_heal(shard_id, checkpoint, restored_root, isolation) {
+            Ok(epochs_verified) => {
+                let readmitted_validators: Vec<Vec<u8>> = checkpoint.validator_signatures
+                    .iter()
+                    .map(|sig| sig.validator_pubkey.clone())
+                    .filter(|pk| stake_map.get(&hex::encode(pk)).is_some_and(|stake| *stake > 0))
+                    .collect();
+                
+                shard.state_root = checkpoint.state_root.clone();
+                shard.last_state_update_height = checkpoint.shard_height;
+                shard.pending_transactions.clear();
+                shard.status = ShardStatus::Active;
+                shard.validators.validators = readmitted_validators.clone();
+                shard.validators.proposer_rotation_index = 0;
+                
+                info!(
+                    "Healed shard {:?} from checkpoint {:?} at height {}",
+                    shard_id, checkpoint.id, checkpoint.shard_height
+                );
+                
+                Ok(HealReport {
+                    shard_id,
+                    checkpoint_id: checkpoint.id,
+                    restored_height: checkpoint.shard_height,
+                    state_root: checkpoint.state_root.root_hash.clone(),
+                    readmitted_validators,
+                    epochs_verified,
+                })
+            }
+            Err(e) => {
+                error!("Healing failed for shard {:?}: {}", shard_id, e);
+                self.healing_progress.remove(&shard_id);
+                Err(HealError::VerificationFailed(e))
+            }
+        }
+    }
+    
+    /// Drive a freshly verified checkpoint through all healing stages
+    /// 
+    /// Isolation is cleared before the final stage so that a failed recovery
+    /// never leaves a completed healing record behind.
+    fn run_heal(
+        &mut self,
+        shard_id: ShardId,
+        checkpoint: &ShardCheckpoint,
+        restored_root: String,
+        isolation: &mut ShardIsolationManager,
+    ) -> Result<u64, String> {
+        let root = &checkpoint.state_root.root_hash;
+        
+        self.initiate_healing(shard_id, checkpoint.id, checkpoint.shard_height, root.clone())?;
+        self.begin_rebuilding(shard_id)?;
+        self.complete_rebuilding(shard_id)?;
+        self.update_sync_progress(shard_id, checkpoint.shard_height, restored_root.clone())?;
+        self.complete_syncing(shard_id)?;
+        
+        // Verification pass: restored state against the checkpoint commitment
+        self.verify_epoch_state(shard_id, checkpoint.epoch_id, root, &restored_root)?;
+        
+        self.mark_ready_for_reintegration(shard_id)?;
+        let progress = self.healing_progress.get(&shard_id).ok_or("Shard not in healing")?;
+        ReintegrationValidator::validate_reintegration(progress)?;
+        let epochs_verified = progress.epochs_verified;
+        
+        isolation.complete_recovery(shard_id)?;
+        self.complete_reintegration(shard_id)?;
+        Ok(epochs_verified)
+    }
+    
     /// Get healing progress for a shard
     pub fn get_healing_progress(&self, shard_id: ShardId) -> Option<&HealingProgress> {
         self.healing_progress.get(&shard_id)
@@ -364,6 +534,7 @@ impl ReintegrationValidator {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::shard_checkpoint::TestShardSigner;
 
     #[test]
     fn test_healing_progress_creation() {
@@ -410,6 +581,174 @@ mod tests {
         assert_eq!(progress.progress_percent(), 100);
     }
 
+    struct Fixture {
+        shard: Shard,
+        stakes: HashMap<String, u128>,
+        restored_state: SparseMerkleTrie,
+        isolation: ShardIsolationManager,
+        checkpoint: ShardCheckpoint,
+    }
+
+    /// Shard 0 suspended and isolated with a stale validator set, restored
+    /// to two accounts, checkpoint signed by validators holding 70% of stake
+    fn fixture() -> Fixture {
+        use crate::shard_registry::ValidatorAssignment;
+        use crate::shard_fault_detection::{FaultEvidence, FaultSeverity, FaultType};
+        
+        let mut restored_state = SparseMerkleTrie::new();
+        restored_state.insert("alice", 500, 1);
+        restored_state.insert("bob", 250, 0);
+        
+        let mut checkpoint = ShardCheckpoint::new(
+            CheckpointId(5),
+            ShardId(0),
+            EpochId(2),
+            1000,
+            4000,
+            crate::shard_registry::ShardStateRoot {
+                root_hash: hex::encode(restored_state.root()),
+                tx_count: 10,
+                height: 1000,
+            },
+            "merkle_root".to_string(),
+        );
+        
+        let validators = TestShardSigner::generate_set(3);
+        let stakes = TestShardSigner::stake_map(&validators, &[40, 30, 30]);
+        for signer in &validators[..2] {
+            signer.sign_checkpoint(&mut checkpoint);
+        }
+        
+        let mut isolation = ShardIsolationManager::new();
+        let evidence = FaultEvidence {
+            fault_type: FaultType::ExecutionFailure {
+                block_height: 990,
+                error: "test error".to_string(),
+            },
+            shard_id: ShardId(0),
+            epoch_id: EpochId(2),
+            severity: FaultSeverity::Critical,
+            detection_height: 990,
+            proof: vec![],
+            details: "test".to_string(),
+        };
+        isolation.isolate_shard(ShardId(0), 2, evidence, 990).unwrap();
+        
+        let mut shard = Shard::new(
+            ShardId(0),
+            EpochId(2),
+            ValidatorAssignment {
+                shard_id: ShardId(0),
+                epoch_id: EpochId(2),
+                validators: vec![vec![9; 64]],
+                proposer_rotation_index: 3,
+            },
+            vec![0x00],
+            vec![0xff],
+        );
+        shard.status = ShardStatus::Suspended;
+        shard.add_pending_transaction(vec![1, 2, 3]);
+        
+        Fixture { shard, stakes, restored_state, isolation, checkpoint }
+    }
+
+    #[test]
+    fn test_heal_from_valid_checkpoint() {
+        let mut manager = ShardHealingManager::new();
+        let mut f = fixture();
+        
+        let report = manager
+            .heal(&mut f.shard, &f.checkpoint, &f.stakes, 0.67, &mut f.restored_state, &mut f.isolation)
+            .unwrap();
+        assert_eq!(report.checkpoint_id, CheckpointId(5));
+        assert_eq!(report.restored_height, 1000);
+        assert_eq!(report.state_root, f.checkpoint.state_root.root_hash);
+        assert_eq!(report.readmitted_validators.len(), 2);
+        assert_eq!(report.epochs_verified, 1);
+        
+        let progress = manager.get_healing_progress(ShardId(0)).unwrap();
+        assert!(progress.is_complete());
+        assert!(f.isolation.can_accept_transactions(ShardId(0)));
+        
+        // The registry shard now runs from the checkpoint with its signers
+        assert_eq!(f.shard.status, ShardStatus::Active);
+        assert_eq!(f.shard.state_root, f.checkpoint.state_root);
+        assert_eq!(f.shard.last_state_update_height, 1000);
+        assert!(f.shard.pending_transactions.is_empty());
+        assert_eq!(f.shard.validators.validators, report.readmitted_validators);
+        assert_eq!(f.shard.validators.proposer_rotation_index, 0);
+    }
+
+    #[test]
+    fn test_heal_rejects_tampered_checkpoint() {
+        let mut manager = ShardHealingManager::new();
+        let mut f = fixture();
+        
+        // Re-hashing after tampering keeps integrity but breaks the signatures
+        let mut checkpoint = f.checkpoint.clone();
+        checkpoint.state_root.root_hash = "cd".repeat(32);
+        checkpoint.checkpoint_hash = ShardCheckpoint::compute_checkpoint_hash(
+            checkpoint.id,
+            checkpoint.shard_id,
+            checkpoint.epoch_id,
+            checkpoint.shard_height,
+            &checkpoint.state_root,
+            &checkpoint.blocks_merkle_root,
+        );
+        assert!(checkpoint.verify_integrity().is_ok());
+        
+        assert!(matches!(
+            manager.heal(&mut f.shard, &checkpoint, &f.stakes, 0.67, &mut f.restored_state, &mut f.isolation),
+            Err(HealError::InvalidCheckpoint(_))
+        ));
+        assert!(!manager.is_healing(ShardId(0)));
+        assert!(f.isolation.is_isolated(ShardId(0)));
+        assert_eq!(f.shard.status, ShardStatus::Suspended);
+        assert_eq!(f.shard.validators.validators, vec![vec![9; 64]]);
+        
+        // Quorum above the signed stake
+        assert!(matches!(
+            manager.heal(&mut f.shard, &f.checkpoint, &f.stakes, 0.8, &mut f.restored_state, &mut f.isolation),
+            Err(HealError::InvalidCheckpoint(_))
+        ));
+        
+        let mut other_shard = f.shard.clone();
+        other_shard.id = ShardId(1);
+        assert!(matches!(
+            manager.heal(&mut other_shard, &f.checkpoint, &f.stakes, 0.67, &mut f.restored_state, &mut f.isolation),
+            Err(HealError::ShardMismatch { .. })
+        ));
+        assert!(!manager.is_healing(ShardId(1)));
+    }
+
+    #[test]
+    fn test_heal_rejects_diverging_restored_state() {
+        let mut manager = ShardHealingManager::new();
+        let mut f = fixture();
+        
+        f.restored_state.insert("mallory", 1_000, 0);
+        assert!(matches!(
+            manager.heal(&mut f.shard, &f.checkpoint, &f.stakes, 0.67, &mut f.restored_state, &mut f.isolation),
+            Err(HealError::VerificationFailed(_))
+        ));
+        assert!(!manager.is_healing(ShardId(0)));
+        assert!(f.isolation.is_isolated(ShardId(0)));
+    }
+
+    #[test]
+    fn test_heal_requires_isolated_shard() {
+        let mut manager = ShardHealingManager::new();
+        let mut f = fixture();
+        let mut healthy = ShardIsolationManager::new();
+        
+        assert_eq!(
+            manager
+                .heal(&mut f.shard, &f.checkpoint, &f.stakes, 0.67, &mut f.restored_state, &mut healthy)
+                .unwrap_err(),
+            HealError::NotIsolated(ShardId(0))
+        );
+    }
+
     #[test]
     fn test_reintegration_validation() {
         let mut progress = HealingProgress::new(
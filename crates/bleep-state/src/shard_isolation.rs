@@ -8,13 +8,31 @@
 // 4. Unaffected shards continue operating normally
 // 5. Isolation is epoch-bound and reversible only through protocol rules
 // 6. Isolation decisions are consensus-verified
+// 7. Isolation never leaves fewer than the minimum active validators
 
 use crate::shard_registry::ShardId;
-use crate::shard_fault_detection::{FaultEvidence, FaultSeverity};
+use crate::shard_fault_detection::{FaultEvidence, FaultEvidenceAggregator, FaultSeverity};
 use serde::{Serialize, Deserialize};
-use log::info;
+use log::{info, warn};
 use std::collections::{HashMap, HashSet};
 use std::time::{SystemTime, UNIX_EPOCH};
+use thiserror::Error;
+
+/// Shard isolation errors
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum IsolationError {
+    #[error("Shard {0:?} is already isolated")]
+    AlreadyIsolated(ShardId),
+    
+    #[error("Isolation would leave {remaining} active validators, minimum is {minimum}")]
+    InsufficientValidators { remaining: u32, minimum: u32 },
+    
+    #[error("No fault incident with hash {0}")]
+    UnknownIncident(String),
+    
+    #[error("Fault incident {0} has not been confirmed by a validator quorum")]
+    UnconfirmedIncident(String),
+}
 
 /// Shard isolation status
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -156,6 +174,71 @@ impl ShardIsolationManager {
         Ok(())
     }
     
+    /// Isolate the shard named by a confirmed fault incident, only if the
+    /// network stays above the validator minimum
+    /// 
+    /// `fault_hash` identifies the incident in `aggregator`;
+    /// `remaining_validators` is the number of validators that would remain
+    /// active once the shard's validators are removed.
+    /// 
+    /// SAFETY: Only incidents a quorum of validators confirmed through the
+    /// aggregator can isolate a shard, and every isolation leaves an audit
+    /// record carrying the incident's evidence. A degraded shard is
+    /// preferable to breaking BFT safety, so the shard is left in its
+    /// current state when the guard would be breached.
+    pub fn isolate(
+        &mut self,
+        aggregator: &FaultEvidenceAggregator,
+        fault_hash: &str,
+        remaining_validators: u32,
+        min_validators: u32,
+    ) -> Result<(), IsolationError> {
+        let incident = aggregator
+            .get_incident(fault_hash)
+            .ok_or_else(|| IsolationError::UnknownIncident(fault_hash.to_string()))?;
+        if !incident.confirmed {
+            return Err(IsolationError::UnconfirmedIncident(fault_hash.to_string()));
+        }
+        let evidence = &incident.evidence;
+        let shard_id = evidence.shard_id;
+        
+        if self.isolated_shards.contains(&shard_id) {
+            return Err(IsolationError::AlreadyIsolated(shard_id));
+        }
+        
+        if remaining_validators < min_validators {
+            warn!(
+                "Refusing to isolate shard {:?}: {} validators would remain, minimum {}",
+                shard_id, remaining_validators, min_validators
+            );
+            return Err(IsolationError::InsufficientValidators {
+                remaining: remaining_validators,
+                minimum: min_validators,
+            });
+        }
+        
+        let mut record = IsolationRecord::new(
+            shard_id,
+            evidence.epoch_id.0,
+            evidence.clone(),
+            evidence.detection_height,
+        );
+        record.status = IsolationStatus::Isolated;
+        
+        self.frozen_shards.remove(&shard_id);
+        self.isolated_shards.insert(shard_id);
+        self.isolation_records
+            .entry(shard_id)
+            .or_insert_with(Vec::new)
+            .push(record);
+        
+        info!(
+            "Isolated shard {:?} on confirmed fault {} ({} validators remain active)",
+            shard_id, fault_hash, remaining_validators
+        );
+        Ok(())
+    }
+    
     /// Finalize isolation (commit to consensus)
     /// 
     /// SAFETY: Called only after consensus has verified isolation decision.
@@ -228,20 +311,18 @@ impl ShardIsolationManager {
     /// 
     /// SAFETY: Requires explicit consensus approval.
     pub fn complete_recovery(&mut self, shard_id: ShardId) -> Result<(), String> {
+        let record = self.isolation_records.get_mut(&shard_id)
+            .ok_or("No isolation record for shard")?
+            .last_mut()
+            .ok_or("No active isolation record")?;
+        record.status = IsolationStatus::Normal;
+        
         if !self.isolated_shards.remove(&shard_id) {
             self.frozen_shards.remove(&shard_id);
         }
         
-        let records = self.isolation_records.get_mut(&shard_id)
-            .ok_or("No isolation record for shard")?;
-        
-        if let Some(record) = records.last_mut() {
-            record.status = IsolationStatus::Normal;
-            info!("Completed recovery for shard {:?}", shard_id);
-            Ok(())
-        } else {
-            Err("No active isolation record".to_string())
-        }
+        info!("Completed recovery for shard {:?}", shard_id);
+        Ok(())
     }
     
     /// Get isolation history for a shard
@@ -318,6 +399,7 @@ impl CrossShardValidator {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::shard_checkpoint::TestShardSigner;
     use crate::shard_fault_detection::{FaultType, FaultEvidence};
 
     #[test]
@@ -385,6 +467,88 @@ mod tests {
         assert!(!manager.is_isolated(ShardId(0)));
     }
 
+    fn reported_fault(shard_id: ShardId) -> FaultEvidence {
+        FaultEvidence {
+            fault_type: FaultType::ExecutionFailure {
+                block_height: 100,
+                error: "divergent execution".to_string(),
+            },
+            shard_id,
+            epoch_id: crate::shard_registry::EpochId(3),
+            severity: FaultSeverity::Critical,
+            detection_height: 100,
+            proof: vec![1],
+            details: "reported".to_string(),
+        }
+    }
+
+    /// Aggregator with `validators` registered (full quorum) and one signed
+    /// report of a fault in `shard_id`; returns the incident's fault hash
+    fn reported_incident(shard_id: ShardId, validators: usize) -> (FaultEvidenceAggregator, String) {
+        let signers = TestShardSigner::generate_set(validators);
+        let registered = signers.iter().map(|s| s.pubkey.clone()).collect();
+        let mut aggregator = FaultEvidenceAggregator::new(registered, 1.0).unwrap();
+        
+        let evidence = reported_fault(shard_id);
+        let fault_hash = evidence.canonical_hash().unwrap();
+        signers[0].report_fault(&mut aggregator, evidence).unwrap();
+        (aggregator, fault_hash)
+    }
+
+    #[test]
+    fn test_isolate_within_validator_minimum() {
+        let mut manager = ShardIsolationManager::new();
+        let (aggregator, fault_hash) = reported_incident(ShardId(1), 1);
+        
+        manager.isolate(&aggregator, &fault_hash, 7, 7).unwrap();
+        assert!(manager.is_isolated(ShardId(1)));
+        assert!(!manager.can_participate_in_crossshard(ShardId(1)));
+        
+        let history = manager.get_isolation_history(ShardId(1));
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].status, IsolationStatus::Isolated);
+        assert_eq!(history[0].triggered_at_epoch, 3);
+        assert_eq!(history[0].trigger_evidence.canonical_hash().unwrap(), fault_hash);
+        manager.finalize_isolation(ShardId(1)).unwrap();
+        
+        assert_eq!(
+            manager.isolate(&aggregator, &fault_hash, 7, 7),
+            Err(IsolationError::AlreadyIsolated(ShardId(1)))
+        );
+    }
+
+    #[test]
+    fn test_isolate_blocked_below_validator_minimum() {
+        let mut manager = ShardIsolationManager::new();
+        let (aggregator, fault_hash) = reported_incident(ShardId(1), 1);
+        
+        assert_eq!(
+            manager.isolate(&aggregator, &fault_hash, 6, 7),
+            Err(IsolationError::InsufficientValidators { remaining: 6, minimum: 7 })
+        );
+        assert!(!manager.is_isolated(ShardId(1)));
+        assert!(manager.can_accept_transactions(ShardId(1)));
+        assert!(manager.get_isolation_history(ShardId(1)).is_empty());
+    }
+
+    #[test]
+    fn test_isolate_requires_confirmed_incident() {
+        let mut manager = ShardIsolationManager::new();
+        
+        // One report out of two validators falls short of the quorum
+        let (aggregator, fault_hash) = reported_incident(ShardId(1), 2);
+        assert_eq!(
+            manager.isolate(&aggregator, &fault_hash, 7, 7),
+            Err(IsolationError::UnconfirmedIncident(fault_hash.clone()))
+        );
+        assert_eq!(
+            manager.isolate(&aggregator, "unknown", 7, 7),
+            Err(IsolationError::UnknownIncident("unknown".to_string()))
+        );
+        assert!(!manager.is_isolated(ShardId(1)));
+        assert!(manager.get_isolation_history(ShardId(1)).is_empty());
+    }
+
     #[test]
     fn test_crossshard_validator() {
         let manager = ShardIsolationManager::new();
@@ -8,6 +8,7 @@
 // 4. Assignments rotate at epoch boundaries only
 // 5. Validator misbehavior is slashable at shard level
 // 6. Assignments ensure Byzantine fault tolerance per shard (2f+1 quorum)
+// 7. Rebalancing moves the minimum number of validators between shards
 
 use crate::shard_registry::{ShardId, EpochId, ValidatorAssignment};
 use sha2::{Digest, Sha256};
@@ -100,6 +101,35 @@ pub enum AssignmentStrategy {
     DeterministicHash,
 }
 
+/// A single validator move between shards
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ValidatorMove {
+    /// Validator public key
+    pub validator: Vec<u8>,
+    
+    /// Shard the validator leaves
+    pub from: ShardId,
+    
+    /// Shard the validator joins
+    pub to: ShardId,
+}
+
+/// Reassignment plan produced by rebalancing
+/// 
+/// SAFETY: Moves are listed in deterministic (source shard, target shard) order.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReassignmentPlan {
+    /// Validator moves, in application order
+    pub moves: Vec<ValidatorMove>,
+}
+
+impl ReassignmentPlan {
+    /// Number of validators that change shard
+    pub fn moved_count(&self) -> usize {
+        self.moves.len()
+    }
+}
+
 /// Shard validator assignment manager
 /// 
 /// SAFETY: Produces deterministic, Byzantine-tolerant validator assignments.
@@ -358,6 +388,76 @@ impl ShardValidatorAssigner {
         Ok(())
     }
     
+    /// Rebalance existing assignments to reach `target_distribution` counts
+    /// 
+    /// Only surplus validators move: every shard keeps its leading validators
+    /// up to its target, so the number of moves equals the total surplus,
+    /// which is the minimum any reassignment can achieve. Shards missing from
+    /// the target are dissolved and all their validators move.
+    /// 
+    /// SAFETY: Deterministic - shards are visited in `ShardId` order and
+    /// surplus validators are taken from the tail of each shard.
+    pub fn rebalance(
+        &self,
+        assignments: &mut BTreeMap<ShardId, ValidatorAssignment>,
+        target_distribution: &BTreeMap<ShardId, u32>,
+    ) -> Result<ReassignmentPlan, String> {
+        if let Some((shard_id, _)) = target_distribution.iter().find(|(_, count)| **count == 0) {
+            return Err(format!("Shard {:?} must keep at least one validator", shard_id));
+        }
+        
+        let current_total: u64 = assignments.values().map(|a| a.validators.len() as u64).sum();
+        let target_total: u64 = target_distribution.values().map(|&c| c as u64).sum();
+        if current_total != target_total {
+            return Err(format!(
+                "Target distribution covers {} validators, {} are assigned",
+                target_total, current_total
+            ));
+        }
+        
+        let epoch_id = assignments.values()
+            .next()
+            .map(|a| a.epoch_id)
+            .ok_or("No current assignments to rebalance")?;
+        
+        // Release surplus validators, keeping each shard's leading validators
+        let mut surplus: Vec<(ShardId, Vec<u8>)> = Vec::new();
+        for (shard_id, assignment) in assignments.iter_mut() {
+            let target = target_distribution.get(shard_id).copied().unwrap_or(0) as usize;
+            if assignment.validators.len() > target {
+                for validator in assignment.validators.drain(target..) {
+                    surplus.push((*shard_id, validator));
+                }
+            }
+        }
+        assignments.retain(|shard_id, _| target_distribution.contains_key(shard_id));
+        
+        // Fill deficits in shard order
+        let mut surplus = surplus.into_iter();
+        let mut plan = ReassignmentPlan::default();
+        for (&shard_id, &target) in target_distribution {
+            let assignment = assignments.entry(shard_id).or_insert_with(|| ValidatorAssignment {
+                shard_id,
+                epoch_id,
+                validators: Vec::new(),
+                proposer_rotation_index: 0,
+            });
+            
+            while assignment.validators.len() < target as usize {
+                let (from, validator) = surplus.next()
+                    .ok_or("Surplus exhausted before target was reached")?;
+                assignment.validators.push(validator.clone());
+                plan.moves.push(ValidatorMove { validator, from, to: shard_id });
+            }
+        }
+        
+        info!(
+            "Rebalanced {} validators across {} shards with {} moves",
+            target_total, target_distribution.len(), plan.moved_count()
+        );
+        Ok(plan)
+    }
+    
     /// Rotate validator proposers within a shard at block boundaries
     /// 
     /// SAFETY: Deterministic rotation ensures all nodes agree on next proposer.
@@ -494,4 +594,62 @@ mod tests {
             assert_eq!(assignment.validators, other.validators);
         }
     }
+
+    fn assignments_with(counts: &[u32]) -> BTreeMap<ShardId, ValidatorAssignment> {
+        let mut next_key = 0u8;
+        counts.iter().enumerate().map(|(idx, &count)| {
+            let shard_id = ShardId(idx as u64);
+            let validators = (0..count).map(|_| { next_key += 1; vec![next_key] }).collect();
+            (shard_id, ValidatorAssignment {
+                shard_id,
+                epoch_id: EpochId(3),
+                validators,
+                proposer_rotation_index: 0,
+            })
+        }).collect()
+    }
+
+    fn distribution(counts: &[u32]) -> BTreeMap<ShardId, u32> {
+        counts.iter().enumerate().map(|(idx, &c)| (ShardId(idx as u64), c)).collect()
+    }
+
+    #[test]
+    fn test_rebalance_reaches_target_distribution() {
+        let assigner = ShardValidatorAssigner::new(AssignmentStrategy::UniformDistribution, 1);
+        let mut assignments = assignments_with(&[4, 4]);
+        let target = distribution(&[3, 3, 2]);
+        
+        let plan = assigner.rebalance(&mut assignments, &target).unwrap();
+        
+        let reached: BTreeMap<ShardId, u32> = assignments.iter()
+            .map(|(id, a)| (*id, a.validators.len() as u32))
+            .collect();
+        assert_eq!(reached, target);
+        assert_eq!(assignments[&ShardId(2)].epoch_id, EpochId(3));
+        
+        // Same inputs, same plan
+        let replay = assigner.rebalance(&mut assignments_with(&[4, 4]), &target).unwrap();
+        assert_eq!(plan, replay);
+        
+        // Totals must match
+        assert!(assigner.rebalance(&mut assignments_with(&[4, 4]), &distribution(&[4, 5])).is_err());
+    }
+
+    #[test]
+    fn test_rebalance_moves_minimum_validators() {
+        let assigner = ShardValidatorAssigner::new(AssignmentStrategy::UniformDistribution, 1);
+        let mut assignments = assignments_with(&[4, 2, 2]);
+        
+        let plan = assigner.rebalance(&mut assignments, &distribution(&[3, 3, 2])).unwrap();
+        
+        // One surplus validator means exactly one move
+        assert_eq!(plan.moved_count(), 1);
+        assert_eq!(
+            plan.moves[0],
+            ValidatorMove { validator: vec![4], from: ShardId(0), to: ShardId(1) }
+        );
+        assert_eq!(assignments[&ShardId(0)].validators, vec![vec![1], vec![2], vec![3]]);
+        assert_eq!(assignments[&ShardId(1)].validators, vec![vec![5], vec![6], vec![4]]);
+        assert_eq!(assignments[&ShardId(2)].validators, vec![vec![7], vec![8]]);
+    }
 }
@@ -10,11 +10,13 @@
 // 6. Snapshots enable deterministic rollback without re-execution
 // 7. All nodes independently derive identical snapshots
 
+use crate::epoch_clock::EpochClock;
 use crate::shard_registry::{ShardId, EpochId, ShardStateRoot};
+use bleep_crypto::tx_signer::verify_tx_signature;
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 use log::{info, warn};
-use std::collections::{BTreeMap, VecDeque};
+use std::collections::{BTreeMap, HashSet, VecDeque};
 
 /// Snapshot ID - unique identifier for a snapshot
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, Hash)]
@@ -46,6 +48,10 @@ pub struct SnapshotConfig {
 
     /// Genesis snapshot ID
     pub genesis_snapshot_id: u64,
+
+    /// Snapshots younger than this many blocks are never pruned (0 = no minimum)
+    #[serde(default)]
+    pub min_retention_blocks: u64,
 }
 
 impl SnapshotConfig {
@@ -75,9 +81,16 @@ impl SnapshotConfig {
             max_rollback_depth,
             min_validator_quorum,
             genesis_snapshot_id: 0,
+            min_retention_blocks: 0,
         })
     }
 
+    /// Keep every snapshot for at least `blocks` blocks after its height
+    pub fn with_min_retention_blocks(mut self, blocks: u64) -> Self {
+        self.min_retention_blocks = blocks;
+        self
+    }
+
     /// Determine if snapshot should be created at this epoch
     pub fn should_create_snapshot(&self, epoch: u64) -> bool {
         epoch > 0 && epoch % self.epochs_per_snapshot == 0
@@ -98,7 +111,7 @@ pub struct SnapshotValidatorSignature {
     /// Signature over snapshot hash
     pub signature: Vec<u8>,
 
-    /// Stake of this validator (for quorum calculation)
+    /// Registered stake of this validator when it signed
     pub stake: u128,
 }
 
@@ -209,6 +222,11 @@ impl StateSnapshot {
         hex::encode(hasher.finalize())
     }
 
+    /// Bytes validators sign for this snapshot
+    pub fn signing_bytes(&self) -> &[u8] {
+        self.snapshot_hash.as_bytes()
+    }
+
     /// Add a validator signature to this snapshot
     ///
     /// SAFETY: Must verify signature is valid before adding
@@ -348,6 +366,25 @@ impl SnapshotLineage {
         }
     }
 
+    /// Ordered chain of snapshots from `from` (ancestor) to `to` (descendant)
+    ///
+    /// Both endpoints are included. Returns `None` if either snapshot is not
+    /// in the lineage or `to` precedes `from`.
+    pub fn ancestry_path(&self, from: SnapshotId, to: SnapshotId) -> Option<Vec<SnapshotId>> {
+        let start = self.snapshots.iter().position(|&id| id == from)?;
+        let end = self.snapshots.iter().position(|&id| id == to)?;
+        if start > end {
+            return None;
+        }
+
+        Some(self.snapshots.range(start..=end).copied().collect())
+    }
+
+    /// Whether `a` is a strict ancestor of `b`
+    pub fn is_ancestor(&self, a: SnapshotId, b: SnapshotId) -> bool {
+        a != b && self.ancestry_path(a, b).is_some()
+    }
+
     /// Compute hash of lineage
     pub fn compute_hash(&self) -> String {
         let mut hasher = Sha256::new();
@@ -397,8 +434,11 @@ pub struct SnapshotEngine {
     /// Next snapshot ID to assign
     next_snapshot_id: SnapshotId,
 
-    /// Total stake of validators (for quorum calculation)
-    total_validator_stake: u128,
+    /// Registered validator signing keys and their stake (for quorum calculation)
+    validator_stakes: BTreeMap<Vec<u8>, u128>,
+
+    /// Shared epoch source
+    clock: EpochClock,
 }
 
 impl SnapshotEngine {
@@ -412,13 +452,69 @@ impl SnapshotEngine {
             snapshots_per_shard: BTreeMap::new(),
             lineage,
             next_snapshot_id: SnapshotId(genesis_snapshot_id.as_u64() + 1),
-            total_validator_stake: 0,
+            validator_stakes: BTreeMap::new(),
+            clock: EpochClock::default(),
         }
     }
 
-    /// Register validator stake (for quorum calculation)
-    pub fn register_validator_stake(&mut self, stake: u128) {
-        self.total_validator_stake = self.total_validator_stake.saturating_add(stake);
+    /// Attach a shared epoch clock
+    pub fn with_clock(mut self, clock: EpochClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Epoch clock this engine reads from
+    pub fn clock(&self) -> &EpochClock {
+        &self.clock
+    }
+
+    /// Current epoch according to the shared clock
+    pub fn current_epoch(&self) -> EpochId {
+        self.clock.current_epoch_id()
+    }
+
+    /// Whether the current epoch is a snapshot boundary
+    pub fn is_snapshot_epoch(&self) -> bool {
+        self.is_snapshot_boundary(self.clock.current_epoch())
+    }
+
+    /// Whether `epoch` is a snapshot boundary
+    pub fn is_snapshot_boundary(&self, epoch: u64) -> bool {
+        self.config.should_create_snapshot(epoch)
+    }
+
+    /// Create a snapshot at the clock's current epoch
+    pub fn create_snapshot_at_current_epoch(
+        &mut self,
+        shard_id: ShardId,
+        global_height: u64,
+        state_root: ShardStateRoot,
+        transactions_merkle_root: String,
+    ) -> Result<SnapshotId, String> {
+        let epoch_id = self.current_epoch();
+        self.create_snapshot(shard_id, epoch_id, global_height, state_root, transactions_merkle_root)
+    }
+
+    /// Register a validator's signing key and stake (for quorum calculation)
+    pub fn register_validator(&mut self, pubkey: Vec<u8>, stake: u128) -> Result<(), String> {
+        if pubkey.is_empty() {
+            return Err("Validator signing key is empty".to_string());
+        }
+        if stake == 0 {
+            return Err(format!("Validator {} has no stake", hex::encode(&pubkey)));
+        }
+        if self.validator_stakes.contains_key(&pubkey) {
+            return Err(format!("Validator {} already registered", hex::encode(&pubkey)));
+        }
+        self.validator_stakes.insert(pubkey, stake);
+        Ok(())
+    }
+
+    /// Total registered validator stake
+    pub fn total_validator_stake(&self) -> u128 {
+        self.validator_stakes
+            .values()
+            .fold(0u128, |total, stake| total.saturating_add(*stake))
     }
 
     /// Create a new snapshot
@@ -477,17 +573,91 @@ impl SnapshotEngine {
         Ok(snapshot_id)
     }
 
+    /// Record a validator's signature on a snapshot
+    ///
+    /// SAFETY: Only registered validators are accepted, their stake comes from
+    /// the registry, and the signature must verify over the snapshot hash. A
+    /// validator key may sign a snapshot only once, so its stake cannot be
+    /// counted twice towards quorum.
+    pub fn collect_signature(
+        &mut self,
+        snapshot_id: SnapshotId,
+        pubkey: Vec<u8>,
+        signature: Vec<u8>,
+    ) -> Result<(), String> {
+        let stake = *self
+            .validator_stakes
+            .get(&pubkey)
+            .ok_or_else(|| format!("Validator {} is not registered", hex::encode(&pubkey)))?;
+        let snapshot = self
+            .snapshots
+            .get_mut(&snapshot_id)
+            .ok_or_else(|| format!("Snapshot {} not found", snapshot_id.as_u64()))?;
+
+        if snapshot.compute_hash() != snapshot.snapshot_hash {
+            return Err(format!("Snapshot {} hash does not match its contents", snapshot_id.as_u64()));
+        }
+        if !verify_tx_signature(snapshot.signing_bytes(), &signature, &pubkey) {
+            return Err(format!(
+                "Invalid signature from validator {} on snapshot {}",
+                hex::encode(&pubkey),
+                snapshot_id.as_u64()
+            ));
+        }
+
+        if snapshot
+            .validator_signatures
+            .iter()
+            .any(|s| s.validator_pubkey == pubkey)
+        {
+            return Err(format!(
+                "Validator {} already signed snapshot {}",
+                hex::encode(&pubkey),
+                snapshot_id.as_u64()
+            ));
+        }
+
+        snapshot.add_validator_signature(pubkey, signature, stake)
+    }
+
     /// Finalize a snapshot (mark it as on-chain)
+    ///
+    /// SAFETY: Refused unless the collected signatures carry at least
+    /// `min_validator_quorum` of the registered validator stake, and never
+    /// with no registered stake. Signed stake is re-read from the registry.
     pub fn finalize_snapshot(
         &mut self,
         snapshot_id: SnapshotId,
         inclusion_proof: Vec<u8>,
     ) -> Result<(), String> {
+        let total_stake = self.total_validator_stake();
+        if total_stake == 0 {
+            return Err(format!(
+                "Snapshot {} cannot be finalized: no validator stake registered",
+                snapshot_id.as_u64()
+            ));
+        }
         let snapshot = self
             .snapshots
             .get_mut(&snapshot_id)
             .ok_or_else(|| format!("Snapshot {} not found", snapshot_id.as_u64()))?;
 
+        let signed_stake = snapshot
+            .validator_signatures
+            .iter()
+            .filter_map(|s| self.validator_stakes.get(&s.validator_pubkey))
+            .fold(0u128, |total, stake| total.saturating_add(*stake));
+        let quorum_stake = (total_stake as f64 * self.config.min_validator_quorum) as u128;
+        if signed_stake < quorum_stake {
+            return Err(format!(
+                "Snapshot {} lacks quorum: {} of {} stake signed (need {:.2})",
+                snapshot_id.as_u64(),
+                signed_stake,
+                total_stake,
+                self.config.min_validator_quorum
+            ));
+        }
+
         snapshot.finalize(inclusion_proof)?;
 
         // Add to lineage
@@ -569,6 +739,26 @@ impl SnapshotEngine {
         Ok(())
     }
 
+    /// Snapshots of `shard_id` that can be rolled back to at `current_height`
+    ///
+    /// Only finalized snapshots at or below `current_height` and within
+    /// `max_rollback_depth` of it, newest first (same rule as `can_rollback_to`).
+    pub fn rollback_candidates(&self, shard_id: ShardId, current_height: u64) -> Vec<&StateSnapshot> {
+        let mut candidates: Vec<&StateSnapshot> = self
+            .get_snapshots_for_shard(shard_id)
+            .into_iter()
+            .filter(|s| s.global_height <= current_height)
+            .filter(|s| self.can_rollback_to(s.id, current_height).is_ok())
+            .collect();
+
+        candidates.sort_by(|a, b| {
+            b.global_height
+                .cmp(&a.global_height)
+                .then(b.id.as_u64().cmp(&a.id.as_u64()))
+        });
+        candidates
+    }
+
     /// Prune old snapshots beyond retention window
     pub fn prune_old_snapshots(&mut self) -> Result<Vec<SnapshotId>, String> {
         let mut pruned = Vec::new();
@@ -586,6 +776,61 @@ impl SnapshotEngine {
         Ok(pruned)
     }
 
+    /// Prune excess snapshots while preserving the rollback window
+    ///
+    /// Like `prune_old_snapshots`, each shard is trimmed down to
+    /// `max_retained_snapshots`, oldest first, but a snapshot that
+    /// `can_rollback_to` accepts at `current_height` is never dropped, nor is
+    /// one younger than `min_retention_blocks`. A shard may therefore stay
+    /// above the count cap.
+    ///
+    /// SAFETY: Pruning must never remove a valid rollback target.
+    pub fn prune_snapshots_at(&mut self, current_height: u64) -> Result<Vec<SnapshotId>, String> {
+        let max_retained = self.config.max_retained_snapshots as usize;
+        let min_age = self.config.min_retention_blocks;
+        let mut to_prune = Vec::new();
+
+        for snapshot_ids in self.snapshots_per_shard.values() {
+            let mut excess = snapshot_ids.len().saturating_sub(max_retained);
+            for &id in snapshot_ids.iter() {
+                if excess == 0 {
+                    break;
+                }
+                if self.can_rollback_to(id, current_height).is_ok() {
+                    continue;
+                }
+                let too_young = self
+                    .snapshots
+                    .get(&id)
+                    .map_or(false, |s| current_height.saturating_sub(s.global_height) < min_age);
+                if too_young {
+                    continue;
+                }
+                to_prune.push(id);
+                excess -= 1;
+            }
+        }
+
+        let pruned: HashSet<SnapshotId> = to_prune.iter().copied().collect();
+        for snapshot_ids in self.snapshots_per_shard.values_mut() {
+            snapshot_ids.retain(|id| !pruned.contains(id));
+        }
+        for id in &to_prune {
+            self.snapshots.remove(id);
+        }
+
+        if !to_prune.is_empty() {
+            info!(
+                "Pruned {} snapshots at height {} (rollback window {})",
+                to_prune.len(),
+                current_height,
+                self.config.max_rollback_depth
+            );
+        }
+
+        Ok(to_prune)
+    }
+
     /// Get snapshot lineage
     pub fn get_lineage(&self) -> &SnapshotLineage {
         &self.lineage
@@ -597,6 +842,35 @@ impl SnapshotEngine {
     }
 }
 
+/// Test fixture: a single registered validator that can sign snapshots
+#[cfg(test)]
+pub(crate) struct TestSnapshotSigner {
+    pub(crate) pubkey: Vec<u8>,
+    secret_key: Vec<u8>,
+}
+
+#[cfg(test)]
+impl TestSnapshotSigner {
+    /// Generate a keypair and register it with `stake`
+    pub(crate) fn register(engine: &mut SnapshotEngine, stake: u128) -> Self {
+        let (pubkey, secret_key) = bleep_crypto::tx_signer::generate_tx_keypair();
+        engine.register_validator(pubkey.clone(), stake).unwrap();
+        TestSnapshotSigner { pubkey, secret_key }
+    }
+
+    pub(crate) fn sign(&self, engine: &SnapshotEngine, snapshot_id: SnapshotId) -> Vec<u8> {
+        let snapshot = engine.get_snapshot(snapshot_id).unwrap();
+        bleep_crypto::tx_signer::sign_tx_payload(snapshot.signing_bytes(), &self.secret_key).unwrap()
+    }
+
+    /// Sign `snapshot_id` and finalize it
+    pub(crate) fn finalize(&self, engine: &mut SnapshotEngine, snapshot_id: SnapshotId) -> Result<(), String> {
+        let signature = self.sign(engine, snapshot_id);
+        engine.collect_signature(snapshot_id, self.pubkey.clone(), signature)?;
+        engine.finalize_snapshot(snapshot_id, vec![])
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -656,6 +930,7 @@ mod tests {
     fn test_snapshot_lineage() {
         let config = SnapshotConfig::new(10, 100, 1000, 0.66).unwrap();
         let mut engine = SnapshotEngine::new(config, SnapshotId(0));
+        let signer = TestSnapshotSigner::register(&mut engine, 100);
 
         let root = ShardStateRoot {
             root_hash: "test_root".to_string(),
@@ -683,8 +958,8 @@ mod tests {
             )
             .unwrap();
 
-        engine.finalize_snapshot(id1, vec![]).unwrap();
-        engine.finalize_snapshot(id2, vec![]).unwrap();
+        signer.finalize(&mut engine, id1).unwrap();
+        signer.finalize(&mut engine, id2).unwrap();
 
         assert!(engine.get_lineage().contains(id1));
         assert!(engine.get_lineage().contains(id2));
@@ -771,4 +1046,256 @@ mod tests {
         // Should have pruned oldest snapshots beyond retention
         assert!(engine.total_snapshots() <= 2);
     }
+
+    #[test]
+    fn test_snapshot_engine_reads_shared_clock() {
+        let config = SnapshotConfig::new(10, 100, 1000, 0.66).unwrap();
+        let clock = EpochClock::new(9);
+        let mut engine = SnapshotEngine::new(config, SnapshotId(0)).with_clock(clock.clone());
+
+        let root = ShardStateRoot {
+            root_hash: "test_root".to_string(),
+            tx_count: 1,
+            height: 1,
+        };
+
+        assert!(!engine.is_snapshot_epoch());
+        assert!(engine
+            .create_snapshot_at_current_epoch(ShardId(0), 90, root.clone(), "m".to_string())
+            .is_err());
+
+        clock.advance();
+        assert_eq!(engine.current_epoch(), EpochId(10));
+        assert!(engine.is_snapshot_epoch());
+        let id = engine
+            .create_snapshot_at_current_epoch(ShardId(0), 100, root, "m".to_string())
+            .unwrap();
+        assert_eq!(engine.get_snapshot(id).unwrap().epoch_id, EpochId(10));
+    }
+
+    #[test]
+    fn test_rollback_candidates_window_and_finality() {
+        // max_rollback_depth = 250 blocks
+        let config = SnapshotConfig::new(10, 100, 250, 0.66).unwrap();
+        let mut engine = SnapshotEngine::new(config, SnapshotId(0));
+        let signer = TestSnapshotSigner::register(&mut engine, 100);
+
+        let root = ShardStateRoot {
+            root_hash: "test_root".to_string(),
+            tx_count: 100,
+            height: 10,
+        };
+
+        let mut ids = Vec::new();
+        for (epoch, height) in [(10u64, 100u64), (20, 300), (30, 500), (40, 600), (50, 700)] {
+            ids.push(
+                engine
+                    .create_snapshot(ShardId(0), EpochId(epoch), height, root.clone(), format!("merkle{}", epoch))
+                    .unwrap(),
+            );
+        }
+        // Another shard's snapshot never appears
+        let other = engine
+            .create_snapshot(ShardId(1), EpochId(40), 600, root.clone(), "other".to_string())
+            .unwrap();
+
+        // 100: out of window; 300 (distance 300): out of window;
+        // 500 and 700: finalized and in window; 600: in window but unfinalized
+        for &id in &[ids[0], ids[1], ids[2], ids[4], other] {
+            signer.finalize(&mut engine, id).unwrap();
+        }
+
+        let candidates: Vec<SnapshotId> = engine
+            .rollback_candidates(ShardId(0), 600)
+            .iter()
+            .map(|s| s.id)
+            .collect();
+        // 700 is above the current height; 500 is the only eligible snapshot
+        assert_eq!(candidates, vec![ids[2]]);
+
+        let candidates: Vec<SnapshotId> = engine
+            .rollback_candidates(ShardId(0), 720)
+            .iter()
+            .map(|s| s.id)
+            .collect();
+        assert_eq!(candidates, vec![ids[4], ids[2]]);
+
+        assert!(engine.rollback_candidates(ShardId(0), 2_000).is_empty());
+        assert!(engine.rollback_candidates(ShardId(7), 600).is_empty());
+    }
+
+    #[test]
+    fn test_finalize_requires_signature_quorum() {
+        let config = SnapshotConfig::new(10, 100, 1000, 0.66).unwrap();
+        let mut engine = SnapshotEngine::new(config, SnapshotId(0));
+
+        let root = ShardStateRoot {
+            root_hash: "test_root".to_string(),
+            tx_count: 100,
+            height: 10,
+        };
+        let snapshot_id = engine
+            .create_snapshot(ShardId(0), EpochId(10), 100, root, "merkle".to_string())
+            .unwrap();
+
+        // With no registered stake there is no quorum to meet
+        assert!(engine.finalize_snapshot(snapshot_id, vec![]).is_err());
+
+        let signers: Vec<_> = (0..3).map(|_| TestSnapshotSigner::register(&mut engine, 100)).collect();
+        assert_eq!(engine.total_validator_stake(), 300);
+        assert!(engine.register_validator(signers[0].pubkey.clone(), 1_000).is_err());
+
+        // No signatures, then 100 of 300 stake: below the 198 quorum
+        assert!(engine.finalize_snapshot(snapshot_id, vec![]).is_err());
+        let signature = signers[0].sign(&engine, snapshot_id);
+        engine.collect_signature(snapshot_id, signers[0].pubkey.clone(), signature.clone()).unwrap();
+        assert!(engine.finalize_snapshot(snapshot_id, vec![]).is_err());
+
+        // The same validator signing again does not add stake
+        assert!(engine.collect_signature(snapshot_id, signers[0].pubkey.clone(), signature).is_err());
+        assert!(engine.finalize_snapshot(snapshot_id, vec![]).is_err());
+        assert_ne!(engine.get_snapshot(snapshot_id).unwrap().status, SnapshotStatus::Finalized);
+
+        let signature = signers[1].sign(&engine, snapshot_id);
+        engine.collect_signature(snapshot_id, signers[1].pubkey.clone(), signature).unwrap();
+        engine.finalize_snapshot(snapshot_id, vec![]).unwrap();
+        assert_eq!(engine.get_snapshot(snapshot_id).unwrap().status, SnapshotStatus::Finalized);
+        assert!(engine.get_lineage().contains(snapshot_id));
+
+        // Finalized snapshots take no further signatures
+        let signature = signers[2].sign(&engine, snapshot_id);
+        assert!(engine.collect_signature(snapshot_id, signers[2].pubkey.clone(), signature.clone()).is_err());
+        assert!(engine.collect_signature(SnapshotId(99), signers[2].pubkey.clone(), signature).is_err());
+    }
+
+    #[test]
+    fn test_collect_signature_rejects_forged_and_unregistered() {
+        let config = SnapshotConfig::new(10, 100, 1000, 0.66).unwrap();
+        let mut engine = SnapshotEngine::new(config, SnapshotId(0));
+        let signer = TestSnapshotSigner::register(&mut engine, 100);
+
+        let root = ShardStateRoot {
+            root_hash: "test_root".to_string(),
+            tx_count: 100,
+            height: 10,
+        };
+        let snapshot_id = engine
+            .create_snapshot(ShardId(0), EpochId(10), 100, root, "merkle".to_string())
+            .unwrap();
+
+        // A registered key cannot be claimed with someone else's signature
+        let (_, other_sk) = bleep_crypto::tx_signer::generate_tx_keypair();
+        let forged = bleep_crypto::tx_signer::sign_tx_payload(
+            engine.get_snapshot(snapshot_id).unwrap().signing_bytes(),
+            &other_sk,
+        )
+        .unwrap();
+        assert!(engine.collect_signature(snapshot_id, signer.pubkey.clone(), forged).is_err());
+
+        // An unregistered key cannot bring its own stake
+        let (outsider_pk, outsider_sk) = bleep_crypto::tx_signer::generate_tx_keypair();
+        let signature = bleep_crypto::tx_signer::sign_tx_payload(
+            engine.get_snapshot(snapshot_id).unwrap().signing_bytes(),
+            &outsider_sk,
+        )
+        .unwrap();
+        assert!(engine.collect_signature(snapshot_id, outsider_pk, signature).is_err());
+
+        assert!(engine.get_snapshot(snapshot_id).unwrap().validator_signatures.is_empty());
+        assert!(engine.finalize_snapshot(snapshot_id, vec![]).is_err());
+        signer.finalize(&mut engine, snapshot_id).unwrap();
+    }
+
+    #[test]
+    fn test_lineage_ancestry_path() {
+        let mut lineage = SnapshotLineage::new(SnapshotId(0));
+        for id in [2, 5, 9] {
+            lineage.add_snapshot(SnapshotId(id)).unwrap();
+        }
+
+        assert_eq!(
+            lineage.ancestry_path(SnapshotId(2), SnapshotId(9)),
+            Some(vec![SnapshotId(2), SnapshotId(5), SnapshotId(9)])
+        );
+        assert_eq!(lineage.ancestry_path(SnapshotId(5), SnapshotId(5)), Some(vec![SnapshotId(5)]));
+        assert!(lineage.is_ancestor(SnapshotId(0), SnapshotId(9)));
+        assert!(!lineage.is_ancestor(SnapshotId(5), SnapshotId(5)));
+
+        // Reversed direction
+        assert_eq!(lineage.ancestry_path(SnapshotId(9), SnapshotId(2)), None);
+        assert!(!lineage.is_ancestor(SnapshotId(9), SnapshotId(2)));
+
+        // Non-member snapshot
+        assert_eq!(lineage.ancestry_path(SnapshotId(0), SnapshotId(3)), None);
+        assert_eq!(lineage.ancestry_path(SnapshotId(3), SnapshotId(9)), None);
+        assert!(!lineage.is_ancestor(SnapshotId(3), SnapshotId(9)));
+    }
+
+    #[test]
+    fn test_prune_preserves_rollback_window() {
+        // Keep at most 2 snapshots, rollback window of 100 blocks
+        let config = SnapshotConfig::new(10, 2, 100, 0.66).unwrap();
+        let mut engine = SnapshotEngine::new(config, SnapshotId(0));
+        let signer = TestSnapshotSigner::register(&mut engine, 100);
+
+        let mut ids = Vec::new();
+        for height in [100, 200, 450, 480, 500] {
+            let root = ShardStateRoot {
+                root_hash: format!("root_{}", height),
+                tx_count: 10,
+                height,
+            };
+            let id = engine
+                .create_snapshot(ShardId(0), EpochId(10), height, root, "merkle".to_string())
+                .unwrap();
+            signer.finalize(&mut engine, id).unwrap();
+            ids.push(id);
+        }
+
+        // At height 500, snapshots at 450, 480 and 500 are in the window
+        let pruned = engine.prune_snapshots_at(500).unwrap();
+        assert_eq!(pruned, vec![ids[0], ids[1]]);
+        assert!(engine.get_snapshot(ids[0]).is_none());
+        assert!(engine.get_snapshot(ids[1]).is_none());
+
+        // Cap is exceeded, but the in-window snapshot survives
+        assert_eq!(engine.get_snapshots_for_shard(ShardId(0)).len(), 3);
+        assert!(engine.get_snapshot(ids[2]).is_some());
+        assert!(engine.can_rollback_to(ids[2], 500).is_ok());
+
+        // Once the window moves past 450 it becomes prunable
+        let pruned = engine.prune_snapshots_at(560).unwrap();
+        assert_eq!(pruned, vec![ids[2]]);
+        assert_eq!(engine.get_snapshots_for_shard(ShardId(0)).len(), 2);
+    }
+
+    #[test]
+    fn test_prune_respects_min_retention_age() {
+        // Window of 10 blocks, but every snapshot is kept for 300 blocks
+        let config = SnapshotConfig::new(10, 1, 10, 0.66).unwrap().with_min_retention_blocks(300);
+        let mut engine = SnapshotEngine::new(config, SnapshotId(0));
+        let signer = TestSnapshotSigner::register(&mut engine, 100);
+
+        let mut ids = Vec::new();
+        for height in [100, 200, 300] {
+            let root = ShardStateRoot {
+                root_hash: format!("root_{}", height),
+                tx_count: 10,
+                height,
+            };
+            let id = engine
+                .create_snapshot(ShardId(0), EpochId(10), height, root, "merkle".to_string())
+                .unwrap();
+            signer.finalize(&mut engine, id).unwrap();
+            ids.push(id);
+        }
+
+        // Only the snapshot at 100 is at least 300 blocks old
+        let pruned = engine.prune_snapshots_at(400).unwrap();
+        assert_eq!(pruned, vec![ids[0]]);
+        assert!(engine.get_snapshot(ids[1]).is_some());
+
+        let pruned = engine.prune_snapshots_at(500).unwrap();
+        assert_eq!(pruned, vec![ids[1]]);
+    }
 }
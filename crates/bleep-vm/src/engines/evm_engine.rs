@@ -16,6 +16,7 @@ use crate::error::{VmError, VmResult};
 use crate::execution::{
     execution_context::ExecutionContext,
     state_transition::StateDiff,
+    trace::{ExecutionTrace, StepTracer},
 };
 use crate::intent::TargetVm;
 use crate::router::vm_router::{Engine, EngineResult};
@@ -162,6 +163,66 @@ impl EvmEngine {
         }
     }
 
+    /// Execute `bytecode` with `calldata`, optionally recording every step.
+    fn run_call(
+        &self,
+        ctx:       &ExecutionContext,
+        bytecode:  &[u8],
+        calldata:  &[u8],
+        gas_limit: u64,
+        tracer:    Option<&mut StepTracer>,
+    ) -> VmResult<EngineResult> {
+        let start = Instant::now();
+
+        let contract_addr = Self::to_evm_address(&ctx.tx.caller);
+        let to = TransactTo::Call(contract_addr);
+
+        let mut db = CacheDB::new(EmptyDB::default());
+        self.populate_db(&mut db);
+
+        if !bytecode.is_empty() {
+            use sha3::{Digest, Keccak256};
+            let code_hash = B256::from_slice(&Keccak256::digest(bytecode));
+            db.insert_account_info(contract_addr, AccountInfo {
+                balance:   U256::from(ctx.tx.value),
+                nonce:     0,
+                code_hash,
+                code:      Some(Bytecode::new_raw(Bytes::from(bytecode.to_vec()))),
+            });
+        }
+
+        let value         = U256::from(ctx.tx.value);
+        let calldata_bytes = Bytes::from(calldata.to_vec());
+
+        let mut evm = self.build_evm(ctx, db, to, calldata_bytes, value, gas_limit);
+
+        let revm_result = match tracer {
+            Some(tracer) => evm.inspect_commit(tracer),
+            None         => evm.transact_commit(),
+        }
+        .map_err(|e| VmError::ExecutionFailed(format!("revm error: {e:?}")))?;
+
+        let diff = Self::extract_state_diff(contract_addr, &revm_result, calldata);
+        Ok(Self::convert_result(revm_result, diff, start))
+    }
+
+    /// Execute like [`Engine::execute`] and also return a step-by-step trace
+    /// (opcode, stack, gas remaining) for debugging.
+    ///
+    /// Tracing never changes the outcome: the returned result matches an
+    /// untraced `execute` of the same call.
+    pub fn execute_traced(
+        &self,
+        ctx:       &ExecutionContext,
+        bytecode:  &[u8],
+        calldata:  &[u8],
+        gas_limit: u64,
+    ) -> VmResult<(EngineResult, ExecutionTrace)> {
+        let mut tracer = StepTracer::new();
+        let result = self.run_call(ctx, bytecode, calldata, gas_limit, Some(&mut tracer))?;
+        Ok((result, tracer.into_trace()))
+    }
+
     fn convert_result(
         revm_result: RevmResult,
         state_diff:  StateDiff,
@@ -269,36 +330,7 @@ impl Engine for EvmEngine {
         calldata:  &[u8],
         gas_limit: u64,
     ) -> VmResult<EngineResult> {
-        let start = Instant::now();
-
-        let contract_addr = Self::to_evm_address(&ctx.tx.caller);
-        let to = TransactTo::Call(contract_addr);
-
-        let mut db = CacheDB::new(EmptyDB::default());
-        self.populate_db(&mut db);
-
-        if !bytecode.is_empty() {
-            use sha3::{Digest, Keccak256};
-            let code_hash = B256::from_slice(&Keccak256::digest(bytecode));
-            db.insert_account_info(contract_addr, AccountInfo {
-                balance:   U256::from(ctx.tx.value),
-                nonce:     0,
-                code_hash,
-                code:      Some(Bytecode::new_raw(Bytes::from(bytecode.to_vec()))),
-            });
-        }
-
-        let value         = U256::from(ctx.tx.value);
-        let calldata_bytes = Bytes::from(calldata.to_vec());
-
-        let mut evm = self.build_evm(ctx, db, to, calldata_bytes, value, gas_limit);
-
-        let revm_result = evm
-            .transact_commit()
-            .map_err(|e| VmError::ExecutionFailed(format!("revm error: {e:?}")))?;
-
-        let diff   = Self::extract_state_diff(contract_addr, &revm_result, calldata);
-        let result = Self::convert_result(revm_result, diff, start);
+        let result = self.run_call(ctx, bytecode, calldata, gas_limit, None)?;
 
         debug!(
             success  = result.success,
@@ -310,6 +342,16 @@ impl Engine for EvmEngine {
         Ok(result)
     }
 
+    async fn execute_with_trace(
+        &self,
+        ctx:       &ExecutionContext,
+        bytecode:  &[u8],
+        calldata:  &[u8],
+        gas_limit: u64,
+    ) -> VmResult<(EngineResult, ExecutionTrace)> {
+        EvmEngine::execute_traced(self, ctx, bytecode, calldata, gas_limit)
+    }
+
     #[instrument(skip(self, ctx, bytecode, init_args), fields(engine = "evm-revm"))]
     async fn deploy(
         &self,
@@ -437,6 +479,80 @@ mod tests {
         assert!(reason.contains("no reason"));
     }
 
+    #[tokio::test]
+    async fn test_execute_traced_records_each_step() {
+        // PUSH1 0x42  PUSH1 0x00  MSTORE  PUSH1 0x20  PUSH1 0x00  RETURN
+        let bytecode = hex::decode("604260005260206000f3").unwrap();
+        let engine   = EvmEngine::new();
+        let ctx      = test_ctx(100_000);
+
+        let (result, trace) = engine.execute_traced(&ctx, &bytecode, &[], 100_000).unwrap();
+        assert!(result.success);
+        assert_eq!(
+            trace.op_names(),
+            vec!["PUSH1", "PUSH1", "MSTORE", "PUSH1", "PUSH1", "RETURN"]
+        );
+        assert_eq!(trace.steps[0].pc, 0);
+        assert!(trace.steps[0].stack.is_empty());
+        assert_eq!(trace.steps[2].stack, vec!["0x42".to_string(), "0x0".to_string()]);
+        assert!(trace.steps.windows(2).all(|w| w[0].gas_remaining > w[1].gas_remaining));
+
+        let (_, replay) = engine.execute_traced(&ctx, &bytecode, &[], 100_000).unwrap();
+        assert_eq!(trace, replay);
+    }
+
+    #[tokio::test]
+    async fn test_trace_records_step_deltas() {
+        // PUSH1 0x42  PUSH1 0x00  MSTORE  PUSH1 0x20  PUSH1 0x00  RETURN
+        let bytecode = hex::decode("604260005260206000f3").unwrap();
+        let engine   = EvmEngine::new();
+        let ctx      = test_ctx(100_000);
+
+        let (_, trace) = engine.execute_traced(&ctx, &bytecode, &[], 100_000).unwrap();
+        let mstore = &trace.steps[2];
+        assert_eq!(mstore.stack.len(), 2);
+        assert_eq!(mstore.stack_after, 0);
+        assert_eq!((mstore.memory_size, mstore.memory_after), (0, 32));
+        assert!(mstore.gas_cost() > 0);
+        assert!(trace.steps.windows(2).all(|w| w[0].gas_after == w[1].gas_remaining));
+        assert!(trace.trapped_step().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_trace_shows_trapping_step() {
+        // PUSH1 0x00  PUSH1 0x00  REVERT
+        let bytecode = hex::decode("60006000fd").unwrap();
+        let engine   = EvmEngine::new();
+        let ctx      = test_ctx(100_000);
+
+        let (result, trace) = engine.execute_traced(&ctx, &bytecode, &[], 100_000).unwrap();
+        assert!(!result.success);
+        let trapped = trace.trapped_step().unwrap();
+        assert_eq!(trapped.op_name, "REVERT");
+        assert_eq!(trapped.pc, 4);
+        assert_eq!(trapped.trap.as_deref(), Some("Revert"));
+
+        // PUSH1 0x01  INVALID
+        let bytecode = hex::decode("6001fe").unwrap();
+        let (_, trace) = engine.execute_traced(&ctx, &bytecode, &[], 100_000).unwrap();
+        let trapped = trace.trapped_step().unwrap();
+        assert_eq!((trapped.pc, trapped.opcode), (2, 0xfe));
+        assert_eq!(trapped.trap.as_deref(), Some("InvalidFEOpcode"));
+    }
+
+    #[tokio::test]
+    async fn test_traced_result_matches_untraced() {
+        let bytecode = hex::decode("604260005260206000f3").unwrap();
+        let engine   = EvmEngine::new();
+        let ctx      = test_ctx(100_000);
+
+        let plain       = engine.execute(&ctx, &bytecode, &[], 100_000).await.unwrap();
+        let (traced, _) = engine.execute_traced(&ctx, &bytecode, &[], 100_000).unwrap();
+        assert_eq!(plain.success, traced.success);
+        assert_eq!(plain.output, traced.output);
+        assert_eq!(plain.gas_used, traced.gas_used);
+    }
+
     #[test]
     fn test_create_address_deterministic() {
         let sender = Address::from([0x01u8; 20]);
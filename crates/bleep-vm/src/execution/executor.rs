@@ -27,15 +27,17 @@ use crate::crosschain::native_bridge::ConnectBridge;
 use crate::engines::evm_engine::EvmEngine;
 use crate::engines::wasm_engine_adapter::WasmEngineAdapter;
 use crate::engines::zk_engine_adapter::ZkEngineAdapter;
-use crate::error::VmResult;
+use crate::error::{VmError, VmResult};
+use crate::execution::quantum_hints::{self, HintViolation, ParallelHint, ScheduleMode};
 use crate::execution::state_transition::{StateDiff, StateTransition};
+use crate::execution::trace::ExecutionTrace;
 use crate::intent::{Intent, IntentKind};
 use crate::router::vm_router::{RouterConfig, RoutedResult, VmRouter};
 use crate::runtime::gas_model::GasModel;
 
 use std::sync::Arc;
 use std::time::{Duration, Instant};
-use tracing::{info, instrument};
+use tracing::{info, instrument, warn};
 
 // ─────────────────────────────────────────────────────────────────────────────
 // EXECUTOR CONFIG
@@ -48,6 +50,8 @@ pub struct ExecutorConfig {
     pub block_number: u64,
     /// Whether to produce `StateTransition` objects for the state layer.
     pub emit_transitions: bool,
+    /// Record a per-step `ExecutionTrace` for each execution (debugging).
+    pub tracing: bool,
 }
 
 impl Default for ExecutorConfig {
@@ -56,6 +60,7 @@ impl Default for ExecutorConfig {
             router:           RouterConfig::default(),
             block_number:     1,
             emit_transitions: true,
+            tracing:          false,
         }
     }
 }
@@ -84,6 +89,21 @@ impl ExecutionOutcome {
     pub fn state_diff(&self) -> &StateDiff {
         &self.routed_result.result.state_diff
     }
+
+    /// Step-by-step trace; empty unless `ExecutorConfig::tracing` is set.
+    pub fn trace(&self) -> &ExecutionTrace {
+        &self.routed_result.trace
+    }
+}
+
+/// Outcome of a hinted batch execution.
+pub struct HintedOutcome {
+    /// Per-intent outcomes, in batch order.
+    pub outcomes: Vec<ExecutionOutcome>,
+    /// All per-intent diffs merged in batch order.
+    pub diff:     StateDiff,
+    /// Whether the hint was honoured.
+    pub mode:     ScheduleMode,
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -103,7 +123,9 @@ pub struct Executor {
 impl Executor {
     /// Build a production executor with all engines registered.
     pub fn production(config: ExecutorConfig) -> Self {
-        let mut router = VmRouter::new(config.router.clone());
+        let mut router_config = config.router.clone();
+        router_config.step_tracing |= config.tracing;
+        let mut router = VmRouter::new(router_config);
 
         // Register Layer 3 engines
         router.register_engine(Arc::new(WasmEngineAdapter::new()));
@@ -121,6 +143,13 @@ impl Executor {
     /// Execute one intent end-to-end through all 7 layers.
     #[instrument(skip(self, intent), fields(intent_id = %intent.id))]
     pub async fn execute(&self, intent: &Intent) -> VmResult<ExecutionOutcome> {
+        self.execute_routed(intent, false).await
+    }
+
+    /// Shared body of `execute`. With `dry_run` the router neither updates
+    /// metrics nor circuit breakers. Cross-chain intents always go to the
+    /// bridge, so callers must not dry-run them.
+    async fn execute_routed(&self, intent: &Intent, dry_run: bool) -> VmResult<ExecutionOutcome> {
         let start = Instant::now();
 
         // Handle cross-chain intents specially (Layer 7)
@@ -153,6 +182,7 @@ impl Executor {
                     exec_time:     start.elapsed(),
                 },
                 bleep_gas,
+                trace:       ExecutionTrace::default(),
             };
             return Ok(ExecutionOutcome {
                 routed_result: result,
@@ -163,7 +193,11 @@ impl Executor {
         }
 
         // All other intents go through the router (Layers 2-6)
-        let routed = self.router.route(intent).await?;
+        let routed = if dry_run {
+            self.router.route_dry_run(intent).await?
+        } else {
+            self.router.route(intent).await?
+        };
         let bleep_gas = routed.bleep_gas;
 
         let transition = if self.config.emit_transitions {
@@ -202,6 +236,102 @@ impl Executor {
         Ok(outcome)
     }
 
+    /// Execute a batch, running the regions of `hint` concurrently.
+    ///
+    /// The hint is validated against the diffs the regions actually produce
+    /// (see `quantum_hints`). If it is not a partition of the batch, if any
+    /// two regions write the same state, if a region reads state another
+    /// region writes, or if the batch contains cross-chain
+    /// intents, the speculative results are discarded and the batch is
+    /// executed sequentially instead. Either way the merged diff equals that
+    /// of a sequential run.
+    ///
+    /// Regions run as router dry runs; routing metrics are committed once,
+    /// after the hint is accepted, so a rejected hint is not counted twice.
+    pub async fn execute_hinted(
+        &self,
+        intents: &[Intent],
+        hint:    &ParallelHint,
+    ) -> VmResult<HintedOutcome> {
+        if let Err(reason) = quantum_hints::check_partition(hint, intents.len()) {
+            return self.execute_sequential(intents, reason).await;
+        }
+        if intents.iter().any(|i| matches!(i.kind, IntentKind::CrossChain(_))) {
+            return self.execute_sequential(intents, HintViolation::CrossChainInBatch).await;
+        }
+
+        let regions = hint.regions.iter().map(|region| async move {
+            let mut results = Vec::with_capacity(region.len());
+            for &idx in region {
+                results.push((idx, self.execute_routed(&intents[idx], true).await?));
+            }
+            VmResult::Ok(results)
+        });
+
+        let mut slots: Vec<Option<ExecutionOutcome>> = intents.iter().map(|_| None).collect();
+        for region in futures::future::join_all(regions).await {
+            for (idx, outcome) in region? {
+                slots[idx] = Some(outcome);
+            }
+        }
+        let outcomes: Vec<ExecutionOutcome> = slots.into_iter().flatten().collect();
+
+        let diffs: Vec<StateDiff> = outcomes.iter().map(|o| o.state_diff().clone()).collect();
+        if let Err(reason) = quantum_hints::validate_hint(hint, intents, &diffs) {
+            warn!(%reason, "Parallel hint rejected, re-executing sequentially");
+            return self.execute_sequential(intents, reason).await;
+        }
+
+        for outcome in &outcomes {
+            self.router.record_metrics(&outcome.routed_result).await;
+        }
+        let mut diff = StateDiff::empty();
+        for d in diffs {
+            diff.merge(d);
+        }
+        Ok(HintedOutcome { outcomes, diff, mode: ScheduleMode::Parallel })
+    }
+
+    async fn execute_sequential(
+        &self,
+        intents: &[Intent],
+        reason:  HintViolation,
+    ) -> VmResult<HintedOutcome> {
+        let mut outcomes = Vec::with_capacity(intents.len());
+        let mut diff = StateDiff::empty();
+        for intent in intents {
+            let outcome = self.execute(intent).await?;
+            diff.merge(outcome.state_diff().clone());
+            outcomes.push(outcome);
+        }
+        Ok(HintedOutcome { outcomes, diff, mode: ScheduleMode::Sequential { reason } })
+    }
+
+    /// Estimate the BLEEP gas an intent would consume, without side effects.
+    ///
+    /// The intent is executed exactly as `execute` would run it, so storage
+    /// reads and writes are charged by the engine as usual, but the resulting
+    /// `StateDiff` is discarded, no `StateTransition` is produced and routing
+    /// metrics are not updated. Cross-chain intents are submitted to a scratch
+    /// bridge so no message is queued for relay.
+    ///
+    /// Returns `ExecutionFailed` if the intent would revert.
+    pub async fn estimate_gas(&self, intent: &Intent) -> VmResult<u64> {
+        if let IntentKind::CrossChain(ref x) = intent.kind {
+            let scratch = ConnectBridge::new();
+            let (_, diff) = scratch.submit(x, &intent.signer).await?;
+            return Ok(diff.gas_charged);
+        }
+
+        let routed = self.router.route_dry_run(intent).await?;
+        if !routed.result.success {
+            let reason = routed.result.revert_reason
+                .unwrap_or_else(|| "execution reverted".into());
+            return Err(VmError::ExecutionFailed(reason));
+        }
+        Ok(routed.bleep_gas)
+    }
+
     /// Return the cross-chain bridge (for status queries).
     pub fn bridge(&self) -> &ConnectBridge {
         &self.bridge
@@ -276,4 +406,147 @@ mod tests {
         let m = exec.metrics().await;
         assert_eq!(m.total_intents, 5);
     }
+
+    #[tokio::test]
+    async fn test_estimate_gas_matches_execution() {
+        let exec = test_executor();
+        let intent = Intent::new_unsigned(
+            IntentKind::Transfer(TransferIntent {
+                from: [3u8; 32], to: [4u8; 32], amount: 500, memo: None,
+            }),
+            ChainId::Bleep,
+        );
+        let estimate = exec.estimate_gas(&intent).await.unwrap();
+        let outcome = exec.execute(&intent).await.unwrap();
+        assert_eq!(estimate, outcome.bleep_gas);
+    }
+
+    #[tokio::test]
+    async fn test_estimate_gas_has_no_side_effects() {
+        use crate::intent::CrossChainIntent;
+
+        let exec = test_executor();
+        let transfer = Intent::new_unsigned(
+            IntentKind::Transfer(TransferIntent {
+                from: [0u8; 32], to: [1u8; 32], amount: 7, memo: None,
+            }),
+            ChainId::Bleep,
+        );
+        exec.estimate_gas(&transfer).await.unwrap();
+        let m = exec.metrics().await;
+        assert_eq!(m.total_intents, 0);
+        assert_eq!(m.total_gas_used, 0);
+
+        let cross = Intent::new_unsigned(
+            IntentKind::CrossChain(CrossChainIntent {
+                destination_chain: ChainId::Ethereum,
+                contract:          vec![0xABu8; 20],
+                calldata:          vec![0x12, 0x34],
+                source_gas_limit:  100_000,
+                dest_gas_limit:    200_000,
+                bridge_value:      0,
+                relay_fee:         1_000,
+                require_zk_proof:  false,
+            }),
+            ChainId::Bleep,
+        );
+        let estimate = exec.estimate_gas(&cross).await.unwrap();
+        assert!(exec.bridge().pending_for_chain(&ChainId::Ethereum).is_empty());
+
+        let outcome = exec.execute(&cross).await.unwrap();
+        assert_eq!(estimate, outcome.bleep_gas);
+        assert_eq!(exec.bridge().pending_for_chain(&ChainId::Ethereum).len(), 1);
+    }
+
+    fn evm_deploy(bytecode: &str) -> Intent {
+        use crate::intent::{DeployIntent, TargetVm};
+        Intent::new_unsigned(
+            IntentKind::Deploy(DeployIntent {
+                target_vm: TargetVm::Evm,
+                bytecode:  hex::decode(bytecode).unwrap(),
+                init_args: Vec::new(),
+                gas_limit: 100_000,
+                salt:      None,
+                abi:       None,
+            }),
+            ChainId::Ethereum,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_tracing_is_opt_in_and_does_not_change_results() {
+        // PUSH1 0x00  PUSH1 0x00  REVERT
+        let intent = evm_deploy("60006000fd");
+
+        let plain = test_executor().execute(&intent).await.unwrap();
+        assert!(plain.trace().is_empty());
+
+        let mut cfg = ExecutorConfig::default();
+        cfg.router.verify_signatures  = false;
+        cfg.router.sandbox_validation = false;
+        cfg.tracing = true;
+        let traced = Executor::production(cfg).execute(&intent).await.unwrap();
+
+        assert_eq!(traced.trace().op_names(), vec!["PUSH1", "PUSH1", "REVERT"]);
+        assert_eq!(traced.trace().trapped_step().unwrap().pc, 4);
+        assert_eq!(plain.success(), traced.success());
+        assert!(!traced.success());
+        assert_eq!(plain.bleep_gas, traced.bleep_gas);
+        assert_eq!(plain.output(), traced.output());
+    }
+
+    fn transfer(from: u8, to: u8) -> Intent {
+        Intent::new_unsigned(
+            IntentKind::Transfer(TransferIntent {
+                from: [from; 32], to: [to; 32], amount: 10, memo: None,
+            }),
+            ChainId::Bleep,
+        )
+    }
+
+    async fn sequential_root(exec: &Executor, intents: &[Intent]) -> [u8; 32] {
+        let mut diff = StateDiff::empty();
+        for intent in intents {
+            diff.merge(exec.execute(intent).await.unwrap().state_diff().clone());
+        }
+        diff.commitment_hash()
+    }
+
+    #[tokio::test]
+    async fn test_disjoint_hint_runs_parallel_with_sequential_result() {
+        let exec = test_executor();
+        let batch = vec![transfer(1, 2), transfer(3, 4), transfer(5, 6)];
+        let hint = ParallelHint::new(vec![vec![1], vec![0, 2]]);
+
+        let hinted = exec.execute_hinted(&batch, &hint).await.unwrap();
+        assert_eq!(hinted.mode, ScheduleMode::Parallel);
+        assert_eq!(hinted.outcomes.len(), 3);
+        assert_eq!(exec.metrics().await.total_intents, 3);
+        assert_eq!(hinted.diff.commitment_hash(), sequential_root(&exec, &batch).await);
+    }
+
+    #[tokio::test]
+    async fn test_false_disjointness_hint_falls_back_to_sequential() {
+        let exec = test_executor();
+        // Both transfers touch account 2
+        let batch = vec![transfer(1, 2), transfer(2, 3)];
+        let hint = ParallelHint::new(vec![vec![0], vec![1]]);
+
+        let hinted = exec.execute_hinted(&batch, &hint).await.unwrap();
+        assert!(matches!(
+            hinted.mode,
+            ScheduleMode::Sequential { reason: HintViolation::OverlappingWrites { .. } }
+        ));
+        // The discarded speculative pass is not counted
+        assert_eq!(exec.metrics().await.total_intents, 2);
+        assert_eq!(hinted.diff.commitment_hash(), sequential_root(&exec, &batch).await);
+
+        // A hint that is not a partition is rejected before execution
+        let bad = ParallelHint::new(vec![vec![0]]);
+        let hinted = exec.execute_hinted(&batch, &bad).await.unwrap();
+        assert!(matches!(
+            hinted.mode,
+            ScheduleMode::Sequential { reason: HintViolation::NotAPartition { .. } }
+        ));
+    }
 }
@@ -0,0 +1,293 @@
+//! # Quantum Hints — hint-driven parallel scheduling
+//!
+//! A caller may annotate a batch of intents with a `ParallelHint`: a
+//! partition of the batch into regions that it claims are side-effect
+//! disjoint. The executor runs the regions concurrently, then checks the
+//! claim against the write footprint of every resulting `StateDiff`.
+//!
+//! ```text
+//! batch + hint
+//!      │
+//!      ▼
+//! run regions concurrently ──► per-intent StateDiffs
+//!      │
+//!      ▼
+//! validate_hint ── ok ──────► merge diffs in batch order
+//!      │
+//!   violated
+//!      ▼
+//! discard, re-run batch sequentially
+//! ```
+//!
+//! Hints are advisory only. A dishonest or wrong hint can cost a
+//! re-execution, but it can never change the merged result: accepted hints
+//! are merged in original batch order, exactly as a sequential run would be.
+//!
+//! Besides write-write overlap, a region may not read state another region
+//! writes. Intents do not see each other's balance, storage or nonce writes
+//! (each diff is computed against the pre-batch state), but a contract call
+//! does read the code an earlier deploy in the batch installed, so calls
+//! carry a read footprint.
+//!
+//! Regions partition a batch of intents, not instructions inside a single
+//! contract: the VM engines expose no per-instruction state footprint to
+//! validate a finer-grained hint against.
+
+use crate::execution::state_transition::StateDiff;
+use crate::intent::{Intent, IntentKind};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::fmt;
+
+// ─────────────────────────────────────────────────────────────────────────────
+// HINT
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Claimed partition of a batch into mutually independent regions.
+///
+/// Each region lists intent indices into the batch, in execution order.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ParallelHint {
+    pub regions: Vec<Vec<usize>>,
+}
+
+impl ParallelHint {
+    pub fn new(regions: Vec<Vec<usize>>) -> Self {
+        ParallelHint { regions }
+    }
+}
+
+/// How a hinted batch was actually executed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ScheduleMode {
+    /// The hint held; regions ran concurrently.
+    Parallel,
+    /// The hint was rejected; the batch ran in order.
+    Sequential { reason: HintViolation },
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// FOOTPRINT
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// One piece of state an intent writes.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum StateKey {
+    Storage { contract: [u8; 32], slot: [u8; 32] },
+    Balance([u8; 32]),
+    Nonce([u8; 32]),
+    Code([u8; 32]),
+}
+
+/// Every state key written by `diff`.
+///
+/// Events are not part of the footprint: they are append-only and merged
+/// in batch order, so they cannot conflict.
+pub fn write_footprint(diff: &StateDiff) -> BTreeSet<StateKey> {
+    let mut keys = BTreeSet::new();
+    for (contract, slot) in diff.storage.keys() {
+        keys.insert(StateKey::Storage { contract: *contract, slot: *slot });
+    }
+    for account in diff.balances.keys() {
+        keys.insert(StateKey::Balance(*account));
+    }
+    for account in diff.nonces.keys() {
+        keys.insert(StateKey::Nonce(*account));
+    }
+    for deployment in &diff.code {
+        keys.insert(StateKey::Code(deployment.address));
+    }
+    keys
+}
+
+/// Every state key `intent` reads that another intent in its batch could
+/// have written: the code of a called contract.
+pub fn read_footprint(intent: &Intent) -> BTreeSet<StateKey> {
+    let mut keys = BTreeSet::new();
+    if let IntentKind::ContractCall(call) = &intent.kind {
+        keys.insert(StateKey::Code(call.contract));
+    }
+    keys
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// VALIDATION
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Reason a hint was rejected.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HintViolation {
+    /// An index is out of range, repeated, or missing from every region.
+    NotAPartition { batch_len: usize },
+    /// Two regions wrote the same state key.
+    OverlappingWrites { first: usize, second: usize, key: StateKey },
+    /// One region read a state key another region wrote.
+    ReadWriteConflict { reader: usize, writer: usize, key: StateKey },
+    /// The batch contains cross-chain intents, whose bridge submission
+    /// order is externally observable and cannot be reordered.
+    CrossChainInBatch,
+}
+
+impl fmt::Display for HintViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HintViolation::NotAPartition { batch_len } => {
+                write!(f, "hint regions do not partition a batch of {batch_len} intents")
+            }
+            HintViolation::OverlappingWrites { first, second, key } => {
+                write!(f, "regions {first} and {second} both write {key:?}")
+            }
+            HintViolation::ReadWriteConflict { reader, writer, key } => {
+                write!(f, "region {reader} reads {key:?}, which region {writer} writes")
+            }
+            HintViolation::CrossChainInBatch => {
+                write!(f, "batch contains cross-chain intents")
+            }
+        }
+    }
+}
+
+/// Check that `hint` partitions `0..batch_len` exactly.
+pub fn check_partition(hint: &ParallelHint, batch_len: usize) -> Result<(), HintViolation> {
+    let mut seen = vec![false; batch_len];
+    for &idx in hint.regions.iter().flatten() {
+        if idx >= batch_len || seen[idx] {
+            return Err(HintViolation::NotAPartition { batch_len });
+        }
+        seen[idx] = true;
+    }
+    if seen.iter().all(|s| *s) {
+        Ok(())
+    } else {
+        Err(HintViolation::NotAPartition { batch_len })
+    }
+}
+
+/// Validate a hint against the diffs its batch actually produced.
+///
+/// `diffs[i]` is the diff of `intents[i]`. The hint holds only if it is a
+/// partition, no state key is written by more than one region, and no
+/// region reads a key another region writes.
+pub fn validate_hint(
+    hint:    &ParallelHint,
+    intents: &[Intent],
+    diffs:   &[StateDiff],
+) -> Result<(), HintViolation> {
+    check_partition(hint, diffs.len())?;
+    if intents.len() != diffs.len() {
+        return Err(HintViolation::NotAPartition { batch_len: intents.len() });
+    }
+
+    let writes: Vec<BTreeSet<StateKey>> = hint
+        .regions
+        .iter()
+        .map(|region| {
+            region.iter().flat_map(|&i| write_footprint(&diffs[i])).collect()
+        })
+        .collect();
+    let reads: Vec<BTreeSet<StateKey>> = hint
+        .regions
+        .iter()
+        .map(|region| region.iter().flat_map(|&i| read_footprint(&intents[i])).collect())
+        .collect();
+
+    for (first, a) in writes.iter().enumerate() {
+        for (offset, b) in writes[first + 1..].iter().enumerate() {
+            if let Some(key) = a.intersection(b).next() {
+                return Err(HintViolation::OverlappingWrites {
+                    first,
+                    second: first + 1 + offset,
+                    key:    key.clone(),
+                });
+            }
+        }
+    }
+    for (reader, read) in reads.iter().enumerate() {
+        for (writer, write) in writes.iter().enumerate() {
+            if reader == writer {
+                continue;
+            }
+            if let Some(key) = read.intersection(write).next() {
+                return Err(HintViolation::ReadWriteConflict { reader, writer, key: key.clone() });
+            }
+        }
+    }
+    Ok(())
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// TESTS
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::intent::{ContractCallIntent, TargetVm, TransferIntent};
+    use crate::types::ChainId;
+    use std::collections::HashMap;
+
+    fn transfer_intent(from: u8, to: u8) -> Intent {
+        Intent::new_unsigned(
+            IntentKind::Transfer(TransferIntent { from: [from; 32], to: [to; 32], amount: 1, memo: None }),
+            ChainId::Bleep,
+        )
+    }
+
+    fn call_intent(contract: u8) -> Intent {
+        Intent::new_unsigned(
+            IntentKind::ContractCall(ContractCallIntent {
+                target_vm: TargetVm::Evm,
+                contract:  [contract; 32],
+                calldata:  vec![],
+                gas_limit: 100_000,
+                value:     0,
+                hints:     HashMap::new(),
+            }),
+            ChainId::Bleep,
+        )
+    }
+
+    fn transfer(from: u8, to: u8) -> StateDiff {
+        let mut d = StateDiff::empty();
+        d.add_balance_update([from; 32], -1);
+        d.add_balance_update([to; 32], 1);
+        d
+    }
+
+    #[test]
+    fn test_partition_rejects_gaps_and_repeats() {
+        assert!(check_partition(&ParallelHint::new(vec![vec![0, 2], vec![1]]), 3).is_ok());
+        assert!(check_partition(&ParallelHint::new(vec![vec![0], vec![2]]), 3).is_err());
+        assert!(check_partition(&ParallelHint::new(vec![vec![0, 1], vec![1, 2]]), 3).is_err());
+        assert!(check_partition(&ParallelHint::new(vec![vec![0, 1, 3]]), 3).is_err());
+    }
+
+    #[test]
+    fn test_overlapping_writes_detected() {
+        let intents = vec![transfer_intent(1, 2), transfer_intent(3, 4), transfer_intent(2, 5)];
+        let diffs = vec![transfer(1, 2), transfer(3, 4), transfer(2, 5)];
+        assert!(validate_hint(&ParallelHint::new(vec![vec![0, 2], vec![1]]), &intents, &diffs).is_ok());
+
+        let err = validate_hint(&ParallelHint::new(vec![vec![0, 1], vec![2]]), &intents, &diffs).unwrap_err();
+        assert_eq!(
+            err,
+            HintViolation::OverlappingWrites { first: 0, second: 1, key: StateKey::Balance([2; 32]) }
+        );
+    }
+
+    #[test]
+    fn test_call_reading_deployed_code_conflicts() {
+        let mut deployed = StateDiff::empty();
+        deployed.deploy_code([7; 32], vec![0x60, 0x00]);
+        let intents = vec![transfer_intent(1, 2), call_intent(7)];
+        let diffs = vec![deployed, StateDiff::empty()];
+
+        assert_eq!(
+            validate_hint(&ParallelHint::new(vec![vec![0], vec![1]]), &intents, &diffs),
+            Err(HintViolation::ReadWriteConflict { reader: 1, writer: 0, key: StateKey::Code([7; 32]) })
+        );
+        // In one region the call runs after the deploy, as it would sequentially
+        assert!(validate_hint(&ParallelHint::new(vec![vec![0, 1]]), &intents, &diffs).is_ok());
+    }
+}
@@ -0,0 +1,133 @@
+//! # Execution Trace
+//!
+//! Step-by-step record of an EVM execution for debugging failing contracts.
+//! Each step captures the opcode about to run, the stack before it runs, and
+//! the gas remaining at that point (EIP-3155 ordering).
+//!
+//! Once the opcode has run, the step is completed with the gas remaining,
+//! stack depth and memory size afterwards, and with the instruction result
+//! if the opcode reverted or halted with an error.
+//!
+//! Traces contain no wall-clock data, so replaying the same transaction
+//! against the same state always yields an identical trace.
+
+use revm::interpreter::{InstructionResult, Interpreter, OpCode};
+use revm::primitives::db::Database;
+use revm::{EVMData, Inspector};
+use serde::{Deserialize, Serialize};
+
+/// One interpreter step.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TraceStep {
+    /// Call depth (0 = top-level frame).
+    pub depth:         u64,
+    /// Program counter within the current frame's bytecode.
+    pub pc:            usize,
+    /// Raw opcode byte.
+    pub opcode:        u8,
+    /// Mnemonic, e.g. `PUSH1`.
+    pub op_name:       String,
+    /// Stack before the opcode executes, bottom first, as `0x`-hex words.
+    pub stack:         Vec<String>,
+    /// Gas remaining before the opcode executes.
+    pub gas_remaining: u64,
+    /// Gas remaining after the opcode executes.
+    pub gas_after:     u64,
+    /// Stack depth after the opcode executes.
+    pub stack_after:   usize,
+    /// Memory size in bytes before the opcode executes.
+    pub memory_size:   usize,
+    /// Memory size in bytes after the opcode executes.
+    pub memory_after:  usize,
+    /// Instruction result if this opcode reverted or trapped, e.g. `Revert`
+    /// or `OutOfGas`. `None` for opcodes that continued or returned normally.
+    pub trap:          Option<String>,
+}
+
+impl TraceStep {
+    /// Gas charged for this opcode (for `CALL`-like opcodes this includes
+    /// the gas consumed by the nested frame).
+    pub fn gas_cost(&self) -> u64 {
+        self.gas_remaining.saturating_sub(self.gas_after)
+    }
+}
+
+/// Ordered list of steps for one execution.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExecutionTrace {
+    pub steps: Vec<TraceStep>,
+}
+
+impl ExecutionTrace {
+    pub fn len(&self) -> usize { self.steps.len() }
+
+    pub fn is_empty(&self) -> bool { self.steps.is_empty() }
+
+    /// Opcode mnemonics in execution order.
+    pub fn op_names(&self) -> Vec<&str> {
+        self.steps.iter().map(|s| s.op_name.as_str()).collect()
+    }
+
+    /// The last step that reverted or trapped, if any.
+    pub fn trapped_step(&self) -> Option<&TraceStep> {
+        self.steps.iter().rev().find(|s| s.trap.is_some())
+    }
+}
+
+/// revm inspector that appends a `TraceStep` before every instruction and
+/// completes it once the instruction has run.
+#[derive(Debug, Default)]
+pub struct StepTracer {
+    trace:   ExecutionTrace,
+    /// Indices of steps awaiting `step_end`. Nested frames run inside their
+    /// parent's `CALL`, so completions arrive in LIFO order.
+    pending: Vec<usize>,
+}
+
+impl StepTracer {
+    pub fn new() -> Self { Self::default() }
+
+    pub fn into_trace(self) -> ExecutionTrace { self.trace }
+}
+
+impl<DB: Database> Inspector<DB> for StepTracer {
+    fn step(&mut self, interp: &mut Interpreter, data: &mut EVMData<'_, DB>) -> InstructionResult {
+        let opcode = interp.current_opcode();
+        let op_name = match OpCode::new(opcode) {
+            Some(op) => op.as_str().to_string(),
+            None     => format!("UNKNOWN(0x{opcode:02X})"),
+        };
+        self.trace.steps.push(TraceStep {
+            depth:         data.journaled_state.depth(),
+            pc:            interp.program_counter(),
+            opcode,
+            op_name,
+            stack:         interp.stack.data().iter().map(|w| format!("{w:#x}")).collect(),
+            gas_remaining: interp.gas.remaining(),
+            gas_after:     interp.gas.remaining(),
+            stack_after:   interp.stack.len(),
+            memory_size:   interp.memory.len(),
+            memory_after:  interp.memory.len(),
+            trap:          None,
+        });
+        self.pending.push(self.trace.steps.len() - 1);
+        InstructionResult::Continue
+    }
+
+    fn step_end(
+        &mut self,
+        interp: &mut Interpreter,
+        _data:  &mut EVMData<'_, DB>,
+        eval:   InstructionResult,
+    ) -> InstructionResult {
+        if let Some(step) = self.pending.pop().and_then(|i| self.trace.steps.get_mut(i)) {
+            step.gas_after    = interp.gas.remaining();
+            step.stack_after  = interp.stack.len();
+            step.memory_after = interp.memory.len();
+            if eval.is_revert() || eval.is_error() {
+                step.trap = Some(format!("{eval:?}"));
+            }
+        }
+        InstructionResult::Continue
+    }
+}
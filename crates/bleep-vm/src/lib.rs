@@ -84,11 +84,15 @@ pub mod execution {
     pub mod call_stack;
     pub mod state_transition;
     pub mod executor;
+    pub mod trace;
+    pub mod quantum_hints;
 
     pub use execution_context::ExecutionContext;
     pub use call_stack::CallStack;
     pub use state_transition::{StateDiff, StateTransition};
-    pub use executor::{Executor, ExecutorConfig, ExecutionOutcome};
+    pub use executor::{Executor, ExecutorConfig, ExecutionOutcome, HintedOutcome};
+    pub use quantum_hints::{ParallelHint, ScheduleMode, HintViolation};
+    pub use trace::{ExecutionTrace, StepTracer, TraceStep};
 }
 
 pub mod crosschain {
@@ -102,6 +106,7 @@ pub mod crosschain {
 // ── Top-level re-exports ──────────────────────────────────────────────────────
 
 pub use types::{ChainId, ContractFormat, ExecutionResult, GasSchedule};
+pub use types::{Address, derive_contract_address, derive_contract_address2};
 pub use error::{VmError, VmResult};
 pub use intent::{Intent, IntentKind, TargetVm, ContractCallBuilder, DeployBuilder};
 pub use intent::{TransferIntent, ContractCallIntent, DeployIntent, CrossChainIntent, ZkVerifyIntent};
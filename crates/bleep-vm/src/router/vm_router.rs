@@ -20,6 +20,7 @@ use crate::error::{VmError, VmResult};
 use crate::execution::{
     execution_context::ExecutionContext,
     state_transition::StateDiff,
+    trace::ExecutionTrace,
 };
 use crate::intent::{Intent, IntentKind, TargetVm};
 use crate::runtime::gas_model::GasModel;
@@ -89,6 +90,20 @@ pub trait Engine: Send + Sync {
         salt:      Option<[u8; 32]>,
     ) -> VmResult<EngineResult>;
 
+    /// Execute like `execute` and also record a step-by-step trace.
+    ///
+    /// Engines without step instrumentation return an empty trace.
+    async fn execute_with_trace(
+        &self,
+        ctx:       &ExecutionContext,
+        bytecode:  &[u8],
+        calldata:  &[u8],
+        gas_limit: u64,
+    ) -> VmResult<(EngineResult, ExecutionTrace)> {
+        let result = self.execute(ctx, bytecode, calldata, gas_limit).await?;
+        Ok((result, ExecutionTrace::default()))
+    }
+
     /// Health probe — returns true if engine is operational.
     fn is_healthy(&self) -> bool { true }
 }
@@ -109,6 +124,9 @@ pub struct RouterConfig {
     pub sandbox_validation:   bool,
     /// Emit structured tracing events.
     pub trace_execution:      bool,
+    /// Record a per-step `ExecutionTrace` in every `RoutedResult`.
+    /// Off by default; never affects execution or gas.
+    pub step_tracing:         bool,
     /// Per-VM engine overrides (e.g. force a specific engine for a ChainId).
     pub chain_vm_overrides:   HashMap<String, TargetVm>,
 }
@@ -121,6 +139,7 @@ impl Default for RouterConfig {
             verify_signatures:   true,
             sandbox_validation:  true,
             trace_execution:     true,
+            step_tracing:        false,
             chain_vm_overrides:  HashMap::new(),
         }
     }
@@ -139,6 +158,9 @@ pub struct RoutedResult {
     pub result:      EngineResult,
     /// Normalised BLEEP gas units actually consumed.
     pub bleep_gas:   u64,
+    /// Step-by-step trace; empty unless `RouterConfig::step_tracing` is set.
+    #[serde(default)]
+    pub trace:       ExecutionTrace,
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -219,6 +241,19 @@ impl VmRouter {
     /// Route and execute one intent end-to-end.
     #[instrument(skip(self, intent), fields(intent_id = %intent.id))]
     pub async fn route(&self, intent: &Intent) -> VmResult<RoutedResult> {
+        self.dispatch(intent, false).await
+    }
+
+    /// Route and execute one intent as a dry run.
+    ///
+    /// Identical execution and gas accounting to `route`, but neither routing
+    /// metrics nor circuit breakers are updated. The returned `StateDiff`
+    /// is never applied.
+    pub async fn route_dry_run(&self, intent: &Intent) -> VmResult<RoutedResult> {
+        self.dispatch(intent, true).await
+    }
+
+    async fn dispatch(&self, intent: &Intent, dry_run: bool) -> VmResult<RoutedResult> {
         let start = Instant::now();
 
         // ── Step 1: Signature verification ───────────────────────────────────
@@ -251,6 +286,7 @@ impl VmRouter {
         let engine_name = engine.name().to_string();
 
         // ── Step 6: Dispatch by intent kind ──────────────────────────────────
+        let mut trace = ExecutionTrace::default();
         let raw_result = match &intent.kind {
             IntentKind::Transfer(t) => {
                 // Transfers don't execute bytecode — produce a state diff directly
@@ -279,6 +315,8 @@ impl VmRouter {
                     &[],           // no bytecode for call — engine fetches from storage
                     &c.calldata,
                     gas_limit,
+                    dry_run,
+                    &mut trace,
                 ).await
             }
             IntentKind::Deploy(d) => {
@@ -294,6 +332,8 @@ impl VmRouter {
                     &d.bytecode,
                     &d.init_args,
                     gas_limit,
+                    dry_run,
+                    &mut trace,
                 ).await;
                 result.map(|mut r| {
                     // On deploy success, output contains deployed contract address
@@ -315,6 +355,8 @@ impl VmRouter {
                     &[],
                     &x.calldata,
                     gas_limit,
+                    dry_run,
+                    &mut trace,
                 ).await
             }
             IntentKind::ZkVerify(z) => {
@@ -325,6 +367,8 @@ impl VmRouter {
                     &z.proof_bytes,
                     &z.public_inputs.concat(),
                     gas_limit,
+                    dry_run,
+                    &mut trace,
                 ).await
             }
         }?;
@@ -332,15 +376,6 @@ impl VmRouter {
         // ── Step 7: Normalise gas to BLEEP units ──────────────────────────────
         let bleep_gas = self.gas_model.normalise(raw_result.gas_used, &vm);
 
-        // ── Step 8: Update metrics ────────────────────────────────────────────
-        {
-            let mut m = self.metrics.write().await;
-            m.total_intents += 1;
-            m.total_gas_used += bleep_gas;
-            *m.by_engine.entry(engine_name.clone()).or_insert(0) += 1;
-            if raw_result.success { m.successes += 1; } else { m.failures += 1; }
-        }
-
         if self.config.trace_execution {
             info!(
                 engine = %engine_name,
@@ -351,13 +386,33 @@ impl VmRouter {
             );
         }
 
-        Ok(RoutedResult {
+        let routed = RoutedResult {
             intent_id:   intent.id,
             engine_name,
             vm:          format!("{vm:?}"),
             result:      raw_result,
             bleep_gas,
-        })
+            trace,
+        };
+
+        // ── Step 8: Update metrics ────────────────────────────────────────────
+        if !dry_run {
+            self.record_metrics(&routed).await;
+        }
+
+        Ok(routed)
+    }
+
+    /// Count a routed result in the routing metrics.
+    ///
+    /// `route` does this itself; callers that ran `route_dry_run` and then
+    /// decided to keep the result use it to commit the metrics exactly once.
+    pub async fn record_metrics(&self, routed: &RoutedResult) {
+        let mut m = self.metrics.write().await;
+        m.total_intents += 1;
+        m.total_gas_used += routed.bleep_gas;
+        *m.by_engine.entry(routed.engine_name.clone()).or_insert(0) += 1;
+        if routed.result.success { m.successes += 1; } else { m.failures += 1; }
     }
 
     /// Snapshot current routing metrics.
@@ -416,8 +471,18 @@ impl VmRouter {
         bytecode:    &[u8],
         calldata:    &[u8],
         gas_limit:   u64,
+        dry_run:     bool,
+        trace:       &mut ExecutionTrace,
     ) -> VmResult<EngineResult> {
-        let result = engine.execute(ctx, bytecode, calldata, gas_limit).await;
+        let result = if self.config.step_tracing {
+            engine.execute_with_trace(ctx, bytecode, calldata, gas_limit).await
+                .map(|(r, t)| { *trace = t; r })
+        } else {
+            engine.execute(ctx, bytecode, calldata, gas_limit).await
+        };
+        if dry_run {
+            return result;
+        }
         let mut breakers = self.breakers.write().await;
         let cb = breakers.entry(name.to_string()).or_default();
         match &result {
@@ -375,6 +375,48 @@ pub enum WasmOpcode {
     Other(u8),
 }
 
+// ─────────────────────────────────────────────────────────────────────────────
+// CONTRACT ADDRESS DERIVATION
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// A 32-byte BLEEP account or contract address.
+pub type Address = [u8; 32];
+
+const CREATE_DOMAIN:  &[u8] = b"BLEEP-CREATE-V1";
+const CREATE2_DOMAIN: &[u8] = b"BLEEP-CREATE2-V1";
+
+/// Derive the address of a contract deployed by `deployer` at `nonce`.
+///
+/// `address = SHA3-256("BLEEP-CREATE-V1" || deployer || nonce_be_u64)`
+///
+/// Every input is fixed-width, so the preimage is unambiguous.
+pub fn derive_contract_address(deployer: &Address, nonce: u64) -> Address {
+    use sha3::{Digest, Sha3_256};
+    let mut h = Sha3_256::new();
+    h.update(CREATE_DOMAIN);
+    h.update(deployer);
+    h.update(nonce.to_be_bytes());
+    h.finalize().into()
+}
+
+/// Derive a nonce-independent (CREATE2-style) contract address.
+///
+/// `address = SHA3-256("BLEEP-CREATE2-V1" || deployer || len_be_u64(salt)
+///                     || salt || code_hash)`
+///
+/// The salt is length-prefixed so that no `(salt, code_hash)` pair can
+/// collide with another by shifting bytes between the two.
+pub fn derive_contract_address2(deployer: &Address, salt: &[u8], code_hash: &[u8]) -> Address {
+    use sha3::{Digest, Sha3_256};
+    let mut h = Sha3_256::new();
+    h.update(CREATE2_DOMAIN);
+    h.update(deployer);
+    h.update((salt.len() as u64).to_be_bytes());
+    h.update(salt);
+    h.update(code_hash);
+    h.finalize().into()
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // HELPER
 // ─────────────────────────────────────────────────────────────────────────────
@@ -385,3 +427,50 @@ pub fn unix_now() -> u64 {
         .unwrap_or_default()
         .as_secs()
 }
+
+// ─────────────────────────────────────────────────────────────────────────────
+// TESTS
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_contract_address_is_deterministic() {
+        let deployer = [7u8; 32];
+        assert_eq!(
+            derive_contract_address(&deployer, 3),
+            derive_contract_address(&deployer, 3),
+        );
+        assert_eq!(
+            derive_contract_address2(&deployer, b"salt", &[0xAA; 32]),
+            derive_contract_address2(&deployer, b"salt", &[0xAA; 32]),
+        );
+    }
+
+    #[test]
+    fn test_contract_address_varies_with_inputs() {
+        let deployer = [7u8; 32];
+        assert_ne!(derive_contract_address(&deployer, 0), derive_contract_address(&deployer, 1));
+        assert_ne!(derive_contract_address(&deployer, 0), derive_contract_address(&[8u8; 32], 0));
+        assert_ne!(
+            derive_contract_address2(&deployer, b"a", &[0xAA; 32]),
+            derive_contract_address2(&deployer, b"b", &[0xAA; 32]),
+        );
+    }
+
+    #[test]
+    fn test_create2_address_depends_on_code_hash() {
+        let deployer = [7u8; 32];
+        assert_ne!(
+            derive_contract_address2(&deployer, b"salt", &[0xAA; 32]),
+            derive_contract_address2(&deployer, b"salt", &[0xBB; 32]),
+        );
+        // Moving bytes across the salt / code-hash boundary changes the address
+        assert_ne!(
+            derive_contract_address2(&deployer, b"ab", b"c"),
+            derive_contract_address2(&deployer, b"a", b"bc"),
+        );
+    }
+}
@@ -0,0 +1,273 @@
+//! # HD Wallet
+//!
+//! BIP-39 mnemonic backup plus hierarchical deterministic key derivation.
+//!
+//! ## Derivation model
+//! ```text
+//! seed   = BIP-39 PBKDF2-HMAC-SHA512(mnemonic, "mnemonic" || passphrase)   (64 bytes)
+//! master = HMAC-SHA512(key = "ed25519 seed", data = seed)
+//! child  = HMAC-SHA512(key = chain_code, data = 0x00 || k_parent || ser32(index))
+//! ```
+//!
+//! Child derivation follows SLIP-10 for Ed25519, which only defines hardened
+//! children — every path segment must be hardened (`44'` or `44h`).
+//! Addresses use the same `BLEEP1<hex40>` format as `EncryptedWallet`.
+
+use bip39::{Language, Mnemonic};
+use ed25519_dalek::{Signature, Signer, SigningKey, VerifyingKey};
+use hmac::{Hmac, Mac};
+use rand::RngCore;
+use sha2::Sha512;
+use zeroize::Zeroize;
+
+use crate::wallet::EncryptedWallet;
+use crate::wallet_core::WalletError;
+
+type HmacSha512 = Hmac<Sha512>;
+
+/// Offset added to an index to mark it as hardened.
+pub const HARDENED_OFFSET: u32 = 0x8000_0000;
+
+/// Default BLEEP account path: `m/44'/7777'/0'/0'/<index>'`.
+pub const BLEEP_DEFAULT_PATH: &str = "m/44'/7777'/0'/0'/0'";
+
+// ─── KeyPair ──────────────────────────────────────────────────────────────────
+
+/// Ed25519 keypair derived at a specific HD path.
+pub struct KeyPair {
+    signing_key: SigningKey,
+    /// Derivation path this key was produced from.
+    pub path: String,
+}
+
+impl KeyPair {
+    pub fn public_key(&self) -> [u8; 32] {
+        self.signing_key.verifying_key().to_bytes()
+    }
+
+    pub fn secret_key(&self) -> [u8; 32] {
+        self.signing_key.to_bytes()
+    }
+
+    pub fn verifying_key(&self) -> VerifyingKey {
+        self.signing_key.verifying_key()
+    }
+
+    /// `BLEEP1<hex40>` address of the public key.
+    pub fn address(&self) -> String {
+        EncryptedWallet::derive_address(&self.public_key())
+    }
+
+    pub fn sign(&self, message: &[u8]) -> Signature {
+        self.signing_key.sign(message)
+    }
+}
+
+// ─── HdWallet ─────────────────────────────────────────────────────────────────
+
+/// Mnemonic-backed wallet that deterministically derives child keys.
+pub struct HdWallet {
+    mnemonic: Mnemonic,
+    seed: [u8; 64],
+}
+
+impl HdWallet {
+    /// Generate a fresh mnemonic with `word_count` words (12 or 24).
+    pub fn generate(word_count: usize, passphrase: &str) -> Result<Self, WalletError> {
+        let entropy_len = match word_count {
+            12 => 16,
+            24 => 32,
+            _ => return Err(WalletError::MnemonicError),
+        };
+        let mut entropy = vec![0u8; entropy_len];
+        rand::rngs::OsRng.fill_bytes(&mut entropy);
+        let mnemonic = Mnemonic::from_entropy_in(Language::English, &entropy)
+            .map_err(|_| WalletError::MnemonicError)?;
+        entropy.zeroize();
+        Ok(Self::from_parsed(mnemonic, passphrase))
+    }
+
+    /// Restore a wallet from an existing 12- or 24-word phrase.
+    ///
+    /// The phrase is checked against the English wordlist and its checksum.
+    pub fn from_mnemonic(phrase: &str, passphrase: &str) -> Result<Self, WalletError> {
+        let mnemonic = Mnemonic::parse_in(Language::English, phrase)
+            .map_err(|_| WalletError::MnemonicError)?;
+        if !matches!(mnemonic.word_count(), 12 | 24) {
+            return Err(WalletError::MnemonicError);
+        }
+        Ok(Self::from_parsed(mnemonic, passphrase))
+    }
+
+    fn from_parsed(mnemonic: Mnemonic, passphrase: &str) -> Self {
+        let seed = mnemonic.to_seed(passphrase);
+        Self { mnemonic, seed }
+    }
+
+    /// Space-separated mnemonic phrase for backup.
+    pub fn mnemonic_phrase(&self) -> String {
+        self.mnemonic.to_string()
+    }
+
+    pub fn word_count(&self) -> usize {
+        self.mnemonic.word_count()
+    }
+
+    /// Derive the keypair at `path`, e.g. `m/44'/7777'/0'/0'/0'`.
+    pub fn derive_child(&self, path: &str) -> Result<KeyPair, WalletError> {
+        derive_from_seed(&self.seed, path)
+    }
+
+    /// Address of the `index`-th account key under the default BLEEP path.
+    pub fn address_at(&self, index: u32) -> Result<String, WalletError> {
+        let path = format!("m/44'/7777'/0'/0'/{}'", index);
+        Ok(self.derive_child(&path)?.address())
+    }
+}
+
+impl Drop for HdWallet {
+    fn drop(&mut self) {
+        self.seed.zeroize();
+    }
+}
+
+// ─── Derivation ───────────────────────────────────────────────────────────────
+
+/// Parse `m/a'/b'/...` into hardened indices.
+///
+/// Rejects a missing `m` root, empty segments, non-numeric indices, indices
+/// already in the hardened range, and non-hardened segments.
+pub fn parse_path(path: &str) -> Result<Vec<u32>, WalletError> {
+    let invalid = |reason: &str| WalletError::InvalidDerivationPath(format!("{}: {}", path, reason));
+
+    let mut segments = path.split('/');
+    if segments.next() != Some("m") {
+        return Err(invalid("path must start with 'm'"));
+    }
+
+    segments
+        .map(|segment| {
+            let digits = segment
+                .strip_suffix('\'')
+                .or_else(|| segment.strip_suffix('h'))
+                .ok_or_else(|| invalid("only hardened segments are supported"))?;
+            let index: u32 = digits
+                .parse()
+                .map_err(|_| invalid("segment is not a number"))?;
+            if index >= HARDENED_OFFSET {
+                return Err(invalid("index out of range"));
+            }
+            Ok(index + HARDENED_OFFSET)
+        })
+        .collect()
+}
+
+/// SLIP-10 Ed25519 derivation of `path` from a BIP-39 seed.
+pub fn derive_from_seed(seed: &[u8], path: &str) -> Result<KeyPair, WalletError> {
+    let indices = parse_path(path)?;
+
+    let (mut key, mut chain_code) = hmac_split(b"ed25519 seed", &[seed]);
+    for index in indices {
+        let (child_key, child_chain) =
+            hmac_split(&chain_code, &[&[0u8], &key, &index.to_be_bytes()]);
+        key.zeroize();
+        key = child_key;
+        chain_code = child_chain;
+    }
+
+    let signing_key = SigningKey::from_bytes(&key);
+    key.zeroize();
+    chain_code.zeroize();
+    Ok(KeyPair { signing_key, path: path.to_string() })
+}
+
+/// `HMAC-SHA512(key, parts...)` split into `(IL, IR)`.
+fn hmac_split(key: &[u8], parts: &[&[u8]]) -> ([u8; 32], [u8; 32]) {
+    let mut mac = HmacSha512::new_from_slice(key).expect("HMAC accepts keys of any length");
+    for part in parts {
+        mac.update(part);
+    }
+    let out = mac.finalize().into_bytes();
+    let mut il = [0u8; 32];
+    let mut ir = [0u8; 32];
+    il.copy_from_slice(&out[..32]);
+    ir.copy_from_slice(&out[32..]);
+    (il, ir)
+}
+
+// ── Unit tests ────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ABANDON_12: &str =
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn slip10_ed25519_test_vector_1() {
+        let seed = hex::decode("000102030405060708090a0b0c0d0e0f").unwrap();
+        let master = derive_from_seed(&seed, "m").unwrap();
+        assert_eq!(
+            hex::encode(master.secret_key()),
+            "2b4be7f19ee27bbf30c667b642d5f4aa69fd169872f8fc3059c08ebae2eb19e7"
+        );
+        let child = derive_from_seed(&seed, "m/0'").unwrap();
+        assert_eq!(
+            hex::encode(child.secret_key()),
+            "68e0fe46dfb67e368c75379acec591dad19df3cde26e63b93a8e704f1dade7a3"
+        );
+        assert_eq!(
+            hex::encode(child.public_key()),
+            "8c8a13df77a28f3445213a0f432fde644acaa215fc72dcdf300d5efaa85d350c"
+        );
+    }
+
+    #[test]
+    fn known_mnemonic_restores_known_first_address() {
+        let wallet = HdWallet::from_mnemonic(ABANDON_12, "").unwrap();
+        assert_eq!(
+            wallet.address_at(0).unwrap(),
+            "BLEEP1579d71a8722149566855817c7b7bba774df31b41"
+        );
+    }
+
+    #[test]
+    fn derivation_is_deterministic() {
+        let a = HdWallet::from_mnemonic(ABANDON_12, "").unwrap();
+        let b = HdWallet::from_mnemonic(ABANDON_12, "").unwrap();
+        let ka = a.derive_child(BLEEP_DEFAULT_PATH).unwrap();
+        let kb = b.derive_child(BLEEP_DEFAULT_PATH).unwrap();
+        assert_eq!(ka.public_key(), kb.public_key());
+        assert_ne!(a.address_at(0).unwrap(), a.address_at(1).unwrap());
+    }
+
+    #[test]
+    fn generated_mnemonic_roundtrips_for_12_and_24_words() {
+        for words in [12, 24] {
+            let wallet = HdWallet::generate(words, "pw").unwrap();
+            assert_eq!(wallet.word_count(), words);
+            let restored = HdWallet::from_mnemonic(&wallet.mnemonic_phrase(), "pw").unwrap();
+            assert_eq!(wallet.address_at(0).unwrap(), restored.address_at(0).unwrap());
+        }
+        assert!(HdWallet::generate(15, "").is_err());
+    }
+
+    #[test]
+    fn invalid_paths_rejected() {
+        let wallet = HdWallet::from_mnemonic(ABANDON_12, "").unwrap();
+        for path in ["", "44'/0'", "m/", "m/44'/x'", "m/44", "m/2147483648'"] {
+            assert!(
+                matches!(wallet.derive_child(path), Err(WalletError::InvalidDerivationPath(_))),
+                "path {:?} should be rejected",
+                path
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_mnemonic_rejected() {
+        let bad_checksum = ABANDON_12.replace("about", "abandon");
+        assert!(HdWallet::from_mnemonic(&bad_checksum, "").is_err());
+    }
+}
@@ -3,12 +3,14 @@ use pqcrypto_traits::kem::{PublicKey as _, SecretKey as _, SharedSecret as _};
 use std::sync::{Arc, Mutex};
 use pqcrypto_kyber::kyber512::{keypair, encapsulate, decapsulate};
 use serde::{Serialize, Deserialize};
-// use sha2::{Digest, Sha256};
+use sha2::{Digest, Sha256};
 // use zeroize::Zeroize;
 // use tokio::sync::RwLock;
 // use log::{info, warn};
-// use rand::{rngs::OsRng, RngCore};
+use rand::RngCore;
 use bip39::{Mnemonic, Language};
+use crate::hd_wallet::{HdWallet, KeyPair};
+use bleep_crypto::quantum_secure::{DilithiumKeypair, DilithiumSignature};
 // use hdwallet::{ExtendedPrivKey, KeyChain, XPrv}; // Not used, remove for now
 // use aes_gcm::{Aes256Gcm, Key, Nonce};
 // use aes_gcm::aead::{Aead, KeyInit};
@@ -45,6 +47,14 @@ pub enum WalletError {
     Serialization(String),
     #[error("Mnemonic error")]
     MnemonicError,
+    #[error("Invalid derivation path: {0}")]
+    InvalidDerivationPath(String),
+    #[error("Multisig error: {0}")]
+    Multisig(String),
+    #[error("Duplicate multisig signer")]
+    DuplicateSigner,
+    #[error("Multisig threshold not met: {collected}/{threshold}")]
+    ThresholdNotMet { collected: usize, threshold: usize },
 }
 
 // 📜 Struct for a Transaction
@@ -65,6 +75,7 @@ pub struct Wallet {
     authenticated: bool,
     public_key: Vec<u8>,
     private_key: Vec<u8>,
+    pq_keypair: DilithiumKeypair,
     mnemonic: Mnemonic,
     ai_decision_module: Arc<BLEEPAIDecisionModule>,
     zkp_module: Arc<BLEEPZKPModule>,
@@ -79,7 +90,8 @@ impl Wallet {
     pub fn new(p2p_node: Arc<P2PNode>, state_merkle: Arc<Mutex<StateMerkle>>) -> Result<Self, WalletError> {
     let (public_key, private_key) = keypair();
     // Generate a new mnemonic (24 words = 256 bits entropy)
-    let entropy = [0u8; 32]; // In production, use rand::random()
+    let mut entropy = [0u8; 32];
+    rand::rngs::OsRng.fill_bytes(&mut entropy);
     let mnemonic = Mnemonic::from_entropy(&entropy)
         .map_err(|_| WalletError::MnemonicError)?;
 
@@ -89,6 +101,7 @@ impl Wallet {
             authenticated: false,
             public_key: public_key.as_bytes().to_vec(),
             private_key: private_key.as_bytes().to_vec(),
+            pq_keypair: pq_keypair_from_mnemonic(&mnemonic),
             mnemonic,
             ai_decision_module: Arc::new(BLEEPAIDecisionModule::new()),
             zkp_module: Arc::new(BLEEPZKPModule::new()),
@@ -112,6 +125,7 @@ impl Wallet {
             authenticated: false,
             public_key: public_key.as_bytes().to_vec(),
             private_key: private_key.as_bytes().to_vec(),
+            pq_keypair: pq_keypair_from_mnemonic(&mnemonic_obj),
             mnemonic: mnemonic_obj,
         ai_decision_module: Arc::new(BLEEPAIDecisionModule::new()),
         zkp_module: Arc::new(BLEEPZKPModule::new()),
@@ -154,6 +168,27 @@ impl Wallet {
         Ok(signed_tx)
     }
 
+    // 🛡️ Post-Quantum Transaction Signing (Dilithium / ML-DSA-65)
+    pub fn sign_transaction_pq(&self, tx: &Transaction) -> Result<DilithiumSignature, WalletError> {
+        let payload = pq_signing_payload(tx)?;
+        Ok(self.pq_keypair.sign(&payload))
+    }
+
+    /// Dilithium public key used to verify this wallet's PQ signatures.
+    pub fn pq_public_key(&self) -> &[u8] {
+        self.pq_keypair.public_key()
+    }
+
+    // 🌳 HD Key Derivation from the wallet mnemonic
+    pub fn derive_child(&self, path: &str) -> Result<KeyPair, WalletError> {
+        HdWallet::from_mnemonic(&self.mnemonic.to_string(), "")?.derive_child(path)
+    }
+
+    /// Mnemonic phrase backing this wallet's HD keys.
+    pub fn mnemonic_phrase(&self) -> String {
+        self.mnemonic.to_string()
+    }
+
     // 📡 Broadcast Transaction to P2P Network
     pub async fn broadcast_transaction(&self, signed_tx: &Transaction) -> Result<String, WalletError> {
         let tx_data = serde_json::to_vec(signed_tx)
@@ -187,3 +222,220 @@ impl Wallet {
         Ok(())
     }
 }
+
+/// Dilithium keypair for a mnemonic: the FIPS 204 seed is
+/// SHA-256("BLEEP-MLDSA65-V1" || BIP-39 seed), so importing the same
+/// mnemonic restores the same PQ key.
+fn pq_keypair_from_mnemonic(mnemonic: &Mnemonic) -> DilithiumKeypair {
+    let mut hasher = Sha256::new();
+    hasher.update(b"BLEEP-MLDSA65-V1");
+    hasher.update(mnemonic.to_seed(""));
+    DilithiumKeypair::from_seed(&hasher.finalize().into())
+}
+
+/// Canonical bytes signed by `sign_transaction_pq`: the JSON encoding of the
+/// transaction with its `signature` field cleared.
+fn pq_signing_payload(tx: &Transaction) -> Result<Vec<u8>, WalletError> {
+    let mut unsigned = tx.clone();
+    unsigned.signature.clear();
+    serde_json::to_vec(&unsigned).map_err(|e| WalletError::Serialization(e.to_string()))
+}
+
+/// Verify a Dilithium signature produced by `Wallet::sign_transaction_pq`.
+pub fn verify_transaction_pq(public_key: &[u8], tx: &Transaction, signature: &DilithiumSignature) -> bool {
+    match pq_signing_payload(tx) {
+        Ok(payload) => DilithiumKeypair::verify(public_key, &payload, signature),
+        Err(_) => false,
+    }
+}
+
+// 🔏 M-of-N Multisig (Dilithium / ML-DSA-65)
+
+/// One signer's Dilithium signature over a multisig transaction.
+#[derive(Debug, Clone)]
+pub struct PartialSignature {
+    pub signer: Vec<u8>,
+    pub signature: DilithiumSignature,
+}
+
+/// Transaction carrying enough partial signatures to satisfy its policy.
+#[derive(Debug, Clone)]
+pub struct SignedTx {
+    pub tx: Transaction,
+    pub signatures: Vec<PartialSignature>,
+}
+
+/// M-of-N policy over Dilithium public keys.
+pub struct MultisigWallet {
+    threshold: usize,
+    signers: Vec<Vec<u8>>,
+}
+
+impl MultisigWallet {
+    /// Create a `threshold`-of-`signers.len()` policy.
+    pub fn new(threshold: usize, signers: Vec<Vec<u8>>) -> Result<Self, WalletError> {
+        if threshold == 0 || threshold > signers.len() {
+            return Err(WalletError::Multisig(format!(
+                "threshold {} invalid for {} signers",
+                threshold,
+                signers.len()
+            )));
+        }
+        for (i, signer) in signers.iter().enumerate() {
+            if signers[..i].contains(signer) {
+                return Err(WalletError::DuplicateSigner);
+            }
+        }
+        Ok(Self { threshold, signers })
+    }
+
+    pub fn threshold(&self) -> usize {
+        self.threshold
+    }
+
+    pub fn signers(&self) -> &[Vec<u8>] {
+        &self.signers
+    }
+
+    /// Sign `tx` with `wallet`'s PQ key, which must belong to this policy.
+    pub fn create_partial_signature(&self, wallet: &Wallet, tx: &Transaction) -> Result<PartialSignature, WalletError> {
+        let signer = wallet.pq_public_key().to_vec();
+        if !self.signers.contains(&signer) {
+            return Err(WalletError::Multisig("wallet is not a signer of this policy".into()));
+        }
+        Ok(PartialSignature { signature: wallet.sign_transaction_pq(tx)?, signer })
+    }
+
+    /// Assemble a `SignedTx` from partial signatures.
+    ///
+    /// Every partial must come from a distinct policy signer and verify
+    /// against `tx`; at least `threshold` of them are required.
+    pub fn combine(&self, tx: &Transaction, partials: Vec<PartialSignature>) -> Result<SignedTx, WalletError> {
+        for (i, partial) in partials.iter().enumerate() {
+            if partials[..i].iter().any(|p| p.signer == partial.signer) {
+                return Err(WalletError::DuplicateSigner);
+            }
+            if !self.signers.contains(&partial.signer) {
+                return Err(WalletError::Multisig("partial from unknown signer".into()));
+            }
+            if !verify_transaction_pq(&partial.signer, tx, &partial.signature) {
+                return Err(WalletError::Multisig("invalid partial signature".into()));
+            }
+        }
+        if partials.len() < self.threshold {
+            return Err(WalletError::ThresholdNotMet { collected: partials.len(), threshold: self.threshold });
+        }
+        Ok(SignedTx { tx: tx.clone(), signatures: partials })
+    }
+
+    /// True if `signed` carries at least `threshold` valid signatures from
+    /// distinct signers of this policy.
+    pub fn verify(&self, signed: &SignedTx) -> bool {
+        let mut seen: Vec<&[u8]> = Vec::new();
+        for partial in &signed.signatures {
+            if seen.contains(&partial.signer.as_slice())
+                || !self.signers.contains(&partial.signer)
+                || !verify_transaction_pq(&partial.signer, &signed.tx, &partial.signature)
+            {
+                return false;
+            }
+            seen.push(&partial.signer);
+        }
+        seen.len() >= self.threshold
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_tx(from: &str) -> Transaction {
+        Transaction {
+            id: "tx-pq-1".to_string(),
+            from: from.to_string(),
+            to: "recipient".to_string(),
+            amount: 42.0,
+            fee: 0.01,
+            signature: vec![],
+        }
+    }
+
+    #[test]
+    fn pq_signature_roundtrip() {
+        let wallet = Wallet::new(Arc::new(P2PNode::new()), Arc::new(Mutex::new(StateMerkle::new()))).unwrap();
+        let mut tx = sample_tx(&wallet.address);
+        let sig = wallet.sign_transaction_pq(&tx).unwrap();
+        tx.signature = sig.as_bytes().to_vec();
+        assert!(verify_transaction_pq(wallet.pq_public_key(), &tx, &sig));
+    }
+
+    #[test]
+    fn pq_key_restored_from_mnemonic() {
+        let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let first = Wallet::import_wallet(phrase).unwrap();
+        let second = Wallet::import_wallet(phrase).unwrap();
+        assert_eq!(first.pq_public_key(), second.pq_public_key());
+
+        let tx = sample_tx(&first.address);
+        let sig = first.sign_transaction_pq(&tx).unwrap();
+        assert!(verify_transaction_pq(second.pq_public_key(), &tx, &sig));
+    }
+
+    #[test]
+    fn pq_signature_rejects_tampered_tx() {
+        let wallet = Wallet::new(Arc::new(P2PNode::new()), Arc::new(Mutex::new(StateMerkle::new()))).unwrap();
+        let mut tx = sample_tx(&wallet.address);
+        let sig = wallet.sign_transaction_pq(&tx).unwrap();
+        tx.amount = 4200.0;
+        assert!(!verify_transaction_pq(wallet.pq_public_key(), &tx, &sig));
+    }
+
+    fn new_wallet() -> Wallet {
+        Wallet::new(Arc::new(P2PNode::new()), Arc::new(Mutex::new(StateMerkle::new()))).unwrap()
+    }
+
+    fn two_of_three() -> (MultisigWallet, Vec<Wallet>) {
+        let wallets: Vec<Wallet> = (0..3).map(|_| new_wallet()).collect();
+        let signers = wallets.iter().map(|w| w.pq_public_key().to_vec()).collect();
+        (MultisigWallet::new(2, signers).unwrap(), wallets)
+    }
+
+    #[test]
+    fn multisig_two_of_three_combines_and_verifies() {
+        let (multisig, wallets) = two_of_three();
+        let tx = sample_tx("treasury");
+        let partials = vec![
+            multisig.create_partial_signature(&wallets[0], &tx).unwrap(),
+            multisig.create_partial_signature(&wallets[2], &tx).unwrap(),
+        ];
+        let signed = multisig.combine(&tx, partials).unwrap();
+        assert!(multisig.verify(&signed));
+
+        let mut tampered = signed.clone();
+        tampered.tx.amount = 1_000_000.0;
+        assert!(!multisig.verify(&tampered));
+    }
+
+    #[test]
+    fn multisig_below_threshold_fails() {
+        let (multisig, wallets) = two_of_three();
+        let tx = sample_tx("treasury");
+        let partials = vec![multisig.create_partial_signature(&wallets[1], &tx).unwrap()];
+        assert!(matches!(
+            multisig.combine(&tx, partials),
+            Err(WalletError::ThresholdNotMet { collected: 1, threshold: 2 })
+        ));
+    }
+
+    #[test]
+    fn multisig_rejects_duplicate_signer() {
+        let (multisig, wallets) = two_of_three();
+        let tx = sample_tx("treasury");
+        let partials = vec![
+            multisig.create_partial_signature(&wallets[0], &tx).unwrap(),
+            multisig.create_partial_signature(&wallets[0], &tx).unwrap(),
+        ];
+        assert!(matches!(multisig.combine(&tx, partials), Err(WalletError::DuplicateSigner)));
+        assert!(multisig.create_partial_signature(&new_wallet(), &tx).is_err());
+    }
+}
@@ -1,6 +1,6 @@
 // src/bin/bleep_governance.rs
 
-use bleep_governance::GovernanceEngine;
+use bleep_governance::{GovernanceEngine, Proposal};
 // use bleep_governance::off_chain_voting::load_off_chain_votes;
 // use bleep_governance::self_amending::apply_protocol_updates;
 
@@ -23,7 +23,7 @@ fn run_governance_module() -> Result<(), Box<dyn Error>> {
     info!("✅ Governance engine loaded.");
 
     // Step 2: Placeholder for proposal processing
-    let proposals = Vec::new();
+    let proposals: Vec<Proposal> = Vec::new();
     info!("📮 Loaded {} off-chain proposals.", proposals.len());
     // engine.process_proposals(proposals);
     info!("✅ Proposals processed.");
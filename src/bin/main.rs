@@ -35,6 +35,9 @@
 //!   - OracleBridgeEngine: 5 oracle operators, 3-of-5 BLEEP/USD quorum
 //!   - Standalone `bleep-executor` binary for Layer 4 intent market
 
+// The combined warp route tree exceeds the default trait-solver depth.
+#![recursion_limit = "256"]
+
 use std::error::Error;
 use std::sync::{Arc, RwLock};
 use parking_lot::Mutex;
@@ -55,16 +58,21 @@ use bleep_core::run_mempool_bridge;
 // ── State ─────────────────────────────────────────────────────────────────────
 use bleep_state::state_manager::StateManager;
 
+// ── Genesis ───────────────────────────────────────────────────────────────────
+use bleep_root::genesis::{GenesisBuilder, GenesisValidator, VALIDATOR_STAKE_ACCOUNT};
+use bleep_root::integrity::{verify_node_integrity, NodeState};
+
 // ── Consensus ─────────────────────────────────────────────────────────────────
 use bleep_consensus::{run_consensus_engine, BlockProducer};
-use bleep_consensus::validator_identity::{ValidatorIdentity, ValidatorRegistry};
 use bleep_consensus::slashing_engine::SlashingEngine;
+use bleep_consensus::finality::FinalizityManager;
+use bleep_state::shard_registry::ShardStateRoot;
 
 // ── Scheduler ─────────────────────────────────────────────────────────────────
 use bleep_scheduler::{Scheduler, BlockTick};
 
 // ── Governance ────────────────────────────────────────────────────────────────
-use bleep_governance::governance_core::GovernanceEngine;
+use bleep_governance::governance_core::{GovernanceEngine, StateDepositLedger};
 
 // ── P2P ───────────────────────────────────────────────────────────────────────
 use bleep_p2p::p2p_node::{P2PNode, P2PNodeConfig};
@@ -77,7 +85,7 @@ use bleep_wallet_core::init_wallet_services;
 use bleep_pat::launch_asset_token_logic;
 
 // ── Economics ─────────────────────────────────────────────────────────────────
-use bleep_economics::{BleepEconomicsRuntime, EpochInput};
+use bleep_economics::EpochInput;
 use bleep_economics::oracle_bridge::{PriceUpdate, OracleSource};
 
 // ── Interop ───────────────────────────────────────────────────────────────────
@@ -85,7 +93,7 @@ use bleep_interop::interoperability::start_interop_services;
 use bleep_pat::PATRegistry;
 
 // ── AI advisory ───────────────────────────────────────────────────────────────
-use bleep_ai::ai_assistant::init_ai_advisory;
+use bleep_ai::start_ai_services;
 
 // ── Telemetry ─────────────────────────────────────────────────────────────────
 use bleep_telemetry::{init_telemetry, metrics::{MetricCounter, MetricGauge}};
@@ -94,7 +102,7 @@ use bleep_telemetry::{init_telemetry, metrics::{MetricCounter, MetricGauge}};
 use bleep_rpc::{rpc_routes_with_state, RpcState};
 use warp;
 use hex;
-use zksnarks;
+use bleep_zkp as zksnarks;
 
 #[tokio::main]
 async fn main() {
@@ -150,16 +158,33 @@ async fn run() -> Result<(), Box<dyn Error>> {
         }
     };
 
+    // Build and cross-check genesis across economics, governance, consensus,
+    // and state before anything is persisted. The local node is the sole
+    // genesis validator and bonds its stake from the validator allocation.
+    let genesis_spec = GenesisBuilder::new()
+        .allocate("bleep:genesis:foundation", 500_000_000_000_000u128)
+        .allocate("bleep:genesis:rewards",    100_000_000_000_000u128)
+        .allocate(VALIDATOR_STAKE_ACCOUNT,     50_000_000_000_000u128)
+        .validator(GenesisValidator {
+            id:                 hex::encode(&sphincs_pk[..8]),
+            kyber_public_key:   kyber_pk.as_bytes().to_vec(),
+            signing_key_id:     hex::encode(&sphincs_pk),
+            signing_public_key: sphincs_pk.clone(),
+            stake:              1_000_000u128, // initial stake (matches BlockProducer config)
+        })
+        .build()
+        .unwrap_or_else(|e| { error!("Genesis build failed: {}", e); std::process::exit(1); });
+    info!("  ✅ Genesis verified: supply={} µBLEEP, snapshot={}",
+          genesis_spec.total_supply, &genesis_spec.snapshot.snapshot_hash[..16]);
+
     // Mint genesis allocations only at height 0 (first start).
     // mint() now returns Result — cap violations are logged and abort startup.
     if state.block_height() == 0 {
-        state.mint("bleep:genesis:foundation", 500_000_000_000_000u128)
-            .unwrap_or_else(|e| { error!("Genesis mint foundation failed: {}", e); std::process::exit(1); });
-        state.mint("bleep:genesis:rewards",    100_000_000_000_000u128)
-            .unwrap_or_else(|e| { error!("Genesis mint rewards failed: {}", e); std::process::exit(1); });
-        state.mint("bleep:genesis:validators",  50_000_000_000_000u128)
-            .unwrap_or_else(|e| { error!("Genesis mint validators failed: {}", e); std::process::exit(1); });
-        info!("  ✅ Genesis allocations minted (650T µBLEEP).");
+        for (account, amount) in &genesis_spec.allocations {
+            state.mint(account, *amount)
+                .unwrap_or_else(|e| { error!("Genesis mint {} failed: {}", account, e); std::process::exit(1); });
+        }
+        info!("  ✅ Genesis allocations minted ({} µBLEEP).", genesis_spec.total_supply);
     }
 
     // Rebuild the Sparse Merkle Trie from the persisted DB state
@@ -196,49 +221,37 @@ async fn run() -> Result<(), Box<dyn Error>> {
 
     // ── Step 5: AI advisory ───────────────────────────────────────────────────
     info!("🧠 [5/13] Starting AI advisory engine…");
-    init_ai_advisory()?;
+    start_ai_services();
     info!("  ✅ AI advisory ready (deterministic mode).");
 
-    // ── Step 6: Governance ────────────────────────────────────────────────────
-    info!("🏛  [6/16] Initialising governance engine…");
-    let mut governance = GovernanceEngine::new(1_000_000_000u128);
+    // ── Step 6: ValidatorRegistry + SlashingEngine ────────────────────────────
+    info!("🗳  [6/16] Initialising ValidatorRegistry and SlashingEngine…");
+    // Genesis validator set (local node, Kyber-1024 PK wired from Step 1 keygen)
+    // comes pre-registered and active from the GenesisBuilder.
+    let validator_registry = Arc::new(Mutex::new(genesis_spec.validators));
+    let slashing_engine    = Arc::new(Mutex::new(SlashingEngine::new()));
+    info!("  ✅ Genesis validator set: {} active, stake={}",
+          validator_registry.lock().active_count(), validator_registry.lock().total_active_stake());
+
+    // ── Step 6b: Governance ───────────────────────────────────────────────────
+    info!("🏛  [6b/16] Initialising governance engine…");
+    // Proposals snapshot voting stake from the live validator set when voting opens,
+    // and escrow their deposits from the proposer's on-chain balance
+    let governance_stakes = Arc::clone(&validator_registry);
+    let mut governance = GovernanceEngine::new(1_000_000_000u128)
+        .with_deposit_ledger(Arc::new(StateDepositLedger::new(Arc::clone(&state))))
+        .with_stake_source(Arc::new(move || {
+            governance_stakes
+                .lock()
+                .get_active_validators()
+                .into_iter()
+                .map(|v| (v.id.clone(), v.stake))
+                .collect()
+        }));
     governance.persist()?;
+    let governance = Arc::new(Mutex::new(governance));
     info!("  ✅ Governance online (1B total stake).");
 
-    // ── Step 6b: ValidatorRegistry + SlashingEngine ───────────────────────────
-    info!("🗳  [6b/16] Initialising ValidatorRegistry and SlashingEngine…");
-    let validator_registry = Arc::new(Mutex::new(ValidatorRegistry::new()));
-    let slashing_engine    = Arc::new(Mutex::new(SlashingEngine::new()));
-
-    // Register the local node as the genesis validator with the real Kyber-1024 public key.
-    {
-        let mut reg = validator_registry.lock();
-        // Use the first 8 bytes of the SPHINCS+ PK as the validator ID
-        let validator_id   = hex::encode(&sphincs_pk[..8]);
-        // Real Kyber-1024 PK bytes (1568 bytes) — wired from Step 1 keygen
-        let real_kyber_pk  = kyber_pk.as_bytes().to_vec();
-        let signing_key_id = hex::encode(&sphincs_pk);
-        // S-06: pass the real SPHINCS+ public key bytes so SlashingEngine can
-        // cryptographically verify evidence before slashing this validator.
-        let real_sphincs_pk = sphincs_pk.clone();
-        let genesis_validator = ValidatorIdentity::new(
-            validator_id.clone(),
-            real_kyber_pk,
-            real_sphincs_pk,  // S-06: SPHINCS+ pk for evidence verification
-            signing_key_id,
-            1_000_000u128, // initial stake (matches BlockProducer config)
-            0,             // genesis epoch
-        );
-        match genesis_validator {
-            Ok(mut v) => {
-                let _ = v.activate();
-                let _ = reg.register_validator(v);
-                info!("  ✅ Genesis validator registered: id={} (Kyber-1024 + SPHINCS+ PKs wired)", validator_id);
-            }
-            Err(e) => warn!("  ⚠️  Genesis validator registration skipped: {}", e),
-        }
-    }
-
     // ── Step 6c: Groth16 devnet SRS ───────────────────────────────────────────
     info!("🔐 [6c/16] Generating Groth16 devnet SRS (block circuit)…");
     info!("   MPC ceremony COMPLETE — 5-participant SRS. See /rpc/ceremony/status.");
@@ -256,11 +269,8 @@ async fn run() -> Result<(), Box<dyn Error>> {
     // ── Step 6d: BleepEconomicsRuntime ────────────────────────────────────────
     info!("💰 [6d/16] Initialising BleepEconomicsRuntime (tokenomics + fee market + oracle)…");
     let economics_runtime = {
-        let mut rt = BleepEconomicsRuntime::genesis();
-
-        // Register genesis validator with economics engine
-        let genesis_val_id = hex::encode(&sphincs_pk[..8]);
-        let _ = rt.register_validator(genesis_val_id.as_bytes().to_vec(), 1_000_000u128);
+        // Genesis supply and validator bonds were registered by the GenesisBuilder.
+        let mut rt = genesis_spec.economics;
 
         // Register 5 oracle operators for 3-of-5 BLEEP/USD quorum
         for i in 0u8..5 {
@@ -291,8 +301,8 @@ async fn run() -> Result<(), Box<dyn Error>> {
             let _ = rt.submit_price_update(update);
         }
 
-        info!("  ✅ EconomicsRuntime: genesis supply=0, base_fee={} µBLEEP, 5 oracle operators, 3 initial price seeds",
-              rt.current_base_fee());
+        info!("  ✅ EconomicsRuntime: genesis supply={}, base_fee={} µBLEEP, 5 oracle operators, 3 initial price seeds",
+              rt.circulating_supply(), rt.current_base_fee());
         rt
     };
     let economics_runtime = Arc::new(Mutex::new(economics_runtime));
@@ -381,6 +391,8 @@ async fn run() -> Result<(), Box<dyn Error>> {
         sphincs_pk.clone(),                  // full SPHINCS+ PK bytes (32 bytes)
         Some(Arc::clone(&p2p_node)),         // direct gossip broadcast
     );
+    // Per-block fee reports follow the live base fee and governance fee routing
+    let block_producer = block_producer.with_economics(Arc::clone(&economics_runtime));
 
     // Subscribe a second receiver for GossipBridge BEFORE the producer starts
     let block_rx_gossip = block_producer.subscribe();
@@ -397,6 +409,15 @@ async fn run() -> Result<(), Box<dyn Error>> {
     let txs_relay        = txs_processed.clone();
     let gas_relay        = gas_used_gauge.clone();
     let economics_relay  = Arc::clone(&economics_runtime);
+    let validators_relay = Arc::clone(&validator_registry);
+    let governance_relay = Arc::clone(&governance);
+
+    // Self-check inputs: the genesis commitments and a finality tracker over
+    // the genesis stake, checked against every block the node relays.
+    let finality_relay   = FinalizityManager::new(validator_registry.lock().total_active_stake());
+    let ruleset_relay    = genesis_spec.ruleset;
+    let snapshot_relay   = genesis_spec.snapshot;
+    let snapshot_engine_relay = genesis_spec.snapshot_engine;
 
     // Track last epoch to fire economics only once per epoch boundary
     let mut last_economics_epoch: u64 = 0;
@@ -411,8 +432,10 @@ async fn run() -> Result<(), Box<dyn Error>> {
                     for _ in 0..fb.tx_count { txs_relay.increment(); }
                     gas_relay.set(fb.gas_used as i64);
 
-                    // Accumulate fee revenue (gas_used * base_fee approximation)
-                    epoch_fee_revenue = epoch_fee_revenue.saturating_add(fb.gas_used as u128 * 1_000);
+                    // Accumulate fee revenue from the block's economics report
+                    if let Some(report) = &fb.economics {
+                        epoch_fee_revenue = epoch_fee_revenue.saturating_add(report.total_fees);
+                    }
 
                     scheduler_relay.on_new_block(BlockTick {
                         height:    fb.height,
@@ -420,10 +443,17 @@ async fn run() -> Result<(), Box<dyn Error>> {
                         timestamp: chrono::Utc::now().timestamp() as u64,
                     });
 
-                    // ── Economics epoch-end hook ───────────────────────────
+                    // ── Epoch-boundary hook (validators + economics) ──────
                     // Fires once when we see the first block of a new epoch.
                     if fb.epoch > last_economics_epoch {
                         let completed_epoch = last_economics_epoch;
+
+                        // Release validators whose jail term ended with the new epoch
+                        let released = validators_relay.lock().tick(fb.epoch);
+                        if !released.is_empty() {
+                            info!("🔓 Epoch {}: released {} jailed validator(s): {:?}",
+                                  fb.epoch, released.len(), released);
+                        }
                         let fee_rev = epoch_fee_revenue;
 
                         let input = EpochInput {
@@ -454,6 +484,32 @@ async fn run() -> Result<(), Box<dyn Error>> {
                         fb.height, fb.epoch, fb.tx_count, fb.gas_used,
                         hex::encode(&fb.state_root[..4])
                     );
+
+                    // ── Integrity self-check — halt rather than build on bad state ──
+                    let head_root = ShardStateRoot {
+                        root_hash: hex::encode(fb.state_root),
+                        tx_count:  fb.tx_count as u64,
+                        height:    fb.height,
+                    };
+                    let integrity = {
+                        let econ       = economics_relay.lock();
+                        let validators = validators_relay.lock();
+                        let governance = governance_relay.lock();
+                        verify_node_integrity(&NodeState {
+                            economics:       &econ,
+                            validators:      &validators,
+                            finality:        &finality_relay,
+                            snapshot_engine: &snapshot_engine_relay,
+                            latest_snapshot: &snapshot_relay,
+                            state_root:      &head_root,
+                            ruleset:         &ruleset_relay,
+                            governance:      &governance,
+                        })
+                    };
+                    if let Err(report) = integrity {
+                        error!("🛑 Block {}: {} — halting node", fb.height, report);
+                        std::process::exit(1);
+                    }
                 }
                 Err(tokio::sync::broadcast::error::RecvError::Lagged(n)) => {
                     warn!("[relay] Lagged {} blocks — scheduler may miss ticks", n);
@@ -617,7 +673,7 @@ async fn run() -> Result<(), Box<dyn Error>> {
         warp::serve(routes).run(([0, 0, 0, 0], 8545)).await;
     });
 
-    info!("  ✅ RPC: /rpc/health  /rpc/telemetry  /rpc/state/{addr}  /rpc/proof/{addr}");
+    info!("  ✅ RPC: /rpc/health  /rpc/telemetry  /rpc/state/{{addr}}  /rpc/proof/{{addr}}");
 
     // ── Ready banner ──────────────────────────────────────────────────────────
     info!("");
@@ -627,8 +683,8 @@ async fn run() -> Result<(), Box<dyn Error>> {
     info!("══ Core RPC ═════════════════════════════════════════════════════════");
     info!("   Health:       http://0.0.0.0:8545/rpc/health");
     info!("   State:        http://0.0.0.0:8545/rpc/state/{{address}}");
-    info!("   Supply:       http://0.0.0.0:8545/rpc/economics/supply
-    info!("   Distribution: http://0.0.0.0:8545/rpc/economics/distribution  [Updated tokenomics]");");
+    info!("   Supply:       http://0.0.0.0:8545/rpc/economics/supply");
+    info!("   Distribution: http://0.0.0.0:8545/rpc/economics/distribution  [Updated tokenomics]");
     info!("   Oracle:       http://0.0.0.0:8545/rpc/oracle/price/BLEEP%2FUSD");
     info!("══ BLEEP Connect ════════════════════════════════════════════════════");
     info!("   L4 Intents:   http://0.0.0.0:8545/rpc/connect/intents/pending");
@@ -669,7 +725,7 @@ async fn run() -> Result<(), Box<dyn Error>> {
     }
 
     // Persist governance
-    governance.persist().unwrap_or_else(|e| warn!("Governance persist: {}", e));
+    governance.lock().persist().unwrap_or_else(|e| warn!("Governance persist: {}", e));
 
     // Abort background tasks
     producer_handle.abort();
@@ -0,0 +1,416 @@
+//! # Genesis Builder
+//!
+//! Builds one consistent genesis across every stateful subsystem:
+//!
+//! ```text
+//!   economics   → BleepEconomicsRuntime with the genesis supply minted
+//!   governance  → canonical genesis ProtocolRuleSet (committed)
+//!   consensus   → ValidatorRegistry with every genesis validator active
+//!   state       → genesis StateSnapshot (SnapshotId 0, epoch 0, height 0)
+//! ```
+//!
+//! ## Cross-subsystem invariants
+//! - Total genesis supply ≤ `MAX_SUPPLY` and equals economics circulating supply.
+//! - Σ validator stake ≤ the `bleep:genesis:validators` allocation.
+//! - Registry active stake == Σ validator stake == economics bonded stake.
+//! - Genesis snapshot state root commits to the allocation table.
+//!
+//! The output is fully deterministic: the same builder inputs always
+//! produce the same snapshot hash and ruleset commitment.
+
+use std::collections::BTreeMap;
+
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+use bleep_consensus::validator_identity::{ValidatorIdentity, ValidatorRegistry};
+use bleep_economics::runtime::BleepEconomicsRuntime;
+use bleep_economics::tokenomics::{CanonicalTokenomicsEngine, TokenomicsError, MAX_SUPPLY};
+use bleep_governance::protocol_rules::{ProtocolRuleSet, ProtocolRuleSetFactory};
+use bleep_state::shard_registry::{EpochId, ShardId, ShardStateRoot};
+use bleep_state::snapshot_engine::{
+    SnapshotConfig, SnapshotEngine, SnapshotId, SnapshotStatus, StateSnapshot,
+};
+
+/// Allocation account that backs the genesis validator stake.
+pub const VALIDATOR_STAKE_ACCOUNT: &str = "bleep:genesis:validators";
+
+/// Genesis snapshot identifier.
+pub const GENESIS_SNAPSHOT_ID: SnapshotId = SnapshotId(0);
+
+#[derive(Debug, Error, PartialEq)]
+pub enum GenesisError {
+    #[error("No genesis allocations")]
+    NoAllocations,
+    #[error("Allocation for {0} must be > 0")]
+    ZeroAllocation(String),
+    #[error("Genesis supply {0} exceeds MAX_SUPPLY {1}")]
+    SupplyCapExceeded(u128, u128),
+    #[error("No genesis validators")]
+    NoValidators,
+    #[error("Duplicate genesis validator {0}")]
+    DuplicateValidator(String),
+    #[error("Validator stake {stake} exceeds {account} allocation {allocation}")]
+    StakeExceedsAllocation { stake: u128, allocation: u128, account: String },
+    #[error("Economics invariant violated: {0}")]
+    Economics(String),
+    #[error("Governance invariant violated: {0}")]
+    Governance(String),
+    #[error("Consensus invariant violated: {0}")]
+    Consensus(String),
+    #[error("State invariant violated: {0}")]
+    State(String),
+}
+
+/// A validator present in the genesis set.
+#[derive(Debug, Clone)]
+pub struct GenesisValidator {
+    pub id: String,
+    /// Kyber-1024 public key (1568 bytes).
+    pub kyber_public_key: Vec<u8>,
+    pub signing_key_id: String,
+    /// SPHINCS+ public key the validator signs snapshots and blocks with.
+    pub signing_public_key: Vec<u8>,
+    pub stake: u128,
+}
+
+/// Fully assembled, cross-checked genesis.
+pub struct Genesis {
+    /// Account → µBLEEP, sorted for deterministic hashing.
+    pub allocations: BTreeMap<String, u128>,
+    pub total_supply: u128,
+    pub economics: BleepEconomicsRuntime,
+    pub ruleset: ProtocolRuleSet,
+    pub validators: ValidatorRegistry,
+    pub snapshot: StateSnapshot,
+    pub snapshot_engine: SnapshotEngine,
+}
+
+impl Genesis {
+    /// Run every subsystem's own invariant checks plus the cross-subsystem ones.
+    pub fn verify(&self) -> Result<(), GenesisError> {
+        // Economics
+        self.economics
+            .state
+            .verify_epoch_invariants()
+            .map_err(|e| GenesisError::Economics(e.to_string()))?;
+        if self.economics.circulating_supply() != self.total_supply {
+            return Err(GenesisError::Economics(format!(
+                "circulating supply {} != genesis supply {}",
+                self.economics.circulating_supply(),
+                self.total_supply
+            )));
+        }
+
+        // Governance
+        match self.ruleset.verify_commitment() {
+            Ok(true) => {}
+            Ok(false) => {
+                return Err(GenesisError::Governance(
+                    "ruleset commitment hash mismatch".to_string(),
+                ))
+            }
+            Err(e) => return Err(GenesisError::Governance(format!("{:?}", e))),
+        }
+
+        // Consensus ↔ economics
+        let allocation = self.allocations.get(VALIDATOR_STAKE_ACCOUNT).copied().unwrap_or(0);
+        let active_stake = self.validators.total_active_stake();
+        if active_stake > allocation {
+            return Err(GenesisError::StakeExceedsAllocation {
+                stake: active_stake,
+                allocation,
+                account: VALIDATOR_STAKE_ACCOUNT.to_string(),
+            });
+        }
+        if self.validators.active_count() == 0 {
+            return Err(GenesisError::Consensus("empty active validator set".to_string()));
+        }
+        let registered_stake: u128 = self.validators
+            .get_active_validators()
+            .iter()
+            .map(|v| v.effective_stake())
+            .sum();
+        if registered_stake != active_stake {
+            return Err(GenesisError::Consensus(format!(
+                "validator stake {} != registry active stake {}",
+                registered_stake, active_stake
+            )));
+        }
+        let bonded_stake = self.economics.state.validators.total_active_stake();
+        if bonded_stake != active_stake {
+            return Err(GenesisError::Economics(format!(
+                "bonded stake {} != registry active stake {}",
+                bonded_stake, active_stake
+            )));
+        }
+
+        // State
+        if self.snapshot.compute_hash() != self.snapshot.snapshot_hash {
+            return Err(GenesisError::State("genesis snapshot hash mismatch".to_string()));
+        }
+        if self.snapshot.state_root.root_hash != allocation_root(&self.allocations) {
+            return Err(GenesisError::State(
+                "genesis state root does not commit to allocations".to_string(),
+            ));
+        }
+        self.snapshot_engine
+            .verify_lineage()
+            .map_err(GenesisError::State)?;
+
+        Ok(())
+    }
+}
+
+/// Collects genesis inputs and produces a verified `Genesis`.
+pub struct GenesisBuilder {
+    /// Allocations in the order given; repeated accounts are summed by the
+    /// tokenomics engine with overflow checks.
+    allocations: Vec<(String, u128)>,
+    validators: Vec<GenesisValidator>,
+    snapshot_config: SnapshotConfig,
+}
+
+impl Default for GenesisBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GenesisBuilder {
+    pub fn new() -> Self {
+        GenesisBuilder {
+            allocations: Vec::new(),
+            validators: Vec::new(),
+            snapshot_config: SnapshotConfig::new(10, 100, 1000, 0.66)
+                .expect("default snapshot config is valid"),
+        }
+    }
+
+    /// Credit `amount` µBLEEP to `account` at genesis.
+    pub fn allocate(mut self, account: impl Into<String>, amount: u128) -> Self {
+        self.allocations.push((account.into(), amount));
+        self
+    }
+
+    /// Add a validator to the genesis set.
+    pub fn validator(mut self, validator: GenesisValidator) -> Self {
+        self.validators.push(validator);
+        self
+    }
+
+    pub fn snapshot_config(mut self, config: SnapshotConfig) -> Self {
+        self.snapshot_config = config;
+        self
+    }
+
+    /// Assemble and verify the genesis.
+    pub fn build(self) -> Result<Genesis, GenesisError> {
+        // ── Supply ────────────────────────────────────────────────────────────
+        if self.allocations.is_empty() {
+            return Err(GenesisError::NoAllocations);
+        }
+        if let Some((account, _)) = self.allocations.iter().find(|(_, amount)| *amount == 0) {
+            return Err(GenesisError::ZeroAllocation(account.clone()));
+        }
+        let requested = self.allocations
+            .iter()
+            .try_fold(0u128, |total, (_, amount)| total.checked_add(*amount))
+            .unwrap_or(u128::MAX);
+        let tokenomics = CanonicalTokenomicsEngine::genesis_with_allocations(&self.allocations)
+            .map_err(|e| match e {
+                TokenomicsError::SupplyCapExceeded => {
+                    GenesisError::SupplyCapExceeded(requested, MAX_SUPPLY)
+                }
+                other => GenesisError::Economics(other.to_string()),
+            })?;
+        let allocations = tokenomics.genesis_allocations.clone();
+        let total_supply = tokenomics.supply_state.total_minted;
+
+        // ── Validator stake ↔ supply ──────────────────────────────────────────
+        if self.validators.is_empty() {
+            return Err(GenesisError::NoValidators);
+        }
+        let mut stake_total = 0u128;
+        for (i, v) in self.validators.iter().enumerate() {
+            if self.validators[..i].iter().any(|other| other.id == v.id) {
+                return Err(GenesisError::DuplicateValidator(v.id.clone()));
+            }
+            stake_total = stake_total.saturating_add(v.stake);
+        }
+        let allocation = allocations.get(VALIDATOR_STAKE_ACCOUNT).copied().unwrap_or(0);
+        if stake_total > allocation {
+            return Err(GenesisError::StakeExceedsAllocation {
+                stake: stake_total,
+                allocation,
+                account: VALIDATOR_STAKE_ACCOUNT.to_string(),
+            });
+        }
+
+        // ── Economics ─────────────────────────────────────────────────────────
+        let mut economics = BleepEconomicsRuntime::genesis();
+        economics.state.tokenomics = tokenomics;
+        for v in &self.validators {
+            economics
+                .register_validator(v.id.as_bytes().to_vec(), v.stake)
+                .map_err(|e| GenesisError::Economics(e.to_string()))?;
+        }
+
+        // ── Governance ────────────────────────────────────────────────────────
+        let ruleset = ProtocolRuleSetFactory::create_genesis()
+            .map_err(|e| GenesisError::Governance(format!("{:?}", e)))?;
+
+        // ── Consensus ─────────────────────────────────────────────────────────
+        let mut validators = ValidatorRegistry::new();
+        for v in &self.validators {
+            let identity = ValidatorIdentity::new(
+                v.id.clone(),
+                v.kyber_public_key.clone(),
+                v.signing_key_id.clone(),
+                v.stake,
+                0,
+            )
+            .map_err(GenesisError::Consensus)?
+            .with_signing_public_key(v.signing_public_key.clone());
+            validators.register_validator(identity).map_err(GenesisError::Consensus)?;
+            validators.activate_validator(&v.id).map_err(GenesisError::Consensus)?;
+        }
+
+        // ── State ─────────────────────────────────────────────────────────────
+        let mut snapshot = StateSnapshot::new(
+            GENESIS_SNAPSHOT_ID,
+            ShardId(0),
+            EpochId(0),
+            0,
+            ShardStateRoot {
+                root_hash: allocation_root(&allocations),
+                tx_count: 0,
+                height: 0,
+            },
+            String::new(),
+            None,
+        );
+        // Genesis must hash identically on every node: pin the timestamp.
+        snapshot.timestamp = 0;
+        snapshot.snapshot_hash = snapshot.compute_hash();
+        snapshot.status = SnapshotStatus::Finalized;
+
+        let mut snapshot_engine = SnapshotEngine::new(self.snapshot_config, GENESIS_SNAPSHOT_ID);
+        for v in &self.validators {
+            snapshot_engine
+                .register_validator(v.signing_public_key.clone(), v.stake)
+                .map_err(GenesisError::State)?;
+        }
+
+        let genesis = Genesis {
+            allocations,
+            total_supply,
+            economics,
+            ruleset,
+            validators,
+            snapshot,
+            snapshot_engine,
+        };
+        genesis.verify()?;
+        Ok(genesis)
+    }
+}
+
+/// `SHA-256( for each (account, amount) in key order: account || 0x00 || amount_le16 )`
+fn allocation_root(allocations: &BTreeMap<String, u128>) -> String {
+    let mut hasher = Sha256::new();
+    for (account, amount) in allocations {
+        hasher.update(account.as_bytes());
+        hasher.update([0u8]);
+        hasher.update(amount.to_le_bytes());
+    }
+    hex::encode(hasher.finalize())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn validator(id: &str, stake: u128) -> GenesisValidator {
+        GenesisValidator {
+            id: id.to_string(),
+            kyber_public_key: vec![0x11; 1568],
+            signing_key_id: format!("{}-signing", id),
+            signing_public_key: format!("{}-pk", id).into_bytes(),
+            stake,
+        }
+    }
+
+    fn builder() -> GenesisBuilder {
+        GenesisBuilder::new()
+            .allocate("bleep:genesis:foundation", 500_000_000_000_000)
+            .allocate("bleep:genesis:rewards", 100_000_000_000_000)
+            .allocate(VALIDATOR_STAKE_ACCOUNT, 3_000_000)
+            .validator(validator("val-a", 1_000_000))
+            .validator(validator("val-b", 2_000_000))
+    }
+
+    #[test]
+    fn built_genesis_passes_all_invariants() {
+        let genesis = builder().build().unwrap();
+        assert!(genesis.verify().is_ok());
+        assert_eq!(genesis.total_supply, 600_000_003_000_000);
+        assert_eq!(genesis.economics.circulating_supply(), genesis.total_supply);
+        assert_eq!(genesis.validators.total_active_stake(), 3_000_000);
+        assert_eq!(genesis.snapshot.id, GENESIS_SNAPSHOT_ID);
+        assert_eq!(genesis.snapshot_engine.total_validator_stake(), 3_000_000);
+    }
+
+    #[test]
+    fn genesis_is_deterministic() {
+        let a = builder().build().unwrap();
+        let b = builder().build().unwrap();
+        assert_eq!(a.snapshot.snapshot_hash, b.snapshot.snapshot_hash);
+        assert_eq!(a.ruleset.commitment_hash, b.ruleset.commitment_hash);
+    }
+
+    #[test]
+    fn stake_supply_mismatch_rejected() {
+        let result = builder().validator(validator("val-c", 5)).build();
+        assert!(matches!(result, Err(GenesisError::StakeExceedsAllocation { .. })));
+    }
+
+    #[test]
+    fn stake_may_be_backed_by_a_larger_pool() {
+        let genesis = builder().allocate(VALIDATOR_STAKE_ACCOUNT, 7_000_000).build().unwrap();
+        assert_eq!(genesis.allocations[VALIDATOR_STAKE_ACCOUNT], 10_000_000);
+        assert_eq!(genesis.validators.total_active_stake(), 3_000_000);
+    }
+
+    #[test]
+    fn allocation_overflow_rejected() {
+        let result = builder()
+            .allocate("bleep:genesis:a", u128::MAX)
+            .allocate("bleep:genesis:a", u128::MAX)
+            .build();
+        assert!(matches!(result, Err(GenesisError::SupplyCapExceeded(_, _))));
+    }
+
+    #[test]
+    fn supply_cap_enforced() {
+        let result = builder().allocate("bleep:genesis:excess", MAX_SUPPLY).build();
+        assert!(matches!(result, Err(GenesisError::SupplyCapExceeded(_, _))));
+    }
+
+    #[test]
+    fn duplicate_validator_rejected() {
+        let result = GenesisBuilder::new()
+            .allocate(VALIDATOR_STAKE_ACCOUNT, 2)
+            .validator(validator("val-a", 1))
+            .validator(validator("val-a", 1))
+            .build();
+        assert_eq!(result.err(), Some(GenesisError::DuplicateValidator("val-a".to_string())));
+    }
+
+    #[test]
+    fn bonded_stake_must_match_registry() {
+        let mut genesis = builder().build().unwrap();
+        genesis.economics.register_validator(b"val-x".to_vec(), 1).unwrap();
+        assert!(matches!(genesis.verify(), Err(GenesisError::Economics(_))));
+    }
+}
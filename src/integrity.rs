@@ -0,0 +1,298 @@
+//! # Node Integrity Check
+//!
+//! One deterministic pass over every stateful subsystem, cheap enough to run
+//! after each block:
+//!
+//! ```text
+//!   economics   → supply / circulation / fee-routing invariants
+//!   consensus   → non-empty active set, cached stake, finality safety
+//!   state       → snapshot lineage and state-root consistency
+//!   governance  → ruleset commitment and proposal state machine
+//! ```
+//!
+//! Every check runs even after an earlier one fails, so the report names all
+//! inconsistent subsystems at once. A node should halt on any violation.
+
+use std::fmt;
+
+use bleep_consensus::finality::FinalizityManager;
+use bleep_consensus::validator_identity::ValidatorRegistry;
+use bleep_economics::runtime::BleepEconomicsRuntime;
+use bleep_governance::governance_core::GovernanceEngine;
+use bleep_governance::protocol_rules::ProtocolRuleSet;
+use bleep_state::shard_registry::ShardStateRoot;
+use bleep_state::snapshot_engine::{SnapshotEngine, StateSnapshot};
+
+/// Subsystem a violation was found in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Subsystem {
+    Economics,
+    Consensus,
+    State,
+    Governance,
+}
+
+impl fmt::Display for Subsystem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Subsystem::Economics => "economics",
+            Subsystem::Consensus => "consensus",
+            Subsystem::State => "state",
+            Subsystem::Governance => "governance",
+        };
+        f.write_str(name)
+    }
+}
+
+/// One failed invariant.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntegrityViolation {
+    pub subsystem: Subsystem,
+    pub reason: String,
+}
+
+/// Every violation found in one pass, in check order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntegrityReport {
+    pub violations: Vec<IntegrityViolation>,
+}
+
+impl IntegrityReport {
+    /// Distinct subsystems with at least one violation, in check order.
+    pub fn subsystems(&self) -> Vec<Subsystem> {
+        let mut subsystems: Vec<Subsystem> = Vec::new();
+        for v in &self.violations {
+            if !subsystems.contains(&v.subsystem) {
+                subsystems.push(v.subsystem);
+            }
+        }
+        subsystems
+    }
+}
+
+impl fmt::Display for IntegrityReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} integrity violation(s)", self.violations.len())?;
+        for v in &self.violations {
+            write!(f, "; {}: {}", v.subsystem, v.reason)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for IntegrityReport {}
+
+/// Borrowed view of a node's subsystems after applying a block.
+pub struct NodeState<'a> {
+    pub economics: &'a BleepEconomicsRuntime,
+    pub validators: &'a ValidatorRegistry,
+    pub finality: &'a FinalizityManager,
+    pub snapshot_engine: &'a SnapshotEngine,
+    /// Latest finalized snapshot of the node's shard.
+    pub latest_snapshot: &'a StateSnapshot,
+    /// State root the node computed for its current head.
+    pub state_root: &'a ShardStateRoot,
+    pub ruleset: &'a ProtocolRuleSet,
+    pub governance: &'a GovernanceEngine,
+}
+
+/// Run every subsystem's invariants and report all violations.
+pub fn verify_node_integrity(node: &NodeState<'_>) -> Result<(), IntegrityReport> {
+    let mut violations = Vec::new();
+    let mut report = |subsystem: Subsystem, reason: String| {
+        violations.push(IntegrityViolation { subsystem, reason });
+    };
+
+    for reason in check_economics(node) {
+        report(Subsystem::Economics, reason);
+    }
+    for reason in check_consensus(node) {
+        report(Subsystem::Consensus, reason);
+    }
+    for reason in check_state(node) {
+        report(Subsystem::State, reason);
+    }
+    for reason in check_governance(node) {
+        report(Subsystem::Governance, reason);
+    }
+
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(IntegrityReport { violations })
+    }
+}
+
+fn check_economics(node: &NodeState<'_>) -> Vec<String> {
+    let mut reasons = Vec::new();
+    if let Err(e) = node.economics.state.verify_epoch_invariants() {
+        reasons.push(e.to_string());
+    }
+    reasons
+}
+
+fn check_consensus(node: &NodeState<'_>) -> Vec<String> {
+    let mut reasons = Vec::new();
+
+    if node.validators.active_count() == 0 {
+        reasons.push("empty active validator set".to_string());
+    }
+
+    let active_stake: u128 = node
+        .validators
+        .get_active_validators()
+        .iter()
+        .map(|v| v.effective_stake())
+        .sum();
+    if active_stake != node.validators.total_active_stake() {
+        reasons.push(format!(
+            "active stake {} != cached total {}",
+            active_stake,
+            node.validators.total_active_stake()
+        ));
+    }
+
+    // SAFETY: Evidence arriving after finalization can still reveal a
+    // conflicting quorum at the finalized head.
+    let head = node.finality.highest_finalized();
+    if let Some(certificate) = node.finality.get_certificate(head) {
+        if let Err(alarm) = node.finality.check_safety_before_finalize(certificate) {
+            reasons.push(alarm.to_string());
+        }
+    }
+
+    reasons
+}
+
+fn check_state(node: &NodeState<'_>) -> Vec<String> {
+    let mut reasons = Vec::new();
+
+    if let Err(e) = node.snapshot_engine.verify_lineage() {
+        reasons.push(e);
+    }
+
+    let snapshot = node.latest_snapshot;
+    if snapshot.compute_hash() != snapshot.snapshot_hash {
+        reasons.push(format!("snapshot {} hash mismatch", snapshot.id.as_u64()));
+    }
+
+    let committed = &snapshot.state_root;
+    if node.state_root.height < committed.height {
+        reasons.push(format!(
+            "state root height {} is behind finalized snapshot height {}",
+            node.state_root.height, committed.height
+        ));
+    } else if node.state_root.height == committed.height
+        && node.state_root.root_hash != committed.root_hash
+    {
+        reasons.push(format!(
+            "state root {} != finalized snapshot root {} at height {}",
+            node.state_root.root_hash, committed.root_hash, committed.height
+        ));
+    }
+
+    reasons
+}
+
+fn check_governance(node: &NodeState<'_>) -> Vec<String> {
+    let mut reasons = Vec::new();
+
+    match node.ruleset.verify_commitment() {
+        Ok(true) => {}
+        Ok(false) => reasons.push("ruleset commitment hash mismatch".to_string()),
+        Err(e) => reasons.push(format!("{:?}", e)),
+    }
+    if let Err(e) = node.governance.verify_state_machine() {
+        reasons.push(e.to_string());
+    }
+
+    reasons
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::genesis::{Genesis, GenesisBuilder, GenesisValidator, VALIDATOR_STAKE_ACCOUNT};
+
+    struct Node {
+        genesis: Genesis,
+        finality: FinalizityManager,
+        state_root: ShardStateRoot,
+        governance: GovernanceEngine,
+    }
+
+    impl Node {
+        fn view(&self) -> NodeState<'_> {
+            NodeState {
+                economics: &self.genesis.economics,
+                validators: &self.genesis.validators,
+                finality: &self.finality,
+                snapshot_engine: &self.genesis.snapshot_engine,
+                latest_snapshot: &self.genesis.snapshot,
+                state_root: &self.state_root,
+                ruleset: &self.genesis.ruleset,
+                governance: &self.governance,
+            }
+        }
+    }
+
+    fn node() -> Node {
+        let validator = |id: &str, stake: u128| GenesisValidator {
+            id: id.to_string(),
+            kyber_public_key: vec![0x11; 1568],
+            signing_key_id: format!("{}-signing", id),
+            signing_public_key: format!("{}-pk", id).into_bytes(),
+            stake,
+        };
+        let genesis = GenesisBuilder::new()
+            .allocate("bleep:genesis:foundation", 500_000_000_000_000)
+            .allocate(VALIDATOR_STAKE_ACCOUNT, 3_000_000)
+            .validator(validator("val-a", 1_000_000))
+            .validator(validator("val-b", 2_000_000))
+            .build()
+            .unwrap();
+        Node {
+            finality: FinalizityManager::new(genesis.validators.total_active_stake()),
+            state_root: genesis.snapshot.state_root.clone(),
+            governance: GovernanceEngine::new(genesis.validators.total_active_stake()),
+            genesis,
+        }
+    }
+
+    fn offending(node: &Node) -> Vec<Subsystem> {
+        verify_node_integrity(&node.view()).unwrap_err().subsystems()
+    }
+
+    #[test]
+    fn consistent_node_passes() {
+        assert_eq!(verify_node_integrity(&node().view()), Ok(()));
+    }
+
+    #[test]
+    fn economics_inconsistency_reported() {
+        let mut node = node();
+        node.genesis.economics.state.tokenomics.supply_state.circulating_supply += 1;
+        assert_eq!(offending(&node), vec![Subsystem::Economics]);
+    }
+
+    #[test]
+    fn consensus_inconsistency_reported() {
+        let mut node = node();
+        node.genesis.validators.get_mut("val-a").unwrap().stake += 1;
+        assert_eq!(offending(&node), vec![Subsystem::Consensus]);
+    }
+
+    #[test]
+    fn state_inconsistency_reported() {
+        let mut node = node();
+        node.state_root.root_hash = "forked".to_string();
+        assert_eq!(offending(&node), vec![Subsystem::State]);
+    }
+
+    #[test]
+    fn governance_inconsistency_reported() {
+        let mut node = node();
+        node.genesis.ruleset.commitment_hash = vec![0; 32];
+        assert_eq!(offending(&node), vec![Subsystem::Governance]);
+    }
+}
@@ -0,0 +1,4 @@
+//! BLEEP node library — cross-crate wiring shared by the `bleep` binaries.
+
+pub mod genesis;
+pub mod integrity;